@@ -41,5 +41,20 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // probing.
     tokio::time::sleep(Duration::from_secs(3)).await;
 
+    // once probing has finished, the connection reports the largest UDP payload it settled on
+    let path_mtu = connection.path_mtu()?;
+    eprintln!("sending with a max UDP payload of {path_mtu}");
+
+    // send a large payload in chunks sized to the path MTU
+    let payload = bytes::Bytes::from(vec![42u8; 1_000_000]);
+    let mut stream = connection.open_send_stream().await?;
+    for chunk in payload.chunks(path_mtu) {
+        stream.send(payload.slice_ref(chunk)).await?;
+    }
+    stream.finish()?;
+
+    // give the server a chance to receive the data before closing the connection
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
     Ok(())
 }
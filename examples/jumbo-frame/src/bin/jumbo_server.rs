@@ -35,13 +35,19 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .start()?;
 
     eprintln!("Listening for a connection");
-    let connection = server.accept().await.unwrap();
+    let mut connection = server.accept().await.unwrap();
 
     eprintln!("Connection accepted from {:?}", connection.remote_addr());
 
-    // we aren't sending any data, but the endpoint will be probing
-    // for higher mtu's during this time.
-    tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+    // the endpoint will be probing for higher mtu's while waiting for the
+    // client to send its data.
+    if let Some(mut stream) = connection.accept_receive_stream().await? {
+        let mut received = 0;
+        while let Some(chunk) = stream.receive().await? {
+            received += chunk.len();
+        }
+        eprintln!("Received {received} bytes");
+    }
 
     Ok(())
 }
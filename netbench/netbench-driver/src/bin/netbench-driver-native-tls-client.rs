@@ -34,17 +34,25 @@ impl Client {
         let addresses = self.opts.address_map().await?;
         let scenario = self.opts.scenario();
 
+        if scenario.uses_datagrams() {
+            return Err("the native-tls driver does not support datagram operations".into());
+        }
+
         let mut trace = self.opts.trace();
-        let mut checkpoints = HashSet::new();
+        let mut checkpoints = (HashSet::new(), self.opts.shared.barrier());
         let mut timer = netbench::timer::Tokio::default();
 
         if let Some(config) = self.opts.multiplex() {
             let client = self.multiplex_client(config)?;
-            let client = netbench::Client::new(client, &scenario, &addresses);
+            let client = netbench::Client::new(client, &scenario, &addresses)
+                .with_rate_limit(self.opts.shared.rate_limit)
+                .with_max_concurrent_handshakes(self.opts.max_concurrent_handshakes);
             client.run(&mut trace, &mut checkpoints, &mut timer).await?;
         } else {
             let client = self.duplex_client()?;
-            let client = netbench::Client::new(client, &scenario, &addresses);
+            let client = netbench::Client::new(client, &scenario, &addresses)
+                .with_rate_limit(self.opts.shared.rate_limit)
+                .with_max_concurrent_handshakes(self.opts.max_concurrent_handshakes);
             client.run(&mut trace, &mut checkpoints, &mut timer).await?;
         }
 
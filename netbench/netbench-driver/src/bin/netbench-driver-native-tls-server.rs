@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use netbench::{multiplex, scenario, Result, Timer};
+use netbench::{multiplex, scenario, units::Rate, Barrier, Result, Timer};
 use netbench_driver::Allocator;
 use std::{collections::HashSet, sync::Arc};
 use structopt::StructOpt;
@@ -30,12 +30,19 @@ pub struct Server {
 impl Server {
     pub async fn run(&self) -> Result<()> {
         let scenario = self.opts.scenario();
+
+        if scenario.uses_datagrams() {
+            return Err("the native-tls driver does not support datagram operations".into());
+        }
+
         let buffer = (*self.opts.rx_buffer as usize, *self.opts.tx_buffer as usize);
 
         let server = self.server().await?;
 
         let trace = self.opts.trace();
         let config = self.opts.multiplex();
+        let rate_limit = self.opts.shared.rate_limit;
+        let barrier = self.opts.shared.barrier();
         let ident = self.identity()?;
         let acceptor = TlsAcceptor::builder(ident).build()?;
         let acceptor: tokio_native_tls::TlsAcceptor = acceptor.into();
@@ -55,16 +62,19 @@ impl Server {
             let acceptor = acceptor.clone();
             let trace = trace.clone();
             let config = config.clone();
+            let barrier = barrier.clone();
             spawn(async move {
-                if let Err(err) =
-                    handle_connection(acceptor, connection, id, scenario, trace, config, buffer)
-                        .await
+                if let Err(err) = handle_connection(
+                    acceptor, connection, id, scenario, trace, config, buffer, rate_limit, barrier,
+                )
+                .await
                 {
                     eprintln!("error: {err}");
                 }
             });
         }
 
+        #[allow(clippy::too_many_arguments)]
         async fn handle_connection(
             acceptor: Arc<tokio_native_tls::TlsAcceptor>,
             connection: TcpStream,
@@ -73,6 +83,8 @@ impl Server {
             mut trace: impl netbench::Trace,
             config: Option<multiplex::Config>,
             (rx_buffer, tx_buffer): (usize, usize),
+            rate_limit: Option<Rate>,
+            barrier: Barrier,
         ) -> Result<()> {
             let connection = io::BufStream::with_capacity(rx_buffer, tx_buffer, connection);
 
@@ -92,15 +104,15 @@ impl Server {
                 .get(server_idx as usize)
                 .ok_or("invalid connection id")?;
 
-            let mut checkpoints = HashSet::new();
+            let mut checkpoints = (HashSet::new(), barrier);
 
             if let Some(config) = config {
                 let conn = netbench::multiplex::Connection::new(conn_id, connection, config);
-                let conn = netbench::Driver::new(scenario, conn);
+                let conn = netbench::Driver::new(scenario, conn).with_rate_limit(rate_limit);
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             } else {
                 let conn = netbench::duplex::Connection::new(conn_id, connection);
-                let conn = netbench::Driver::new(scenario, conn);
+                let conn = netbench::Driver::new(scenario, conn).with_rate_limit(rate_limit);
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             }
 
@@ -3,7 +3,7 @@
 
 use netbench::Result;
 use netbench_driver::Allocator;
-use s2n_quic::provider::io;
+use s2n_quic::provider::{datagram, io};
 use std::collections::HashSet;
 use structopt::StructOpt;
 
@@ -34,9 +34,11 @@ impl Client {
         let scenario = self.opts.scenario();
 
         let client = self.client()?;
-        let client = netbench::Client::new(client, &scenario, &addresses);
+        let client = netbench::Client::new(client, &scenario, &addresses)
+            .with_rate_limit(self.opts.shared.rate_limit)
+            .with_max_concurrent_handshakes(self.opts.max_concurrent_handshakes);
         let mut trace = self.opts.trace();
-        let mut checkpoints = HashSet::new();
+        let mut checkpoints = (HashSet::new(), self.opts.shared.barrier());
         let mut timer = netbench::timer::Tokio::default();
         let mut client = client.run(&mut trace, &mut checkpoints, &mut timer).await?;
 
@@ -73,6 +75,7 @@ impl Client {
         let client = s2n_quic::Client::builder()
             .with_io(io)?
             .with_tls(tls)?
+            .with_datagram(datagram::default::Endpoint::default())?
             .start()
             .unwrap();
 
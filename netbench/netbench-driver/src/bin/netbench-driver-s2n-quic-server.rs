@@ -1,9 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use netbench::{scenario, timer::Timestamp, Result, Timer};
+use netbench::{scenario, timer::Timestamp, units::Rate, Barrier, Result, Timer};
 use netbench_driver::Allocator;
-use s2n_quic::{provider::io, Connection};
+use s2n_quic::{
+    provider::{datagram, io},
+    Connection,
+};
 use std::{collections::HashSet, sync::Arc};
 use structopt::StructOpt;
 use tokio::spawn;
@@ -33,14 +36,21 @@ impl Server {
     pub async fn run(&self) -> Result<()> {
         let scenario = self.opts.scenario();
         let trace = self.opts.trace();
+        let rate_limit = self.opts.shared.rate_limit;
+        let barrier = self.opts.shared.barrier();
+        let verbose = self.opts.verbose;
 
         let mut server = self.server(trace.clone())?;
 
         while let Some(connection) = server.accept().await {
             let scenario = scenario.clone();
             let trace = trace.clone();
+            let barrier = barrier.clone();
             spawn(async move {
-                if let Err(error) = handle_connection(connection, scenario, trace).await {
+                if let Err(error) =
+                    handle_connection(connection, scenario, trace, rate_limit, barrier, verbose)
+                        .await
+                {
                     eprintln!("error: {error:#}");
                 }
             });
@@ -52,13 +62,25 @@ impl Server {
             connection: Connection,
             scenario: Arc<scenario::Server>,
             mut trace: impl netbench::Trace,
+            rate_limit: Option<Rate>,
+            barrier: Barrier,
+            verbose: bool,
         ) -> Result<()> {
             let server_name = connection.server_name()?.ok_or("missing server name")?;
+
+            // log the negotiated parameters so benchmark results can be reproduced
+            if verbose {
+                if let Some(info) = connection.tls_info()? {
+                    eprintln!("connection {}: {info:?}", connection.id());
+                }
+            }
+
             let scenario = scenario.on_server_name(&server_name)?;
             let conn =
-                netbench::Driver::new(scenario, netbench::s2n_quic::Connection::new(connection));
+                netbench::Driver::new(scenario, netbench::s2n_quic::Connection::new(connection))
+                    .with_rate_limit(rate_limit);
 
-            let mut checkpoints = HashSet::new();
+            let mut checkpoints = (HashSet::new(), barrier);
             let mut timer = netbench::timer::Tokio::default();
 
             conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
@@ -93,6 +115,7 @@ impl Server {
         let server = s2n_quic::Server::builder()
             .with_io(io)?
             .with_tls(tls)?
+            .with_datagram(datagram::default::Endpoint::default())?
             .with_event(EventTracer::new(trace))?
             .start()
             .unwrap();
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use netbench::{duplex, multiplex, scenario, Driver, Result, Timer};
+use netbench::{duplex, multiplex, scenario, units::Rate, Barrier, Driver, Result, Timer};
 use netbench_driver::Allocator;
 use s2n_tls::{
     config::{Builder, Config},
@@ -39,6 +39,8 @@ impl Server {
 
         let trace = self.opts.trace();
         let config = self.opts.multiplex();
+        let rate_limit = self.opts.shared.rate_limit;
+        let barrier = self.opts.shared.barrier();
 
         let acceptor = TlsAcceptor::new(self.config()?.build()?);
         let acceptor: s2n_tls_tokio::TlsAcceptor<Config> = acceptor;
@@ -58,15 +60,19 @@ impl Server {
             let acceptor = acceptor.clone();
             let trace = trace.clone();
             let config = config.clone();
+            let barrier = barrier.clone();
             spawn(async move {
-                if let Err(err) =
-                    handle_connection(acceptor, connection, id, scenario, trace, config).await
+                if let Err(err) = handle_connection(
+                    acceptor, connection, id, scenario, trace, config, rate_limit, barrier,
+                )
+                .await
                 {
                     eprintln!("error: {err}");
                 }
             });
         }
 
+        #[allow(clippy::too_many_arguments)]
         async fn handle_connection(
             acceptor: Arc<s2n_tls_tokio::TlsAcceptor<Config>>,
             connection: TcpStream,
@@ -74,6 +80,8 @@ impl Server {
             scenario: Arc<scenario::Server>,
             mut trace: impl netbench::Trace,
             config: Option<multiplex::Config>,
+            rate_limit: Option<Rate>,
+            barrier: Barrier,
         ) -> Result<()> {
             let mut timer = netbench::timer::Tokio::default();
             let before = timer.now();
@@ -91,15 +99,15 @@ impl Server {
 
             let connection = Box::pin(connection);
 
-            let mut checkpoints = HashSet::new();
+            let mut checkpoints = (HashSet::new(), barrier);
 
             if let Some(config) = config {
                 let conn = multiplex::Connection::new(conn_id, connection, config);
-                let conn = Driver::new(scenario, conn);
+                let conn = Driver::new(scenario, conn).with_rate_limit(rate_limit);
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             } else {
                 let conn = duplex::Connection::new(conn_id, connection);
-                let conn = Driver::new(scenario, conn);
+                let conn = Driver::new(scenario, conn).with_rate_limit(rate_limit);
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             }
 
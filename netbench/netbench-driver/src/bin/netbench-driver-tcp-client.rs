@@ -30,16 +30,20 @@ impl Client {
         let scenario = self.opts.scenario();
 
         let mut trace = self.opts.trace();
-        let mut checkpoints = HashSet::new();
+        let mut checkpoints = (HashSet::new(), self.opts.shared.barrier());
         let mut timer = netbench::timer::Tokio::default();
 
         if let Some(config) = self.opts.multiplex() {
             let client = self.multiplex_client(config)?;
-            let client = netbench::Client::new(client, &scenario, &addresses);
+            let client = netbench::Client::new(client, &scenario, &addresses)
+                .with_rate_limit(self.opts.shared.rate_limit)
+                .with_max_concurrent_handshakes(self.opts.max_concurrent_handshakes);
             client.run(&mut trace, &mut checkpoints, &mut timer).await?;
         } else {
             let client = self.duplex_client()?;
-            let client = netbench::Client::new(client, &scenario, &addresses);
+            let client = netbench::Client::new(client, &scenario, &addresses)
+                .with_rate_limit(self.opts.shared.rate_limit)
+                .with_max_concurrent_handshakes(self.opts.max_concurrent_handshakes);
             client.run(&mut trace, &mut checkpoints, &mut timer).await?;
         }
 
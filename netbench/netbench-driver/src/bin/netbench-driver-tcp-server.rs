@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use netbench::{multiplex, scenario, Result, Timer};
+use netbench::{multiplex, scenario, units::Rate, Barrier, Result, Timer};
 use netbench_driver::Allocator;
 use std::{collections::HashSet, sync::Arc};
 use structopt::StructOpt;
@@ -33,6 +33,8 @@ impl Server {
         let server = self.server().await?;
         let trace = self.opts.trace();
         let config = self.opts.multiplex();
+        let rate_limit = self.opts.shared.rate_limit;
+        let barrier = self.opts.shared.barrier();
 
         let mut conn_id = 0;
         loop {
@@ -47,15 +49,19 @@ impl Server {
             conn_id += 1;
             let trace = trace.clone();
             let config = config.clone();
+            let barrier = barrier.clone();
             spawn(async move {
-                if let Err(err) =
-                    handle_connection(connection, id, scenario, trace, config, buffer).await
+                if let Err(err) = handle_connection(
+                    connection, id, scenario, trace, config, buffer, rate_limit, barrier,
+                )
+                .await
                 {
                     eprintln!("error: {err}");
                 }
             });
         }
 
+        #[allow(clippy::too_many_arguments)]
         async fn handle_connection(
             connection: TcpStream,
             conn_id: u64,
@@ -63,6 +69,8 @@ impl Server {
             mut trace: impl netbench::Trace,
             config: Option<multiplex::Config>,
             (rx_buffer, tx_buffer): (usize, usize),
+            rate_limit: Option<Rate>,
+            barrier: Barrier,
         ) -> Result<()> {
             let mut timer = netbench::timer::Tokio::default();
 
@@ -78,20 +86,22 @@ impl Server {
                 .get(server_idx as usize)
                 .ok_or("invalid connection id")?;
 
-            let mut checkpoints = HashSet::new();
+            let mut checkpoints = (HashSet::new(), barrier);
 
             if let Some(config) = config {
                 let conn = netbench::Driver::new(
                     scenario,
                     netbench::multiplex::Connection::new(conn_id, connection, config),
-                );
+                )
+                .with_rate_limit(rate_limit);
 
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             } else {
                 let conn = netbench::Driver::new(
                     scenario,
                     netbench::duplex::Connection::new(conn_id, connection),
-                );
+                )
+                .with_rate_limit(rate_limit);
 
                 conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
             }
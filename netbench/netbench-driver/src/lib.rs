@@ -4,10 +4,17 @@
 use netbench::{
     client::{self, AddressMap},
     multiplex, scenario, trace,
-    units::Byte,
-    Error, Result,
+    units::{Byte, Rate},
+    Barrier, Error, Result,
+};
+use std::{
+    net::IpAddr,
+    ops::Deref,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
 };
-use std::{net::IpAddr, ops::Deref, path::Path, str::FromStr, sync::Arc, time::Duration};
 use structopt::StructOpt;
 
 mod alloc;
@@ -55,6 +62,9 @@ pub struct Server {
     /// Without this, the requirement is inferred based on the scenario
     #[structopt(long, env = "MULTIPLEX")]
     multiplex: Option<Option<bool>>,
+
+    #[structopt(flatten)]
+    pub shared: Shared,
 }
 
 impl Server {
@@ -88,7 +98,12 @@ impl Server {
     }
 
     pub fn trace(&self) -> impl trace::Trace + Clone {
-        traces(&self.trace[..], self.verbose, &self.scenario.traces)
+        traces(
+            &self.trace[..],
+            self.verbose,
+            self.shared.prometheus_textfile.as_deref(),
+            &self.scenario.traces,
+        )
     }
 
     pub fn multiplex(&self) -> Option<multiplex::Config> {
@@ -139,6 +154,16 @@ pub struct Client {
     /// Without this, the requirement is inferred based on the scenario
     #[structopt(long, env = "MULTIPLEX")]
     multiplex: Option<Option<bool>>,
+
+    #[structopt(flatten)]
+    pub shared: Shared,
+
+    /// Limits the number of handshakes that can be in flight at the same time
+    ///
+    /// Connections opened while the limit is reached are queued until a pending handshake
+    /// completes. Without this option, all connections are opened as soon as they are reached.
+    #[structopt(long, env = "MAX_CONCURRENT_HANDSHAKES")]
+    pub max_concurrent_handshakes: Option<usize>,
 }
 
 impl Client {
@@ -179,7 +204,12 @@ impl Client {
     }
 
     pub fn trace(&self) -> impl trace::Trace + Clone {
-        traces(&self.trace[..], self.verbose, &self.scenario.traces)
+        traces(
+            &self.trace[..],
+            self.verbose,
+            self.shared.prometheus_textfile.as_deref(),
+            &self.scenario.traces,
+        )
     }
 
     pub fn multiplex(&self) -> Option<multiplex::Config> {
@@ -193,6 +223,35 @@ impl Client {
     }
 }
 
+/// Options shared by the client and server drivers
+#[derive(Debug, StructOpt)]
+pub struct Shared {
+    /// Periodically writes stream and throughput histograms to the file in the Prometheus text
+    /// format
+    #[structopt(long, env = "PROMETHEUS_TEXTFILE")]
+    pub prometheus_textfile: Option<PathBuf>,
+
+    /// Limits the throughput of stream data on each connection (e.g. `10MBps`)
+    ///
+    /// Sending and receiving are limited independently.
+    #[structopt(long, env = "RATE_LIMIT")]
+    pub rate_limit: Option<Rate>,
+
+    /// The number of connections that must reach a barrier before any of them proceed
+    ///
+    /// Barriers are shared by all of the connections in the driver process, while `park` and
+    /// `unpark` checkpoints remain local to each connection. Without this option, barriers are
+    /// released as soon as they are reached.
+    #[structopt(long, env = "BARRIER_PARTIES")]
+    pub barrier_parties: Option<usize>,
+}
+
+impl Shared {
+    pub fn barrier(&self) -> Barrier {
+        Barrier::new(self.barrier_parties.unwrap_or(1))
+    }
+}
+
 fn is_multiplex_enabled(opt: Option<Option<bool>>) -> bool {
     match opt {
         Some(Some(v)) => v,
@@ -241,7 +300,12 @@ impl Deref for Scenario {
     }
 }
 
-fn traces(trace: &[String], verbose: bool, traces: &Arc<Vec<String>>) -> impl trace::Trace + Clone {
+fn traces(
+    trace: &[String],
+    verbose: bool,
+    prometheus_textfile: Option<&Path>,
+    traces: &Arc<Vec<String>>,
+) -> impl trace::Trace + Clone {
     let enabled = !trace.iter().any(|v| v == "disabled");
 
     let throughput = if enabled && trace.iter().any(|v| v == "throughput") {
@@ -260,7 +324,13 @@ fn traces(trace: &[String], verbose: bool, traces: &Arc<Vec<String>>) -> impl tr
         None
     };
 
+    let prometheus = prometheus_textfile.map(|path| {
+        let trace = trace::Prometheus::default();
+        trace.reporter(path.to_path_buf(), Duration::from_secs(1));
+        trace
+    });
+
     let usdt = trace::Usdt::default();
 
-    (usdt, (throughput, stdio))
+    (usdt, (throughput, (stdio, prometheus)))
 }
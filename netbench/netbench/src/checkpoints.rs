@@ -1,12 +1,32 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use core::task::{Context, Poll};
-use std::collections::HashSet;
+use core::task::{Context, Poll, Waker};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
 
 pub trait Checkpoints {
     fn park(&mut self, id: u64) -> Poll<()>;
     fn unpark(&mut self, id: u64, cx: &mut Context);
+
+    /// Records the arrival at a barrier and returns the generation that was joined
+    fn arrive(&mut self, id: u64) -> u64 {
+        let _ = id;
+        0
+    }
+
+    /// Polls until all of the parties have arrived for the barrier generation
+    ///
+    /// Without a shared [`Barrier`], the only party is the current connection so the barrier
+    /// is released immediately.
+    fn poll_release(&mut self, id: u64, generation: u64, cx: &mut Context) -> Poll<()> {
+        let _ = id;
+        let _ = generation;
+        let _ = cx;
+        Poll::Ready(())
+    }
 }
 
 impl Checkpoints for HashSet<u64> {
@@ -24,3 +44,125 @@ impl Checkpoints for HashSet<u64> {
         cx.waker().wake_by_ref();
     }
 }
+
+/// Barriers shared between connections
+///
+/// The per-connection checkpoint set is still used for `park` and `unpark` operations, which
+/// only synchronize operations within a single connection. `barrier` operations are instead
+/// routed to the shared state and wait until `parties` connections have reached the barrier.
+/// Once released, the barrier resets so it can be reused by subsequent iterations.
+///
+/// ```rust,ignore
+/// let barrier = Barrier::new(parties);
+/// // each connection gets its own checkpoint set along with a handle to the shared barriers
+/// let mut checkpoints = (HashSet::new(), barrier.clone());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Barrier(Arc<Mutex<BarrierState>>);
+
+#[derive(Debug)]
+struct BarrierState {
+    parties: usize,
+    checkpoints: HashMap<u64, BarrierEntry>,
+}
+
+#[derive(Debug, Default)]
+struct BarrierEntry {
+    arrived: usize,
+    generation: u64,
+    wakers: Vec<Waker>,
+}
+
+impl Barrier {
+    /// Creates a barrier which is released once `parties` connections have arrived
+    pub fn new(parties: usize) -> Self {
+        Self(Arc::new(Mutex::new(BarrierState {
+            parties: parties.max(1),
+            checkpoints: HashMap::new(),
+        })))
+    }
+
+    fn arrive(&self, id: u64) -> u64 {
+        let mut state = self.0.lock().unwrap();
+        let parties = state.parties;
+        let entry = state.checkpoints.entry(id).or_default();
+        let generation = entry.generation;
+
+        entry.arrived += 1;
+
+        if entry.arrived >= parties {
+            entry.arrived = 0;
+            entry.generation += 1;
+            for waker in entry.wakers.drain(..) {
+                waker.wake();
+            }
+        }
+
+        generation
+    }
+
+    fn poll_release(&self, id: u64, generation: u64, cx: &mut Context) -> Poll<()> {
+        let mut state = self.0.lock().unwrap();
+        let entry = state.checkpoints.entry(id).or_default();
+
+        if entry.generation != generation {
+            return Poll::Ready(());
+        }
+
+        if !entry.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            entry.wakers.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<C: Checkpoints> Checkpoints for (C, Barrier) {
+    fn park(&mut self, id: u64) -> Poll<()> {
+        self.0.park(id)
+    }
+
+    fn unpark(&mut self, id: u64, cx: &mut Context) {
+        self.0.unpark(id, cx)
+    }
+
+    fn arrive(&mut self, id: u64) -> u64 {
+        self.1.arrive(id)
+    }
+
+    fn poll_release(&mut self, id: u64, generation: u64, cx: &mut Context) -> Poll<()> {
+        self.1.poll_release(id, generation, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_test::task::new_count_waker;
+
+    #[test]
+    fn barrier_test() {
+        let barrier = Barrier::new(2);
+        let mut a = (HashSet::new(), barrier.clone());
+        let mut b = (HashSet::new(), barrier);
+
+        let (waker, count) = new_count_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let generation = a.arrive(0);
+        assert!(a.poll_release(0, generation, &mut cx).is_pending());
+
+        // the per-connection checkpoints are not affected by the barrier
+        assert!(b.park(0).is_pending());
+
+        assert_eq!(b.arrive(0), generation);
+        assert_eq!(count.get(), 1, "the waiting party should be woken");
+        assert!(a.poll_release(0, generation, &mut cx).is_ready());
+        assert!(b.poll_release(0, generation, &mut cx).is_ready());
+
+        // the barrier resets for the next generation
+        let next = a.arrive(0);
+        assert_ne!(next, generation);
+        assert!(a.poll_release(0, next, &mut cx).is_pending());
+    }
+}
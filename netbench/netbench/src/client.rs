@@ -6,6 +6,7 @@ use crate::{
     operation as ops,
     scenario::{self, Scenario},
     timer::Timer,
+    units::Rate,
     Checkpoints, Result, Trace,
 };
 use std::{
@@ -16,8 +17,11 @@ use std::{
     task::{Context, Poll},
 };
 
+mod handshake;
 mod thread;
 
+use handshake::Handshakes;
+
 pub trait Client<'a> {
     type Connect: Future<Output = Result<Self::Connection>> + Unpin;
     type Connection: Connection;
@@ -34,6 +38,9 @@ pub trait Client<'a> {
 pub trait Connection: crate::driver::timer::Provider {
     fn id(&self) -> u64;
 
+    /// Limits the throughput of stream data on the connection
+    fn set_rate_limit(&mut self, rate: Option<Rate>);
+
     fn poll<T: Trace, Ch: Checkpoints>(
         &mut self,
         trace: &mut T,
@@ -47,17 +54,36 @@ pub struct Driver<'a, C: Client<'a>> {
     client: C,
     thread: thread::Thread<'a, C>,
     addresses: &'a AddressMap,
+    handshakes: Handshakes,
 }
 
 impl<'a, C: Client<'a>> Driver<'a, C> {
     pub fn new(client: C, scenario: &'a scenario::Client, addresses: &'a AddressMap) -> Self {
         Self {
             client,
-            thread: thread::Thread::new(scenario, &scenario.scenario),
+            thread: thread::Thread::new(scenario, &scenario.scenario, None),
             addresses,
+            handshakes: Handshakes::default(),
         }
     }
 
+    /// Limits the throughput of stream data on each of the client's connections
+    ///
+    /// See [`crate::Driver::with_rate_limit`] for details.
+    pub fn with_rate_limit(mut self, rate: Option<Rate>) -> Self {
+        self.thread.rate_limit = rate;
+        self
+    }
+
+    /// Limits the number of handshakes that can be in flight at the same time
+    ///
+    /// Any connections opened while the limit is reached are queued until one of the pending
+    /// handshakes completes. Passing `None` allows an unlimited number of concurrent handshakes.
+    pub fn with_max_concurrent_handshakes(mut self, max: Option<usize>) -> Self {
+        self.handshakes = Handshakes::new(max);
+        self
+    }
+
     pub async fn run<T: Trace, Ch: Checkpoints, Ti: Timer>(
         mut self,
         trace: &mut T,
@@ -96,6 +122,7 @@ impl<'a, C: Client<'a>> Driver<'a, C> {
         self.thread.poll(
             &mut self.client,
             self.addresses,
+            &mut self.handshakes,
             trace,
             checkpoints,
             now,
@@ -214,3 +241,164 @@ impl AddressMap {
         &self.hostnames[connection_id as usize]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{timer, trace::Disabled};
+    use core::pin::Pin;
+    use futures_test::task::new_count_waker;
+    use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+    #[derive(Debug, Default)]
+    struct Stats {
+        pending: usize,
+        max_pending: usize,
+    }
+
+    #[derive(Default)]
+    struct TestClient {
+        id: u64,
+        stats: Rc<RefCell<Stats>>,
+    }
+
+    impl<'a> Client<'a> for TestClient {
+        type Connect = TestConnect;
+        type Connection = TestConnection;
+
+        fn connect(
+            &mut self,
+            _addr: SocketAddr,
+            _hostname: &str,
+            _server_conn_id: u64,
+            _ops: &'a Arc<scenario::Connection>,
+        ) -> Self::Connect {
+            let mut stats = self.stats.borrow_mut();
+            stats.pending += 1;
+            stats.max_pending = stats.max_pending.max(stats.pending);
+
+            let id = self.id;
+            self.id += 1;
+
+            TestConnect {
+                id,
+                polled: false,
+                stats: self.stats.clone(),
+            }
+        }
+    }
+
+    /// A handshake that completes on its second poll
+    struct TestConnect {
+        id: u64,
+        polled: bool,
+        stats: Rc<RefCell<Stats>>,
+    }
+
+    impl Future for TestConnect {
+        type Output = Result<TestConnection>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+            if !self.polled {
+                self.polled = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
+            self.stats.borrow_mut().pending -= 1;
+            Ok(TestConnection { id: self.id }).into()
+        }
+    }
+
+    struct TestConnection {
+        id: u64,
+    }
+
+    impl timer::Provider for TestConnection {
+        fn timers<Q: timer::Query>(&self, _query: &mut Q) -> timer::Result {
+            Ok(())
+        }
+    }
+
+    impl Connection for TestConnection {
+        fn id(&self) -> u64 {
+            self.id
+        }
+
+        fn set_rate_limit(&mut self, _rate: Option<Rate>) {}
+
+        fn poll<T: Trace, Ch: Checkpoints>(
+            &mut self,
+            _trace: &mut T,
+            _checkpoints: &mut Ch,
+            _now: timer::Timestamp,
+            _cx: &mut Context,
+        ) -> Poll<Result<()>> {
+            Ok(()).into()
+        }
+    }
+
+    /// Opens `connections` concurrently and returns the maximum number of pending handshakes
+    fn max_pending_handshakes(connections: usize, max: Option<usize>) -> usize {
+        let scenario = Scenario::build(|scenario| {
+            let server = scenario.create_server();
+
+            scenario.create_client(|client| {
+                client.scope(|scope| {
+                    for _ in 0..connections {
+                        scope.spawn(|client| {
+                            client.connect_to(server, |_conn| {});
+                        });
+                    }
+                });
+            });
+        });
+
+        let addresses = AddressMap {
+            routers: vec![],
+            servers: vec![([127, 0, 0, 1], 4433).into()],
+            hostnames: (0..connections).map(|id| format!("{id}.test")).collect(),
+        };
+
+        let client = TestClient::default();
+        let stats = client.stats.clone();
+
+        let mut driver = Driver::new(client, &scenario.clients[0], &addresses)
+            .with_max_concurrent_handshakes(max);
+        let mut trace = Disabled::default();
+        let mut checkpoints = HashSet::new();
+        let mut timer = timer::Testing::default();
+
+        let (waker, count) = new_count_waker();
+        let mut prev_count = 0;
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match driver.poll_with_timer(&mut trace, &mut checkpoints, &mut timer, &mut cx) {
+                Poll::Ready(Ok(())) => break,
+                Poll::Ready(Err(e)) => panic!("{e}"),
+                Poll::Pending => {
+                    let current_count = count.get();
+                    assert!(current_count > prev_count, "test is deadlocked");
+                    prev_count = current_count;
+                }
+            }
+        }
+
+        let stats = stats.borrow();
+        assert_eq!(stats.pending, 0);
+        stats.max_pending
+    }
+
+    #[test]
+    fn max_concurrent_handshakes_test() {
+        assert_eq!(max_pending_handshakes(10, None), 10);
+
+        for max in 1..=10 {
+            assert_eq!(max_pending_handshakes(10, Some(max)), max);
+        }
+
+        // a limit larger than the number of connections has no effect
+        assert_eq!(max_pending_handshakes(10, Some(20)), 10);
+    }
+}
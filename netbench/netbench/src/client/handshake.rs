@@ -0,0 +1,45 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::task::Context;
+
+/// Bounds the number of handshakes that are in flight at the same time
+///
+/// All of the client threads are polled from the same task so a plain counter is sufficient.
+#[derive(Debug, Default)]
+pub(crate) struct Handshakes {
+    max: Option<usize>,
+    pending: usize,
+}
+
+impl Handshakes {
+    pub fn new(max: Option<usize>) -> Self {
+        // a limit of 0 would never make progress so treat it as unlimited
+        let max = max.filter(|max| *max > 0);
+        Self { max, pending: 0 }
+    }
+
+    /// Returns `true` if a handshake can be started
+    #[inline]
+    pub fn acquire(&mut self) -> bool {
+        if let Some(max) = self.max {
+            if self.pending >= max {
+                return false;
+            }
+        }
+
+        self.pending += 1;
+        true
+    }
+
+    /// Notifies the limiter that a handshake has completed, either successfully or not
+    #[inline]
+    pub fn release(&mut self, cx: &mut Context) {
+        self.pending -= 1;
+
+        if self.max.is_some() {
+            // notify the task so any queued threads can make progress
+            cx.waker().wake_by_ref();
+        }
+    }
+}
@@ -1,10 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{AddressMap, Client, Connection};
+use super::{handshake::Handshakes, AddressMap, Client, Connection};
 use crate::{
     driver::timer::{self, Timer, Timestamp},
-    operation as op, scenario, Checkpoints, Result, Trace,
+    operation as op, scenario,
+    units::Rate,
+    Checkpoints, Result, Trace,
 };
 use core::{
     future::Future,
@@ -18,24 +20,32 @@ pub struct Thread<'a, C: Client<'a>> {
     index: usize,
     op: Option<Op<'a, C>>,
     timer: Timer,
+    pub(super) rate_limit: Option<Rate>,
 }
 
 impl<'a, C: Client<'a>> Thread<'a, C> {
-    pub fn new(scenario: &'a scenario::Client, ops: &'a [op::Client]) -> Self {
+    pub fn new(
+        scenario: &'a scenario::Client,
+        ops: &'a [op::Client],
+        rate_limit: Option<Rate>,
+    ) -> Self {
         Self {
             scenario,
             ops,
             index: 0,
             op: None,
             timer: Timer::default(),
+            rate_limit,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(super) fn poll<T: Trace, Ch: Checkpoints>(
         &mut self,
         client: &mut C,
         address_map: &AddressMap,
+        handshakes: &mut Handshakes,
         trace: &mut T,
         checkpoints: &mut Ch,
         now: Timestamp,
@@ -45,14 +55,23 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
             while self.op.is_none() {
                 if let Some(op) = self.ops.get(self.index) {
                     self.index += 1;
-                    self.on_op(client, address_map, op, trace, checkpoints, now, cx);
+                    self.on_op(
+                        client,
+                        address_map,
+                        handshakes,
+                        op,
+                        trace,
+                        checkpoints,
+                        now,
+                        cx,
+                    );
                 } else {
                     // we are all done processing the operations
                     return Poll::Ready(Ok(()));
                 }
             }
 
-            ready!(self.poll_op(client, address_map, trace, checkpoints, now, cx))?;
+            ready!(self.poll_op(client, address_map, handshakes, trace, checkpoints, now, cx))?;
             self.op = None;
         }
     }
@@ -63,6 +82,7 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
         &mut self,
         client: &mut C,
         addresses: &AddressMap,
+        handshakes: &mut Handshakes,
         op: &'a op::Client,
         trace: &mut T,
         checkpoints: &mut Ch,
@@ -76,25 +96,13 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
                 self.timer.sleep(now, *timeout);
                 self.op = Some(Op::Sleep);
             }
-            Connect {
-                server_id,
-                router_id,
-                server_connection_id,
-                client_connection_id,
-            } => {
-                let addr = if let Some(router_id) = router_id {
-                    addresses.router(*router_id, *server_id)
+            Connect { .. } => {
+                if handshakes.acquire() {
+                    self.connect(client, addresses, op, now);
                 } else {
-                    addresses.server(*server_id)
-                };
-                let hostname = addresses.hostname(*server_connection_id);
-                let ops = &self.scenario.connections[*client_connection_id as usize];
-                let connect = client.connect(addr, hostname, *server_connection_id, ops);
-                self.op = Some(Op::Connect {
-                    connect,
-                    id: *client_connection_id,
-                    start: now,
-                });
+                    // wait for one of the in-flight handshakes to complete
+                    self.op = Some(Op::Queued { op });
+                }
             }
             Park { checkpoint } => {
                 trace.park(now, *checkpoint);
@@ -112,7 +120,7 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
                 if !threads.is_empty() {
                     let threads = threads
                         .iter()
-                        .map(|thread| Thread::new(self.scenario, thread))
+                        .map(|thread| Thread::new(self.scenario, thread, self.rate_limit))
                         .collect();
                     self.op = Some(Op::Scope { threads });
                 }
@@ -120,11 +128,44 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
         }
     }
 
+    #[inline]
+    fn connect(
+        &mut self,
+        client: &mut C,
+        addresses: &AddressMap,
+        op: &'a op::Client,
+        now: Timestamp,
+    ) {
+        if let op::Client::Connect {
+            server_id,
+            router_id,
+            server_connection_id,
+            client_connection_id,
+        } = op
+        {
+            let addr = if let Some(router_id) = router_id {
+                addresses.router(*router_id, *server_id)
+            } else {
+                addresses.server(*server_id)
+            };
+            let hostname = addresses.hostname(*server_connection_id);
+            let ops = &self.scenario.connections[*client_connection_id as usize];
+            let connect = client.connect(addr, hostname, *server_connection_id, ops);
+            self.op = Some(Op::Connect {
+                connect,
+                id: *client_connection_id,
+                start: now,
+            });
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn poll_op<T: Trace, Ch: Checkpoints>(
         &mut self,
         client: &mut C,
         addresses: &AddressMap,
+        handshakes: &mut Handshakes,
         trace: &mut T,
         checkpoints: &mut Ch,
         now: Timestamp,
@@ -134,14 +175,25 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
             Op::Sleep => {
                 ready!(self.timer.poll(now));
             }
+            Op::Queued { op } => {
+                if !handshakes.acquire() {
+                    return Poll::Pending;
+                }
+                let op = *op;
+                self.connect(client, addresses, op, now);
+                return self.poll_op(client, addresses, handshakes, trace, checkpoints, now, cx);
+            }
             Op::Connect { connect, id, start } => {
                 let connect = core::pin::Pin::new(connect);
-                let connection = ready!(connect.poll(cx))?;
+                let result = ready!(connect.poll(cx));
+                handshakes.release(cx);
+                let mut connection = result?;
+                connection.set_rate_limit(self.rate_limit);
                 let time = now - *start;
                 trace.enter_connection(connection.id());
                 trace.connect(now, *id, time);
                 self.op = Some(Op::Connection { connection });
-                return self.poll_op(client, addresses, trace, checkpoints, now, cx);
+                return self.poll_op(client, addresses, handshakes, trace, checkpoints, now, cx);
             }
             Op::Connection { connection } => {
                 ready!(connection.poll(trace, checkpoints, now, cx))?;
@@ -155,7 +207,8 @@ impl<'a, C: Client<'a>> Thread<'a, C> {
                 let op_idx = self.index;
                 for (idx, thread) in threads.iter_mut().enumerate() {
                     trace.enter(now, op_idx as _, idx);
-                    let result = thread.poll(client, addresses, trace, checkpoints, now, cx);
+                    let result =
+                        thread.poll(client, addresses, handshakes, trace, checkpoints, now, cx);
                     trace.exit(now);
                     match result {
                         Poll::Ready(Ok(_)) => {}
@@ -196,6 +249,9 @@ impl<'a, C: Client<'a>> timer::Provider for Thread<'a, C> {
 
 enum Op<'a, C: Client<'a>> {
     Sleep,
+    Queued {
+        op: &'a op::Client,
+    },
     Connect {
         connect: C::Connect,
         id: u64,
@@ -25,6 +25,21 @@ pub trait Connection {
     ) -> Poll<Result<u64>>;
     fn poll_send_finish(&mut self, owner: Owner, id: u64, cx: &mut Context) -> Poll<Result<()>>;
     fn poll_receive_finish(&mut self, owner: Owner, id: u64, cx: &mut Context) -> Poll<Result<()>>;
+    /// Sends a single unreliable datagram of `bytes` length to the peer
+    ///
+    /// Connections that do not support datagrams return an error.
+    fn poll_send_datagram(&mut self, bytes: u64, cx: &mut Context) -> Poll<Result<()>> {
+        let _ = bytes;
+        let _ = cx;
+        Err("datagrams are not supported by this connection".into()).into()
+    }
+    /// Receives a single unreliable datagram from the peer and returns its length
+    ///
+    /// Connections that do not support datagrams return an error.
+    fn poll_receive_datagram(&mut self, cx: &mut Context) -> Poll<Result<u64>> {
+        let _ = cx;
+        Err("datagrams are not supported by this connection".into()).into()
+    }
     fn poll_progress(&mut self, cx: &mut Context) -> Poll<Result<()>> {
         let _ = cx;
         Ok(()).into()
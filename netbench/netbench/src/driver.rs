@@ -1,13 +1,21 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{connection::Owner, units::Rates, Checkpoints, Connection, Result, Timer, Trace};
+use crate::{
+    connection::Owner,
+    units::{Rate, Rates},
+    Checkpoints, Connection, Result, Timer, Trace,
+};
 use core::task::{Context, Poll};
 use futures::ready;
 
+mod limit;
+mod stats;
 mod thread;
 pub(crate) mod timer;
 
+use limit::Limit;
+use stats::Stats;
 use thread::Thread;
 
 #[derive(Debug)]
@@ -17,12 +25,15 @@ pub struct Driver<'a, C: Connection> {
     local_rates: Rates,
     peer_streams: Vec<(Poll<()>, Thread<'a>)>,
     peer_rates: Rates,
+    limit: Limit,
+    stats: Stats,
     can_accept: bool,
     is_finished: bool,
 }
 
 impl<'a, C: Connection> Driver<'a, C> {
     pub fn new(scenario: &'a crate::scenario::Connection, connection: C) -> Self {
+        let stats = Stats::new(connection.id(), scenario);
         Self {
             connection,
             local_thread: Thread::new(&scenario.ops, Owner::Local),
@@ -33,11 +44,26 @@ impl<'a, C: Connection> Driver<'a, C> {
                 .map(|ops| (Poll::Pending, Thread::new(ops, Owner::Remote)))
                 .collect(),
             peer_rates: Default::default(),
+            limit: Default::default(),
+            stats,
             can_accept: true,
             is_finished: false,
         }
     }
 
+    /// Limits the throughput of stream data on the connection
+    ///
+    /// Sent and received data are limited independently to the provided rate. Bursts of up to a
+    /// single period of the rate are allowed when the connection has been idle.
+    pub fn with_rate_limit(mut self, rate: Option<Rate>) -> Self {
+        self.set_rate_limit(rate);
+        self
+    }
+
+    pub fn set_rate_limit(&mut self, rate: Option<Rate>) {
+        self.limit.set_rate(rate);
+    }
+
     pub async fn run<T: Trace, Ch: Checkpoints, Ti: Timer>(
         mut self,
         trace: &mut T,
@@ -90,6 +116,8 @@ impl<'a, C: Connection> Driver<'a, C> {
             trace,
             checkpoints,
             &mut self.local_rates,
+            &mut self.limit,
+            &mut self.stats,
             now,
             cx,
         );
@@ -115,6 +143,8 @@ impl<'a, C: Connection> Driver<'a, C> {
                 trace,
                 checkpoints,
                 &mut self.peer_rates,
+                &mut self.limit,
+                &mut self.stats,
                 now,
                 cx,
             );
@@ -127,10 +157,13 @@ impl<'a, C: Connection> Driver<'a, C> {
             }
         }
 
+        self.stats.sample(now, trace);
+
         if poll_accept {
             match self.connection.poll_accept_stream(cx) {
                 Poll::Ready(Ok(Some(id))) => {
                     trace.accept(now, id);
+                    self.stats.on_open(Owner::Remote, id, now);
                     if let Some((accepted, _)) = self.peer_streams.get_mut(id as usize) {
                         *accepted = Poll::Ready(());
                         cx.waker().wake_by_ref();
@@ -147,6 +180,7 @@ impl<'a, C: Connection> Driver<'a, C> {
         all_ready &= !timer::Provider::is_armed(&self);
 
         if all_ready {
+            self.stats.flush(now, trace);
             self.is_finished = true;
             self.connection.poll_finish(cx)
         } else {
@@ -162,6 +196,11 @@ impl<'a, C: Connection> crate::client::Connection for Driver<'a, C> {
         self.connection.id()
     }
 
+    #[inline]
+    fn set_rate_limit(&mut self, rate: Option<Rate>) {
+        Self::set_rate_limit(self, rate)
+    }
+
     #[inline]
     fn poll<T, Ch>(
         &mut self,
@@ -182,9 +221,195 @@ impl<'a, C: Connection> timer::Provider for Driver<'a, C> {
     #[inline]
     fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
         self.local_thread.timers(query)?;
+        self.limit.timers(query)?;
         for (_, thread) in self.peer_streams.iter() {
             thread.timers(query)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{multiplex, scenario::Scenario, testing, timer, trace::MemoryLogger, units::*};
+    use futures_test::task::new_count_waker;
+    use std::collections::HashSet;
+
+    /// Runs the scenario with the client rate limited and returns the time it took to complete
+    fn completion_time(scenario: &Scenario, rate_limit: Option<Rate>) -> Duration {
+        let mut client_trace = MemoryLogger::new(scenario.traces.clone());
+        run(scenario, rate_limit, &mut client_trace)
+    }
+
+    fn run<T: Trace>(
+        scenario: &Scenario,
+        rate_limit: Option<Rate>,
+        client_trace: &mut T,
+    ) -> Duration {
+        let traces = &scenario.traces;
+        let config = multiplex::Config::default();
+
+        let (client, server) = testing::Connection::pair(10000);
+
+        let mut client = {
+            let scenario = &scenario.clients[0].connections[0];
+            let conn = multiplex::Connection::new(0, Box::pin(client), config.clone());
+            Driver::new(scenario, conn).with_rate_limit(rate_limit)
+        };
+        let mut client_checkpoints = HashSet::new();
+        let mut client_timer = timer::Testing::default();
+
+        let mut server = {
+            let scenario = &scenario.servers[0].connections[0];
+            let conn = multiplex::Connection::new(1, Box::pin(server), config);
+            Driver::new(scenario, conn)
+        };
+        let mut server_trace = MemoryLogger::new(traces.clone());
+        let mut server_checkpoints = HashSet::new();
+        let mut server_timer = timer::Testing::default();
+
+        let (waker, count) = new_count_waker();
+        let mut prev_count = 0;
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            let c = client.poll_with_timer(
+                client_trace,
+                &mut client_checkpoints,
+                &mut client_timer,
+                &mut cx,
+            );
+            let s = server.poll_with_timer(
+                &mut server_trace,
+                &mut server_checkpoints,
+                &mut server_timer,
+                &mut cx,
+            );
+
+            match (c, s) {
+                (Poll::Ready(Ok(())), Poll::Ready(Ok(()))) => break,
+                (Poll::Ready(Err(e)), _) | (_, Poll::Ready(Err(e))) => panic!("{e}"),
+                _ => {
+                    let current_count = count.get();
+                    if current_count > prev_count {
+                        prev_count = current_count;
+                        continue;
+                    }
+
+                    assert!(
+                        client_timer.advance_pair(&mut server_timer).is_some(),
+                        "test is deadlocked"
+                    );
+                }
+            }
+        }
+
+        let start = timer::Testing::default().now();
+        client_timer.now().saturating_duration_since(start)
+    }
+
+    #[test]
+    fn rate_limit_test() {
+        let scenario = Scenario::build(|scenario| {
+            let server = scenario.create_server();
+
+            scenario.create_client(|client| {
+                client.connect_to(server, |conn| {
+                    conn.open_send_stream(
+                        |local| {
+                            local.send(10.kilobytes());
+                        },
+                        |remote| {
+                            remote.receive_all();
+                        },
+                    );
+                });
+            });
+        });
+
+        assert_eq!(completion_time(&scenario, None), Duration::ZERO);
+
+        // the bucket starts with a full period's worth of tokens so the first kilobyte is sent
+        // immediately and the remaining 9 take 100ms each
+        let rate = 1.kilobytes() / 100.millis();
+        assert_eq!(
+            completion_time(&scenario, Some(rate)),
+            Duration::from_millis(900)
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        streams: Vec<(u64, u64, Duration)>,
+        samples: Vec<(u64, u64)>,
+    }
+
+    impl Trace for Recorder {
+        fn on_stream_complete(&mut self, conn_id: u64, bytes: u64, duration: Duration) {
+            self.streams.push((conn_id, bytes, duration));
+        }
+
+        fn on_throughput_sample(&mut self, conn_id: u64, bytes_per_sec: u64) {
+            self.samples.push((conn_id, bytes_per_sec));
+        }
+    }
+
+    #[test]
+    fn stats_test() {
+        let scenario = Scenario::build(|scenario| {
+            let server = scenario.create_server();
+
+            scenario.create_client(|client| {
+                client.connect_to(server, |conn| {
+                    conn.open_bidirectional_stream(
+                        |local| {
+                            local.send(10.kilobytes());
+                            local.receive(5.kilobytes());
+                        },
+                        |remote| {
+                            remote.receive(10.kilobytes());
+                            remote.send(5.kilobytes());
+                        },
+                    );
+                    conn.open_send_stream(
+                        |local| {
+                            local.send(1.kilobytes());
+                        },
+                        |remote| {
+                            remote.receive_all();
+                        },
+                    );
+                });
+            });
+        });
+
+        // limit the rate so the transfer spans multiple sample intervals
+        let rate = 1.kilobytes() / 100.millis();
+        let mut trace = Recorder::default();
+        let time = run(&scenario, Some(rate), &mut trace);
+        assert!(time > Duration::from_secs(1));
+
+        let mut bytes: Vec<_> = trace
+            .streams
+            .iter()
+            .map(|(conn_id, bytes, duration)| {
+                assert_eq!(*conn_id, 0);
+                assert!(*duration <= time);
+                *bytes
+            })
+            .collect();
+        bytes.sort_unstable();
+        assert_eq!(
+            bytes,
+            [*1.kilobytes(), *10.kilobytes() + *5.kilobytes()],
+            "each stream should complete once with the bytes sent and received"
+        );
+
+        assert!(trace.samples.iter().all(|(conn_id, _)| *conn_id == 0));
+        assert!(trace
+            .samples
+            .iter()
+            .any(|(_, bytes_per_sec)| *bytes_per_sec > 0));
+    }
+}
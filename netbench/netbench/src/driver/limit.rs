@@ -0,0 +1,121 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::timer::{self, Timestamp};
+use crate::units::{Byte, ByteExt, Rate};
+use core::{task::Poll, time::Duration};
+
+/// Limits the application-layer throughput of a connection
+///
+/// Sending and receiving are limited independently, each with its own token bucket.
+#[derive(Debug, Default)]
+pub(crate) struct Limit {
+    pub send: Bucket,
+    pub receive: Bucket,
+}
+
+impl Limit {
+    pub fn set_rate(&mut self, rate: Option<Rate>) {
+        self.send = Bucket::new(rate);
+        self.receive = Bucket::new(rate);
+    }
+}
+
+impl timer::Provider for Limit {
+    #[inline]
+    fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
+        self.send.timer.timers(query)?;
+        self.receive.timer.timers(query)?;
+        Ok(())
+    }
+}
+
+/// A token bucket which holds up to a single period's worth of bytes
+///
+/// The bucket starts full and refills continuously at the configured rate.
+#[derive(Debug, Default)]
+pub(crate) struct Bucket {
+    rate: Option<Rate>,
+    tokens: u64,
+    updated: Option<Timestamp>,
+    timer: s2n_quic_core::time::Timer,
+}
+
+impl Bucket {
+    fn new(rate: Option<Rate>) -> Self {
+        // a zero rate would never refill so treat it as unlimited
+        let rate = rate.filter(|rate| *rate.bytes > 0 && !rate.period.is_zero());
+        Self {
+            rate,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of bytes that can be transferred, up to `requested`
+    ///
+    /// If the bucket is empty, the timer is armed for when enough tokens are available and
+    /// `Poll::Pending` is returned.
+    #[inline]
+    pub fn poll_acquire(&mut self, requested: Byte, now: Timestamp) -> Poll<Byte> {
+        let rate = if let Some(rate) = self.rate {
+            rate
+        } else {
+            return Poll::Ready(requested);
+        };
+
+        self.timer.cancel();
+        self.refill(&rate, now);
+
+        if self.tokens > 0 {
+            return Poll::Ready(requested.min(self.tokens.bytes()));
+        }
+
+        // wait until the bucket can satisfy the request, bounded by its capacity
+        let needed = (*requested).clamp(1, *rate.bytes);
+        let capacity = *rate.bytes as u128;
+        let wait = (needed as u128 * rate.period.as_nanos() + capacity - 1) / capacity;
+        let updated = self.updated.unwrap_or(now);
+        self.timer.set(updated + Duration::from_nanos(wait as u64));
+
+        Poll::Pending
+    }
+
+    /// Removes the transferred bytes from the bucket
+    #[inline]
+    pub fn release(&mut self, amount: u64) {
+        if self.rate.is_some() {
+            self.tokens = self.tokens.saturating_sub(amount);
+        }
+    }
+
+    fn refill(&mut self, rate: &Rate, now: Timestamp) {
+        let capacity = *rate.bytes;
+
+        let updated = if let Some(updated) = self.updated {
+            updated
+        } else {
+            self.tokens = capacity;
+            self.updated = Some(now);
+            return;
+        };
+
+        let period = rate.period.as_nanos();
+        let elapsed = now.saturating_duration_since(updated).as_nanos();
+        let added = elapsed * capacity as u128 / period;
+
+        if added == 0 {
+            return;
+        }
+
+        let tokens = self.tokens as u128 + added;
+        if tokens >= capacity as u128 {
+            self.tokens = capacity;
+            self.updated = Some(now);
+        } else {
+            self.tokens = tokens as u64;
+            // only consume the time that was converted into tokens so partial tokens carry over
+            let consumed = added * period / capacity as u128;
+            self.updated = Some(updated + Duration::from_nanos(consumed as u64));
+        }
+    }
+}
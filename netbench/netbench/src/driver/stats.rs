@@ -0,0 +1,150 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::timer::Timestamp;
+use crate::{connection::Owner, operation as op, scenario, Trace};
+use core::time::Duration;
+use std::collections::HashMap;
+
+/// The interval at which throughput samples are reported
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tracks the statistics reported by [`Trace::on_stream_complete`] and
+/// [`Trace::on_throughput_sample`]
+#[derive(Debug)]
+pub(crate) struct Stats {
+    conn_id: u64,
+    streams: HashMap<(Owner, u64), Stream>,
+    sample_start: Option<Timestamp>,
+    sample_bytes: u64,
+}
+
+#[derive(Debug, Default)]
+struct Stream {
+    /// The number of finish operations left before the stream is complete
+    remaining: usize,
+    start: Option<Timestamp>,
+    bytes: u64,
+}
+
+impl Stats {
+    pub fn new(conn_id: u64, scenario: &scenario::Connection) -> Self {
+        let mut stats = Self {
+            conn_id,
+            streams: HashMap::new(),
+            sample_start: None,
+            sample_bytes: 0,
+        };
+
+        stats.count_finishes(Owner::Local, &scenario.ops);
+        for ops in scenario.peer_streams.iter() {
+            stats.count_finishes(Owner::Remote, ops);
+        }
+
+        stats
+    }
+
+    /// Records each of the finish operations in the scenario
+    ///
+    /// A stream is considered complete once all of the directions that this endpoint uses are
+    /// finished, which is one for unidirectional streams and two for bidirectional streams.
+    fn count_finishes(&mut self, owner: Owner, ops: &[op::Connection]) {
+        use op::Connection::*;
+        for op in ops {
+            match op {
+                SendFinish { stream_id } | ReceiveFinish { stream_id } => {
+                    self.streams
+                        .entry((owner, *stream_id))
+                        .or_default()
+                        .remaining += 1;
+                }
+                Profile { operations, .. } | Iterate { operations, .. } => {
+                    self.count_finishes(owner, operations);
+                }
+                Scope { threads } => {
+                    for thread in threads {
+                        self.count_finishes(owner, thread);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[inline]
+    pub fn on_open(&mut self, owner: Owner, id: u64, now: Timestamp) {
+        if let Some(stream) = self.streams.get_mut(&(owner, id)) {
+            stream.start.get_or_insert(now);
+        }
+    }
+
+    #[inline]
+    pub fn on_transfer(&mut self, owner: Owner, id: u64, len: u64, now: Timestamp) {
+        self.sample_bytes += len;
+
+        if let Some(stream) = self.streams.get_mut(&(owner, id)) {
+            stream.start.get_or_insert(now);
+            stream.bytes += len;
+        }
+    }
+
+    #[inline]
+    pub fn on_datagram(&mut self, len: u64) {
+        self.sample_bytes += len;
+    }
+
+    #[inline]
+    pub fn on_finish<T: Trace>(&mut self, owner: Owner, id: u64, now: Timestamp, trace: &mut T) {
+        let key = (owner, id);
+
+        let is_complete = if let Some(stream) = self.streams.get_mut(&key) {
+            stream.remaining = stream.remaining.saturating_sub(1);
+            stream.remaining == 0
+        } else {
+            false
+        };
+
+        if is_complete {
+            let stream = self.streams.remove(&key).unwrap();
+            let duration = now.saturating_duration_since(stream.start.unwrap_or(now));
+            trace.on_stream_complete(self.conn_id, stream.bytes, duration);
+        }
+    }
+
+    /// Reports the connection throughput if the sample interval has elapsed
+    #[inline]
+    pub fn sample<T: Trace>(&mut self, now: Timestamp, trace: &mut T) {
+        let start = *self.sample_start.get_or_insert(now);
+
+        if now.saturating_duration_since(start) >= SAMPLE_INTERVAL {
+            self.report(now, trace);
+        }
+    }
+
+    /// Reports the throughput for the remaining partial sample interval
+    #[inline]
+    pub fn flush<T: Trace>(&mut self, now: Timestamp, trace: &mut T) {
+        self.report(now, trace);
+    }
+
+    fn report<T: Trace>(&mut self, now: Timestamp, trace: &mut T) {
+        let start = if let Some(start) = self.sample_start {
+            start
+        } else {
+            return;
+        };
+
+        let elapsed = now.saturating_duration_since(start);
+
+        // a rate can't be computed without any time passing
+        if elapsed.is_zero() {
+            return;
+        }
+
+        let bytes_per_sec = self.sample_bytes as u128 * 1_000_000_000 / elapsed.as_nanos();
+        trace.on_throughput_sample(self.conn_id, bytes_per_sec as u64);
+
+        self.sample_start = Some(now);
+        self.sample_bytes = 0;
+    }
+}
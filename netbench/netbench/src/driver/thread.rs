@@ -1,7 +1,11 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::timer::{self, Timer, Timestamp};
+use super::{
+    limit::Limit,
+    stats::Stats,
+    timer::{self, Timer, Timestamp},
+};
 use crate::{
     connection::Owner,
     operation as op,
@@ -32,6 +36,7 @@ impl<'a> Thread<'a> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(crate) fn poll<C: Connection, T: Trace, Ch: Checkpoints>(
         &mut self,
@@ -39,6 +44,8 @@ impl<'a> Thread<'a> {
         trace: &mut T,
         checkpoints: &mut Ch,
         rates: &mut Rates,
+        limit: &mut Limit,
+        stats: &mut Stats,
         now: Timestamp,
         cx: &mut Context,
     ) -> Poll<Result<()>> {
@@ -53,7 +60,7 @@ impl<'a> Thread<'a> {
                 }
             }
 
-            ready!(self.poll_op(conn, trace, checkpoints, rates, now, cx))?;
+            ready!(self.poll_op(conn, trace, checkpoints, rates, limit, stats, now, cx))?;
             self.op = None;
         }
     }
@@ -119,6 +126,12 @@ impl<'a> Thread<'a> {
             ReceiveRate { stream_id, rate } => {
                 rates.receive.insert(*stream_id, *rate);
             }
+            SendDatagram { bytes } => {
+                self.op = Some(Op::SendDatagram { len: *bytes });
+            }
+            ReceiveDatagram {} => {
+                self.op = Some(Op::ReceiveDatagram);
+            }
             Trace { trace_id } => {
                 trace.trace(now, *trace_id);
             }
@@ -168,6 +181,14 @@ impl<'a> Thread<'a> {
                 // notify the checkpoint that it can make progress
                 checkpoints.unpark(*checkpoint, cx);
             }
+            Barrier { checkpoint } => {
+                trace.park(now, *checkpoint);
+                let generation = checkpoints.arrive(*checkpoint);
+                self.op = Some(Op::Barrier {
+                    checkpoint: *checkpoint,
+                    generation,
+                });
+            }
             Scope { threads } => {
                 if !threads.is_empty() {
                     let threads = threads
@@ -180,6 +201,7 @@ impl<'a> Thread<'a> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn poll_op<C: Connection, T: Trace, Ch: Checkpoints>(
         &mut self,
@@ -187,6 +209,8 @@ impl<'a> Thread<'a> {
         trace: &mut T,
         checkpoints: &mut Ch,
         rates: &mut Rates,
+        limit: &mut Limit,
+        stats: &mut Stats,
         now: Timestamp,
         cx: &mut Context,
     ) -> Poll<Result<()>> {
@@ -198,10 +222,12 @@ impl<'a> Thread<'a> {
             Op::OpenBidirectionalStream { id } => {
                 ready!(conn.poll_open_bidirectional_stream(*id, cx))?;
                 trace.open(now, *id);
+                stats.on_open(owner, *id, now);
             }
             Op::OpenSendStream { id } => {
                 ready!(conn.poll_open_send_stream(*id, cx))?;
                 trace.open(now, *id);
+                stats.on_open(owner, *id, now);
             }
             Op::Send {
                 id,
@@ -209,14 +235,18 @@ impl<'a> Thread<'a> {
                 rate,
             } => {
                 return self.timer.transfer(remaining, rate, now, cx, |bytes, cx| {
+                    let bytes = ready!(limit.send.poll_acquire(bytes, now));
                     let amount = ready!(conn.poll_send(owner, *id, *bytes, cx))?;
+                    limit.send.release(amount);
                     trace.send(now, *id, amount);
+                    stats.on_transfer(owner, *id, amount, now);
                     Ok(amount).into()
                 })
             }
             Op::SendFinish { id } => {
                 ready!(conn.poll_send_finish(owner, *id, cx))?;
                 trace.send_finish(now, *id);
+                stats.on_finish(owner, *id, now, trace);
             }
             Op::Receive {
                 id,
@@ -224,8 +254,11 @@ impl<'a> Thread<'a> {
                 rate,
             } => {
                 return self.timer.transfer(remaining, rate, now, cx, |bytes, cx| {
+                    let bytes = ready!(limit.receive.poll_acquire(bytes, now));
                     let amount = ready!(conn.poll_receive(owner, *id, *bytes, cx))?;
+                    limit.receive.release(amount);
                     trace.receive(now, *id, amount);
+                    stats.on_transfer(owner, *id, amount, now);
                     Ok(amount).into()
                 })
             }
@@ -234,26 +267,47 @@ impl<'a> Thread<'a> {
                 return self
                     .timer
                     .transfer(&mut remaining, rate, now, cx, |bytes, cx| {
+                        let bytes = ready!(limit.receive.poll_acquire(bytes, now));
                         let amount = ready!(conn.poll_receive(owner, *id, *bytes, cx))?;
+                        limit.receive.release(amount);
                         trace.receive(now, *id, amount);
+                        stats.on_transfer(owner, *id, amount, now);
                         Ok(amount).into()
                     });
             }
             Op::ReceiveFinish { id } => {
                 ready!(conn.poll_receive_finish(owner, *id, cx))?;
                 trace.receive_finish(now, *id);
+                stats.on_finish(owner, *id, now, trace);
+            }
+            Op::SendDatagram { len } => {
+                ready!(conn.poll_send_datagram(**len, cx))?;
+                trace.send_datagram(now, **len);
+                stats.on_datagram(**len);
+            }
+            Op::ReceiveDatagram => {
+                let len = ready!(conn.poll_receive_datagram(cx))?;
+                trace.receive_datagram(now, len);
+                stats.on_datagram(len);
             }
             Op::Wait { checkpoint } => {
                 ready!(checkpoints.park(*checkpoint));
                 trace.unpark(now, *checkpoint);
             }
+            Op::Barrier {
+                checkpoint,
+                generation,
+            } => {
+                ready!(checkpoints.poll_release(*checkpoint, *generation, cx));
+                trace.unpark(now, *checkpoint);
+            }
             Op::Iterate {
                 start,
                 count,
                 thread,
                 trace_id,
             } => {
-                ready!(thread.poll(conn, trace, checkpoints, rates, now, cx))?;
+                ready!(thread.poll(conn, trace, checkpoints, rates, limit, stats, now, cx))?;
 
                 if let Some(trace_id) = trace_id {
                     let time = now.saturating_duration_since(*start);
@@ -274,7 +328,9 @@ impl<'a> Thread<'a> {
                 thread,
                 trace_id,
             } => {
-                if let Poll::Ready(res) = thread.poll(conn, trace, checkpoints, rates, now, cx) {
+                if let Poll::Ready(res) =
+                    thread.poll(conn, trace, checkpoints, rates, limit, stats, now, cx)
+                {
                     res?;
                     thread.reset(cx);
 
@@ -292,7 +348,7 @@ impl<'a> Thread<'a> {
                 start,
                 thread,
             } => {
-                ready!(thread.poll(conn, trace, checkpoints, rates, now, cx))?;
+                ready!(thread.poll(conn, trace, checkpoints, rates, limit, stats, now, cx))?;
                 let time = now.saturating_duration_since(*start);
                 trace.profile(now, *trace_id, time);
             }
@@ -301,7 +357,8 @@ impl<'a> Thread<'a> {
                 let op_idx = self.index;
                 for (idx, thread) in threads.iter_mut().enumerate() {
                     trace.enter(now, op_idx as _, idx);
-                    let result = thread.poll(conn, trace, checkpoints, rates, now, cx);
+                    let result =
+                        thread.poll(conn, trace, checkpoints, rates, limit, stats, now, cx);
                     trace.exit(now);
                     match result {
                         Poll::Ready(Ok(_)) => {}
@@ -369,9 +426,17 @@ enum Op<'a> {
     ReceiveFinish {
         id: u64,
     },
+    SendDatagram {
+        len: Byte,
+    },
+    ReceiveDatagram,
     Wait {
         checkpoint: u64,
     },
+    Barrier {
+        checkpoint: u64,
+        generation: u64,
+    },
     Iterate {
         start: Timestamp,
         count: u64,
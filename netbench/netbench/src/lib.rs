@@ -23,7 +23,7 @@ pub mod timer;
 pub mod trace;
 pub mod units;
 
-pub use checkpoints::Checkpoints;
+pub use checkpoints::{Barrier, Checkpoints};
 pub use client::Driver as Client;
 pub use connection::Connection;
 pub use driver::Driver;
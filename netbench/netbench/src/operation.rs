@@ -38,10 +38,16 @@ pub enum Connection {
         #[serde(flatten)]
         rate: Rate,
     },
+    /// Send a single unreliable datagram of the specified size
+    SendDatagram { bytes: Byte },
+    /// Receive a single unreliable datagram from the peer
+    ReceiveDatagram {},
     /// Parks the current thread and waits for the checkpoint to be unparked
     Park { checkpoint: u64 },
     /// Notifies the parked checkpoint that it can continue
     Unpark { checkpoint: u64 },
+    /// Waits for all of the connections sharing the barrier to reach the checkpoint
+    Barrier { checkpoint: u64 },
     /// Emit a trace event
     Trace { trace_id: u64 },
     /// Profiles the time it takes to perform the contained operations
@@ -11,6 +11,7 @@ use core::{
 use futures::ready;
 use s2n_quic::{
     connection,
+    provider::datagram::default::{DatagramError, Receiver, Sender},
     stream::{LocalStream, PeerStream, SplittableStream},
 };
 use s2n_quic_core::stream::testing::Data;
@@ -43,6 +44,22 @@ fn conn_error(err: s2n_quic::connection::Error) -> Result<()> {
     Err(err.into())
 }
 
+fn datagram_error(err: DatagramError) -> Result<()> {
+    if let DatagramError::ConnectionError { error, .. } = err {
+        return conn_error(error);
+    }
+
+    Err(err.to_string().into())
+}
+
+fn datagram_query_error(err: s2n_quic_core::query::Error) -> crate::Error {
+    if let s2n_quic_core::query::Error::ContextTypeMismatch = err {
+        return "datagrams require the default s2n-quic datagram provider".into();
+    }
+
+    err.into()
+}
+
 impl<'a> crate::client::Client<'a> for s2n_quic::Client {
     type Connect = Connect<'a>;
     type Connection = crate::Driver<'a, Connection>;
@@ -81,6 +98,7 @@ pub struct Connection {
     streams: [HashMap<u64, Stream>; 2],
     opened_streams: HashMap<u64, (Bytes, LocalStream)>,
     unidentified_peer_stream: Option<(IdPrefixReader, PeerStream)>,
+    datagram_payload: Bytes,
 }
 
 impl From<s2n_quic::Connection> for Connection {
@@ -96,6 +114,7 @@ impl Connection {
             streams: [HashMap::new(), HashMap::new()],
             opened_streams: HashMap::new(),
             unidentified_peer_stream: Default::default(),
+            datagram_payload: Bytes::new(),
         }
     }
 
@@ -103,6 +122,16 @@ impl Connection {
         self.conn
     }
 
+    /// Returns a payload of `len` bytes to send in a datagram
+    ///
+    /// The payload is shared between datagrams to avoid allocating on every send.
+    fn datagram_payload(&mut self, len: usize) -> Bytes {
+        if self.datagram_payload.len() < len {
+            self.datagram_payload = Bytes::from(vec![0; len]);
+        }
+        self.datagram_payload.slice(..len)
+    }
+
     fn open_local_stream<
         F: FnOnce(&mut s2n_quic::Connection, &mut Context) -> Poll<Result<S, connection::Error>>,
         S: Into<LocalStream>,
@@ -254,6 +283,33 @@ impl super::Connection for Connection {
 
         Poll::Ready(Ok(()))
     }
+
+    fn poll_send_datagram(&mut self, bytes: u64, cx: &mut Context) -> Poll<Result<()>> {
+        let mut payload = self.datagram_payload(bytes as usize);
+
+        let res = self
+            .conn
+            .datagram_mut(|sender: &mut Sender| sender.poll_send_datagram(&mut payload, cx))
+            .map_err(datagram_query_error)?;
+
+        match ready!(res) {
+            Ok(()) => Ok(()).into(),
+            Err(err) => datagram_error(err).into(),
+        }
+    }
+
+    fn poll_receive_datagram(&mut self, cx: &mut Context) -> Poll<Result<u64>> {
+        let res = self
+            .conn
+            .datagram_mut(|receiver: &mut Receiver| receiver.poll_recv_datagram(cx))
+            .map_err(datagram_query_error)?;
+
+        match ready!(res) {
+            Ok(datagram) => Ok(datagram.len() as u64).into(),
+            // the connection was closed by the application so no more datagrams will arrive
+            Err(err) => datagram_error(err).map(|_| 0).into(),
+        }
+    }
 }
 
 macro_rules! chunks {
@@ -416,3 +472,81 @@ impl SendStream {
         Poll::Ready(Ok(len))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{scenario::Scenario, timer, trace::MemoryLogger, units::*, Driver};
+    use s2n_quic::provider::datagram;
+    use s2n_quic_core::crypto::tls::testing::certificates::{CERT_PEM, KEY_PEM};
+    use std::collections::HashSet;
+
+    #[tokio::test]
+    async fn datagram_scenario() -> Result<()> {
+        const COUNT: usize = 10;
+
+        let scenario = Scenario::build(|scenario| {
+            let server = scenario.create_server();
+
+            scenario.create_client(|client| {
+                let server = server.with(|conn| {
+                    conn.iterate(COUNT as u64, |conn| {
+                        conn.recv_datagram();
+                    });
+                });
+
+                client.connect_to(server, |conn| {
+                    conn.iterate(COUNT as u64, |conn| {
+                        conn.send_datagram(100.bytes());
+                    });
+                });
+            });
+        });
+
+        let mut server = s2n_quic::Server::builder()
+            .with_tls((CERT_PEM, KEY_PEM))?
+            .with_io("127.0.0.1:0")?
+            .with_datagram(datagram::default::Endpoint::default())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let client = s2n_quic::Client::builder()
+            .with_tls(CERT_PEM)?
+            .with_io("0.0.0.0:0")?
+            .with_datagram(datagram::default::Endpoint::default())?
+            .start()?;
+
+        let server_task = async {
+            let conn = server.accept().await.ok_or("server closed")?;
+            let conn = Driver::new(&scenario.servers[0].connections[0], Connection::new(conn));
+            let mut trace = MemoryLogger::new(scenario.traces.clone());
+            let mut checkpoints = HashSet::new();
+            let mut timer = timer::Tokio::default();
+            // keep the connection open until both drivers have finished
+            let conn = conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
+            Result::<_>::Ok((conn, trace))
+        };
+
+        let client_task = async {
+            let connect = s2n_quic::client::Connect::new(addr).with_server_name("localhost");
+            let conn = client.connect(connect).await?;
+            let conn = Driver::new(&scenario.clients[0].connections[0], Connection::new(conn));
+            let mut trace = MemoryLogger::new(scenario.traces.clone());
+            let mut checkpoints = HashSet::new();
+            let mut timer = timer::Tokio::default();
+            let conn = conn.run(&mut trace, &mut checkpoints, &mut timer).await?;
+            Result::<_>::Ok((conn, trace))
+        };
+
+        let (server, client) = tokio::join!(server_task, client_task);
+        let (_server_conn, server_trace) = server?;
+        let (_client_conn, client_trace) = client?;
+
+        let sent = client_trace.as_str().unwrap().matches("dsnd=100").count();
+        let received = server_trace.as_str().unwrap().matches("drcv=100").count();
+        assert_eq!(sent, COUNT);
+        assert_eq!(received, COUNT);
+
+        Ok(())
+    }
+}
@@ -68,7 +68,19 @@ pub struct Server {
     pub certificate_authority: u64,
 }
 
+impl Client {
+    /// Returns `true` if any of the client's connections send or receive datagrams
+    pub fn uses_datagrams(&self) -> bool {
+        self.connections.iter().any(|conn| conn.uses_datagrams())
+    }
+}
+
 impl Server {
+    /// Returns `true` if any of the server's connections send or receive datagrams
+    pub fn uses_datagrams(&self) -> bool {
+        self.connections.iter().any(|conn| conn.uses_datagrams())
+    }
+
     pub fn on_server_name(&self, server_name: &str) -> Result<&Arc<Connection>> {
         let (conn_idx, _) = server_name.split_once('.').ok_or("invalid hostname")?;
         let conn_idx: usize = conn_idx.parse()?;
@@ -85,6 +97,27 @@ pub struct Connection {
     pub peer_streams: Vec<Vec<op::Connection>>,
 }
 
+impl Connection {
+    /// Returns `true` if any of the connection's operations send or receive datagrams
+    pub fn uses_datagrams(&self) -> bool {
+        fn uses_datagrams(ops: &[op::Connection]) -> bool {
+            ops.iter().any(|op| match op {
+                op::Connection::SendDatagram { .. } | op::Connection::ReceiveDatagram { .. } => {
+                    true
+                }
+                op::Connection::Profile { operations, .. }
+                | op::Connection::Iterate { operations, .. } => uses_datagrams(operations),
+                op::Connection::Scope { threads } => {
+                    threads.iter().any(|thread| uses_datagrams(thread))
+                }
+                _ => false,
+            })
+        }
+
+        uses_datagrams(&self.ops) || self.peer_streams.iter().any(|ops| uses_datagrams(ops))
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Hash)]
 pub struct Router {
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -122,6 +122,11 @@ impl Builder {
         self.state.create_ca_with(f)
     }
 
+    pub fn create_barrier(&mut self) -> checkpoint::Barrier {
+        let id = self.state.checkpoint.next_id();
+        checkpoint::Barrier { id }
+    }
+
     pub fn create_server(&mut self) -> Server {
         self.create_server_with(|_| {})
     }
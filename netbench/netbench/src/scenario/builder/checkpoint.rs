@@ -22,6 +22,15 @@ impl<Endpoint, Location, Op> Checkpoint<Endpoint, Location, Op> {
     }
 }
 
+/// A checkpoint shared by multiple connections
+///
+/// Each connection that reaches the barrier waits until all of the parties configured in the
+/// driver have arrived.
+#[derive(Clone, Copy, Debug)]
+pub struct Barrier {
+    pub(crate) id: u64,
+}
+
 #[derive(Debug)]
 pub struct Park;
 
@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{
-    checkpoint::{Checkpoint, Park, Unpark},
+    checkpoint::{Barrier, Checkpoint, Park, Unpark},
     state::{IdPool, RefVec},
     stream::{ReceiveStream, SendStream, Stream},
     Client, Endpoint, Local, Remote, Scope, Server,
 };
-use crate::operation as op;
+use crate::{operation as op, units::Byte};
 use core::marker::PhantomData;
 use std::sync::Arc;
 
@@ -65,6 +65,13 @@ impl<E: Endpoint> Builder<E> {
     }
 
     sync!(E, Local);
+
+    pub fn barrier(&mut self, barrier: Barrier) -> &mut Self {
+        self.ops.push(op::Connection::Barrier {
+            checkpoint: barrier.id,
+        });
+        self
+    }
     sleep!();
     trace!();
     iterate!();
@@ -148,6 +155,21 @@ impl<E: Endpoint> Builder<E> {
         self
     }
 
+    /// Sends a single unreliable datagram of the specified size to the peer
+    pub fn send_datagram(&mut self, bytes: Byte) -> &mut Self {
+        self.ops.push(op::Connection::SendDatagram { bytes });
+        self
+    }
+
+    /// Waits for a single unreliable datagram from the peer
+    ///
+    /// Datagrams can be lost so this should be paired with a time-bounded `iterate` if the
+    /// scenario needs to tolerate loss.
+    pub fn recv_datagram(&mut self) -> &mut Self {
+        self.ops.push(op::Connection::ReceiveDatagram {});
+        self
+    }
+
     pub(crate) fn finish(self) -> crate::scenario::Connection {
         let peer_streams = self.state.peer_streams.take();
         let ops = self.ops;
@@ -8,8 +8,9 @@ use std::sync::{
     Arc,
 };
 
+mod prometheus;
 mod usdt;
-pub use self::usdt::Usdt;
+pub use self::{prometheus::Prometheus, usdt::Usdt};
 
 pub trait Trace {
     #[inline(always)]
@@ -67,6 +68,18 @@ pub trait Trace {
         let _ = stream_id;
     }
 
+    #[inline(always)]
+    fn send_datagram(&mut self, now: Timestamp, len: u64) {
+        let _ = now;
+        let _ = len;
+    }
+
+    #[inline(always)]
+    fn receive_datagram(&mut self, now: Timestamp, len: u64) {
+        let _ = now;
+        let _ = len;
+    }
+
     #[inline(always)]
     fn accept(&mut self, now: Timestamp, stream_id: u64) {
         let _ = now;
@@ -110,6 +123,24 @@ pub trait Trace {
         let _ = connection_id;
         let _ = time;
     }
+
+    /// Called once all of the directions of a stream used by the connection have finished
+    ///
+    /// `bytes` is the total amount of data sent and received on the stream, and `duration` is
+    /// the time from when the stream was opened or accepted until it completed.
+    #[inline(always)]
+    fn on_stream_complete(&mut self, conn_id: u64, bytes: u64, duration: Duration) {
+        let _ = conn_id;
+        let _ = bytes;
+        let _ = duration;
+    }
+
+    /// Called periodically with the combined send and receive throughput of a connection
+    #[inline(always)]
+    fn on_throughput_sample(&mut self, conn_id: u64, bytes_per_sec: u64) {
+        let _ = conn_id;
+        let _ = bytes_per_sec;
+    }
 }
 
 impl<A: Trace, B: Trace> Trace for (A, B) {
@@ -167,6 +198,18 @@ impl<A: Trace, B: Trace> Trace for (A, B) {
         self.1.receive_finish(now, stream_id);
     }
 
+    #[inline(always)]
+    fn send_datagram(&mut self, now: Timestamp, len: u64) {
+        self.0.send_datagram(now, len);
+        self.1.send_datagram(now, len);
+    }
+
+    #[inline(always)]
+    fn receive_datagram(&mut self, now: Timestamp, len: u64) {
+        self.0.receive_datagram(now, len);
+        self.1.receive_datagram(now, len);
+    }
+
     #[inline(always)]
     fn accept(&mut self, now: Timestamp, stream_id: u64) {
         self.0.accept(now, stream_id);
@@ -208,6 +251,18 @@ impl<A: Trace, B: Trace> Trace for (A, B) {
         self.0.connect(now, connection_id, time);
         self.1.connect(now, connection_id, time);
     }
+
+    #[inline(always)]
+    fn on_stream_complete(&mut self, conn_id: u64, bytes: u64, duration: Duration) {
+        self.0.on_stream_complete(conn_id, bytes, duration);
+        self.1.on_stream_complete(conn_id, bytes, duration);
+    }
+
+    #[inline(always)]
+    fn on_throughput_sample(&mut self, conn_id: u64, bytes_per_sec: u64) {
+        self.0.on_throughput_sample(conn_id, bytes_per_sec);
+        self.1.on_throughput_sample(conn_id, bytes_per_sec);
+    }
 }
 
 impl<T: Trace> Trace for Option<T> {
@@ -274,6 +329,20 @@ impl<T: Trace> Trace for Option<T> {
         }
     }
 
+    #[inline(always)]
+    fn send_datagram(&mut self, now: Timestamp, len: u64) {
+        if let Some(t) = self.as_mut() {
+            t.send_datagram(now, len);
+        }
+    }
+
+    #[inline(always)]
+    fn receive_datagram(&mut self, now: Timestamp, len: u64) {
+        if let Some(t) = self.as_mut() {
+            t.receive_datagram(now, len);
+        }
+    }
+
     #[inline(always)]
     fn accept(&mut self, now: Timestamp, stream_id: u64) {
         if let Some(t) = self.as_mut() {
@@ -322,6 +391,20 @@ impl<T: Trace> Trace for Option<T> {
             t.connect(now, connection_id, time);
         }
     }
+
+    #[inline]
+    fn on_stream_complete(&mut self, conn_id: u64, bytes: u64, duration: Duration) {
+        if let Some(t) = self.as_mut() {
+            t.on_stream_complete(conn_id, bytes, duration);
+        }
+    }
+
+    #[inline]
+    fn on_throughput_sample(&mut self, conn_id: u64, bytes_per_sec: u64) {
+        if let Some(t) = self.as_mut() {
+            t.on_throughput_sample(conn_id, bytes_per_sec);
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -499,6 +582,16 @@ impl<O: Output> Trace for Logger<O> {
         self.log(now, format_args!("rfin[{stream_id}]"));
     }
 
+    #[inline(always)]
+    fn send_datagram(&mut self, now: Timestamp, len: u64) {
+        self.log(now, format_args!("dsnd={len}"));
+    }
+
+    #[inline(always)]
+    fn receive_datagram(&mut self, now: Timestamp, len: u64) {
+        self.log(now, format_args!("drcv={len}"));
+    }
+
     #[inline(always)]
     fn accept(&mut self, now: Timestamp, stream_id: u64) {
         self.log(now, format_args!("acpt[{stream_id}]"));
@@ -603,6 +696,14 @@ impl Trace for Throughput {
     fn receive(&mut self, _now: Timestamp, _stream_id: u64, len: u64) {
         self.0.results.rx.fetch_add(len, Ordering::Relaxed);
     }
+
+    fn send_datagram(&mut self, _now: Timestamp, len: u64) {
+        self.0.results.tx.fetch_add(len, Ordering::Relaxed);
+    }
+
+    fn receive_datagram(&mut self, _now: Timestamp, len: u64) {
+        self.0.results.rx.fetch_add(len, Ordering::Relaxed);
+    }
 }
 
 impl Drop for ThroughputInner {
@@ -0,0 +1,173 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Trace;
+use crate::units::*;
+use core::fmt;
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+const DURATION_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 60.0];
+const BYTE_BUCKETS: &[f64] = &[1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9];
+const RATE_BUCKETS: &[f64] = &[1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+/// Aggregates stream and throughput statistics as Prometheus histograms
+///
+/// The metrics are shared by all of the connections in the process and rendered in the text
+/// exposition format with the `Display` implementation.
+#[derive(Clone, Debug, Default)]
+pub struct Prometheus(Arc<Mutex<Metrics>>);
+
+impl Prometheus {
+    /// Periodically writes the metrics to `path`
+    ///
+    /// The file is replaced atomically so it can be scraped by the node exporter's textfile
+    /// collector while the driver is running.
+    pub fn reporter(&self, path: PathBuf, freq: Duration) {
+        let handle = self.clone();
+        tokio::spawn(async move {
+            let tmp = path.with_extension("tmp");
+            loop {
+                tokio::time::sleep(freq).await;
+                let contents = handle.to_string();
+                if let Err(err) =
+                    std::fs::write(&tmp, contents).and_then(|_| std::fs::rename(&tmp, &path))
+                {
+                    eprintln!("could not write prometheus metrics: {err}");
+                }
+            }
+        });
+    }
+}
+
+impl Trace for Prometheus {
+    fn on_stream_complete(&mut self, _conn_id: u64, bytes: u64, duration: Duration) {
+        let mut metrics = self.0.lock().unwrap();
+        metrics.stream_duration.observe(duration.as_secs_f64());
+        metrics.stream_bytes.observe(bytes as f64);
+    }
+
+    fn on_throughput_sample(&mut self, _conn_id: u64, bytes_per_sec: u64) {
+        let mut metrics = self.0.lock().unwrap();
+        metrics.throughput.observe(bytes_per_sec as f64);
+    }
+}
+
+impl fmt::Display for Prometheus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let metrics = self.0.lock().unwrap();
+        metrics.stream_duration.encode(
+            f,
+            "netbench_stream_duration_seconds",
+            "Time from opening a stream until all of its data is transferred",
+        )?;
+        metrics.stream_bytes.encode(
+            f,
+            "netbench_stream_bytes",
+            "Amount of data sent and received on each stream",
+        )?;
+        metrics.throughput.encode(
+            f,
+            "netbench_connection_throughput_bytes_per_second",
+            "Sampled throughput of each connection",
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Metrics {
+    stream_duration: Histogram,
+    stream_bytes: Histogram,
+    throughput: Histogram,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            stream_duration: Histogram::new(DURATION_BUCKETS),
+            stream_bytes: Histogram::new(BYTE_BUCKETS),
+            throughput: Histogram::new(RATE_BUCKETS),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Histogram {
+    bounds: &'static [f64],
+    /// The cumulative count for each of the bounds
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            buckets: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for (bound, bucket) in self.bounds.iter().zip(self.buckets.iter_mut()) {
+            if value <= *bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn encode(&self, f: &mut fmt::Formatter, name: &str, help: &str) -> fmt::Result {
+        writeln!(f, "# HELP {name} {help}")?;
+        writeln!(f, "# TYPE {name} histogram")?;
+        for (bound, bucket) in self.bounds.iter().zip(self.buckets.iter()) {
+            writeln!(f, "{name}_bucket{{le=\"{bound}\"}} {bucket}")?;
+        }
+        writeln!(f, "{name}_bucket{{le=\"+Inf\"}} {}", self.count)?;
+        writeln!(f, "{name}_sum {}", self.sum)?;
+        writeln!(f, "{name}_count {}", self.count)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exposition_test() {
+        let mut trace = Prometheus::default();
+        trace.on_stream_complete(0, 5_000, Duration::from_millis(20));
+        trace.on_stream_complete(1, 50_000, Duration::from_secs(2));
+        trace.on_throughput_sample(0, 2_000_000);
+
+        let output = trace.to_string();
+        let lines: Vec<_> = output.lines().collect();
+
+        for expected in [
+            "# TYPE netbench_stream_duration_seconds histogram",
+            "netbench_stream_duration_seconds_bucket{le=\"0.01\"} 0",
+            "netbench_stream_duration_seconds_bucket{le=\"0.05\"} 1",
+            "netbench_stream_duration_seconds_bucket{le=\"5\"} 2",
+            "netbench_stream_duration_seconds_bucket{le=\"+Inf\"} 2",
+            "netbench_stream_duration_seconds_count 2",
+            "netbench_stream_bytes_bucket{le=\"10000\"} 1",
+            "netbench_stream_bytes_bucket{le=\"100000\"} 2",
+            "netbench_stream_bytes_sum 55000",
+            "netbench_connection_throughput_bytes_per_second_bucket{le=\"1000000\"} 0",
+            "netbench_connection_throughput_bytes_per_second_bucket{le=\"10000000\"} 1",
+            "netbench_connection_throughput_bytes_per_second_count 1",
+        ] {
+            assert!(
+                lines.contains(&expected),
+                "missing {expected:?} in\n{output}"
+            );
+        }
+    }
+}
@@ -55,6 +55,21 @@ impl Trace for Usdt {
         );
     }
 
+    #[inline(never)]
+    fn send_datagram(&mut self, _now: Timestamp, len: u64) {
+        probe!(netbench, netbench__send__datagram, self.connection_id, len);
+    }
+
+    #[inline(never)]
+    fn receive_datagram(&mut self, _now: Timestamp, len: u64) {
+        probe!(
+            netbench,
+            netbench__receive__datagram,
+            self.connection_id,
+            len
+        );
+    }
+
     #[inline(never)]
     fn accept(&mut self, _now: Timestamp, stream_id: u64) {
         probe!(netbench, netbench__accept, self.connection_id, stream_id);
@@ -91,4 +106,20 @@ impl Trace for Usdt {
         let time = time.as_micros() as u64;
         probe!(netbench, netbench__connect, self.connection_id, id, time);
     }
+
+    #[inline(never)]
+    fn on_stream_complete(&mut self, conn_id: u64, bytes: u64, duration: Duration) {
+        let time = duration.as_micros() as u64;
+        probe!(netbench, netbench__stream__complete, conn_id, bytes, time);
+    }
+
+    #[inline(never)]
+    fn on_throughput_sample(&mut self, conn_id: u64, bytes_per_sec: u64) {
+        probe!(
+            netbench,
+            netbench__throughput__sample,
+            conn_id,
+            bytes_per_sec
+        );
+    }
 }
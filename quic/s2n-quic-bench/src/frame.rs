@@ -33,10 +33,12 @@ fn codec(c: &mut Criterion) {
 
     let mut inputs = [
         input!("ack"),
+        input!("ack_frequency"),
         input!("connection_close"),
         input!("crypto"),
         input!("data_blocked"),
         input!("handshake_done"),
+        input!("immediate_ack"),
         input!("max_data"),
         input!("max_stream_data"),
         input!("max_streams"),
@@ -981,3 +981,26 @@ fn allocation_size_test() {
         }
     }
 }
+
+#[test]
+fn pop_zero_copy_test() {
+    let mut buffer = ReceiveBuffer::new();
+    let chunk_len = 64;
+    let mut offset = 0u32;
+    let mut expected_ptr = None;
+
+    // the first allocation fits all of the chunks so popping shouldn't allocate or copy
+    for _ in 0..(MIN_BUFFER_ALLOCATION_SIZE / chunk_len) {
+        buffer.write_at(offset.into(), &BYTES[..chunk_len]).unwrap();
+        offset += chunk_len as u32;
+
+        let chunk = buffer.pop().unwrap().freeze();
+        assert_eq!(chunk.len(), chunk_len);
+
+        // each chunk should be a view directly after the previous one in the initial buffer
+        if let Some(expected_ptr) = expected_ptr {
+            assert_eq!(chunk.as_ptr(), expected_ptr);
+        }
+        expected_ptr = Some(chunk.as_ptr().wrapping_add(chunk.len()));
+    }
+}
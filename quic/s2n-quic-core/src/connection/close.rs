@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{application, crypto, transport};
+#[cfg(feature = "alloc")]
+use crate::{connection, endpoint};
 pub use crate::{frame::ConnectionClose, inet::SocketAddress};
+#[cfg(feature = "alloc")]
+use bytes::Bytes;
 
 /// Provides a hook for applications to rewrite CONNECTION_CLOSE frames
 ///
@@ -158,3 +162,152 @@ impl Formatter for Production {
         transport::Error::APPLICATION_ERROR.into()
     }
 }
+
+/// The reason a connection was closed
+///
+/// The `Transport` and `Application` variants mirror the fields of the CONNECTION_CLOSE frame
+/// which closed the connection.
+#[cfg(feature = "alloc")]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// The connection was closed with a transport error
+    #[non_exhaustive]
+    Transport {
+        /// The transport error code
+        code: transport::error::Code,
+        /// The type of frame which triggered the error
+        frame_type: u64,
+        /// The reason phrase explaining why the connection was closed
+        reason: Bytes,
+        /// The endpoint which closed the connection
+        initiator: endpoint::Location,
+    },
+
+    /// The connection was closed with an application error code
+    #[non_exhaustive]
+    Application {
+        /// The error code supplied by the application
+        error: application::Error,
+        /// The reason phrase explaining why the connection was closed
+        reason: Bytes,
+        /// The endpoint which closed the connection
+        initiator: endpoint::Location,
+    },
+
+    /// The connection was closed because the local connection's idle timer expired
+    IdleTimerExpired,
+
+    /// The connection was reset by a stateless reset from the peer
+    StatelessReset,
+
+    /// The connection was closed for another reason, such as a local error or without an error
+    #[non_exhaustive]
+    Other { error: connection::Error },
+}
+
+#[cfg(feature = "alloc")]
+impl Reason {
+    /// Creates a close reason from the error which closed the connection
+    ///
+    /// `remote_reason` is the reason phrase of the CONNECTION_CLOSE frame received from the peer,
+    /// if any. It is only used for errors initiated by the peer.
+    pub fn new(error: connection::Error, remote_reason: Option<&Bytes>) -> Self {
+        let reason = |initiator: endpoint::Location, local_reason: &'static str| {
+            if initiator.is_remote() {
+                remote_reason.cloned().unwrap_or_default()
+            } else {
+                Bytes::from_static(local_reason.as_bytes())
+            }
+        };
+
+        match error {
+            connection::Error::Transport {
+                code,
+                frame_type,
+                reason: local_reason,
+                initiator,
+                ..
+            } => Self::Transport {
+                code,
+                frame_type,
+                reason: reason(initiator, local_reason),
+                initiator,
+            },
+            connection::Error::Application {
+                error, initiator, ..
+            } => Self::Application {
+                error,
+                reason: reason(initiator, ""),
+                initiator,
+            },
+            connection::Error::IdleTimerExpired { .. } => Self::IdleTimerExpired,
+            connection::Error::StatelessReset { .. } => Self::StatelessReset,
+            error => Self::Other { error },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::varint::VarInt;
+
+    #[test]
+    fn reason_test() {
+        let remote_reason = Bytes::from_static(b"remote reason");
+
+        let frame = ConnectionClose {
+            error_code: VarInt::from_u8(42),
+            frame_type: None,
+            reason: Some(&remote_reason),
+        };
+        assert_eq!(
+            Reason::new(frame.into(), Some(&remote_reason)),
+            Reason::Application {
+                error: application::Error::from(42u8),
+                reason: remote_reason.clone(),
+                initiator: endpoint::Location::Remote,
+            }
+        );
+
+        let frame = ConnectionClose {
+            error_code: transport::Error::PROTOCOL_VIOLATION.code.as_varint(),
+            frame_type: Some(VarInt::from_u8(1)),
+            reason: Some(&remote_reason),
+        };
+        assert_eq!(
+            Reason::new(frame.into(), Some(&remote_reason)),
+            Reason::Transport {
+                code: transport::Error::PROTOCOL_VIOLATION.code,
+                frame_type: 1,
+                reason: remote_reason.clone(),
+                initiator: endpoint::Location::Remote,
+            }
+        );
+
+        // locally-initiated errors use the local reason rather than the peer's
+        let error = transport::Error::PROTOCOL_VIOLATION.with_reason("local reason");
+        assert_eq!(
+            Reason::new(error.into(), Some(&remote_reason)),
+            Reason::Transport {
+                code: transport::Error::PROTOCOL_VIOLATION.code,
+                frame_type: 0,
+                reason: Bytes::from_static(b"local reason"),
+                initiator: endpoint::Location::Local,
+            }
+        );
+
+        assert_eq!(
+            Reason::new(connection::Error::idle_timer_expired(), None),
+            Reason::IdleTimerExpired
+        );
+        assert_eq!(
+            Reason::new(connection::Error::stateless_reset(), None),
+            Reason::StatelessReset
+        );
+
+        let error = connection::Error::closed(endpoint::Location::Local);
+        assert_eq!(Reason::new(error, None), Reason::Other { error });
+    }
+}
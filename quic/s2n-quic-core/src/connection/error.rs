@@ -87,6 +87,31 @@ pub enum Error {
         source: &'static panic::Location<'static>,
     },
 
+    /// The connection was closed because a graceful close did not finish flushing
+    /// outstanding stream data within the requested timeout
+    #[non_exhaustive]
+    GracefulCloseTimedOut {
+        timeout: Duration,
+        source: &'static panic::Location<'static>,
+    },
+
+    /// The connection was closed because it reached the configured max connection lifetime
+    ///
+    /// Outstanding stream data is flushed before the peer is notified.
+    #[non_exhaustive]
+    MaxLifetimeExceeded {
+        max_lifetime: Duration,
+        source: &'static panic::Location<'static>,
+    },
+
+    /// The connection was closed because the peer didn't acknowledge any ack-eliciting
+    /// packets within the configured dead peer timeout
+    #[non_exhaustive]
+    DeadPeerDetected {
+        timeout: Duration,
+        source: &'static panic::Location<'static>,
+    },
+
     /// The connection was closed due to an unspecified reason
     #[non_exhaustive]
     Unspecified {
@@ -149,6 +174,21 @@ impl fmt::Display for Error {
             Self::EndpointClosing { .. } => {
                 write!(f, "The connection attempt was rejected because the endpoint is closing")
             }
+            Self::GracefulCloseTimedOut { timeout, .. } => write!(
+                f,
+                "The connection was closed because outstanding data could not be flushed \
+                within the graceful close timeout of {timeout:?}"
+            ),
+            Self::MaxLifetimeExceeded { max_lifetime, .. } => write!(
+                f,
+                "The connection was closed because it reached the max connection lifetime \
+                of {max_lifetime:?}"
+            ),
+            Self::DeadPeerDetected { timeout, .. } => write!(
+                f,
+                "The connection was closed because the peer did not acknowledge any packets \
+                within the dead peer timeout of {timeout:?}"
+            ),
             Self::Unspecified { .. } => {
                 write!(f, "The connection was closed due to an unspecified reason")
             }
@@ -170,6 +210,9 @@ impl Error {
             Error::MaxHandshakeDurationExceeded { source, .. } => source,
             Error::ImmediateClose { source, .. } => source,
             Error::EndpointClosing { source } => source,
+            Error::GracefulCloseTimedOut { source, .. } => source,
+            Error::MaxLifetimeExceeded { source, .. } => source,
+            Error::DeadPeerDetected { source, .. } => source,
             Error::Unspecified { source } => source,
         }
     }
@@ -274,6 +317,33 @@ impl Error {
         Error::EndpointClosing { source }
     }
 
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn graceful_close_timed_out(timeout: Duration) -> Error {
+        let source = panic::Location::caller();
+        Error::GracefulCloseTimedOut { timeout, source }
+    }
+
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn max_lifetime_exceeded(max_lifetime: Duration) -> Error {
+        let source = panic::Location::caller();
+        Error::MaxLifetimeExceeded {
+            max_lifetime,
+            source,
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn dead_peer_detected(timeout: Duration) -> Error {
+        let source = panic::Location::caller();
+        Error::DeadPeerDetected { timeout, source }
+    }
+
     #[inline]
     #[track_caller]
     #[doc(hidden)]
@@ -375,6 +445,26 @@ pub fn as_frame<'a, F: connection::close::Formatter>(
         Error::MaxHandshakeDurationExceeded { .. } => None,
         Error::ImmediateClose { .. } => None,
         Error::EndpointClosing { .. } => None,
+        Error::GracefulCloseTimedOut { .. } => {
+            // Outstanding stream data was discarded so the peer is notified with an error code
+            // that can be told apart from a completed close
+            let error = transport::Error::APPLICATION_ERROR.with_reason("graceful close timed out");
+
+            let early = formatter.format_early_transport_error(context, error);
+            let one_rtt = formatter.format_transport_error(context, error);
+
+            Some((early, one_rtt))
+        }
+        Error::MaxLifetimeExceeded { .. } => {
+            let error = transport::Error::NO_ERROR.with_reason("max connection lifetime exceeded");
+
+            let early = formatter.format_early_transport_error(context, error);
+            let one_rtt = formatter.format_transport_error(context, error);
+
+            Some((early, one_rtt))
+        }
+        // The peer is unreachable so nothing is sent
+        Error::DeadPeerDetected { .. } => None,
         Error::Unspecified { .. } => {
             let error =
                 transport::Error::INTERNAL_ERROR.with_reason("an unspecified error occurred");
@@ -460,6 +550,9 @@ impl From<Error> for std::io::ErrorKind {
             Error::MaxHandshakeDurationExceeded { .. } => ErrorKind::TimedOut,
             Error::ImmediateClose { .. } => ErrorKind::Other,
             Error::EndpointClosing { .. } => ErrorKind::Other,
+            Error::GracefulCloseTimedOut { .. } => ErrorKind::TimedOut,
+            Error::MaxLifetimeExceeded { .. } => ErrorKind::TimedOut,
+            Error::DeadPeerDetected { .. } => ErrorKind::TimedOut,
             Error::Unspecified { .. } => ErrorKind::Other,
         }
     }
@@ -474,6 +567,138 @@ pub enum ProcessingError {
     Other,
 }
 
+/// An error returned when the keep-alive period of a connection can't be updated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeepAliveError {
+    /// The period isn't less than the idle timeout negotiated with the peer, so the connection
+    /// would time out before a PING frame was sent
+    #[non_exhaustive]
+    InvalidPeriod {
+        period: Duration,
+        max_idle_timeout: Duration,
+    },
+    /// The connection encountered an error and can no longer be kept alive
+    ConnectionError(Error),
+}
+
+impl From<Error> for KeepAliveError {
+    fn from(inner_error: Error) -> Self {
+        KeepAliveError::ConnectionError(inner_error)
+    }
+}
+
+impl fmt::Display for KeepAliveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPeriod {
+                period,
+                max_idle_timeout,
+            } => write!(
+                f,
+                "The keep-alive period of {period:?} must be less than the max idle timeout \
+                of {max_idle_timeout:?}"
+            ),
+            Self::ConnectionError(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeepAliveError {}
+
+/// An error returned when a connection migration requested by the application fails
+///
+/// Unless the error is a `ConnectionError`, the connection remains open on the previously
+/// active path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MigrationError {
+    /// Only clients are allowed to initiate a migration
+    NotClient,
+    /// The handshake hasn't been confirmed yet
+    HandshakeNotConfirmed,
+    /// A migration to another local address is already in progress
+    InProgress,
+    /// The IO provider can't send from the requested local address
+    UnsupportedLocalAddress,
+    /// The maximum number of paths for the connection has been reached
+    PathLimitExceeded,
+    /// The peer hasn't issued a connection ID that can be used on the new path
+    NoPeerConnectionId,
+    /// The peer didn't respond to the path validation on the new path
+    ValidationFailed,
+    /// The connection encountered an error and can no longer be migrated
+    ConnectionError(Error),
+}
+
+impl From<Error> for MigrationError {
+    fn from(inner_error: Error) -> Self {
+        MigrationError::ConnectionError(inner_error)
+    }
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotClient => write!(f, "Only clients can migrate a connection"),
+            Self::HandshakeNotConfirmed => write!(
+                f,
+                "The connection can't be migrated before the handshake is confirmed"
+            ),
+            Self::InProgress => write!(f, "A connection migration is already in progress"),
+            Self::UnsupportedLocalAddress => write!(
+                f,
+                "The IO provider can't send from the requested local address"
+            ),
+            Self::PathLimitExceeded => write!(
+                f,
+                "The maximum number of paths for the connection has been reached"
+            ),
+            Self::NoPeerConnectionId => {
+                write!(f, "The peer hasn't issued a connection ID for the new path")
+            }
+            Self::ValidationFailed => {
+                write!(f, "Path validation for the new local address failed")
+            }
+            Self::ConnectionError(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MigrationError {}
+
+/// An error returned when accepting a stream with a timeout fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcceptTimeoutError {
+    /// No stream was accepted before the timeout elapsed
+    ///
+    /// The connection remains open, so accepting can be retried.
+    TimedOut,
+    /// The connection encountered an error and can no longer accept streams
+    ConnectionError(Error),
+}
+
+impl From<Error> for AcceptTimeoutError {
+    fn from(inner_error: Error) -> Self {
+        AcceptTimeoutError::ConnectionError(inner_error)
+    }
+}
+
+impl fmt::Display for AcceptTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TimedOut => write!(f, "No stream was accepted before the timeout elapsed"),
+            Self::ConnectionError(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AcceptTimeoutError {}
+
 impl From<Error> for ProcessingError {
     fn from(inner_error: Error) -> Self {
         ProcessingError::ConnectionError(inner_error)
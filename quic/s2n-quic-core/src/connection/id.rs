@@ -5,7 +5,7 @@
 
 use crate::{
     event::{api::SocketAddress, IntoEvent},
-    inet, transport,
+    inet, random, transport,
 };
 use core::{
     convert::{TryFrom, TryInto},
@@ -368,6 +368,21 @@ pub trait Generator {
     /// otherwise the endpoint may terminate.
     fn generate(&mut self, connection_info: &ConnectionInfo) -> LocalId;
 
+    /// Generates a connection ID with the endpoint's random generator
+    ///
+    /// The endpoint calls this method instead of `generate` so randomly generated connection IDs
+    /// can be derived from the configured random provider. By default, the random generator is
+    /// ignored and `generate` is called.
+    #[inline]
+    fn generate_with_random(
+        &mut self,
+        connection_info: &ConnectionInfo,
+        random_generator: &mut dyn random::Generator,
+    ) -> LocalId {
+        let _ = random_generator;
+        self.generate(connection_info)
+    }
+
     /// The maximum amount of time each generated connection ID should be
     /// used for. By default there is no maximum, though connection IDs
     /// may be retired due to rotation requirements or peer requests.
@@ -4,12 +4,12 @@
 use crate::{
     ack,
     event::{api::SocketAddress, IntoEvent},
-    inet, stream,
+    inet, path, recovery, stream,
     transport::parameters::{
         AckDelayExponent, ActiveConnectionIdLimit, InitialFlowControlLimits, InitialMaxData,
         InitialMaxStreamDataBidiLocal, InitialMaxStreamDataBidiRemote, InitialMaxStreamDataUni,
         InitialMaxStreamsBidi, InitialMaxStreamsUni, InitialStreamLimits, MaxAckDelay,
-        MaxDatagramFrameSize, MaxIdleTimeout, TransportParameters,
+        MaxDatagramFrameSize, MaxIdleTimeout, MinAckDelay, TransportParameters,
     },
 };
 use core::{convert::TryInto, time::Duration};
@@ -29,6 +29,23 @@ const MAX_HANDSHAKE_DURATION_DEFAULT: Duration = Duration::from_secs(10);
 //# middleboxes from losing state for UDP flows [GATEWAY].
 const MAX_KEEP_ALIVE_PERIOD_DEFAULT: Duration = Duration::from_secs(30);
 
+//= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.1
+//# An endpoint that initiates migration and requires non-zero-length
+//# connection IDs SHOULD ensure that the pool of connection IDs
+//# available to its peer allows the peer to use a new connection ID on
+//# migration, as the peer will be unable to respond if the pool is
+//# exhausted.
+/// The number of connection IDs issued to the peer by default, which allows the peer to
+/// migrate to a new path while keeping the amount of per-path state small
+pub const ISSUED_CONNECTION_ID_LIMIT_DEFAULT: u8 = 3;
+
+/// The number of PATH_CHALLENGE frames sent when validating a new path by default
+const PATH_VALIDATION_ATTEMPTS_DEFAULT: u8 = 2;
+
+/// The amount of time a connection that reached its max lifetime is given to flush its streams
+/// by default
+const MAX_CONNECTION_LIFETIME_FLUSH_TIMEOUT_DEFAULT: Duration = Duration::from_secs(5);
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ConnectionInfo<'a> {
@@ -45,6 +62,37 @@ impl<'a> ConnectionInfo<'a> {
     }
 }
 
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct ServerNameInfo<'a> {
+    pub remote_address: SocketAddress<'a>,
+    /// The server name requested by the client
+    pub server_name: &'a str,
+}
+
+impl<'a> ServerNameInfo<'a> {
+    #[inline]
+    #[doc(hidden)]
+    pub fn new(remote_address: &'a inet::SocketAddress, server_name: &'a str) -> Self {
+        Self {
+            remote_address: remote_address.into_event(),
+            server_name,
+        }
+    }
+}
+
+/// ConnectionDecision describes how the library should proceed with a connection after it has
+/// been inspected by the [`Limiter`]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConnectionDecision {
+    /// Allow the handshake to continue
+    Accept,
+
+    /// Abort the handshake with an `unrecognized_name` TLS alert
+    Reject,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Limits {
     pub(crate) max_idle_timeout: MaxIdleTimeout,
@@ -59,12 +107,31 @@ pub struct Limits {
     pub(crate) max_ack_delay: MaxAckDelay,
     pub(crate) ack_delay_exponent: AckDelayExponent,
     pub(crate) max_active_connection_ids: ActiveConnectionIdLimit,
+    pub(crate) issued_connection_id_limit: u8,
     pub(crate) ack_elicitation_interval: u8,
     pub(crate) ack_ranges_limit: u8,
     pub(crate) max_send_buffer_size: stream::limits::MaxSendBufferSize,
     pub(crate) max_handshake_duration: Duration,
     pub(crate) max_keep_alive_period: Duration,
+    pub(crate) keep_alive_period: Option<Duration>,
     pub(crate) max_datagram_frame_size: MaxDatagramFrameSize,
+    pub(crate) max_receive_window: Option<u32>,
+    pub(crate) key_update_bytes: Option<u64>,
+    pub(crate) amplification_limit: u8,
+    pub(crate) initial_rtt: Duration,
+    pub(crate) pto_backoff_factor: u32,
+    pub(crate) max_pto: Option<Duration>,
+    pub(crate) packet_reordering_threshold: u64,
+    pub(crate) time_threshold: f32,
+    pub(crate) path_validation_timeout: Option<Duration>,
+    pub(crate) path_validation_attempts: u8,
+    pub(crate) spin_bit: bool,
+    pub(crate) max_connection_lifetime: Option<Duration>,
+    pub(crate) max_connection_lifetime_flush_timeout: Duration,
+    pub(crate) max_stream_reorder_buffer: Option<u64>,
+    pub(crate) dead_peer_timeout: Option<Duration>,
+    pub(crate) grease: bool,
+    pub(crate) ack_frequency: bool,
 }
 
 impl Default for Limits {
@@ -74,7 +141,8 @@ impl Default for Limits {
 }
 
 macro_rules! setter {
-    ($name:ident, $field:ident, $inner:ty $(, |$validate_value:ident| $validaiton:block)?) => {
+    ($(#[$attr:meta])* $name:ident, $field:ident, $inner:ty $(, |$validate_value:ident| $validaiton:block)?) => {
+        $(#[$attr])*
         pub fn $name(mut self, value: $inner) -> Result<Self, ValidationError> {
             $(
                 let $validate_value = value;
@@ -101,12 +169,31 @@ impl Limits {
             max_ack_delay: MaxAckDelay::RECOMMENDED,
             ack_delay_exponent: AckDelayExponent::RECOMMENDED,
             max_active_connection_ids: ActiveConnectionIdLimit::RECOMMENDED,
+            issued_connection_id_limit: ISSUED_CONNECTION_ID_LIMIT_DEFAULT,
             ack_elicitation_interval: ack::Settings::RECOMMENDED.ack_elicitation_interval,
             ack_ranges_limit: ack::Settings::RECOMMENDED.ack_ranges_limit,
             max_send_buffer_size: stream::Limits::RECOMMENDED.max_send_buffer_size,
             max_handshake_duration: MAX_HANDSHAKE_DURATION_DEFAULT,
             max_keep_alive_period: MAX_KEEP_ALIVE_PERIOD_DEFAULT,
+            keep_alive_period: None,
             max_datagram_frame_size: MaxDatagramFrameSize::DEFAULT,
+            max_receive_window: None,
+            key_update_bytes: None,
+            amplification_limit: path::DEFAULT_AMPLIFICATION_LIMIT,
+            initial_rtt: recovery::DEFAULT_INITIAL_RTT,
+            pto_backoff_factor: path::DEFAULT_PTO_BACKOFF_FACTOR,
+            max_pto: None,
+            packet_reordering_threshold: recovery::K_PACKET_THRESHOLD,
+            time_threshold: recovery::K_TIME_THRESHOLD,
+            path_validation_timeout: None,
+            path_validation_attempts: PATH_VALIDATION_ATTEMPTS_DEFAULT,
+            spin_bit: false,
+            max_connection_lifetime: None,
+            max_connection_lifetime_flush_timeout: MAX_CONNECTION_LIFETIME_FLUSH_TIMEOUT_DEFAULT,
+            max_stream_reorder_buffer: None,
+            dead_peer_timeout: None,
+            grease: false,
+            ack_frequency: false,
         }
     }
 
@@ -156,7 +243,51 @@ impl Limits {
         }
     );
 
-    setter!(with_max_idle_timeout, max_idle_timeout, Duration);
+    /// Sets the receive window for each stream
+    ///
+    /// This sets the bidirectional local, bidirectional remote and unidirectional stream data
+    /// windows to the same value.
+    pub fn with_stream_receive_window(self, bytes: u64) -> Result<Self, ValidationError> {
+        self.with_bidirectional_local_data_window(bytes)?
+            .with_bidirectional_remote_data_window(bytes)?
+            .with_unidirectional_data_window(bytes)
+    }
+
+    /// Sets the receive window for the entire connection
+    ///
+    /// This is equivalent to `with_data_window`.
+    pub fn with_connection_receive_window(self, bytes: u64) -> Result<Self, ValidationError> {
+        self.with_data_window(bytes)
+    }
+
+    /// Enables auto-tuning of the stream and connection receive windows
+    ///
+    /// The configured windows are used as the initial windows. If the peer consumes an
+    /// entire window in less than two round trips, the window is doubled, up to `max_window`
+    /// bytes. This allows high bandwidth-delay product paths to be saturated without requiring
+    /// large windows for every connection.
+    pub fn with_receive_window_auto_tuning(
+        mut self,
+        max_window: u64,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            max_window <= u32::MAX.into(),
+            "max_receive_window must be <= u32::MAX"
+        );
+        self.max_receive_window = Some(max_window as u32);
+        Ok(self)
+    }
+
+    setter!(
+        /// Sets the maximum idle timeout advertised to the peer
+        ///
+        /// The effective idle timeout of a connection is the minimum of the values advertised
+        /// by both endpoints. A value of zero doesn't impose a local limit and defers entirely to
+        /// the peer's value. Defaults to 30 seconds.
+        with_max_idle_timeout,
+        max_idle_timeout,
+        Duration
+    );
 
     /// Sets both the max local and remote limits for bidirectional streams.
     #[deprecated(
@@ -184,6 +315,10 @@ impl Limits {
 
     /// Sets the max remote limits for bidirectional streams.
     ///
+    /// The value is advertised to the peer in the `initial_max_streams_bidi` transport
+    /// parameter. A peer that opens more bidirectional streams than allowed has the connection
+    /// closed with a `STREAM_LIMIT_ERROR`.
+    ///
     /// The value set is used instead of `with_max_open_bidirectional_streams` when set.
     pub fn with_max_open_remote_bidirectional_streams(
         mut self,
@@ -194,16 +329,33 @@ impl Limits {
     }
 
     setter!(
+        /// Sets the max local limits for unidirectional streams
         with_max_open_local_unidirectional_streams,
         max_open_local_unidirectional_streams,
         u64
     );
     setter!(
+        /// Sets the max remote limits for unidirectional streams
+        ///
+        /// The value is advertised to the peer in the `initial_max_streams_uni` transport
+        /// parameter. A peer that opens more unidirectional streams than allowed has the
+        /// connection closed with a `STREAM_LIMIT_ERROR`.
         with_max_open_remote_unidirectional_streams,
         max_open_remote_unidirectional_streams,
         u64
     );
-    setter!(with_max_ack_delay, max_ack_delay, Duration);
+    setter!(
+        /// Sets the maximum amount of time the endpoint delays acknowledging packets
+        ///
+        /// The value is advertised to the peer in the `max_ack_delay` transport parameter and
+        /// bounds how long the ACK scheduler waits to batch acknowledgements for ack-eliciting
+        /// packets. Lower values reduce latency at the cost of sending more ACK frames, while
+        /// higher values send fewer of them. The value is truncated to milliseconds and must be
+        /// less than 2^14 milliseconds.
+        with_max_ack_delay,
+        max_ack_delay,
+        Duration
+    );
     setter!(
         with_max_active_connection_ids,
         max_active_connection_ids,
@@ -219,6 +371,275 @@ impl Limits {
     );
     setter!(with_max_keep_alive_period, max_keep_alive_period, Duration);
 
+    /// Enables keep-alive on each connection by default, sending a PING frame after the
+    /// connection has been idle for `period`.
+    ///
+    /// The period is checked against the idle timeout once it has been negotiated with the
+    /// peer, so it doesn't matter whether it is set before or after the max idle timeout. If
+    /// the period isn't less than the negotiated idle timeout, the connection falls back to a
+    /// period derived from that timeout instead.
+    pub fn with_keep_alive_period(mut self, period: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !period.is_zero(),
+            "keep_alive_period must be greater than zero"
+        );
+        self.keep_alive_period = Some(period);
+        Ok(self)
+    }
+
+    /// Sets the maximum number of connection IDs issued to the peer that are active at any
+    /// given time
+    ///
+    /// The number of connection IDs issued is also bounded by the `active_connection_id_limit`
+    /// sent by the peer. Issuing more connection IDs allows the peer to probe more paths
+    /// simultaneously at the expense of additional state to maintain.
+    ///
+    /// This is the counterpart of [`Self::with_max_active_connection_ids`], which limits the
+    /// number of connection IDs the peer issues to this endpoint.
+    pub fn with_issued_connection_id_limit(mut self, limit: u64) -> Result<Self, ValidationError> {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.1
+        //# An endpoint that initiates migration and requires non-zero-length
+        //# connection IDs SHOULD ensure that the pool of connection IDs
+        //# available to its peer allows the peer to use a new connection ID on
+        //# migration, as the peer will be unable to respond if the pool is
+        //# exhausted.
+        decoder_invariant!(limit >= 2, "issued_connection_id_limit must be at least 2");
+        decoder_invariant!(
+            limit <= u8::MAX.into(),
+            "issued_connection_id_limit must be <= u8::MAX"
+        );
+        self.issued_connection_id_limit = limit as u8;
+        Ok(self)
+    }
+
+    /// Initiates a 1-RTT key update after `bytes` have been sent with the current keys
+    ///
+    /// Without this, keys are only updated before reaching the confidentiality limit of the
+    /// negotiated AEAD, which is impractically high for most connections. Updates can also be
+    /// initiated by the application with `Connection::initiate_key_update`.
+    pub fn with_key_update_bytes(mut self, bytes: u64) -> Result<Self, ValidationError> {
+        decoder_invariant!(bytes > 0, "key_update_bytes must be greater than zero");
+        self.key_update_bytes = Some(bytes);
+        Ok(self)
+    }
+
+    /// Sets the factor by which the bytes a server sends may exceed the bytes it received
+    /// from a client before the client's address is validated
+    ///
+    /// The default of 3 is required by RFC 9000. Raising it avoids waiting on the client when
+    /// the server's first flight is large, but also allows the server to amplify attacks
+    /// against spoofed addresses. It should only be raised on networks where the source
+    /// addresses of received packets can be trusted. This only applies to servers.
+    pub fn with_amplification_limit(mut self, factor: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(factor >= 1, "amplification_limit must be at least 1");
+        self.amplification_limit = factor;
+        Ok(self)
+    }
+
+    /// Sets the RTT estimate used before the first RTT sample is taken
+    ///
+    /// The estimate determines the probe timeout (PTO) for the first packets of a connection.
+    /// The default of 333ms causes unnecessary retransmissions on networks with higher
+    /// latencies, while a lower value delays recovery from lost packets on faster networks.
+    /// The estimate is replaced by the first RTT sample.
+    pub fn with_initial_rtt(mut self, initial_rtt: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !initial_rtt.is_zero(),
+            "initial_rtt must be greater than zero"
+        );
+        self.initial_rtt = initial_rtt;
+        Ok(self)
+    }
+
+    /// Sets the factor by which the probe timeout (PTO) period is increased each time the
+    /// PTO expires without an acknowledgement
+    ///
+    /// The default of 2 is required by RFC 9002. A factor of 1 disables the exponential
+    /// backoff entirely, which should be combined with `with_max_pto` only on networks where
+    /// the additional probes won't contribute to congestion.
+    pub fn with_pto_backoff_factor(mut self, factor: u32) -> Result<Self, ValidationError> {
+        decoder_invariant!(factor >= 1, "pto_backoff_factor must be at least 1");
+        self.pto_backoff_factor = factor;
+        Ok(self)
+    }
+
+    /// Sets the upper bound of the probe timeout (PTO) period
+    ///
+    /// Without a bound, the PTO period grows exponentially while a path doesn't respond,
+    /// which can stall the connection for a long time after the path recovers. Bounding the
+    /// period causes probes to be sent at least this often.
+    pub fn with_max_pto(mut self, max_pto: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(!max_pto.is_zero(), "max_pto must be greater than zero");
+        self.max_pto = Some(max_pto);
+        Ok(self)
+    }
+
+    /// Sets the number of packets that must be acknowledged after an unacknowledged packet
+    /// before it is declared lost
+    ///
+    /// The default of 3 is recommended by RFC 9002. Raising the threshold avoids declaring
+    /// packets lost on networks that reorder packets, at the cost of detecting actual losses
+    /// more slowly.
+    pub fn with_packet_reordering_threshold(
+        mut self,
+        threshold: u64,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            threshold >= 1,
+            "packet_reordering_threshold must be at least 1"
+        );
+        self.packet_reordering_threshold = threshold;
+        Ok(self)
+    }
+
+    /// Sets the multiple of the RTT after which an unacknowledged packet is declared lost if
+    /// a packet sent after it has been acknowledged
+    ///
+    /// The default of 9/8 is recommended by RFC 9002. Higher values make loss detection more
+    /// tolerant of reordering and delay variation, while lower values recover from losses
+    /// sooner.
+    pub fn with_time_threshold(mut self, multiplier: f32) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            multiplier.is_finite() && multiplier >= 1.0,
+            "time_threshold must be at least 1.0"
+        );
+        self.time_threshold = multiplier;
+        Ok(self)
+    }
+
+    /// Sets the amount of time to wait for the peer to respond to a PATH_CHALLENGE before
+    /// validation of a new path is abandoned
+    ///
+    /// By default, three times the larger of the PTO of the new path and the current path is
+    /// used, as recommended by RFC 9000. If validation of the active path is abandoned, the
+    /// connection falls back to the last validated path.
+    pub fn with_path_validation_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !timeout.is_zero(),
+            "path_validation_timeout must be greater than zero"
+        );
+        self.path_validation_timeout = Some(timeout);
+        Ok(self)
+    }
+
+    /// Sets the number of PATH_CHALLENGE frames sent when validating a new path
+    ///
+    /// Sending more than one challenge guards against packet loss on the new path.
+    pub fn with_path_validation_attempts(mut self, attempts: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(attempts >= 1, "path_validation_attempts must be at least 1");
+        self.path_validation_attempts = attempts;
+        Ok(self)
+    }
+
+    /// Enables the latency spin bit in 1-RTT packets
+    ///
+    /// The spin bit allows on-path observers to passively measure the round-trip time of the
+    /// connection, which aids network operators but reveals information about the connection.
+    /// It is disabled by default. Even when enabled, the spin bit is not used for a random
+    /// selection of connection IDs, as required by RFC 9000.
+    pub fn with_spin_bit(mut self, enabled: bool) -> Result<Self, ValidationError> {
+        self.spin_bit = enabled;
+        Ok(self)
+    }
+
+    /// Sets the amount of time after which a connection is closed gracefully
+    ///
+    /// Once the lifetime is reached, the connection stops accepting new streams, flushes the
+    /// outstanding stream data, and closes with a `MaxLifetimeExceeded` error. Connections that
+    /// are still handshaking are closed once the handshake completes. By default, connections
+    /// don't have a max lifetime.
+    ///
+    /// The time spent flushing is bounded by
+    /// [`with_max_connection_lifetime_flush_timeout`](Self::with_max_connection_lifetime_flush_timeout).
+    pub fn with_max_connection_lifetime(
+        mut self,
+        lifetime: Duration,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !lifetime.is_zero(),
+            "max_connection_lifetime must be greater than zero"
+        );
+        self.max_connection_lifetime = Some(lifetime);
+        Ok(self)
+    }
+
+    /// Sets the amount of time a connection that reached its max lifetime is given to flush the
+    /// outstanding stream data
+    ///
+    /// If the streams aren't flushed in time, for example because the peer stopped reading, the
+    /// connection is closed immediately with a `GracefulCloseTimedOut` error, which tells the peer
+    /// that outstanding data was discarded. The default is 5 seconds.
+    pub fn with_max_connection_lifetime_flush_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !timeout.is_zero(),
+            "max_connection_lifetime_flush_timeout must be greater than zero"
+        );
+        self.max_connection_lifetime_flush_timeout = timeout;
+        Ok(self)
+    }
+
+    /// Sets the maximum amount of out-of-order data that is buffered for each stream
+    ///
+    /// Data received more than `bytes` past the first byte of a stream that is still missing
+    /// causes the connection to be closed with a `FLOW_CONTROL_ERROR`. This bounds the memory
+    /// that a peer can force a stream to hold with data that can't be delivered to the
+    /// application, independent of the flow control window. The limit should allow for the
+    /// amount of data that can be in flight when packets are lost. By default, out-of-order data
+    /// is only limited by the flow control window.
+    pub fn with_max_stream_reorder_buffer(mut self, bytes: u64) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            bytes > 0,
+            "max_stream_reorder_buffer must be greater than zero"
+        );
+        self.max_stream_reorder_buffer = Some(bytes);
+        Ok(self)
+    }
+
+    /// Sets the amount of time the peer has to acknowledge an ack-eliciting packet before the
+    /// connection is considered dead
+    ///
+    /// The timeout starts when an ack-eliciting packet is sent and no other ack-eliciting
+    /// packets are awaiting acknowledgement. If none of the packets, including any
+    /// retransmissions, are acknowledged before it expires, the connection is closed silently
+    /// with a `DeadPeerDetected` error. This detects an unreachable peer faster than the idle
+    /// timeout. The timeout should be several times the expected RTT to allow for loss
+    /// recovery. By default, dead peer detection is disabled.
+    pub fn with_dead_peer_timeout(mut self, timeout: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !timeout.is_zero(),
+            "dead_peer_timeout must be greater than zero"
+        );
+        self.dead_peer_timeout = Some(timeout);
+        Ok(self)
+    }
+
+    /// Sends a reserved transport parameter during the handshake
+    ///
+    /// The parameter has a random identifier and value, and exercises the requirement that
+    /// peers ignore transport parameters they don't support. QUIC version 1 doesn't reserve
+    /// any frame types, so only transport parameters are greased. It is disabled by default.
+    pub fn with_grease(mut self, enabled: bool) -> Result<Self, ValidationError> {
+        self.grease = enabled;
+        Ok(self)
+    }
+
+    /// Enables the ACK_FREQUENCY extension
+    ///
+    /// When both peers enable the extension, each endpoint requests that the other sends fewer
+    /// acknowledgements once the handshake is confirmed, which reduces the number of packets
+    /// sent on the return path of high-throughput transfers. Peers that don't support the
+    /// extension keep acknowledging at the default rate. It is disabled by default.
+    pub fn with_ack_frequency(mut self, enabled: bool) -> Result<Self, ValidationError> {
+        self.ack_frequency = enabled;
+        Ok(self)
+    }
+
     // internal APIs
 
     #[doc(hidden)]
@@ -271,6 +692,7 @@ impl Limits {
             max_send_buffer_size: self.max_send_buffer_size,
             max_open_local_unidirectional_streams: self.max_open_local_unidirectional_streams,
             max_open_local_bidirectional_streams: self.max_open_local_bidirectional_streams,
+            max_reorder_buffer_size: self.max_stream_reorder_buffer,
         }
     }
 
@@ -291,11 +713,160 @@ impl Limits {
     pub fn max_keep_alive_period(&self) -> Duration {
         self.max_keep_alive_period
     }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn keep_alive_period(&self) -> Option<Duration> {
+        self.keep_alive_period
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn issued_connection_id_limit(&self) -> u8 {
+        self.issued_connection_id_limit
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_receive_window(&self) -> Option<u32> {
+        self.max_receive_window
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn key_update_bytes(&self) -> Option<u64> {
+        self.key_update_bytes
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn amplification_limit(&self) -> u8 {
+        self.amplification_limit
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn initial_rtt(&self) -> Duration {
+        self.initial_rtt
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn pto_backoff_factor(&self) -> u32 {
+        self.pto_backoff_factor
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_pto(&self) -> Option<Duration> {
+        self.max_pto
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn packet_reordering_threshold(&self) -> u64 {
+        self.packet_reordering_threshold
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn time_threshold(&self) -> f32 {
+        self.time_threshold
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn path_validation_timeout(&self) -> Option<Duration> {
+        self.path_validation_timeout
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn path_validation_attempts(&self) -> u8 {
+        self.path_validation_attempts
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn spin_bit(&self) -> bool {
+        self.spin_bit
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_connection_lifetime(&self) -> Option<Duration> {
+        self.max_connection_lifetime
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_connection_lifetime_flush_timeout(&self) -> Duration {
+        self.max_connection_lifetime_flush_timeout
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn dead_peer_timeout(&self) -> Option<Duration> {
+        self.dead_peer_timeout
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn grease(&self) -> bool {
+        self.grease
+    }
+
+    /// Returns the `min_ack_delay` advertised to the peer, if the ACK_FREQUENCY extension is
+    /// enabled
+    #[doc(hidden)]
+    #[inline]
+    pub fn min_ack_delay(&self) -> Option<MinAckDelay> {
+        // the extension can't be advertised if the max_ack_delay is smaller than the min_ack_delay
+        Some(MinAckDelay::RECOMMENDED).filter(|min_ack_delay| {
+            self.ack_frequency && min_ack_delay.is_valid_for(self.max_ack_delay)
+        })
+    }
 }
 
 /// Creates limits for a given connection
 pub trait Limiter: 'static + Send {
     fn on_connection(&mut self, info: &ConnectionInfo) -> Limits;
+
+    /// Called on the server once the TLS provider has parsed the server name sent by the client
+    ///
+    /// This is invoked before the handshake completes. Returning [`ConnectionDecision::Reject`]
+    /// aborts the handshake with an `unrecognized_name` TLS alert rather than completing it and
+    /// closing the connection later.
+    ///
+    /// ```rust
+    /// # mod s2n_quic { pub mod provider { pub mod limits { pub use s2n_quic_core::connection::limits::*; } } }
+    /// use s2n_quic::provider::limits::{
+    ///     ConnectionDecision, ConnectionInfo, Limiter, Limits, ServerNameInfo,
+    /// };
+    ///
+    /// struct MyLimits {
+    ///     tenants: Vec<String>,
+    /// }
+    ///
+    /// impl Limiter for MyLimits {
+    ///     fn on_connection(&mut self, _info: &ConnectionInfo) -> Limits {
+    ///         Limits::default()
+    ///     }
+    ///
+    ///     fn on_server_name(&mut self, info: &ServerNameInfo) -> ConnectionDecision {
+    ///         if self.tenants.iter().any(|tenant| tenant == info.server_name) {
+    ///             ConnectionDecision::Accept
+    ///         } else {
+    ///             ConnectionDecision::Reject
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn on_server_name(&mut self, info: &ServerNameInfo) -> ConnectionDecision {
+        let _ = info;
+        ConnectionDecision::Accept
+    }
 }
 
 /// Implement Limiter for a Limits struct
@@ -308,6 +879,7 @@ impl Limiter for Limits {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::varint::VarInt;
 
     // Local max data limits should be <= u32::MAX
     #[test]
@@ -325,4 +897,233 @@ mod tests {
         assert!(limits.with_bidirectional_remote_data_window(data).is_ok());
         assert!(limits.with_unidirectional_data_window(data).is_ok());
     }
+
+    // Receive window limits should be <= u32::MAX
+    #[test]
+    fn receive_window_validation() {
+        let limits = Limits::default();
+        let data = u32::MAX as u64 + 1;
+        assert!(limits.with_stream_receive_window(data).is_err());
+        assert!(limits.with_connection_receive_window(data).is_err());
+        assert!(limits.with_receive_window_auto_tuning(data).is_err());
+
+        let limits = limits
+            .with_stream_receive_window(1000)
+            .unwrap()
+            .with_connection_receive_window(2000)
+            .unwrap()
+            .with_receive_window_auto_tuning(u32::MAX.into())
+            .unwrap();
+        let stream_limits = limits.initial_stream_limits();
+        assert_eq!(stream_limits.max_data_bidi_local, VarInt::from_u32(1000));
+        assert_eq!(stream_limits.max_data_bidi_remote, VarInt::from_u32(1000));
+        assert_eq!(stream_limits.max_data_uni, VarInt::from_u32(1000));
+        assert_eq!(
+            limits.initial_flow_control_limits().max_data,
+            VarInt::from_u32(2000)
+        );
+        assert_eq!(limits.max_receive_window(), Some(u32::MAX));
+    }
+
+    // The keep-alive period should be less than the max idle timeout
+    #[test]
+    fn keep_alive_period_validation() {
+        let limits = Limits::default();
+
+        assert!(limits.with_keep_alive_period(Duration::ZERO).is_err());
+
+        // the period isn't checked against the idle timeout until it is negotiated, so the
+        // order of the setters doesn't matter
+        let limits = limits
+            .with_keep_alive_period(Duration::from_secs(60))
+            .unwrap()
+            .with_max_idle_timeout(Duration::from_secs(120))
+            .unwrap();
+        assert_eq!(limits.keep_alive_period(), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn max_idle_timeout_zero() {
+        let limits = Limits::default()
+            .with_max_idle_timeout(Duration::ZERO)
+            .unwrap();
+        assert_eq!(limits.max_idle_timeout(), None);
+
+        // without a local limit, any keep-alive period is allowed
+        assert!(limits
+            .with_keep_alive_period(Duration::from_secs(60))
+            .is_ok());
+    }
+
+    #[test]
+    fn key_update_bytes_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.key_update_bytes(), None);
+        assert!(limits.with_key_update_bytes(0).is_err());
+
+        let limits = limits.with_key_update_bytes(1 << 30).unwrap();
+        assert_eq!(limits.key_update_bytes(), Some(1 << 30));
+    }
+
+    #[test]
+    fn max_ack_delay_validation() {
+        let limits = Limits::default();
+        assert_eq!(
+            limits.ack_settings().max_ack_delay,
+            Duration::from_millis(25)
+        );
+        assert!(limits
+            .with_max_ack_delay(Duration::from_millis(1 << 14))
+            .is_err());
+
+        let limits = limits.with_max_ack_delay(Duration::from_millis(5)).unwrap();
+        assert_eq!(
+            limits.ack_settings().max_ack_delay,
+            Duration::from_millis(5)
+        );
+    }
+
+    #[test]
+    fn amplification_limit_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.amplification_limit(), 3);
+        assert!(limits.with_amplification_limit(0).is_err());
+
+        let limits = limits.with_amplification_limit(10).unwrap();
+        assert_eq!(limits.amplification_limit(), 10);
+    }
+
+    #[test]
+    fn initial_rtt_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.initial_rtt(), recovery::DEFAULT_INITIAL_RTT);
+        assert!(limits.with_initial_rtt(Duration::ZERO).is_err());
+
+        let limits = limits.with_initial_rtt(Duration::from_millis(600)).unwrap();
+        assert_eq!(limits.initial_rtt(), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn pto_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.pto_backoff_factor(), 2);
+        assert_eq!(limits.max_pto(), None);
+        assert!(limits.with_pto_backoff_factor(0).is_err());
+        assert!(limits.with_max_pto(Duration::ZERO).is_err());
+
+        let limits = limits
+            .with_pto_backoff_factor(3)
+            .unwrap()
+            .with_max_pto(Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(limits.pto_backoff_factor(), 3);
+        assert_eq!(limits.max_pto(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn loss_threshold_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.packet_reordering_threshold(), 3);
+        assert_eq!(limits.time_threshold(), 1.125);
+        assert!(limits.with_packet_reordering_threshold(0).is_err());
+        assert!(limits.with_time_threshold(0.5).is_err());
+        assert!(limits.with_time_threshold(f32::NAN).is_err());
+        assert!(limits.with_time_threshold(f32::INFINITY).is_err());
+
+        let limits = limits
+            .with_packet_reordering_threshold(10)
+            .unwrap()
+            .with_time_threshold(1.5)
+            .unwrap();
+        assert_eq!(limits.packet_reordering_threshold(), 10);
+        assert_eq!(limits.time_threshold(), 1.5);
+    }
+
+    #[test]
+    fn path_validation_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.path_validation_timeout(), None);
+        assert_eq!(limits.path_validation_attempts(), 2);
+        assert!(limits.with_path_validation_timeout(Duration::ZERO).is_err());
+        assert!(limits.with_path_validation_attempts(0).is_err());
+
+        let limits = limits
+            .with_path_validation_timeout(Duration::from_secs(1))
+            .unwrap()
+            .with_path_validation_attempts(5)
+            .unwrap();
+        assert_eq!(
+            limits.path_validation_timeout(),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(limits.path_validation_attempts(), 5);
+    }
+
+    #[test]
+    fn max_connection_lifetime_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.max_connection_lifetime(), None);
+        assert!(limits.with_max_connection_lifetime(Duration::ZERO).is_err());
+
+        let limits = limits
+            .with_max_connection_lifetime(Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(
+            limits.max_connection_lifetime(),
+            Some(Duration::from_secs(60))
+        );
+
+        assert_eq!(
+            limits.max_connection_lifetime_flush_timeout(),
+            Duration::from_secs(5)
+        );
+        assert!(limits
+            .with_max_connection_lifetime_flush_timeout(Duration::ZERO)
+            .is_err());
+        let limits = limits
+            .with_max_connection_lifetime_flush_timeout(Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(
+            limits.max_connection_lifetime_flush_timeout(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn dead_peer_timeout_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.dead_peer_timeout(), None);
+        assert!(limits.with_dead_peer_timeout(Duration::ZERO).is_err());
+
+        let limits = limits
+            .with_dead_peer_timeout(Duration::from_secs(3))
+            .unwrap();
+        assert_eq!(limits.dead_peer_timeout(), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn max_stream_reorder_buffer_validation() {
+        let limits = Limits::default();
+        assert_eq!(limits.stream_limits().max_reorder_buffer_size, None);
+        assert!(limits.with_max_stream_reorder_buffer(0).is_err());
+
+        let limits = limits.with_max_stream_reorder_buffer(1 << 20).unwrap();
+        assert_eq!(
+            limits.stream_limits().max_reorder_buffer_size,
+            Some(1 << 20)
+        );
+    }
+
+    // The issued connection id limit should leave the peer a spare connection id
+    #[test]
+    fn issued_connection_id_limit_validation() {
+        let limits = Limits::default();
+
+        assert!(limits.with_issued_connection_id_limit(1).is_err());
+        assert!(limits.with_issued_connection_id_limit(256).is_err());
+
+        let limits = limits.with_issued_connection_id_limit(8).unwrap();
+        assert_eq!(limits.issued_connection_id_limit(), 8);
+    }
 }
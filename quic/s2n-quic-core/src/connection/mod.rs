@@ -5,7 +5,9 @@ pub mod close;
 pub mod error;
 pub mod id;
 pub mod limits;
+pub mod statistics;
 
-pub use error::{Error, ProcessingError};
+pub use error::{AcceptTimeoutError, Error, KeepAliveError, MigrationError, ProcessingError};
 pub use id::{InitialId, LocalId, PeerId, UnboundedId};
 pub use limits::Limits;
+pub use statistics::Statistics;
@@ -0,0 +1,45 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+/// A point-in-time snapshot of a connection's statistics
+///
+/// The RTT, congestion and MTU values are taken from the connection's active path, using the
+/// same names as the fields in the `RecoveryMetrics` and `MtuUpdated` events. The byte and
+/// packet counts are totals across all of the paths of the connection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Statistics {
+    /// The minimum RTT observed on the active path
+    pub min_rtt: Duration,
+    /// The smoothed RTT of the active path
+    pub smoothed_rtt: Duration,
+    /// The most recent RTT sample of the active path
+    pub latest_rtt: Duration,
+    /// The variation in the RTT samples of the active path
+    pub rtt_variance: Duration,
+    /// The congestion window of the active path, in bytes
+    pub congestion_window: u32,
+    /// The number of bytes currently in flight on the active path
+    pub bytes_in_flight: u32,
+    /// The most recent estimate of the rate at which the active path delivers data, in bytes
+    /// per second
+    ///
+    /// The estimate is sampled from acknowledgements by the congestion controller. It is zero if
+    /// the congestion controller doesn't sample the delivery rate, which is the case for CUBIC
+    /// but not BBR. It is also zero until the first packets are acknowledged, and underestimates
+    /// the capacity of the path while the application isn't sending enough data to fill it.
+    pub delivery_rate: u64,
+    /// The maximum UDP payload size of the active path
+    pub mtu: u16,
+    /// The largest maximum UDP payload size confirmed on any path, even if it was later
+    /// reduced
+    pub largest_mtu: u16,
+    /// The total number of bytes sent in datagrams by the connection
+    pub bytes_sent: u64,
+    /// The total number of bytes received in datagrams by the connection
+    pub bytes_received: u64,
+    /// The total number of packets declared lost by the connection
+    pub packets_lost: u64,
+}
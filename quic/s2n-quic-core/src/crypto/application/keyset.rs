@@ -30,6 +30,8 @@ pub struct KeySet<K> {
     aead_integrity_limit: u64,
     /// The number of times the key has been rotated
     generation: u16,
+    /// Set when a key update has been requested but the peer hasn't responded yet
+    update_requested: bool,
 
     /// Set of keys for the current and next phase
     crypto: KeyArray<K>,
@@ -66,6 +68,7 @@ impl<K: OneRttKey> KeySet<K> {
             packet_decryption_failures: 0,
             aead_integrity_limit,
             generation: 0,
+            update_requested: false,
             crypto: KeyArray([active_key, next_key]),
             limits,
         }
@@ -75,6 +78,16 @@ impl<K: OneRttKey> KeySet<K> {
     fn rotate_phase(&mut self) {
         self.generation += 1;
         self.key_phase = KeyPhase::next_phase(self.key_phase);
+        self.update_requested = false;
+    }
+
+    /// Requests that subsequent packets are encrypted with the next key phase
+    ///
+    /// The update completes once a packet protected with the new keys is received from the peer.
+    /// If a previous update is still in progress, the request is deferred until the old keys
+    /// have been discarded.
+    pub fn request_update(&mut self) {
+        self.update_requested = true;
     }
 
     /// Derive a new key based on the active key, and store it in the non-active slot
@@ -218,6 +231,12 @@ impl<K: OneRttKey> KeySet<K> {
             return KeyPhase::next_phase(self.key_phase());
         }
 
+        // The next key isn't derived until the derivation timer for the previous update expires
+        // so requested updates need to wait until then.
+        if self.update_requested && !self.key_update_in_progress() {
+            return KeyPhase::next_phase(self.key_phase());
+        }
+
         self.key_phase()
     }
 
@@ -254,7 +273,7 @@ impl<K: OneRttKey> KeySet<K> {
         //= https://www.rfc-editor.org/rfc/rfc9001#section-6.6
         //# Endpoints MUST count the number of encrypted packets for each set of
         //# keys.
-        self.crypto[phase].on_packet_encryption(&self.limits);
+        self.crypto[phase].on_packet_encryption(r.0.len(), &self.limits);
 
         Ok(r)
     }
@@ -284,6 +303,11 @@ impl<K: OneRttKey> KeySet<K> {
         &mut self.crypto[self.key_phase]
     }
 
+    /// Returns the key for the given phase
+    pub fn key(&self, key_phase: KeyPhase) -> &limited::Key<K> {
+        &self.crypto[key_phase]
+    }
+
     fn decryption_error_count(&self) -> u64 {
         self.packet_decryption_failures
     }
@@ -506,6 +530,7 @@ mod tests {
             .is_ok());
 
         assert_eq!(keyset.active_key().encrypted_packets(), 1);
+        assert_eq!(keyset.active_key().encrypted_bytes(), 512);
     }
 
     #[test]
@@ -542,6 +567,31 @@ mod tests {
         assert!(keyset.active_key().needs_update(&keyset.limits));
     }
 
+    #[test]
+    fn test_requested_key_update() {
+        let mut clock = Clock::default();
+        let mut keyset = KeySet::new(TestKey::default(), Default::default());
+
+        assert_eq!(keyset.encryption_phase(), KeyPhase::Zero);
+
+        keyset.request_update();
+        assert_eq!(keyset.encryption_phase(), KeyPhase::One);
+        assert_eq!(keyset.key_phase(), KeyPhase::Zero);
+
+        // the peer responding with the new phase completes the update
+        keyset.rotate_phase();
+        keyset.set_derivation_timer(clock.get_time() + Duration::from_millis(10));
+        assert_eq!(keyset.encryption_phase(), KeyPhase::One);
+
+        // subsequent requests are deferred until the next key has been derived
+        keyset.request_update();
+        assert_eq!(keyset.encryption_phase(), KeyPhase::One);
+
+        clock.inc_by(Duration::from_millis(10));
+        keyset.on_timeout(clock.get_time());
+        assert_eq!(keyset.encryption_phase(), KeyPhase::Zero);
+    }
+
     //= https://www.rfc-editor.org/rfc/rfc9001#section-6.6
     //= type=test
     //# If the total number of encrypted packets with the same key
@@ -12,6 +12,7 @@ pub struct Key<K> {
     // Keeping encrypted_packets out of the key allow keys to be immutable, which allows optimizations
     // later on.
     encrypted_packets: u64,
+    encrypted_bytes: u64,
     decrypted_packets: u64,
     confidentiality_limit: u64,
 }
@@ -53,6 +54,7 @@ impl<K: OneRttKey> Key<K> {
             confidentiality_limit: key.aead_confidentiality_limit(),
             key,
             encrypted_packets: 0,
+            encrypted_bytes: 0,
             decrypted_packets: 0,
         }
     }
@@ -83,9 +85,16 @@ impl<K: OneRttKey> Key<K> {
         self.encrypted_packets
     }
 
+    /// Returns the number of bytes, including packet headers, encrypted with the key
     #[inline]
-    pub fn on_packet_encryption(&mut self, limits: &Limits) {
+    pub fn encrypted_bytes(&self) -> u64 {
+        self.encrypted_bytes
+    }
+
+    #[inline]
+    pub fn on_packet_encryption(&mut self, len: usize, limits: &Limits) {
         self.encrypted_packets += 1;
+        self.encrypted_bytes += len as u64;
 
         if self.encrypted_packets == limits.sealer_optimization_threshold {
             self.key.update_sealer_pmtu(limits.max_mtu.into());
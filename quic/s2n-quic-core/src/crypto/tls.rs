@@ -64,6 +64,37 @@ pub trait Context<Crypto: crate::crypto::CryptoSuite> {
     //# peer's Finished message.
     fn on_handshake_complete(&mut self) -> Result<(), crate::transport::Error>;
 
+    /// Called when the TLS handshake resumed a previously established session
+    ///
+    /// TLS implementations which support resumption call this before `on_handshake_complete`.
+    #[inline]
+    fn on_session_resumed(&mut self) {}
+
+    /// Called with the DER-encoded certificate chain presented by the peer
+    ///
+    /// The peer's end-entity certificate is first in the chain. TLS implementations call this
+    /// before `on_handshake_complete` if the peer authenticated with a certificate.
+    #[inline]
+    fn on_peer_certificate_chain(&mut self, chain: alloc::vec::Vec<Bytes>) {
+        let _ = chain;
+    }
+
+    /// Called with the OCSP response stapled by the server
+    ///
+    /// This is only called on clients and only if the server stapled a response.
+    #[inline]
+    fn on_ocsp_response(&mut self, response: Bytes) {
+        let _ = response;
+    }
+
+    /// Called with the parameters negotiated by the handshake
+    ///
+    /// TLS implementations call this before `on_handshake_complete`.
+    #[inline]
+    fn on_tls_info(&mut self, info: TlsInfo) {
+        let _ = info;
+    }
+
     /// Receives data from the initial packet space
     ///
     /// A `max_len` may be provided to indicate how many bytes the TLS implementation
@@ -208,7 +239,7 @@ impl HelloOffsets {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 pub enum CipherSuite {
     TLS_AES_128_GCM_SHA256,
@@ -238,6 +269,49 @@ impl crate::event::IntoEvent<crate::event::api::CipherSuite> for CipherSuite {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum KeyExchangeGroup {
+    secp256r1,
+    secp384r1,
+    secp521r1,
+    x25519,
+    Unknown,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum ProtocolVersion {
+    TLS_1_3,
+    Unknown,
+}
+
+/// The parameters negotiated by the TLS handshake
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TlsInfo {
+    pub cipher_suite: CipherSuite,
+    /// The group used for the key exchange
+    ///
+    /// This is `Unknown` if the TLS implementation doesn't expose the negotiated group.
+    pub key_exchange_group: KeyExchangeGroup,
+    pub protocol_version: ProtocolVersion,
+}
+
+impl TlsInfo {
+    pub fn new(
+        cipher_suite: CipherSuite,
+        key_exchange_group: KeyExchangeGroup,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        Self {
+            cipher_suite,
+            key_exchange_group,
+            protocol_version,
+        }
+    }
+}
+
 macro_rules! handshake_type {
     ($($variant:ident($value:literal)),* $(,)?) => {
         #[derive(Clone, Copy, Debug, PartialEq, Eq, AsBytes, Unaligned)]
@@ -45,6 +45,10 @@ pub mod certificates {
     pem!(UNTRUSTED_CERT_PEM, "untrusted_cert.pem");
     pem!(UNTRUSTED_KEY_PEM, "untrusted_key.pem");
 
+    // An OCSP response for CERT_PEM, which is self-signed, generated with `openssl ocsp` using
+    // CERT_PEM and KEY_PEM as the responder. The response is valid until 2126.
+    der!(OCSP_DER, "ocsp.der");
+
     // Certificates and Keys used specifically for testing the mTLS (mutual TLS) feature.
     //
     // The MTLS_CA_CERT is the root CA cert and used to sign both MTLS_SERVER_CERT and
@@ -380,6 +384,9 @@ where
     pub server_name: Option<Bytes>,
     pub application_protocol: Option<Bytes>,
     pub transport_parameters: Option<Bytes>,
+    pub peer_certificate_chain: Vec<Bytes>,
+    pub ocsp_response: Option<Bytes>,
+    pub tls_info: Option<tls::TlsInfo>,
     endpoint: endpoint::Type,
     pub state: State,
     waker: Waker,
@@ -400,6 +407,9 @@ where
             .field("sni", &self.server_name)
             .field("application_protocol", &self.application_protocol)
             .field("transport_parameters", &self.transport_parameters)
+            .field("peer_certificate_chain", &self.peer_certificate_chain.len())
+            .field("ocsp_response", &self.ocsp_response)
+            .field("tls_info", &self.tls_info)
             .field("endpoint", &self.endpoint)
             .finish()
     }
@@ -420,6 +430,9 @@ where
             server_name: None,
             application_protocol: None,
             transport_parameters: None,
+            peer_certificate_chain: Vec::new(),
+            ocsp_response: None,
+            tls_info: None,
             endpoint,
             state,
             waker,
@@ -702,6 +715,25 @@ where
         Ok(())
     }
 
+    fn on_peer_certificate_chain(&mut self, chain: Vec<Bytes>) {
+        self.log("peer certificate chain");
+        self.peer_certificate_chain = chain;
+    }
+
+    fn on_ocsp_response(&mut self, response: Bytes) {
+        assert!(
+            self.endpoint.is_client(),
+            "only clients should receive a stapled response"
+        );
+        self.log("ocsp response");
+        self.ocsp_response = Some(response);
+    }
+
+    fn on_tls_info(&mut self, info: tls::TlsInfo) {
+        self.log("tls info");
+        self.tls_info = Some(info);
+    }
+
     fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
         assert!(
             !self.handshake_complete,
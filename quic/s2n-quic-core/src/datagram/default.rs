@@ -5,7 +5,10 @@
 
 use crate::{
     connection,
-    datagram::{ConnectionInfo, Packet, PreConnectionInfo, ReceiveContext},
+    datagram::{
+        ConnectionInfo, DatagramPriority, DropReason, Packet, PreConnectionInfo, ReceiveContext,
+        WriteError,
+    },
     transport::parameters::MaxDatagramFrameSize,
 };
 use alloc::collections::VecDeque;
@@ -23,10 +26,17 @@ use core::{
 /// and Receiver behavior can be swapped out by implementing the respective [`Sender`](s2n-quic-core::datagram::traits::Sender) and
 /// [`Receiver`](s2n-quic-core::datagram::traits::Receiver) traits.
 ///
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Endpoint {
     send_queue_capacity: usize,
     recv_queue_capacity: usize,
+    priority: DatagramPriority,
+}
+
+impl Default for Endpoint {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
 }
 
 impl Endpoint {
@@ -37,12 +47,26 @@ impl Endpoint {
 }
 
 /// A builder for the default datagram endpoint
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct EndpointBuilder {
     send_queue_capacity: usize,
     recv_queue_capacity: usize,
+    priority: DatagramPriority,
+}
+
+impl Default for EndpointBuilder {
+    fn default() -> Self {
+        Self {
+            send_queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            recv_queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            priority: DatagramPriority::default(),
+        }
+    }
 }
 
+/// The number of datagrams that can be queued in each direction by default
+const DEFAULT_QUEUE_CAPACITY: usize = 200;
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum BuilderError {
@@ -64,6 +88,13 @@ impl fmt::Display for BuilderError {
 
 /// Builder for the datagram endpoint
 impl EndpointBuilder {
+    /// Sets the number of datagrams that can be queued for sending on each connection
+    ///
+    /// Once the queue is full, [`Sender::send_datagram`] returns
+    /// [`DatagramError::QueueAtCapacity`] and [`Sender::poll_send_datagram`] returns
+    /// `Poll::Pending` until the connection has transmitted a queued datagram. This
+    /// applies backpressure to applications that produce datagrams faster than the
+    /// network can drain them.
     pub fn with_send_capacity(mut self, capacity: usize) -> Result<Self, BuilderError> {
         if capacity == 0 {
             return Err(BuilderError::ZeroCapacity);
@@ -72,6 +103,7 @@ impl EndpointBuilder {
         Ok(self)
     }
 
+    /// Sets the number of received datagrams that can be queued on each connection
     pub fn with_recv_capacity(mut self, capacity: usize) -> Result<Self, BuilderError> {
         if capacity == 0 {
             return Err(BuilderError::ZeroCapacity);
@@ -80,10 +112,17 @@ impl EndpointBuilder {
         Ok(self)
     }
 
+    /// Sets whether datagrams or stream data are sent first when both are pending
+    pub fn with_priority(mut self, priority: DatagramPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     pub fn build(self) -> Result<Endpoint, core::convert::Infallible> {
         Ok(Endpoint {
             send_queue_capacity: self.send_queue_capacity,
             recv_queue_capacity: self.recv_queue_capacity,
+            priority: self.priority,
         })
     }
 }
@@ -110,6 +149,11 @@ impl super::Endpoint for Endpoint {
     fn max_datagram_frame_size(&self, _info: &PreConnectionInfo) -> u64 {
         MaxDatagramFrameSize::RECOMMENDED
     }
+
+    #[inline]
+    fn priority(&self) -> DatagramPriority {
+        self.priority
+    }
 }
 
 /// Handles receiving unreliable datagrams.
@@ -200,7 +244,7 @@ struct ReceiverBuilder {
 impl Default for ReceiverBuilder {
     fn default() -> Self {
         Self {
-            queue_capacity: 200,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
             max_datagram_frame_size: MaxDatagramFrameSize::RECOMMENDED,
         }
     }
@@ -243,6 +287,21 @@ impl ReceiverBuilder {
 /// the case where all datagrams are dropped because only a small amount of packet
 /// space remains.
 ///
+/// Datagrams that are larger than the largest datagram that fits in a packet on the
+/// current path are rejected with [`DatagramError::ExceedsPathMtu`] when they are
+/// enqueued, since DATAGRAM frames cannot be fragmented. The limit starts at the value
+/// in the [`ConnectionInfo`] and is updated as the path MTU changes.
+///
+/// The queue is bounded by the capacity configured with
+/// [`EndpointBuilder::with_send_capacity`]. [`Sender::poll_send_datagram`] waits for space
+/// to become available when the queue is full, [`Sender::send_datagram`] returns
+/// [`DatagramError::QueueAtCapacity`], and [`Sender::send_datagram_forced`] evicts the
+/// oldest datagram.
+///
+/// When the connection is closed, any datagrams remaining on the queue are dropped
+/// without being sent, and a pending [`Sender::poll_send_datagram`] call is woken to
+/// return [`DatagramError::ConnectionError`].
+///
 /// Note that there is currently no expiration date for datagrams to live on the queue.
 /// Implement the [`Sender`](s2n-quic-core::datagram::traits::Sender) trait if
 /// this behavior is necessary for your use-case.
@@ -256,6 +315,11 @@ pub struct Sender {
     smoothed_packet_size: f64,
     waker: Option<Waker>,
     max_datagram_payload: u64,
+    max_datagram_size: usize,
+    /// The number of datagrams evicted from the queue that have yet to be reported
+    evicted_count: usize,
+    /// The total length of the datagrams evicted from the queue that have yet to be reported
+    evicted_len: usize,
     error: Option<connection::Error>,
 }
 
@@ -266,13 +330,15 @@ pub struct Datagram {
 }
 
 #[non_exhaustive]
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DatagramError {
     #[non_exhaustive]
     QueueAtCapacity,
     #[non_exhaustive]
     ExceedsPeerTransportLimits,
     #[non_exhaustive]
+    ExceedsPathMtu { max_datagram_size: usize },
+    #[non_exhaustive]
     ConnectionError { error: connection::Error },
 }
 
@@ -288,6 +354,12 @@ impl fmt::Display for DatagramError {
                     "Datagram size is larger than peer's transport parameters."
                 )
             }
+            Self::ExceedsPathMtu { max_datagram_size } => {
+                write!(
+                    f,
+                    "Datagram size is larger than the path MTU allows. The maximum datagram size is {max_datagram_size}."
+                )
+            }
             Self::ConnectionError { .. } => {
                 write!(f, "Connection-level error occurred.")
             }
@@ -301,8 +373,31 @@ impl Sender {
         SenderBuilder::default()
     }
 
+    /// Checks that a datagram of `len` bytes can be sent on the connection
+    fn check_datagram_len(&self, len: usize) -> Result<(), DatagramError> {
+        if len as u64 > self.max_datagram_payload {
+            return Err(DatagramError::ExceedsPeerTransportLimits);
+        }
+
+        if len > self.max_datagram_size {
+            return Err(DatagramError::ExceedsPathMtu {
+                max_datagram_size: self.max_datagram_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the size of the largest datagram that fits in a packet on the current path
+    pub fn max_datagram_size(&self) -> usize {
+        self.max_datagram_size
+    }
+
     /// Enqueues a datagram for sending it towards the peer.
     ///
+    /// This is the backpressure-aware way of sending datagrams. It can be awaited
+    /// by wrapping the connection's `datagram_mut` call in `futures::future::poll_fn`.
+    ///
     /// # Return value
     ///
     /// - `Poll::Pending` if the datagram's send buffer capacity is currently exhausted
@@ -317,8 +412,8 @@ impl Sender {
         data: &mut bytes::Bytes,
         cx: &mut Context,
     ) -> Poll<Result<(), DatagramError>> {
-        if data.len() as u64 > self.max_datagram_payload {
-            return Poll::Ready(Err(DatagramError::ExceedsPeerTransportLimits));
+        if let Err(err) = self.check_datagram_len(data.len()) {
+            return Poll::Ready(Err(err));
         }
 
         // If there was some connection-level error the user is not allowed to add
@@ -352,9 +447,7 @@ impl Sender {
         &mut self,
         data: bytes::Bytes,
     ) -> Result<Option<Bytes>, DatagramError> {
-        if data.len() as u64 > self.max_datagram_payload {
-            return Err(DatagramError::ExceedsPeerTransportLimits);
-        }
+        self.check_datagram_len(data.len())?;
 
         // If there was some connection-level error the user is not allowed to add
         // datagrams to the queue as they will never be sent.
@@ -368,6 +461,12 @@ impl Sender {
             oldest = self.queue.pop_front();
         }
 
+        // Report the eviction the next time the connection transmits
+        if let Some(datagram) = oldest.as_ref() {
+            self.evicted_count += 1;
+            self.evicted_len += datagram.data.len();
+        }
+
         let datagram = Datagram { data };
         self.queue.push_back(datagram);
 
@@ -385,9 +484,7 @@ impl Sender {
     /// - `Ok()` if the datagram was enqueued for sending
     /// - `Err(DatagramError)` if some error occurred
     pub fn send_datagram(&mut self, data: bytes::Bytes) -> Result<(), DatagramError> {
-        if data.len() as u64 > self.max_datagram_payload {
-            return Err(DatagramError::ExceedsPeerTransportLimits);
-        }
+        self.check_datagram_len(data.len())?;
 
         // If there was some connection-level error the user is not allowed to add
         // datagrams to the queue as they will never be sent.
@@ -453,6 +550,14 @@ impl Sender {
 
 impl super::Sender for Sender {
     fn on_transmit<P: Packet>(&mut self, packet: &mut P) {
+        self.max_datagram_size = packet.max_datagram_size();
+
+        if self.evicted_count > 0 {
+            packet.on_datagram_dropped(self.evicted_count, self.evicted_len, DropReason::QueueFull);
+            self.evicted_count = 0;
+            self.evicted_len = 0;
+        }
+
         // Cede space to stream data when datagrams are not prioritized
         if packet.has_pending_streams() && !packet.datagrams_prioritized() {
             return;
@@ -465,7 +570,14 @@ impl super::Sender for Sender {
                 if packet.remaining_capacity() >= datagram.data.len() {
                     match packet.write_datagram(&datagram.data) {
                         Ok(()) => has_written = true,
-                        Err(_error) => {
+                        Err(error) => {
+                            let reason = match error {
+                                WriteError::ExceedsPeerTransportLimits => {
+                                    DropReason::ExceedsPeerTransportLimits
+                                }
+                                _ => DropReason::ExceedsPacketCapacity,
+                            };
+                            packet.on_datagram_dropped(1, datagram.data.len(), reason);
                             continue;
                         }
                     }
@@ -482,6 +594,12 @@ impl super::Sender for Sender {
                         self.queue.push_front(datagram);
                         return;
                     }
+
+                    packet.on_datagram_dropped(
+                        1,
+                        datagram.data.len(),
+                        DropReason::ExceedsPacketCapacity,
+                    );
                 }
             } else {
                 // If there are no datagrams on the queue we return
@@ -497,6 +615,10 @@ impl super::Sender for Sender {
 
     fn on_connection_error(&mut self, error: connection::Error) {
         self.error = Some(error);
+        // The queued datagrams can no longer be sent so release them
+        self.queue.clear();
+        self.evicted_count = 0;
+        self.evicted_len = 0;
         if let Some(w) = self.waker.take() {
             w.wake();
         }
@@ -510,13 +632,15 @@ impl super::Sender for Sender {
 struct SenderBuilder {
     queue_capacity: usize,
     max_datagram_payload: u64,
+    max_datagram_size: usize,
 }
 
 impl Default for SenderBuilder {
     fn default() -> Self {
         Self {
-            queue_capacity: 200,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
             max_datagram_payload: 0,
+            max_datagram_size: usize::MAX,
         }
     }
 }
@@ -531,6 +655,7 @@ impl SenderBuilder {
     /// Gives the default sender relevant connection info
     pub fn with_connection_info(mut self, connection_info: &ConnectionInfo) -> Self {
         self.max_datagram_payload = connection_info.max_datagram_payload;
+        self.max_datagram_size = connection_info.max_datagram_size;
         self
     }
 
@@ -540,6 +665,9 @@ impl SenderBuilder {
             queue: VecDeque::with_capacity(self.queue_capacity),
             capacity: self.queue_capacity,
             max_datagram_payload: self.max_datagram_payload,
+            max_datagram_size: self.max_datagram_size,
+            evicted_count: 0,
+            evicted_len: 0,
             max_packet_space: 0,
             min_packet_space: 0,
             smoothed_packet_size: 0.0,
@@ -560,6 +688,7 @@ mod tests {
     fn send_datagram_forced() {
         let conn_info = ConnectionInfo {
             max_datagram_payload: 100,
+            max_datagram_size: 100,
             waker: noop_waker(),
         };
         // Create a default sender queue that only holds two elements
@@ -599,6 +728,7 @@ mod tests {
     fn send_datagram() {
         let conn_info = ConnectionInfo {
             max_datagram_payload: 100,
+            max_datagram_size: 100,
             waker: noop_waker(),
         };
         // Create a default sender queue that only holds two elements
@@ -638,7 +768,7 @@ mod tests {
 
     #[test]
     fn poll_send_datagram() {
-        let conn_info = ConnectionInfo::new(100, noop_waker());
+        let conn_info = ConnectionInfo::new(100, 100, noop_waker());
         let mut default_sender = Sender::builder()
             .with_capacity(2)
             .with_connection_info(&conn_info)
@@ -678,6 +808,8 @@ mod tests {
             remaining_capacity: 10,
             has_pending_streams: false,
             datagrams_prioritized: false,
+            max_datagram_size: 10,
+            dropped: vec![],
         };
         crate::datagram::Sender::on_transmit(&mut default_sender, &mut packet);
 
@@ -706,10 +838,67 @@ mod tests {
         assert!(default_sender.queue.is_empty());
     }
 
+    #[test]
+    // Check that pending datagrams are dropped and a blocked sender is woken on connection close
+    fn on_connection_error_test() {
+        let conn_info = ConnectionInfo::new(100, 100, noop_waker());
+        let mut default_sender = Sender::builder()
+            .with_capacity(1)
+            .with_connection_info(&conn_info)
+            .build()
+            .unwrap();
+        let (waker, wake_count) = new_count_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(
+            default_sender.send_datagram_forced(bytes::Bytes::from_static(&[1, 2, 3])),
+            Ok(None)
+        );
+        let mut datagram = bytes::Bytes::from_static(&[4, 5, 6]);
+        assert_eq!(
+            default_sender.poll_send_datagram(&mut datagram, &mut cx),
+            Poll::Pending
+        );
+        assert_eq!(wake_count, 0);
+
+        let conn_err = connection::Error::closed(crate::endpoint::Location::Local);
+        crate::datagram::Sender::on_connection_error(&mut default_sender, conn_err);
+
+        assert_eq!(wake_count, 1);
+        assert!(default_sender.queue.is_empty());
+        assert!(!crate::datagram::Sender::has_transmission_interest(
+            &default_sender
+        ));
+        assert_eq!(
+            default_sender.poll_send_datagram(&mut datagram, &mut cx),
+            Poll::Ready(Err(DatagramError::ConnectionError { error: conn_err }))
+        );
+    }
+
+    #[test]
+    fn endpoint_default_capacity_test() {
+        let mut endpoint = Endpoint::default();
+        let conn_info = ConnectionInfo::new(100, 100, noop_waker());
+        let (mut sender, _receiver) =
+            crate::datagram::Endpoint::create_connection(&mut endpoint, &conn_info);
+
+        for _ in 0..DEFAULT_QUEUE_CAPACITY {
+            assert_eq!(
+                sender.send_datagram(bytes::Bytes::from_static(&[1])),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            sender.send_datagram(bytes::Bytes::from_static(&[1])),
+            Err(DatagramError::QueueAtCapacity)
+        );
+    }
+
     #[test]
     fn retain_datagrams() {
         let conn_info = ConnectionInfo {
             max_datagram_payload: 100,
+            max_datagram_size: 100,
             waker: noop_waker(),
         };
         let mut default_sender = Sender::builder()
@@ -755,7 +944,7 @@ mod tests {
     // Check that our default on_transmit function doesn't continue to pop datagrams
     // off the send queue if the remaining packet space is too small to send datagrams.
     fn has_written_test() {
-        let conn_info = ConnectionInfo::new(100, noop_waker());
+        let conn_info = ConnectionInfo::new(100, 100, noop_waker());
         let mut default_sender = Sender::builder()
             .with_connection_info(&conn_info)
             .build()
@@ -771,6 +960,8 @@ mod tests {
             remaining_capacity: 5,
             has_pending_streams: false,
             datagrams_prioritized: false,
+            max_datagram_size: 5,
+            dropped: vec![],
         };
         crate::datagram::Sender::on_transmit(&mut default_sender, &mut packet);
 
@@ -780,6 +971,90 @@ mod tests {
         assert!(!default_sender.queue.is_empty());
     }
 
+    #[test]
+    // Check that datagrams larger than the path allows are rejected when they are enqueued
+    fn exceeds_path_mtu_test() {
+        let conn_info = ConnectionInfo::new(100, 4, noop_waker());
+        let mut default_sender = Sender::builder()
+            .with_connection_info(&conn_info)
+            .build()
+            .unwrap();
+        let datagram = bytes::Bytes::from_static(&[1, 2, 3, 4, 5]);
+
+        let err = DatagramError::ExceedsPathMtu {
+            max_datagram_size: 4,
+        };
+        assert_eq!(default_sender.max_datagram_size(), 4);
+        assert_eq!(default_sender.send_datagram(datagram.clone()), Err(err));
+        assert_eq!(
+            default_sender.send_datagram_forced(datagram.clone()),
+            Err(err)
+        );
+        assert_eq!(
+            default_sender.poll_send_datagram(
+                &mut datagram.clone(),
+                &mut Context::from_waker(&noop_waker())
+            ),
+            Poll::Ready(Err(err))
+        );
+        assert!(default_sender.queue.is_empty());
+
+        // The limit follows the path MTU reported by each packet
+        let mut packet = MockPacket {
+            remaining_capacity: 10,
+            has_pending_streams: false,
+            datagrams_prioritized: false,
+            max_datagram_size: 10,
+            dropped: vec![],
+        };
+        crate::datagram::Sender::on_transmit(&mut default_sender, &mut packet);
+
+        assert_eq!(default_sender.max_datagram_size(), 10);
+        assert_eq!(default_sender.send_datagram(datagram), Ok(()));
+    }
+
+    #[test]
+    // Check that datagrams evicted from a full queue are reported on the next transmission
+    fn evicted_datagrams_test() {
+        let conn_info = ConnectionInfo::new(100, 100, noop_waker());
+        let mut default_sender = Sender::builder()
+            .with_capacity(1)
+            .with_connection_info(&conn_info)
+            .build()
+            .unwrap();
+        let datagram_0 = bytes::Bytes::from_static(&[1, 2, 3]);
+        let datagram_1 = bytes::Bytes::from_static(&[4, 5]);
+        let datagram_2 = bytes::Bytes::from_static(&[6]);
+        assert_eq!(
+            default_sender.send_datagram_forced(datagram_0.clone()),
+            Ok(None)
+        );
+        assert_eq!(
+            default_sender.send_datagram_forced(datagram_1.clone()),
+            Ok(Some(datagram_0))
+        );
+        assert_eq!(
+            default_sender.send_datagram_forced(datagram_2),
+            Ok(Some(datagram_1))
+        );
+
+        let mut packet = MockPacket {
+            remaining_capacity: 10,
+            has_pending_streams: false,
+            datagrams_prioritized: false,
+            max_datagram_size: 10,
+            dropped: vec![],
+        };
+        crate::datagram::Sender::on_transmit(&mut default_sender, &mut packet);
+
+        // The evictions are reported together
+        assert_eq!(packet.dropped, [(2, 5, DropReason::QueueFull)]);
+        // The newest datagram was written to the packet
+        assert_eq!(packet.remaining_capacity, 9);
+        assert_eq!(default_sender.evicted_count, 0);
+        assert_eq!(default_sender.evicted_len, 0);
+    }
+
     fn fake_receive_context() -> crate::datagram::ReceiveContext<'static> {
         crate::datagram::ReceiveContext {
             path: crate::event::api::Path {
@@ -906,6 +1181,8 @@ mod tests {
         has_pending_streams: bool,
         datagrams_prioritized: bool,
         remaining_capacity: usize,
+        max_datagram_size: usize,
+        dropped: Vec<(usize, usize, DropReason)>,
     }
 
     impl crate::datagram::Packet for MockPacket {
@@ -933,5 +1210,13 @@ mod tests {
         fn datagrams_prioritized(&self) -> bool {
             self.datagrams_prioritized
         }
+
+        fn max_datagram_size(&self) -> usize {
+            self.max_datagram_size
+        }
+
+        fn on_datagram_dropped(&mut self, count: usize, len: usize, reason: DropReason) {
+            self.dropped.push((count, len, reason));
+        }
     }
 }
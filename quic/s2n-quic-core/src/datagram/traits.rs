@@ -3,7 +3,7 @@
 
 use core::task::Waker;
 
-use crate::connection;
+use crate::{connection, event, event::IntoEvent};
 
 /// The datagram endpoint trait provides a way to implement custom unreliable datagram
 /// sending and receiving logic. The Sender type should be implemented for custom
@@ -17,6 +17,32 @@ pub trait Endpoint: 'static + Send {
 
     /// Returns the maximum datagram frame size the provider is willing to accept
     fn max_datagram_frame_size(&self, info: &PreConnectionInfo) -> u64;
+
+    /// Returns how packet space is shared between datagrams and stream data
+    ///
+    /// This is consulted for every packet in which both stream data and datagrams are
+    /// waiting to be sent. Defaults to [`DatagramPriority::Stream`].
+    #[inline]
+    fn priority(&self) -> DatagramPriority {
+        DatagramPriority::default()
+    }
+}
+
+/// Controls whether datagrams or stream data are written first when both are pending
+///
+/// When the congestion window limits how many packets can be sent, the data that is
+/// written first gets to use the available bytes. Datagrams that are not sent stay in
+/// the [`Sender`] queue, where they may be dropped to make room for newer datagrams.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DatagramPriority {
+    /// Stream data is written before datagrams
+    #[default]
+    Stream,
+    /// Datagrams are written before stream data
+    Datagram,
+    /// Datagrams and stream data take turns being written first in each packet
+    RoundRobin,
 }
 
 /// Information about the accepted connection for which the Sender/Receiver are being created.
@@ -27,6 +53,11 @@ pub struct ConnectionInfo {
     /// result in an error.
     pub max_datagram_payload: u64,
 
+    /// The size of the largest datagram that fits in a packet on the connection's path when the
+    /// connection is created. This grows as the path MTU increases, so senders should refresh it
+    /// with [`Packet::max_datagram_size`] on every transmission.
+    pub max_datagram_size: usize,
+
     /// The `waker` associated with this connection. When woken, the connection will check the
     /// interest in sending ([`Sender::has_transmission_interest`]), and send packets if necessary.
     ///
@@ -38,9 +69,10 @@ pub struct ConnectionInfo {
 
 impl ConnectionInfo {
     #[doc(hidden)]
-    pub fn new(max_datagram_payload: u64, waker: Waker) -> Self {
+    pub fn new(max_datagram_payload: u64, max_datagram_size: usize, waker: Waker) -> Self {
         ConnectionInfo {
             max_datagram_payload,
+            max_datagram_size,
             waker,
         }
     }
@@ -118,9 +150,58 @@ pub trait Packet {
 
     /// Returns whether or not datagrams are prioritized in this packet or not.
     ///
-    /// Datagrams get prioritized every other packet, which gives the application the best
-    /// chance to send a large datagram.
+    /// This is determined by the [`DatagramPriority`] returned by [`Endpoint::priority`].
+    /// With [`DatagramPriority::RoundRobin`], datagrams get prioritized every other packet,
+    /// which gives the application the best chance to send a large datagram.
     fn datagrams_prioritized(&self) -> bool;
+
+    /// Returns the size of the largest datagram that fits in a packet on the current path
+    ///
+    /// DATAGRAM frames cannot be fragmented across packets, so datagrams larger than this
+    /// value cannot be sent until the path MTU increases. Returns `usize::MAX` if the
+    /// packet does not know the limit.
+    #[inline]
+    fn max_datagram_size(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Notifies the connection that `count` datagrams totalling `len` bytes were dropped
+    /// without being sent
+    ///
+    /// This emits a `DatagramFrameDropped` event for the connection.
+    #[inline]
+    fn on_datagram_dropped(&mut self, count: usize, len: usize, reason: DropReason) {
+        let _ = (count, len, reason);
+    }
+}
+
+/// The reason a datagram was dropped by a [`Sender`] without being sent
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropReason {
+    /// The datagram was evicted from a full send queue to make room for a newer datagram.
+    ///
+    /// This usually happens when the congestion controller is limiting how quickly
+    /// datagrams can be sent.
+    QueueFull,
+    /// The datagram did not fit in the remaining space of the packet.
+    ExceedsPacketCapacity,
+    /// The datagram was larger than the peer's `max_datagram_frame_size`.
+    ExceedsPeerTransportLimits,
+}
+
+impl IntoEvent<event::builder::DatagramFrameDropReason> for DropReason {
+    #[inline]
+    fn into_event(self) -> event::builder::DatagramFrameDropReason {
+        use event::builder::DatagramFrameDropReason;
+        match self {
+            Self::QueueFull => DatagramFrameDropReason::QueueFull {},
+            Self::ExceedsPacketCapacity => DatagramFrameDropReason::ExceedsPacketCapacity {},
+            Self::ExceedsPeerTransportLimits => {
+                DatagramFrameDropReason::ExceedsPeerTransportLimits {}
+            }
+        }
+    }
 }
 
 #[non_exhaustive]
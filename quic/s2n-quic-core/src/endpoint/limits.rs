@@ -35,6 +35,13 @@ pub enum Outcome {
     /// Use `Outcome::close()` to construct this variant
     #[non_exhaustive]
     Close,
+
+    /// Silently drop the connection attempt since the endpoint is receiving connection attempts
+    /// faster than it is willing to accept them
+    ///
+    /// Use `Outcome::rate_limited()` to construct this variant
+    #[non_exhaustive]
+    RateLimited,
 }
 
 impl Outcome {
@@ -57,6 +64,11 @@ impl Outcome {
     pub fn close() -> Self {
         Self::Close
     }
+
+    /// Silently drop the connection attempt since the rate of connection attempts is too high
+    pub fn rate_limited() -> Self {
+        Self::RateLimited
+    }
 }
 
 /// A ConnectionAttempt holds information about the state of endpoint receiving a connect, along
@@ -119,4 +131,36 @@ pub trait Limiter: 'static + Send {
     /// }
     /// ```
     fn on_connection_attempt(&mut self, info: &ConnectionAttempt) -> Outcome;
+
+    /// Returns `true` if connection attempts from the `remote_address` should be processed
+    ///
+    /// This is called on receipt of a new Initial packet, before
+    /// [`on_connection_attempt`](Self::on_connection_attempt) and before any cryptographic work
+    /// is performed. Datagrams from denied addresses are silently dropped, which makes this a
+    /// cheap way to block known-bad address ranges. The address is not validated, so this
+    /// shouldn't be relied on to authenticate peers.
+    ///
+    /// ```rust
+    /// # mod s2n_quic { pub mod provider { pub mod endpoint_limits { pub use s2n_quic_core::endpoint::limits::*; } } }
+    /// use s2n_quic::provider::endpoint_limits::{Limiter, ConnectionAttempt, Outcome};
+    /// use s2n_quic_core::event::api::SocketAddress;
+    ///
+    /// struct MyEndpointLimits;
+    ///
+    /// impl Limiter for MyEndpointLimits {
+    ///    fn on_connection_attempt(&mut self, _info: &ConnectionAttempt) -> Outcome {
+    ///        Outcome::allow()
+    ///    }
+    ///
+    ///    fn accept_connection(&mut self, remote_address: &SocketAddress) -> bool {
+    ///        // deny the 192.0.2.0/24 documentation range
+    ///        !remote_address.ip().starts_with(&[192, 0, 2])
+    ///    }
+    /// }
+    /// ```
+    #[inline]
+    fn accept_connection(&mut self, remote_address: &SocketAddress) -> bool {
+        let _ = remote_address;
+        true
+    }
 }
@@ -132,6 +132,32 @@ pub trait Endpoint: 'static + Send + Sized {
     /// Sets the largest maximum transmission unit (MTU) that can be sent on a path
     fn set_max_mtu(&mut self, max_mtu: MaxMtu);
 
+    /// Sets the configuration used for discovering the maximum transmission unit (MTU) of a path
+    ///
+    /// By default, only the `max_mtu` of the configuration is applied.
+    #[inline]
+    fn set_mtu_config(&mut self, mtu_config: path::mtu::Config) {
+        self.set_max_mtu(mtu_config.max_mtu)
+    }
+
+    /// Sets how Explicit Congestion Notification (ECN) is used on each path
+    ///
+    /// By default, the mode is ignored.
+    #[inline]
+    fn set_ecn_mode(&mut self, ecn_mode: path::ecn::Mode) {
+        let _ = ecn_mode;
+    }
+
+    /// Shares the clock used by the IO provider with the endpoint
+    ///
+    /// This allows application calls, which run outside of the event loop, to timestamp the
+    /// requests they make.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn set_clock(&mut self, clock: crate::time::clock::Shared) {
+        let _ = clock;
+    }
+
     /// Returns the endpoint's event subscriber
     fn subscriber(&mut self) -> &mut Self::Subscriber;
 }
@@ -7,6 +7,11 @@ use core::{ops::RangeInclusive, time::Duration};
 mod generated;
 pub use generated::*;
 
+#[cfg(feature = "alloc")]
+mod sampled;
+#[cfg(feature = "alloc")]
+pub use sampled::{SampleInfo, Sampled};
+
 /// All event types which can be emitted from this library.
 pub trait Event: core::fmt::Debug {
     const NAME: &'static str;
@@ -57,6 +62,7 @@ ident_into_event!(
     Duration,
     bool,
     connection::Error,
+    connection::Statistics,
     endpoint::Location,
 );
 borrowed_into_event!([u8; 4], [u8; 16], [u8], [u32], [&'a [u8]]);
@@ -25,7 +25,10 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
-    pub struct ConnectionInfo {}
+    pub struct ConnectionInfo<'a> {
+        #[doc = " The destination connection ID of the first Initial packet sent by the client"]
+        pub original_destination_connection_id: ConnectionId<'a>,
+    }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
     pub struct TransportParameters<'a> {
@@ -199,6 +202,15 @@ pub mod api {
         HandshakeDone {},
         #[non_exhaustive]
         Datagram { len: u16 },
+        #[non_exhaustive]
+        AckFrequency {
+            sequence_number: u64,
+            ack_eliciting_threshold: u64,
+            request_max_ack_delay: u64,
+            reordering_threshold: u64,
+        },
+        #[non_exhaustive]
+        ImmediateAck {},
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
@@ -305,6 +317,19 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    pub enum DatagramFrameDropReason {
+        #[non_exhaustive]
+        #[doc = " The datagram was evicted from a full send queue to make room for a newer datagram."]
+        QueueFull {},
+        #[non_exhaustive]
+        #[doc = " The datagram did not fit in the remaining space of the packet."]
+        ExceedsPacketCapacity {},
+        #[non_exhaustive]
+        #[doc = " The datagram was larger than the peer's `max_datagram_frame_size`."]
+        ExceedsPeerTransportLimits {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub enum KeySpace {
         #[non_exhaustive]
         Initial {},
@@ -365,6 +390,12 @@ pub mod api {
         #[non_exhaustive]
         #[doc = " The received Initial packet was not transported in a datagram of at least 1200 bytes"]
         UndersizedInitialPacket { path: Path<'a> },
+        #[non_exhaustive]
+        #[doc = " The connection attempt was dropped since the endpoint exceeded its accept rate limit."]
+        RateLimited { path: Path<'a> },
+        #[non_exhaustive]
+        #[doc = " The connection attempt was dropped since the endpoint limits denied the source address."]
+        AddressFiltered { path: Path<'a> },
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
@@ -460,6 +491,20 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The phases of the handshake reported by the `HandshakeProgress` event"]
+    pub enum HandshakePhase {
+        #[non_exhaustive]
+        #[doc = " The first Initial packet was sent"]
+        InitialSent {},
+        #[non_exhaustive]
+        #[doc = " The first Handshake packet was received from the peer"]
+        HandshakeReceived {},
+        #[non_exhaustive]
+        #[doc = " The handshake has been confirmed"]
+        HandshakeConfirmed {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " The source that caused a congestion event"]
     pub enum CongestionSource {
         #[non_exhaustive]
@@ -513,6 +558,44 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The reason the congestion window was updated"]
+    pub enum CongestionWindowUpdatedCause {
+        #[non_exhaustive]
+        #[doc = " The congestion window grew while in Slow Start"]
+        SlowStart {},
+        #[non_exhaustive]
+        #[doc = " The congestion window grew at a reduced rate while in HyStart++ Conservative Slow Start"]
+        #[doc = ""]
+        #[doc = " Conservative Slow Start is entered after the round trip time increased during"]
+        #[doc = " Slow Start, and precedes an exit to Congestion Avoidance if the increase persists."]
+        ConservativeSlowStart {},
+        #[non_exhaustive]
+        #[doc = " The congestion window was adjusted while in Congestion Avoidance"]
+        #[doc = ""]
+        #[doc = " With the BBR congestion controller, this cause is used for any adjustment"]
+        #[doc = " made after exiting the Startup state."]
+        CongestionAvoidance {},
+        #[non_exhaustive]
+        #[doc = " The congestion window was reduced because a packet was determined lost"]
+        PacketLoss {},
+        #[non_exhaustive]
+        #[doc = " The congestion window was reset to the minimum window due to persistent congestion"]
+        PersistentCongestion {},
+        #[non_exhaustive]
+        #[doc = " An Explicit Congestion Notification: Congestion Experienced marking was received"]
+        Ecn {},
+        #[non_exhaustive]
+        #[doc = " The congestion window was scaled to account for an updated MTU"]
+        MtuUpdate {},
+        #[non_exhaustive]
+        #[doc = " The congestion window was updated due to a reason other than those above"]
+        #[doc = ""]
+        #[doc = " With the BBR congestion controller, this cause is used when the congestion window"]
+        #[doc = " is restored after restarting from idle."]
+        Other {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " The reason the MTU was updated"]
     pub enum MtuUpdatedCause {
         #[non_exhaustive]
@@ -527,6 +610,17 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The flow control limit that blocked the local endpoint from sending data"]
+    pub enum FlowControlScope {
+        #[non_exhaustive]
+        #[doc = " The connection limit advertised by the peer in MAX_DATA frames"]
+        Connection {},
+        #[non_exhaustive]
+        #[doc = " The stream limit advertised by the peer in MAX_STREAM_DATA frames"]
+        Stream { id: u64 },
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub enum BbrState {
         #[non_exhaustive]
         Startup {},
@@ -664,6 +758,19 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Persistent congestion was declared and the congestion window was collapsed"]
+    pub struct PersistentCongestion<'a> {
+        pub path: Path<'a>,
+        #[doc = " The length of the period in which all ack-eliciting packets were lost"]
+        pub duration: Duration,
+        #[doc = " The congestion window after persistent congestion was declared"]
+        pub congestion_window: u32,
+    }
+    impl<'a> Event for PersistentCongestion<'a> {
+        const NAME: &'static str = "recovery:persistent_congestion";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Events related to ACK processing"]
     #[deprecated(note = "use on_rx_ack_range_dropped event instead")]
     #[allow(deprecated)]
@@ -758,14 +865,34 @@ pub mod api {
     #[derive(Clone, Debug)]
     #[non_exhaustive]
     #[doc = " Connection closed"]
+    #[doc = ""]
+    #[doc = " This is emitted for both graceful and abrupt closes and summarizes the lifetime of the"]
+    #[doc = " connection."]
     pub struct ConnectionClosed {
         pub error: crate::connection::Error,
+        #[doc = " The amount of time since the connection was created"]
+        pub duration: Duration,
+        #[doc = " The statistics of the connection at the time it was closed"]
+        pub statistics: crate::connection::Statistics,
     }
     impl Event for ConnectionClosed {
         const NAME: &'static str = "connectivity:connection_closed";
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A graceful close finished flushing the connection or gave up after its timeout"]
+    #[doc = ""]
+    #[doc = " This is emitted before `ConnectionClosed` so subscribers can tell graceful closes"]
+    #[doc = " apart from abrupt ones."]
+    pub struct GracefulClose {
+        #[doc = " Set if the timeout elapsed before all of the outstanding stream data was flushed"]
+        pub timed_out: bool,
+    }
+    impl Event for GracefulClose {
+        const NAME: &'static str = "connectivity:graceful_close";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Duplicate packet received"]
     pub struct DuplicatePacket<'a> {
         pub packet_header: PacketHeader,
@@ -821,6 +948,19 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Application datagrams were dropped by the connection before they were sent"]
+    pub struct DatagramFrameDropped {
+        #[doc = " The number of datagrams that were dropped"]
+        pub count: usize,
+        #[doc = " The total number of bytes in the dropped datagrams"]
+        pub len: usize,
+        pub reason: DatagramFrameDropReason,
+    }
+    impl Event for DatagramFrameDropped {
+        const NAME: &'static str = "transport:datagram_frame_dropped";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " ConnectionId updated"]
     pub struct ConnectionIdUpdated<'a> {
         pub path_id: u64,
@@ -834,6 +974,39 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A new connection ID was issued to the peer"]
+    pub struct ConnectionIdIssued<'a> {
+        pub sequence_number: u64,
+        pub connection_id: ConnectionId<'a>,
+    }
+    impl<'a> Event for ConnectionIdIssued<'a> {
+        const NAME: &'static str = "connectivity:connection_id_issued";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The peer retired a connection ID that was issued to it"]
+    pub struct ConnectionIdRetired<'a> {
+        pub sequence_number: u64,
+        pub connection_id: ConnectionId<'a>,
+    }
+    impl<'a> Event for ConnectionIdRetired<'a> {
+        const NAME: &'static str = "connectivity:connection_id_retired";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The local endpoint has data to send but is blocked by the peer's flow control limits"]
+    #[doc = ""]
+    #[doc = " This is emitted each time a DATA_BLOCKED or STREAM_DATA_BLOCKED frame is sent to the peer."]
+    pub struct FlowControlBlocked {
+        pub scope: FlowControlScope,
+        #[doc = " The flow control limit, in bytes, that blocked the local endpoint"]
+        pub window: u64,
+    }
+    impl Event for FlowControlBlocked {
+        const NAME: &'static str = "transport:flow_control_blocked";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct EcnStateChanged<'a> {
         pub path: Path<'a>,
         pub state: EcnState,
@@ -851,6 +1024,24 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The peer's address changed without it changing its connection ID, which usually means a"]
+    #[doc = " NAT rebinding occurred"]
+    #[doc = ""]
+    #[doc = " This is emitted once validation of the new path finishes. `path` has the same id as"]
+    #[doc = " the `MtuUpdated` event with a `NewPath` cause that was emitted when the path was created."]
+    pub struct NatRebindingDetected<'a> {
+        #[doc = " The path created for the peer's new address"]
+        pub path: Path<'a>,
+        #[doc = " The address the peer was using before the rebinding"]
+        pub previous_remote_addr: SocketAddress<'a>,
+        #[doc = " Set if the peer's new address was validated"]
+        pub validated: bool,
+    }
+    impl<'a> Event for NatRebindingDetected<'a> {
+        const NAME: &'static str = "connectivity:nat_rebinding_detected";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct HandshakeStatusUpdated {
         pub status: HandshakeStatus,
     }
@@ -921,6 +1112,24 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " MTU probing for the path has concluded"]
+    #[doc = ""]
+    #[doc = " The MTU is not expected to change unless a black hole is detected, after which"]
+    #[doc = " probing is restarted and this event will be emitted again once it concludes."]
+    pub struct MtuProbingComplete {
+        pub path_id: u64,
+        #[doc = " The MTU confirmed for the path"]
+        pub mtu: u16,
+        #[doc = " The largest MTU that was probed for"]
+        pub max_probed_mtu: u16,
+        #[doc = " The number of MTU probes that were sent"]
+        pub probes_sent: u32,
+    }
+    impl Event for MtuProbingComplete {
+        const NAME: &'static str = "connectivity:mtu_probing_complete";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " The slow start congestion controller state has been exited"]
     pub struct SlowStartExited {
         pub path_id: u64,
@@ -932,6 +1141,21 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The congestion window has been updated"]
+    pub struct CongestionWindowUpdated {
+        pub path_id: u64,
+        pub cause: CongestionWindowUpdatedCause,
+        #[doc = " The congestion window before the update"]
+        pub old_congestion_window: u32,
+        #[doc = " The congestion window after the update"]
+        pub new_congestion_window: u32,
+        pub bytes_in_flight: u32,
+    }
+    impl Event for CongestionWindowUpdated {
+        const NAME: &'static str = "recovery:congestion_window_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " A new delivery rate sample has been generated"]
     #[doc = " Note: This event is only recorded for congestion controllers that support"]
     #[doc = "       bandwidth estimates, such as BBR"]
@@ -966,6 +1190,85 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The handshake has reached a new phase"]
+    #[doc = ""]
+    #[doc = " Each phase is reported once and in order."]
+    pub struct HandshakeProgress {
+        pub phase: HandshakePhase,
+        #[doc = " The time elapsed since the connection was created"]
+        pub elapsed: Duration,
+    }
+    impl Event for HandshakeProgress {
+        const NAME: &'static str = "connectivity:handshake_progress";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The handshake has been confirmed"]
+    #[doc = ""]
+    #[doc = " This is emitted after the `HandshakeConfirmed` phase is reported by `HandshakeProgress`."]
+    pub struct HandshakeComplete {
+        #[doc = " The time elapsed between the connection being created and the handshake being confirmed"]
+        pub duration: Duration,
+        #[doc = " Set if 0-RTT keys were negotiated during the handshake"]
+        pub zero_rtt: bool,
+        #[doc = " Set if the TLS provider resumed a previous session"]
+        pub resumed: bool,
+    }
+    impl Event for HandshakeComplete {
+        const NAME: &'static str = "connectivity:handshake_complete";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The 1-RTT keys were updated"]
+    #[doc = ""]
+    #[doc = " This is emitted by both peers once the update is complete, regardless of which side"]
+    #[doc = " initiated it."]
+    pub struct KeyUpdated {
+        #[doc = " The key phase of the updated keys"]
+        pub key_phase: u8,
+        #[doc = " The number of packets that were sent with the previous keys"]
+        pub packets_sent: u64,
+    }
+    impl Event for KeyUpdated {
+        const NAME: &'static str = "security:key_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " None of the PATH_CHALLENGE frames sent on a path were answered before the validation"]
+    #[doc = " timeout expired"]
+    #[doc = ""]
+    #[doc = " If the path was active, the connection falls back to the last validated path."]
+    pub struct PathValidationFailed<'a> {
+        #[doc = " The path that failed validation"]
+        pub path: Path<'a>,
+        #[doc = " The number of PATH_CHALLENGE frames sent on the path"]
+        pub attempts: u8,
+        #[doc = " The path the connection fell back to, if the failed path was active"]
+        pub fallback_path: Option<Path<'a>>,
+    }
+    impl<'a> Event for PathValidationFailed<'a> {
+        const NAME: &'static str = "connectivity:path_validation_failed";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The rate at which acknowledgements are sent for received packets has changed"]
+    #[doc = ""]
+    #[doc = " This is emitted when an ACK_FREQUENCY frame from the peer is applied."]
+    pub struct AckFrequencyUpdated {
+        #[doc = " The sequence number of the ACK_FREQUENCY frame that was applied"]
+        pub sequence_number: u64,
+        #[doc = " The number of ack-eliciting packets that are received before an acknowledgement is sent"]
+        pub ack_eliciting_threshold: u64,
+        #[doc = " The maximum amount of time that an acknowledgement is delayed"]
+        pub max_ack_delay: Duration,
+        #[doc = " The number of packets that can be reordered before an acknowledgement is sent immediately"]
+        pub reordering_threshold: u64,
+    }
+    impl Event for AckFrequencyUpdated {
+        const NAME: &'static str = "recovery:ack_frequency_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " QUIC version"]
     pub struct VersionInformation<'a> {
         pub server_versions: &'a [u32],
@@ -1038,6 +1341,24 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A Retry packet was sent to validate the address of a peer attempting a connection"]
+    pub struct RetrySent<'a> {
+        pub remote_addr: SocketAddress<'a>,
+    }
+    impl<'a> Event for RetrySent<'a> {
+        const NAME: &'static str = "transport:retry_sent";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A packet was dropped by the endpoint before it was associated with a connection"]
+    pub struct EndpointPacketDropped<'a> {
+        pub reason: PacketDropReason<'a>,
+    }
+    impl<'a> Event for EndpointPacketDropped<'a> {
+        const NAME: &'static str = "transport:packet_dropped";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Emitted when the platform sends at least one packet"]
     pub struct PlatformTx {
         #[doc = " The number of packets sent"]
@@ -1127,6 +1448,24 @@ pub mod api {
         #[non_exhaustive]
         #[doc = " Emitted when the maximum transmission unit is configured"]
         MaxMtu { mtu: u16 },
+        #[non_exhaustive]
+        #[doc = " Emitted when the socket send buffer is configured"]
+        SendBufferSize {
+            #[doc = " The size of the send buffer granted by the operating system, in bytes"]
+            #[doc = ""]
+            #[doc = " This value may differ from the requested size since the operating system can adjust or"]
+            #[doc = " clamp it."]
+            size: usize,
+        },
+        #[non_exhaustive]
+        #[doc = " Emitted when the socket receive buffer is configured"]
+        ReceiveBufferSize {
+            #[doc = " The size of the receive buffer granted by the operating system, in bytes"]
+            #[doc = ""]
+            #[doc = " This value may differ from the requested size since the operating system can adjust or"]
+            #[doc = " clamp it."]
+            size: usize,
+        },
     }
     impl<'a> IntoEvent<builder::PreferredAddress<'a>>
         for &'a crate::transport::parameters::PreferredAddress
@@ -1494,6 +1833,23 @@ pub mod api {
             }
         }
     }
+    impl IntoEvent<builder::Frame> for &crate::frame::ImmediateAck {
+        #[inline]
+        fn into_event(self) -> builder::Frame {
+            builder::Frame::ImmediateAck {}
+        }
+    }
+    impl IntoEvent<builder::Frame> for &crate::frame::AckFrequency {
+        #[inline]
+        fn into_event(self) -> builder::Frame {
+            builder::Frame::AckFrequency {
+                sequence_number: self.sequence_number.as_u64(),
+                ack_eliciting_threshold: self.ack_eliciting_threshold.as_u64(),
+                request_max_ack_delay: self.request_max_ack_delay.as_u64(),
+                reordering_threshold: self.reordering_threshold.as_u64(),
+            }
+        }
+    }
     impl IntoEvent<builder::StreamType> for &crate::stream::StreamType {
         #[inline]
         fn into_event(self) -> builder::StreamType {
@@ -1761,6 +2117,21 @@ pub mod tracing {
             tracing :: event ! (target : "congestion" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , source = tracing :: field :: debug (source));
         }
         #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::PersistentCongestion,
+        ) {
+            let id = context.id();
+            let api::PersistentCongestion {
+                path,
+                duration,
+                congestion_window,
+            } = event;
+            tracing :: event ! (target : "persistent_congestion" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , duration = tracing :: field :: debug (duration) , congestion_window = tracing :: field :: debug (congestion_window));
+        }
+        #[inline]
         #[allow(deprecated)]
         fn on_ack_processed(
             &mut self,
@@ -1873,8 +2244,23 @@ pub mod tracing {
             event: &api::ConnectionClosed,
         ) {
             let id = context.id();
-            let api::ConnectionClosed { error } = event;
-            tracing :: event ! (target : "connection_closed" , parent : id , tracing :: Level :: DEBUG , error = tracing :: field :: debug (error));
+            let api::ConnectionClosed {
+                error,
+                duration,
+                statistics,
+            } = event;
+            tracing :: event ! (target : "connection_closed" , parent : id , tracing :: Level :: DEBUG , error = tracing :: field :: debug (error) , duration = tracing :: field :: debug (duration) , statistics = tracing :: field :: debug (statistics));
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::GracefulClose,
+        ) {
+            let id = context.id();
+            let api::GracefulClose { timed_out } = event;
+            tracing :: event ! (target : "graceful_close" , parent : id , tracing :: Level :: DEBUG , timed_out = tracing :: field :: debug (timed_out));
         }
         #[inline]
         fn on_duplicate_packet(
@@ -1938,6 +2324,17 @@ pub mod tracing {
             tracing :: event ! (target : "datagram_dropped" , parent : id , tracing :: Level :: DEBUG , len = tracing :: field :: debug (len) , reason = tracing :: field :: debug (reason));
         }
         #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::DatagramFrameDropped,
+        ) {
+            let id = context.id();
+            let api::DatagramFrameDropped { count, len, reason } = event;
+            tracing :: event ! (target : "datagram_frame_dropped" , parent : id , tracing :: Level :: DEBUG , count = tracing :: field :: debug (count) , len = tracing :: field :: debug (len) , reason = tracing :: field :: debug (reason));
+        }
+        #[inline]
         fn on_connection_id_updated(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -1954,6 +2351,45 @@ pub mod tracing {
             tracing :: event ! (target : "connection_id_updated" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , cid_consumer = tracing :: field :: debug (cid_consumer) , previous = tracing :: field :: debug (previous) , current = tracing :: field :: debug (current));
         }
         #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdIssued,
+        ) {
+            let id = context.id();
+            let api::ConnectionIdIssued {
+                sequence_number,
+                connection_id,
+            } = event;
+            tracing :: event ! (target : "connection_id_issued" , parent : id , tracing :: Level :: DEBUG , sequence_number = tracing :: field :: debug (sequence_number) , connection_id = tracing :: field :: debug (connection_id));
+        }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdRetired,
+        ) {
+            let id = context.id();
+            let api::ConnectionIdRetired {
+                sequence_number,
+                connection_id,
+            } = event;
+            tracing :: event ! (target : "connection_id_retired" , parent : id , tracing :: Level :: DEBUG , sequence_number = tracing :: field :: debug (sequence_number) , connection_id = tracing :: field :: debug (connection_id));
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::FlowControlBlocked,
+        ) {
+            let id = context.id();
+            let api::FlowControlBlocked { scope, window } = event;
+            tracing :: event ! (target : "flow_control_blocked" , parent : id , tracing :: Level :: DEBUG , scope = tracing :: field :: debug (scope) , window = tracing :: field :: debug (window));
+        }
+        #[inline]
         fn on_ecn_state_changed(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -1976,6 +2412,21 @@ pub mod tracing {
             tracing :: event ! (target : "connection_migration_denied" , parent : id , tracing :: Level :: DEBUG , reason = tracing :: field :: debug (reason));
         }
         #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::NatRebindingDetected,
+        ) {
+            let id = context.id();
+            let api::NatRebindingDetected {
+                path,
+                previous_remote_addr,
+                validated,
+            } = event;
+            tracing :: event ! (target : "nat_rebinding_detected" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , previous_remote_addr = tracing :: field :: debug (previous_remote_addr) , validated = tracing :: field :: debug (validated));
+        }
+        #[inline]
         fn on_handshake_status_updated(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -2072,6 +2523,22 @@ pub mod tracing {
             tracing :: event ! (target : "mtu_updated" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , mtu = tracing :: field :: debug (mtu) , cause = tracing :: field :: debug (cause));
         }
         #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::MtuProbingComplete,
+        ) {
+            let id = context.id();
+            let api::MtuProbingComplete {
+                path_id,
+                mtu,
+                max_probed_mtu,
+                probes_sent,
+            } = event;
+            tracing :: event ! (target : "mtu_probing_complete" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , mtu = tracing :: field :: debug (mtu) , max_probed_mtu = tracing :: field :: debug (max_probed_mtu) , probes_sent = tracing :: field :: debug (probes_sent));
+        }
+        #[inline]
         fn on_slow_start_exited(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -2087,6 +2554,23 @@ pub mod tracing {
             tracing :: event ! (target : "slow_start_exited" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , cause = tracing :: field :: debug (cause) , congestion_window = tracing :: field :: debug (congestion_window));
         }
         #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::CongestionWindowUpdated,
+        ) {
+            let id = context.id();
+            let api::CongestionWindowUpdated {
+                path_id,
+                cause,
+                old_congestion_window,
+                new_congestion_window,
+                bytes_in_flight,
+            } = event;
+            tracing :: event ! (target : "congestion_window_updated" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , cause = tracing :: field :: debug (cause) , old_congestion_window = tracing :: field :: debug (old_congestion_window) , new_congestion_window = tracing :: field :: debug (new_congestion_window) , bytes_in_flight = tracing :: field :: debug (bytes_in_flight));
+        }
+        #[inline]
         fn on_delivery_rate_sampled(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -2128,37 +2612,108 @@ pub mod tracing {
             tracing :: event ! (target : "bbr_state_changed" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , state = tracing :: field :: debug (state));
         }
         #[inline]
-        fn on_version_information(
+        fn on_handshake_progress(
             &mut self,
-            meta: &api::EndpointMeta,
-            event: &api::VersionInformation,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::HandshakeProgress,
         ) {
-            let parent = match meta.endpoint_type {
-                api::EndpointType::Client {} => self.client.id(),
-                api::EndpointType::Server {} => self.server.id(),
-            };
-            let api::VersionInformation {
-                server_versions,
-                client_versions,
-                chosen_version,
-            } = event;
-            tracing :: event ! (target : "version_information" , parent : parent , tracing :: Level :: DEBUG , server_versions = tracing :: field :: debug (server_versions) , client_versions = tracing :: field :: debug (client_versions) , chosen_version = tracing :: field :: debug (chosen_version));
+            let id = context.id();
+            let api::HandshakeProgress { phase, elapsed } = event;
+            tracing :: event ! (target : "handshake_progress" , parent : id , tracing :: Level :: DEBUG , phase = tracing :: field :: debug (phase) , elapsed = tracing :: field :: debug (elapsed));
         }
         #[inline]
-        fn on_endpoint_packet_sent(
+        fn on_handshake_complete(
             &mut self,
-            meta: &api::EndpointMeta,
-            event: &api::EndpointPacketSent,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::HandshakeComplete,
         ) {
-            let parent = match meta.endpoint_type {
-                api::EndpointType::Client {} => self.client.id(),
-                api::EndpointType::Server {} => self.server.id(),
-            };
-            let api::EndpointPacketSent { packet_header } = event;
-            tracing :: event ! (target : "endpoint_packet_sent" , parent : parent , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
+            let id = context.id();
+            let api::HandshakeComplete {
+                duration,
+                zero_rtt,
+                resumed,
+            } = event;
+            tracing :: event ! (target : "handshake_complete" , parent : id , tracing :: Level :: DEBUG , duration = tracing :: field :: debug (duration) , zero_rtt = tracing :: field :: debug (zero_rtt) , resumed = tracing :: field :: debug (resumed));
         }
         #[inline]
-        fn on_endpoint_packet_received(
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::KeyUpdated,
+        ) {
+            let id = context.id();
+            let api::KeyUpdated {
+                key_phase,
+                packets_sent,
+            } = event;
+            tracing :: event ! (target : "key_updated" , parent : id , tracing :: Level :: DEBUG , key_phase = tracing :: field :: debug (key_phase) , packets_sent = tracing :: field :: debug (packets_sent));
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::PathValidationFailed,
+        ) {
+            let id = context.id();
+            let api::PathValidationFailed {
+                path,
+                attempts,
+                fallback_path,
+            } = event;
+            tracing :: event ! (target : "path_validation_failed" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , attempts = tracing :: field :: debug (attempts) , fallback_path = tracing :: field :: debug (fallback_path));
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::AckFrequencyUpdated,
+        ) {
+            let id = context.id();
+            let api::AckFrequencyUpdated {
+                sequence_number,
+                ack_eliciting_threshold,
+                max_ack_delay,
+                reordering_threshold,
+            } = event;
+            tracing :: event ! (target : "ack_frequency_updated" , parent : id , tracing :: Level :: DEBUG , sequence_number = tracing :: field :: debug (sequence_number) , ack_eliciting_threshold = tracing :: field :: debug (ack_eliciting_threshold) , max_ack_delay = tracing :: field :: debug (max_ack_delay) , reordering_threshold = tracing :: field :: debug (reordering_threshold));
+        }
+        #[inline]
+        fn on_version_information(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::VersionInformation,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::VersionInformation {
+                server_versions,
+                client_versions,
+                chosen_version,
+            } = event;
+            tracing :: event ! (target : "version_information" , parent : parent , tracing :: Level :: DEBUG , server_versions = tracing :: field :: debug (server_versions) , client_versions = tracing :: field :: debug (client_versions) , chosen_version = tracing :: field :: debug (chosen_version));
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketSent,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointPacketSent { packet_header } = event;
+            tracing :: event ! (target : "endpoint_packet_sent" , parent : parent , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
             &mut self,
             meta: &api::EndpointMeta,
             event: &api::EndpointPacketReceived,
@@ -2223,6 +2778,28 @@ pub mod tracing {
             tracing :: event ! (target : "endpoint_connection_attempt_failed" , parent : parent , tracing :: Level :: DEBUG , error = tracing :: field :: debug (error));
         }
         #[inline]
+        fn on_retry_sent(&mut self, meta: &api::EndpointMeta, event: &api::RetrySent) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::RetrySent { remote_addr } = event;
+            tracing :: event ! (target : "retry_sent" , parent : parent , tracing :: Level :: DEBUG , remote_addr = tracing :: field :: debug (remote_addr));
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketDropped,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointPacketDropped { reason } = event;
+            tracing :: event ! (target : "endpoint_packet_dropped" , parent : parent , tracing :: Level :: DEBUG , reason = tracing :: field :: debug (reason));
+        }
+        #[inline]
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             let parent = match meta.endpoint_type {
                 api::EndpointType::Client {} => self.client.id(),
@@ -2307,2186 +2884,8620 @@ pub mod tracing {
         }
     }
 }
-pub mod builder {
-    use super::*;
-    #[derive(Clone, Debug)]
-    pub struct ConnectionMeta {
-        pub endpoint_type: crate::endpoint::Type,
-        pub id: u64,
-        pub timestamp: crate::time::Timestamp,
+#[cfg(feature = "event-json")]
+pub mod json {
+    #![doc = r" This module contains a subscriber which writes events as newline-delimited JSON"]
+    use super::api;
+    use core::fmt::{self, Write as _};
+    use std::{io, string::String};
+    #[doc = r" Writes each event as a line of JSON to the provided writer"]
+    #[doc = r""]
+    #[doc = r" Each line contains the snake case `type` of the event, the `endpoint` type, the"]
+    #[doc = r" `connection_id` for connection-level events and the event `fields`. Integer,"]
+    #[doc = r" floating point and boolean fields are written as JSON values and the remaining"]
+    #[doc = r" fields as strings holding their `Debug` representation."]
+    #[doc = r""]
+    #[doc = r" The events are written synchronously from the endpoint task so the writer"]
+    #[doc = r" must be fast, for example by buffering or forwarding the lines to another"]
+    #[doc = r" thread. A slow writer delays the processing of every connection on the"]
+    #[doc = r" endpoint. Errors returned by the writer are ignored."]
+    #[derive(Debug)]
+    pub struct Subscriber<W> {
+        writer: W,
+        line: String,
+        value: String,
     }
-    impl IntoEvent<api::ConnectionMeta> for ConnectionMeta {
-        #[inline]
-        fn into_event(self) -> api::ConnectionMeta {
-            let ConnectionMeta {
-                endpoint_type,
-                id,
-                timestamp,
-            } = self;
-            api::ConnectionMeta {
-                endpoint_type: endpoint_type.into_event(),
-                id: id.into_event(),
-                timestamp: timestamp.into_event(),
+    impl<W: io::Write> Subscriber<W> {
+        pub fn new(writer: W) -> Self {
+            Self {
+                writer,
+                line: String::new(),
+                value: String::new(),
             }
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct EndpointMeta {
-        pub endpoint_type: crate::endpoint::Type,
-        pub timestamp: crate::time::Timestamp,
-    }
-    impl IntoEvent<api::EndpointMeta> for EndpointMeta {
-        #[inline]
-        fn into_event(self) -> api::EndpointMeta {
-            let EndpointMeta {
-                endpoint_type,
-                timestamp,
-            } = self;
-            api::EndpointMeta {
-                endpoint_type: endpoint_type.into_event(),
-                timestamp: timestamp.into_event(),
+        #[doc = r" Returns the underlying writer"]
+        pub fn into_inner(self) -> W {
+            self.writer
+        }
+        fn write(
+            &mut self,
+            endpoint_type: &api::EndpointType,
+            connection_id: Option<u64>,
+            name: &str,
+            fields: &[(&str, Value)],
+        ) {
+            let endpoint_type = match endpoint_type {
+                api::EndpointType::Client {} => "client",
+                api::EndpointType::Server {} => "server",
+            };
+            let line = &mut self.line;
+            line.clear();
+            let _ = write!(
+                line,
+                "{{\"type\":\"{name}\",\"endpoint\":\"{endpoint_type}\""
+            );
+            if let Some(connection_id) = connection_id {
+                let _ = write!(line, ",\"connection_id\":{connection_id}");
+            }
+            line.push_str(",\"fields\":{");
+            for (idx, (key, value)) in fields.iter().enumerate() {
+                if idx > 0 {
+                    line.push(',');
+                }
+                let _ = write!(line, "\"{key}\":");
+                match value {
+                    Value::Number(value) => {
+                        let _ = write!(line, "{value}");
+                    }
+                    Value::Float(value) if !value.is_finite() => line.push_str("null"),
+                    Value::Float(value) => {
+                        let _ = write!(line, "{value}");
+                    }
+                    Value::Bool(value) => {
+                        let _ = write!(line, "{value}");
+                    }
+                    Value::Debug(value) => {
+                        self.value.clear();
+                        let _ = write!(self.value, "{value:?}");
+                        line.push('"');
+                        escape(line, &self.value);
+                        line.push('"');
+                    }
+                }
+            }
+            line.push_str("}}\n");
+            let _ = self.writer.write_all(line.as_bytes());
+        }
+    }
+    #[doc = r" The value of an event field"]
+    enum Value<'a> {
+        Number(&'a dyn fmt::Display),
+        Float(f64),
+        Bool(bool),
+        #[doc = r" Written as a string holding the `Debug` representation of the value"]
+        Debug(&'a dyn fmt::Debug),
+    }
+    #[doc = r" Escapes a string to be included in a JSON string"]
+    fn escape(output: &mut String, value: &str) {
+        for c in value.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                c if c.is_control() => {
+                    let _ = write!(output, "\\u{:04x}", c as u32);
+                }
+                c => output.push(c),
             }
         }
     }
-    #[derive(Clone, Debug)]
-    pub struct ConnectionInfo {}
-    impl IntoEvent<api::ConnectionInfo> for ConnectionInfo {
+    impl<W: 'static + io::Write + Send> super::Subscriber for Subscriber<W> {
+        type ConnectionContext = ();
+        fn create_connection_context(
+            &mut self,
+            _meta: &api::ConnectionMeta,
+            _info: &api::ConnectionInfo,
+        ) -> Self::ConnectionContext {
+        }
         #[inline]
-        fn into_event(self) -> api::ConnectionInfo {
-            let ConnectionInfo {} = self;
-            api::ConnectionInfo {}
+        fn on_application_protocol_information(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ApplicationProtocolInformation,
+        ) {
+            let api::ApplicationProtocolInformation {
+                chosen_application_protocol,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "application_protocol_information",
+                &[(
+                    "chosen_application_protocol",
+                    Value::Debug(chosen_application_protocol),
+                )],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct TransportParameters<'a> {
-        pub original_destination_connection_id: Option<ConnectionId<'a>>,
-        pub initial_source_connection_id: Option<ConnectionId<'a>>,
-        pub retry_source_connection_id: Option<ConnectionId<'a>>,
-        pub stateless_reset_token: Option<&'a [u8]>,
-        pub preferred_address: Option<PreferredAddress<'a>>,
-        pub migration_support: bool,
-        pub max_idle_timeout: Duration,
-        pub ack_delay_exponent: u8,
-        pub max_ack_delay: Duration,
-        pub max_udp_payload_size: u64,
-        pub active_connection_id_limit: u64,
-        pub initial_max_stream_data_bidi_local: u64,
-        pub initial_max_stream_data_bidi_remote: u64,
-        pub initial_max_stream_data_uni: u64,
-        pub initial_max_streams_bidi: u64,
-        pub initial_max_streams_uni: u64,
-        pub max_datagram_frame_size: u64,
-    }
-    impl<'a> IntoEvent<api::TransportParameters<'a>> for TransportParameters<'a> {
         #[inline]
-        fn into_event(self) -> api::TransportParameters<'a> {
-            let TransportParameters {
-                original_destination_connection_id,
-                initial_source_connection_id,
-                retry_source_connection_id,
-                stateless_reset_token,
-                preferred_address,
-                migration_support,
-                max_idle_timeout,
-                ack_delay_exponent,
-                max_ack_delay,
-                max_udp_payload_size,
-                active_connection_id_limit,
-                initial_max_stream_data_bidi_local,
-                initial_max_stream_data_bidi_remote,
-                initial_max_stream_data_uni,
-                initial_max_streams_bidi,
-                initial_max_streams_uni,
-                max_datagram_frame_size,
-            } = self;
-            api::TransportParameters {
-                original_destination_connection_id: original_destination_connection_id.into_event(),
-                initial_source_connection_id: initial_source_connection_id.into_event(),
-                retry_source_connection_id: retry_source_connection_id.into_event(),
-                stateless_reset_token: stateless_reset_token.into_event(),
-                preferred_address: preferred_address.into_event(),
-                migration_support: migration_support.into_event(),
-                max_idle_timeout: max_idle_timeout.into_event(),
-                ack_delay_exponent: ack_delay_exponent.into_event(),
-                max_ack_delay: max_ack_delay.into_event(),
-                max_udp_payload_size: max_udp_payload_size.into_event(),
-                active_connection_id_limit: active_connection_id_limit.into_event(),
-                initial_max_stream_data_bidi_local: initial_max_stream_data_bidi_local.into_event(),
-                initial_max_stream_data_bidi_remote: initial_max_stream_data_bidi_remote
-                    .into_event(),
-                initial_max_stream_data_uni: initial_max_stream_data_uni.into_event(),
-                initial_max_streams_bidi: initial_max_streams_bidi.into_event(),
-                initial_max_streams_uni: initial_max_streams_uni.into_event(),
-                max_datagram_frame_size: max_datagram_frame_size.into_event(),
-            }
+        fn on_server_name_information(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ServerNameInformation,
+        ) {
+            let api::ServerNameInformation { chosen_server_name } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "server_name_information",
+                &[("chosen_server_name", Value::Debug(chosen_server_name))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct PreferredAddress<'a> {
-        pub ipv4_address: Option<SocketAddress<'a>>,
-        pub ipv6_address: Option<SocketAddress<'a>>,
-        pub connection_id: ConnectionId<'a>,
-        pub stateless_reset_token: &'a [u8],
-    }
-    impl<'a> IntoEvent<api::PreferredAddress<'a>> for PreferredAddress<'a> {
         #[inline]
-        fn into_event(self) -> api::PreferredAddress<'a> {
-            let PreferredAddress {
-                ipv4_address,
-                ipv6_address,
-                connection_id,
-                stateless_reset_token,
-            } = self;
-            api::PreferredAddress {
-                ipv4_address: ipv4_address.into_event(),
-                ipv6_address: ipv6_address.into_event(),
-                connection_id: connection_id.into_event(),
-                stateless_reset_token: stateless_reset_token.into_event(),
-            }
+        fn on_packet_sent(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketSent,
+        ) {
+            let api::PacketSent {
+                packet_header,
+                packet_len,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "packet_sent",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("packet_len", Value::Number(packet_len)),
+                ],
+            );
         }
-    }
-    #[derive(Copy, Clone, Debug)]
-    pub struct Path<'a> {
-        pub local_addr: SocketAddress<'a>,
-        pub local_cid: ConnectionId<'a>,
-        pub remote_addr: SocketAddress<'a>,
-        pub remote_cid: ConnectionId<'a>,
-        pub id: u64,
-        pub is_active: bool,
-    }
-    impl<'a> IntoEvent<api::Path<'a>> for Path<'a> {
         #[inline]
-        fn into_event(self) -> api::Path<'a> {
-            let Path {
-                local_addr,
-                local_cid,
-                remote_addr,
-                remote_cid,
-                id,
-                is_active,
-            } = self;
-            api::Path {
-                local_addr: local_addr.into_event(),
-                local_cid: local_cid.into_event(),
-                remote_addr: remote_addr.into_event(),
-                remote_cid: remote_cid.into_event(),
-                id: id.into_event(),
-                is_active: is_active.into_event(),
-            }
+        fn on_packet_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketReceived,
+        ) {
+            let api::PacketReceived { packet_header } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "packet_received",
+                &[("packet_header", Value::Debug(packet_header))],
+            );
         }
-    }
-    #[derive(Copy, Clone, Debug)]
-    pub struct ConnectionId<'a> {
-        pub bytes: &'a [u8],
-    }
-    impl<'a> IntoEvent<api::ConnectionId<'a>> for ConnectionId<'a> {
         #[inline]
-        fn into_event(self) -> api::ConnectionId<'a> {
-            let ConnectionId { bytes } = self;
-            api::ConnectionId {
-                bytes: bytes.into_event(),
-            }
+        fn on_active_path_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ActivePathUpdated,
+        ) {
+            let api::ActivePathUpdated { previous, active } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "active_path_updated",
+                &[
+                    ("previous", Value::Debug(previous)),
+                    ("active", Value::Debug(active)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct EcnCounts {
-        #[doc = " A variable-length integer representing the total number of packets"]
-        #[doc = " received with the ECT(0) codepoint."]
-        pub ect_0_count: u64,
-        #[doc = " A variable-length integer representing the total number of packets"]
-        #[doc = " received with the ECT(1) codepoint."]
-        pub ect_1_count: u64,
-        #[doc = " A variable-length integer representing the total number of packets"]
-        #[doc = " received with the CE codepoint."]
-        pub ce_count: u64,
-    }
-    impl IntoEvent<api::EcnCounts> for EcnCounts {
         #[inline]
-        fn into_event(self) -> api::EcnCounts {
-            let EcnCounts {
-                ect_0_count,
-                ect_1_count,
-                ce_count,
-            } = self;
-            api::EcnCounts {
-                ect_0_count: ect_0_count.into_event(),
-                ect_1_count: ect_1_count.into_event(),
-                ce_count: ce_count.into_event(),
-            }
+        fn on_path_created(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathCreated,
+        ) {
+            let api::PathCreated { active, new } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "path_created",
+                &[("active", Value::Debug(active)), ("new", Value::Debug(new))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " A bandwidth delivery rate estimate with associated metadata"]
-    pub struct RateSample {
-        #[doc = " The length of the sampling interval"]
-        pub interval: Duration,
-        #[doc = " The amount of data in bytes marked as delivered over the sampling interval"]
-        pub delivered_bytes: u64,
-        #[doc = " The amount of data in bytes marked as lost over the sampling interval"]
-        pub lost_bytes: u64,
-        #[doc = " The number of packets marked as explicit congestion experienced over the sampling interval"]
-        pub ecn_ce_count: u64,
-        #[doc = " PacketInfo::is_app_limited from the most recent acknowledged packet"]
-        pub is_app_limited: bool,
-        #[doc = " PacketInfo::delivered_bytes from the most recent acknowledged packet"]
-        pub prior_delivered_bytes: u64,
-        #[doc = " PacketInfo::bytes_in_flight from the most recent acknowledged packet"]
-        pub bytes_in_flight: u32,
-        #[doc = " PacketInfo::lost_bytes from the most recent acknowledged packet"]
-        pub prior_lost_bytes: u64,
-        #[doc = " PacketInfo::ecn_ce_count from the most recent acknowledged packet"]
-        pub prior_ecn_ce_count: u64,
-        #[doc = " The delivery rate for this rate sample"]
-        pub delivery_rate_bytes_per_second: u64,
-    }
-    impl IntoEvent<api::RateSample> for RateSample {
         #[inline]
-        fn into_event(self) -> api::RateSample {
-            let RateSample {
-                interval,
-                delivered_bytes,
-                lost_bytes,
-                ecn_ce_count,
-                is_app_limited,
-                prior_delivered_bytes,
+        fn on_frame_sent(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FrameSent,
+        ) {
+            let api::FrameSent {
+                packet_header,
+                path_id,
+                frame,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "frame_sent",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path_id", Value::Number(path_id)),
+                    ("frame", Value::Debug(frame)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FrameReceived,
+        ) {
+            let api::FrameReceived {
+                packet_header,
+                path,
+                frame,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "frame_received",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path", Value::Debug(path)),
+                    ("frame", Value::Debug(frame)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketLost,
+        ) {
+            let api::PacketLost {
+                packet_header,
+                path,
+                bytes_lost,
+                is_mtu_probe,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "packet_lost",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path", Value::Debug(path)),
+                    ("bytes_lost", Value::Number(bytes_lost)),
+                    ("is_mtu_probe", Value::Bool(*is_mtu_probe)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RecoveryMetrics,
+        ) {
+            let api::RecoveryMetrics {
+                path,
+                min_rtt,
+                smoothed_rtt,
+                latest_rtt,
+                rtt_variance,
+                max_ack_delay,
+                pto_count,
+                congestion_window,
                 bytes_in_flight,
-                prior_lost_bytes,
-                prior_ecn_ce_count,
-                delivery_rate_bytes_per_second,
-            } = self;
-            api::RateSample {
-                interval: interval.into_event(),
-                delivered_bytes: delivered_bytes.into_event(),
-                lost_bytes: lost_bytes.into_event(),
-                ecn_ce_count: ecn_ce_count.into_event(),
-                is_app_limited: is_app_limited.into_event(),
-                prior_delivered_bytes: prior_delivered_bytes.into_event(),
-                bytes_in_flight: bytes_in_flight.into_event(),
-                prior_lost_bytes: prior_lost_bytes.into_event(),
-                prior_ecn_ce_count: prior_ecn_ce_count.into_event(),
-                delivery_rate_bytes_per_second: delivery_rate_bytes_per_second.into_event(),
-            }
+                congestion_limited,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "recovery_metrics",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("min_rtt", Value::Debug(min_rtt)),
+                    ("smoothed_rtt", Value::Debug(smoothed_rtt)),
+                    ("latest_rtt", Value::Debug(latest_rtt)),
+                    ("rtt_variance", Value::Debug(rtt_variance)),
+                    ("max_ack_delay", Value::Debug(max_ack_delay)),
+                    ("pto_count", Value::Number(pto_count)),
+                    ("congestion_window", Value::Number(congestion_window)),
+                    ("bytes_in_flight", Value::Number(bytes_in_flight)),
+                    ("congestion_limited", Value::Bool(*congestion_limited)),
+                ],
+            );
         }
-    }
-    #[derive(Copy, Clone, Debug)]
-    pub enum SocketAddress<'a> {
-        IpV4 { ip: &'a [u8; 4], port: u16 },
-        IpV6 { ip: &'a [u8; 16], port: u16 },
-    }
-    impl<'a> IntoEvent<api::SocketAddress<'a>> for SocketAddress<'a> {
         #[inline]
-        fn into_event(self) -> api::SocketAddress<'a> {
-            use api::SocketAddress::*;
-            match self {
-                Self::IpV4 { ip, port } => IpV4 {
-                    ip: ip.into_event(),
-                    port: port.into_event(),
-                },
-                Self::IpV6 { ip, port } => IpV6 {
-                    ip: ip.into_event(),
-                    port: port.into_event(),
-                },
-            }
+        fn on_congestion(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::Congestion,
+        ) {
+            let api::Congestion { path, source } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "congestion",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("source", Value::Debug(source)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum DuplicatePacketError {
-        #[doc = " The packet number was already received and is a duplicate."]
-        Duplicate,
-        #[doc = " The received packet number was outside the range of tracked packet numbers."]
-        #[doc = ""]
-        #[doc = " This can happen when packets are heavily delayed or reordered. Currently, the maximum"]
-        #[doc = " amount of reordering is limited to 128 packets. For example, if packet number `142`"]
-        #[doc = " is received, the allowed range would be limited to `14-142`. If an endpoint received"]
-        #[doc = " packet `< 14`, it would trigger this event."]
-        TooOld,
-    }
-    impl IntoEvent<api::DuplicatePacketError> for DuplicatePacketError {
         #[inline]
-        fn into_event(self) -> api::DuplicatePacketError {
-            use api::DuplicatePacketError::*;
-            match self {
-                Self::Duplicate => Duplicate {},
-                Self::TooOld => TooOld {},
-            }
+        fn on_persistent_congestion(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PersistentCongestion,
+        ) {
+            let api::PersistentCongestion {
+                path,
+                duration,
+                congestion_window,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "persistent_congestion",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("duration", Value::Debug(duration)),
+                    ("congestion_window", Value::Number(congestion_window)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum Frame {
-        Padding,
-        Ping,
-        Ack {
-            ecn_counts: Option<EcnCounts>,
-            largest_acknowledged: u64,
-            ack_range_count: u64,
-        },
-        ResetStream {
-            id: u64,
-            error_code: u64,
-            final_size: u64,
-        },
-        StopSending {
-            id: u64,
-            error_code: u64,
-        },
-        Crypto {
-            offset: u64,
-            len: u16,
-        },
-        NewToken,
-        Stream {
-            id: u64,
-            offset: u64,
-            len: u16,
-            is_fin: bool,
-        },
-        MaxData {
-            value: u64,
-        },
-        MaxStreamData {
-            stream_type: StreamType,
-            id: u64,
-            value: u64,
-        },
-        MaxStreams {
-            stream_type: StreamType,
-            value: u64,
-        },
-        DataBlocked {
-            data_limit: u64,
-        },
-        StreamDataBlocked {
-            stream_id: u64,
-            stream_data_limit: u64,
-        },
-        StreamsBlocked {
-            stream_type: StreamType,
-            stream_limit: u64,
-        },
-        NewConnectionId,
-        RetireConnectionId,
-        PathChallenge,
-        PathResponse,
-        ConnectionClose,
-        HandshakeDone,
-        Datagram {
-            len: u16,
-        },
-    }
-    impl IntoEvent<api::Frame> for Frame {
         #[inline]
-        fn into_event(self) -> api::Frame {
-            use api::Frame::*;
-            match self {
-                Self::Padding => Padding {},
-                Self::Ping => Ping {},
-                Self::Ack {
-                    ecn_counts,
-                    largest_acknowledged,
-                    ack_range_count,
-                } => Ack {
-                    ecn_counts: ecn_counts.into_event(),
-                    largest_acknowledged: largest_acknowledged.into_event(),
-                    ack_range_count: ack_range_count.into_event(),
-                },
-                Self::ResetStream {
-                    id,
-                    error_code,
-                    final_size,
-                } => ResetStream {
-                    id: id.into_event(),
-                    error_code: error_code.into_event(),
-                    final_size: final_size.into_event(),
-                },
-                Self::StopSending { id, error_code } => StopSending {
-                    id: id.into_event(),
-                    error_code: error_code.into_event(),
-                },
-                Self::Crypto { offset, len } => Crypto {
-                    offset: offset.into_event(),
-                    len: len.into_event(),
-                },
-                Self::NewToken => NewToken {},
-                Self::Stream {
-                    id,
-                    offset,
-                    len,
-                    is_fin,
-                } => Stream {
-                    id: id.into_event(),
-                    offset: offset.into_event(),
-                    len: len.into_event(),
-                    is_fin: is_fin.into_event(),
-                },
-                Self::MaxData { value } => MaxData {
-                    value: value.into_event(),
-                },
-                Self::MaxStreamData {
-                    stream_type,
-                    id,
-                    value,
-                } => MaxStreamData {
-                    stream_type: stream_type.into_event(),
-                    id: id.into_event(),
-                    value: value.into_event(),
-                },
-                Self::MaxStreams { stream_type, value } => MaxStreams {
-                    stream_type: stream_type.into_event(),
-                    value: value.into_event(),
-                },
-                Self::DataBlocked { data_limit } => DataBlocked {
-                    data_limit: data_limit.into_event(),
-                },
-                Self::StreamDataBlocked {
-                    stream_id,
-                    stream_data_limit,
-                } => StreamDataBlocked {
-                    stream_id: stream_id.into_event(),
-                    stream_data_limit: stream_data_limit.into_event(),
-                },
-                Self::StreamsBlocked {
-                    stream_type,
-                    stream_limit,
-                } => StreamsBlocked {
-                    stream_type: stream_type.into_event(),
-                    stream_limit: stream_limit.into_event(),
-                },
-                Self::NewConnectionId => NewConnectionId {},
-                Self::RetireConnectionId => RetireConnectionId {},
-                Self::PathChallenge => PathChallenge {},
-                Self::PathResponse => PathResponse {},
-                Self::ConnectionClose => ConnectionClose {},
-                Self::HandshakeDone => HandshakeDone {},
-                Self::Datagram { len } => Datagram {
-                    len: len.into_event(),
-                },
-            }
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckProcessed,
+        ) {
+            let api::AckProcessed { action, path } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "ack_processed",
+                &[
+                    ("action", Value::Debug(action)),
+                    ("path", Value::Debug(path)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum StreamType {
-        Bidirectional,
-        Unidirectional,
-    }
-    impl IntoEvent<api::StreamType> for StreamType {
         #[inline]
-        fn into_event(self) -> api::StreamType {
-            use api::StreamType::*;
-            match self {
-                Self::Bidirectional => Bidirectional {},
-                Self::Unidirectional => Unidirectional {},
-            }
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RxAckRangeDropped,
+        ) {
+            let api::RxAckRangeDropped {
+                path,
+                packet_number_range,
+                capacity,
+                stored_range,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "rx_ack_range_dropped",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("packet_number_range", Value::Debug(packet_number_range)),
+                    ("capacity", Value::Number(capacity)),
+                    ("stored_range", Value::Debug(stored_range)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum PacketHeader {
-        Initial { number: u64, version: u32 },
-        Handshake { number: u64, version: u32 },
-        ZeroRtt { number: u64, version: u32 },
-        OneRtt { number: u64 },
-        Retry { version: u32 },
-        VersionNegotiation,
-        StatelessReset,
-    }
-    impl IntoEvent<api::PacketHeader> for PacketHeader {
         #[inline]
-        fn into_event(self) -> api::PacketHeader {
-            use api::PacketHeader::*;
-            match self {
-                Self::Initial { number, version } => Initial {
-                    number: number.into_event(),
-                    version: version.into_event(),
-                },
-                Self::Handshake { number, version } => Handshake {
-                    number: number.into_event(),
-                    version: version.into_event(),
-                },
-                Self::ZeroRtt { number, version } => ZeroRtt {
-                    number: number.into_event(),
-                    version: version.into_event(),
-                },
-                Self::OneRtt { number } => OneRtt {
-                    number: number.into_event(),
-                },
-                Self::Retry { version } => Retry {
-                    version: version.into_event(),
-                },
-                Self::VersionNegotiation => VersionNegotiation {},
-                Self::StatelessReset => StatelessReset {},
-            }
+        fn on_ack_range_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckRangeReceived,
+        ) {
+            let api::AckRangeReceived {
+                packet_header,
+                path,
+                ack_range,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "ack_range_received",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path", Value::Debug(path)),
+                    ("ack_range", Value::Debug(ack_range)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum KeyType {
-        Initial,
-        Handshake,
-        ZeroRtt,
-        OneRtt { generation: u16 },
-    }
-    impl IntoEvent<api::KeyType> for KeyType {
         #[inline]
-        fn into_event(self) -> api::KeyType {
-            use api::KeyType::*;
-            match self {
-                Self::Initial => Initial {},
-                Self::Handshake => Handshake {},
-                Self::ZeroRtt => ZeroRtt {},
-                Self::OneRtt { generation } => OneRtt {
-                    generation: generation.into_event(),
-                },
-            }
+        fn on_ack_range_sent(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckRangeSent,
+        ) {
+            let api::AckRangeSent {
+                packet_header,
+                path_id,
+                ack_range,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "ack_range_sent",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path_id", Value::Number(path_id)),
+                    ("ack_range", Value::Debug(ack_range)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " A context from which the event is being emitted"]
-    #[doc = ""]
-    #[doc = " An event can occur in the context of an Endpoint or Connection"]
-    pub enum Subject {
-        Endpoint,
-        #[doc = " This maps to an internal connection id, which is a stable identifier across CID changes."]
-        Connection {
-            id: u64,
-        },
-    }
-    impl IntoEvent<api::Subject> for Subject {
         #[inline]
-        fn into_event(self) -> api::Subject {
-            use api::Subject::*;
-            match self {
-                Self::Endpoint => Endpoint {},
-                Self::Connection { id } => Connection {
-                    id: id.into_event(),
-                },
-            }
-        }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " An endpoint may be either a Server or a Client"]
-    pub enum EndpointType {
-        Server,
-        Client,
-    }
-    impl IntoEvent<api::EndpointType> for EndpointType {
-        #[inline]
-        fn into_event(self) -> api::EndpointType {
-            use api::EndpointType::*;
-            match self {
-                Self::Server => Server {},
-                Self::Client => Client {},
-            }
+        fn on_packet_dropped(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketDropped,
+        ) {
+            let api::PacketDropped { reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "packet_dropped",
+                &[("reason", Value::Debug(reason))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum DatagramDropReason {
-        #[doc = " There was an error while attempting to decode the datagram."]
-        DecodingFailed,
-        #[doc = " There was an error while parsing the Retry token."]
-        InvalidRetryToken,
-        #[doc = " The peer specified an unsupported QUIC version."]
-        UnsupportedVersion,
-        #[doc = " The peer sent an invalid Destination Connection Id."]
-        InvalidDestinationConnectionId,
-        #[doc = " The peer sent an invalid Source Connection Id."]
-        InvalidSourceConnectionId,
-        #[doc = " The Destination Connection Id is unknown and does not map to a Connection."]
-        #[doc = ""]
-        #[doc = " Connections are mapped to Destination Connections Ids (DCID) and packets"]
-        #[doc = " in a Datagram are routed to a connection based on the DCID in the first"]
-        #[doc = " packet. If a Connection is not found for the specified DCID then the"]
-        #[doc = " datagram can not be processed and is dropped."]
-        UnknownDestinationConnectionId,
-        #[doc = " The connection attempt was rejected."]
-        RejectedConnectionAttempt,
-        #[doc = " A datagram was received from an unknown server address."]
-        UnknownServerAddress,
-        #[doc = " The peer initiated a connection migration before the handshake was confirmed."]
-        ConnectionMigrationDuringHandshake,
-        #[doc = " The attempted connection migration was rejected."]
-        RejectedConnectionMigration,
-        #[doc = " The maximum number of paths per connection was exceeded."]
-        PathLimitExceeded,
-        #[doc = " The peer initiated a connection migration without supplying enough connection IDs to use."]
-        InsufficientConnectionIds,
-    }
-    impl IntoEvent<api::DatagramDropReason> for DatagramDropReason {
         #[inline]
-        fn into_event(self) -> api::DatagramDropReason {
-            use api::DatagramDropReason::*;
-            match self {
-                Self::DecodingFailed => DecodingFailed {},
-                Self::InvalidRetryToken => InvalidRetryToken {},
-                Self::UnsupportedVersion => UnsupportedVersion {},
-                Self::InvalidDestinationConnectionId => InvalidDestinationConnectionId {},
-                Self::InvalidSourceConnectionId => InvalidSourceConnectionId {},
-                Self::UnknownDestinationConnectionId => UnknownDestinationConnectionId {},
-                Self::RejectedConnectionAttempt => RejectedConnectionAttempt {},
-                Self::UnknownServerAddress => UnknownServerAddress {},
-                Self::ConnectionMigrationDuringHandshake => ConnectionMigrationDuringHandshake {},
-                Self::RejectedConnectionMigration => RejectedConnectionMigration {},
-                Self::PathLimitExceeded => PathLimitExceeded {},
-                Self::InsufficientConnectionIds => InsufficientConnectionIds {},
-            }
+        fn on_key_update(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeyUpdate,
+        ) {
+            let api::KeyUpdate {
+                key_type,
+                cipher_suite,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "key_update",
+                &[
+                    ("key_type", Value::Debug(key_type)),
+                    ("cipher_suite", Value::Debug(cipher_suite)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum KeySpace {
-        Initial,
-        Handshake,
-        ZeroRtt,
-        OneRtt,
-    }
-    impl IntoEvent<api::KeySpace> for KeySpace {
         #[inline]
-        fn into_event(self) -> api::KeySpace {
-            use api::KeySpace::*;
-            match self {
-                Self::Initial => Initial {},
-                Self::Handshake => Handshake {},
-                Self::ZeroRtt => ZeroRtt {},
-                Self::OneRtt => OneRtt {},
-            }
+        fn on_key_space_discarded(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeySpaceDiscarded,
+        ) {
+            let api::KeySpaceDiscarded { space } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "key_space_discarded",
+                &[("space", Value::Debug(space))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum PacketDropReason<'a> {
-        #[doc = " A connection error occurred and is no longer able to process packets."]
-        ConnectionError { path: Path<'a> },
-        #[doc = " The handshake needed to be complete before processing the packet."]
-        #[doc = ""]
-        #[doc = " To ensure the connection stays secure, short packets can only be processed"]
-        #[doc = " once the handshake has completed."]
-        HandshakeNotComplete { path: Path<'a> },
-        #[doc = " The packet contained a version which did not match the version negotiated"]
-        #[doc = " during the handshake."]
-        VersionMismatch { version: u32, path: Path<'a> },
-        #[doc = " A datagram contained more than one destination connection ID, which is"]
-        #[doc = " not allowed."]
-        ConnectionIdMismatch {
-            packet_cid: &'a [u8],
-            path: Path<'a>,
-        },
-        #[doc = " There was a failure when attempting to remove header protection."]
-        UnprotectFailed { space: KeySpace, path: Path<'a> },
-        #[doc = " There was a failure when attempting to decrypt the packet."]
-        DecryptionFailed {
-            path: Path<'a>,
-            packet_header: PacketHeader,
-        },
-        #[doc = " Packet decoding failed."]
-        #[doc = ""]
-        #[doc = " The payload is decoded one packet at a time. If decoding fails"]
-        #[doc = " then the remaining packets are also discarded."]
-        DecodingFailed { path: Path<'a> },
-        #[doc = " The client received a non-empty retry token."]
-        NonEmptyRetryToken { path: Path<'a> },
-        #[doc = " A Retry packet was discarded."]
-        RetryDiscarded {
-            reason: RetryDiscardReason<'a>,
-            path: Path<'a>,
-        },
-        #[doc = " The received Initial packet was not transported in a datagram of at least 1200 bytes"]
-        UndersizedInitialPacket { path: Path<'a> },
-    }
-    impl<'a> IntoEvent<api::PacketDropReason<'a>> for PacketDropReason<'a> {
         #[inline]
-        fn into_event(self) -> api::PacketDropReason<'a> {
-            use api::PacketDropReason::*;
-            match self {
-                Self::ConnectionError { path } => ConnectionError {
-                    path: path.into_event(),
-                },
-                Self::HandshakeNotComplete { path } => HandshakeNotComplete {
-                    path: path.into_event(),
-                },
-                Self::VersionMismatch { version, path } => VersionMismatch {
-                    version: version.into_event(),
-                    path: path.into_event(),
-                },
-                Self::ConnectionIdMismatch { packet_cid, path } => ConnectionIdMismatch {
-                    packet_cid: packet_cid.into_event(),
-                    path: path.into_event(),
-                },
-                Self::UnprotectFailed { space, path } => UnprotectFailed {
-                    space: space.into_event(),
-                    path: path.into_event(),
-                },
-                Self::DecryptionFailed {
-                    path,
-                    packet_header,
-                } => DecryptionFailed {
-                    path: path.into_event(),
-                    packet_header: packet_header.into_event(),
-                },
-                Self::DecodingFailed { path } => DecodingFailed {
-                    path: path.into_event(),
-                },
-                Self::NonEmptyRetryToken { path } => NonEmptyRetryToken {
-                    path: path.into_event(),
-                },
-                Self::RetryDiscarded { reason, path } => RetryDiscarded {
-                    reason: reason.into_event(),
-                    path: path.into_event(),
-                },
-                Self::UndersizedInitialPacket { path } => UndersizedInitialPacket {
-                    path: path.into_event(),
-                },
-            }
+        fn on_connection_started(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionStarted,
+        ) {
+            let api::ConnectionStarted { path } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_started",
+                &[("path", Value::Debug(path))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum AckAction {
-        #[doc = " Ack range for received packets was dropped due to space constraints"]
-        #[doc = ""]
-        #[doc = " For the purpose of processing Acks, RX packet numbers are stored as"]
-        #[doc = " packet_number ranges in an IntervalSet; only lower and upper bounds"]
-        #[doc = " are stored instead of individual packet_numbers. Ranges are merged"]
-        #[doc = " when possible so only disjointed ranges are stored."]
-        #[doc = ""]
-        #[doc = " When at `capacity`, the lowest packet_number range is dropped."]
-        RxAckRangeDropped {
-            #[doc = " The packet number range which was dropped"]
-            packet_number_range: core::ops::RangeInclusive<u64>,
-            #[doc = " The number of disjoint ranges the IntervalSet can store"]
-            capacity: usize,
-            #[doc = " The store packet_number range in the IntervalSet"]
-            stored_range: core::ops::RangeInclusive<u64>,
-        },
-    }
-    #[allow(deprecated)]
-    impl IntoEvent<api::AckAction> for AckAction {
         #[inline]
-        fn into_event(self) -> api::AckAction {
-            use api::AckAction::*;
-            match self {
-                Self::RxAckRangeDropped {
-                    packet_number_range,
-                    capacity,
-                    stored_range,
-                } => RxAckRangeDropped {
-                    packet_number_range: packet_number_range.into_event(),
-                    capacity: capacity.into_event(),
-                    stored_range: stored_range.into_event(),
-                },
-            }
+        fn on_connection_closed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionClosed,
+        ) {
+            let api::ConnectionClosed {
+                error,
+                duration,
+                statistics,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_closed",
+                &[
+                    ("error", Value::Debug(error)),
+                    ("duration", Value::Debug(duration)),
+                    ("statistics", Value::Debug(statistics)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum RetryDiscardReason<'a> {
-        #[doc = " Received a Retry packet with SCID field equal to DCID field."]
-        ScidEqualsDcid { cid: &'a [u8] },
-        #[doc = " A client only processes at most one Retry packet."]
-        RetryAlreadyProcessed,
-        #[doc = " The client discards Retry packets if a valid Initial packet"]
-        #[doc = " has been received and processed."]
-        InitialAlreadyProcessed,
-        #[doc = " The Retry packet received contained an invalid retry integrity tag"]
-        InvalidIntegrityTag,
-    }
-    impl<'a> IntoEvent<api::RetryDiscardReason<'a>> for RetryDiscardReason<'a> {
         #[inline]
-        fn into_event(self) -> api::RetryDiscardReason<'a> {
-            use api::RetryDiscardReason::*;
-            match self {
-                Self::ScidEqualsDcid { cid } => ScidEqualsDcid {
-                    cid: cid.into_event(),
-                },
-                Self::RetryAlreadyProcessed => RetryAlreadyProcessed {},
-                Self::InitialAlreadyProcessed => InitialAlreadyProcessed {},
-                Self::InvalidIntegrityTag => InvalidIntegrityTag {},
-            }
+        fn on_graceful_close(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::GracefulClose,
+        ) {
+            let api::GracefulClose { timed_out } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "graceful_close",
+                &[("timed_out", Value::Bool(*timed_out))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum MigrationDenyReason {
-        BlockedPort,
-        PortScopeChanged,
-        IpScopeChange,
-        ConnectionMigrationDisabled,
-    }
-    impl IntoEvent<api::MigrationDenyReason> for MigrationDenyReason {
         #[inline]
-        fn into_event(self) -> api::MigrationDenyReason {
-            use api::MigrationDenyReason::*;
-            match self {
-                Self::BlockedPort => BlockedPort {},
-                Self::PortScopeChanged => PortScopeChanged {},
-                Self::IpScopeChange => IpScopeChange {},
-                Self::ConnectionMigrationDisabled => ConnectionMigrationDisabled {},
-            }
+        fn on_duplicate_packet(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DuplicatePacket,
+        ) {
+            let api::DuplicatePacket {
+                packet_header,
+                path,
+                error,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "duplicate_packet",
+                &[
+                    ("packet_header", Value::Debug(packet_header)),
+                    ("path", Value::Debug(path)),
+                    ("error", Value::Debug(error)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The current state of the ECN controller for the path"]
-    pub enum EcnState {
-        #[doc = " ECN capability is being actively tested"]
-        Testing,
-        #[doc = " ECN capability has been tested, but not validated yet"]
-        Unknown,
-        #[doc = " ECN capability testing has failed validation"]
-        Failed,
-        #[doc = " ECN capability has been confirmed"]
-        Capable,
-    }
-    impl IntoEvent<api::EcnState> for EcnState {
         #[inline]
-        fn into_event(self) -> api::EcnState {
-            use api::EcnState::*;
-            match self {
-                Self::Testing => Testing {},
-                Self::Unknown => Unknown {},
-                Self::Failed => Failed {},
-                Self::Capable => Capable {},
-            }
+        fn on_transport_parameters_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TransportParametersReceived,
+        ) {
+            let api::TransportParametersReceived {
+                transport_parameters,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "transport_parameters_received",
+                &[("transport_parameters", Value::Debug(transport_parameters))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Events tracking the progress of handshake status"]
-    pub enum HandshakeStatus {
-        #[doc = " The handshake has completed."]
-        Complete,
-        #[doc = " The handshake has been confirmed."]
-        Confirmed,
-        #[doc = " A HANDSHAKE_DONE frame was delivered or received."]
-        #[doc = ""]
-        #[doc = " A Client endpoint receives a HANDSHAKE_DONE frame and"]
-        #[doc = " only a Server is allowed to send the HANDSHAKE_DONE"]
-        #[doc = " frame."]
-        HandshakeDoneAcked,
-        #[doc = " A HANDSHAKE_DONE frame was declared lost."]
-        #[doc = ""]
-        #[doc = " The Server is responsible for re-transmitting the"]
-        #[doc = " HANDSHAKE_DONE frame until it is acked by the peer."]
-        HandshakeDoneLost,
-    }
-    impl IntoEvent<api::HandshakeStatus> for HandshakeStatus {
         #[inline]
-        fn into_event(self) -> api::HandshakeStatus {
-            use api::HandshakeStatus::*;
-            match self {
-                Self::Complete => Complete {},
-                Self::Confirmed => Confirmed {},
-                Self::HandshakeDoneAcked => HandshakeDoneAcked {},
-                Self::HandshakeDoneLost => HandshakeDoneLost {},
-            }
+        fn on_datagram_sent(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramSent,
+        ) {
+            let api::DatagramSent { len, gso_offset } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "datagram_sent",
+                &[
+                    ("len", Value::Number(len)),
+                    ("gso_offset", Value::Number(gso_offset)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The source that caused a congestion event"]
-    pub enum CongestionSource {
-        #[doc = " Explicit Congestion Notification"]
-        Ecn,
-        #[doc = " One or more packets were detected lost"]
-        PacketLoss,
-    }
-    impl IntoEvent<api::CongestionSource> for CongestionSource {
         #[inline]
-        fn into_event(self) -> api::CongestionSource {
-            use api::CongestionSource::*;
-            match self {
-                Self::Ecn => Ecn {},
-                Self::PacketLoss => PacketLoss {},
-            }
+        fn on_datagram_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramReceived,
+        ) {
+            let api::DatagramReceived { len } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "datagram_received",
+                &[("len", Value::Number(len))],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[allow(non_camel_case_types)]
-    pub enum CipherSuite {
-        TLS_AES_128_GCM_SHA256,
-        TLS_AES_256_GCM_SHA384,
-        TLS_CHACHA20_POLY1305_SHA256,
-        Unknown,
-    }
-    impl IntoEvent<api::CipherSuite> for CipherSuite {
         #[inline]
-        fn into_event(self) -> api::CipherSuite {
-            use api::CipherSuite::*;
-            match self {
-                Self::TLS_AES_128_GCM_SHA256 => TLS_AES_128_GCM_SHA256 {},
-                Self::TLS_AES_256_GCM_SHA384 => TLS_AES_256_GCM_SHA384 {},
-                Self::TLS_CHACHA20_POLY1305_SHA256 => TLS_CHACHA20_POLY1305_SHA256 {},
-                Self::Unknown => Unknown {},
-            }
+        fn on_datagram_dropped(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramDropped,
+        ) {
+            let api::DatagramDropped { len, reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "datagram_dropped",
+                &[
+                    ("len", Value::Number(len)),
+                    ("reason", Value::Debug(reason)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum PathChallengeStatus {
-        Validated,
-        Abandoned,
-    }
-    impl IntoEvent<api::PathChallengeStatus> for PathChallengeStatus {
         #[inline]
-        fn into_event(self) -> api::PathChallengeStatus {
-            use api::PathChallengeStatus::*;
-            match self {
-                Self::Validated => Validated {},
-                Self::Abandoned => Abandoned {},
-            }
+        fn on_datagram_frame_dropped(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramFrameDropped,
+        ) {
+            let api::DatagramFrameDropped { count, len, reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "datagram_frame_dropped",
+                &[
+                    ("count", Value::Number(count)),
+                    ("len", Value::Number(len)),
+                    ("reason", Value::Debug(reason)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The reason the slow start congestion controller state has been exited"]
-    pub enum SlowStartExitCause {
-        #[doc = " A packet was determined lost"]
-        PacketLoss,
-        #[doc = " An Explicit Congestion Notification: Congestion Experienced marking was received"]
-        Ecn,
-        #[doc = " The round trip time estimate was updated"]
-        Rtt,
-        #[doc = " Slow Start exited due to a reason other than those above"]
-        #[doc = ""]
-        #[doc = " With the Cubic congestion controller, this reason is used after the initial exiting of"]
-        #[doc = " Slow Start, when the previously determined Slow Start threshold is exceed by the"]
-        #[doc = " congestion window."]
-        Other,
-    }
-    impl IntoEvent<api::SlowStartExitCause> for SlowStartExitCause {
         #[inline]
-        fn into_event(self) -> api::SlowStartExitCause {
-            use api::SlowStartExitCause::*;
-            match self {
-                Self::PacketLoss => PacketLoss {},
-                Self::Ecn => Ecn {},
-                Self::Rtt => Rtt {},
-                Self::Other => Other {},
-            }
+        fn on_connection_id_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdUpdated,
+        ) {
+            let api::ConnectionIdUpdated {
+                path_id,
+                cid_consumer,
+                previous,
+                current,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_id_updated",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("cid_consumer", Value::Debug(cid_consumer)),
+                    ("previous", Value::Debug(previous)),
+                    ("current", Value::Debug(current)),
+                ],
+            );
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The reason the MTU was updated"]
-    pub enum MtuUpdatedCause {
-        #[doc = " The MTU was initialized with the default value"]
-        NewPath,
-        #[doc = " An MTU probe was acknowledged by the peer"]
-        ProbeAcknowledged,
-        #[doc = " A blackhole was detected"]
-        Blackhole,
-    }
-    impl IntoEvent<api::MtuUpdatedCause> for MtuUpdatedCause {
         #[inline]
-        fn into_event(self) -> api::MtuUpdatedCause {
-            use api::MtuUpdatedCause::*;
-            match self {
-                Self::NewPath => NewPath {},
-                Self::ProbeAcknowledged => ProbeAcknowledged {},
-                Self::Blackhole => Blackhole {},
-            }
+        fn on_connection_id_issued(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdIssued,
+        ) {
+            let api::ConnectionIdIssued {
+                sequence_number,
+                connection_id,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_id_issued",
+                &[
+                    ("sequence_number", Value::Number(sequence_number)),
+                    ("connection_id", Value::Debug(connection_id)),
+                ],
+            );
         }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdRetired,
+        ) {
+            let api::ConnectionIdRetired {
+                sequence_number,
+                connection_id,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_id_retired",
+                &[
+                    ("sequence_number", Value::Number(sequence_number)),
+                    ("connection_id", Value::Debug(connection_id)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FlowControlBlocked,
+        ) {
+            let api::FlowControlBlocked { scope, window } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "flow_control_blocked",
+                &[
+                    ("scope", Value::Debug(scope)),
+                    ("window", Value::Number(window)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::EcnStateChanged,
+        ) {
+            let api::EcnStateChanged { path, state } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "ecn_state_changed",
+                &[("path", Value::Debug(path)), ("state", Value::Debug(state))],
+            );
+        }
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionMigrationDenied,
+        ) {
+            let api::ConnectionMigrationDenied { reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "connection_migration_denied",
+                &[("reason", Value::Debug(reason))],
+            );
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::NatRebindingDetected,
+        ) {
+            let api::NatRebindingDetected {
+                path,
+                previous_remote_addr,
+                validated,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "nat_rebinding_detected",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("previous_remote_addr", Value::Debug(previous_remote_addr)),
+                    ("validated", Value::Bool(*validated)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeStatusUpdated,
+        ) {
+            let api::HandshakeStatusUpdated { status } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "handshake_status_updated",
+                &[("status", Value::Debug(status))],
+            );
+        }
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathChallengeUpdated,
+        ) {
+            let api::PathChallengeUpdated {
+                path_challenge_status,
+                path,
+                challenge_data,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "path_challenge_updated",
+                &[
+                    ("path_challenge_status", Value::Debug(path_challenge_status)),
+                    ("path", Value::Debug(path)),
+                    ("challenge_data", Value::Debug(challenge_data)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TlsClientHello,
+        ) {
+            let api::TlsClientHello { payload } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "tls_client_hello",
+                &[("payload", Value::Debug(payload))],
+            );
+        }
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TlsServerHello,
+        ) {
+            let api::TlsServerHello { payload } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "tls_server_hello",
+                &[("payload", Value::Debug(payload))],
+            );
+        }
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RxStreamProgress,
+        ) {
+            let api::RxStreamProgress { bytes } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "rx_stream_progress",
+                &[("bytes", Value::Number(bytes))],
+            );
+        }
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TxStreamProgress,
+        ) {
+            let api::TxStreamProgress { bytes } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "tx_stream_progress",
+                &[("bytes", Value::Number(bytes))],
+            );
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeepAliveTimerExpired,
+        ) {
+            let api::KeepAliveTimerExpired { timeout } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "keep_alive_timer_expired",
+                &[("timeout", Value::Debug(timeout))],
+            );
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::MtuUpdated,
+        ) {
+            let api::MtuUpdated {
+                path_id,
+                mtu,
+                cause,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "mtu_updated",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("mtu", Value::Number(mtu)),
+                    ("cause", Value::Debug(cause)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::MtuProbingComplete,
+        ) {
+            let api::MtuProbingComplete {
+                path_id,
+                mtu,
+                max_probed_mtu,
+                probes_sent,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "mtu_probing_complete",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("mtu", Value::Number(mtu)),
+                    ("max_probed_mtu", Value::Number(max_probed_mtu)),
+                    ("probes_sent", Value::Number(probes_sent)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::SlowStartExited,
+        ) {
+            let api::SlowStartExited {
+                path_id,
+                cause,
+                congestion_window,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "slow_start_exited",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("cause", Value::Debug(cause)),
+                    ("congestion_window", Value::Number(congestion_window)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionWindowUpdated,
+        ) {
+            let api::CongestionWindowUpdated {
+                path_id,
+                cause,
+                old_congestion_window,
+                new_congestion_window,
+                bytes_in_flight,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "congestion_window_updated",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("cause", Value::Debug(cause)),
+                    (
+                        "old_congestion_window",
+                        Value::Number(old_congestion_window),
+                    ),
+                    (
+                        "new_congestion_window",
+                        Value::Number(new_congestion_window),
+                    ),
+                    ("bytes_in_flight", Value::Number(bytes_in_flight)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DeliveryRateSampled,
+        ) {
+            let api::DeliveryRateSampled {
+                path_id,
+                rate_sample,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "delivery_rate_sampled",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("rate_sample", Value::Debug(rate_sample)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacingRateUpdated,
+        ) {
+            let api::PacingRateUpdated {
+                path_id,
+                bytes_per_second,
+                burst_size,
+                pacing_gain,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "pacing_rate_updated",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("bytes_per_second", Value::Number(bytes_per_second)),
+                    ("burst_size", Value::Number(burst_size)),
+                    ("pacing_gain", Value::Float(f64::from(*pacing_gain))),
+                ],
+            );
+        }
+        #[inline]
+        fn on_bbr_state_changed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::BbrStateChanged,
+        ) {
+            let api::BbrStateChanged { path_id, state } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "bbr_state_changed",
+                &[
+                    ("path_id", Value::Number(path_id)),
+                    ("state", Value::Debug(state)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeProgress,
+        ) {
+            let api::HandshakeProgress { phase, elapsed } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "handshake_progress",
+                &[
+                    ("phase", Value::Debug(phase)),
+                    ("elapsed", Value::Debug(elapsed)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeComplete,
+        ) {
+            let api::HandshakeComplete {
+                duration,
+                zero_rtt,
+                resumed,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "handshake_complete",
+                &[
+                    ("duration", Value::Debug(duration)),
+                    ("zero_rtt", Value::Bool(*zero_rtt)),
+                    ("resumed", Value::Bool(*resumed)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeyUpdated,
+        ) {
+            let api::KeyUpdated {
+                key_phase,
+                packets_sent,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "key_updated",
+                &[
+                    ("key_phase", Value::Number(key_phase)),
+                    ("packets_sent", Value::Number(packets_sent)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathValidationFailed,
+        ) {
+            let api::PathValidationFailed {
+                path,
+                attempts,
+                fallback_path,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "path_validation_failed",
+                &[
+                    ("path", Value::Debug(path)),
+                    ("attempts", Value::Number(attempts)),
+                    ("fallback_path", Value::Debug(fallback_path)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckFrequencyUpdated,
+        ) {
+            let api::AckFrequencyUpdated {
+                sequence_number,
+                ack_eliciting_threshold,
+                max_ack_delay,
+                reordering_threshold,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                Some(meta.id),
+                "ack_frequency_updated",
+                &[
+                    ("sequence_number", Value::Number(sequence_number)),
+                    (
+                        "ack_eliciting_threshold",
+                        Value::Number(ack_eliciting_threshold),
+                    ),
+                    ("max_ack_delay", Value::Debug(max_ack_delay)),
+                    ("reordering_threshold", Value::Number(reordering_threshold)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_version_information(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::VersionInformation,
+        ) {
+            let api::VersionInformation {
+                server_versions,
+                client_versions,
+                chosen_version,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "version_information",
+                &[
+                    ("server_versions", Value::Debug(server_versions)),
+                    ("client_versions", Value::Debug(client_versions)),
+                    ("chosen_version", Value::Debug(chosen_version)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketSent,
+        ) {
+            let api::EndpointPacketSent { packet_header } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_packet_sent",
+                &[("packet_header", Value::Debug(packet_header))],
+            );
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketReceived,
+        ) {
+            let api::EndpointPacketReceived { packet_header } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_packet_received",
+                &[("packet_header", Value::Debug(packet_header))],
+            );
+        }
+        #[inline]
+        fn on_endpoint_datagram_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramSent,
+        ) {
+            let api::EndpointDatagramSent { len, gso_offset } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_datagram_sent",
+                &[
+                    ("len", Value::Number(len)),
+                    ("gso_offset", Value::Number(gso_offset)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramReceived,
+        ) {
+            let api::EndpointDatagramReceived { len } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_datagram_received",
+                &[("len", Value::Number(len))],
+            );
+        }
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramDropped,
+        ) {
+            let api::EndpointDatagramDropped { len, reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_datagram_dropped",
+                &[
+                    ("len", Value::Number(len)),
+                    ("reason", Value::Debug(reason)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptFailed,
+        ) {
+            let api::EndpointConnectionAttemptFailed { error } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_connection_attempt_failed",
+                &[("error", Value::Debug(error))],
+            );
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &api::EndpointMeta, event: &api::RetrySent) {
+            let api::RetrySent { remote_addr } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "retry_sent",
+                &[("remote_addr", Value::Debug(remote_addr))],
+            );
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketDropped,
+        ) {
+            let api::EndpointPacketDropped { reason } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "endpoint_packet_dropped",
+                &[("reason", Value::Debug(reason))],
+            );
+        }
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
+            let api::PlatformTx { count } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_tx",
+                &[("count", Value::Number(count))],
+            );
+        }
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTxError) {
+            let api::PlatformTxError { errno } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_tx_error",
+                &[("errno", Value::Number(errno))],
+            );
+        }
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRx) {
+            let api::PlatformRx { count } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_rx",
+                &[("count", Value::Number(count))],
+            );
+        }
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRxError) {
+            let api::PlatformRxError { errno } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_rx_error",
+                &[("errno", Value::Number(errno))],
+            );
+        }
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformFeatureConfigured,
+        ) {
+            let api::PlatformFeatureConfigured { configuration } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_feature_configured",
+                &[("configuration", Value::Debug(configuration))],
+            );
+        }
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformEventLoopWakeup,
+        ) {
+            let api::PlatformEventLoopWakeup {
+                timeout_expired,
+                rx_ready,
+                tx_ready,
+                application_wakeup,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_event_loop_wakeup",
+                &[
+                    ("timeout_expired", Value::Bool(*timeout_expired)),
+                    ("rx_ready", Value::Bool(*rx_ready)),
+                    ("tx_ready", Value::Bool(*tx_ready)),
+                    ("application_wakeup", Value::Bool(*application_wakeup)),
+                ],
+            );
+        }
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformEventLoopSleep,
+        ) {
+            let api::PlatformEventLoopSleep {
+                timeout,
+                processing_duration,
+            } = event;
+            self.write(
+                &meta.endpoint_type,
+                None,
+                "platform_event_loop_sleep",
+                &[
+                    ("timeout", Value::Debug(timeout)),
+                    ("processing_duration", Value::Debug(processing_duration)),
+                ],
+            );
+        }
+    }
+}
+pub mod builder {
+    use super::*;
+    #[derive(Clone, Debug)]
+    pub struct ConnectionMeta {
+        pub endpoint_type: crate::endpoint::Type,
+        pub id: u64,
+        pub timestamp: crate::time::Timestamp,
+    }
+    impl IntoEvent<api::ConnectionMeta> for ConnectionMeta {
+        #[inline]
+        fn into_event(self) -> api::ConnectionMeta {
+            let ConnectionMeta {
+                endpoint_type,
+                id,
+                timestamp,
+            } = self;
+            api::ConnectionMeta {
+                endpoint_type: endpoint_type.into_event(),
+                id: id.into_event(),
+                timestamp: timestamp.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct EndpointMeta {
+        pub endpoint_type: crate::endpoint::Type,
+        pub timestamp: crate::time::Timestamp,
+    }
+    impl IntoEvent<api::EndpointMeta> for EndpointMeta {
+        #[inline]
+        fn into_event(self) -> api::EndpointMeta {
+            let EndpointMeta {
+                endpoint_type,
+                timestamp,
+            } = self;
+            api::EndpointMeta {
+                endpoint_type: endpoint_type.into_event(),
+                timestamp: timestamp.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ConnectionInfo<'a> {
+        #[doc = " The destination connection ID of the first Initial packet sent by the client"]
+        pub original_destination_connection_id: ConnectionId<'a>,
+    }
+    impl<'a> IntoEvent<api::ConnectionInfo<'a>> for ConnectionInfo<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionInfo<'a> {
+            let ConnectionInfo {
+                original_destination_connection_id,
+            } = self;
+            api::ConnectionInfo {
+                original_destination_connection_id: original_destination_connection_id.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct TransportParameters<'a> {
+        pub original_destination_connection_id: Option<ConnectionId<'a>>,
+        pub initial_source_connection_id: Option<ConnectionId<'a>>,
+        pub retry_source_connection_id: Option<ConnectionId<'a>>,
+        pub stateless_reset_token: Option<&'a [u8]>,
+        pub preferred_address: Option<PreferredAddress<'a>>,
+        pub migration_support: bool,
+        pub max_idle_timeout: Duration,
+        pub ack_delay_exponent: u8,
+        pub max_ack_delay: Duration,
+        pub max_udp_payload_size: u64,
+        pub active_connection_id_limit: u64,
+        pub initial_max_stream_data_bidi_local: u64,
+        pub initial_max_stream_data_bidi_remote: u64,
+        pub initial_max_stream_data_uni: u64,
+        pub initial_max_streams_bidi: u64,
+        pub initial_max_streams_uni: u64,
+        pub max_datagram_frame_size: u64,
+    }
+    impl<'a> IntoEvent<api::TransportParameters<'a>> for TransportParameters<'a> {
+        #[inline]
+        fn into_event(self) -> api::TransportParameters<'a> {
+            let TransportParameters {
+                original_destination_connection_id,
+                initial_source_connection_id,
+                retry_source_connection_id,
+                stateless_reset_token,
+                preferred_address,
+                migration_support,
+                max_idle_timeout,
+                ack_delay_exponent,
+                max_ack_delay,
+                max_udp_payload_size,
+                active_connection_id_limit,
+                initial_max_stream_data_bidi_local,
+                initial_max_stream_data_bidi_remote,
+                initial_max_stream_data_uni,
+                initial_max_streams_bidi,
+                initial_max_streams_uni,
+                max_datagram_frame_size,
+            } = self;
+            api::TransportParameters {
+                original_destination_connection_id: original_destination_connection_id.into_event(),
+                initial_source_connection_id: initial_source_connection_id.into_event(),
+                retry_source_connection_id: retry_source_connection_id.into_event(),
+                stateless_reset_token: stateless_reset_token.into_event(),
+                preferred_address: preferred_address.into_event(),
+                migration_support: migration_support.into_event(),
+                max_idle_timeout: max_idle_timeout.into_event(),
+                ack_delay_exponent: ack_delay_exponent.into_event(),
+                max_ack_delay: max_ack_delay.into_event(),
+                max_udp_payload_size: max_udp_payload_size.into_event(),
+                active_connection_id_limit: active_connection_id_limit.into_event(),
+                initial_max_stream_data_bidi_local: initial_max_stream_data_bidi_local.into_event(),
+                initial_max_stream_data_bidi_remote: initial_max_stream_data_bidi_remote
+                    .into_event(),
+                initial_max_stream_data_uni: initial_max_stream_data_uni.into_event(),
+                initial_max_streams_bidi: initial_max_streams_bidi.into_event(),
+                initial_max_streams_uni: initial_max_streams_uni.into_event(),
+                max_datagram_frame_size: max_datagram_frame_size.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PreferredAddress<'a> {
+        pub ipv4_address: Option<SocketAddress<'a>>,
+        pub ipv6_address: Option<SocketAddress<'a>>,
+        pub connection_id: ConnectionId<'a>,
+        pub stateless_reset_token: &'a [u8],
+    }
+    impl<'a> IntoEvent<api::PreferredAddress<'a>> for PreferredAddress<'a> {
+        #[inline]
+        fn into_event(self) -> api::PreferredAddress<'a> {
+            let PreferredAddress {
+                ipv4_address,
+                ipv6_address,
+                connection_id,
+                stateless_reset_token,
+            } = self;
+            api::PreferredAddress {
+                ipv4_address: ipv4_address.into_event(),
+                ipv6_address: ipv6_address.into_event(),
+                connection_id: connection_id.into_event(),
+                stateless_reset_token: stateless_reset_token.into_event(),
+            }
+        }
+    }
+    #[derive(Copy, Clone, Debug)]
+    pub struct Path<'a> {
+        pub local_addr: SocketAddress<'a>,
+        pub local_cid: ConnectionId<'a>,
+        pub remote_addr: SocketAddress<'a>,
+        pub remote_cid: ConnectionId<'a>,
+        pub id: u64,
+        pub is_active: bool,
+    }
+    impl<'a> IntoEvent<api::Path<'a>> for Path<'a> {
+        #[inline]
+        fn into_event(self) -> api::Path<'a> {
+            let Path {
+                local_addr,
+                local_cid,
+                remote_addr,
+                remote_cid,
+                id,
+                is_active,
+            } = self;
+            api::Path {
+                local_addr: local_addr.into_event(),
+                local_cid: local_cid.into_event(),
+                remote_addr: remote_addr.into_event(),
+                remote_cid: remote_cid.into_event(),
+                id: id.into_event(),
+                is_active: is_active.into_event(),
+            }
+        }
+    }
+    #[derive(Copy, Clone, Debug)]
+    pub struct ConnectionId<'a> {
+        pub bytes: &'a [u8],
+    }
+    impl<'a> IntoEvent<api::ConnectionId<'a>> for ConnectionId<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionId<'a> {
+            let ConnectionId { bytes } = self;
+            api::ConnectionId {
+                bytes: bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct EcnCounts {
+        #[doc = " A variable-length integer representing the total number of packets"]
+        #[doc = " received with the ECT(0) codepoint."]
+        pub ect_0_count: u64,
+        #[doc = " A variable-length integer representing the total number of packets"]
+        #[doc = " received with the ECT(1) codepoint."]
+        pub ect_1_count: u64,
+        #[doc = " A variable-length integer representing the total number of packets"]
+        #[doc = " received with the CE codepoint."]
+        pub ce_count: u64,
+    }
+    impl IntoEvent<api::EcnCounts> for EcnCounts {
+        #[inline]
+        fn into_event(self) -> api::EcnCounts {
+            let EcnCounts {
+                ect_0_count,
+                ect_1_count,
+                ce_count,
+            } = self;
+            api::EcnCounts {
+                ect_0_count: ect_0_count.into_event(),
+                ect_1_count: ect_1_count.into_event(),
+                ce_count: ce_count.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A bandwidth delivery rate estimate with associated metadata"]
+    pub struct RateSample {
+        #[doc = " The length of the sampling interval"]
+        pub interval: Duration,
+        #[doc = " The amount of data in bytes marked as delivered over the sampling interval"]
+        pub delivered_bytes: u64,
+        #[doc = " The amount of data in bytes marked as lost over the sampling interval"]
+        pub lost_bytes: u64,
+        #[doc = " The number of packets marked as explicit congestion experienced over the sampling interval"]
+        pub ecn_ce_count: u64,
+        #[doc = " PacketInfo::is_app_limited from the most recent acknowledged packet"]
+        pub is_app_limited: bool,
+        #[doc = " PacketInfo::delivered_bytes from the most recent acknowledged packet"]
+        pub prior_delivered_bytes: u64,
+        #[doc = " PacketInfo::bytes_in_flight from the most recent acknowledged packet"]
+        pub bytes_in_flight: u32,
+        #[doc = " PacketInfo::lost_bytes from the most recent acknowledged packet"]
+        pub prior_lost_bytes: u64,
+        #[doc = " PacketInfo::ecn_ce_count from the most recent acknowledged packet"]
+        pub prior_ecn_ce_count: u64,
+        #[doc = " The delivery rate for this rate sample"]
+        pub delivery_rate_bytes_per_second: u64,
+    }
+    impl IntoEvent<api::RateSample> for RateSample {
+        #[inline]
+        fn into_event(self) -> api::RateSample {
+            let RateSample {
+                interval,
+                delivered_bytes,
+                lost_bytes,
+                ecn_ce_count,
+                is_app_limited,
+                prior_delivered_bytes,
+                bytes_in_flight,
+                prior_lost_bytes,
+                prior_ecn_ce_count,
+                delivery_rate_bytes_per_second,
+            } = self;
+            api::RateSample {
+                interval: interval.into_event(),
+                delivered_bytes: delivered_bytes.into_event(),
+                lost_bytes: lost_bytes.into_event(),
+                ecn_ce_count: ecn_ce_count.into_event(),
+                is_app_limited: is_app_limited.into_event(),
+                prior_delivered_bytes: prior_delivered_bytes.into_event(),
+                bytes_in_flight: bytes_in_flight.into_event(),
+                prior_lost_bytes: prior_lost_bytes.into_event(),
+                prior_ecn_ce_count: prior_ecn_ce_count.into_event(),
+                delivery_rate_bytes_per_second: delivery_rate_bytes_per_second.into_event(),
+            }
+        }
+    }
+    #[derive(Copy, Clone, Debug)]
+    pub enum SocketAddress<'a> {
+        IpV4 { ip: &'a [u8; 4], port: u16 },
+        IpV6 { ip: &'a [u8; 16], port: u16 },
+    }
+    impl<'a> IntoEvent<api::SocketAddress<'a>> for SocketAddress<'a> {
+        #[inline]
+        fn into_event(self) -> api::SocketAddress<'a> {
+            use api::SocketAddress::*;
+            match self {
+                Self::IpV4 { ip, port } => IpV4 {
+                    ip: ip.into_event(),
+                    port: port.into_event(),
+                },
+                Self::IpV6 { ip, port } => IpV6 {
+                    ip: ip.into_event(),
+                    port: port.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum DuplicatePacketError {
+        #[doc = " The packet number was already received and is a duplicate."]
+        Duplicate,
+        #[doc = " The received packet number was outside the range of tracked packet numbers."]
+        #[doc = ""]
+        #[doc = " This can happen when packets are heavily delayed or reordered. Currently, the maximum"]
+        #[doc = " amount of reordering is limited to 128 packets. For example, if packet number `142`"]
+        #[doc = " is received, the allowed range would be limited to `14-142`. If an endpoint received"]
+        #[doc = " packet `< 14`, it would trigger this event."]
+        TooOld,
+    }
+    impl IntoEvent<api::DuplicatePacketError> for DuplicatePacketError {
+        #[inline]
+        fn into_event(self) -> api::DuplicatePacketError {
+            use api::DuplicatePacketError::*;
+            match self {
+                Self::Duplicate => Duplicate {},
+                Self::TooOld => TooOld {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum Frame {
+        Padding,
+        Ping,
+        Ack {
+            ecn_counts: Option<EcnCounts>,
+            largest_acknowledged: u64,
+            ack_range_count: u64,
+        },
+        ResetStream {
+            id: u64,
+            error_code: u64,
+            final_size: u64,
+        },
+        StopSending {
+            id: u64,
+            error_code: u64,
+        },
+        Crypto {
+            offset: u64,
+            len: u16,
+        },
+        NewToken,
+        Stream {
+            id: u64,
+            offset: u64,
+            len: u16,
+            is_fin: bool,
+        },
+        MaxData {
+            value: u64,
+        },
+        MaxStreamData {
+            stream_type: StreamType,
+            id: u64,
+            value: u64,
+        },
+        MaxStreams {
+            stream_type: StreamType,
+            value: u64,
+        },
+        DataBlocked {
+            data_limit: u64,
+        },
+        StreamDataBlocked {
+            stream_id: u64,
+            stream_data_limit: u64,
+        },
+        StreamsBlocked {
+            stream_type: StreamType,
+            stream_limit: u64,
+        },
+        NewConnectionId,
+        RetireConnectionId,
+        PathChallenge,
+        PathResponse,
+        ConnectionClose,
+        HandshakeDone,
+        Datagram {
+            len: u16,
+        },
+        AckFrequency {
+            sequence_number: u64,
+            ack_eliciting_threshold: u64,
+            request_max_ack_delay: u64,
+            reordering_threshold: u64,
+        },
+        ImmediateAck,
+    }
+    impl IntoEvent<api::Frame> for Frame {
+        #[inline]
+        fn into_event(self) -> api::Frame {
+            use api::Frame::*;
+            match self {
+                Self::Padding => Padding {},
+                Self::Ping => Ping {},
+                Self::Ack {
+                    ecn_counts,
+                    largest_acknowledged,
+                    ack_range_count,
+                } => Ack {
+                    ecn_counts: ecn_counts.into_event(),
+                    largest_acknowledged: largest_acknowledged.into_event(),
+                    ack_range_count: ack_range_count.into_event(),
+                },
+                Self::ResetStream {
+                    id,
+                    error_code,
+                    final_size,
+                } => ResetStream {
+                    id: id.into_event(),
+                    error_code: error_code.into_event(),
+                    final_size: final_size.into_event(),
+                },
+                Self::StopSending { id, error_code } => StopSending {
+                    id: id.into_event(),
+                    error_code: error_code.into_event(),
+                },
+                Self::Crypto { offset, len } => Crypto {
+                    offset: offset.into_event(),
+                    len: len.into_event(),
+                },
+                Self::NewToken => NewToken {},
+                Self::Stream {
+                    id,
+                    offset,
+                    len,
+                    is_fin,
+                } => Stream {
+                    id: id.into_event(),
+                    offset: offset.into_event(),
+                    len: len.into_event(),
+                    is_fin: is_fin.into_event(),
+                },
+                Self::MaxData { value } => MaxData {
+                    value: value.into_event(),
+                },
+                Self::MaxStreamData {
+                    stream_type,
+                    id,
+                    value,
+                } => MaxStreamData {
+                    stream_type: stream_type.into_event(),
+                    id: id.into_event(),
+                    value: value.into_event(),
+                },
+                Self::MaxStreams { stream_type, value } => MaxStreams {
+                    stream_type: stream_type.into_event(),
+                    value: value.into_event(),
+                },
+                Self::DataBlocked { data_limit } => DataBlocked {
+                    data_limit: data_limit.into_event(),
+                },
+                Self::StreamDataBlocked {
+                    stream_id,
+                    stream_data_limit,
+                } => StreamDataBlocked {
+                    stream_id: stream_id.into_event(),
+                    stream_data_limit: stream_data_limit.into_event(),
+                },
+                Self::StreamsBlocked {
+                    stream_type,
+                    stream_limit,
+                } => StreamsBlocked {
+                    stream_type: stream_type.into_event(),
+                    stream_limit: stream_limit.into_event(),
+                },
+                Self::NewConnectionId => NewConnectionId {},
+                Self::RetireConnectionId => RetireConnectionId {},
+                Self::PathChallenge => PathChallenge {},
+                Self::PathResponse => PathResponse {},
+                Self::ConnectionClose => ConnectionClose {},
+                Self::HandshakeDone => HandshakeDone {},
+                Self::Datagram { len } => Datagram {
+                    len: len.into_event(),
+                },
+                Self::AckFrequency {
+                    sequence_number,
+                    ack_eliciting_threshold,
+                    request_max_ack_delay,
+                    reordering_threshold,
+                } => AckFrequency {
+                    sequence_number: sequence_number.into_event(),
+                    ack_eliciting_threshold: ack_eliciting_threshold.into_event(),
+                    request_max_ack_delay: request_max_ack_delay.into_event(),
+                    reordering_threshold: reordering_threshold.into_event(),
+                },
+                Self::ImmediateAck => ImmediateAck {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum StreamType {
+        Bidirectional,
+        Unidirectional,
+    }
+    impl IntoEvent<api::StreamType> for StreamType {
+        #[inline]
+        fn into_event(self) -> api::StreamType {
+            use api::StreamType::*;
+            match self {
+                Self::Bidirectional => Bidirectional {},
+                Self::Unidirectional => Unidirectional {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum PacketHeader {
+        Initial { number: u64, version: u32 },
+        Handshake { number: u64, version: u32 },
+        ZeroRtt { number: u64, version: u32 },
+        OneRtt { number: u64 },
+        Retry { version: u32 },
+        VersionNegotiation,
+        StatelessReset,
+    }
+    impl IntoEvent<api::PacketHeader> for PacketHeader {
+        #[inline]
+        fn into_event(self) -> api::PacketHeader {
+            use api::PacketHeader::*;
+            match self {
+                Self::Initial { number, version } => Initial {
+                    number: number.into_event(),
+                    version: version.into_event(),
+                },
+                Self::Handshake { number, version } => Handshake {
+                    number: number.into_event(),
+                    version: version.into_event(),
+                },
+                Self::ZeroRtt { number, version } => ZeroRtt {
+                    number: number.into_event(),
+                    version: version.into_event(),
+                },
+                Self::OneRtt { number } => OneRtt {
+                    number: number.into_event(),
+                },
+                Self::Retry { version } => Retry {
+                    version: version.into_event(),
+                },
+                Self::VersionNegotiation => VersionNegotiation {},
+                Self::StatelessReset => StatelessReset {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum KeyType {
+        Initial,
+        Handshake,
+        ZeroRtt,
+        OneRtt { generation: u16 },
+    }
+    impl IntoEvent<api::KeyType> for KeyType {
+        #[inline]
+        fn into_event(self) -> api::KeyType {
+            use api::KeyType::*;
+            match self {
+                Self::Initial => Initial {},
+                Self::Handshake => Handshake {},
+                Self::ZeroRtt => ZeroRtt {},
+                Self::OneRtt { generation } => OneRtt {
+                    generation: generation.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A context from which the event is being emitted"]
+    #[doc = ""]
+    #[doc = " An event can occur in the context of an Endpoint or Connection"]
+    pub enum Subject {
+        Endpoint,
+        #[doc = " This maps to an internal connection id, which is a stable identifier across CID changes."]
+        Connection {
+            id: u64,
+        },
+    }
+    impl IntoEvent<api::Subject> for Subject {
+        #[inline]
+        fn into_event(self) -> api::Subject {
+            use api::Subject::*;
+            match self {
+                Self::Endpoint => Endpoint {},
+                Self::Connection { id } => Connection {
+                    id: id.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " An endpoint may be either a Server or a Client"]
+    pub enum EndpointType {
+        Server,
+        Client,
+    }
+    impl IntoEvent<api::EndpointType> for EndpointType {
+        #[inline]
+        fn into_event(self) -> api::EndpointType {
+            use api::EndpointType::*;
+            match self {
+                Self::Server => Server {},
+                Self::Client => Client {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum DatagramDropReason {
+        #[doc = " There was an error while attempting to decode the datagram."]
+        DecodingFailed,
+        #[doc = " There was an error while parsing the Retry token."]
+        InvalidRetryToken,
+        #[doc = " The peer specified an unsupported QUIC version."]
+        UnsupportedVersion,
+        #[doc = " The peer sent an invalid Destination Connection Id."]
+        InvalidDestinationConnectionId,
+        #[doc = " The peer sent an invalid Source Connection Id."]
+        InvalidSourceConnectionId,
+        #[doc = " The Destination Connection Id is unknown and does not map to a Connection."]
+        #[doc = ""]
+        #[doc = " Connections are mapped to Destination Connections Ids (DCID) and packets"]
+        #[doc = " in a Datagram are routed to a connection based on the DCID in the first"]
+        #[doc = " packet. If a Connection is not found for the specified DCID then the"]
+        #[doc = " datagram can not be processed and is dropped."]
+        UnknownDestinationConnectionId,
+        #[doc = " The connection attempt was rejected."]
+        RejectedConnectionAttempt,
+        #[doc = " A datagram was received from an unknown server address."]
+        UnknownServerAddress,
+        #[doc = " The peer initiated a connection migration before the handshake was confirmed."]
+        ConnectionMigrationDuringHandshake,
+        #[doc = " The attempted connection migration was rejected."]
+        RejectedConnectionMigration,
+        #[doc = " The maximum number of paths per connection was exceeded."]
+        PathLimitExceeded,
+        #[doc = " The peer initiated a connection migration without supplying enough connection IDs to use."]
+        InsufficientConnectionIds,
+    }
+    impl IntoEvent<api::DatagramDropReason> for DatagramDropReason {
+        #[inline]
+        fn into_event(self) -> api::DatagramDropReason {
+            use api::DatagramDropReason::*;
+            match self {
+                Self::DecodingFailed => DecodingFailed {},
+                Self::InvalidRetryToken => InvalidRetryToken {},
+                Self::UnsupportedVersion => UnsupportedVersion {},
+                Self::InvalidDestinationConnectionId => InvalidDestinationConnectionId {},
+                Self::InvalidSourceConnectionId => InvalidSourceConnectionId {},
+                Self::UnknownDestinationConnectionId => UnknownDestinationConnectionId {},
+                Self::RejectedConnectionAttempt => RejectedConnectionAttempt {},
+                Self::UnknownServerAddress => UnknownServerAddress {},
+                Self::ConnectionMigrationDuringHandshake => ConnectionMigrationDuringHandshake {},
+                Self::RejectedConnectionMigration => RejectedConnectionMigration {},
+                Self::PathLimitExceeded => PathLimitExceeded {},
+                Self::InsufficientConnectionIds => InsufficientConnectionIds {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum DatagramFrameDropReason {
+        #[doc = " The datagram was evicted from a full send queue to make room for a newer datagram."]
+        QueueFull,
+        #[doc = " The datagram did not fit in the remaining space of the packet."]
+        ExceedsPacketCapacity,
+        #[doc = " The datagram was larger than the peer's `max_datagram_frame_size`."]
+        ExceedsPeerTransportLimits,
+    }
+    impl IntoEvent<api::DatagramFrameDropReason> for DatagramFrameDropReason {
+        #[inline]
+        fn into_event(self) -> api::DatagramFrameDropReason {
+            use api::DatagramFrameDropReason::*;
+            match self {
+                Self::QueueFull => QueueFull {},
+                Self::ExceedsPacketCapacity => ExceedsPacketCapacity {},
+                Self::ExceedsPeerTransportLimits => ExceedsPeerTransportLimits {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum KeySpace {
+        Initial,
+        Handshake,
+        ZeroRtt,
+        OneRtt,
+    }
+    impl IntoEvent<api::KeySpace> for KeySpace {
+        #[inline]
+        fn into_event(self) -> api::KeySpace {
+            use api::KeySpace::*;
+            match self {
+                Self::Initial => Initial {},
+                Self::Handshake => Handshake {},
+                Self::ZeroRtt => ZeroRtt {},
+                Self::OneRtt => OneRtt {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum PacketDropReason<'a> {
+        #[doc = " A connection error occurred and is no longer able to process packets."]
+        ConnectionError { path: Path<'a> },
+        #[doc = " The handshake needed to be complete before processing the packet."]
+        #[doc = ""]
+        #[doc = " To ensure the connection stays secure, short packets can only be processed"]
+        #[doc = " once the handshake has completed."]
+        HandshakeNotComplete { path: Path<'a> },
+        #[doc = " The packet contained a version which did not match the version negotiated"]
+        #[doc = " during the handshake."]
+        VersionMismatch { version: u32, path: Path<'a> },
+        #[doc = " A datagram contained more than one destination connection ID, which is"]
+        #[doc = " not allowed."]
+        ConnectionIdMismatch {
+            packet_cid: &'a [u8],
+            path: Path<'a>,
+        },
+        #[doc = " There was a failure when attempting to remove header protection."]
+        UnprotectFailed { space: KeySpace, path: Path<'a> },
+        #[doc = " There was a failure when attempting to decrypt the packet."]
+        DecryptionFailed {
+            path: Path<'a>,
+            packet_header: PacketHeader,
+        },
+        #[doc = " Packet decoding failed."]
+        #[doc = ""]
+        #[doc = " The payload is decoded one packet at a time. If decoding fails"]
+        #[doc = " then the remaining packets are also discarded."]
+        DecodingFailed { path: Path<'a> },
+        #[doc = " The client received a non-empty retry token."]
+        NonEmptyRetryToken { path: Path<'a> },
+        #[doc = " A Retry packet was discarded."]
+        RetryDiscarded {
+            reason: RetryDiscardReason<'a>,
+            path: Path<'a>,
+        },
+        #[doc = " The received Initial packet was not transported in a datagram of at least 1200 bytes"]
+        UndersizedInitialPacket { path: Path<'a> },
+        #[doc = " The connection attempt was dropped since the endpoint exceeded its accept rate limit."]
+        RateLimited { path: Path<'a> },
+        #[doc = " The connection attempt was dropped since the endpoint limits denied the source address."]
+        AddressFiltered { path: Path<'a> },
+    }
+    impl<'a> IntoEvent<api::PacketDropReason<'a>> for PacketDropReason<'a> {
+        #[inline]
+        fn into_event(self) -> api::PacketDropReason<'a> {
+            use api::PacketDropReason::*;
+            match self {
+                Self::ConnectionError { path } => ConnectionError {
+                    path: path.into_event(),
+                },
+                Self::HandshakeNotComplete { path } => HandshakeNotComplete {
+                    path: path.into_event(),
+                },
+                Self::VersionMismatch { version, path } => VersionMismatch {
+                    version: version.into_event(),
+                    path: path.into_event(),
+                },
+                Self::ConnectionIdMismatch { packet_cid, path } => ConnectionIdMismatch {
+                    packet_cid: packet_cid.into_event(),
+                    path: path.into_event(),
+                },
+                Self::UnprotectFailed { space, path } => UnprotectFailed {
+                    space: space.into_event(),
+                    path: path.into_event(),
+                },
+                Self::DecryptionFailed {
+                    path,
+                    packet_header,
+                } => DecryptionFailed {
+                    path: path.into_event(),
+                    packet_header: packet_header.into_event(),
+                },
+                Self::DecodingFailed { path } => DecodingFailed {
+                    path: path.into_event(),
+                },
+                Self::NonEmptyRetryToken { path } => NonEmptyRetryToken {
+                    path: path.into_event(),
+                },
+                Self::RetryDiscarded { reason, path } => RetryDiscarded {
+                    reason: reason.into_event(),
+                    path: path.into_event(),
+                },
+                Self::UndersizedInitialPacket { path } => UndersizedInitialPacket {
+                    path: path.into_event(),
+                },
+                Self::RateLimited { path } => RateLimited {
+                    path: path.into_event(),
+                },
+                Self::AddressFiltered { path } => AddressFiltered {
+                    path: path.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum AckAction {
+        #[doc = " Ack range for received packets was dropped due to space constraints"]
+        #[doc = ""]
+        #[doc = " For the purpose of processing Acks, RX packet numbers are stored as"]
+        #[doc = " packet_number ranges in an IntervalSet; only lower and upper bounds"]
+        #[doc = " are stored instead of individual packet_numbers. Ranges are merged"]
+        #[doc = " when possible so only disjointed ranges are stored."]
+        #[doc = ""]
+        #[doc = " When at `capacity`, the lowest packet_number range is dropped."]
+        RxAckRangeDropped {
+            #[doc = " The packet number range which was dropped"]
+            packet_number_range: core::ops::RangeInclusive<u64>,
+            #[doc = " The number of disjoint ranges the IntervalSet can store"]
+            capacity: usize,
+            #[doc = " The store packet_number range in the IntervalSet"]
+            stored_range: core::ops::RangeInclusive<u64>,
+        },
+    }
+    #[allow(deprecated)]
+    impl IntoEvent<api::AckAction> for AckAction {
+        #[inline]
+        fn into_event(self) -> api::AckAction {
+            use api::AckAction::*;
+            match self {
+                Self::RxAckRangeDropped {
+                    packet_number_range,
+                    capacity,
+                    stored_range,
+                } => RxAckRangeDropped {
+                    packet_number_range: packet_number_range.into_event(),
+                    capacity: capacity.into_event(),
+                    stored_range: stored_range.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum RetryDiscardReason<'a> {
+        #[doc = " Received a Retry packet with SCID field equal to DCID field."]
+        ScidEqualsDcid { cid: &'a [u8] },
+        #[doc = " A client only processes at most one Retry packet."]
+        RetryAlreadyProcessed,
+        #[doc = " The client discards Retry packets if a valid Initial packet"]
+        #[doc = " has been received and processed."]
+        InitialAlreadyProcessed,
+        #[doc = " The Retry packet received contained an invalid retry integrity tag"]
+        InvalidIntegrityTag,
+    }
+    impl<'a> IntoEvent<api::RetryDiscardReason<'a>> for RetryDiscardReason<'a> {
+        #[inline]
+        fn into_event(self) -> api::RetryDiscardReason<'a> {
+            use api::RetryDiscardReason::*;
+            match self {
+                Self::ScidEqualsDcid { cid } => ScidEqualsDcid {
+                    cid: cid.into_event(),
+                },
+                Self::RetryAlreadyProcessed => RetryAlreadyProcessed {},
+                Self::InitialAlreadyProcessed => InitialAlreadyProcessed {},
+                Self::InvalidIntegrityTag => InvalidIntegrityTag {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum MigrationDenyReason {
+        BlockedPort,
+        PortScopeChanged,
+        IpScopeChange,
+        ConnectionMigrationDisabled,
+    }
+    impl IntoEvent<api::MigrationDenyReason> for MigrationDenyReason {
+        #[inline]
+        fn into_event(self) -> api::MigrationDenyReason {
+            use api::MigrationDenyReason::*;
+            match self {
+                Self::BlockedPort => BlockedPort {},
+                Self::PortScopeChanged => PortScopeChanged {},
+                Self::IpScopeChange => IpScopeChange {},
+                Self::ConnectionMigrationDisabled => ConnectionMigrationDisabled {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The current state of the ECN controller for the path"]
+    pub enum EcnState {
+        #[doc = " ECN capability is being actively tested"]
+        Testing,
+        #[doc = " ECN capability has been tested, but not validated yet"]
+        Unknown,
+        #[doc = " ECN capability testing has failed validation"]
+        Failed,
+        #[doc = " ECN capability has been confirmed"]
+        Capable,
+    }
+    impl IntoEvent<api::EcnState> for EcnState {
+        #[inline]
+        fn into_event(self) -> api::EcnState {
+            use api::EcnState::*;
+            match self {
+                Self::Testing => Testing {},
+                Self::Unknown => Unknown {},
+                Self::Failed => Failed {},
+                Self::Capable => Capable {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Events tracking the progress of handshake status"]
+    pub enum HandshakeStatus {
+        #[doc = " The handshake has completed."]
+        Complete,
+        #[doc = " The handshake has been confirmed."]
+        Confirmed,
+        #[doc = " A HANDSHAKE_DONE frame was delivered or received."]
+        #[doc = ""]
+        #[doc = " A Client endpoint receives a HANDSHAKE_DONE frame and"]
+        #[doc = " only a Server is allowed to send the HANDSHAKE_DONE"]
+        #[doc = " frame."]
+        HandshakeDoneAcked,
+        #[doc = " A HANDSHAKE_DONE frame was declared lost."]
+        #[doc = ""]
+        #[doc = " The Server is responsible for re-transmitting the"]
+        #[doc = " HANDSHAKE_DONE frame until it is acked by the peer."]
+        HandshakeDoneLost,
+    }
+    impl IntoEvent<api::HandshakeStatus> for HandshakeStatus {
+        #[inline]
+        fn into_event(self) -> api::HandshakeStatus {
+            use api::HandshakeStatus::*;
+            match self {
+                Self::Complete => Complete {},
+                Self::Confirmed => Confirmed {},
+                Self::HandshakeDoneAcked => HandshakeDoneAcked {},
+                Self::HandshakeDoneLost => HandshakeDoneLost {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The phases of the handshake reported by the `HandshakeProgress` event"]
+    pub enum HandshakePhase {
+        #[doc = " The first Initial packet was sent"]
+        InitialSent,
+        #[doc = " The first Handshake packet was received from the peer"]
+        HandshakeReceived,
+        #[doc = " The handshake has been confirmed"]
+        HandshakeConfirmed,
+    }
+    impl IntoEvent<api::HandshakePhase> for HandshakePhase {
+        #[inline]
+        fn into_event(self) -> api::HandshakePhase {
+            use api::HandshakePhase::*;
+            match self {
+                Self::InitialSent => InitialSent {},
+                Self::HandshakeReceived => HandshakeReceived {},
+                Self::HandshakeConfirmed => HandshakeConfirmed {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The source that caused a congestion event"]
+    pub enum CongestionSource {
+        #[doc = " Explicit Congestion Notification"]
+        Ecn,
+        #[doc = " One or more packets were detected lost"]
+        PacketLoss,
+    }
+    impl IntoEvent<api::CongestionSource> for CongestionSource {
+        #[inline]
+        fn into_event(self) -> api::CongestionSource {
+            use api::CongestionSource::*;
+            match self {
+                Self::Ecn => Ecn {},
+                Self::PacketLoss => PacketLoss {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[allow(non_camel_case_types)]
+    pub enum CipherSuite {
+        TLS_AES_128_GCM_SHA256,
+        TLS_AES_256_GCM_SHA384,
+        TLS_CHACHA20_POLY1305_SHA256,
+        Unknown,
+    }
+    impl IntoEvent<api::CipherSuite> for CipherSuite {
+        #[inline]
+        fn into_event(self) -> api::CipherSuite {
+            use api::CipherSuite::*;
+            match self {
+                Self::TLS_AES_128_GCM_SHA256 => TLS_AES_128_GCM_SHA256 {},
+                Self::TLS_AES_256_GCM_SHA384 => TLS_AES_256_GCM_SHA384 {},
+                Self::TLS_CHACHA20_POLY1305_SHA256 => TLS_CHACHA20_POLY1305_SHA256 {},
+                Self::Unknown => Unknown {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum PathChallengeStatus {
+        Validated,
+        Abandoned,
+    }
+    impl IntoEvent<api::PathChallengeStatus> for PathChallengeStatus {
+        #[inline]
+        fn into_event(self) -> api::PathChallengeStatus {
+            use api::PathChallengeStatus::*;
+            match self {
+                Self::Validated => Validated {},
+                Self::Abandoned => Abandoned {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The reason the slow start congestion controller state has been exited"]
+    pub enum SlowStartExitCause {
+        #[doc = " A packet was determined lost"]
+        PacketLoss,
+        #[doc = " An Explicit Congestion Notification: Congestion Experienced marking was received"]
+        Ecn,
+        #[doc = " The round trip time estimate was updated"]
+        Rtt,
+        #[doc = " Slow Start exited due to a reason other than those above"]
+        #[doc = ""]
+        #[doc = " With the Cubic congestion controller, this reason is used after the initial exiting of"]
+        #[doc = " Slow Start, when the previously determined Slow Start threshold is exceed by the"]
+        #[doc = " congestion window."]
+        Other,
+    }
+    impl IntoEvent<api::SlowStartExitCause> for SlowStartExitCause {
+        #[inline]
+        fn into_event(self) -> api::SlowStartExitCause {
+            use api::SlowStartExitCause::*;
+            match self {
+                Self::PacketLoss => PacketLoss {},
+                Self::Ecn => Ecn {},
+                Self::Rtt => Rtt {},
+                Self::Other => Other {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The reason the congestion window was updated"]
+    pub enum CongestionWindowUpdatedCause {
+        #[doc = " The congestion window grew while in Slow Start"]
+        SlowStart,
+        #[doc = " The congestion window grew at a reduced rate while in HyStart++ Conservative Slow Start"]
+        #[doc = ""]
+        #[doc = " Conservative Slow Start is entered after the round trip time increased during"]
+        #[doc = " Slow Start, and precedes an exit to Congestion Avoidance if the increase persists."]
+        ConservativeSlowStart,
+        #[doc = " The congestion window was adjusted while in Congestion Avoidance"]
+        #[doc = ""]
+        #[doc = " With the BBR congestion controller, this cause is used for any adjustment"]
+        #[doc = " made after exiting the Startup state."]
+        CongestionAvoidance,
+        #[doc = " The congestion window was reduced because a packet was determined lost"]
+        PacketLoss,
+        #[doc = " The congestion window was reset to the minimum window due to persistent congestion"]
+        PersistentCongestion,
+        #[doc = " An Explicit Congestion Notification: Congestion Experienced marking was received"]
+        Ecn,
+        #[doc = " The congestion window was scaled to account for an updated MTU"]
+        MtuUpdate,
+        #[doc = " The congestion window was updated due to a reason other than those above"]
+        #[doc = ""]
+        #[doc = " With the BBR congestion controller, this cause is used when the congestion window"]
+        #[doc = " is restored after restarting from idle."]
+        Other,
+    }
+    impl IntoEvent<api::CongestionWindowUpdatedCause> for CongestionWindowUpdatedCause {
+        #[inline]
+        fn into_event(self) -> api::CongestionWindowUpdatedCause {
+            use api::CongestionWindowUpdatedCause::*;
+            match self {
+                Self::SlowStart => SlowStart {},
+                Self::ConservativeSlowStart => ConservativeSlowStart {},
+                Self::CongestionAvoidance => CongestionAvoidance {},
+                Self::PacketLoss => PacketLoss {},
+                Self::PersistentCongestion => PersistentCongestion {},
+                Self::Ecn => Ecn {},
+                Self::MtuUpdate => MtuUpdate {},
+                Self::Other => Other {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The reason the MTU was updated"]
+    pub enum MtuUpdatedCause {
+        #[doc = " The MTU was initialized with the default value"]
+        NewPath,
+        #[doc = " An MTU probe was acknowledged by the peer"]
+        ProbeAcknowledged,
+        #[doc = " A blackhole was detected"]
+        Blackhole,
+    }
+    impl IntoEvent<api::MtuUpdatedCause> for MtuUpdatedCause {
+        #[inline]
+        fn into_event(self) -> api::MtuUpdatedCause {
+            use api::MtuUpdatedCause::*;
+            match self {
+                Self::NewPath => NewPath {},
+                Self::ProbeAcknowledged => ProbeAcknowledged {},
+                Self::Blackhole => Blackhole {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The flow control limit that blocked the local endpoint from sending data"]
+    pub enum FlowControlScope {
+        #[doc = " The connection limit advertised by the peer in MAX_DATA frames"]
+        Connection,
+        #[doc = " The stream limit advertised by the peer in MAX_STREAM_DATA frames"]
+        Stream { id: u64 },
+    }
+    impl IntoEvent<api::FlowControlScope> for FlowControlScope {
+        #[inline]
+        fn into_event(self) -> api::FlowControlScope {
+            use api::FlowControlScope::*;
+            match self {
+                Self::Connection => Connection {},
+                Self::Stream { id } => Stream {
+                    id: id.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum BbrState {
+        Startup,
+        Drain,
+        ProbeBwDown,
+        ProbeBwCruise,
+        ProbeBwRefill,
+        ProbeBwUp,
+        ProbeRtt,
+    }
+    impl IntoEvent<api::BbrState> for BbrState {
+        #[inline]
+        fn into_event(self) -> api::BbrState {
+            use api::BbrState::*;
+            match self {
+                Self::Startup => Startup {},
+                Self::Drain => Drain {},
+                Self::ProbeBwDown => ProbeBwDown {},
+                Self::ProbeBwCruise => ProbeBwCruise {},
+                Self::ProbeBwRefill => ProbeBwRefill {},
+                Self::ProbeBwUp => ProbeBwUp {},
+                Self::ProbeRtt => ProbeRtt {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Application level protocol"]
+    pub struct ApplicationProtocolInformation<'a> {
+        pub chosen_application_protocol: &'a [u8],
+    }
+    impl<'a> IntoEvent<api::ApplicationProtocolInformation<'a>> for ApplicationProtocolInformation<'a> {
+        #[inline]
+        fn into_event(self) -> api::ApplicationProtocolInformation<'a> {
+            let ApplicationProtocolInformation {
+                chosen_application_protocol,
+            } = self;
+            api::ApplicationProtocolInformation {
+                chosen_application_protocol: chosen_application_protocol.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Server Name was negotiated for the connection"]
+    pub struct ServerNameInformation<'a> {
+        pub chosen_server_name: &'a str,
+    }
+    impl<'a> IntoEvent<api::ServerNameInformation<'a>> for ServerNameInformation<'a> {
+        #[inline]
+        fn into_event(self) -> api::ServerNameInformation<'a> {
+            let ServerNameInformation { chosen_server_name } = self;
+            api::ServerNameInformation {
+                chosen_server_name: chosen_server_name.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was sent by a connection"]
+    pub struct PacketSent {
+        pub packet_header: PacketHeader,
+        pub packet_len: usize,
+    }
+    impl IntoEvent<api::PacketSent> for PacketSent {
+        #[inline]
+        fn into_event(self) -> api::PacketSent {
+            let PacketSent {
+                packet_header,
+                packet_len,
+            } = self;
+            api::PacketSent {
+                packet_header: packet_header.into_event(),
+                packet_len: packet_len.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was received by a connection"]
+    pub struct PacketReceived {
+        pub packet_header: PacketHeader,
+    }
+    impl IntoEvent<api::PacketReceived> for PacketReceived {
+        #[inline]
+        fn into_event(self) -> api::PacketReceived {
+            let PacketReceived { packet_header } = self;
+            api::PacketReceived {
+                packet_header: packet_header.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Active path was updated"]
+    pub struct ActivePathUpdated<'a> {
+        pub previous: Path<'a>,
+        pub active: Path<'a>,
+    }
+    impl<'a> IntoEvent<api::ActivePathUpdated<'a>> for ActivePathUpdated<'a> {
+        #[inline]
+        fn into_event(self) -> api::ActivePathUpdated<'a> {
+            let ActivePathUpdated { previous, active } = self;
+            api::ActivePathUpdated {
+                previous: previous.into_event(),
+                active: active.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A new path was created"]
+    pub struct PathCreated<'a> {
+        pub active: Path<'a>,
+        pub new: Path<'a>,
+    }
+    impl<'a> IntoEvent<api::PathCreated<'a>> for PathCreated<'a> {
+        #[inline]
+        fn into_event(self) -> api::PathCreated<'a> {
+            let PathCreated { active, new } = self;
+            api::PathCreated {
+                active: active.into_event(),
+                new: new.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Frame was sent"]
+    pub struct FrameSent {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        pub frame: Frame,
+    }
+    impl IntoEvent<api::FrameSent> for FrameSent {
+        #[inline]
+        fn into_event(self) -> api::FrameSent {
+            let FrameSent {
+                packet_header,
+                path_id,
+                frame,
+            } = self;
+            api::FrameSent {
+                packet_header: packet_header.into_event(),
+                path_id: path_id.into_event(),
+                frame: frame.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Frame was received"]
+    pub struct FrameReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        pub frame: Frame,
+    }
+    impl<'a> IntoEvent<api::FrameReceived<'a>> for FrameReceived<'a> {
+        #[inline]
+        fn into_event(self) -> api::FrameReceived<'a> {
+            let FrameReceived {
+                packet_header,
+                path,
+                frame,
+            } = self;
+            api::FrameReceived {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                frame: frame.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was lost"]
+    pub struct PacketLost<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        pub bytes_lost: u16,
+        pub is_mtu_probe: bool,
+    }
+    impl<'a> IntoEvent<api::PacketLost<'a>> for PacketLost<'a> {
+        #[inline]
+        fn into_event(self) -> api::PacketLost<'a> {
+            let PacketLost {
+                packet_header,
+                path,
+                bytes_lost,
+                is_mtu_probe,
+            } = self;
+            api::PacketLost {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                bytes_lost: bytes_lost.into_event(),
+                is_mtu_probe: is_mtu_probe.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Recovery metrics updated"]
+    pub struct RecoveryMetrics<'a> {
+        pub path: Path<'a>,
+        pub min_rtt: Duration,
+        pub smoothed_rtt: Duration,
+        pub latest_rtt: Duration,
+        pub rtt_variance: Duration,
+        pub max_ack_delay: Duration,
+        pub pto_count: u32,
+        pub congestion_window: u32,
+        pub bytes_in_flight: u32,
+        pub congestion_limited: bool,
+    }
+    impl<'a> IntoEvent<api::RecoveryMetrics<'a>> for RecoveryMetrics<'a> {
+        #[inline]
+        fn into_event(self) -> api::RecoveryMetrics<'a> {
+            let RecoveryMetrics {
+                path,
+                min_rtt,
+                smoothed_rtt,
+                latest_rtt,
+                rtt_variance,
+                max_ack_delay,
+                pto_count,
+                congestion_window,
+                bytes_in_flight,
+                congestion_limited,
+            } = self;
+            api::RecoveryMetrics {
+                path: path.into_event(),
+                min_rtt: min_rtt.into_event(),
+                smoothed_rtt: smoothed_rtt.into_event(),
+                latest_rtt: latest_rtt.into_event(),
+                rtt_variance: rtt_variance.into_event(),
+                max_ack_delay: max_ack_delay.into_event(),
+                pto_count: pto_count.into_event(),
+                congestion_window: congestion_window.into_event(),
+                bytes_in_flight: bytes_in_flight.into_event(),
+                congestion_limited: congestion_limited.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Congestion (ECN or packet loss) has occurred"]
+    pub struct Congestion<'a> {
+        pub path: Path<'a>,
+        pub source: CongestionSource,
+    }
+    impl<'a> IntoEvent<api::Congestion<'a>> for Congestion<'a> {
+        #[inline]
+        fn into_event(self) -> api::Congestion<'a> {
+            let Congestion { path, source } = self;
+            api::Congestion {
+                path: path.into_event(),
+                source: source.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Persistent congestion was declared and the congestion window was collapsed"]
+    pub struct PersistentCongestion<'a> {
+        pub path: Path<'a>,
+        #[doc = " The length of the period in which all ack-eliciting packets were lost"]
+        pub duration: Duration,
+        #[doc = " The congestion window after persistent congestion was declared"]
+        pub congestion_window: u32,
+    }
+    impl<'a> IntoEvent<api::PersistentCongestion<'a>> for PersistentCongestion<'a> {
+        #[inline]
+        fn into_event(self) -> api::PersistentCongestion<'a> {
+            let PersistentCongestion {
+                path,
+                duration,
+                congestion_window,
+            } = self;
+            api::PersistentCongestion {
+                path: path.into_event(),
+                duration: duration.into_event(),
+                congestion_window: congestion_window.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Events related to ACK processing"]
+    pub struct AckProcessed<'a> {
+        pub action: AckAction,
+        pub path: Path<'a>,
+    }
+    #[allow(deprecated)]
+    impl<'a> IntoEvent<api::AckProcessed<'a>> for AckProcessed<'a> {
+        #[inline]
+        fn into_event(self) -> api::AckProcessed<'a> {
+            let AckProcessed { action, path } = self;
+            api::AckProcessed {
+                action: action.into_event(),
+                path: path.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Ack range for received packets was dropped due to space constraints"]
+    #[doc = ""]
+    #[doc = " For the purpose of processing Acks, RX packet numbers are stored as"]
+    #[doc = " packet_number ranges in an IntervalSet; only lower and upper bounds"]
+    #[doc = " are stored instead of individual packet_numbers. Ranges are merged"]
+    #[doc = " when possible so only disjointed ranges are stored."]
+    #[doc = ""]
+    #[doc = " When at `capacity`, the lowest packet_number range is dropped."]
+    pub struct RxAckRangeDropped<'a> {
+        pub path: Path<'a>,
+        #[doc = " The packet number range which was dropped"]
+        pub packet_number_range: core::ops::RangeInclusive<u64>,
+        #[doc = " The number of disjoint ranges the IntervalSet can store"]
+        pub capacity: usize,
+        #[doc = " The store packet_number range in the IntervalSet"]
+        pub stored_range: core::ops::RangeInclusive<u64>,
+    }
+    impl<'a> IntoEvent<api::RxAckRangeDropped<'a>> for RxAckRangeDropped<'a> {
+        #[inline]
+        fn into_event(self) -> api::RxAckRangeDropped<'a> {
+            let RxAckRangeDropped {
+                path,
+                packet_number_range,
+                capacity,
+                stored_range,
+            } = self;
+            api::RxAckRangeDropped {
+                path: path.into_event(),
+                packet_number_range: packet_number_range.into_event(),
+                capacity: capacity.into_event(),
+                stored_range: stored_range.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " ACK range was received"]
+    pub struct AckRangeReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        pub ack_range: RangeInclusive<u64>,
+    }
+    impl<'a> IntoEvent<api::AckRangeReceived<'a>> for AckRangeReceived<'a> {
+        #[inline]
+        fn into_event(self) -> api::AckRangeReceived<'a> {
+            let AckRangeReceived {
+                packet_header,
+                path,
+                ack_range,
+            } = self;
+            api::AckRangeReceived {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                ack_range: ack_range.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " ACK range was sent"]
+    pub struct AckRangeSent {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        pub ack_range: RangeInclusive<u64>,
+    }
+    impl IntoEvent<api::AckRangeSent> for AckRangeSent {
+        #[inline]
+        fn into_event(self) -> api::AckRangeSent {
+            let AckRangeSent {
+                packet_header,
+                path_id,
+                ack_range,
+            } = self;
+            api::AckRangeSent {
+                packet_header: packet_header.into_event(),
+                path_id: path_id.into_event(),
+                ack_range: ack_range.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was dropped with the given reason"]
+    pub struct PacketDropped<'a> {
+        pub reason: PacketDropReason<'a>,
+    }
+    impl<'a> IntoEvent<api::PacketDropped<'a>> for PacketDropped<'a> {
+        #[inline]
+        fn into_event(self) -> api::PacketDropped<'a> {
+            let PacketDropped { reason } = self;
+            api::PacketDropped {
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Crypto key updated"]
+    pub struct KeyUpdate {
+        pub key_type: KeyType,
+        pub cipher_suite: CipherSuite,
+    }
+    impl IntoEvent<api::KeyUpdate> for KeyUpdate {
+        #[inline]
+        fn into_event(self) -> api::KeyUpdate {
+            let KeyUpdate {
+                key_type,
+                cipher_suite,
+            } = self;
+            api::KeyUpdate {
+                key_type: key_type.into_event(),
+                cipher_suite: cipher_suite.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct KeySpaceDiscarded {
+        pub space: KeySpace,
+    }
+    impl IntoEvent<api::KeySpaceDiscarded> for KeySpaceDiscarded {
+        #[inline]
+        fn into_event(self) -> api::KeySpaceDiscarded {
+            let KeySpaceDiscarded { space } = self;
+            api::KeySpaceDiscarded {
+                space: space.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Connection started"]
+    pub struct ConnectionStarted<'a> {
+        pub path: Path<'a>,
+    }
+    impl<'a> IntoEvent<api::ConnectionStarted<'a>> for ConnectionStarted<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionStarted<'a> {
+            let ConnectionStarted { path } = self;
+            api::ConnectionStarted {
+                path: path.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Connection closed"]
+    #[doc = ""]
+    #[doc = " This is emitted for both graceful and abrupt closes and summarizes the lifetime of the"]
+    #[doc = " connection."]
+    pub struct ConnectionClosed {
+        pub error: crate::connection::Error,
+        #[doc = " The amount of time since the connection was created"]
+        pub duration: Duration,
+        #[doc = " The statistics of the connection at the time it was closed"]
+        pub statistics: crate::connection::Statistics,
+    }
+    impl IntoEvent<api::ConnectionClosed> for ConnectionClosed {
+        #[inline]
+        fn into_event(self) -> api::ConnectionClosed {
+            let ConnectionClosed {
+                error,
+                duration,
+                statistics,
+            } = self;
+            api::ConnectionClosed {
+                error: error.into_event(),
+                duration: duration.into_event(),
+                statistics: statistics.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A graceful close finished flushing the connection or gave up after its timeout"]
+    #[doc = ""]
+    #[doc = " This is emitted before `ConnectionClosed` so subscribers can tell graceful closes"]
+    #[doc = " apart from abrupt ones."]
+    pub struct GracefulClose {
+        #[doc = " Set if the timeout elapsed before all of the outstanding stream data was flushed"]
+        pub timed_out: bool,
+    }
+    impl IntoEvent<api::GracefulClose> for GracefulClose {
+        #[inline]
+        fn into_event(self) -> api::GracefulClose {
+            let GracefulClose { timed_out } = self;
+            api::GracefulClose {
+                timed_out: timed_out.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Duplicate packet received"]
+    pub struct DuplicatePacket<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        pub error: DuplicatePacketError,
+    }
+    impl<'a> IntoEvent<api::DuplicatePacket<'a>> for DuplicatePacket<'a> {
+        #[inline]
+        fn into_event(self) -> api::DuplicatePacket<'a> {
+            let DuplicatePacket {
+                packet_header,
+                path,
+                error,
+            } = self;
+            api::DuplicatePacket {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                error: error.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Transport parameters received by connection"]
+    pub struct TransportParametersReceived<'a> {
+        pub transport_parameters: TransportParameters<'a>,
+    }
+    impl<'a> IntoEvent<api::TransportParametersReceived<'a>> for TransportParametersReceived<'a> {
+        #[inline]
+        fn into_event(self) -> api::TransportParametersReceived<'a> {
+            let TransportParametersReceived {
+                transport_parameters,
+            } = self;
+            api::TransportParametersReceived {
+                transport_parameters: transport_parameters.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram sent by a connection"]
+    pub struct DatagramSent {
+        pub len: u16,
+        #[doc = " The GSO offset at which this datagram was written"]
+        #[doc = ""]
+        #[doc = " If this value is greater than 0, it indicates that this datagram has been sent with other"]
+        #[doc = " segments in a single buffer."]
+        #[doc = ""]
+        #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
+        pub gso_offset: usize,
+    }
+    impl IntoEvent<api::DatagramSent> for DatagramSent {
+        #[inline]
+        fn into_event(self) -> api::DatagramSent {
+            let DatagramSent { len, gso_offset } = self;
+            api::DatagramSent {
+                len: len.into_event(),
+                gso_offset: gso_offset.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram received by a connection"]
+    pub struct DatagramReceived {
+        pub len: u16,
+    }
+    impl IntoEvent<api::DatagramReceived> for DatagramReceived {
+        #[inline]
+        fn into_event(self) -> api::DatagramReceived {
+            let DatagramReceived { len } = self;
+            api::DatagramReceived {
+                len: len.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram dropped by a connection"]
+    pub struct DatagramDropped {
+        pub len: u16,
+        pub reason: DatagramDropReason,
+    }
+    impl IntoEvent<api::DatagramDropped> for DatagramDropped {
+        #[inline]
+        fn into_event(self) -> api::DatagramDropped {
+            let DatagramDropped { len, reason } = self;
+            api::DatagramDropped {
+                len: len.into_event(),
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Application datagrams were dropped by the connection before they were sent"]
+    pub struct DatagramFrameDropped {
+        #[doc = " The number of datagrams that were dropped"]
+        pub count: usize,
+        #[doc = " The total number of bytes in the dropped datagrams"]
+        pub len: usize,
+        pub reason: DatagramFrameDropReason,
+    }
+    impl IntoEvent<api::DatagramFrameDropped> for DatagramFrameDropped {
+        #[inline]
+        fn into_event(self) -> api::DatagramFrameDropped {
+            let DatagramFrameDropped { count, len, reason } = self;
+            api::DatagramFrameDropped {
+                count: count.into_event(),
+                len: len.into_event(),
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " ConnectionId updated"]
+    pub struct ConnectionIdUpdated<'a> {
+        pub path_id: u64,
+        #[doc = " The endpoint that updated its connection id"]
+        pub cid_consumer: crate::endpoint::Location,
+        pub previous: ConnectionId<'a>,
+        pub current: ConnectionId<'a>,
+    }
+    impl<'a> IntoEvent<api::ConnectionIdUpdated<'a>> for ConnectionIdUpdated<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionIdUpdated<'a> {
+            let ConnectionIdUpdated {
+                path_id,
+                cid_consumer,
+                previous,
+                current,
+            } = self;
+            api::ConnectionIdUpdated {
+                path_id: path_id.into_event(),
+                cid_consumer: cid_consumer.into_event(),
+                previous: previous.into_event(),
+                current: current.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A new connection ID was issued to the peer"]
+    pub struct ConnectionIdIssued<'a> {
+        pub sequence_number: u64,
+        pub connection_id: ConnectionId<'a>,
+    }
+    impl<'a> IntoEvent<api::ConnectionIdIssued<'a>> for ConnectionIdIssued<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionIdIssued<'a> {
+            let ConnectionIdIssued {
+                sequence_number,
+                connection_id,
+            } = self;
+            api::ConnectionIdIssued {
+                sequence_number: sequence_number.into_event(),
+                connection_id: connection_id.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The peer retired a connection ID that was issued to it"]
+    pub struct ConnectionIdRetired<'a> {
+        pub sequence_number: u64,
+        pub connection_id: ConnectionId<'a>,
+    }
+    impl<'a> IntoEvent<api::ConnectionIdRetired<'a>> for ConnectionIdRetired<'a> {
+        #[inline]
+        fn into_event(self) -> api::ConnectionIdRetired<'a> {
+            let ConnectionIdRetired {
+                sequence_number,
+                connection_id,
+            } = self;
+            api::ConnectionIdRetired {
+                sequence_number: sequence_number.into_event(),
+                connection_id: connection_id.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The local endpoint has data to send but is blocked by the peer's flow control limits"]
+    #[doc = ""]
+    #[doc = " This is emitted each time a DATA_BLOCKED or STREAM_DATA_BLOCKED frame is sent to the peer."]
+    pub struct FlowControlBlocked {
+        pub scope: FlowControlScope,
+        #[doc = " The flow control limit, in bytes, that blocked the local endpoint"]
+        pub window: u64,
+    }
+    impl IntoEvent<api::FlowControlBlocked> for FlowControlBlocked {
+        #[inline]
+        fn into_event(self) -> api::FlowControlBlocked {
+            let FlowControlBlocked { scope, window } = self;
+            api::FlowControlBlocked {
+                scope: scope.into_event(),
+                window: window.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct EcnStateChanged<'a> {
+        pub path: Path<'a>,
+        pub state: EcnState,
+    }
+    impl<'a> IntoEvent<api::EcnStateChanged<'a>> for EcnStateChanged<'a> {
+        #[inline]
+        fn into_event(self) -> api::EcnStateChanged<'a> {
+            let EcnStateChanged { path, state } = self;
+            api::EcnStateChanged {
+                path: path.into_event(),
+                state: state.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ConnectionMigrationDenied {
+        pub reason: MigrationDenyReason,
+    }
+    impl IntoEvent<api::ConnectionMigrationDenied> for ConnectionMigrationDenied {
+        #[inline]
+        fn into_event(self) -> api::ConnectionMigrationDenied {
+            let ConnectionMigrationDenied { reason } = self;
+            api::ConnectionMigrationDenied {
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The peer's address changed without it changing its connection ID, which usually means a"]
+    #[doc = " NAT rebinding occurred"]
+    #[doc = ""]
+    #[doc = " This is emitted once validation of the new path finishes. `path` has the same id as"]
+    #[doc = " the `MtuUpdated` event with a `NewPath` cause that was emitted when the path was created."]
+    pub struct NatRebindingDetected<'a> {
+        #[doc = " The path created for the peer's new address"]
+        pub path: Path<'a>,
+        #[doc = " The address the peer was using before the rebinding"]
+        pub previous_remote_addr: SocketAddress<'a>,
+        #[doc = " Set if the peer's new address was validated"]
+        pub validated: bool,
+    }
+    impl<'a> IntoEvent<api::NatRebindingDetected<'a>> for NatRebindingDetected<'a> {
+        #[inline]
+        fn into_event(self) -> api::NatRebindingDetected<'a> {
+            let NatRebindingDetected {
+                path,
+                previous_remote_addr,
+                validated,
+            } = self;
+            api::NatRebindingDetected {
+                path: path.into_event(),
+                previous_remote_addr: previous_remote_addr.into_event(),
+                validated: validated.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct HandshakeStatusUpdated {
+        pub status: HandshakeStatus,
+    }
+    impl IntoEvent<api::HandshakeStatusUpdated> for HandshakeStatusUpdated {
+        #[inline]
+        fn into_event(self) -> api::HandshakeStatusUpdated {
+            let HandshakeStatusUpdated { status } = self;
+            api::HandshakeStatusUpdated {
+                status: status.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Path challenge updated"]
+    pub struct PathChallengeUpdated<'a> {
+        pub path_challenge_status: PathChallengeStatus,
+        pub path: Path<'a>,
+        pub challenge_data: &'a [u8],
+    }
+    impl<'a> IntoEvent<api::PathChallengeUpdated<'a>> for PathChallengeUpdated<'a> {
+        #[inline]
+        fn into_event(self) -> api::PathChallengeUpdated<'a> {
+            let PathChallengeUpdated {
+                path_challenge_status,
+                path,
+                challenge_data,
+            } = self;
+            api::PathChallengeUpdated {
+                path_challenge_status: path_challenge_status.into_event(),
+                path: path.into_event(),
+                challenge_data: challenge_data.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct TlsClientHello<'a> {
+        pub payload: &'a [&'a [u8]],
+    }
+    impl<'a> IntoEvent<api::TlsClientHello<'a>> for TlsClientHello<'a> {
+        #[inline]
+        fn into_event(self) -> api::TlsClientHello<'a> {
+            let TlsClientHello { payload } = self;
+            api::TlsClientHello {
+                payload: payload.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct TlsServerHello<'a> {
+        pub payload: &'a [&'a [u8]],
+    }
+    impl<'a> IntoEvent<api::TlsServerHello<'a>> for TlsServerHello<'a> {
+        #[inline]
+        fn into_event(self) -> api::TlsServerHello<'a> {
+            let TlsServerHello { payload } = self;
+            api::TlsServerHello {
+                payload: payload.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct RxStreamProgress {
+        pub bytes: usize,
+    }
+    impl IntoEvent<api::RxStreamProgress> for RxStreamProgress {
+        #[inline]
+        fn into_event(self) -> api::RxStreamProgress {
+            let RxStreamProgress { bytes } = self;
+            api::RxStreamProgress {
+                bytes: bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct TxStreamProgress {
+        pub bytes: usize,
+    }
+    impl IntoEvent<api::TxStreamProgress> for TxStreamProgress {
+        #[inline]
+        fn into_event(self) -> api::TxStreamProgress {
+            let TxStreamProgress { bytes } = self;
+            api::TxStreamProgress {
+                bytes: bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct KeepAliveTimerExpired {
+        pub timeout: Duration,
+    }
+    impl IntoEvent<api::KeepAliveTimerExpired> for KeepAliveTimerExpired {
+        #[inline]
+        fn into_event(self) -> api::KeepAliveTimerExpired {
+            let KeepAliveTimerExpired { timeout } = self;
+            api::KeepAliveTimerExpired {
+                timeout: timeout.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The maximum transmission unit (MTU) for the path has changed"]
+    pub struct MtuUpdated {
+        pub path_id: u64,
+        pub mtu: u16,
+        pub cause: MtuUpdatedCause,
+    }
+    impl IntoEvent<api::MtuUpdated> for MtuUpdated {
+        #[inline]
+        fn into_event(self) -> api::MtuUpdated {
+            let MtuUpdated {
+                path_id,
+                mtu,
+                cause,
+            } = self;
+            api::MtuUpdated {
+                path_id: path_id.into_event(),
+                mtu: mtu.into_event(),
+                cause: cause.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " MTU probing for the path has concluded"]
+    #[doc = ""]
+    #[doc = " The MTU is not expected to change unless a black hole is detected, after which"]
+    #[doc = " probing is restarted and this event will be emitted again once it concludes."]
+    pub struct MtuProbingComplete {
+        pub path_id: u64,
+        #[doc = " The MTU confirmed for the path"]
+        pub mtu: u16,
+        #[doc = " The largest MTU that was probed for"]
+        pub max_probed_mtu: u16,
+        #[doc = " The number of MTU probes that were sent"]
+        pub probes_sent: u32,
+    }
+    impl IntoEvent<api::MtuProbingComplete> for MtuProbingComplete {
+        #[inline]
+        fn into_event(self) -> api::MtuProbingComplete {
+            let MtuProbingComplete {
+                path_id,
+                mtu,
+                max_probed_mtu,
+                probes_sent,
+            } = self;
+            api::MtuProbingComplete {
+                path_id: path_id.into_event(),
+                mtu: mtu.into_event(),
+                max_probed_mtu: max_probed_mtu.into_event(),
+                probes_sent: probes_sent.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The slow start congestion controller state has been exited"]
+    pub struct SlowStartExited {
+        pub path_id: u64,
+        pub cause: SlowStartExitCause,
+        pub congestion_window: u32,
+    }
+    impl IntoEvent<api::SlowStartExited> for SlowStartExited {
+        #[inline]
+        fn into_event(self) -> api::SlowStartExited {
+            let SlowStartExited {
+                path_id,
+                cause,
+                congestion_window,
+            } = self;
+            api::SlowStartExited {
+                path_id: path_id.into_event(),
+                cause: cause.into_event(),
+                congestion_window: congestion_window.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The congestion window has been updated"]
+    pub struct CongestionWindowUpdated {
+        pub path_id: u64,
+        pub cause: CongestionWindowUpdatedCause,
+        #[doc = " The congestion window before the update"]
+        pub old_congestion_window: u32,
+        #[doc = " The congestion window after the update"]
+        pub new_congestion_window: u32,
+        pub bytes_in_flight: u32,
+    }
+    impl IntoEvent<api::CongestionWindowUpdated> for CongestionWindowUpdated {
+        #[inline]
+        fn into_event(self) -> api::CongestionWindowUpdated {
+            let CongestionWindowUpdated {
+                path_id,
+                cause,
+                old_congestion_window,
+                new_congestion_window,
+                bytes_in_flight,
+            } = self;
+            api::CongestionWindowUpdated {
+                path_id: path_id.into_event(),
+                cause: cause.into_event(),
+                old_congestion_window: old_congestion_window.into_event(),
+                new_congestion_window: new_congestion_window.into_event(),
+                bytes_in_flight: bytes_in_flight.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A new delivery rate sample has been generated"]
+    #[doc = " Note: This event is only recorded for congestion controllers that support"]
+    #[doc = "       bandwidth estimates, such as BBR"]
+    pub struct DeliveryRateSampled {
+        pub path_id: u64,
+        pub rate_sample: RateSample,
+    }
+    impl IntoEvent<api::DeliveryRateSampled> for DeliveryRateSampled {
+        #[inline]
+        fn into_event(self) -> api::DeliveryRateSampled {
+            let DeliveryRateSampled {
+                path_id,
+                rate_sample,
+            } = self;
+            api::DeliveryRateSampled {
+                path_id: path_id.into_event(),
+                rate_sample: rate_sample.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The pacing rate has been updated"]
+    pub struct PacingRateUpdated {
+        pub path_id: u64,
+        pub bytes_per_second: u64,
+        pub burst_size: u32,
+        pub pacing_gain: f32,
+    }
+    impl IntoEvent<api::PacingRateUpdated> for PacingRateUpdated {
+        #[inline]
+        fn into_event(self) -> api::PacingRateUpdated {
+            let PacingRateUpdated {
+                path_id,
+                bytes_per_second,
+                burst_size,
+                pacing_gain,
+            } = self;
+            api::PacingRateUpdated {
+                path_id: path_id.into_event(),
+                bytes_per_second: bytes_per_second.into_event(),
+                burst_size: burst_size.into_event(),
+                pacing_gain: pacing_gain.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The BBR state has changed"]
+    pub struct BbrStateChanged {
+        pub path_id: u64,
+        pub state: BbrState,
+    }
+    impl IntoEvent<api::BbrStateChanged> for BbrStateChanged {
+        #[inline]
+        fn into_event(self) -> api::BbrStateChanged {
+            let BbrStateChanged { path_id, state } = self;
+            api::BbrStateChanged {
+                path_id: path_id.into_event(),
+                state: state.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The handshake has reached a new phase"]
+    #[doc = ""]
+    #[doc = " Each phase is reported once and in order."]
+    pub struct HandshakeProgress {
+        pub phase: HandshakePhase,
+        #[doc = " The time elapsed since the connection was created"]
+        pub elapsed: Duration,
+    }
+    impl IntoEvent<api::HandshakeProgress> for HandshakeProgress {
+        #[inline]
+        fn into_event(self) -> api::HandshakeProgress {
+            let HandshakeProgress { phase, elapsed } = self;
+            api::HandshakeProgress {
+                phase: phase.into_event(),
+                elapsed: elapsed.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The handshake has been confirmed"]
+    #[doc = ""]
+    #[doc = " This is emitted after the `HandshakeConfirmed` phase is reported by `HandshakeProgress`."]
+    pub struct HandshakeComplete {
+        #[doc = " The time elapsed between the connection being created and the handshake being confirmed"]
+        pub duration: Duration,
+        #[doc = " Set if 0-RTT keys were negotiated during the handshake"]
+        pub zero_rtt: bool,
+        #[doc = " Set if the TLS provider resumed a previous session"]
+        pub resumed: bool,
+    }
+    impl IntoEvent<api::HandshakeComplete> for HandshakeComplete {
+        #[inline]
+        fn into_event(self) -> api::HandshakeComplete {
+            let HandshakeComplete {
+                duration,
+                zero_rtt,
+                resumed,
+            } = self;
+            api::HandshakeComplete {
+                duration: duration.into_event(),
+                zero_rtt: zero_rtt.into_event(),
+                resumed: resumed.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The 1-RTT keys were updated"]
+    #[doc = ""]
+    #[doc = " This is emitted by both peers once the update is complete, regardless of which side"]
+    #[doc = " initiated it."]
+    pub struct KeyUpdated {
+        #[doc = " The key phase of the updated keys"]
+        pub key_phase: u8,
+        #[doc = " The number of packets that were sent with the previous keys"]
+        pub packets_sent: u64,
+    }
+    impl IntoEvent<api::KeyUpdated> for KeyUpdated {
+        #[inline]
+        fn into_event(self) -> api::KeyUpdated {
+            let KeyUpdated {
+                key_phase,
+                packets_sent,
+            } = self;
+            api::KeyUpdated {
+                key_phase: key_phase.into_event(),
+                packets_sent: packets_sent.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " None of the PATH_CHALLENGE frames sent on a path were answered before the validation"]
+    #[doc = " timeout expired"]
+    #[doc = ""]
+    #[doc = " If the path was active, the connection falls back to the last validated path."]
+    pub struct PathValidationFailed<'a> {
+        #[doc = " The path that failed validation"]
+        pub path: Path<'a>,
+        #[doc = " The number of PATH_CHALLENGE frames sent on the path"]
+        pub attempts: u8,
+        #[doc = " The path the connection fell back to, if the failed path was active"]
+        pub fallback_path: Option<Path<'a>>,
+    }
+    impl<'a> IntoEvent<api::PathValidationFailed<'a>> for PathValidationFailed<'a> {
+        #[inline]
+        fn into_event(self) -> api::PathValidationFailed<'a> {
+            let PathValidationFailed {
+                path,
+                attempts,
+                fallback_path,
+            } = self;
+            api::PathValidationFailed {
+                path: path.into_event(),
+                attempts: attempts.into_event(),
+                fallback_path: fallback_path.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The rate at which acknowledgements are sent for received packets has changed"]
+    #[doc = ""]
+    #[doc = " This is emitted when an ACK_FREQUENCY frame from the peer is applied."]
+    pub struct AckFrequencyUpdated {
+        #[doc = " The sequence number of the ACK_FREQUENCY frame that was applied"]
+        pub sequence_number: u64,
+        #[doc = " The number of ack-eliciting packets that are received before an acknowledgement is sent"]
+        pub ack_eliciting_threshold: u64,
+        #[doc = " The maximum amount of time that an acknowledgement is delayed"]
+        pub max_ack_delay: Duration,
+        #[doc = " The number of packets that can be reordered before an acknowledgement is sent immediately"]
+        pub reordering_threshold: u64,
+    }
+    impl IntoEvent<api::AckFrequencyUpdated> for AckFrequencyUpdated {
+        #[inline]
+        fn into_event(self) -> api::AckFrequencyUpdated {
+            let AckFrequencyUpdated {
+                sequence_number,
+                ack_eliciting_threshold,
+                max_ack_delay,
+                reordering_threshold,
+            } = self;
+            api::AckFrequencyUpdated {
+                sequence_number: sequence_number.into_event(),
+                ack_eliciting_threshold: ack_eliciting_threshold.into_event(),
+                max_ack_delay: max_ack_delay.into_event(),
+                reordering_threshold: reordering_threshold.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " QUIC version"]
+    pub struct VersionInformation<'a> {
+        pub server_versions: &'a [u32],
+        pub client_versions: &'a [u32],
+        pub chosen_version: Option<u32>,
+    }
+    impl<'a> IntoEvent<api::VersionInformation<'a>> for VersionInformation<'a> {
+        #[inline]
+        fn into_event(self) -> api::VersionInformation<'a> {
+            let VersionInformation {
+                server_versions,
+                client_versions,
+                chosen_version,
+            } = self;
+            api::VersionInformation {
+                server_versions: server_versions.into_event(),
+                client_versions: client_versions.into_event(),
+                chosen_version: chosen_version.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was sent by the endpoint"]
+    pub struct EndpointPacketSent {
+        pub packet_header: PacketHeader,
+    }
+    impl IntoEvent<api::EndpointPacketSent> for EndpointPacketSent {
+        #[inline]
+        fn into_event(self) -> api::EndpointPacketSent {
+            let EndpointPacketSent { packet_header } = self;
+            api::EndpointPacketSent {
+                packet_header: packet_header.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Packet was received by the endpoint"]
+    pub struct EndpointPacketReceived {
+        pub packet_header: PacketHeader,
+    }
+    impl IntoEvent<api::EndpointPacketReceived> for EndpointPacketReceived {
+        #[inline]
+        fn into_event(self) -> api::EndpointPacketReceived {
+            let EndpointPacketReceived { packet_header } = self;
+            api::EndpointPacketReceived {
+                packet_header: packet_header.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram sent by the endpoint"]
+    pub struct EndpointDatagramSent {
+        pub len: u16,
+        #[doc = " The GSO offset at which this datagram was written"]
+        #[doc = ""]
+        #[doc = " If this value is greater than 0, it indicates that this datagram has been sent with other"]
+        #[doc = " segments in a single buffer."]
+        #[doc = ""]
+        #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
+        pub gso_offset: usize,
+    }
+    impl IntoEvent<api::EndpointDatagramSent> for EndpointDatagramSent {
+        #[inline]
+        fn into_event(self) -> api::EndpointDatagramSent {
+            let EndpointDatagramSent { len, gso_offset } = self;
+            api::EndpointDatagramSent {
+                len: len.into_event(),
+                gso_offset: gso_offset.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram received by the endpoint"]
+    pub struct EndpointDatagramReceived {
+        pub len: u16,
+    }
+    impl IntoEvent<api::EndpointDatagramReceived> for EndpointDatagramReceived {
+        #[inline]
+        fn into_event(self) -> api::EndpointDatagramReceived {
+            let EndpointDatagramReceived { len } = self;
+            api::EndpointDatagramReceived {
+                len: len.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Datagram dropped by the endpoint"]
+    pub struct EndpointDatagramDropped {
+        pub len: u16,
+        pub reason: DatagramDropReason,
+    }
+    impl IntoEvent<api::EndpointDatagramDropped> for EndpointDatagramDropped {
+        #[inline]
+        fn into_event(self) -> api::EndpointDatagramDropped {
+            let EndpointDatagramDropped { len, reason } = self;
+            api::EndpointDatagramDropped {
+                len: len.into_event(),
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct EndpointConnectionAttemptFailed {
+        pub error: crate::connection::Error,
+    }
+    impl IntoEvent<api::EndpointConnectionAttemptFailed> for EndpointConnectionAttemptFailed {
+        #[inline]
+        fn into_event(self) -> api::EndpointConnectionAttemptFailed {
+            let EndpointConnectionAttemptFailed { error } = self;
+            api::EndpointConnectionAttemptFailed {
+                error: error.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A Retry packet was sent to validate the address of a peer attempting a connection"]
+    pub struct RetrySent<'a> {
+        pub remote_addr: SocketAddress<'a>,
+    }
+    impl<'a> IntoEvent<api::RetrySent<'a>> for RetrySent<'a> {
+        #[inline]
+        fn into_event(self) -> api::RetrySent<'a> {
+            let RetrySent { remote_addr } = self;
+            api::RetrySent {
+                remote_addr: remote_addr.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A packet was dropped by the endpoint before it was associated with a connection"]
+    pub struct EndpointPacketDropped<'a> {
+        pub reason: PacketDropReason<'a>,
+    }
+    impl<'a> IntoEvent<api::EndpointPacketDropped<'a>> for EndpointPacketDropped<'a> {
+        #[inline]
+        fn into_event(self) -> api::EndpointPacketDropped<'a> {
+            let EndpointPacketDropped { reason } = self;
+            api::EndpointPacketDropped {
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when the platform sends at least one packet"]
+    pub struct PlatformTx {
+        #[doc = " The number of packets sent"]
+        pub count: usize,
+    }
+    impl IntoEvent<api::PlatformTx> for PlatformTx {
+        #[inline]
+        fn into_event(self) -> api::PlatformTx {
+            let PlatformTx { count } = self;
+            api::PlatformTx {
+                count: count.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when the platform returns an error while sending datagrams"]
+    pub struct PlatformTxError {
+        #[doc = " The error code returned by the platform"]
+        pub errno: i32,
+    }
+    impl IntoEvent<api::PlatformTxError> for PlatformTxError {
+        #[inline]
+        fn into_event(self) -> api::PlatformTxError {
+            let PlatformTxError { errno } = self;
+            api::PlatformTxError {
+                errno: errno.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when the platform receives at least one packet"]
+    pub struct PlatformRx {
+        #[doc = " The number of packets received"]
+        pub count: usize,
+    }
+    impl IntoEvent<api::PlatformRx> for PlatformRx {
+        #[inline]
+        fn into_event(self) -> api::PlatformRx {
+            let PlatformRx { count } = self;
+            api::PlatformRx {
+                count: count.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when the platform returns an error while receiving datagrams"]
+    pub struct PlatformRxError {
+        #[doc = " The error code returned by the platform"]
+        pub errno: i32,
+    }
+    impl IntoEvent<api::PlatformRxError> for PlatformRxError {
+        #[inline]
+        fn into_event(self) -> api::PlatformRxError {
+            let PlatformRxError { errno } = self;
+            api::PlatformRxError {
+                errno: errno.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when a platform feature is configured"]
+    pub struct PlatformFeatureConfigured {
+        pub configuration: PlatformFeatureConfiguration,
+    }
+    impl IntoEvent<api::PlatformFeatureConfigured> for PlatformFeatureConfigured {
+        #[inline]
+        fn into_event(self) -> api::PlatformFeatureConfigured {
+            let PlatformFeatureConfigured { configuration } = self;
+            api::PlatformFeatureConfigured {
+                configuration: configuration.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PlatformEventLoopWakeup {
+        pub timeout_expired: bool,
+        pub rx_ready: bool,
+        pub tx_ready: bool,
+        pub application_wakeup: bool,
+    }
+    impl IntoEvent<api::PlatformEventLoopWakeup> for PlatformEventLoopWakeup {
+        #[inline]
+        fn into_event(self) -> api::PlatformEventLoopWakeup {
+            let PlatformEventLoopWakeup {
+                timeout_expired,
+                rx_ready,
+                tx_ready,
+                application_wakeup,
+            } = self;
+            api::PlatformEventLoopWakeup {
+                timeout_expired: timeout_expired.into_event(),
+                rx_ready: rx_ready.into_event(),
+                tx_ready: tx_ready.into_event(),
+                application_wakeup: application_wakeup.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PlatformEventLoopSleep {
+        #[doc = " The next time at which the event loop will wake"]
+        pub timeout: Option<core::time::Duration>,
+        #[doc = " The amount of time spent processing endpoint events in a single event loop"]
+        pub processing_duration: core::time::Duration,
+    }
+    impl IntoEvent<api::PlatformEventLoopSleep> for PlatformEventLoopSleep {
+        #[inline]
+        fn into_event(self) -> api::PlatformEventLoopSleep {
+            let PlatformEventLoopSleep {
+                timeout,
+                processing_duration,
+            } = self;
+            api::PlatformEventLoopSleep {
+                timeout: timeout.into_event(),
+                processing_duration: processing_duration.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub enum PlatformFeatureConfiguration {
+        #[doc = " Emitted when segment offload was configured"]
+        Gso {
+            #[doc = " The maximum number of segments that can be sent in a single GSO packet"]
+            #[doc = ""]
+            #[doc = " If this value not greater than 1, GSO is disabled."]
+            max_segments: usize,
+        },
+        #[doc = " Emitted when receive segment offload was configured"]
+        Gro { enabled: bool },
+        #[doc = " Emitted when ECN support is configured"]
+        Ecn { enabled: bool },
+        #[doc = " Emitted when the maximum transmission unit is configured"]
+        MaxMtu { mtu: u16 },
+        #[doc = " Emitted when the socket send buffer is configured"]
+        SendBufferSize {
+            #[doc = " The size of the send buffer granted by the operating system, in bytes"]
+            #[doc = ""]
+            #[doc = " This value may differ from the requested size since the operating system can adjust or"]
+            #[doc = " clamp it."]
+            size: usize,
+        },
+        #[doc = " Emitted when the socket receive buffer is configured"]
+        ReceiveBufferSize {
+            #[doc = " The size of the receive buffer granted by the operating system, in bytes"]
+            #[doc = ""]
+            #[doc = " This value may differ from the requested size since the operating system can adjust or"]
+            #[doc = " clamp it."]
+            size: usize,
+        },
+    }
+    impl IntoEvent<api::PlatformFeatureConfiguration> for PlatformFeatureConfiguration {
+        #[inline]
+        fn into_event(self) -> api::PlatformFeatureConfiguration {
+            use api::PlatformFeatureConfiguration::*;
+            match self {
+                Self::Gso { max_segments } => Gso {
+                    max_segments: max_segments.into_event(),
+                },
+                Self::Gro { enabled } => Gro {
+                    enabled: enabled.into_event(),
+                },
+                Self::Ecn { enabled } => Ecn {
+                    enabled: enabled.into_event(),
+                },
+                Self::MaxMtu { mtu } => MaxMtu {
+                    mtu: mtu.into_event(),
+                },
+                Self::SendBufferSize { size } => SendBufferSize {
+                    size: size.into_event(),
+                },
+                Self::ReceiveBufferSize { size } => ReceiveBufferSize {
+                    size: size.into_event(),
+                },
+            }
+        }
+    }
+}
+pub mod supervisor {
+    #![doc = r" This module contains the `supervisor::Outcome` and `supervisor::Context` for use"]
+    #![doc = r" when implementing [`Subscriber::supervisor_timeout`](crate::event::Subscriber::supervisor_timeout) and"]
+    #![doc = r" [`Subscriber::on_supervisor_timeout`](crate::event::Subscriber::on_supervisor_timeout)"]
+    #![doc = r" on a Subscriber."]
+    use crate::{
+        application,
+        event::{builder::SocketAddress, IntoEvent},
+    };
+    #[non_exhaustive]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum Outcome {
+        #[doc = r" Allow the connection to remain open"]
+        Continue,
+        #[doc = r" Close the connection and notify the peer"]
+        Close { error_code: application::Error },
+        #[doc = r" Close the connection without notifying the peer"]
+        ImmediateClose { reason: &'static str },
+    }
+    impl Default for Outcome {
+        fn default() -> Self {
+            Self::Continue
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Debug)]
+    pub struct Context<'a> {
+        #[doc = r" Number of handshakes that have begun but not completed"]
+        pub inflight_handshakes: usize,
+        #[doc = r" Number of open connections"]
+        pub connection_count: usize,
+        #[doc = r" The address of the peer"]
+        pub remote_address: SocketAddress<'a>,
+        #[doc = r" True if the connection is in the handshake state, false otherwise"]
+        pub is_handshaking: bool,
+    }
+    impl<'a> Context<'a> {
+        pub fn new(
+            inflight_handshakes: usize,
+            connection_count: usize,
+            remote_address: &'a crate::inet::SocketAddress,
+            is_handshaking: bool,
+        ) -> Self {
+            Self {
+                inflight_handshakes,
+                connection_count,
+                remote_address: remote_address.into_event(),
+                is_handshaking,
+            }
+        }
+    }
+}
+pub use traits::*;
+mod traits {
+    use super::*;
+    use crate::query;
+    use api::*;
+    use core::fmt;
+    #[doc = r" Provides metadata related to an event"]
+    pub trait Meta: fmt::Debug {
+        #[doc = r" Returns whether the local endpoint is a Client or Server"]
+        fn endpoint_type(&self) -> &EndpointType;
+        #[doc = r" A context from which the event is being emitted"]
+        #[doc = r""]
+        #[doc = r" An event can occur in the context of an Endpoint or Connection"]
+        fn subject(&self) -> Subject;
+        #[doc = r" The time the event occurred"]
+        fn timestamp(&self) -> &crate::event::Timestamp;
+    }
+    impl Meta for ConnectionMeta {
+        fn endpoint_type(&self) -> &EndpointType {
+            &self.endpoint_type
+        }
+        fn subject(&self) -> Subject {
+            Subject::Connection { id: self.id }
+        }
+        fn timestamp(&self) -> &crate::event::Timestamp {
+            &self.timestamp
+        }
+    }
+    impl Meta for EndpointMeta {
+        fn endpoint_type(&self) -> &EndpointType {
+            &self.endpoint_type
+        }
+        fn subject(&self) -> Subject {
+            Subject::Endpoint {}
+        }
+        fn timestamp(&self) -> &crate::event::Timestamp {
+            &self.timestamp
+        }
+    }
+    #[doc = r" Allows for events to be subscribed to"]
+    pub trait Subscriber: 'static + Send {
+        #[doc = r" An application provided type associated with each connection."]
+        #[doc = r""]
+        #[doc = r" The context provides a mechanism for applications to provide a custom type"]
+        #[doc = r" and update it on each event, e.g. computing statistics. Each event"]
+        #[doc = r" invocation (e.g. [`Subscriber::on_packet_sent`]) also provides mutable"]
+        #[doc = r" access to the context `&mut ConnectionContext` and allows for updating the"]
+        #[doc = r" context."]
+        #[doc = r""]
+        #[doc = r" ```no_run"]
+        #[doc = r" # mod s2n_quic { pub mod provider { pub mod event {"]
+        #[doc = r" #     pub use s2n_quic_core::event::{api as events, api::ConnectionInfo, api::ConnectionMeta, Subscriber};"]
+        #[doc = r" # }}}"]
+        #[doc = r" use s2n_quic::provider::event::{"]
+        #[doc = r"     ConnectionInfo, ConnectionMeta, Subscriber, events::PacketSent"]
+        #[doc = r" };"]
+        #[doc = r""]
+        #[doc = r" pub struct MyEventSubscriber;"]
+        #[doc = r""]
+        #[doc = r" pub struct MyEventContext {"]
+        #[doc = r"     packet_sent: u64,"]
+        #[doc = r" }"]
+        #[doc = r""]
+        #[doc = r" impl Subscriber for MyEventSubscriber {"]
+        #[doc = r"     type ConnectionContext = MyEventContext;"]
+        #[doc = r""]
+        #[doc = r"     fn create_connection_context("]
+        #[doc = r"         &mut self, _meta: &ConnectionMeta,"]
+        #[doc = r"         _info: &ConnectionInfo,"]
+        #[doc = r"     ) -> Self::ConnectionContext {"]
+        #[doc = r"         MyEventContext { packet_sent: 0 }"]
+        #[doc = r"     }"]
+        #[doc = r""]
+        #[doc = r"     fn on_packet_sent("]
+        #[doc = r"         &mut self,"]
+        #[doc = r"         context: &mut Self::ConnectionContext,"]
+        #[doc = r"         _meta: &ConnectionMeta,"]
+        #[doc = r"         _event: &PacketSent,"]
+        #[doc = r"     ) {"]
+        #[doc = r"         context.packet_sent += 1;"]
+        #[doc = r"     }"]
+        #[doc = r" }"]
+        #[doc = r"  ```"]
+        type ConnectionContext: 'static + Send;
+        #[doc = r" Creates a context to be passed to each connection-related event"]
+        fn create_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> Self::ConnectionContext;
+        #[doc = r" The period at which `on_supervisor_timeout` is called"]
+        #[doc = r""]
+        #[doc = r" If multiple `event::Subscriber`s are composed together, the minimum `supervisor_timeout`"]
+        #[doc = r" across all `event::Subscriber`s will be used."]
+        #[doc = r""]
+        #[doc = r" If the `supervisor_timeout()` is `None` across all `event::Subscriber`s, connection supervision"]
+        #[doc = r" will cease for the remaining lifetime of the connection and `on_supervisor_timeout` will no longer"]
+        #[doc = r" be called."]
+        #[doc = r""]
+        #[doc = r" It is recommended to avoid setting this value less than ~100ms, as short durations"]
+        #[doc = r" may lead to higher CPU utilization."]
+        #[allow(unused_variables)]
+        fn supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            None
+        }
+        #[doc = r" Called for each `supervisor_timeout` to determine any action to take on the connection based on the `supervisor::Outcome`"]
+        #[doc = r""]
+        #[doc = r" If multiple `event::Subscriber`s are composed together, the minimum `supervisor_timeout`"]
+        #[doc = r" across all `event::Subscriber`s will be used, and thus `on_supervisor_timeout` may be called"]
+        #[doc = r" earlier than the `supervisor_timeout` for a given `event::Subscriber` implementation."]
+        #[allow(unused_variables)]
+        fn on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            supervisor::Outcome::default()
+        }
+        #[doc = "Called when the `ApplicationProtocolInformation` event is triggered"]
+        #[inline]
+        fn on_application_protocol_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ServerNameInformation` event is triggered"]
+        #[inline]
+        fn on_server_name_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PacketSent` event is triggered"]
+        #[inline]
+        fn on_packet_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PacketReceived` event is triggered"]
+        #[inline]
+        fn on_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ActivePathUpdated` event is triggered"]
+        #[inline]
+        fn on_active_path_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PathCreated` event is triggered"]
+        #[inline]
+        fn on_path_created(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `FrameSent` event is triggered"]
+        #[inline]
+        fn on_frame_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `FrameReceived` event is triggered"]
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PacketLost` event is triggered"]
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `RecoveryMetrics` event is triggered"]
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `Congestion` event is triggered"]
+        #[inline]
+        fn on_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PersistentCongestion` event is triggered"]
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckProcessed` event is triggered"]
+        #[inline]
+        #[deprecated(note = "use on_rx_ack_range_dropped event instead")]
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `RxAckRangeDropped` event is triggered"]
+        #[inline]
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckRangeReceived` event is triggered"]
+        #[inline]
+        fn on_ack_range_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckRangeSent` event is triggered"]
+        #[inline]
+        fn on_ack_range_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PacketDropped` event is triggered"]
+        #[inline]
+        fn on_packet_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `KeyUpdate` event is triggered"]
+        #[inline]
+        fn on_key_update(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `KeySpaceDiscarded` event is triggered"]
+        #[inline]
+        fn on_key_space_discarded(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionStarted` event is triggered"]
+        #[inline]
+        fn on_connection_started(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionClosed` event is triggered"]
+        #[inline]
+        fn on_connection_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `GracefulClose` event is triggered"]
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DuplicatePacket` event is triggered"]
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `TransportParametersReceived` event is triggered"]
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DatagramSent` event is triggered"]
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DatagramReceived` event is triggered"]
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DatagramDropped` event is triggered"]
+        #[inline]
+        fn on_datagram_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DatagramFrameDropped` event is triggered"]
+        #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionIdUpdated` event is triggered"]
+        #[inline]
+        fn on_connection_id_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionIdIssued` event is triggered"]
+        #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionIdRetired` event is triggered"]
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `FlowControlBlocked` event is triggered"]
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EcnStateChanged` event is triggered"]
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionMigrationDenied` event is triggered"]
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `NatRebindingDetected` event is triggered"]
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `HandshakeStatusUpdated` event is triggered"]
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PathChallengeUpdated` event is triggered"]
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `TlsClientHello` event is triggered"]
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `TlsServerHello` event is triggered"]
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `RxStreamProgress` event is triggered"]
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `TxStreamProgress` event is triggered"]
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `KeepAliveTimerExpired` event is triggered"]
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `MtuUpdated` event is triggered"]
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `MtuProbingComplete` event is triggered"]
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `SlowStartExited` event is triggered"]
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `CongestionWindowUpdated` event is triggered"]
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `DeliveryRateSampled` event is triggered"]
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PacingRateUpdated` event is triggered"]
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `BbrStateChanged` event is triggered"]
+        #[inline]
+        fn on_bbr_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `HandshakeProgress` event is triggered"]
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `HandshakeComplete` event is triggered"]
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `KeyUpdated` event is triggered"]
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PathValidationFailed` event is triggered"]
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckFrequencyUpdated` event is triggered"]
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `VersionInformation` event is triggered"]
+        #[inline]
+        fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointPacketSent` event is triggered"]
+        #[inline]
+        fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointPacketReceived` event is triggered"]
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointDatagramSent` event is triggered"]
+        #[inline]
+        fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointDatagramReceived` event is triggered"]
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointDatagramDropped` event is triggered"]
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointConnectionAttemptFailed` event is triggered"]
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `RetrySent` event is triggered"]
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointPacketDropped` event is triggered"]
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformTx` event is triggered"]
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformTxError` event is triggered"]
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformRx` event is triggered"]
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformRxError` event is triggered"]
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformFeatureConfigured` event is triggered"]
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformEventLoopWakeup` event is triggered"]
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformEventLoopSleep` event is triggered"]
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = r" Called for each event that relates to the endpoint and all connections"]
+        #[inline]
+        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = r" Called for each event that relates to a connection"]
+        #[inline]
+        fn on_connection_event<E: Event>(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &E,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = r" Used for querying the `Subscriber::ConnectionContext` on a Subscriber"]
+        #[inline]
+        fn query(
+            context: &Self::ConnectionContext,
+            query: &mut dyn query::Query,
+        ) -> query::ControlFlow {
+            query.execute(context)
+        }
+        #[doc = r" Used for querying and mutating the `Subscriber::ConnectionContext` on a Subscriber"]
+        #[inline]
+        fn query_mut(
+            context: &mut Self::ConnectionContext,
+            query: &mut dyn query::QueryMut,
+        ) -> query::ControlFlow {
+            query.execute_mut(context)
+        }
+    }
+    #[doc = r" Subscriber is implemented for a 2-element tuple to make it easy to compose multiple"]
+    #[doc = r" subscribers."]
+    impl<A, B> Subscriber for (A, B)
+    where
+        A: Subscriber,
+        B: Subscriber,
+    {
+        type ConnectionContext = (A::ConnectionContext, B::ConnectionContext);
+        #[inline]
+        fn create_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> Self::ConnectionContext {
+            (
+                self.0.create_connection_context(meta, info),
+                self.1.create_connection_context(meta, info),
+            )
+        }
+        #[inline]
+        fn supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            let timeout_a = self
+                .0
+                .supervisor_timeout(&mut conn_context.0, meta, context);
+            let timeout_b = self
+                .1
+                .supervisor_timeout(&mut conn_context.1, meta, context);
+            match (timeout_a, timeout_b) {
+                (None, None) => None,
+                (None, Some(timeout)) | (Some(timeout), None) => Some(timeout),
+                (Some(a), Some(b)) => Some(a.min(b)),
+            }
+        }
+        #[inline]
+        fn on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            let outcome_a = self
+                .0
+                .on_supervisor_timeout(&mut conn_context.0, meta, context);
+            let outcome_b = self
+                .1
+                .on_supervisor_timeout(&mut conn_context.1, meta, context);
+            match (outcome_a, outcome_b) {
+                (supervisor::Outcome::ImmediateClose { reason }, _)
+                | (_, supervisor::Outcome::ImmediateClose { reason }) => {
+                    supervisor::Outcome::ImmediateClose { reason }
+                }
+                (supervisor::Outcome::Close { error_code }, _)
+                | (_, supervisor::Outcome::Close { error_code }) => {
+                    supervisor::Outcome::Close { error_code }
+                }
+                _ => supervisor::Outcome::Continue,
+            }
+        }
+        #[inline]
+        fn on_application_protocol_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        ) {
+            (self.0).on_application_protocol_information(&mut context.0, meta, event);
+            (self.1).on_application_protocol_information(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_server_name_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        ) {
+            (self.0).on_server_name_information(&mut context.0, meta, event);
+            (self.1).on_server_name_information(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_packet_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        ) {
+            (self.0).on_packet_sent(&mut context.0, meta, event);
+            (self.1).on_packet_sent(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        ) {
+            (self.0).on_packet_received(&mut context.0, meta, event);
+            (self.1).on_packet_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_active_path_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        ) {
+            (self.0).on_active_path_updated(&mut context.0, meta, event);
+            (self.1).on_active_path_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_path_created(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        ) {
+            (self.0).on_path_created(&mut context.0, meta, event);
+            (self.1).on_path_created(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_frame_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        ) {
+            (self.0).on_frame_sent(&mut context.0, meta, event);
+            (self.1).on_frame_sent(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        ) {
+            (self.0).on_frame_received(&mut context.0, meta, event);
+            (self.1).on_frame_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        ) {
+            (self.0).on_packet_lost(&mut context.0, meta, event);
+            (self.1).on_packet_lost(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            (self.0).on_recovery_metrics(&mut context.0, meta, event);
+            (self.1).on_recovery_metrics(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        ) {
+            (self.0).on_congestion(&mut context.0, meta, event);
+            (self.1).on_congestion(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            (self.0).on_persistent_congestion(&mut context.0, meta, event);
+            (self.1).on_persistent_congestion(&mut context.1, meta, event);
+        }
+        #[inline]
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        ) {
+            (self.0).on_ack_processed(&mut context.0, meta, event);
+            (self.1).on_ack_processed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        ) {
+            (self.0).on_rx_ack_range_dropped(&mut context.0, meta, event);
+            (self.1).on_rx_ack_range_dropped(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ack_range_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        ) {
+            (self.0).on_ack_range_received(&mut context.0, meta, event);
+            (self.1).on_ack_range_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ack_range_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        ) {
+            (self.0).on_ack_range_sent(&mut context.0, meta, event);
+            (self.1).on_ack_range_sent(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_packet_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        ) {
+            (self.0).on_packet_dropped(&mut context.0, meta, event);
+            (self.1).on_packet_dropped(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_key_update(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        ) {
+            (self.0).on_key_update(&mut context.0, meta, event);
+            (self.1).on_key_update(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_key_space_discarded(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        ) {
+            (self.0).on_key_space_discarded(&mut context.0, meta, event);
+            (self.1).on_key_space_discarded(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_started(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        ) {
+            (self.0).on_connection_started(&mut context.0, meta, event);
+            (self.1).on_connection_started(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        ) {
+            (self.0).on_connection_closed(&mut context.0, meta, event);
+            (self.1).on_connection_closed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            (self.0).on_graceful_close(&mut context.0, meta, event);
+            (self.1).on_graceful_close(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            (self.0).on_duplicate_packet(&mut context.0, meta, event);
+            (self.1).on_duplicate_packet(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            (self.0).on_transport_parameters_received(&mut context.0, meta, event);
+            (self.1).on_transport_parameters_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            (self.0).on_datagram_sent(&mut context.0, meta, event);
+            (self.1).on_datagram_sent(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            (self.0).on_datagram_received(&mut context.0, meta, event);
+            (self.1).on_datagram_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_datagram_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        ) {
+            (self.0).on_datagram_dropped(&mut context.0, meta, event);
+            (self.1).on_datagram_dropped(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            (self.0).on_datagram_frame_dropped(&mut context.0, meta, event);
+            (self.1).on_datagram_frame_dropped(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        ) {
+            (self.0).on_connection_id_updated(&mut context.0, meta, event);
+            (self.1).on_connection_id_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            (self.0).on_connection_id_issued(&mut context.0, meta, event);
+            (self.1).on_connection_id_issued(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            (self.0).on_connection_id_retired(&mut context.0, meta, event);
+            (self.1).on_connection_id_retired(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            (self.0).on_flow_control_blocked(&mut context.0, meta, event);
+            (self.1).on_flow_control_blocked(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        ) {
+            (self.0).on_ecn_state_changed(&mut context.0, meta, event);
+            (self.1).on_ecn_state_changed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        ) {
+            (self.0).on_connection_migration_denied(&mut context.0, meta, event);
+            (self.1).on_connection_migration_denied(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            (self.0).on_nat_rebinding_detected(&mut context.0, meta, event);
+            (self.1).on_nat_rebinding_detected(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        ) {
+            (self.0).on_handshake_status_updated(&mut context.0, meta, event);
+            (self.1).on_handshake_status_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        ) {
+            (self.0).on_path_challenge_updated(&mut context.0, meta, event);
+            (self.1).on_path_challenge_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        ) {
+            (self.0).on_tls_client_hello(&mut context.0, meta, event);
+            (self.1).on_tls_client_hello(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        ) {
+            (self.0).on_tls_server_hello(&mut context.0, meta, event);
+            (self.1).on_tls_server_hello(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        ) {
+            (self.0).on_rx_stream_progress(&mut context.0, meta, event);
+            (self.1).on_rx_stream_progress(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        ) {
+            (self.0).on_tx_stream_progress(&mut context.0, meta, event);
+            (self.1).on_tx_stream_progress(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            (self.0).on_keep_alive_timer_expired(&mut context.0, meta, event);
+            (self.1).on_keep_alive_timer_expired(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            (self.0).on_mtu_updated(&mut context.0, meta, event);
+            (self.1).on_mtu_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            (self.0).on_mtu_probing_complete(&mut context.0, meta, event);
+            (self.1).on_mtu_probing_complete(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            (self.0).on_slow_start_exited(&mut context.0, meta, event);
+            (self.1).on_slow_start_exited(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            (self.0).on_congestion_window_updated(&mut context.0, meta, event);
+            (self.1).on_congestion_window_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            (self.0).on_delivery_rate_sampled(&mut context.0, meta, event);
+            (self.1).on_delivery_rate_sampled(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            (self.0).on_pacing_rate_updated(&mut context.0, meta, event);
+            (self.1).on_pacing_rate_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_bbr_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        ) {
+            (self.0).on_bbr_state_changed(&mut context.0, meta, event);
+            (self.1).on_bbr_state_changed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            (self.0).on_handshake_progress(&mut context.0, meta, event);
+            (self.1).on_handshake_progress(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            (self.0).on_handshake_complete(&mut context.0, meta, event);
+            (self.1).on_handshake_complete(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            (self.0).on_key_updated(&mut context.0, meta, event);
+            (self.1).on_key_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            (self.0).on_path_validation_failed(&mut context.0, meta, event);
+            (self.1).on_path_validation_failed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            (self.0).on_ack_frequency_updated(&mut context.0, meta, event);
+            (self.1).on_ack_frequency_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
+            (self.0).on_version_information(meta, event);
+            (self.1).on_version_information(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
+            (self.0).on_endpoint_packet_sent(meta, event);
+            (self.1).on_endpoint_packet_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        ) {
+            (self.0).on_endpoint_packet_received(meta, event);
+            (self.1).on_endpoint_packet_received(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
+            (self.0).on_endpoint_datagram_sent(meta, event);
+            (self.1).on_endpoint_datagram_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        ) {
+            (self.0).on_endpoint_datagram_received(meta, event);
+            (self.1).on_endpoint_datagram_received(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        ) {
+            (self.0).on_endpoint_datagram_dropped(meta, event);
+            (self.1).on_endpoint_datagram_dropped(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        ) {
+            (self.0).on_endpoint_connection_attempt_failed(meta, event);
+            (self.1).on_endpoint_connection_attempt_failed(meta, event);
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            (self.0).on_retry_sent(meta, event);
+            (self.1).on_retry_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            (self.0).on_endpoint_packet_dropped(meta, event);
+            (self.1).on_endpoint_packet_dropped(meta, event);
+        }
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
+            (self.0).on_platform_tx(meta, event);
+            (self.1).on_platform_tx(meta, event);
+        }
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
+            (self.0).on_platform_tx_error(meta, event);
+            (self.1).on_platform_tx_error(meta, event);
+        }
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
+            (self.0).on_platform_rx(meta, event);
+            (self.1).on_platform_rx(meta, event);
+        }
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
+            (self.0).on_platform_rx_error(meta, event);
+            (self.1).on_platform_rx_error(meta, event);
+        }
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        ) {
+            (self.0).on_platform_feature_configured(meta, event);
+            (self.1).on_platform_feature_configured(meta, event);
+        }
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        ) {
+            (self.0).on_platform_event_loop_wakeup(meta, event);
+            (self.1).on_platform_event_loop_wakeup(meta, event);
+        }
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        ) {
+            (self.0).on_platform_event_loop_sleep(meta, event);
+            (self.1).on_platform_event_loop_sleep(meta, event);
+        }
+        #[inline]
+        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+            self.0.on_event(meta, event);
+            self.1.on_event(meta, event);
+        }
+        #[inline]
+        fn on_connection_event<E: Event>(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &E,
+        ) {
+            self.0.on_connection_event(&mut context.0, meta, event);
+            self.1.on_connection_event(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn query(
+            context: &Self::ConnectionContext,
+            query: &mut dyn query::Query,
+        ) -> query::ControlFlow {
+            query
+                .execute(context)
+                .and_then(|| A::query(&context.0, query))
+                .and_then(|| B::query(&context.1, query))
+        }
+        #[inline]
+        fn query_mut(
+            context: &mut Self::ConnectionContext,
+            query: &mut dyn query::QueryMut,
+        ) -> query::ControlFlow {
+            query
+                .execute_mut(context)
+                .and_then(|| A::query_mut(&mut context.0, query))
+                .and_then(|| B::query_mut(&mut context.1, query))
+        }
+    }
+    #[doc = r" Subscriber is implemented for boxed subscribers to allow storing them in a"]
+    #[doc = r" `Vec`."]
+    #[cfg(feature = "alloc")]
+    impl<S> Subscriber for alloc::boxed::Box<S>
+    where
+        S: Subscriber,
+    {
+        type ConnectionContext = S::ConnectionContext;
+        #[inline]
+        fn create_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> Self::ConnectionContext {
+            self.as_mut().create_connection_context(meta, info)
+        }
+        #[inline]
+        fn supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            self.as_mut()
+                .supervisor_timeout(conn_context, meta, context)
+        }
+        #[inline]
+        fn on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            self.as_mut()
+                .on_supervisor_timeout(conn_context, meta, context)
+        }
+        #[inline]
+        fn on_application_protocol_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        ) {
+            self.as_mut()
+                .on_application_protocol_information(context, meta, event);
+        }
+        #[inline]
+        fn on_server_name_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        ) {
+            self.as_mut()
+                .on_server_name_information(context, meta, event);
+        }
+        #[inline]
+        fn on_packet_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        ) {
+            self.as_mut().on_packet_sent(context, meta, event);
+        }
+        #[inline]
+        fn on_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        ) {
+            self.as_mut().on_packet_received(context, meta, event);
+        }
+        #[inline]
+        fn on_active_path_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        ) {
+            self.as_mut().on_active_path_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_path_created(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        ) {
+            self.as_mut().on_path_created(context, meta, event);
+        }
+        #[inline]
+        fn on_frame_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        ) {
+            self.as_mut().on_frame_sent(context, meta, event);
+        }
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        ) {
+            self.as_mut().on_frame_received(context, meta, event);
+        }
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        ) {
+            self.as_mut().on_packet_lost(context, meta, event);
+        }
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            self.as_mut().on_recovery_metrics(context, meta, event);
+        }
+        #[inline]
+        fn on_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        ) {
+            self.as_mut().on_congestion(context, meta, event);
+        }
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            self.as_mut().on_persistent_congestion(context, meta, event);
+        }
+        #[inline]
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        ) {
+            self.as_mut().on_ack_processed(context, meta, event);
+        }
+        #[inline]
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        ) {
+            self.as_mut().on_rx_ack_range_dropped(context, meta, event);
+        }
+        #[inline]
+        fn on_ack_range_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        ) {
+            self.as_mut().on_ack_range_received(context, meta, event);
+        }
+        #[inline]
+        fn on_ack_range_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        ) {
+            self.as_mut().on_ack_range_sent(context, meta, event);
+        }
+        #[inline]
+        fn on_packet_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        ) {
+            self.as_mut().on_packet_dropped(context, meta, event);
+        }
+        #[inline]
+        fn on_key_update(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        ) {
+            self.as_mut().on_key_update(context, meta, event);
+        }
+        #[inline]
+        fn on_key_space_discarded(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        ) {
+            self.as_mut().on_key_space_discarded(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_started(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        ) {
+            self.as_mut().on_connection_started(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        ) {
+            self.as_mut().on_connection_closed(context, meta, event);
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            self.as_mut().on_graceful_close(context, meta, event);
+        }
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            self.as_mut().on_duplicate_packet(context, meta, event);
+        }
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            self.as_mut()
+                .on_transport_parameters_received(context, meta, event);
+        }
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            self.as_mut().on_datagram_sent(context, meta, event);
+        }
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            self.as_mut().on_datagram_received(context, meta, event);
+        }
+        #[inline]
+        fn on_datagram_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        ) {
+            self.as_mut().on_datagram_dropped(context, meta, event);
+        }
+        #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            self.as_mut()
+                .on_datagram_frame_dropped(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        ) {
+            self.as_mut().on_connection_id_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            self.as_mut().on_connection_id_issued(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            self.as_mut().on_connection_id_retired(context, meta, event);
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            self.as_mut().on_flow_control_blocked(context, meta, event);
+        }
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        ) {
+            self.as_mut().on_ecn_state_changed(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        ) {
+            self.as_mut()
+                .on_connection_migration_denied(context, meta, event);
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            self.as_mut()
+                .on_nat_rebinding_detected(context, meta, event);
+        }
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        ) {
+            self.as_mut()
+                .on_handshake_status_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        ) {
+            self.as_mut()
+                .on_path_challenge_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        ) {
+            self.as_mut().on_tls_client_hello(context, meta, event);
+        }
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        ) {
+            self.as_mut().on_tls_server_hello(context, meta, event);
+        }
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        ) {
+            self.as_mut().on_rx_stream_progress(context, meta, event);
+        }
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        ) {
+            self.as_mut().on_tx_stream_progress(context, meta, event);
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            self.as_mut()
+                .on_keep_alive_timer_expired(context, meta, event);
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            self.as_mut().on_mtu_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            self.as_mut().on_mtu_probing_complete(context, meta, event);
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            self.as_mut().on_slow_start_exited(context, meta, event);
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            self.as_mut()
+                .on_congestion_window_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            self.as_mut().on_delivery_rate_sampled(context, meta, event);
+        }
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            self.as_mut().on_pacing_rate_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_bbr_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        ) {
+            self.as_mut().on_bbr_state_changed(context, meta, event);
+        }
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            self.as_mut().on_handshake_progress(context, meta, event);
+        }
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            self.as_mut().on_handshake_complete(context, meta, event);
+        }
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            self.as_mut().on_key_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            self.as_mut()
+                .on_path_validation_failed(context, meta, event);
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            self.as_mut().on_ack_frequency_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
+            self.as_mut().on_version_information(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
+            self.as_mut().on_endpoint_packet_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        ) {
+            self.as_mut().on_endpoint_packet_received(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
+            self.as_mut().on_endpoint_datagram_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        ) {
+            self.as_mut().on_endpoint_datagram_received(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        ) {
+            self.as_mut().on_endpoint_datagram_dropped(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        ) {
+            self.as_mut()
+                .on_endpoint_connection_attempt_failed(meta, event);
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            self.as_mut().on_retry_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            self.as_mut().on_endpoint_packet_dropped(meta, event);
+        }
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
+            self.as_mut().on_platform_tx(meta, event);
+        }
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
+            self.as_mut().on_platform_tx_error(meta, event);
+        }
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
+            self.as_mut().on_platform_rx(meta, event);
+        }
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
+            self.as_mut().on_platform_rx_error(meta, event);
+        }
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        ) {
+            self.as_mut().on_platform_feature_configured(meta, event);
+        }
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        ) {
+            self.as_mut().on_platform_event_loop_wakeup(meta, event);
+        }
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        ) {
+            self.as_mut().on_platform_event_loop_sleep(meta, event);
+        }
+        #[inline]
+        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+            self.as_mut().on_event(meta, event);
+        }
+        #[inline]
+        fn on_connection_event<E: Event>(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &E,
+        ) {
+            self.as_mut().on_connection_event(context, meta, event);
+        }
+        #[inline]
+        fn query(
+            context: &Self::ConnectionContext,
+            query: &mut dyn query::Query,
+        ) -> query::ControlFlow {
+            S::query(context, query)
+        }
+        #[inline]
+        fn query_mut(
+            context: &mut Self::ConnectionContext,
+            query: &mut dyn query::QueryMut,
+        ) -> query::ControlFlow {
+            S::query_mut(context, query)
+        }
+    }
+    #[doc = r" Subscriber is implemented for a `Vec` to compose a list of subscribers which is"]
+    #[doc = r" only known at runtime."]
+    #[doc = r""]
+    #[doc = r" Each event is passed to the subscribers in the order of the list. Subscribers"]
+    #[doc = r" added to the list after a connection was created will not receive its events."]
+    #[cfg(feature = "alloc")]
+    impl<S> Subscriber for alloc::vec::Vec<S>
+    where
+        S: Subscriber,
+    {
+        type ConnectionContext = alloc::vec::Vec<S::ConnectionContext>;
+        #[inline]
+        fn create_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> Self::ConnectionContext {
+            self.iter_mut()
+                .map(|subscriber| subscriber.create_connection_context(meta, info))
+                .collect()
+        }
+        #[inline]
+        fn supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            self.iter_mut()
+                .zip(conn_context.iter_mut())
+                .filter_map(|(subscriber, conn_context)| {
+                    subscriber.supervisor_timeout(conn_context, meta, context)
+                })
+                .min()
+        }
+        #[inline]
+        fn on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            let mut outcome = supervisor::Outcome::Continue;
+            for (subscriber, conn_context) in self.iter_mut().zip(conn_context.iter_mut()) {
+                outcome = match (
+                    outcome,
+                    subscriber.on_supervisor_timeout(conn_context, meta, context),
+                ) {
+                    (supervisor::Outcome::ImmediateClose { reason }, _)
+                    | (_, supervisor::Outcome::ImmediateClose { reason }) => {
+                        supervisor::Outcome::ImmediateClose { reason }
+                    }
+                    (supervisor::Outcome::Close { error_code }, _)
+                    | (_, supervisor::Outcome::Close { error_code }) => {
+                        supervisor::Outcome::Close { error_code }
+                    }
+                    _ => supervisor::Outcome::Continue,
+                };
+            }
+            outcome
+        }
+        #[inline]
+        fn on_application_protocol_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_application_protocol_information(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_server_name_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_server_name_information(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_packet_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_packet_sent(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_packet_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_active_path_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_active_path_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_path_created(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_path_created(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_frame_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_frame_sent(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_frame_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_packet_lost(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_recovery_metrics(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_congestion(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_persistent_congestion(context, meta, event);
+            }
+        }
+        #[inline]
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_ack_processed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_rx_ack_range_dropped(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_ack_range_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_ack_range_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_ack_range_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_ack_range_sent(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_packet_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_packet_dropped(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_key_update(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_key_update(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_key_space_discarded(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_key_space_discarded(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_started(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_started(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_closed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_graceful_close(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_duplicate_packet(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_transport_parameters_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_datagram_sent(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_datagram_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_datagram_dropped(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_datagram_frame_dropped(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_id_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_id_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_id_issued(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_id_retired(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_flow_control_blocked(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_ecn_state_changed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_migration_denied(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_nat_rebinding_detected(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_handshake_status_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_path_challenge_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_tls_client_hello(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_tls_server_hello(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_rx_stream_progress(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_tx_stream_progress(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_keep_alive_timer_expired(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_mtu_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_mtu_probing_complete(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_slow_start_exited(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_congestion_window_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_delivery_rate_sampled(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_pacing_rate_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_bbr_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_bbr_state_changed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_handshake_progress(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_handshake_complete(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_key_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_path_validation_failed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_ack_frequency_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_version_information(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_packet_sent(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_packet_received(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_datagram_sent(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_datagram_received(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_datagram_dropped(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_connection_attempt_failed(meta, event);
+            }
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_retry_sent(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_endpoint_packet_dropped(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_tx(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_tx_error(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_rx(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_rx_error(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_feature_configured(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_event_loop_wakeup(meta, event);
+            }
+        }
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        ) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_platform_event_loop_sleep(meta, event);
+            }
+        }
+        #[inline]
+        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+            for subscriber in self.iter_mut() {
+                subscriber.on_event(meta, event);
+            }
+        }
+        #[inline]
+        fn on_connection_event<E: Event>(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &E,
+        ) {
+            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                subscriber.on_connection_event(context, meta, event);
+            }
+        }
+        #[inline]
+        fn query(
+            context: &Self::ConnectionContext,
+            query: &mut dyn query::Query,
+        ) -> query::ControlFlow {
+            let mut flow = query.execute(context);
+            for context in context.iter() {
+                flow = flow.and_then(|| S::query(context, query));
+            }
+            flow
+        }
+        #[inline]
+        fn query_mut(
+            context: &mut Self::ConnectionContext,
+            query: &mut dyn query::QueryMut,
+        ) -> query::ControlFlow {
+            let mut flow = query.execute_mut(context);
+            for context in context.iter_mut() {
+                flow = flow.and_then(|| S::query_mut(context, query));
+            }
+            flow
+        }
+    }
+    #[doc = r" An object-safe version of [`Subscriber`] which allows subscribers of different"]
+    #[doc = r" types to be stored in a list only known at runtime, e.g."]
+    #[doc = r" `Vec<Box<dyn DynSubscriber>>`."]
+    #[doc = r""]
+    #[doc = r" `DynSubscriber` is implemented for every [`Subscriber`] and"]
+    #[doc = r" `Box<dyn DynSubscriber>` implements [`Subscriber`] so it can be passed anywhere"]
+    #[doc = r" a subscriber is expected."]
+    #[cfg(feature = "alloc")]
+    pub trait DynSubscriber: 'static + Send {
+        #[doc = r" Creates a type-erased context to be passed to all connection events"]
+        fn create_dyn_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> DynConnectionContext;
+        #[doc = r" The period at which `dyn_on_supervisor_timeout` is called"]
+        fn dyn_supervisor_timeout(
+            &mut self,
+            conn_context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration>;
+        #[doc = r" Called for each `supervisor_timeout` to determine any action to take on the connection"]
+        fn dyn_on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome;
+        #[doc = "Called when the `ApplicationProtocolInformation` event is triggered"]
+        fn dyn_on_application_protocol_information(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        );
+        #[doc = "Called when the `ServerNameInformation` event is triggered"]
+        fn dyn_on_server_name_information(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        );
+        #[doc = "Called when the `PacketSent` event is triggered"]
+        fn dyn_on_packet_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        );
+        #[doc = "Called when the `PacketReceived` event is triggered"]
+        fn dyn_on_packet_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        );
+        #[doc = "Called when the `ActivePathUpdated` event is triggered"]
+        fn dyn_on_active_path_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        );
+        #[doc = "Called when the `PathCreated` event is triggered"]
+        fn dyn_on_path_created(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        );
+        #[doc = "Called when the `FrameSent` event is triggered"]
+        fn dyn_on_frame_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        );
+        #[doc = "Called when the `FrameReceived` event is triggered"]
+        fn dyn_on_frame_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        );
+        #[doc = "Called when the `PacketLost` event is triggered"]
+        fn dyn_on_packet_lost(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        );
+        #[doc = "Called when the `RecoveryMetrics` event is triggered"]
+        fn dyn_on_recovery_metrics(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        );
+        #[doc = "Called when the `Congestion` event is triggered"]
+        fn dyn_on_congestion(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        );
+        #[doc = "Called when the `PersistentCongestion` event is triggered"]
+        fn dyn_on_persistent_congestion(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        );
+        #[doc = "Called when the `AckProcessed` event is triggered"]
+        #[allow(deprecated)]
+        fn dyn_on_ack_processed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        );
+        #[doc = "Called when the `RxAckRangeDropped` event is triggered"]
+        fn dyn_on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        );
+        #[doc = "Called when the `AckRangeReceived` event is triggered"]
+        fn dyn_on_ack_range_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        );
+        #[doc = "Called when the `AckRangeSent` event is triggered"]
+        fn dyn_on_ack_range_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        );
+        #[doc = "Called when the `PacketDropped` event is triggered"]
+        fn dyn_on_packet_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        );
+        #[doc = "Called when the `KeyUpdate` event is triggered"]
+        fn dyn_on_key_update(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        );
+        #[doc = "Called when the `KeySpaceDiscarded` event is triggered"]
+        fn dyn_on_key_space_discarded(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        );
+        #[doc = "Called when the `ConnectionStarted` event is triggered"]
+        fn dyn_on_connection_started(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        );
+        #[doc = "Called when the `ConnectionClosed` event is triggered"]
+        fn dyn_on_connection_closed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        );
+        #[doc = "Called when the `GracefulClose` event is triggered"]
+        fn dyn_on_graceful_close(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        );
+        #[doc = "Called when the `DuplicatePacket` event is triggered"]
+        fn dyn_on_duplicate_packet(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        );
+        #[doc = "Called when the `TransportParametersReceived` event is triggered"]
+        fn dyn_on_transport_parameters_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        );
+        #[doc = "Called when the `DatagramSent` event is triggered"]
+        fn dyn_on_datagram_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        );
+        #[doc = "Called when the `DatagramReceived` event is triggered"]
+        fn dyn_on_datagram_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        );
+        #[doc = "Called when the `DatagramDropped` event is triggered"]
+        fn dyn_on_datagram_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        );
+        #[doc = "Called when the `DatagramFrameDropped` event is triggered"]
+        fn dyn_on_datagram_frame_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        );
+        #[doc = "Called when the `ConnectionIdUpdated` event is triggered"]
+        fn dyn_on_connection_id_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        );
+        #[doc = "Called when the `ConnectionIdIssued` event is triggered"]
+        fn dyn_on_connection_id_issued(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        );
+        #[doc = "Called when the `ConnectionIdRetired` event is triggered"]
+        fn dyn_on_connection_id_retired(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        );
+        #[doc = "Called when the `FlowControlBlocked` event is triggered"]
+        fn dyn_on_flow_control_blocked(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        );
+        #[doc = "Called when the `EcnStateChanged` event is triggered"]
+        fn dyn_on_ecn_state_changed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        );
+        #[doc = "Called when the `ConnectionMigrationDenied` event is triggered"]
+        fn dyn_on_connection_migration_denied(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        );
+        #[doc = "Called when the `NatRebindingDetected` event is triggered"]
+        fn dyn_on_nat_rebinding_detected(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        );
+        #[doc = "Called when the `HandshakeStatusUpdated` event is triggered"]
+        fn dyn_on_handshake_status_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        );
+        #[doc = "Called when the `PathChallengeUpdated` event is triggered"]
+        fn dyn_on_path_challenge_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        );
+        #[doc = "Called when the `TlsClientHello` event is triggered"]
+        fn dyn_on_tls_client_hello(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        );
+        #[doc = "Called when the `TlsServerHello` event is triggered"]
+        fn dyn_on_tls_server_hello(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        );
+        #[doc = "Called when the `RxStreamProgress` event is triggered"]
+        fn dyn_on_rx_stream_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        );
+        #[doc = "Called when the `TxStreamProgress` event is triggered"]
+        fn dyn_on_tx_stream_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        );
+        #[doc = "Called when the `KeepAliveTimerExpired` event is triggered"]
+        fn dyn_on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        );
+        #[doc = "Called when the `MtuUpdated` event is triggered"]
+        fn dyn_on_mtu_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        );
+        #[doc = "Called when the `MtuProbingComplete` event is triggered"]
+        fn dyn_on_mtu_probing_complete(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        );
+        #[doc = "Called when the `SlowStartExited` event is triggered"]
+        fn dyn_on_slow_start_exited(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        );
+        #[doc = "Called when the `CongestionWindowUpdated` event is triggered"]
+        fn dyn_on_congestion_window_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        );
+        #[doc = "Called when the `DeliveryRateSampled` event is triggered"]
+        fn dyn_on_delivery_rate_sampled(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        );
+        #[doc = "Called when the `PacingRateUpdated` event is triggered"]
+        fn dyn_on_pacing_rate_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        );
+        #[doc = "Called when the `BbrStateChanged` event is triggered"]
+        fn dyn_on_bbr_state_changed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        );
+        #[doc = "Called when the `HandshakeProgress` event is triggered"]
+        fn dyn_on_handshake_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        );
+        #[doc = "Called when the `HandshakeComplete` event is triggered"]
+        fn dyn_on_handshake_complete(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        );
+        #[doc = "Called when the `KeyUpdated` event is triggered"]
+        fn dyn_on_key_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        );
+        #[doc = "Called when the `PathValidationFailed` event is triggered"]
+        fn dyn_on_path_validation_failed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        );
+        #[doc = "Called when the `AckFrequencyUpdated` event is triggered"]
+        fn dyn_on_ack_frequency_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        );
+        #[doc = "Called when the `VersionInformation` event is triggered"]
+        fn dyn_on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation);
+        #[doc = "Called when the `EndpointPacketSent` event is triggered"]
+        fn dyn_on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent);
+        #[doc = "Called when the `EndpointPacketReceived` event is triggered"]
+        fn dyn_on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        );
+        #[doc = "Called when the `EndpointDatagramSent` event is triggered"]
+        fn dyn_on_endpoint_datagram_sent(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramSent,
+        );
+        #[doc = "Called when the `EndpointDatagramReceived` event is triggered"]
+        fn dyn_on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        );
+        #[doc = "Called when the `EndpointDatagramDropped` event is triggered"]
+        fn dyn_on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        );
+        #[doc = "Called when the `EndpointConnectionAttemptFailed` event is triggered"]
+        fn dyn_on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        );
+        #[doc = "Called when the `RetrySent` event is triggered"]
+        fn dyn_on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent);
+        #[doc = "Called when the `EndpointPacketDropped` event is triggered"]
+        fn dyn_on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        );
+        #[doc = "Called when the `PlatformTx` event is triggered"]
+        fn dyn_on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx);
+        #[doc = "Called when the `PlatformTxError` event is triggered"]
+        fn dyn_on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError);
+        #[doc = "Called when the `PlatformRx` event is triggered"]
+        fn dyn_on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx);
+        #[doc = "Called when the `PlatformRxError` event is triggered"]
+        fn dyn_on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError);
+        #[doc = "Called when the `PlatformFeatureConfigured` event is triggered"]
+        fn dyn_on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        );
+        #[doc = "Called when the `PlatformEventLoopWakeup` event is triggered"]
+        fn dyn_on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        );
+        #[doc = "Called when the `PlatformEventLoopSleep` event is triggered"]
+        fn dyn_on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        );
     }
-    #[derive(Clone, Debug)]
-    pub enum BbrState {
-        Startup,
-        Drain,
-        ProbeBwDown,
-        ProbeBwCruise,
-        ProbeBwRefill,
-        ProbeBwUp,
-        ProbeRtt,
-    }
-    impl IntoEvent<api::BbrState> for BbrState {
+    #[doc = r" The connection context of a [`DynSubscriber`]"]
+    #[doc = r""]
+    #[doc = r" Queries are forwarded to the context of the subscriber which created it."]
+    #[cfg(feature = "alloc")]
+    pub struct DynConnectionContext {
+        context: alloc::boxed::Box<dyn core::any::Any + Send>,
+        query: fn(&dyn core::any::Any, &mut dyn query::Query) -> query::ControlFlow,
+        query_mut: fn(&mut dyn core::any::Any, &mut dyn query::QueryMut) -> query::ControlFlow,
+    }
+    #[cfg(feature = "alloc")]
+    impl DynConnectionContext {
         #[inline]
-        fn into_event(self) -> api::BbrState {
-            use api::BbrState::*;
-            match self {
-                Self::Startup => Startup {},
-                Self::Drain => Drain {},
-                Self::ProbeBwDown => ProbeBwDown {},
-                Self::ProbeBwCruise => ProbeBwCruise {},
-                Self::ProbeBwRefill => ProbeBwRefill {},
-                Self::ProbeBwUp => ProbeBwUp {},
-                Self::ProbeRtt => ProbeRtt {},
+        fn new<S: Subscriber>(context: S::ConnectionContext) -> Self {
+            Self {
+                context: alloc::boxed::Box::new(context),
+                query: |context, query| match context.downcast_ref::<S::ConnectionContext>() {
+                    Some(context) => S::query(context, query),
+                    None => query::ControlFlow::Continue,
+                },
+                query_mut: |context, query| match context.downcast_mut::<S::ConnectionContext>() {
+                    Some(context) => S::query_mut(context, query),
+                    None => query::ControlFlow::Continue,
+                },
             }
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Application level protocol"]
-    pub struct ApplicationProtocolInformation<'a> {
-        pub chosen_application_protocol: &'a [u8],
-    }
-    impl<'a> IntoEvent<api::ApplicationProtocolInformation<'a>> for ApplicationProtocolInformation<'a> {
         #[inline]
-        fn into_event(self) -> api::ApplicationProtocolInformation<'a> {
-            let ApplicationProtocolInformation {
-                chosen_application_protocol,
-            } = self;
-            api::ApplicationProtocolInformation {
-                chosen_application_protocol: chosen_application_protocol.into_event(),
-            }
+        fn downcast_mut<C: 'static>(&mut self) -> Option<&mut C> {
+            self.context.downcast_mut()
         }
     }
-    #[derive(Clone, Debug)]
-    #[doc = " Server Name was negotiated for the connection"]
-    pub struct ServerNameInformation<'a> {
-        pub chosen_server_name: &'a str,
+    #[cfg(feature = "alloc")]
+    impl fmt::Debug for DynConnectionContext {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("DynConnectionContext")
+                .finish_non_exhaustive()
+        }
     }
-    impl<'a> IntoEvent<api::ServerNameInformation<'a>> for ServerNameInformation<'a> {
+    #[cfg(feature = "alloc")]
+    impl<S> DynSubscriber for S
+    where
+        S: Subscriber,
+    {
         #[inline]
-        fn into_event(self) -> api::ServerNameInformation<'a> {
-            let ServerNameInformation { chosen_server_name } = self;
-            api::ServerNameInformation {
-                chosen_server_name: chosen_server_name.into_event(),
-            }
+        fn create_dyn_connection_context(
+            &mut self,
+            meta: &ConnectionMeta,
+            info: &ConnectionInfo,
+        ) -> DynConnectionContext {
+            DynConnectionContext::new::<S>(self.create_connection_context(meta, info))
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was sent by a connection"]
-    pub struct PacketSent {
-        pub packet_header: PacketHeader,
-        pub packet_len: usize,
-    }
-    impl IntoEvent<api::PacketSent> for PacketSent {
         #[inline]
-        fn into_event(self) -> api::PacketSent {
-            let PacketSent {
-                packet_header,
-                packet_len,
-            } = self;
-            api::PacketSent {
-                packet_header: packet_header.into_event(),
-                packet_len: packet_len.into_event(),
+        fn dyn_supervisor_timeout(
+            &mut self,
+            conn_context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            let conn_context = conn_context.downcast_mut::<S::ConnectionContext>()?;
+            self.supervisor_timeout(conn_context, meta, context)
+        }
+        #[inline]
+        fn dyn_on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            match conn_context.downcast_mut::<S::ConnectionContext>() {
+                Some(conn_context) => self.on_supervisor_timeout(conn_context, meta, context),
+                None => supervisor::Outcome::Continue,
             }
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was received by a connection"]
-    pub struct PacketReceived {
-        pub packet_header: PacketHeader,
-    }
-    impl IntoEvent<api::PacketReceived> for PacketReceived {
         #[inline]
-        fn into_event(self) -> api::PacketReceived {
-            let PacketReceived { packet_header } = self;
-            api::PacketReceived {
-                packet_header: packet_header.into_event(),
+        fn dyn_on_application_protocol_information(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ApplicationProtocolInformation,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_application_protocol_information(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Active path was updated"]
-    pub struct ActivePathUpdated<'a> {
-        pub previous: Path<'a>,
-        pub active: Path<'a>,
-    }
-    impl<'a> IntoEvent<api::ActivePathUpdated<'a>> for ActivePathUpdated<'a> {
         #[inline]
-        fn into_event(self) -> api::ActivePathUpdated<'a> {
-            let ActivePathUpdated { previous, active } = self;
-            api::ActivePathUpdated {
-                previous: previous.into_event(),
-                active: active.into_event(),
+        fn dyn_on_server_name_information(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ServerNameInformation,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_server_name_information(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " A new path was created"]
-    pub struct PathCreated<'a> {
-        pub active: Path<'a>,
-        pub new: Path<'a>,
-    }
-    impl<'a> IntoEvent<api::PathCreated<'a>> for PathCreated<'a> {
         #[inline]
-        fn into_event(self) -> api::PathCreated<'a> {
-            let PathCreated { active, new } = self;
-            api::PathCreated {
-                active: active.into_event(),
-                new: new.into_event(),
+        fn dyn_on_packet_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketSent,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_packet_sent(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Frame was sent"]
-    pub struct FrameSent {
-        pub packet_header: PacketHeader,
-        pub path_id: u64,
-        pub frame: Frame,
-    }
-    impl IntoEvent<api::FrameSent> for FrameSent {
         #[inline]
-        fn into_event(self) -> api::FrameSent {
-            let FrameSent {
-                packet_header,
-                path_id,
-                frame,
-            } = self;
-            api::FrameSent {
-                packet_header: packet_header.into_event(),
-                path_id: path_id.into_event(),
-                frame: frame.into_event(),
+        fn dyn_on_packet_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketReceived,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_packet_received(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Frame was received"]
-    pub struct FrameReceived<'a> {
-        pub packet_header: PacketHeader,
-        pub path: Path<'a>,
-        pub frame: Frame,
-    }
-    impl<'a> IntoEvent<api::FrameReceived<'a>> for FrameReceived<'a> {
         #[inline]
-        fn into_event(self) -> api::FrameReceived<'a> {
-            let FrameReceived {
-                packet_header,
-                path,
-                frame,
-            } = self;
-            api::FrameReceived {
-                packet_header: packet_header.into_event(),
-                path: path.into_event(),
-                frame: frame.into_event(),
+        fn dyn_on_active_path_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ActivePathUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_active_path_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was lost"]
-    pub struct PacketLost<'a> {
-        pub packet_header: PacketHeader,
-        pub path: Path<'a>,
-        pub bytes_lost: u16,
-        pub is_mtu_probe: bool,
-    }
-    impl<'a> IntoEvent<api::PacketLost<'a>> for PacketLost<'a> {
         #[inline]
-        fn into_event(self) -> api::PacketLost<'a> {
-            let PacketLost {
-                packet_header,
-                path,
-                bytes_lost,
-                is_mtu_probe,
-            } = self;
-            api::PacketLost {
-                packet_header: packet_header.into_event(),
-                path: path.into_event(),
-                bytes_lost: bytes_lost.into_event(),
-                is_mtu_probe: is_mtu_probe.into_event(),
+        fn dyn_on_path_created(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathCreated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_path_created(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Recovery metrics updated"]
-    pub struct RecoveryMetrics<'a> {
-        pub path: Path<'a>,
-        pub min_rtt: Duration,
-        pub smoothed_rtt: Duration,
-        pub latest_rtt: Duration,
-        pub rtt_variance: Duration,
-        pub max_ack_delay: Duration,
-        pub pto_count: u32,
-        pub congestion_window: u32,
-        pub bytes_in_flight: u32,
-        pub congestion_limited: bool,
-    }
-    impl<'a> IntoEvent<api::RecoveryMetrics<'a>> for RecoveryMetrics<'a> {
         #[inline]
-        fn into_event(self) -> api::RecoveryMetrics<'a> {
-            let RecoveryMetrics {
-                path,
-                min_rtt,
-                smoothed_rtt,
-                latest_rtt,
-                rtt_variance,
-                max_ack_delay,
-                pto_count,
-                congestion_window,
-                bytes_in_flight,
-                congestion_limited,
-            } = self;
-            api::RecoveryMetrics {
-                path: path.into_event(),
-                min_rtt: min_rtt.into_event(),
-                smoothed_rtt: smoothed_rtt.into_event(),
-                latest_rtt: latest_rtt.into_event(),
-                rtt_variance: rtt_variance.into_event(),
-                max_ack_delay: max_ack_delay.into_event(),
-                pto_count: pto_count.into_event(),
-                congestion_window: congestion_window.into_event(),
-                bytes_in_flight: bytes_in_flight.into_event(),
-                congestion_limited: congestion_limited.into_event(),
+        fn dyn_on_frame_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameSent,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_frame_sent(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Congestion (ECN or packet loss) has occurred"]
-    pub struct Congestion<'a> {
-        pub path: Path<'a>,
-        pub source: CongestionSource,
-    }
-    impl<'a> IntoEvent<api::Congestion<'a>> for Congestion<'a> {
         #[inline]
-        fn into_event(self) -> api::Congestion<'a> {
-            let Congestion { path, source } = self;
-            api::Congestion {
-                path: path.into_event(),
-                source: source.into_event(),
+        fn dyn_on_frame_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FrameReceived,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_frame_received(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Events related to ACK processing"]
-    pub struct AckProcessed<'a> {
-        pub action: AckAction,
-        pub path: Path<'a>,
-    }
-    #[allow(deprecated)]
-    impl<'a> IntoEvent<api::AckProcessed<'a>> for AckProcessed<'a> {
         #[inline]
-        fn into_event(self) -> api::AckProcessed<'a> {
-            let AckProcessed { action, path } = self;
-            api::AckProcessed {
-                action: action.into_event(),
-                path: path.into_event(),
+        fn dyn_on_packet_lost(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketLost,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_packet_lost(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Ack range for received packets was dropped due to space constraints"]
-    #[doc = ""]
-    #[doc = " For the purpose of processing Acks, RX packet numbers are stored as"]
-    #[doc = " packet_number ranges in an IntervalSet; only lower and upper bounds"]
-    #[doc = " are stored instead of individual packet_numbers. Ranges are merged"]
-    #[doc = " when possible so only disjointed ranges are stored."]
-    #[doc = ""]
-    #[doc = " When at `capacity`, the lowest packet_number range is dropped."]
-    pub struct RxAckRangeDropped<'a> {
-        pub path: Path<'a>,
-        #[doc = " The packet number range which was dropped"]
-        pub packet_number_range: core::ops::RangeInclusive<u64>,
-        #[doc = " The number of disjoint ranges the IntervalSet can store"]
-        pub capacity: usize,
-        #[doc = " The store packet_number range in the IntervalSet"]
-        pub stored_range: core::ops::RangeInclusive<u64>,
-    }
-    impl<'a> IntoEvent<api::RxAckRangeDropped<'a>> for RxAckRangeDropped<'a> {
         #[inline]
-        fn into_event(self) -> api::RxAckRangeDropped<'a> {
-            let RxAckRangeDropped {
-                path,
-                packet_number_range,
-                capacity,
-                stored_range,
-            } = self;
-            api::RxAckRangeDropped {
-                path: path.into_event(),
-                packet_number_range: packet_number_range.into_event(),
-                capacity: capacity.into_event(),
-                stored_range: stored_range.into_event(),
+        fn dyn_on_recovery_metrics(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_recovery_metrics(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " ACK range was received"]
-    pub struct AckRangeReceived<'a> {
-        pub packet_header: PacketHeader,
-        pub path: Path<'a>,
-        pub ack_range: RangeInclusive<u64>,
-    }
-    impl<'a> IntoEvent<api::AckRangeReceived<'a>> for AckRangeReceived<'a> {
         #[inline]
-        fn into_event(self) -> api::AckRangeReceived<'a> {
-            let AckRangeReceived {
-                packet_header,
-                path,
-                ack_range,
-            } = self;
-            api::AckRangeReceived {
-                packet_header: packet_header.into_event(),
-                path: path.into_event(),
-                ack_range: ack_range.into_event(),
+        fn dyn_on_congestion(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &Congestion,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_congestion(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " ACK range was sent"]
-    pub struct AckRangeSent {
-        pub packet_header: PacketHeader,
-        pub path_id: u64,
-        pub ack_range: RangeInclusive<u64>,
-    }
-    impl IntoEvent<api::AckRangeSent> for AckRangeSent {
         #[inline]
-        fn into_event(self) -> api::AckRangeSent {
-            let AckRangeSent {
-                packet_header,
-                path_id,
-                ack_range,
-            } = self;
-            api::AckRangeSent {
-                packet_header: packet_header.into_event(),
-                path_id: path_id.into_event(),
-                ack_range: ack_range.into_event(),
+        fn dyn_on_persistent_congestion(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_persistent_congestion(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was dropped with the given reason"]
-    pub struct PacketDropped<'a> {
-        pub reason: PacketDropReason<'a>,
-    }
-    impl<'a> IntoEvent<api::PacketDropped<'a>> for PacketDropped<'a> {
         #[inline]
-        fn into_event(self) -> api::PacketDropped<'a> {
-            let PacketDropped { reason } = self;
-            api::PacketDropped {
-                reason: reason.into_event(),
+        #[allow(deprecated)]
+        fn dyn_on_ack_processed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckProcessed,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_ack_processed(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Crypto key updated"]
-    pub struct KeyUpdate {
-        pub key_type: KeyType,
-        pub cipher_suite: CipherSuite,
-    }
-    impl IntoEvent<api::KeyUpdate> for KeyUpdate {
         #[inline]
-        fn into_event(self) -> api::KeyUpdate {
-            let KeyUpdate {
-                key_type,
-                cipher_suite,
-            } = self;
-            api::KeyUpdate {
-                key_type: key_type.into_event(),
-                cipher_suite: cipher_suite.into_event(),
+        fn dyn_on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxAckRangeDropped,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_rx_ack_range_dropped(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct KeySpaceDiscarded {
-        pub space: KeySpace,
-    }
-    impl IntoEvent<api::KeySpaceDiscarded> for KeySpaceDiscarded {
         #[inline]
-        fn into_event(self) -> api::KeySpaceDiscarded {
-            let KeySpaceDiscarded { space } = self;
-            api::KeySpaceDiscarded {
-                space: space.into_event(),
+        fn dyn_on_ack_range_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeReceived,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_ack_range_received(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Connection started"]
-    pub struct ConnectionStarted<'a> {
-        pub path: Path<'a>,
-    }
-    impl<'a> IntoEvent<api::ConnectionStarted<'a>> for ConnectionStarted<'a> {
         #[inline]
-        fn into_event(self) -> api::ConnectionStarted<'a> {
-            let ConnectionStarted { path } = self;
-            api::ConnectionStarted {
-                path: path.into_event(),
+        fn dyn_on_ack_range_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckRangeSent,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_ack_range_sent(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Connection closed"]
-    pub struct ConnectionClosed {
-        pub error: crate::connection::Error,
-    }
-    impl IntoEvent<api::ConnectionClosed> for ConnectionClosed {
         #[inline]
-        fn into_event(self) -> api::ConnectionClosed {
-            let ConnectionClosed { error } = self;
-            api::ConnectionClosed {
-                error: error.into_event(),
+        fn dyn_on_packet_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacketDropped,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_packet_dropped(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Duplicate packet received"]
-    pub struct DuplicatePacket<'a> {
-        pub packet_header: PacketHeader,
-        pub path: Path<'a>,
-        pub error: DuplicatePacketError,
-    }
-    impl<'a> IntoEvent<api::DuplicatePacket<'a>> for DuplicatePacket<'a> {
         #[inline]
-        fn into_event(self) -> api::DuplicatePacket<'a> {
-            let DuplicatePacket {
-                packet_header,
-                path,
-                error,
-            } = self;
-            api::DuplicatePacket {
-                packet_header: packet_header.into_event(),
-                path: path.into_event(),
-                error: error.into_event(),
+        fn dyn_on_key_update(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdate,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_key_update(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Transport parameters received by connection"]
-    pub struct TransportParametersReceived<'a> {
-        pub transport_parameters: TransportParameters<'a>,
-    }
-    impl<'a> IntoEvent<api::TransportParametersReceived<'a>> for TransportParametersReceived<'a> {
         #[inline]
-        fn into_event(self) -> api::TransportParametersReceived<'a> {
-            let TransportParametersReceived {
-                transport_parameters,
-            } = self;
-            api::TransportParametersReceived {
-                transport_parameters: transport_parameters.into_event(),
+        fn dyn_on_key_space_discarded(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeySpaceDiscarded,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_key_space_discarded(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram sent by a connection"]
-    pub struct DatagramSent {
-        pub len: u16,
-        #[doc = " The GSO offset at which this datagram was written"]
-        #[doc = ""]
-        #[doc = " If this value is greater than 0, it indicates that this datagram has been sent with other"]
-        #[doc = " segments in a single buffer."]
-        #[doc = ""]
-        #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
-        pub gso_offset: usize,
-    }
-    impl IntoEvent<api::DatagramSent> for DatagramSent {
         #[inline]
-        fn into_event(self) -> api::DatagramSent {
-            let DatagramSent { len, gso_offset } = self;
-            api::DatagramSent {
-                len: len.into_event(),
-                gso_offset: gso_offset.into_event(),
+        fn dyn_on_connection_started(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionStarted,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_started(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram received by a connection"]
-    pub struct DatagramReceived {
-        pub len: u16,
-    }
-    impl IntoEvent<api::DatagramReceived> for DatagramReceived {
         #[inline]
-        fn into_event(self) -> api::DatagramReceived {
-            let DatagramReceived { len } = self;
-            api::DatagramReceived {
-                len: len.into_event(),
+        fn dyn_on_connection_closed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionClosed,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_closed(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram dropped by a connection"]
-    pub struct DatagramDropped {
-        pub len: u16,
-        pub reason: DatagramDropReason,
-    }
-    impl IntoEvent<api::DatagramDropped> for DatagramDropped {
         #[inline]
-        fn into_event(self) -> api::DatagramDropped {
-            let DatagramDropped { len, reason } = self;
-            api::DatagramDropped {
-                len: len.into_event(),
-                reason: reason.into_event(),
+        fn dyn_on_graceful_close(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_graceful_close(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " ConnectionId updated"]
-    pub struct ConnectionIdUpdated<'a> {
-        pub path_id: u64,
-        #[doc = " The endpoint that updated its connection id"]
-        pub cid_consumer: crate::endpoint::Location,
-        pub previous: ConnectionId<'a>,
-        pub current: ConnectionId<'a>,
-    }
-    impl<'a> IntoEvent<api::ConnectionIdUpdated<'a>> for ConnectionIdUpdated<'a> {
         #[inline]
-        fn into_event(self) -> api::ConnectionIdUpdated<'a> {
-            let ConnectionIdUpdated {
-                path_id,
-                cid_consumer,
-                previous,
-                current,
-            } = self;
-            api::ConnectionIdUpdated {
-                path_id: path_id.into_event(),
-                cid_consumer: cid_consumer.into_event(),
-                previous: previous.into_event(),
-                current: current.into_event(),
+        fn dyn_on_duplicate_packet(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_duplicate_packet(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct EcnStateChanged<'a> {
-        pub path: Path<'a>,
-        pub state: EcnState,
-    }
-    impl<'a> IntoEvent<api::EcnStateChanged<'a>> for EcnStateChanged<'a> {
         #[inline]
-        fn into_event(self) -> api::EcnStateChanged<'a> {
-            let EcnStateChanged { path, state } = self;
-            api::EcnStateChanged {
-                path: path.into_event(),
-                state: state.into_event(),
+        fn dyn_on_transport_parameters_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_transport_parameters_received(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct ConnectionMigrationDenied {
-        pub reason: MigrationDenyReason,
-    }
-    impl IntoEvent<api::ConnectionMigrationDenied> for ConnectionMigrationDenied {
         #[inline]
-        fn into_event(self) -> api::ConnectionMigrationDenied {
-            let ConnectionMigrationDenied { reason } = self;
-            api::ConnectionMigrationDenied {
-                reason: reason.into_event(),
+        fn dyn_on_datagram_sent(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_datagram_sent(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct HandshakeStatusUpdated {
-        pub status: HandshakeStatus,
-    }
-    impl IntoEvent<api::HandshakeStatusUpdated> for HandshakeStatusUpdated {
         #[inline]
-        fn into_event(self) -> api::HandshakeStatusUpdated {
-            let HandshakeStatusUpdated { status } = self;
-            api::HandshakeStatusUpdated {
-                status: status.into_event(),
+        fn dyn_on_datagram_received(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_datagram_received(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Path challenge updated"]
-    pub struct PathChallengeUpdated<'a> {
-        pub path_challenge_status: PathChallengeStatus,
-        pub path: Path<'a>,
-        pub challenge_data: &'a [u8],
-    }
-    impl<'a> IntoEvent<api::PathChallengeUpdated<'a>> for PathChallengeUpdated<'a> {
         #[inline]
-        fn into_event(self) -> api::PathChallengeUpdated<'a> {
-            let PathChallengeUpdated {
-                path_challenge_status,
-                path,
-                challenge_data,
-            } = self;
-            api::PathChallengeUpdated {
-                path_challenge_status: path_challenge_status.into_event(),
-                path: path.into_event(),
-                challenge_data: challenge_data.into_event(),
+        fn dyn_on_datagram_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramDropped,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_datagram_dropped(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct TlsClientHello<'a> {
-        pub payload: &'a [&'a [u8]],
-    }
-    impl<'a> IntoEvent<api::TlsClientHello<'a>> for TlsClientHello<'a> {
         #[inline]
-        fn into_event(self) -> api::TlsClientHello<'a> {
-            let TlsClientHello { payload } = self;
-            api::TlsClientHello {
-                payload: payload.into_event(),
+        fn dyn_on_datagram_frame_dropped(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_datagram_frame_dropped(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct TlsServerHello<'a> {
-        pub payload: &'a [&'a [u8]],
-    }
-    impl<'a> IntoEvent<api::TlsServerHello<'a>> for TlsServerHello<'a> {
         #[inline]
-        fn into_event(self) -> api::TlsServerHello<'a> {
-            let TlsServerHello { payload } = self;
-            api::TlsServerHello {
-                payload: payload.into_event(),
+        fn dyn_on_connection_id_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_id_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct RxStreamProgress {
-        pub bytes: usize,
-    }
-    impl IntoEvent<api::RxStreamProgress> for RxStreamProgress {
         #[inline]
-        fn into_event(self) -> api::RxStreamProgress {
-            let RxStreamProgress { bytes } = self;
-            api::RxStreamProgress {
-                bytes: bytes.into_event(),
+        fn dyn_on_connection_id_issued(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_id_issued(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct TxStreamProgress {
-        pub bytes: usize,
-    }
-    impl IntoEvent<api::TxStreamProgress> for TxStreamProgress {
         #[inline]
-        fn into_event(self) -> api::TxStreamProgress {
-            let TxStreamProgress { bytes } = self;
-            api::TxStreamProgress {
-                bytes: bytes.into_event(),
+        fn dyn_on_connection_id_retired(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_id_retired(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct KeepAliveTimerExpired {
-        pub timeout: Duration,
-    }
-    impl IntoEvent<api::KeepAliveTimerExpired> for KeepAliveTimerExpired {
         #[inline]
-        fn into_event(self) -> api::KeepAliveTimerExpired {
-            let KeepAliveTimerExpired { timeout } = self;
-            api::KeepAliveTimerExpired {
-                timeout: timeout.into_event(),
+        fn dyn_on_flow_control_blocked(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_flow_control_blocked(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The maximum transmission unit (MTU) for the path has changed"]
-    pub struct MtuUpdated {
-        pub path_id: u64,
-        pub mtu: u16,
-        pub cause: MtuUpdatedCause,
-    }
-    impl IntoEvent<api::MtuUpdated> for MtuUpdated {
         #[inline]
-        fn into_event(self) -> api::MtuUpdated {
-            let MtuUpdated {
-                path_id,
-                mtu,
-                cause,
-            } = self;
-            api::MtuUpdated {
-                path_id: path_id.into_event(),
-                mtu: mtu.into_event(),
-                cause: cause.into_event(),
+        fn dyn_on_ecn_state_changed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &EcnStateChanged,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_ecn_state_changed(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The slow start congestion controller state has been exited"]
-    pub struct SlowStartExited {
-        pub path_id: u64,
-        pub cause: SlowStartExitCause,
-        pub congestion_window: u32,
-    }
-    impl IntoEvent<api::SlowStartExited> for SlowStartExited {
         #[inline]
-        fn into_event(self) -> api::SlowStartExited {
-            let SlowStartExited {
-                path_id,
-                cause,
-                congestion_window,
-            } = self;
-            api::SlowStartExited {
-                path_id: path_id.into_event(),
-                cause: cause.into_event(),
-                congestion_window: congestion_window.into_event(),
+        fn dyn_on_connection_migration_denied(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionMigrationDenied,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_connection_migration_denied(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " A new delivery rate sample has been generated"]
-    #[doc = " Note: This event is only recorded for congestion controllers that support"]
-    #[doc = "       bandwidth estimates, such as BBR"]
-    pub struct DeliveryRateSampled {
-        pub path_id: u64,
-        pub rate_sample: RateSample,
-    }
-    impl IntoEvent<api::DeliveryRateSampled> for DeliveryRateSampled {
         #[inline]
-        fn into_event(self) -> api::DeliveryRateSampled {
-            let DeliveryRateSampled {
-                path_id,
-                rate_sample,
-            } = self;
-            api::DeliveryRateSampled {
-                path_id: path_id.into_event(),
-                rate_sample: rate_sample.into_event(),
+        fn dyn_on_nat_rebinding_detected(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_nat_rebinding_detected(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
-        }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The pacing rate has been updated"]
-    pub struct PacingRateUpdated {
-        pub path_id: u64,
-        pub bytes_per_second: u64,
-        pub burst_size: u32,
-        pub pacing_gain: f32,
-    }
-    impl IntoEvent<api::PacingRateUpdated> for PacingRateUpdated {
+            self.on_event(meta, event);
+        }
         #[inline]
-        fn into_event(self) -> api::PacingRateUpdated {
-            let PacingRateUpdated {
-                path_id,
-                bytes_per_second,
-                burst_size,
-                pacing_gain,
-            } = self;
-            api::PacingRateUpdated {
-                path_id: path_id.into_event(),
-                bytes_per_second: bytes_per_second.into_event(),
-                burst_size: burst_size.into_event(),
-                pacing_gain: pacing_gain.into_event(),
+        fn dyn_on_handshake_status_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeStatusUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_handshake_status_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " The BBR state has changed"]
-    pub struct BbrStateChanged {
-        pub path_id: u64,
-        pub state: BbrState,
-    }
-    impl IntoEvent<api::BbrStateChanged> for BbrStateChanged {
         #[inline]
-        fn into_event(self) -> api::BbrStateChanged {
-            let BbrStateChanged { path_id, state } = self;
-            api::BbrStateChanged {
-                path_id: path_id.into_event(),
-                state: state.into_event(),
+        fn dyn_on_path_challenge_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathChallengeUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_path_challenge_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " QUIC version"]
-    pub struct VersionInformation<'a> {
-        pub server_versions: &'a [u32],
-        pub client_versions: &'a [u32],
-        pub chosen_version: Option<u32>,
-    }
-    impl<'a> IntoEvent<api::VersionInformation<'a>> for VersionInformation<'a> {
         #[inline]
-        fn into_event(self) -> api::VersionInformation<'a> {
-            let VersionInformation {
-                server_versions,
-                client_versions,
-                chosen_version,
-            } = self;
-            api::VersionInformation {
-                server_versions: server_versions.into_event(),
-                client_versions: client_versions.into_event(),
-                chosen_version: chosen_version.into_event(),
+        fn dyn_on_tls_client_hello(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsClientHello,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_tls_client_hello(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was sent by the endpoint"]
-    pub struct EndpointPacketSent {
-        pub packet_header: PacketHeader,
-    }
-    impl IntoEvent<api::EndpointPacketSent> for EndpointPacketSent {
         #[inline]
-        fn into_event(self) -> api::EndpointPacketSent {
-            let EndpointPacketSent { packet_header } = self;
-            api::EndpointPacketSent {
-                packet_header: packet_header.into_event(),
+        fn dyn_on_tls_server_hello(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TlsServerHello,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_tls_server_hello(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Packet was received by the endpoint"]
-    pub struct EndpointPacketReceived {
-        pub packet_header: PacketHeader,
-    }
-    impl IntoEvent<api::EndpointPacketReceived> for EndpointPacketReceived {
         #[inline]
-        fn into_event(self) -> api::EndpointPacketReceived {
-            let EndpointPacketReceived { packet_header } = self;
-            api::EndpointPacketReceived {
-                packet_header: packet_header.into_event(),
+        fn dyn_on_rx_stream_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RxStreamProgress,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_rx_stream_progress(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram sent by the endpoint"]
-    pub struct EndpointDatagramSent {
-        pub len: u16,
-        #[doc = " The GSO offset at which this datagram was written"]
-        #[doc = ""]
-        #[doc = " If this value is greater than 0, it indicates that this datagram has been sent with other"]
-        #[doc = " segments in a single buffer."]
-        #[doc = ""]
-        #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
-        pub gso_offset: usize,
-    }
-    impl IntoEvent<api::EndpointDatagramSent> for EndpointDatagramSent {
         #[inline]
-        fn into_event(self) -> api::EndpointDatagramSent {
-            let EndpointDatagramSent { len, gso_offset } = self;
-            api::EndpointDatagramSent {
-                len: len.into_event(),
-                gso_offset: gso_offset.into_event(),
+        fn dyn_on_tx_stream_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TxStreamProgress,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_tx_stream_progress(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram received by the endpoint"]
-    pub struct EndpointDatagramReceived {
-        pub len: u16,
-    }
-    impl IntoEvent<api::EndpointDatagramReceived> for EndpointDatagramReceived {
         #[inline]
-        fn into_event(self) -> api::EndpointDatagramReceived {
-            let EndpointDatagramReceived { len } = self;
-            api::EndpointDatagramReceived {
-                len: len.into_event(),
+        fn dyn_on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_keep_alive_timer_expired(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Datagram dropped by the endpoint"]
-    pub struct EndpointDatagramDropped {
-        pub len: u16,
-        pub reason: DatagramDropReason,
-    }
-    impl IntoEvent<api::EndpointDatagramDropped> for EndpointDatagramDropped {
         #[inline]
-        fn into_event(self) -> api::EndpointDatagramDropped {
-            let EndpointDatagramDropped { len, reason } = self;
-            api::EndpointDatagramDropped {
-                len: len.into_event(),
-                reason: reason.into_event(),
+        fn dyn_on_mtu_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_mtu_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct EndpointConnectionAttemptFailed {
-        pub error: crate::connection::Error,
-    }
-    impl IntoEvent<api::EndpointConnectionAttemptFailed> for EndpointConnectionAttemptFailed {
         #[inline]
-        fn into_event(self) -> api::EndpointConnectionAttemptFailed {
-            let EndpointConnectionAttemptFailed { error } = self;
-            api::EndpointConnectionAttemptFailed {
-                error: error.into_event(),
+        fn dyn_on_mtu_probing_complete(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_mtu_probing_complete(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Emitted when the platform sends at least one packet"]
-    pub struct PlatformTx {
-        #[doc = " The number of packets sent"]
-        pub count: usize,
-    }
-    impl IntoEvent<api::PlatformTx> for PlatformTx {
         #[inline]
-        fn into_event(self) -> api::PlatformTx {
-            let PlatformTx { count } = self;
-            api::PlatformTx {
-                count: count.into_event(),
+        fn dyn_on_slow_start_exited(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_slow_start_exited(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Emitted when the platform returns an error while sending datagrams"]
-    pub struct PlatformTxError {
-        #[doc = " The error code returned by the platform"]
-        pub errno: i32,
-    }
-    impl IntoEvent<api::PlatformTxError> for PlatformTxError {
         #[inline]
-        fn into_event(self) -> api::PlatformTxError {
-            let PlatformTxError { errno } = self;
-            api::PlatformTxError {
-                errno: errno.into_event(),
+        fn dyn_on_congestion_window_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_congestion_window_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Emitted when the platform receives at least one packet"]
-    pub struct PlatformRx {
-        #[doc = " The number of packets received"]
-        pub count: usize,
-    }
-    impl IntoEvent<api::PlatformRx> for PlatformRx {
         #[inline]
-        fn into_event(self) -> api::PlatformRx {
-            let PlatformRx { count } = self;
-            api::PlatformRx {
-                count: count.into_event(),
+        fn dyn_on_delivery_rate_sampled(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_delivery_rate_sampled(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Emitted when the platform returns an error while receiving datagrams"]
-    pub struct PlatformRxError {
-        #[doc = " The error code returned by the platform"]
-        pub errno: i32,
-    }
-    impl IntoEvent<api::PlatformRxError> for PlatformRxError {
         #[inline]
-        fn into_event(self) -> api::PlatformRxError {
-            let PlatformRxError { errno } = self;
-            api::PlatformRxError {
-                errno: errno.into_event(),
+        fn dyn_on_pacing_rate_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_pacing_rate_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    #[doc = " Emitted when a platform feature is configured"]
-    pub struct PlatformFeatureConfigured {
-        pub configuration: PlatformFeatureConfiguration,
-    }
-    impl IntoEvent<api::PlatformFeatureConfigured> for PlatformFeatureConfigured {
         #[inline]
-        fn into_event(self) -> api::PlatformFeatureConfigured {
-            let PlatformFeatureConfigured { configuration } = self;
-            api::PlatformFeatureConfigured {
-                configuration: configuration.into_event(),
+        fn dyn_on_bbr_state_changed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &BbrStateChanged,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_bbr_state_changed(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct PlatformEventLoopWakeup {
-        pub timeout_expired: bool,
-        pub rx_ready: bool,
-        pub tx_ready: bool,
-        pub application_wakeup: bool,
-    }
-    impl IntoEvent<api::PlatformEventLoopWakeup> for PlatformEventLoopWakeup {
         #[inline]
-        fn into_event(self) -> api::PlatformEventLoopWakeup {
-            let PlatformEventLoopWakeup {
-                timeout_expired,
-                rx_ready,
-                tx_ready,
-                application_wakeup,
-            } = self;
-            api::PlatformEventLoopWakeup {
-                timeout_expired: timeout_expired.into_event(),
-                rx_ready: rx_ready.into_event(),
-                tx_ready: tx_ready.into_event(),
-                application_wakeup: application_wakeup.into_event(),
+        fn dyn_on_handshake_progress(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_handshake_progress(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub struct PlatformEventLoopSleep {
-        #[doc = " The next time at which the event loop will wake"]
-        pub timeout: Option<core::time::Duration>,
-        #[doc = " The amount of time spent processing endpoint events in a single event loop"]
-        pub processing_duration: core::time::Duration,
-    }
-    impl IntoEvent<api::PlatformEventLoopSleep> for PlatformEventLoopSleep {
         #[inline]
-        fn into_event(self) -> api::PlatformEventLoopSleep {
-            let PlatformEventLoopSleep {
-                timeout,
-                processing_duration,
-            } = self;
-            api::PlatformEventLoopSleep {
-                timeout: timeout.into_event(),
-                processing_duration: processing_duration.into_event(),
+        fn dyn_on_handshake_complete(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_handshake_complete(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-    #[derive(Clone, Debug)]
-    pub enum PlatformFeatureConfiguration {
-        #[doc = " Emitted when segment offload was configured"]
-        Gso {
-            #[doc = " The maximum number of segments that can be sent in a single GSO packet"]
-            #[doc = ""]
-            #[doc = " If this value not greater than 1, GSO is disabled."]
-            max_segments: usize,
-        },
-        #[doc = " Emitted when receive segment offload was configured"]
-        Gro { enabled: bool },
-        #[doc = " Emitted when ECN support is configured"]
-        Ecn { enabled: bool },
-        #[doc = " Emitted when the maximum transmission unit is configured"]
-        MaxMtu { mtu: u16 },
-    }
-    impl IntoEvent<api::PlatformFeatureConfiguration> for PlatformFeatureConfiguration {
         #[inline]
-        fn into_event(self) -> api::PlatformFeatureConfiguration {
-            use api::PlatformFeatureConfiguration::*;
-            match self {
-                Self::Gso { max_segments } => Gso {
-                    max_segments: max_segments.into_event(),
-                },
-                Self::Gro { enabled } => Gro {
-                    enabled: enabled.into_event(),
-                },
-                Self::Ecn { enabled } => Ecn {
-                    enabled: enabled.into_event(),
-                },
-                Self::MaxMtu { mtu } => MaxMtu {
-                    mtu: mtu.into_event(),
-                },
+        fn dyn_on_key_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_key_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-}
-pub mod supervisor {
-    #![doc = r" This module contains the `supervisor::Outcome` and `supervisor::Context` for use"]
-    #![doc = r" when implementing [`Subscriber::supervisor_timeout`](crate::event::Subscriber::supervisor_timeout) and"]
-    #![doc = r" [`Subscriber::on_supervisor_timeout`](crate::event::Subscriber::on_supervisor_timeout)"]
-    #![doc = r" on a Subscriber."]
-    use crate::{
-        application,
-        event::{builder::SocketAddress, IntoEvent},
-    };
-    #[non_exhaustive]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum Outcome {
-        #[doc = r" Allow the connection to remain open"]
-        Continue,
-        #[doc = r" Close the connection and notify the peer"]
-        Close { error_code: application::Error },
-        #[doc = r" Close the connection without notifying the peer"]
-        ImmediateClose { reason: &'static str },
-    }
-    impl Default for Outcome {
-        fn default() -> Self {
-            Self::Continue
+        #[inline]
+        fn dyn_on_path_validation_failed(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_path_validation_failed(context, meta, event);
+                self.on_connection_event(context, meta, event);
+            }
+            self.on_event(meta, event);
         }
-    }
-    #[non_exhaustive]
-    #[derive(Debug)]
-    pub struct Context<'a> {
-        #[doc = r" Number of handshakes that have begun but not completed"]
-        pub inflight_handshakes: usize,
-        #[doc = r" Number of open connections"]
-        pub connection_count: usize,
-        #[doc = r" The address of the peer"]
-        pub remote_address: SocketAddress<'a>,
-        #[doc = r" True if the connection is in the handshake state, false otherwise"]
-        pub is_handshaking: bool,
-    }
-    impl<'a> Context<'a> {
-        pub fn new(
-            inflight_handshakes: usize,
-            connection_count: usize,
-            remote_address: &'a crate::inet::SocketAddress,
-            is_handshaking: bool,
-        ) -> Self {
-            Self {
-                inflight_handshakes,
-                connection_count,
-                remote_address: remote_address.into_event(),
-                is_handshaking,
+        #[inline]
+        fn dyn_on_ack_frequency_updated(
+            &mut self,
+            context: &mut DynConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                self.on_ack_frequency_updated(context, meta, event);
+                self.on_connection_event(context, meta, event);
             }
+            self.on_event(meta, event);
         }
-    }
-}
-pub use traits::*;
-mod traits {
-    use super::*;
-    use crate::query;
-    use api::*;
-    use core::fmt;
-    #[doc = r" Provides metadata related to an event"]
-    pub trait Meta: fmt::Debug {
-        #[doc = r" Returns whether the local endpoint is a Client or Server"]
-        fn endpoint_type(&self) -> &EndpointType;
-        #[doc = r" A context from which the event is being emitted"]
-        #[doc = r""]
-        #[doc = r" An event can occur in the context of an Endpoint or Connection"]
-        fn subject(&self) -> Subject;
-        #[doc = r" The time the event occurred"]
-        fn timestamp(&self) -> &crate::event::Timestamp;
-    }
-    impl Meta for ConnectionMeta {
-        fn endpoint_type(&self) -> &EndpointType {
-            &self.endpoint_type
+        #[inline]
+        fn dyn_on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
+            self.on_version_information(meta, event);
+            self.on_event(meta, event);
         }
-        fn subject(&self) -> Subject {
-            Subject::Connection { id: self.id }
+        #[inline]
+        fn dyn_on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
+            self.on_endpoint_packet_sent(meta, event);
+            self.on_event(meta, event);
         }
-        fn timestamp(&self) -> &crate::event::Timestamp {
-            &self.timestamp
+        #[inline]
+        fn dyn_on_endpoint_packet_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketReceived,
+        ) {
+            self.on_endpoint_packet_received(meta, event);
+            self.on_event(meta, event);
         }
-    }
-    impl Meta for EndpointMeta {
-        fn endpoint_type(&self) -> &EndpointType {
-            &self.endpoint_type
+        #[inline]
+        fn dyn_on_endpoint_datagram_sent(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramSent,
+        ) {
+            self.on_endpoint_datagram_sent(meta, event);
+            self.on_event(meta, event);
         }
-        fn subject(&self) -> Subject {
-            Subject::Endpoint {}
+        #[inline]
+        fn dyn_on_endpoint_datagram_received(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramReceived,
+        ) {
+            self.on_endpoint_datagram_received(meta, event);
+            self.on_event(meta, event);
         }
-        fn timestamp(&self) -> &crate::event::Timestamp {
-            &self.timestamp
+        #[inline]
+        fn dyn_on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointDatagramDropped,
+        ) {
+            self.on_endpoint_datagram_dropped(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointConnectionAttemptFailed,
+        ) {
+            self.on_endpoint_connection_attempt_failed(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            self.on_retry_sent(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            self.on_endpoint_packet_dropped(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
+            self.on_platform_tx(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
+            self.on_platform_tx_error(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
+            self.on_platform_rx(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
+            self.on_platform_rx_error(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_feature_configured(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformFeatureConfigured,
+        ) {
+            self.on_platform_feature_configured(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopWakeup,
+        ) {
+            self.on_platform_event_loop_wakeup(meta, event);
+            self.on_event(meta, event);
+        }
+        #[inline]
+        fn dyn_on_platform_event_loop_sleep(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &PlatformEventLoopSleep,
+        ) {
+            self.on_platform_event_loop_sleep(meta, event);
+            self.on_event(meta, event);
         }
     }
-    #[doc = r" Allows for events to be subscribed to"]
-    pub trait Subscriber: 'static + Send {
-        #[doc = r" An application provided type associated with each connection."]
-        #[doc = r""]
-        #[doc = r" The context provides a mechanism for applications to provide a custom type"]
-        #[doc = r" and update it on each event, e.g. computing statistics. Each event"]
-        #[doc = r" invocation (e.g. [`Subscriber::on_packet_sent`]) also provides mutable"]
-        #[doc = r" access to the context `&mut ConnectionContext` and allows for updating the"]
-        #[doc = r" context."]
-        #[doc = r""]
-        #[doc = r" ```no_run"]
-        #[doc = r" # mod s2n_quic { pub mod provider { pub mod event {"]
-        #[doc = r" #     pub use s2n_quic_core::event::{api as events, api::ConnectionInfo, api::ConnectionMeta, Subscriber};"]
-        #[doc = r" # }}}"]
-        #[doc = r" use s2n_quic::provider::event::{"]
-        #[doc = r"     ConnectionInfo, ConnectionMeta, Subscriber, events::PacketSent"]
-        #[doc = r" };"]
-        #[doc = r""]
-        #[doc = r" pub struct MyEventSubscriber;"]
-        #[doc = r""]
-        #[doc = r" pub struct MyEventContext {"]
-        #[doc = r"     packet_sent: u64,"]
-        #[doc = r" }"]
-        #[doc = r""]
-        #[doc = r" impl Subscriber for MyEventSubscriber {"]
-        #[doc = r"     type ConnectionContext = MyEventContext;"]
-        #[doc = r""]
-        #[doc = r"     fn create_connection_context("]
-        #[doc = r"         &mut self, _meta: &ConnectionMeta,"]
-        #[doc = r"         _info: &ConnectionInfo,"]
-        #[doc = r"     ) -> Self::ConnectionContext {"]
-        #[doc = r"         MyEventContext { packet_sent: 0 }"]
-        #[doc = r"     }"]
-        #[doc = r""]
-        #[doc = r"     fn on_packet_sent("]
-        #[doc = r"         &mut self,"]
-        #[doc = r"         context: &mut Self::ConnectionContext,"]
-        #[doc = r"         _meta: &ConnectionMeta,"]
-        #[doc = r"         _event: &PacketSent,"]
-        #[doc = r"     ) {"]
-        #[doc = r"         context.packet_sent += 1;"]
-        #[doc = r"     }"]
-        #[doc = r" }"]
-        #[doc = r"  ```"]
-        type ConnectionContext: 'static + Send;
-        #[doc = r" Creates a context to be passed to each connection-related event"]
+    #[doc = r" Subscriber is implemented for boxed [`DynSubscriber`]s to allow storing"]
+    #[doc = r" subscribers of different types in a `Vec`."]
+    #[cfg(feature = "alloc")]
+    impl Subscriber for alloc::boxed::Box<dyn DynSubscriber> {
+        type ConnectionContext = DynConnectionContext;
+        #[inline]
         fn create_connection_context(
             &mut self,
             meta: &ConnectionMeta,
             info: &ConnectionInfo,
-        ) -> Self::ConnectionContext;
-        #[doc = r" The period at which `on_supervisor_timeout` is called"]
-        #[doc = r""]
-        #[doc = r" If multiple `event::Subscriber`s are composed together, the minimum `supervisor_timeout`"]
-        #[doc = r" across all `event::Subscriber`s will be used."]
-        #[doc = r""]
-        #[doc = r" If the `supervisor_timeout()` is `None` across all `event::Subscriber`s, connection supervision"]
-        #[doc = r" will cease for the remaining lifetime of the connection and `on_supervisor_timeout` will no longer"]
-        #[doc = r" be called."]
-        #[doc = r""]
-        #[doc = r" It is recommended to avoid setting this value less than ~100ms, as short durations"]
-        #[doc = r" may lead to higher CPU utilization."]
-        #[allow(unused_variables)]
+        ) -> Self::ConnectionContext {
+            self.as_mut().create_dyn_connection_context(meta, info)
+        }
+        #[inline]
         fn supervisor_timeout(
             &mut self,
             conn_context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
             context: &supervisor::Context,
         ) -> Option<Duration> {
-            None
+            self.as_mut()
+                .dyn_supervisor_timeout(conn_context, meta, context)
         }
-        #[doc = r" Called for each `supervisor_timeout` to determine any action to take on the connection based on the `supervisor::Outcome`"]
-        #[doc = r""]
-        #[doc = r" If multiple `event::Subscriber`s are composed together, the minimum `supervisor_timeout`"]
-        #[doc = r" across all `event::Subscriber`s will be used, and thus `on_supervisor_timeout` may be called"]
-        #[doc = r" earlier than the `supervisor_timeout` for a given `event::Subscriber` implementation."]
-        #[allow(unused_variables)]
+        #[inline]
         fn on_supervisor_timeout(
             &mut self,
             conn_context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
             context: &supervisor::Context,
         ) -> supervisor::Outcome {
-            supervisor::Outcome::default()
+            self.as_mut()
+                .dyn_on_supervisor_timeout(conn_context, meta, context)
         }
-        #[doc = "Called when the `ApplicationProtocolInformation` event is triggered"]
         #[inline]
         fn on_application_protocol_information(
             &mut self,
@@ -4494,11 +11505,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ApplicationProtocolInformation,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_application_protocol_information(context, meta, event);
         }
-        #[doc = "Called when the `ServerNameInformation` event is triggered"]
         #[inline]
         fn on_server_name_information(
             &mut self,
@@ -4506,11 +11515,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ServerNameInformation,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_server_name_information(context, meta, event);
         }
-        #[doc = "Called when the `PacketSent` event is triggered"]
         #[inline]
         fn on_packet_sent(
             &mut self,
@@ -4518,11 +11525,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketSent,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_packet_sent(context, meta, event);
         }
-        #[doc = "Called when the `PacketReceived` event is triggered"]
         #[inline]
         fn on_packet_received(
             &mut self,
@@ -4530,11 +11534,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketReceived,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_packet_received(context, meta, event);
         }
-        #[doc = "Called when the `ActivePathUpdated` event is triggered"]
         #[inline]
         fn on_active_path_updated(
             &mut self,
@@ -4542,11 +11543,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ActivePathUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_active_path_updated(context, meta, event);
         }
-        #[doc = "Called when the `PathCreated` event is triggered"]
         #[inline]
         fn on_path_created(
             &mut self,
@@ -4554,11 +11553,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PathCreated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_path_created(context, meta, event);
         }
-        #[doc = "Called when the `FrameSent` event is triggered"]
         #[inline]
         fn on_frame_sent(
             &mut self,
@@ -4566,11 +11562,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &FrameSent,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_frame_sent(context, meta, event);
         }
-        #[doc = "Called when the `FrameReceived` event is triggered"]
         #[inline]
         fn on_frame_received(
             &mut self,
@@ -4578,11 +11571,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &FrameReceived,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_frame_received(context, meta, event);
         }
-        #[doc = "Called when the `PacketLost` event is triggered"]
         #[inline]
         fn on_packet_lost(
             &mut self,
@@ -4590,11 +11580,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketLost,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_packet_lost(context, meta, event);
         }
-        #[doc = "Called when the `RecoveryMetrics` event is triggered"]
         #[inline]
         fn on_recovery_metrics(
             &mut self,
@@ -4602,11 +11589,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RecoveryMetrics,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_recovery_metrics(context, meta, event);
         }
-        #[doc = "Called when the `Congestion` event is triggered"]
         #[inline]
         fn on_congestion(
             &mut self,
@@ -4614,13 +11598,19 @@ mod traits {
             meta: &ConnectionMeta,
             event: &Congestion,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_congestion(context, meta, event);
+        }
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            self.as_mut()
+                .dyn_on_persistent_congestion(context, meta, event);
         }
-        #[doc = "Called when the `AckProcessed` event is triggered"]
         #[inline]
-        #[deprecated(note = "use on_rx_ack_range_dropped event instead")]
         #[allow(deprecated)]
         fn on_ack_processed(
             &mut self,
@@ -4628,11 +11618,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckProcessed,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_ack_processed(context, meta, event);
         }
-        #[doc = "Called when the `RxAckRangeDropped` event is triggered"]
         #[inline]
         fn on_rx_ack_range_dropped(
             &mut self,
@@ -4640,11 +11627,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RxAckRangeDropped,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_rx_ack_range_dropped(context, meta, event);
         }
-        #[doc = "Called when the `AckRangeReceived` event is triggered"]
         #[inline]
         fn on_ack_range_received(
             &mut self,
@@ -4652,11 +11637,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckRangeReceived,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_ack_range_received(context, meta, event);
         }
-        #[doc = "Called when the `AckRangeSent` event is triggered"]
         #[inline]
         fn on_ack_range_sent(
             &mut self,
@@ -4664,11 +11647,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckRangeSent,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_ack_range_sent(context, meta, event);
         }
-        #[doc = "Called when the `PacketDropped` event is triggered"]
         #[inline]
         fn on_packet_dropped(
             &mut self,
@@ -4676,11 +11656,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketDropped,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_packet_dropped(context, meta, event);
         }
-        #[doc = "Called when the `KeyUpdate` event is triggered"]
         #[inline]
         fn on_key_update(
             &mut self,
@@ -4688,11 +11665,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &KeyUpdate,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_key_update(context, meta, event);
         }
-        #[doc = "Called when the `KeySpaceDiscarded` event is triggered"]
         #[inline]
         fn on_key_space_discarded(
             &mut self,
@@ -4700,11 +11674,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &KeySpaceDiscarded,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_key_space_discarded(context, meta, event);
         }
-        #[doc = "Called when the `ConnectionStarted` event is triggered"]
         #[inline]
         fn on_connection_started(
             &mut self,
@@ -4712,11 +11684,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionStarted,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_connection_started(context, meta, event);
         }
-        #[doc = "Called when the `ConnectionClosed` event is triggered"]
         #[inline]
         fn on_connection_closed(
             &mut self,
@@ -4724,11 +11694,17 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionClosed,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_connection_closed(context, meta, event);
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            self.as_mut().dyn_on_graceful_close(context, meta, event);
         }
-        #[doc = "Called when the `DuplicatePacket` event is triggered"]
         #[inline]
         fn on_duplicate_packet(
             &mut self,
@@ -4736,11 +11712,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &DuplicatePacket,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_duplicate_packet(context, meta, event);
         }
-        #[doc = "Called when the `TransportParametersReceived` event is triggered"]
         #[inline]
         fn on_transport_parameters_received(
             &mut self,
@@ -4748,11 +11721,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TransportParametersReceived,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_transport_parameters_received(context, meta, event);
         }
-        #[doc = "Called when the `DatagramSent` event is triggered"]
         #[inline]
         fn on_datagram_sent(
             &mut self,
@@ -4760,11 +11731,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &DatagramSent,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_datagram_sent(context, meta, event);
         }
-        #[doc = "Called when the `DatagramReceived` event is triggered"]
         #[inline]
         fn on_datagram_received(
             &mut self,
@@ -4772,11 +11740,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &DatagramReceived,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_datagram_received(context, meta, event);
         }
-        #[doc = "Called when the `DatagramDropped` event is triggered"]
         #[inline]
         fn on_datagram_dropped(
             &mut self,
@@ -4784,11 +11749,18 @@ mod traits {
             meta: &ConnectionMeta,
             event: &DatagramDropped,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_datagram_dropped(context, meta, event);
+        }
+        #[inline]
+        fn on_datagram_frame_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramFrameDropped,
+        ) {
+            self.as_mut()
+                .dyn_on_datagram_frame_dropped(context, meta, event);
         }
-        #[doc = "Called when the `ConnectionIdUpdated` event is triggered"]
         #[inline]
         fn on_connection_id_updated(
             &mut self,
@@ -4796,11 +11768,39 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionIdUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_connection_id_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdIssued,
+        ) {
+            self.as_mut()
+                .dyn_on_connection_id_issued(context, meta, event);
+        }
+        #[inline]
+        fn on_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdRetired,
+        ) {
+            self.as_mut()
+                .dyn_on_connection_id_retired(context, meta, event);
+        }
+        #[inline]
+        fn on_flow_control_blocked(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlBlocked,
+        ) {
+            self.as_mut()
+                .dyn_on_flow_control_blocked(context, meta, event);
         }
-        #[doc = "Called when the `EcnStateChanged` event is triggered"]
         #[inline]
         fn on_ecn_state_changed(
             &mut self,
@@ -4808,11 +11808,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &EcnStateChanged,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_ecn_state_changed(context, meta, event);
         }
-        #[doc = "Called when the `ConnectionMigrationDenied` event is triggered"]
         #[inline]
         fn on_connection_migration_denied(
             &mut self,
@@ -4820,11 +11817,19 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionMigrationDenied,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_connection_migration_denied(context, meta, event);
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            self.as_mut()
+                .dyn_on_nat_rebinding_detected(context, meta, event);
         }
-        #[doc = "Called when the `HandshakeStatusUpdated` event is triggered"]
         #[inline]
         fn on_handshake_status_updated(
             &mut self,
@@ -4832,11 +11837,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &HandshakeStatusUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_handshake_status_updated(context, meta, event);
         }
-        #[doc = "Called when the `PathChallengeUpdated` event is triggered"]
         #[inline]
         fn on_path_challenge_updated(
             &mut self,
@@ -4844,11 +11847,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PathChallengeUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_path_challenge_updated(context, meta, event);
         }
-        #[doc = "Called when the `TlsClientHello` event is triggered"]
         #[inline]
         fn on_tls_client_hello(
             &mut self,
@@ -4856,11 +11857,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TlsClientHello,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_tls_client_hello(context, meta, event);
         }
-        #[doc = "Called when the `TlsServerHello` event is triggered"]
         #[inline]
         fn on_tls_server_hello(
             &mut self,
@@ -4868,11 +11866,8 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TlsServerHello,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_tls_server_hello(context, meta, event);
         }
-        #[doc = "Called when the `RxStreamProgress` event is triggered"]
         #[inline]
         fn on_rx_stream_progress(
             &mut self,
@@ -4880,11 +11875,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RxStreamProgress,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_rx_stream_progress(context, meta, event);
         }
-        #[doc = "Called when the `TxStreamProgress` event is triggered"]
         #[inline]
         fn on_tx_stream_progress(
             &mut self,
@@ -4892,247 +11885,263 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TxStreamProgress,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_tx_stream_progress(context, meta, event);
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeepAliveTimerExpired,
+        ) {
+            self.as_mut()
+                .dyn_on_keep_alive_timer_expired(context, meta, event);
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuUpdated,
+        ) {
+            self.as_mut().dyn_on_mtu_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            self.as_mut()
+                .dyn_on_mtu_probing_complete(context, meta, event);
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SlowStartExited,
+        ) {
+            self.as_mut().dyn_on_slow_start_exited(context, meta, event);
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            self.as_mut()
+                .dyn_on_congestion_window_updated(context, meta, event);
+        }
+        #[inline]
+        fn on_delivery_rate_sampled(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DeliveryRateSampled,
+        ) {
+            self.as_mut()
+                .dyn_on_delivery_rate_sampled(context, meta, event);
+        }
+        #[inline]
+        fn on_pacing_rate_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PacingRateUpdated,
+        ) {
+            self.as_mut()
+                .dyn_on_pacing_rate_updated(context, meta, event);
         }
-        #[doc = "Called when the `KeepAliveTimerExpired` event is triggered"]
         #[inline]
-        fn on_keep_alive_timer_expired(
+        fn on_bbr_state_changed(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &KeepAliveTimerExpired,
+            event: &BbrStateChanged,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_bbr_state_changed(context, meta, event);
         }
-        #[doc = "Called when the `MtuUpdated` event is triggered"]
         #[inline]
-        fn on_mtu_updated(
+        fn on_handshake_progress(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &MtuUpdated,
+            event: &HandshakeProgress,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_handshake_progress(context, meta, event);
         }
-        #[doc = "Called when the `SlowStartExited` event is triggered"]
         #[inline]
-        fn on_slow_start_exited(
+        fn on_handshake_complete(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &SlowStartExited,
+            event: &HandshakeComplete,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_handshake_complete(context, meta, event);
         }
-        #[doc = "Called when the `DeliveryRateSampled` event is triggered"]
         #[inline]
-        fn on_delivery_rate_sampled(
+        fn on_key_updated(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &DeliveryRateSampled,
+            event: &KeyUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_key_updated(context, meta, event);
         }
-        #[doc = "Called when the `PacingRateUpdated` event is triggered"]
         #[inline]
-        fn on_pacing_rate_updated(
+        fn on_path_validation_failed(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &PacingRateUpdated,
+            event: &PathValidationFailed,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_path_validation_failed(context, meta, event);
         }
-        #[doc = "Called when the `BbrStateChanged` event is triggered"]
         #[inline]
-        fn on_bbr_state_changed(
+        fn on_ack_frequency_updated(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &BbrStateChanged,
+            event: &AckFrequencyUpdated,
         ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_ack_frequency_updated(context, meta, event);
         }
-        #[doc = "Called when the `VersionInformation` event is triggered"]
         #[inline]
         fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_version_information(meta, event);
         }
-        #[doc = "Called when the `EndpointPacketSent` event is triggered"]
         #[inline]
         fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_endpoint_packet_sent(meta, event);
         }
-        #[doc = "Called when the `EndpointPacketReceived` event is triggered"]
         #[inline]
         fn on_endpoint_packet_received(
             &mut self,
             meta: &EndpointMeta,
             event: &EndpointPacketReceived,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_endpoint_packet_received(meta, event);
         }
-        #[doc = "Called when the `EndpointDatagramSent` event is triggered"]
         #[inline]
         fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_endpoint_datagram_sent(meta, event);
         }
-        #[doc = "Called when the `EndpointDatagramReceived` event is triggered"]
         #[inline]
         fn on_endpoint_datagram_received(
             &mut self,
             meta: &EndpointMeta,
             event: &EndpointDatagramReceived,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_endpoint_datagram_received(meta, event);
         }
-        #[doc = "Called when the `EndpointDatagramDropped` event is triggered"]
         #[inline]
         fn on_endpoint_datagram_dropped(
             &mut self,
             meta: &EndpointMeta,
             event: &EndpointDatagramDropped,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_endpoint_datagram_dropped(meta, event);
         }
-        #[doc = "Called when the `EndpointConnectionAttemptFailed` event is triggered"]
         #[inline]
         fn on_endpoint_connection_attempt_failed(
             &mut self,
             meta: &EndpointMeta,
             event: &EndpointConnectionAttemptFailed,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_endpoint_connection_attempt_failed(meta, event);
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            self.as_mut().dyn_on_retry_sent(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            self.as_mut().dyn_on_endpoint_packet_dropped(meta, event);
         }
-        #[doc = "Called when the `PlatformTx` event is triggered"]
         #[inline]
         fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_tx(meta, event);
         }
-        #[doc = "Called when the `PlatformTxError` event is triggered"]
         #[inline]
         fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_tx_error(meta, event);
         }
-        #[doc = "Called when the `PlatformRx` event is triggered"]
         #[inline]
         fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_rx(meta, event);
         }
-        #[doc = "Called when the `PlatformRxError` event is triggered"]
         #[inline]
         fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_rx_error(meta, event);
         }
-        #[doc = "Called when the `PlatformFeatureConfigured` event is triggered"]
         #[inline]
         fn on_platform_feature_configured(
             &mut self,
             meta: &EndpointMeta,
             event: &PlatformFeatureConfigured,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut()
+                .dyn_on_platform_feature_configured(meta, event);
         }
-        #[doc = "Called when the `PlatformEventLoopWakeup` event is triggered"]
         #[inline]
         fn on_platform_event_loop_wakeup(
             &mut self,
             meta: &EndpointMeta,
             event: &PlatformEventLoopWakeup,
         ) {
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_event_loop_wakeup(meta, event);
         }
-        #[doc = "Called when the `PlatformEventLoopSleep` event is triggered"]
         #[inline]
         fn on_platform_event_loop_sleep(
             &mut self,
             meta: &EndpointMeta,
             event: &PlatformEventLoopSleep,
         ) {
-            let _ = meta;
-            let _ = event;
-        }
-        #[doc = r" Called for each event that relates to the endpoint and all connections"]
-        #[inline]
-        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
-            let _ = meta;
-            let _ = event;
-        }
-        #[doc = r" Called for each event that relates to a connection"]
-        #[inline]
-        fn on_connection_event<E: Event>(
-            &mut self,
-            context: &mut Self::ConnectionContext,
-            meta: &ConnectionMeta,
-            event: &E,
-        ) {
-            let _ = context;
-            let _ = meta;
-            let _ = event;
+            self.as_mut().dyn_on_platform_event_loop_sleep(meta, event);
         }
-        #[doc = r" Used for querying the `Subscriber::ConnectionContext` on a Subscriber"]
         #[inline]
         fn query(
             context: &Self::ConnectionContext,
             query: &mut dyn query::Query,
         ) -> query::ControlFlow {
-            query.execute(context)
+            (context.query)(context.context.as_ref(), query)
         }
-        #[doc = r" Used for querying and mutating the `Subscriber::ConnectionContext` on a Subscriber"]
         #[inline]
         fn query_mut(
             context: &mut Self::ConnectionContext,
             query: &mut dyn query::QueryMut,
         ) -> query::ControlFlow {
-            query.execute_mut(context)
+            (context.query_mut)(context.context.as_mut(), query)
         }
     }
-    #[doc = r" Subscriber is implemented for a 2-element tuple to make it easy to compose multiple"]
-    #[doc = r" subscribers."]
-    impl<A, B> Subscriber for (A, B)
+    #[doc = r" Subscriber is implemented for [`Sampled`](crate::event::Sampled) to only forward"]
+    #[doc = r" a sample of the events to the inner subscriber."]
+    #[cfg(feature = "alloc")]
+    impl<S> Subscriber for crate::event::Sampled<S>
     where
-        A: Subscriber,
-        B: Subscriber,
+        S: Subscriber,
     {
-        type ConnectionContext = (A::ConnectionContext, B::ConnectionContext);
+        type ConnectionContext = S::ConnectionContext;
         #[inline]
         fn create_connection_context(
             &mut self,
             meta: &ConnectionMeta,
             info: &ConnectionInfo,
         ) -> Self::ConnectionContext {
-            (
-                self.0.create_connection_context(meta, info),
-                self.1.create_connection_context(meta, info),
-            )
+            self.subscriber.create_connection_context(meta, info)
         }
         #[inline]
         fn supervisor_timeout(
@@ -5141,17 +12150,8 @@ mod traits {
             meta: &ConnectionMeta,
             context: &supervisor::Context,
         ) -> Option<Duration> {
-            let timeout_a = self
-                .0
-                .supervisor_timeout(&mut conn_context.0, meta, context);
-            let timeout_b = self
-                .1
-                .supervisor_timeout(&mut conn_context.1, meta, context);
-            match (timeout_a, timeout_b) {
-                (None, None) => None,
-                (None, Some(timeout)) | (Some(timeout), None) => Some(timeout),
-                (Some(a), Some(b)) => Some(a.min(b)),
-            }
+            self.subscriber
+                .supervisor_timeout(conn_context, meta, context)
         }
         #[inline]
         fn on_supervisor_timeout(
@@ -5160,23 +12160,8 @@ mod traits {
             meta: &ConnectionMeta,
             context: &supervisor::Context,
         ) -> supervisor::Outcome {
-            let outcome_a = self
-                .0
-                .on_supervisor_timeout(&mut conn_context.0, meta, context);
-            let outcome_b = self
-                .1
-                .on_supervisor_timeout(&mut conn_context.1, meta, context);
-            match (outcome_a, outcome_b) {
-                (supervisor::Outcome::ImmediateClose { reason }, _)
-                | (_, supervisor::Outcome::ImmediateClose { reason }) => {
-                    supervisor::Outcome::ImmediateClose { reason }
-                }
-                (supervisor::Outcome::Close { error_code }, _)
-                | (_, supervisor::Outcome::Close { error_code }) => {
-                    supervisor::Outcome::Close { error_code }
-                }
-                _ => supervisor::Outcome::Continue,
-            }
+            self.subscriber
+                .on_supervisor_timeout(conn_context, meta, context)
         }
         #[inline]
         fn on_application_protocol_information(
@@ -5185,8 +12170,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ApplicationProtocolInformation,
         ) {
-            (self.0).on_application_protocol_information(&mut context.0, meta, event);
-            (self.1).on_application_protocol_information(&mut context.1, meta, event);
+            if self.sample(0usize, "application_protocol_information", Some(meta.id)) {
+                self.subscriber
+                    .on_application_protocol_information(context, meta, event);
+            }
         }
         #[inline]
         fn on_server_name_information(
@@ -5195,8 +12182,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ServerNameInformation,
         ) {
-            (self.0).on_server_name_information(&mut context.0, meta, event);
-            (self.1).on_server_name_information(&mut context.1, meta, event);
+            if self.sample(1usize, "server_name_information", Some(meta.id)) {
+                self.subscriber
+                    .on_server_name_information(context, meta, event);
+            }
         }
         #[inline]
         fn on_packet_sent(
@@ -5205,8 +12194,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketSent,
         ) {
-            (self.0).on_packet_sent(&mut context.0, meta, event);
-            (self.1).on_packet_sent(&mut context.1, meta, event);
+            if self.sample(2usize, "packet_sent", Some(meta.id)) {
+                self.subscriber.on_packet_sent(context, meta, event);
+            }
         }
         #[inline]
         fn on_packet_received(
@@ -5215,8 +12205,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketReceived,
         ) {
-            (self.0).on_packet_received(&mut context.0, meta, event);
-            (self.1).on_packet_received(&mut context.1, meta, event);
+            if self.sample(3usize, "packet_received", Some(meta.id)) {
+                self.subscriber.on_packet_received(context, meta, event);
+            }
         }
         #[inline]
         fn on_active_path_updated(
@@ -5225,8 +12216,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ActivePathUpdated,
         ) {
-            (self.0).on_active_path_updated(&mut context.0, meta, event);
-            (self.1).on_active_path_updated(&mut context.1, meta, event);
+            if self.sample(4usize, "active_path_updated", Some(meta.id)) {
+                self.subscriber.on_active_path_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_path_created(
@@ -5235,8 +12227,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PathCreated,
         ) {
-            (self.0).on_path_created(&mut context.0, meta, event);
-            (self.1).on_path_created(&mut context.1, meta, event);
+            if self.sample(5usize, "path_created", Some(meta.id)) {
+                self.subscriber.on_path_created(context, meta, event);
+            }
         }
         #[inline]
         fn on_frame_sent(
@@ -5245,8 +12238,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &FrameSent,
         ) {
-            (self.0).on_frame_sent(&mut context.0, meta, event);
-            (self.1).on_frame_sent(&mut context.1, meta, event);
+            if self.sample(6usize, "frame_sent", Some(meta.id)) {
+                self.subscriber.on_frame_sent(context, meta, event);
+            }
         }
         #[inline]
         fn on_frame_received(
@@ -5255,8 +12249,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &FrameReceived,
         ) {
-            (self.0).on_frame_received(&mut context.0, meta, event);
-            (self.1).on_frame_received(&mut context.1, meta, event);
+            if self.sample(7usize, "frame_received", Some(meta.id)) {
+                self.subscriber.on_frame_received(context, meta, event);
+            }
         }
         #[inline]
         fn on_packet_lost(
@@ -5265,8 +12260,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketLost,
         ) {
-            (self.0).on_packet_lost(&mut context.0, meta, event);
-            (self.1).on_packet_lost(&mut context.1, meta, event);
+            if self.sample(8usize, "packet_lost", Some(meta.id)) {
+                self.subscriber.on_packet_lost(context, meta, event);
+            }
         }
         #[inline]
         fn on_recovery_metrics(
@@ -5275,8 +12271,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RecoveryMetrics,
         ) {
-            (self.0).on_recovery_metrics(&mut context.0, meta, event);
-            (self.1).on_recovery_metrics(&mut context.1, meta, event);
+            if self.sample(9usize, "recovery_metrics", Some(meta.id)) {
+                self.subscriber.on_recovery_metrics(context, meta, event);
+            }
         }
         #[inline]
         fn on_congestion(
@@ -5285,8 +12282,21 @@ mod traits {
             meta: &ConnectionMeta,
             event: &Congestion,
         ) {
-            (self.0).on_congestion(&mut context.0, meta, event);
-            (self.1).on_congestion(&mut context.1, meta, event);
+            if self.sample(10usize, "congestion", Some(meta.id)) {
+                self.subscriber.on_congestion(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_persistent_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PersistentCongestion,
+        ) {
+            if self.sample(11usize, "persistent_congestion", Some(meta.id)) {
+                self.subscriber
+                    .on_persistent_congestion(context, meta, event);
+            }
         }
         #[inline]
         #[allow(deprecated)]
@@ -5296,8 +12306,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckProcessed,
         ) {
-            (self.0).on_ack_processed(&mut context.0, meta, event);
-            (self.1).on_ack_processed(&mut context.1, meta, event);
+            if self.sample(12usize, "ack_processed", Some(meta.id)) {
+                self.subscriber.on_ack_processed(context, meta, event);
+            }
         }
         #[inline]
         fn on_rx_ack_range_dropped(
@@ -5306,8 +12317,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RxAckRangeDropped,
         ) {
-            (self.0).on_rx_ack_range_dropped(&mut context.0, meta, event);
-            (self.1).on_rx_ack_range_dropped(&mut context.1, meta, event);
+            if self.sample(13usize, "rx_ack_range_dropped", Some(meta.id)) {
+                self.subscriber
+                    .on_rx_ack_range_dropped(context, meta, event);
+            }
         }
         #[inline]
         fn on_ack_range_received(
@@ -5316,8 +12329,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckRangeReceived,
         ) {
-            (self.0).on_ack_range_received(&mut context.0, meta, event);
-            (self.1).on_ack_range_received(&mut context.1, meta, event);
+            if self.sample(14usize, "ack_range_received", Some(meta.id)) {
+                self.subscriber.on_ack_range_received(context, meta, event);
+            }
         }
         #[inline]
         fn on_ack_range_sent(
@@ -5326,8 +12340,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &AckRangeSent,
         ) {
-            (self.0).on_ack_range_sent(&mut context.0, meta, event);
-            (self.1).on_ack_range_sent(&mut context.1, meta, event);
+            if self.sample(15usize, "ack_range_sent", Some(meta.id)) {
+                self.subscriber.on_ack_range_sent(context, meta, event);
+            }
         }
         #[inline]
         fn on_packet_dropped(
@@ -5336,8 +12351,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacketDropped,
         ) {
-            (self.0).on_packet_dropped(&mut context.0, meta, event);
-            (self.1).on_packet_dropped(&mut context.1, meta, event);
+            if self.sample(16usize, "packet_dropped", Some(meta.id)) {
+                self.subscriber.on_packet_dropped(context, meta, event);
+            }
         }
         #[inline]
         fn on_key_update(
@@ -5346,8 +12362,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &KeyUpdate,
         ) {
-            (self.0).on_key_update(&mut context.0, meta, event);
-            (self.1).on_key_update(&mut context.1, meta, event);
+            if self.sample(17usize, "key_update", Some(meta.id)) {
+                self.subscriber.on_key_update(context, meta, event);
+            }
         }
         #[inline]
         fn on_key_space_discarded(
@@ -5356,8 +12373,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &KeySpaceDiscarded,
         ) {
-            (self.0).on_key_space_discarded(&mut context.0, meta, event);
-            (self.1).on_key_space_discarded(&mut context.1, meta, event);
+            if self.sample(18usize, "key_space_discarded", Some(meta.id)) {
+                self.subscriber.on_key_space_discarded(context, meta, event);
+            }
         }
         #[inline]
         fn on_connection_started(
@@ -5366,8 +12384,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionStarted,
         ) {
-            (self.0).on_connection_started(&mut context.0, meta, event);
-            (self.1).on_connection_started(&mut context.1, meta, event);
+            if self.sample(19usize, "connection_started", Some(meta.id)) {
+                self.subscriber.on_connection_started(context, meta, event);
+            }
         }
         #[inline]
         fn on_connection_closed(
@@ -5376,68 +12395,136 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionClosed,
         ) {
-            (self.0).on_connection_closed(&mut context.0, meta, event);
-            (self.1).on_connection_closed(&mut context.1, meta, event);
+            if self.sample(20usize, "connection_closed", Some(meta.id)) {
+                self.subscriber.on_connection_closed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_graceful_close(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &GracefulClose,
+        ) {
+            if self.sample(21usize, "graceful_close", Some(meta.id)) {
+                self.subscriber.on_graceful_close(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicatePacket,
+        ) {
+            if self.sample(22usize, "duplicate_packet", Some(meta.id)) {
+                self.subscriber.on_duplicate_packet(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &TransportParametersReceived,
+        ) {
+            if self.sample(23usize, "transport_parameters_received", Some(meta.id)) {
+                self.subscriber
+                    .on_transport_parameters_received(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramSent,
+        ) {
+            if self.sample(24usize, "datagram_sent", Some(meta.id)) {
+                self.subscriber.on_datagram_sent(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DatagramReceived,
+        ) {
+            if self.sample(25usize, "datagram_received", Some(meta.id)) {
+                self.subscriber.on_datagram_received(context, meta, event);
+            }
         }
         #[inline]
-        fn on_duplicate_packet(
+        fn on_datagram_dropped(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &DuplicatePacket,
+            event: &DatagramDropped,
         ) {
-            (self.0).on_duplicate_packet(&mut context.0, meta, event);
-            (self.1).on_duplicate_packet(&mut context.1, meta, event);
+            if self.sample(26usize, "datagram_dropped", Some(meta.id)) {
+                self.subscriber.on_datagram_dropped(context, meta, event);
+            }
         }
         #[inline]
-        fn on_transport_parameters_received(
+        fn on_datagram_frame_dropped(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &TransportParametersReceived,
+            event: &DatagramFrameDropped,
         ) {
-            (self.0).on_transport_parameters_received(&mut context.0, meta, event);
-            (self.1).on_transport_parameters_received(&mut context.1, meta, event);
+            if self.sample(27usize, "datagram_frame_dropped", Some(meta.id)) {
+                self.subscriber
+                    .on_datagram_frame_dropped(context, meta, event);
+            }
         }
         #[inline]
-        fn on_datagram_sent(
+        fn on_connection_id_updated(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &DatagramSent,
+            event: &ConnectionIdUpdated,
         ) {
-            (self.0).on_datagram_sent(&mut context.0, meta, event);
-            (self.1).on_datagram_sent(&mut context.1, meta, event);
+            if self.sample(28usize, "connection_id_updated", Some(meta.id)) {
+                self.subscriber
+                    .on_connection_id_updated(context, meta, event);
+            }
         }
         #[inline]
-        fn on_datagram_received(
+        fn on_connection_id_issued(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &DatagramReceived,
+            event: &ConnectionIdIssued,
         ) {
-            (self.0).on_datagram_received(&mut context.0, meta, event);
-            (self.1).on_datagram_received(&mut context.1, meta, event);
+            if self.sample(29usize, "connection_id_issued", Some(meta.id)) {
+                self.subscriber
+                    .on_connection_id_issued(context, meta, event);
+            }
         }
         #[inline]
-        fn on_datagram_dropped(
+        fn on_connection_id_retired(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &DatagramDropped,
+            event: &ConnectionIdRetired,
         ) {
-            (self.0).on_datagram_dropped(&mut context.0, meta, event);
-            (self.1).on_datagram_dropped(&mut context.1, meta, event);
+            if self.sample(30usize, "connection_id_retired", Some(meta.id)) {
+                self.subscriber
+                    .on_connection_id_retired(context, meta, event);
+            }
         }
         #[inline]
-        fn on_connection_id_updated(
+        fn on_flow_control_blocked(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &ConnectionIdUpdated,
+            event: &FlowControlBlocked,
         ) {
-            (self.0).on_connection_id_updated(&mut context.0, meta, event);
-            (self.1).on_connection_id_updated(&mut context.1, meta, event);
+            if self.sample(31usize, "flow_control_blocked", Some(meta.id)) {
+                self.subscriber
+                    .on_flow_control_blocked(context, meta, event);
+            }
         }
         #[inline]
         fn on_ecn_state_changed(
@@ -5446,8 +12533,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &EcnStateChanged,
         ) {
-            (self.0).on_ecn_state_changed(&mut context.0, meta, event);
-            (self.1).on_ecn_state_changed(&mut context.1, meta, event);
+            if self.sample(32usize, "ecn_state_changed", Some(meta.id)) {
+                self.subscriber.on_ecn_state_changed(context, meta, event);
+            }
         }
         #[inline]
         fn on_connection_migration_denied(
@@ -5456,8 +12544,22 @@ mod traits {
             meta: &ConnectionMeta,
             event: &ConnectionMigrationDenied,
         ) {
-            (self.0).on_connection_migration_denied(&mut context.0, meta, event);
-            (self.1).on_connection_migration_denied(&mut context.1, meta, event);
+            if self.sample(33usize, "connection_migration_denied", Some(meta.id)) {
+                self.subscriber
+                    .on_connection_migration_denied(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_nat_rebinding_detected(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &NatRebindingDetected,
+        ) {
+            if self.sample(34usize, "nat_rebinding_detected", Some(meta.id)) {
+                self.subscriber
+                    .on_nat_rebinding_detected(context, meta, event);
+            }
         }
         #[inline]
         fn on_handshake_status_updated(
@@ -5466,8 +12568,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &HandshakeStatusUpdated,
         ) {
-            (self.0).on_handshake_status_updated(&mut context.0, meta, event);
-            (self.1).on_handshake_status_updated(&mut context.1, meta, event);
+            if self.sample(35usize, "handshake_status_updated", Some(meta.id)) {
+                self.subscriber
+                    .on_handshake_status_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_path_challenge_updated(
@@ -5476,8 +12580,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PathChallengeUpdated,
         ) {
-            (self.0).on_path_challenge_updated(&mut context.0, meta, event);
-            (self.1).on_path_challenge_updated(&mut context.1, meta, event);
+            if self.sample(36usize, "path_challenge_updated", Some(meta.id)) {
+                self.subscriber
+                    .on_path_challenge_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_tls_client_hello(
@@ -5486,8 +12592,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TlsClientHello,
         ) {
-            (self.0).on_tls_client_hello(&mut context.0, meta, event);
-            (self.1).on_tls_client_hello(&mut context.1, meta, event);
+            if self.sample(37usize, "tls_client_hello", Some(meta.id)) {
+                self.subscriber.on_tls_client_hello(context, meta, event);
+            }
         }
         #[inline]
         fn on_tls_server_hello(
@@ -5496,8 +12603,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TlsServerHello,
         ) {
-            (self.0).on_tls_server_hello(&mut context.0, meta, event);
-            (self.1).on_tls_server_hello(&mut context.1, meta, event);
+            if self.sample(38usize, "tls_server_hello", Some(meta.id)) {
+                self.subscriber.on_tls_server_hello(context, meta, event);
+            }
         }
         #[inline]
         fn on_rx_stream_progress(
@@ -5506,8 +12614,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &RxStreamProgress,
         ) {
-            (self.0).on_rx_stream_progress(&mut context.0, meta, event);
-            (self.1).on_rx_stream_progress(&mut context.1, meta, event);
+            if self.sample(39usize, "rx_stream_progress", Some(meta.id)) {
+                self.subscriber.on_rx_stream_progress(context, meta, event);
+            }
         }
         #[inline]
         fn on_tx_stream_progress(
@@ -5516,8 +12625,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &TxStreamProgress,
         ) {
-            (self.0).on_tx_stream_progress(&mut context.0, meta, event);
-            (self.1).on_tx_stream_progress(&mut context.1, meta, event);
+            if self.sample(40usize, "tx_stream_progress", Some(meta.id)) {
+                self.subscriber.on_tx_stream_progress(context, meta, event);
+            }
         }
         #[inline]
         fn on_keep_alive_timer_expired(
@@ -5526,8 +12636,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &KeepAliveTimerExpired,
         ) {
-            (self.0).on_keep_alive_timer_expired(&mut context.0, meta, event);
-            (self.1).on_keep_alive_timer_expired(&mut context.1, meta, event);
+            if self.sample(41usize, "keep_alive_timer_expired", Some(meta.id)) {
+                self.subscriber
+                    .on_keep_alive_timer_expired(context, meta, event);
+            }
         }
         #[inline]
         fn on_mtu_updated(
@@ -5536,8 +12648,21 @@ mod traits {
             meta: &ConnectionMeta,
             event: &MtuUpdated,
         ) {
-            (self.0).on_mtu_updated(&mut context.0, meta, event);
-            (self.1).on_mtu_updated(&mut context.1, meta, event);
+            if self.sample(42usize, "mtu_updated", Some(meta.id)) {
+                self.subscriber.on_mtu_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_mtu_probing_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &MtuProbingComplete,
+        ) {
+            if self.sample(43usize, "mtu_probing_complete", Some(meta.id)) {
+                self.subscriber
+                    .on_mtu_probing_complete(context, meta, event);
+            }
         }
         #[inline]
         fn on_slow_start_exited(
@@ -5546,8 +12671,21 @@ mod traits {
             meta: &ConnectionMeta,
             event: &SlowStartExited,
         ) {
-            (self.0).on_slow_start_exited(&mut context.0, meta, event);
-            (self.1).on_slow_start_exited(&mut context.1, meta, event);
+            if self.sample(44usize, "slow_start_exited", Some(meta.id)) {
+                self.subscriber.on_slow_start_exited(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_congestion_window_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionWindowUpdated,
+        ) {
+            if self.sample(45usize, "congestion_window_updated", Some(meta.id)) {
+                self.subscriber
+                    .on_congestion_window_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_delivery_rate_sampled(
@@ -5556,8 +12694,10 @@ mod traits {
             meta: &ConnectionMeta,
             event: &DeliveryRateSampled,
         ) {
-            (self.0).on_delivery_rate_sampled(&mut context.0, meta, event);
-            (self.1).on_delivery_rate_sampled(&mut context.1, meta, event);
+            if self.sample(46usize, "delivery_rate_sampled", Some(meta.id)) {
+                self.subscriber
+                    .on_delivery_rate_sampled(context, meta, event);
+            }
         }
         #[inline]
         fn on_pacing_rate_updated(
@@ -5566,8 +12706,9 @@ mod traits {
             meta: &ConnectionMeta,
             event: &PacingRateUpdated,
         ) {
-            (self.0).on_pacing_rate_updated(&mut context.0, meta, event);
-            (self.1).on_pacing_rate_updated(&mut context.1, meta, event);
+            if self.sample(47usize, "pacing_rate_updated", Some(meta.id)) {
+                self.subscriber.on_pacing_rate_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_bbr_state_changed(
@@ -5576,18 +12717,78 @@ mod traits {
             meta: &ConnectionMeta,
             event: &BbrStateChanged,
         ) {
-            (self.0).on_bbr_state_changed(&mut context.0, meta, event);
-            (self.1).on_bbr_state_changed(&mut context.1, meta, event);
+            if self.sample(48usize, "bbr_state_changed", Some(meta.id)) {
+                self.subscriber.on_bbr_state_changed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_handshake_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeProgress,
+        ) {
+            if self.sample(49usize, "handshake_progress", Some(meta.id)) {
+                self.subscriber.on_handshake_progress(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_handshake_complete(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &HandshakeComplete,
+        ) {
+            if self.sample(50usize, "handshake_complete", Some(meta.id)) {
+                self.subscriber.on_handshake_complete(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_key_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &KeyUpdated,
+        ) {
+            if self.sample(51usize, "key_updated", Some(meta.id)) {
+                self.subscriber.on_key_updated(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_path_validation_failed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &PathValidationFailed,
+        ) {
+            if self.sample(52usize, "path_validation_failed", Some(meta.id)) {
+                self.subscriber
+                    .on_path_validation_failed(context, meta, event);
+            }
+        }
+        #[inline]
+        fn on_ack_frequency_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckFrequencyUpdated,
+        ) {
+            if self.sample(53usize, "ack_frequency_updated", Some(meta.id)) {
+                self.subscriber
+                    .on_ack_frequency_updated(context, meta, event);
+            }
         }
         #[inline]
         fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
-            (self.0).on_version_information(meta, event);
-            (self.1).on_version_information(meta, event);
+            if self.sample(54usize, "version_information", None) {
+                self.subscriber.on_version_information(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_packet_sent(&mut self, meta: &EndpointMeta, event: &EndpointPacketSent) {
-            (self.0).on_endpoint_packet_sent(meta, event);
-            (self.1).on_endpoint_packet_sent(meta, event);
+            if self.sample(55usize, "endpoint_packet_sent", None) {
+                self.subscriber.on_endpoint_packet_sent(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_packet_received(
@@ -5595,13 +12796,15 @@ mod traits {
             meta: &EndpointMeta,
             event: &EndpointPacketReceived,
         ) {
-            (self.0).on_endpoint_packet_received(meta, event);
-            (self.1).on_endpoint_packet_received(meta, event);
+            if self.sample(56usize, "endpoint_packet_received", None) {
+                self.subscriber.on_endpoint_packet_received(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_datagram_sent(&mut self, meta: &EndpointMeta, event: &EndpointDatagramSent) {
-            (self.0).on_endpoint_datagram_sent(meta, event);
-            (self.1).on_endpoint_datagram_sent(meta, event);
+            if self.sample(57usize, "endpoint_datagram_sent", None) {
+                self.subscriber.on_endpoint_datagram_sent(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_datagram_received(
@@ -5609,8 +12812,9 @@ mod traits {
             meta: &EndpointMeta,
             event: &EndpointDatagramReceived,
         ) {
-            (self.0).on_endpoint_datagram_received(meta, event);
-            (self.1).on_endpoint_datagram_received(meta, event);
+            if self.sample(58usize, "endpoint_datagram_received", None) {
+                self.subscriber.on_endpoint_datagram_received(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_datagram_dropped(
@@ -5618,8 +12822,9 @@ mod traits {
             meta: &EndpointMeta,
             event: &EndpointDatagramDropped,
         ) {
-            (self.0).on_endpoint_datagram_dropped(meta, event);
-            (self.1).on_endpoint_datagram_dropped(meta, event);
+            if self.sample(59usize, "endpoint_datagram_dropped", None) {
+                self.subscriber.on_endpoint_datagram_dropped(meta, event);
+            }
         }
         #[inline]
         fn on_endpoint_connection_attempt_failed(
@@ -5627,28 +12832,50 @@ mod traits {
             meta: &EndpointMeta,
             event: &EndpointConnectionAttemptFailed,
         ) {
-            (self.0).on_endpoint_connection_attempt_failed(meta, event);
-            (self.1).on_endpoint_connection_attempt_failed(meta, event);
+            if self.sample(60usize, "endpoint_connection_attempt_failed", None) {
+                self.subscriber
+                    .on_endpoint_connection_attempt_failed(meta, event);
+            }
+        }
+        #[inline]
+        fn on_retry_sent(&mut self, meta: &EndpointMeta, event: &RetrySent) {
+            if self.sample(61usize, "retry_sent", None) {
+                self.subscriber.on_retry_sent(meta, event);
+            }
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointPacketDropped,
+        ) {
+            if self.sample(62usize, "endpoint_packet_dropped", None) {
+                self.subscriber.on_endpoint_packet_dropped(meta, event);
+            }
         }
         #[inline]
         fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
-            (self.0).on_platform_tx(meta, event);
-            (self.1).on_platform_tx(meta, event);
+            if self.sample(63usize, "platform_tx", None) {
+                self.subscriber.on_platform_tx(meta, event);
+            }
         }
         #[inline]
         fn on_platform_tx_error(&mut self, meta: &EndpointMeta, event: &PlatformTxError) {
-            (self.0).on_platform_tx_error(meta, event);
-            (self.1).on_platform_tx_error(meta, event);
+            if self.sample(64usize, "platform_tx_error", None) {
+                self.subscriber.on_platform_tx_error(meta, event);
+            }
         }
         #[inline]
         fn on_platform_rx(&mut self, meta: &EndpointMeta, event: &PlatformRx) {
-            (self.0).on_platform_rx(meta, event);
-            (self.1).on_platform_rx(meta, event);
+            if self.sample(65usize, "platform_rx", None) {
+                self.subscriber.on_platform_rx(meta, event);
+            }
         }
         #[inline]
         fn on_platform_rx_error(&mut self, meta: &EndpointMeta, event: &PlatformRxError) {
-            (self.0).on_platform_rx_error(meta, event);
-            (self.1).on_platform_rx_error(meta, event);
+            if self.sample(66usize, "platform_rx_error", None) {
+                self.subscriber.on_platform_rx_error(meta, event);
+            }
         }
         #[inline]
         fn on_platform_feature_configured(
@@ -5656,8 +12883,9 @@ mod traits {
             meta: &EndpointMeta,
             event: &PlatformFeatureConfigured,
         ) {
-            (self.0).on_platform_feature_configured(meta, event);
-            (self.1).on_platform_feature_configured(meta, event);
+            if self.sample(67usize, "platform_feature_configured", None) {
+                self.subscriber.on_platform_feature_configured(meta, event);
+            }
         }
         #[inline]
         fn on_platform_event_loop_wakeup(
@@ -5665,8 +12893,9 @@ mod traits {
             meta: &EndpointMeta,
             event: &PlatformEventLoopWakeup,
         ) {
-            (self.0).on_platform_event_loop_wakeup(meta, event);
-            (self.1).on_platform_event_loop_wakeup(meta, event);
+            if self.sample(68usize, "platform_event_loop_wakeup", None) {
+                self.subscriber.on_platform_event_loop_wakeup(meta, event);
+            }
         }
         #[inline]
         fn on_platform_event_loop_sleep(
@@ -5674,13 +12903,15 @@ mod traits {
             meta: &EndpointMeta,
             event: &PlatformEventLoopSleep,
         ) {
-            (self.0).on_platform_event_loop_sleep(meta, event);
-            (self.1).on_platform_event_loop_sleep(meta, event);
+            if self.sample(69usize, "platform_event_loop_sleep", None) {
+                self.subscriber.on_platform_event_loop_sleep(meta, event);
+            }
         }
         #[inline]
         fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
-            self.0.on_event(meta, event);
-            self.1.on_event(meta, event);
+            if self.is_sampled() {
+                self.subscriber.on_event(meta, event);
+            }
         }
         #[inline]
         fn on_connection_event<E: Event>(
@@ -5689,28 +12920,23 @@ mod traits {
             meta: &ConnectionMeta,
             event: &E,
         ) {
-            self.0.on_connection_event(&mut context.0, meta, event);
-            self.1.on_connection_event(&mut context.1, meta, event);
+            if self.is_sampled() {
+                self.subscriber.on_connection_event(context, meta, event);
+            }
         }
         #[inline]
         fn query(
             context: &Self::ConnectionContext,
             query: &mut dyn query::Query,
         ) -> query::ControlFlow {
-            query
-                .execute(context)
-                .and_then(|| A::query(&context.0, query))
-                .and_then(|| B::query(&context.1, query))
+            S::query(context, query)
         }
         #[inline]
         fn query_mut(
             context: &mut Self::ConnectionContext,
             query: &mut dyn query::QueryMut,
         ) -> query::ControlFlow {
-            query
-                .execute_mut(context)
-                .and_then(|| A::query_mut(&mut context.0, query))
-                .and_then(|| B::query_mut(&mut context.1, query))
+            S::query_mut(context, query)
         }
     }
     pub trait EndpointPublisher {
@@ -5731,6 +12957,10 @@ mod traits {
             &mut self,
             event: builder::EndpointConnectionAttemptFailed,
         );
+        #[doc = "Publishes a `RetrySent` event to the publisher's subscriber"]
+        fn on_retry_sent(&mut self, event: builder::RetrySent);
+        #[doc = "Publishes a `EndpointPacketDropped` event to the publisher's subscriber"]
+        fn on_endpoint_packet_dropped(&mut self, event: builder::EndpointPacketDropped);
         #[doc = "Publishes a `PlatformTx` event to the publisher's subscriber"]
         fn on_platform_tx(&mut self, event: builder::PlatformTx);
         #[doc = "Publishes a `PlatformTxError` event to the publisher's subscriber"]
@@ -5827,6 +13057,19 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_retry_sent(&mut self, event: builder::RetrySent) {
+            let event = event.into_event();
+            self.subscriber.on_retry_sent(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_endpoint_packet_dropped(&mut self, event: builder::EndpointPacketDropped) {
+            let event = event.into_event();
+            self.subscriber
+                .on_endpoint_packet_dropped(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             let event = event.into_event();
             self.subscriber.on_platform_tx(&self.meta, &event);
@@ -5902,6 +13145,8 @@ mod traits {
         fn on_recovery_metrics(&mut self, event: builder::RecoveryMetrics);
         #[doc = "Publishes a `Congestion` event to the publisher's subscriber"]
         fn on_congestion(&mut self, event: builder::Congestion);
+        #[doc = "Publishes a `PersistentCongestion` event to the publisher's subscriber"]
+        fn on_persistent_congestion(&mut self, event: builder::PersistentCongestion);
         #[doc = "Publishes a `AckProcessed` event to the publisher's subscriber"]
         fn on_ack_processed(&mut self, event: builder::AckProcessed);
         #[doc = "Publishes a `RxAckRangeDropped` event to the publisher's subscriber"]
@@ -5920,6 +13165,8 @@ mod traits {
         fn on_connection_started(&mut self, event: builder::ConnectionStarted);
         #[doc = "Publishes a `ConnectionClosed` event to the publisher's subscriber"]
         fn on_connection_closed(&mut self, event: builder::ConnectionClosed);
+        #[doc = "Publishes a `GracefulClose` event to the publisher's subscriber"]
+        fn on_graceful_close(&mut self, event: builder::GracefulClose);
         #[doc = "Publishes a `DuplicatePacket` event to the publisher's subscriber"]
         fn on_duplicate_packet(&mut self, event: builder::DuplicatePacket);
         #[doc = "Publishes a `TransportParametersReceived` event to the publisher's subscriber"]
@@ -5930,12 +13177,22 @@ mod traits {
         fn on_datagram_received(&mut self, event: builder::DatagramReceived);
         #[doc = "Publishes a `DatagramDropped` event to the publisher's subscriber"]
         fn on_datagram_dropped(&mut self, event: builder::DatagramDropped);
+        #[doc = "Publishes a `DatagramFrameDropped` event to the publisher's subscriber"]
+        fn on_datagram_frame_dropped(&mut self, event: builder::DatagramFrameDropped);
         #[doc = "Publishes a `ConnectionIdUpdated` event to the publisher's subscriber"]
         fn on_connection_id_updated(&mut self, event: builder::ConnectionIdUpdated);
+        #[doc = "Publishes a `ConnectionIdIssued` event to the publisher's subscriber"]
+        fn on_connection_id_issued(&mut self, event: builder::ConnectionIdIssued);
+        #[doc = "Publishes a `ConnectionIdRetired` event to the publisher's subscriber"]
+        fn on_connection_id_retired(&mut self, event: builder::ConnectionIdRetired);
+        #[doc = "Publishes a `FlowControlBlocked` event to the publisher's subscriber"]
+        fn on_flow_control_blocked(&mut self, event: builder::FlowControlBlocked);
         #[doc = "Publishes a `EcnStateChanged` event to the publisher's subscriber"]
         fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged);
         #[doc = "Publishes a `ConnectionMigrationDenied` event to the publisher's subscriber"]
         fn on_connection_migration_denied(&mut self, event: builder::ConnectionMigrationDenied);
+        #[doc = "Publishes a `NatRebindingDetected` event to the publisher's subscriber"]
+        fn on_nat_rebinding_detected(&mut self, event: builder::NatRebindingDetected);
         #[doc = "Publishes a `HandshakeStatusUpdated` event to the publisher's subscriber"]
         fn on_handshake_status_updated(&mut self, event: builder::HandshakeStatusUpdated);
         #[doc = "Publishes a `PathChallengeUpdated` event to the publisher's subscriber"]
@@ -5952,14 +13209,28 @@ mod traits {
         fn on_keep_alive_timer_expired(&mut self, event: builder::KeepAliveTimerExpired);
         #[doc = "Publishes a `MtuUpdated` event to the publisher's subscriber"]
         fn on_mtu_updated(&mut self, event: builder::MtuUpdated);
+        #[doc = "Publishes a `MtuProbingComplete` event to the publisher's subscriber"]
+        fn on_mtu_probing_complete(&mut self, event: builder::MtuProbingComplete);
         #[doc = "Publishes a `SlowStartExited` event to the publisher's subscriber"]
         fn on_slow_start_exited(&mut self, event: builder::SlowStartExited);
+        #[doc = "Publishes a `CongestionWindowUpdated` event to the publisher's subscriber"]
+        fn on_congestion_window_updated(&mut self, event: builder::CongestionWindowUpdated);
         #[doc = "Publishes a `DeliveryRateSampled` event to the publisher's subscriber"]
         fn on_delivery_rate_sampled(&mut self, event: builder::DeliveryRateSampled);
         #[doc = "Publishes a `PacingRateUpdated` event to the publisher's subscriber"]
         fn on_pacing_rate_updated(&mut self, event: builder::PacingRateUpdated);
         #[doc = "Publishes a `BbrStateChanged` event to the publisher's subscriber"]
         fn on_bbr_state_changed(&mut self, event: builder::BbrStateChanged);
+        #[doc = "Publishes a `HandshakeProgress` event to the publisher's subscriber"]
+        fn on_handshake_progress(&mut self, event: builder::HandshakeProgress);
+        #[doc = "Publishes a `HandshakeComplete` event to the publisher's subscriber"]
+        fn on_handshake_complete(&mut self, event: builder::HandshakeComplete);
+        #[doc = "Publishes a `KeyUpdated` event to the publisher's subscriber"]
+        fn on_key_updated(&mut self, event: builder::KeyUpdated);
+        #[doc = "Publishes a `PathValidationFailed` event to the publisher's subscriber"]
+        fn on_path_validation_failed(&mut self, event: builder::PathValidationFailed);
+        #[doc = "Publishes a `AckFrequencyUpdated` event to the publisher's subscriber"]
+        fn on_ack_frequency_updated(&mut self, event: builder::AckFrequencyUpdated);
         #[doc = r" Returns the QUIC version negotiated for the current connection, if any"]
         fn quic_version(&self) -> u32;
         #[doc = r" Returns the [`Subject`] for the current publisher"]
@@ -6099,6 +13370,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_persistent_congestion(&mut self, event: builder::PersistentCongestion) {
+            let event = event.into_event();
+            self.subscriber
+                .on_persistent_congestion(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         #[allow(deprecated)]
         fn on_ack_processed(&mut self, event: builder::AckProcessed) {
             let event = event.into_event();
@@ -6181,6 +13461,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_graceful_close(&mut self, event: builder::GracefulClose) {
+            let event = event.into_event();
+            self.subscriber
+                .on_graceful_close(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_duplicate_packet(&mut self, event: builder::DuplicatePacket) {
             let event = event.into_event();
             self.subscriber
@@ -6214,25 +13503,61 @@ mod traits {
         fn on_datagram_received(&mut self, event: builder::DatagramReceived) {
             let event = event.into_event();
             self.subscriber
-                .on_datagram_received(self.context, &self.meta, &event);
+                .on_datagram_received(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_datagram_dropped(&mut self, event: builder::DatagramDropped) {
+            let event = event.into_event();
+            self.subscriber
+                .on_datagram_dropped(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_datagram_frame_dropped(&mut self, event: builder::DatagramFrameDropped) {
+            let event = event.into_event();
+            self.subscriber
+                .on_datagram_frame_dropped(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_connection_id_updated(&mut self, event: builder::ConnectionIdUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_connection_id_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_connection_id_issued(&mut self, event: builder::ConnectionIdIssued) {
+            let event = event.into_event();
+            self.subscriber
+                .on_connection_id_issued(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_datagram_dropped(&mut self, event: builder::DatagramDropped) {
+        fn on_connection_id_retired(&mut self, event: builder::ConnectionIdRetired) {
             let event = event.into_event();
             self.subscriber
-                .on_datagram_dropped(self.context, &self.meta, &event);
+                .on_connection_id_retired(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_connection_id_updated(&mut self, event: builder::ConnectionIdUpdated) {
+        fn on_flow_control_blocked(&mut self, event: builder::FlowControlBlocked) {
             let event = event.into_event();
             self.subscriber
-                .on_connection_id_updated(self.context, &self.meta, &event);
+                .on_flow_control_blocked(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
@@ -6256,6 +13581,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_nat_rebinding_detected(&mut self, event: builder::NatRebindingDetected) {
+            let event = event.into_event();
+            self.subscriber
+                .on_nat_rebinding_detected(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_handshake_status_updated(&mut self, event: builder::HandshakeStatusUpdated) {
             let event = event.into_event();
             self.subscriber
@@ -6328,6 +13662,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_mtu_probing_complete(&mut self, event: builder::MtuProbingComplete) {
+            let event = event.into_event();
+            self.subscriber
+                .on_mtu_probing_complete(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_slow_start_exited(&mut self, event: builder::SlowStartExited) {
             let event = event.into_event();
             self.subscriber
@@ -6337,6 +13680,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_congestion_window_updated(&mut self, event: builder::CongestionWindowUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_congestion_window_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_delivery_rate_sampled(&mut self, event: builder::DeliveryRateSampled) {
             let event = event.into_event();
             self.subscriber
@@ -6364,6 +13716,51 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_handshake_progress(&mut self, event: builder::HandshakeProgress) {
+            let event = event.into_event();
+            self.subscriber
+                .on_handshake_progress(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_handshake_complete(&mut self, event: builder::HandshakeComplete) {
+            let event = event.into_event();
+            self.subscriber
+                .on_handshake_complete(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_key_updated(&mut self, event: builder::KeyUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_key_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_path_validation_failed(&mut self, event: builder::PathValidationFailed) {
+            let event = event.into_event();
+            self.subscriber
+                .on_path_validation_failed(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_ack_frequency_updated(&mut self, event: builder::AckFrequencyUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_ack_frequency_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn quic_version(&self) -> u32 {
             self.quic_version
         }
@@ -6391,6 +13788,7 @@ pub mod testing {
         pub packet_lost: u32,
         pub recovery_metrics: u32,
         pub congestion: u32,
+        pub persistent_congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
         pub ack_range_received: u32,
@@ -6400,14 +13798,20 @@ pub mod testing {
         pub key_space_discarded: u32,
         pub connection_started: u32,
         pub connection_closed: u32,
+        pub graceful_close: u32,
         pub duplicate_packet: u32,
         pub transport_parameters_received: u32,
         pub datagram_sent: u32,
         pub datagram_received: u32,
         pub datagram_dropped: u32,
+        pub datagram_frame_dropped: u32,
         pub connection_id_updated: u32,
+        pub connection_id_issued: u32,
+        pub connection_id_retired: u32,
+        pub flow_control_blocked: u32,
         pub ecn_state_changed: u32,
         pub connection_migration_denied: u32,
+        pub nat_rebinding_detected: u32,
         pub handshake_status_updated: u32,
         pub path_challenge_updated: u32,
         pub tls_client_hello: u32,
@@ -6416,10 +13820,17 @@ pub mod testing {
         pub tx_stream_progress: u32,
         pub keep_alive_timer_expired: u32,
         pub mtu_updated: u32,
+        pub mtu_probing_complete: u32,
         pub slow_start_exited: u32,
+        pub congestion_window_updated: u32,
         pub delivery_rate_sampled: u32,
         pub pacing_rate_updated: u32,
         pub bbr_state_changed: u32,
+        pub handshake_progress: u32,
+        pub handshake_complete: u32,
+        pub key_updated: u32,
+        pub path_validation_failed: u32,
+        pub ack_frequency_updated: u32,
         pub version_information: u32,
         pub endpoint_packet_sent: u32,
         pub endpoint_packet_received: u32,
@@ -6427,6 +13838,8 @@ pub mod testing {
         pub endpoint_datagram_received: u32,
         pub endpoint_datagram_dropped: u32,
         pub endpoint_connection_attempt_failed: u32,
+        pub retry_sent: u32,
+        pub endpoint_packet_dropped: u32,
         pub platform_tx: u32,
         pub platform_tx_error: u32,
         pub platform_rx: u32,
@@ -6469,6 +13882,7 @@ pub mod testing {
                 packet_lost: 0,
                 recovery_metrics: 0,
                 congestion: 0,
+                persistent_congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
                 ack_range_received: 0,
@@ -6478,14 +13892,20 @@ pub mod testing {
                 key_space_discarded: 0,
                 connection_started: 0,
                 connection_closed: 0,
+                graceful_close: 0,
                 duplicate_packet: 0,
                 transport_parameters_received: 0,
                 datagram_sent: 0,
                 datagram_received: 0,
                 datagram_dropped: 0,
+                datagram_frame_dropped: 0,
                 connection_id_updated: 0,
+                connection_id_issued: 0,
+                connection_id_retired: 0,
+                flow_control_blocked: 0,
                 ecn_state_changed: 0,
                 connection_migration_denied: 0,
+                nat_rebinding_detected: 0,
                 handshake_status_updated: 0,
                 path_challenge_updated: 0,
                 tls_client_hello: 0,
@@ -6494,10 +13914,17 @@ pub mod testing {
                 tx_stream_progress: 0,
                 keep_alive_timer_expired: 0,
                 mtu_updated: 0,
+                mtu_probing_complete: 0,
                 slow_start_exited: 0,
+                congestion_window_updated: 0,
                 delivery_rate_sampled: 0,
                 pacing_rate_updated: 0,
                 bbr_state_changed: 0,
+                handshake_progress: 0,
+                handshake_complete: 0,
+                key_updated: 0,
+                path_validation_failed: 0,
+                ack_frequency_updated: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
                 endpoint_packet_received: 0,
@@ -6505,6 +13932,8 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                retry_sent: 0,
+                endpoint_packet_dropped: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
@@ -6644,6 +14073,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_persistent_congestion(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PersistentCongestion,
+        ) {
+            self.persistent_congestion += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         #[allow(deprecated)]
         fn on_ack_processed(
             &mut self,
@@ -6744,6 +14184,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_graceful_close(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::GracefulClose,
+        ) {
+            self.graceful_close += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_duplicate_packet(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6799,6 +14250,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_datagram_frame_dropped(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramFrameDropped,
+        ) {
+            self.datagram_frame_dropped += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_connection_id_updated(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6810,6 +14272,39 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_connection_id_issued(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdIssued,
+        ) {
+            self.connection_id_issued += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_connection_id_retired(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdRetired,
+        ) {
+            self.connection_id_retired += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_flow_control_blocked(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FlowControlBlocked,
+        ) {
+            self.flow_control_blocked += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_ecn_state_changed(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6832,6 +14327,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_nat_rebinding_detected(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::NatRebindingDetected,
+        ) {
+            self.nat_rebinding_detected += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_handshake_status_updated(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6920,6 +14426,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_mtu_probing_complete(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::MtuProbingComplete,
+        ) {
+            self.mtu_probing_complete += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_slow_start_exited(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6931,6 +14448,17 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_congestion_window_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionWindowUpdated,
+        ) {
+            self.congestion_window_updated += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_delivery_rate_sampled(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6964,6 +14492,61 @@ pub mod testing {
                 self.output.push(format!("{meta:?} {event:?}"));
             }
         }
+        fn on_handshake_progress(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeProgress,
+        ) {
+            self.handshake_progress += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_handshake_complete(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeComplete,
+        ) {
+            self.handshake_complete += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_key_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeyUpdated,
+        ) {
+            self.key_updated += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_path_validation_failed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathValidationFailed,
+        ) {
+            self.path_validation_failed += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
+        fn on_ack_frequency_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckFrequencyUpdated,
+        ) {
+            self.ack_frequency_updated += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{meta:?} {event:?}"));
+            }
+        }
         fn on_version_information(
             &mut self,
             meta: &api::EndpointMeta,
@@ -7020,6 +14603,18 @@ pub mod testing {
             self.endpoint_connection_attempt_failed += 1;
             self.output.push(format!("{meta:?} {event:?}"));
         }
+        fn on_retry_sent(&mut self, meta: &api::EndpointMeta, event: &api::RetrySent) {
+            self.retry_sent += 1;
+            self.output.push(format!("{meta:?} {event:?}"));
+        }
+        fn on_endpoint_packet_dropped(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketDropped,
+        ) {
+            self.endpoint_packet_dropped += 1;
+            self.output.push(format!("{meta:?} {event:?}"));
+        }
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             self.platform_tx += 1;
             self.output.push(format!("{meta:?} {event:?}"));
@@ -7076,6 +14671,7 @@ pub mod testing {
         pub packet_lost: u32,
         pub recovery_metrics: u32,
         pub congestion: u32,
+        pub persistent_congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
         pub ack_range_received: u32,
@@ -7085,14 +14681,20 @@ pub mod testing {
         pub key_space_discarded: u32,
         pub connection_started: u32,
         pub connection_closed: u32,
+        pub graceful_close: u32,
         pub duplicate_packet: u32,
         pub transport_parameters_received: u32,
         pub datagram_sent: u32,
         pub datagram_received: u32,
         pub datagram_dropped: u32,
+        pub datagram_frame_dropped: u32,
         pub connection_id_updated: u32,
+        pub connection_id_issued: u32,
+        pub connection_id_retired: u32,
+        pub flow_control_blocked: u32,
         pub ecn_state_changed: u32,
         pub connection_migration_denied: u32,
+        pub nat_rebinding_detected: u32,
         pub handshake_status_updated: u32,
         pub path_challenge_updated: u32,
         pub tls_client_hello: u32,
@@ -7101,10 +14703,17 @@ pub mod testing {
         pub tx_stream_progress: u32,
         pub keep_alive_timer_expired: u32,
         pub mtu_updated: u32,
+        pub mtu_probing_complete: u32,
         pub slow_start_exited: u32,
+        pub congestion_window_updated: u32,
         pub delivery_rate_sampled: u32,
         pub pacing_rate_updated: u32,
         pub bbr_state_changed: u32,
+        pub handshake_progress: u32,
+        pub handshake_complete: u32,
+        pub key_updated: u32,
+        pub path_validation_failed: u32,
+        pub ack_frequency_updated: u32,
         pub version_information: u32,
         pub endpoint_packet_sent: u32,
         pub endpoint_packet_received: u32,
@@ -7112,6 +14721,8 @@ pub mod testing {
         pub endpoint_datagram_received: u32,
         pub endpoint_datagram_dropped: u32,
         pub endpoint_connection_attempt_failed: u32,
+        pub retry_sent: u32,
+        pub endpoint_packet_dropped: u32,
         pub platform_tx: u32,
         pub platform_tx_error: u32,
         pub platform_rx: u32,
@@ -7144,6 +14755,7 @@ pub mod testing {
                 packet_lost: 0,
                 recovery_metrics: 0,
                 congestion: 0,
+                persistent_congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
                 ack_range_received: 0,
@@ -7153,14 +14765,20 @@ pub mod testing {
                 key_space_discarded: 0,
                 connection_started: 0,
                 connection_closed: 0,
+                graceful_close: 0,
                 duplicate_packet: 0,
                 transport_parameters_received: 0,
                 datagram_sent: 0,
                 datagram_received: 0,
                 datagram_dropped: 0,
+                datagram_frame_dropped: 0,
                 connection_id_updated: 0,
+                connection_id_issued: 0,
+                connection_id_retired: 0,
+                flow_control_blocked: 0,
                 ecn_state_changed: 0,
                 connection_migration_denied: 0,
+                nat_rebinding_detected: 0,
                 handshake_status_updated: 0,
                 path_challenge_updated: 0,
                 tls_client_hello: 0,
@@ -7169,10 +14787,17 @@ pub mod testing {
                 tx_stream_progress: 0,
                 keep_alive_timer_expired: 0,
                 mtu_updated: 0,
+                mtu_probing_complete: 0,
                 slow_start_exited: 0,
+                congestion_window_updated: 0,
                 delivery_rate_sampled: 0,
                 pacing_rate_updated: 0,
                 bbr_state_changed: 0,
+                handshake_progress: 0,
+                handshake_complete: 0,
+                key_updated: 0,
+                path_validation_failed: 0,
+                ack_frequency_updated: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
                 endpoint_packet_received: 0,
@@ -7180,6 +14805,8 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                retry_sent: 0,
+                endpoint_packet_dropped: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
@@ -7229,6 +14856,16 @@ pub mod testing {
             let event = event.into_event();
             self.output.push(format!("{event:?}"));
         }
+        fn on_retry_sent(&mut self, event: builder::RetrySent) {
+            self.retry_sent += 1;
+            let event = event.into_event();
+            self.output.push(format!("{event:?}"));
+        }
+        fn on_endpoint_packet_dropped(&mut self, event: builder::EndpointPacketDropped) {
+            self.endpoint_packet_dropped += 1;
+            let event = event.into_event();
+            self.output.push(format!("{event:?}"));
+        }
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             self.platform_tx += 1;
             let event = event.into_event();
@@ -7349,6 +14986,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_persistent_congestion(&mut self, event: builder::PersistentCongestion) {
+            self.persistent_congestion += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         #[allow(deprecated)]
         fn on_ack_processed(&mut self, event: builder::AckProcessed) {
             self.ack_processed += 1;
@@ -7413,6 +15057,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_graceful_close(&mut self, event: builder::GracefulClose) {
+            self.graceful_close += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_duplicate_packet(&mut self, event: builder::DuplicatePacket) {
             self.duplicate_packet += 1;
             let event = event.into_event();
@@ -7451,6 +15102,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_datagram_frame_dropped(&mut self, event: builder::DatagramFrameDropped) {
+            self.datagram_frame_dropped += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_connection_id_updated(&mut self, event: builder::ConnectionIdUpdated) {
             self.connection_id_updated += 1;
             let event = event.into_event();
@@ -7458,6 +15116,27 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_connection_id_issued(&mut self, event: builder::ConnectionIdIssued) {
+            self.connection_id_issued += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_connection_id_retired(&mut self, event: builder::ConnectionIdRetired) {
+            self.connection_id_retired += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_flow_control_blocked(&mut self, event: builder::FlowControlBlocked) {
+            self.flow_control_blocked += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged) {
             self.ecn_state_changed += 1;
             let event = event.into_event();
@@ -7472,6 +15151,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_nat_rebinding_detected(&mut self, event: builder::NatRebindingDetected) {
+            self.nat_rebinding_detected += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_handshake_status_updated(&mut self, event: builder::HandshakeStatusUpdated) {
             self.handshake_status_updated += 1;
             let event = event.into_event();
@@ -7528,6 +15214,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_mtu_probing_complete(&mut self, event: builder::MtuProbingComplete) {
+            self.mtu_probing_complete += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_slow_start_exited(&mut self, event: builder::SlowStartExited) {
             self.slow_start_exited += 1;
             let event = event.into_event();
@@ -7535,6 +15228,13 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_congestion_window_updated(&mut self, event: builder::CongestionWindowUpdated) {
+            self.congestion_window_updated += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn on_delivery_rate_sampled(&mut self, event: builder::DeliveryRateSampled) {
             self.delivery_rate_sampled += 1;
             let event = event.into_event();
@@ -7556,6 +15256,41 @@ pub mod testing {
                 self.output.push(format!("{event:?}"));
             }
         }
+        fn on_handshake_progress(&mut self, event: builder::HandshakeProgress) {
+            self.handshake_progress += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_handshake_complete(&mut self, event: builder::HandshakeComplete) {
+            self.handshake_complete += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_key_updated(&mut self, event: builder::KeyUpdated) {
+            self.key_updated += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_path_validation_failed(&mut self, event: builder::PathValidationFailed) {
+            self.path_validation_failed += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
+        fn on_ack_frequency_updated(&mut self, event: builder::AckFrequencyUpdated) {
+            self.ack_frequency_updated += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{event:?}"));
+            }
+        }
         fn quic_version(&self) -> u32 {
             1
         }
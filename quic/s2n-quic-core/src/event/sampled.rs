@@ -0,0 +1,174 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+/// Information about an event which is passed to the [`Sampled`] predicate
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct SampleInfo {
+    /// The snake case name of the event, e.g. `packet_dropped`
+    pub event_name: &'static str,
+    /// The internal ID of the connection for connection-level events
+    pub connection_id: Option<u64>,
+}
+
+type Predicate = Box<dyn FnMut(&SampleInfo) -> bool + Send>;
+
+enum Sampler {
+    Every { n: u64, counts: Vec<u64> },
+    Predicate(Predicate),
+}
+
+/// A subscriber which only forwards a sample of the events to the inner subscriber
+///
+/// This is useful for keeping a subscriber for high-volume events, such as per-packet drops,
+/// without having it process every event under load. Connection contexts are always created, and
+/// supervisor and query calls are always forwarded.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // only record every 100th dropped packet
+/// let subscriber = event::Sampled::every(recorder::PacketDropped::new(), 100);
+/// ```
+pub struct Sampled<S> {
+    pub(crate) subscriber: S,
+    sampler: Sampler,
+    is_sampled: bool,
+}
+
+impl<S> Sampled<S> {
+    /// Forwards the first and then every `n`th event of each event type
+    pub fn every(subscriber: S, n: u64) -> Self {
+        Self::new(
+            subscriber,
+            Sampler::Every {
+                n: n.max(1),
+                counts: Vec::new(),
+            },
+        )
+    }
+
+    /// Forwards the events for which the predicate returns `true`
+    pub fn with_predicate<F>(subscriber: S, predicate: F) -> Self
+    where
+        F: 'static + FnMut(&SampleInfo) -> bool + Send,
+    {
+        Self::new(subscriber, Sampler::Predicate(Box::new(predicate)))
+    }
+
+    fn new(subscriber: S, sampler: Sampler) -> Self {
+        Self {
+            subscriber,
+            sampler,
+            is_sampled: false,
+        }
+    }
+
+    /// Returns a reference to the inner subscriber
+    pub fn subscriber(&self) -> &S {
+        &self.subscriber
+    }
+
+    /// Returns the inner subscriber
+    pub fn into_inner(self) -> S {
+        self.subscriber
+    }
+
+    /// Returns if the current event should be forwarded
+    ///
+    /// The decision is also stored for the `on_event` and `on_connection_event` calls that follow
+    /// the event-specific callback.
+    #[inline]
+    pub(crate) fn sample(
+        &mut self,
+        index: usize,
+        event_name: &'static str,
+        connection_id: Option<u64>,
+    ) -> bool {
+        self.is_sampled = match &mut self.sampler {
+            Sampler::Every { n, counts } => {
+                if counts.len() <= index {
+                    counts.resize(index + 1, 0);
+                }
+                let count = counts[index];
+                counts[index] = count.wrapping_add(1);
+                count % *n == 0
+            }
+            Sampler::Predicate(predicate) => predicate(&SampleInfo {
+                event_name,
+                connection_id,
+            }),
+        };
+        self.is_sampled
+    }
+
+    #[inline]
+    pub(crate) fn is_sampled(&self) -> bool {
+        self.is_sampled
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Sampled<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sampler = match &self.sampler {
+            Sampler::Every { n, .. } => alloc::format!("every {n}"),
+            Sampler::Predicate(_) => "predicate".into(),
+        };
+        f.debug_struct("Sampled")
+            .field("subscriber", &self.subscriber)
+            .field("sampler", &sampler)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        endpoint,
+        event::{builder, testing, EndpointPublisher, EndpointPublisherSubscriber},
+        time::Timestamp,
+    };
+
+    fn publish<S: crate::event::Subscriber>(subscriber: &mut S, count: usize) {
+        let meta = builder::EndpointMeta {
+            endpoint_type: endpoint::Type::Server,
+            timestamp: unsafe { Timestamp::from_duration(core::time::Duration::from_secs(1)) },
+        };
+        let mut publisher = EndpointPublisherSubscriber::new(meta, None, subscriber);
+        for len in 0..count {
+            publisher.on_endpoint_datagram_received(builder::EndpointDatagramReceived {
+                len: len as u16,
+            });
+        }
+    }
+
+    #[test]
+    fn every_test() {
+        let mut subscriber = Sampled::every(testing::Subscriber::no_snapshot(), 10);
+        publish(&mut subscriber, 35);
+        // events 0, 10, 20 and 30 are forwarded
+        assert_eq!(subscriber.subscriber().endpoint_datagram_received, 4);
+    }
+
+    #[test]
+    fn every_zero_test() {
+        let mut subscriber = Sampled::every(testing::Subscriber::no_snapshot(), 0);
+        publish(&mut subscriber, 5);
+        assert_eq!(subscriber.into_inner().endpoint_datagram_received, 5);
+    }
+
+    #[test]
+    fn predicate_test() {
+        let mut subscriber = Sampled::with_predicate(testing::Subscriber::no_snapshot(), |info| {
+            assert_eq!(info.event_name, "endpoint_datagram_received");
+            assert_eq!(info.connection_id, None);
+            false
+        });
+        publish(&mut subscriber, 5);
+        assert_eq!(subscriber.subscriber().endpoint_datagram_received, 0);
+    }
+}
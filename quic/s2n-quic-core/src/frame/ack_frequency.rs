@@ -0,0 +1,60 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::varint::VarInt;
+
+// An endpoint sends an ACK_FREQUENCY frame (type=0xaf) to request that its peer
+// changes how often it acknowledges ack-eliciting packets.
+//
+// See <https://datatracker.ietf.org/doc/draft-ietf-quic-ack-frequency/>
+
+macro_rules! ack_frequency_tag {
+    () => {
+        0xafu8
+    };
+}
+
+// ACK_FREQUENCY Frame {
+//   Type (i) = 0xaf,
+//   Sequence Number (i),
+//   Ack-Eliciting Threshold (i),
+//   Request Max Ack Delay (i),
+//   Reordering Threshold (i),
+// }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AckFrequency {
+    /// A sequence number assigned to the frame by the sender, which allows the receiver to
+    /// ignore frames that arrive out of order
+    pub sequence_number: VarInt,
+
+    /// The maximum number of ack-eliciting packets the recipient of this frame receives
+    /// before sending an acknowledgment
+    pub ack_eliciting_threshold: VarInt,
+
+    /// The value, in microseconds, to which the sender requests the recipient update its
+    /// max_ack_delay
+    pub request_max_ack_delay: VarInt,
+
+    /// The maximum packet reordering before the recipient immediately sends an
+    /// acknowledgment, with a value of 0 disabling immediate acknowledgment of reordered packets
+    pub reordering_threshold: VarInt,
+}
+
+impl AckFrequency {
+    pub const fn tag(self) -> u8 {
+        ack_frequency_tag!()
+    }
+}
+
+// The frame type doesn't fit in a single byte variable-length integer so it's encoded
+// with two bytes
+simple_frame_codec!(
+    AckFrequency {
+        sequence_number,
+        ack_eliciting_threshold,
+        request_max_ack_delay,
+        reordering_threshold
+    },
+    VarInt::from_u8(ack_frequency_tag!())
+);
@@ -0,0 +1,24 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+// An endpoint sends an IMMEDIATE_ACK frame (type=0x1f) to request that its peer
+// acknowledges the packet containing the frame without delay.
+//
+// See <https://datatracker.ietf.org/doc/draft-ietf-quic-ack-frequency/>
+
+macro_rules! immediate_ack_tag {
+    () => {
+        0x1fu8
+    };
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ImmediateAck;
+
+impl ImmediateAck {
+    pub const fn tag(self) -> u8 {
+        immediate_ack_tag!()
+    }
+}
+
+simple_frame_codec!(ImmediateAck {}, immediate_ack_tag!());
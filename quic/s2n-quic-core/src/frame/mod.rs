@@ -161,14 +161,22 @@ macro_rules! frames {
                 &mut self,
                 buffer: DecoderBufferMut<'a>,
             ) -> DecoderBufferMutResult<'a, Self::Output> {
-                let tag = buffer.peek_byte(0)?;
-                match tag {
+                let (tag, tag_len) = match buffer.peek_byte(0)? {
+                    // Frame types from 0x40 to 0xff are encoded as two byte variable-integers
+                    0b0100_0000 => match buffer.peek_byte(1)? {
+                        tag @ 0b0100_0000..=0xff => (tag, 2),
+                        _ => return self.handle_extension_frame(buffer),
+                    },
                     // Make sure the single byte frame tags fit into a small variable-integer
                     // otherwise fallback to extension selection
-                    0b0100_0000..=0xff => self.handle_extension_frame(buffer),
+                    0b0100_0001..=0xff => return self.handle_extension_frame(buffer),
+                    tag => (tag, core::mem::size_of::<Tag>()),
+                };
+
+                match tag {
                     $(
                         $tag_macro!() => {
-                            let buffer = buffer.skip(core::mem::size_of::<Tag>())?;
+                            let buffer = buffer.skip(tag_len)?;
                             let (frame, buffer) = buffer.decode_parameterized(tag)?;
                             let output = self.$handler(frame)?;
                             Ok((output, buffer))
@@ -237,6 +245,7 @@ frames! {
     padding_tag => padding, handle_padding_frame, Padding;
     ping_tag => ping, handle_ping_frame, Ping;
     ack_tag => ack, handle_ack_frame, Ack[AckRanges];
+    ack_frequency_tag => ack_frequency, handle_ack_frequency_frame, AckFrequency;
     reset_stream_tag => reset_stream, handle_reset_stream_frame, ResetStream;
     stop_sending_tag => stop_sending, handle_stop_sending_frame, StopSending;
     crypto_tag => crypto, handle_crypto_frame, Crypto[Data];
@@ -254,6 +263,7 @@ frames! {
     path_response_tag => path_response, handle_path_response_frame, PathResponse['a];
     connection_close_tag => connection_close, handle_connection_close_frame, ConnectionClose['a];
     handshake_done_tag => handshake_done, handle_handshake_done_frame, HandshakeDone;
+    immediate_ack_tag => immediate_ack, handle_immediate_ack_frame, ImmediateAck;
     datagram_tag => datagram, handle_datagram_frame, Datagram[Data];
 }
 
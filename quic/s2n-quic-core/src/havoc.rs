@@ -400,6 +400,15 @@ impl Strategy for Frame {
             },
             |_rand, _data, _cap| frame::Ping.into(),
             // TODO ACK
+            |rand, _data, _cap| {
+                frame::AckFrequency {
+                    sequence_number: rand.gen_varint(),
+                    ack_eliciting_threshold: rand.gen_varint(),
+                    request_max_ack_delay: rand.gen_varint(),
+                    reordering_threshold: rand.gen_varint(),
+                }
+                .into()
+            },
             |rand, _data, _cap| {
                 frame::ResetStream {
                     stream_id: rand.gen_varint(),
@@ -538,6 +547,7 @@ impl Strategy for Frame {
                 .into()
             },
             |_rand, _data, _cap| frame::HandshakeDone.into(),
+            |_rand, _data, _cap| frame::ImmediateAck.into(),
             |rand, data, cap| {
                 let data = rand.gen_slice(&mut data[..cap]);
                 frame::Datagram {
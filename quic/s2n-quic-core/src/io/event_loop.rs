@@ -24,7 +24,7 @@ pub struct EventLoop<E, C, R, T> {
 impl<E, C, R, T> EventLoop<E, C, R, T>
 where
     E: Endpoint,
-    C: ClockWithTimer,
+    C: 'static + ClockWithTimer + Send + Sync,
     R: Rx<PathHandle = E::PathHandle>,
     T: Tx<PathHandle = E::PathHandle>,
 {
@@ -38,6 +38,16 @@ where
             mut cooldown,
         } = self;
 
+        let mut timer = clock.timer();
+
+        // share the clock with the endpoint so application calls can read the current time
+        #[cfg(feature = "alloc")]
+        let clock = {
+            let clock = crate::time::clock::Shared::new(clock);
+            endpoint.set_clock(clock.clone());
+            clock
+        };
+
         /// Creates a event publisher with the endpoint's subscriber
         macro_rules! publisher {
             ($timestamp:expr) => {{
@@ -54,8 +64,6 @@ where
             }};
         }
 
-        let mut timer = clock.timer();
-
         loop {
             // Poll for RX readiness
             let rx_ready = rx.ready();
@@ -4,6 +4,7 @@
 use crate::{
     crypto::{HeaderKey, Key as CryptoKey, ProtectedPayload},
     packet::{
+        interceptor::Header,
         number::{PacketNumber, PacketNumberLen},
         stateless_reset,
     },
@@ -43,6 +44,13 @@ pub trait PacketPayloadEncoder {
         header_len: usize,
         tag_len: usize,
     );
+
+    /// Called with the plaintext header of the packet after the payload has been encoded,
+    /// before the packet is encrypted
+    #[inline]
+    fn on_header(&mut self, header: &mut Header) {
+        let _ = header;
+    }
 }
 
 impl<T: EncoderValue> PacketPayloadEncoder for T {
@@ -263,6 +271,27 @@ pub trait PacketEncoder<K: CryptoKey, H: HeaderKey, Payload: PacketPayloadEncode
         let actual_payload_len = buffer.len() + payload_len + key.tag_len() - header_len;
         payload_len_cursor.update(&mut buffer, actual_payload_len);
 
+        {
+            let (header, packet_number) = buffer.as_mut_slice().split_at_mut(header_len);
+
+            // The most significant bit of the first byte is set for long headers
+            let destination_connection_id = if header[0] & 0x80 == 0x80 {
+                // Long headers are prefixed with the first byte, the 4 byte version and the
+                // 1 byte destination connection ID length
+                let len = header[5] as usize;
+                &mut header[6..6 + len]
+            } else {
+                // Short headers are prefixed with only the first byte and do not contain
+                // a payload len
+                &mut header[1..]
+            };
+
+            self.payload().on_header(&mut Header {
+                destination_connection_id,
+                packet_number,
+            });
+        }
+
         // Advance the buffer cursor by what the payload wrote inline. We'll recreate the scatter
         // buffer with the option extra bytes at the end.
         buffer.advance_position(inline_len);
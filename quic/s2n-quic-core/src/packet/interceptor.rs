@@ -19,6 +19,16 @@ pub struct Packet {
     pub timestamp: Timestamp,
 }
 
+/// The plaintext header of a packet that is about to be encrypted
+///
+/// TODO add `non_exhaustive` once/if this feature is stable
+#[derive(Debug)]
+pub struct Header<'a> {
+    pub destination_connection_id: &'a mut [u8],
+    /// The truncated packet number, as it is encoded on the wire
+    pub packet_number: &'a mut [u8],
+}
+
 /// TODO add `non_exhaustive` once/if this feature is stable
 #[derive(Debug)]
 pub struct Datagram<'a> {
@@ -29,6 +39,7 @@ pub struct Datagram<'a> {
 
 /// Trait which enables an application to intercept packets that are transmitted and received
 pub trait Interceptor: 'static + Send {
+    /// Intercepts a datagram after it is received, before any packets are decoded
     #[inline(always)]
     fn intercept_rx_datagram<'a>(
         &mut self,
@@ -41,6 +52,8 @@ pub trait Interceptor: 'static + Send {
         payload
     }
 
+    /// Intercepts the payload of a received packet after it is decrypted, before its
+    /// frames are processed
     #[inline(always)]
     fn intercept_rx_payload<'a>(
         &mut self,
@@ -53,6 +66,7 @@ pub trait Interceptor: 'static + Send {
         payload
     }
 
+    /// Intercepts a datagram after all of its packets have been encrypted, before it is transmitted
     #[inline(always)]
     fn intercept_tx_datagram(
         &mut self,
@@ -65,6 +79,7 @@ pub trait Interceptor: 'static + Send {
         let _ = payload;
     }
 
+    /// Intercepts the payload of a packet after its frames are written, before it is encrypted
     #[inline(always)]
     fn intercept_tx_payload(
         &mut self,
@@ -76,6 +91,15 @@ pub trait Interceptor: 'static + Send {
         let _ = packet;
         let _ = payload;
     }
+
+    /// Intercepts the header of a packet after its payload is written, before the packet is
+    /// encrypted and header protection is applied
+    #[inline(always)]
+    fn intercept_tx_header(&mut self, subject: &Subject, packet: &Packet, header: &mut Header) {
+        let _ = subject;
+        let _ = packet;
+        let _ = header;
+    }
 }
 
 #[derive(Debug, Default)]
@@ -131,6 +155,12 @@ where
         self.0.intercept_tx_payload(subject, packet, payload);
         self.1.intercept_tx_payload(subject, packet, payload);
     }
+
+    #[inline(always)]
+    fn intercept_tx_header(&mut self, subject: &Subject, packet: &Packet, header: &mut Header) {
+        self.0.intercept_tx_header(subject, packet, header);
+        self.1.intercept_tx_header(subject, packet, header);
+    }
 }
 
 #[derive(Debug, Default)]
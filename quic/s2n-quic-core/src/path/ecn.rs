@@ -0,0 +1,31 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Controls how Explicit Congestion Notification (ECN) is used on each path
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Mode {
+    /// Packets are marked as ECN capable and congestion signaled by the peer through ECN-CE
+    /// counts is treated as congestion by the congestion controller
+    #[default]
+    Enabled,
+    /// Packets are marked as ECN capable and ECN feedback is validated and reported through
+    /// events, but ECN-CE counts are not acted upon by the congestion controller
+    Validate,
+    /// Packets are never marked as ECN capable
+    Disabled,
+}
+
+impl Mode {
+    /// Returns `true` if packets should be marked as ECN capable
+    #[inline]
+    pub fn is_enabled(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+
+    /// Returns `true` if the congestion controller should respond to ECN-CE counts
+    #[inline]
+    pub fn responds_to_congestion(self) -> bool {
+        matches!(self, Self::Enabled)
+    }
+}
@@ -15,7 +15,9 @@ use core::{
 #[cfg(any(test, feature = "generator"))]
 use bolero_generator::*;
 
+pub mod ecn;
 pub mod migration;
+pub mod mtu;
 
 //= https://www.rfc-editor.org/rfc/rfc9000#section-14
 //# QUIC MUST NOT be used if the network path cannot support a
@@ -52,6 +54,19 @@ const MIN_ALLOWED_MAX_MTU: u16 =
 // Initial PTO backoff multiplier is 1 indicating no additional increase to the backoff.
 pub const INITIAL_PTO_BACKOFF: u32 = 1;
 
+//= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
+//# When a PTO timer expires, the PTO backoff MUST be increased,
+//# resulting in the PTO period being set to twice its current value.
+pub const DEFAULT_PTO_BACKOFF_FACTOR: u32 = 2;
+
+//= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
+//# Prior to validating the client address, servers MUST NOT send more
+//# than three times as many bytes as the number of bytes they have
+//# received.
+/// The factor by which a server may send more bytes than it received before the client's
+/// address is validated
+pub const DEFAULT_AMPLIFICATION_LIMIT: u8 = 3;
+
 /// Internal Id of a path in the manager
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct Id(u8);
@@ -95,6 +110,15 @@ pub trait Handle: 'static + Copy + Send + fmt::Debug {
     /// Returns the local address for the given handle
     fn local_address(&self) -> LocalAddress;
 
+    /// Sets the local address that packets on the given handle are sent from
+    ///
+    /// This is used by clients to select the local address of a new path when migrating.
+    /// Handles which are unable to select a local address leave the handle unchanged.
+    #[inline]
+    fn set_local_address(&mut self, local_address: LocalAddress) {
+        let _ = local_address;
+    }
+
     /// Returns `true` if the two handles are equal from a network perspective
     ///
     /// This function is used to determine if a connection has migrated to another
@@ -232,6 +256,11 @@ impl Handle for Tuple {
         self.local_address
     }
 
+    #[inline]
+    fn set_local_address(&mut self, local_address: LocalAddress) {
+        self.local_address = local_address;
+    }
+
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         PartialEq::eq(&self.local_address.unmap(), &other.local_address.unmap())
@@ -310,6 +339,63 @@ impl Display for MaxMtuError {
 #[cfg(feature = "std")]
 impl std::error::Error for MaxMtuError {}
 
+/// The smallest maximum transmission unit (MTU) that all paths are assumed to support
+///
+/// Like [`MaxMtu`], this value includes the IP and UDP headers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MinMtu(NonZeroU16);
+
+impl MinMtu {
+    /// The minimum value required for path MTU
+    pub const MIN: Self = Self(unsafe { NonZeroU16::new_unchecked(MIN_ALLOWED_MAX_MTU) });
+}
+
+impl Default for MinMtu {
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+impl TryFrom<u16> for MinMtu {
+    type Error = MinMtuError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        if value < MIN_ALLOWED_MAX_MTU {
+            return Err(MinMtuError(MIN_ALLOWED_MAX_MTU.try_into().unwrap()));
+        }
+
+        Ok(MinMtu(value.try_into().expect(
+            "Value must be greater than zero according to the check above",
+        )))
+    }
+}
+
+impl From<MinMtu> for usize {
+    #[inline]
+    fn from(value: MinMtu) -> Self {
+        value.0.get() as usize
+    }
+}
+
+impl From<MinMtu> for u16 {
+    #[inline]
+    fn from(value: MinMtu) -> Self {
+        value.0.get()
+    }
+}
+
+#[derive(Debug)]
+pub struct MinMtuError(NonZeroU16);
+
+impl Display for MinMtuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MinMtu must be at least {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MinMtuError {}
+
 //= https://www.rfc-editor.org/rfc/rfc9308#section-8.1
 //# Some UDP protocols are vulnerable to reflection attacks, where an
 //# attacker is able to direct traffic to a third party as a denial of
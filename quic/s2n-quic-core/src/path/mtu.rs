@@ -0,0 +1,238 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    event::{api::SocketAddress, IntoEvent},
+    inet,
+    path::{MaxMtu, MinMtu, IPV4_MIN_HEADER_LEN, IPV6_MIN_HEADER_LEN, MINIMUM_MTU, UDP_HEADER_LEN},
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    time::Duration,
+};
+
+/// The default number of loss bursts of packets larger than the minimum MTU that may occur
+/// before an MTU black hole is detected
+pub const DEFAULT_BLACK_HOLE_THRESHOLD: u8 = 3;
+
+/// The largest black hole threshold that still allows an MTU black hole to be detected
+///
+/// Loss bursts are counted with a counter that saturates at `u8::MAX`, so a threshold of
+/// `u8::MAX` could never be exceeded.
+pub const MAX_BLACK_HOLE_THRESHOLD: u8 = u8::MAX - 1;
+
+/// Configuration for the MTU discovery performed on each path
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The largest maximum transmission unit (MTU) that can be sent on a path
+    pub max_mtu: MaxMtu,
+    /// The smallest maximum transmission unit (MTU) that all paths are assumed to support
+    ///
+    /// Each path starts at this MTU rather than the 1200 byte minimum required by QUIC, and never
+    /// falls below it, even when a black hole is detected. The value includes the IP and UDP
+    /// headers, so it must be at least 1228 bytes, and must not be greater than `max_mtu`.
+    pub min_mtu: MinMtu,
+    /// The minimum amount of time between the transmission of consecutive MTU probes on a path
+    ///
+    /// A value of zero sends the next probe as soon as the previous one has been
+    /// acknowledged or declared lost. Spacing probes out reduces the chance of a transient
+    /// loss being attributed to an oversized probe, at the cost of taking more round trips
+    /// to discover the path MTU.
+    ///
+    /// Each probe is ack-eliciting and restarts the idle timer, so probing keeps an otherwise
+    /// idle connection open until the search completes. Waiting between probes is not
+    /// considered activity, so an interval longer than the idle timeout allows an idle
+    /// connection to close before the next probe is sent.
+    pub probe_interval: Duration,
+    /// The number of loss bursts of packets larger than the minimum MTU that may occur before
+    /// an MTU black hole is detected
+    ///
+    /// Only bursts that start with a packet sent after the last acknowledged MTU-sized packet
+    /// are counted. Once the count exceeds this threshold, the MTU is reduced to the minimum.
+    /// A larger threshold avoids overreacting to brief outages at the cost of taking longer to
+    /// recover from an actual black hole. Must not be greater than [`MAX_BLACK_HOLE_THRESHOLD`].
+    pub black_hole_threshold: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_mtu: MaxMtu::default(),
+            min_mtu: MinMtu::default(),
+            probe_interval: Duration::ZERO,
+            black_hole_threshold: DEFAULT_BLACK_HOLE_THRESHOLD,
+        }
+    }
+}
+
+impl Config {
+    /// Returns an error if the `min_mtu` exceeds the `max_mtu`, or if the `black_hole_threshold`
+    /// exceeds [`MAX_BLACK_HOLE_THRESHOLD`]
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let min_mtu: u16 = self.min_mtu.into();
+        let max_mtu: u16 = self.max_mtu.into();
+
+        if min_mtu > max_mtu {
+            return Err(ConfigError::MinMtuExceedsMaxMtu { min_mtu, max_mtu });
+        }
+
+        if self.black_hole_threshold > MAX_BLACK_HOLE_THRESHOLD {
+            return Err(ConfigError::BlackHoleThresholdTooLarge {
+                black_hole_threshold: self.black_hole_threshold,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the smallest UDP payload that can be sent on a path to the `remote_address`
+    ///
+    /// The UDP header length and minimum IP header length are subtracted from the `min_mtu`.
+    #[inline]
+    pub fn min_udp_payload(&self, remote_address: &inet::SocketAddress) -> u16 {
+        let min_ip_header_len = match remote_address {
+            inet::SocketAddress::IpV4(_) => IPV4_MIN_HEADER_LEN,
+            inet::SocketAddress::IpV6(_) => IPV6_MIN_HEADER_LEN,
+        };
+
+        (u16::from(self.min_mtu) - UDP_HEADER_LEN - min_ip_header_len).max(MINIMUM_MTU)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The `min_mtu` is greater than the `max_mtu`
+    MinMtuExceedsMaxMtu { min_mtu: u16, max_mtu: u16 },
+    /// The `black_hole_threshold` is greater than [`MAX_BLACK_HOLE_THRESHOLD`]
+    BlackHoleThresholdTooLarge { black_hole_threshold: u8 },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MinMtuExceedsMaxMtu { min_mtu, max_mtu } => write!(
+                f,
+                "MinMtu ({min_mtu}) must not be greater than MaxMtu ({max_mtu})"
+            ),
+            Self::BlackHoleThresholdTooLarge {
+                black_hole_threshold,
+            } => write!(
+                f,
+                "MTU black hole threshold ({black_hole_threshold}) must not be greater than {MAX_BLACK_HOLE_THRESHOLD}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
+
+/// Creates an [`MtuController`] for each path of a connection
+pub trait Endpoint: 'static + Debug + Send {
+    type MtuController: MtuController;
+
+    fn new_mtu_controller(&mut self, path_info: PathInfo) -> Self::MtuController;
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PathInfo<'a> {
+    pub remote_address: SocketAddress<'a>,
+}
+
+impl<'a> PathInfo<'a> {
+    #[inline]
+    pub fn new(remote_address: &'a inet::SocketAddress) -> Self {
+        Self {
+            remote_address: remote_address.into_event(),
+        }
+    }
+}
+
+/// Selects the sizes used when probing a path for a larger MTU
+///
+/// All sizes are UDP payload sizes, i.e. they do not include the IP or UDP header.
+///
+/// The first probe on a path targets a standard Ethernet MTU. Each subsequent probe size
+/// is selected by calling [`MtuController::next_probe_size`]. Probing concludes once the
+/// returned size is within a small threshold of the currently confirmed size.
+pub trait MtuController: 'static + Clone + Debug + Send {
+    /// Returns the next size to probe for
+    ///
+    /// `current` is the currently confirmed size and `max` is the largest size
+    /// that may be probed. The returned value is clamped to `current..=max`.
+    fn next_probe_size(&mut self, current: u16, max: u16) -> u16;
+
+    /// Called when a probe of `probed_size` bytes was acknowledged by the peer
+    #[inline]
+    fn on_probe_success(&mut self, probed_size: u16) {
+        let _ = probed_size;
+    }
+
+    /// Called when the maximum number of probes of `probed_size` bytes were lost
+    ///
+    /// `probed_size` will be used as the upper bound for subsequent probes.
+    #[inline]
+    fn on_probe_failure(&mut self, probed_size: u16) {
+        let _ = probed_size;
+    }
+
+    /// Called when a black hole has been detected on the path
+    ///
+    /// Returns the size the path should fall back to. The returned value is clamped
+    /// to `base..=current`, where `base` is the minimum size configured for the path.
+    #[inline]
+    fn on_blackhole_detected(&mut self, current: u16, base: u16) -> u16 {
+        let _ = current;
+        base
+    }
+}
+
+pub mod default {
+    use super::*;
+
+    /// Creates [`Controller`]s that binary search between the confirmed
+    /// and maximum sizes
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Endpoint;
+
+    impl super::Endpoint for Endpoint {
+        type MtuController = Controller;
+
+        #[inline]
+        fn new_mtu_controller(&mut self, _path_info: PathInfo) -> Self::MtuController {
+            Controller
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Controller;
+
+    impl MtuController for Controller {
+        #[inline]
+        fn next_probe_size(&mut self, current: u16, max: u16) -> u16 {
+            //= https://www.rfc-editor.org/rfc/rfc8899#section-5.3.2
+            //# Implementations SHOULD select the set of probe packet sizes to
+            //# maximize the gain in PLPMTU from each search step.
+            current + (max.saturating_sub(current) / 2)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn binary_search_test() {
+            let mut controller = Controller;
+
+            assert_eq!(controller.next_probe_size(1200, 1472), 1336);
+            assert_eq!(controller.next_probe_size(1472, 8973), 5222);
+            assert_eq!(controller.next_probe_size(1472, 1472), 1472);
+            // a max smaller than the current size does not underflow
+            assert_eq!(controller.next_probe_size(1472, 1200), 1472);
+            assert_eq!(controller.on_blackhole_detected(8973, 1200), 1200);
+        }
+    }
+}
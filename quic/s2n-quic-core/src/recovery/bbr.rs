@@ -4,7 +4,7 @@
 use crate::{
     counter::Counter,
     event,
-    event::IntoEvent,
+    event::{builder::CongestionWindowUpdatedCause, IntoEvent},
     random,
     recovery::{
         bandwidth,
@@ -298,7 +298,9 @@ impl CongestionController for BbrCongestionController {
             //= https://tools.ietf.org/id/draft-cardwell-iccrg-bbr-congestion-control-02#4.2.2
             //# BBROnTransmit():
             //#   BBRHandleRestartFromIdle()
+            let prev_cwnd = self.cwnd;
             self.handle_restart_from_idle(time_sent, publisher);
+            self.publish_cwnd_update(prev_cwnd, CongestionWindowUpdatedCause::Other, publisher);
 
             self.bytes_in_flight
                 .try_add(sent_bytes)
@@ -346,6 +348,12 @@ impl CongestionController for BbrCongestionController {
         publisher: &mut Pub,
     ) {
         let is_cwnd_limited = self.is_congestion_limited();
+        let prev_cwnd = self.cwnd;
+        let cause = if self.state.is_startup() {
+            CongestionWindowUpdatedCause::SlowStart
+        } else {
+            CongestionWindowUpdatedCause::CongestionAvoidance
+        };
         self.bytes_in_flight
             .try_sub(bytes_acknowledged)
             .expect("bytes_acknowledged should not exceed u32::MAX");
@@ -448,6 +456,8 @@ impl CongestionController for BbrCongestionController {
             self.pacer.set_send_quantum(self.max_datagram_size);
             self.set_cwnd(bytes_acknowledged);
         }
+
+        self.publish_cwnd_update(prev_cwnd, cause, publisher);
     }
 
     #[inline]
@@ -464,6 +474,7 @@ impl CongestionController for BbrCongestionController {
         debug_assert!(lost_bytes > 0);
 
         self.bytes_in_flight -= lost_bytes;
+        let prev_cwnd = self.cwnd;
         self.bw_estimator.on_loss(lost_bytes as usize);
         self.recovery_state.on_congestion_event(timestamp);
         self.congestion_state
@@ -479,6 +490,12 @@ impl CongestionController for BbrCongestionController {
             timestamp,
             publisher,
         );
+
+        self.publish_cwnd_update(
+            prev_cwnd,
+            CongestionWindowUpdatedCause::PacketLoss,
+            publisher,
+        );
     }
 
     #[inline]
@@ -507,12 +524,19 @@ impl CongestionController for BbrCongestionController {
     //# handshake, the congestion window SHOULD be set to the new initial
     //# congestion window.
     #[inline]
-    fn on_mtu_update<Pub: Publisher>(&mut self, max_datagram_size: u16, _publisher: &mut Pub) {
+    fn on_mtu_update<Pub: Publisher>(&mut self, max_datagram_size: u16, publisher: &mut Pub) {
+        let prev_cwnd = self.cwnd;
         let old_max_datagram_size = self.max_datagram_size;
         self.max_datagram_size = max_datagram_size;
 
         self.cwnd =
             ((self.cwnd as f32 / old_max_datagram_size as f32) * max_datagram_size as f32) as u32;
+
+        self.publish_cwnd_update(
+            prev_cwnd,
+            CongestionWindowUpdatedCause::MtuUpdate,
+            publisher,
+        );
     }
 
     #[inline]
@@ -533,6 +557,11 @@ impl CongestionController for BbrCongestionController {
     fn send_quantum(&self) -> Option<usize> {
         Some(self.pacer.send_quantum())
     }
+
+    #[inline]
+    fn delivery_rate(&self) -> Option<Bandwidth> {
+        Some(self.bw_estimator.rate_sample().delivery_rate())
+    }
 }
 
 impl BbrCongestionController {
@@ -885,6 +914,25 @@ impl BbrCongestionController {
         cap.min(inflight_lo).max(self.minimum_window())
     }
 
+    /// Publishes a `CongestionWindowUpdated` event if the congestion window
+    /// differs from the given `prev_cwnd`
+    #[inline]
+    fn publish_cwnd_update<Pub: Publisher>(
+        &self,
+        prev_cwnd: u32,
+        cause: CongestionWindowUpdatedCause,
+        publisher: &mut Pub,
+    ) {
+        if self.cwnd != prev_cwnd {
+            publisher.on_congestion_window_updated(
+                cause,
+                prev_cwnd,
+                self.cwnd,
+                *self.bytes_in_flight,
+            );
+        }
+    }
+
     /// Saves the last-known good congestion window (the latest cwnd unmodulated by loss recovery or ProbeRTT)
     #[inline]
     fn save_cwnd(&mut self) {
@@ -5,7 +5,7 @@ use crate::{
     event,
     event::{
         api::SocketAddress,
-        builder::{BbrState, SlowStartExitCause},
+        builder::{BbrState, CongestionWindowUpdatedCause, SlowStartExitCause},
         IntoEvent,
     },
     inet, path,
@@ -49,6 +49,24 @@ impl<'a> PathInfo<'a> {
 pub trait Publisher {
     /// Invoked when the congestion controller has exited the Slow Start phase
     fn on_slow_start_exited(&mut self, cause: SlowStartExitCause, congestion_window: u32);
+    /// Invoked when the congestion window has been updated
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn on_congestion_window_updated(
+        &mut self,
+        cause: CongestionWindowUpdatedCause,
+        old_congestion_window: u32,
+        new_congestion_window: u32,
+        bytes_in_flight: u32,
+    ) {
+        let _ = (
+            cause,
+            old_congestion_window,
+            new_congestion_window,
+            bytes_in_flight,
+        );
+    }
     /// Invoked when the delivery rate sample has been updated
     fn on_delivery_rate_sampled(&mut self, rate_sample: RateSample);
     /// Invoked when the pacing rate has been updated
@@ -87,6 +105,24 @@ impl<'a, Pub: event::ConnectionPublisher> Publisher for PathPublisher<'a, Pub> {
             });
     }
 
+    #[inline]
+    fn on_congestion_window_updated(
+        &mut self,
+        cause: CongestionWindowUpdatedCause,
+        old_congestion_window: u32,
+        new_congestion_window: u32,
+        bytes_in_flight: u32,
+    ) {
+        self.publisher
+            .on_congestion_window_updated(event::builder::CongestionWindowUpdated {
+                path_id: self.path_id.into_event(),
+                cause,
+                old_congestion_window,
+                new_congestion_window,
+                bytes_in_flight,
+            });
+    }
+
     #[inline]
     fn on_delivery_rate_sampled(&mut self, rate_sample: RateSample) {
         self.publisher
@@ -126,9 +162,9 @@ impl<'a, Pub: event::ConnectionPublisher> Publisher for PathPublisher<'a, Pub> {
 
 /// An algorithm for controlling congestion.
 ///
-/// NOTE: This trait is considered unstable and can only be implemented by
-///       including the `unstable-congestion-controller` feature.
-pub trait CongestionController: 'static + Clone + Send + Debug + private::Sealed {
+/// The endpoint drives each path's congestion controller as packets are sent, acknowledged and
+/// declared lost, and consults it before every transmission, including MTU probes.
+pub trait CongestionController: 'static + Clone + Send + Debug {
     /// Additional metadata about a packet to track until a sent packet
     /// is either acknowledged or declared lost
     type PacketInfo: Copy + Send + Sized + Debug;
@@ -245,26 +281,13 @@ pub trait CongestionController: 'static + Clone + Send + Debug + private::Sealed
     fn send_quantum(&self) -> Option<usize> {
         None
     }
-}
 
-// Prevent implementation of the `CongestionController` trait if the
-// `unstable-congestion-controller` feature is not turned on.
-mod private {
-    use cfg_if::cfg_if;
-
-    pub trait Sealed {}
-
-    cfg_if!(
-        if #[cfg(any(test, feature = "unstable-congestion-controller", feature = "testing"))] {
-            // If `unstable-congestion-controller` is enabled, implement Sealed for any type that
-            // otherwise implements `CongestionController`
-            impl<T: crate::recovery::CongestionController> Sealed for T {}
-        } else {
-            // Otherwise only allow the included CUBIC and BBRv2 congestion controllers
-            impl Sealed for crate::recovery::CubicCongestionController {}
-            impl Sealed for crate::recovery::bbr::BbrCongestionController {}
-        }
-    );
+    /// The most recent estimate of the rate at which the path delivers data
+    ///
+    /// If the value is `None`, the congestion controller does not sample the delivery rate.
+    fn delivery_rate(&self) -> Option<Bandwidth> {
+        None
+    }
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -3,7 +3,8 @@
 
 use crate::{
     counter::Counter,
-    event::builder::SlowStartExitCause,
+    event::builder::{CongestionWindowUpdatedCause, SlowStartExitCause},
+    path::MINIMUM_MTU,
     random,
     recovery::{
         congestion_controller::{self, CongestionController, Publisher},
@@ -340,6 +341,15 @@ impl CongestionController for CubicCongestionController {
             return;
         }
 
+        let prev_congestion_window = self.congestion_window();
+        let cause = if self.state.is_slow_start() && self.slow_start.is_conservative() {
+            CongestionWindowUpdatedCause::ConservativeSlowStart
+        } else if self.state.is_slow_start() {
+            CongestionWindowUpdatedCause::SlowStart
+        } else {
+            CongestionWindowUpdatedCause::CongestionAvoidance
+        };
+
         match self.state {
             SlowStart => {
                 //= https://www.rfc-editor.org/rfc/rfc9002#section-7.3.1
@@ -383,6 +393,8 @@ impl CongestionController for CubicCongestionController {
             }
         };
 
+        self.publish_congestion_window_update(prev_congestion_window, cause, publisher);
+
         debug_assert!(self.congestion_window >= self.cubic.minimum_window());
     }
 
@@ -401,6 +413,8 @@ impl CongestionController for CubicCongestionController {
 
         self.bytes_in_flight -= lost_bytes;
 
+        let prev_congestion_window = self.congestion_window();
+
         if self.state.is_slow_start() && !persistent_congestion {
             publisher
                 .on_slow_start_exited(SlowStartExitCause::PacketLoss, self.congestion_window());
@@ -418,6 +432,13 @@ impl CongestionController for CubicCongestionController {
             self.state = State::SlowStart;
             self.cubic.reset();
         }
+
+        let cause = if persistent_congestion {
+            CongestionWindowUpdatedCause::PersistentCongestion
+        } else {
+            CongestionWindowUpdatedCause::PacketLoss
+        };
+        self.publish_congestion_window_update(prev_congestion_window, cause, publisher);
     }
 
     #[inline]
@@ -427,6 +448,8 @@ impl CongestionController for CubicCongestionController {
         event_time: Timestamp,
         publisher: &mut Pub,
     ) {
+        let prev_congestion_window = self.congestion_window();
+
         if self.state.is_slow_start() {
             publisher.on_slow_start_exited(SlowStartExitCause::Ecn, self.congestion_window());
         }
@@ -437,6 +460,12 @@ impl CongestionController for CubicCongestionController {
         //# Experienced (CE) codepoint in the IP header as a signal of
         //# congestion.
         self.on_congestion_event(event_time);
+
+        self.publish_congestion_window_update(
+            prev_congestion_window,
+            CongestionWindowUpdatedCause::Ecn,
+            publisher,
+        );
     }
 
     //= https://www.rfc-editor.org/rfc/rfc8899#section-3
@@ -457,13 +486,20 @@ impl CongestionController for CubicCongestionController {
     //# handshake, the congestion window SHOULD be set to the new initial
     //# congestion window.
     #[inline]
-    fn on_mtu_update<Pub: Publisher>(&mut self, max_datagram_size: u16, _publisher: &mut Pub) {
+    fn on_mtu_update<Pub: Publisher>(&mut self, max_datagram_size: u16, publisher: &mut Pub) {
+        let prev_congestion_window = self.congestion_window();
         let old_max_datagram_size = self.max_datagram_size;
         self.max_datagram_size = max_datagram_size;
         self.cubic.max_datagram_size = max_datagram_size;
 
         self.congestion_window =
             (self.congestion_window / old_max_datagram_size as f32) * max_datagram_size as f32;
+
+        self.publish_congestion_window_update(
+            prev_congestion_window,
+            CongestionWindowUpdatedCause::MtuUpdate,
+            publisher,
+        );
     }
 
     //= https://www.rfc-editor.org/rfc/rfc9002#section-6.4
@@ -529,6 +565,26 @@ impl CubicCongestionController {
         )
     }
 
+    /// Publishes a `CongestionWindowUpdated` event if the congestion window
+    /// differs from the given `prev_congestion_window`
+    #[inline]
+    fn publish_congestion_window_update<Pub: Publisher>(
+        &self,
+        prev_congestion_window: u32,
+        cause: CongestionWindowUpdatedCause,
+        publisher: &mut Pub,
+    ) {
+        let congestion_window = self.congestion_window();
+        if congestion_window != prev_congestion_window {
+            publisher.on_congestion_window_updated(
+                cause,
+                prev_congestion_window,
+                congestion_window,
+                *self.bytes_in_flight,
+            );
+        }
+    }
+
     #[inline]
     fn congestion_avoidance(
         &mut self,
@@ -695,6 +751,8 @@ struct Cubic {
     // k is the time until we expect to reach w_max
     k: Duration,
     max_datagram_size: u16,
+    // the configured minimum window in bytes, if it differs from the recommended value
+    minimum_window: Option<u32>,
 }
 
 //= https://www.rfc-editor.org/rfc/rfc8312#section-5.1
@@ -714,6 +772,7 @@ impl Cubic {
             w_last_max: 0.0,
             k: Duration::ZERO,
             max_datagram_size,
+            minimum_window: None,
         }
     }
 
@@ -853,7 +912,11 @@ impl Cubic {
     //# value is 2 * max_datagram_size.
     #[inline]
     fn minimum_window(&self) -> f32 {
-        2.0 * self.max_datagram_size as f32
+        match self.minimum_window {
+            // the window must always allow for at least one full-sized packet to be sent
+            Some(minimum_window) => (minimum_window as f32).max(self.max_datagram_size as f32),
+            None => 2.0 * self.max_datagram_size as f32,
+        }
     }
 
     #[inline]
@@ -864,7 +927,30 @@ impl Cubic {
 
 #[non_exhaustive]
 #[derive(Debug, Default)]
-pub struct Endpoint {}
+pub struct Endpoint {
+    initial_congestion_window: Option<u32>,
+    minimum_congestion_window: Option<u32>,
+    slow_start_mode: Option<SlowStartMode>,
+}
+
+/// The algorithm used to exit Slow Start before any packets are lost
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SlowStartMode {
+    /// Hybrid Slow Start, which exits Slow Start once the round trip time increases
+    #[default]
+    Hybrid,
+    /// HyStart++, which slows the growth of the congestion window once the round trip time
+    /// increases and exits Slow Start if the increase persists for several rounds
+    HyStartPlusPlus,
+}
+
+impl Endpoint {
+    /// Creates a builder for the CUBIC congestion controller endpoint
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
 
 impl congestion_controller::Endpoint for Endpoint {
     type CongestionController = CubicCongestionController;
@@ -873,7 +959,114 @@ impl congestion_controller::Endpoint for Endpoint {
         &mut self,
         path_info: congestion_controller::PathInfo,
     ) -> Self::CongestionController {
-        CubicCongestionController::new(path_info.max_datagram_size)
+        let mut congestion_controller = CubicCongestionController::new(path_info.max_datagram_size);
+
+        congestion_controller.cubic.minimum_window = self.minimum_congestion_window;
+
+        if let Some(mode) = self.slow_start_mode {
+            congestion_controller
+                .slow_start
+                .set_hystart_plus_plus(mode == SlowStartMode::HyStartPlusPlus);
+        }
+
+        if let Some(initial_congestion_window) = self.initial_congestion_window {
+            congestion_controller.congestion_window = (initial_congestion_window as f32)
+                .max(congestion_controller.cubic.minimum_window());
+        }
+
+        congestion_controller
+    }
+}
+
+/// A builder for the CUBIC congestion controller endpoint
+#[derive(Debug, Default)]
+pub struct Builder {
+    initial_congestion_window: Option<u32>,
+    minimum_congestion_window: Option<u32>,
+    slow_start_mode: Option<SlowStartMode>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuilderError {
+    /// The window can't fit a single packet of the minimum MTU
+    WindowTooSmall,
+    /// The initial congestion window is smaller than the minimum congestion window
+    InitialWindowBelowMinimum,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuilderError {}
+
+impl core::fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::WindowTooSmall => write!(
+                f,
+                "The congestion window must be at least {MINIMUM_MTU} bytes"
+            ),
+            Self::InitialWindowBelowMinimum => write!(
+                f,
+                "The initial congestion window must not be smaller than the minimum congestion window"
+            ),
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the congestion window, in bytes, that new paths start with
+    ///
+    /// Defaults to ten times the maximum datagram size, limited to 14,720 bytes, as
+    /// recommended by RFC 9002. Larger values allow transfers over paths with a high
+    /// bandwidth-delay product to ramp up in fewer round trips.
+    pub fn with_initial_congestion_window(mut self, bytes: u32) -> Result<Self, BuilderError> {
+        if bytes < MINIMUM_MTU as u32 {
+            return Err(BuilderError::WindowTooSmall);
+        }
+        self.initial_congestion_window = Some(bytes);
+        Ok(self)
+    }
+
+    /// Sets the smallest congestion window, in bytes, that a path can be reduced to in
+    /// response to loss, ECN congestion marks or persistent congestion
+    ///
+    /// Defaults to twice the maximum datagram size. The window never drops below a single
+    /// maximum datagram size, no matter the configured value.
+    pub fn with_min_congestion_window(mut self, bytes: u32) -> Result<Self, BuilderError> {
+        if bytes < MINIMUM_MTU as u32 {
+            return Err(BuilderError::WindowTooSmall);
+        }
+        self.minimum_congestion_window = Some(bytes);
+        Ok(self)
+    }
+
+    /// Sets the algorithm used to exit Slow Start before any packets are lost
+    ///
+    /// Defaults to [`SlowStartMode::Hybrid`], or [`SlowStartMode::HyStartPlusPlus`] if the
+    /// `S2N_UNSTABLE_USE_HYSTART_PP` environment variable is set. Setting the mode explicitly
+    /// ignores the environment variable. [`SlowStartMode::HyStartPlusPlus`] can reduce the
+    /// amount the congestion window overshoots the available capacity on paths with a high
+    /// bandwidth-delay product.
+    pub fn with_slow_start_mode(mut self, mode: SlowStartMode) -> Self {
+        self.slow_start_mode = Some(mode);
+        self
+    }
+
+    pub fn build(self) -> Result<Endpoint, BuilderError> {
+        if let (Some(initial), Some(minimum)) = (
+            self.initial_congestion_window,
+            self.minimum_congestion_window,
+        ) {
+            if initial < minimum {
+                return Err(BuilderError::InitialWindowBelowMinimum);
+            }
+        }
+
+        Ok(Endpoint {
+            initial_congestion_window: self.initial_congestion_window,
+            minimum_congestion_window: self.minimum_congestion_window,
+            slow_start_mode: self.slow_start_mode,
+        })
     }
 }
 
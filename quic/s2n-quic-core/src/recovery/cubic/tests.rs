@@ -210,6 +210,79 @@ fn minimum_window_equals_two_times_max_datagram_size() {
     );
 }
 
+#[test]
+fn configured_windows() {
+    use congestion_controller::Endpoint as _;
+
+    let remote_address = crate::inet::SocketAddress::default();
+    let path_info = congestion_controller::PathInfo::new(&remote_address);
+
+    let mut endpoint = Endpoint::builder()
+        .with_initial_congestion_window(100_000)
+        .unwrap()
+        .with_min_congestion_window(5_000)
+        .unwrap()
+        .build()
+        .unwrap();
+    let cc = endpoint.new_congestion_controller(path_info);
+
+    assert_eq!(cc.congestion_window(), 100_000);
+    assert_delta!(cc.cubic.minimum_window(), 5_000.0, 0.001);
+
+    // the minimum window always fits a single packet
+    let mut cc = CubicCongestionController::new(1200);
+    cc.cubic.minimum_window = Some(1200);
+    cc.cubic.max_datagram_size = 1500;
+    assert_delta!(cc.cubic.minimum_window(), 1500.0, 0.001);
+}
+
+#[test]
+fn configured_slow_start_mode() {
+    use congestion_controller::Endpoint as _;
+
+    let remote_address = crate::inet::SocketAddress::default();
+
+    // an explicit mode overrides the `S2N_UNSTABLE_USE_HYSTART_PP` environment variable
+    for (mode, expected) in [
+        (SlowStartMode::Hybrid, false),
+        (SlowStartMode::HyStartPlusPlus, true),
+    ] {
+        let mut endpoint = Endpoint::builder()
+            .with_slow_start_mode(mode)
+            .build()
+            .unwrap();
+        let path_info = congestion_controller::PathInfo::new(&remote_address);
+        let cc = endpoint.new_congestion_controller(path_info);
+        assert_eq!(cc.slow_start.use_hystart_plus_plus, expected, "{mode:?}");
+    }
+}
+
+#[test]
+fn configured_windows_validation() {
+    assert_eq!(
+        Endpoint::builder()
+            .with_initial_congestion_window(1199)
+            .unwrap_err(),
+        BuilderError::WindowTooSmall
+    );
+    assert_eq!(
+        Endpoint::builder()
+            .with_min_congestion_window(1199)
+            .unwrap_err(),
+        BuilderError::WindowTooSmall
+    );
+    assert_eq!(
+        Endpoint::builder()
+            .with_initial_congestion_window(2_000)
+            .unwrap()
+            .with_min_congestion_window(3_000)
+            .unwrap()
+            .build()
+            .unwrap_err(),
+        BuilderError::InitialWindowBelowMinimum
+    );
+}
+
 #[test]
 fn on_packet_sent() {
     let mut cc = CubicCongestionController::new(1000);
@@ -19,7 +19,7 @@ pub struct HybridSlowStart {
     pub(super) threshold: f32,
     max_datagram_size: u16,
     rtt_round_end_time: Option<Timestamp>,
-    use_hystart_plus_plus: bool,
+    pub(super) use_hystart_plus_plus: bool,
     ss_growth_divisor: f32,
     css_count: usize,
     css_baseline_min_rtt: Duration,
@@ -172,6 +172,17 @@ impl HybridSlowStart {
         }
     }
 
+    /// Sets whether HyStart++ is used, regardless of the `S2N_UNSTABLE_USE_HYSTART_PP`
+    /// environment variable
+    pub fn set_hystart_plus_plus(&mut self, enabled: bool) {
+        self.use_hystart_plus_plus = enabled;
+    }
+
+    /// Returns true if HyStart++ Conservative Slow Start is reducing the rate of growth
+    pub fn is_conservative(&self) -> bool {
+        self.ss_growth_divisor > 1.0
+    }
+
     /// return cwnd increment during slow start phase
     /// should be called from on_packet_ack
     pub fn cwnd_increment(&self, sent_bytes: usize) -> f32 {
@@ -11,6 +11,7 @@ pub mod bbr;
 pub mod congestion_controller;
 pub mod cubic;
 mod hybrid_slow_start;
+pub mod pacer;
 mod pacing;
 mod rtt_estimator;
 mod sent_packets;
@@ -35,3 +36,16 @@ mod simulation;
 //# A sender with knowledge that the network path to the
 //# receiver can absorb larger bursts MAY use a higher limit.
 pub const MAX_BURST_PACKETS: u32 = 10;
+
+//= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.1
+//# The RECOMMENDED initial value for the packet reordering threshold
+//# (kPacketThreshold) is 3, based on best practices for TCP loss
+//# detection [RFC5681] [RFC6675].  In order to remain similar to TCP,
+//# implementations SHOULD NOT use a packet threshold less than 3; see
+//# [RFC5681].
+pub const K_PACKET_THRESHOLD: u64 = 3;
+
+//= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.2
+//# The RECOMMENDED time threshold (kTimeThreshold), expressed as an
+//# RTT multiplier, is 9/8.
+pub const K_TIME_THRESHOLD: f32 = 9.0 / 8.0;
@@ -0,0 +1,162 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    event::{api::SocketAddress, IntoEvent},
+    inet,
+    recovery::RttEstimator,
+    time::Timestamp,
+};
+use core::fmt::Debug;
+
+/// Creates a [`Pacer`] for each path of a connection
+pub trait Endpoint: 'static + Debug + Send {
+    type Pacer: Pacer;
+
+    fn new_pacer(&mut self, path_info: PathInfo) -> Self::Pacer;
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct PathInfo<'a> {
+    pub remote_address: SocketAddress<'a>,
+}
+
+impl<'a> PathInfo<'a> {
+    #[inline]
+    pub fn new(remote_address: &'a inet::SocketAddress) -> Self {
+        Self {
+            remote_address: remote_address.into_event(),
+        }
+    }
+}
+
+/// Schedules the departure of congestion controlled packets on a path
+pub trait Pacer: 'static + Clone + Debug + Send {
+    /// Called when a congestion controlled packet of `bytes_sent` bytes has been sent
+    #[inline]
+    fn on_packet_sent(
+        &mut self,
+        time_sent: Timestamp,
+        bytes_sent: usize,
+        congestion_window: u32,
+        rtt_estimator: &RttEstimator,
+    ) {
+        let _ = (time_sent, bytes_sent, congestion_window, rtt_estimator);
+    }
+
+    /// Returns the earliest time that the next packet may be transmitted
+    ///
+    /// `congestion_controller_departure_time` is the departure time computed by the
+    /// pacing built into the congestion controller. If the returned time is in the
+    /// past or is `None`, the packet is transmitted immediately.
+    fn next_departure_time(
+        &self,
+        now: Timestamp,
+        congestion_window: u32,
+        rtt_estimator: &RttEstimator,
+        congestion_controller_departure_time: Option<Timestamp>,
+    ) -> Option<Timestamp>;
+}
+
+pub mod default {
+    use super::*;
+
+    /// Creates [`Pacer`]s that follow the pacing built into the congestion controller
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Endpoint;
+
+    impl super::Endpoint for Endpoint {
+        type Pacer = Pacer;
+
+        #[inline]
+        fn new_pacer(&mut self, _path_info: PathInfo) -> Self::Pacer {
+            Pacer
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Pacer;
+
+    impl super::Pacer for Pacer {
+        #[inline]
+        fn next_departure_time(
+            &self,
+            _now: Timestamp,
+            _congestion_window: u32,
+            _rtt_estimator: &RttEstimator,
+            congestion_controller_departure_time: Option<Timestamp>,
+        ) -> Option<Timestamp> {
+            congestion_controller_departure_time
+        }
+    }
+}
+
+pub mod disabled {
+    use super::*;
+
+    /// Creates [`Pacer`]s that transmit packets as soon as the congestion window allows
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Endpoint;
+
+    impl super::Endpoint for Endpoint {
+        type Pacer = Pacer;
+
+        #[inline]
+        fn new_pacer(&mut self, _path_info: PathInfo) -> Self::Pacer {
+            Pacer
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Pacer;
+
+    impl super::Pacer for Pacer {
+        #[inline]
+        fn next_departure_time(
+            &self,
+            _now: Timestamp,
+            _congestion_window: u32,
+            _rtt_estimator: &RttEstimator,
+            _congestion_controller_departure_time: Option<Timestamp>,
+        ) -> Option<Timestamp> {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pacer as _, *};
+    use crate::time::{Clock, NoopClock};
+
+    #[test]
+    fn default_pacer_test() {
+        let now = NoopClock.get_time();
+        let rtt_estimator = RttEstimator::default();
+        let mut pacer = default::Pacer;
+
+        pacer.on_packet_sent(now, 1200, 12_000, &rtt_estimator);
+        assert_eq!(
+            pacer.next_departure_time(now, 12_000, &rtt_estimator, None),
+            None
+        );
+        assert_eq!(
+            pacer.next_departure_time(now, 12_000, &rtt_estimator, Some(now)),
+            Some(now)
+        );
+    }
+
+    #[test]
+    fn disabled_pacer_test() {
+        let now = NoopClock.get_time();
+        let rtt_estimator = RttEstimator::default();
+        let mut pacer = disabled::Pacer;
+
+        pacer.on_packet_sent(now, 1200, 12_000, &rtt_estimator);
+        assert_eq!(
+            pacer.next_departure_time(now, 12_000, &rtt_estimator, Some(now)),
+            None
+        );
+    }
+}
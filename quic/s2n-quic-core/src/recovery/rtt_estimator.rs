@@ -54,6 +54,13 @@ impl Default for RttEstimator {
 impl RttEstimator {
     /// Creates a new RTT Estimator with default initial values using the given `max_ack_delay`.
     pub fn new(max_ack_delay: Duration) -> Self {
+        Self::with_initial_rtt(max_ack_delay, DEFAULT_INITIAL_RTT)
+    }
+
+    /// Creates a new RTT Estimator using the given `max_ack_delay` and `initial_rtt`
+    ///
+    /// The `initial_rtt` is used until the first RTT sample is available.
+    pub fn with_initial_rtt(max_ack_delay: Duration, initial_rtt: Duration) -> Self {
         //= https://www.rfc-editor.org/rfc/rfc9002#section-5.3
         //# Before any RTT samples are available for a new path or when the
         //# estimator is reset, the estimator is initialized using the initial RTT;
@@ -64,12 +71,12 @@ impl RttEstimator {
         //
         //# smoothed_rtt = kInitialRtt
         //# rttvar = kInitialRtt / 2
-        let smoothed_rtt = DEFAULT_INITIAL_RTT;
-        let rttvar = DEFAULT_INITIAL_RTT / 2;
+        let smoothed_rtt = initial_rtt;
+        let rttvar = initial_rtt / 2;
 
         Self {
-            latest_rtt: DEFAULT_INITIAL_RTT,
-            min_rtt: DEFAULT_INITIAL_RTT,
+            latest_rtt: initial_rtt,
+            min_rtt: initial_rtt,
             smoothed_rtt,
             rttvar,
             max_ack_delay,
@@ -364,6 +371,34 @@ mod test {
         );
     }
 
+    /// Test a configured initial RTT is used until the first RTT sample
+    #[test]
+    fn configured_initial_rtt() {
+        let initial_rtt = Duration::from_millis(600);
+        let mut rtt_estimator = RttEstimator::with_initial_rtt(Duration::ZERO, initial_rtt);
+        assert_eq!(rtt_estimator.min_rtt, initial_rtt);
+        assert_eq!(rtt_estimator.latest_rtt(), initial_rtt);
+        assert_eq!(rtt_estimator.smoothed_rtt(), initial_rtt);
+        assert_eq!(rtt_estimator.rttvar(), initial_rtt / 2);
+        assert_eq!(
+            rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, PacketNumberSpace::Initial),
+            Duration::from_millis(1800)
+        );
+
+        // the first sample replaces the initial estimate
+        let now = NoopClock.get_time();
+        rtt_estimator.update_rtt(
+            Duration::ZERO,
+            Duration::from_millis(100),
+            now,
+            false,
+            PacketNumberSpace::Initial,
+        );
+        assert_eq!(rtt_estimator.min_rtt, Duration::from_millis(100));
+        assert_eq!(rtt_estimator.smoothed_rtt(), Duration::from_millis(100));
+        assert_eq!(rtt_estimator.rttvar(), Duration::from_millis(50));
+    }
+
     /// Test a zero RTT value is treated as 1 µs
     #[test]
     fn zero_rtt_sample() {
@@ -3,6 +3,7 @@
 
 //! Defines the Stateless Reset token
 
+use crate::random;
 use core::convert::{TryFrom, TryInto};
 use s2n_codec::{decoder_value, Encoder, EncoderValue};
 use subtle::ConstantTimeEq;
@@ -110,6 +111,21 @@ pub trait Generator: 'static + Send {
     /// To enable stateless reset functionality, the stateless reset token must
     /// be generated the same for a given `local_connection_id` before and after loss of state.
     fn generate(&mut self, local_connection_id: &[u8]) -> Token;
+
+    /// Generates a stateless reset token with the endpoint's random generator
+    ///
+    /// The endpoint calls this method instead of `generate` so randomly generated tokens can be
+    /// derived from the configured random provider. By default, the random generator is ignored
+    /// and `generate` is called.
+    #[inline]
+    fn generate_with_random(
+        &mut self,
+        local_connection_id: &[u8],
+        random_generator: &mut dyn random::Generator,
+    ) -> Token {
+        let _ = random_generator;
+        self.generate(local_connection_id)
+    }
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -22,6 +22,11 @@ pub enum StreamError {
     #[non_exhaustive]
     StreamReset {
         error: application::Error,
+        /// The final size of the stream, as reported by the peer
+        ///
+        /// This is only available on the receiving side of a stream that was
+        /// reset by a `RESET_STREAM` frame.
+        final_size: Option<u64>,
         source: &'static panic::Location<'static>,
     },
     /// A send attempt had been performed on a Stream after it was closed
@@ -131,7 +136,23 @@ impl StreamError {
     #[doc(hidden)]
     pub fn stream_reset(error: application::Error) -> StreamError {
         let source = panic::Location::caller();
-        StreamError::StreamReset { source, error }
+        StreamError::StreamReset {
+            source,
+            error,
+            final_size: None,
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    #[doc(hidden)]
+    pub fn stream_reset_with_final_size(error: application::Error, final_size: u64) -> StreamError {
+        let source = panic::Location::caller();
+        StreamError::StreamReset {
+            source,
+            error,
+            final_size: Some(final_size),
+        }
     }
 
     #[track_caller]
@@ -185,10 +206,10 @@ impl StreamError {
 
 impl application::error::TryInto for StreamError {
     fn application_error(&self) -> Option<application::Error> {
-        if let StreamError::ConnectionError { error, .. } = self {
-            error.application_error()
-        } else {
-            None
+        match self {
+            StreamError::StreamReset { error, .. } => Some(*error),
+            StreamError::ConnectionError { error, .. } => error.application_error(),
+            _ => None,
         }
     }
 }
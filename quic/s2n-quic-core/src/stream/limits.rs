@@ -29,6 +29,9 @@ pub struct Limits {
     /// is not communicated to the peer, it is only used for limiting
     /// concurrent streams opened locally by the application.
     pub max_open_local_bidirectional_streams: LocalBidirectional,
+    /// The maximum distance past the first missing byte of a Stream at which received data is
+    /// buffered. A peer exceeding this limit causes the connection to be closed.
+    pub max_reorder_buffer_size: Option<u64>,
 }
 
 impl Default for Limits {
@@ -42,6 +45,7 @@ impl Limits {
         max_send_buffer_size: MaxSendBufferSize::RECOMMENDED,
         max_open_local_unidirectional_streams: LocalUnidirectional::RECOMMENDED,
         max_open_local_bidirectional_streams: LocalBidirectional::RECOMMENDED,
+        max_reorder_buffer_size: None,
     };
 }
 
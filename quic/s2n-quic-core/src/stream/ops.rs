@@ -29,7 +29,7 @@
 //! ```
 
 use crate::{application, stream};
-use core::task::Poll;
+use core::{task::Poll, time::Duration};
 
 /// A request made on a stream
 #[derive(Default, Debug)]
@@ -66,6 +66,29 @@ impl<'a> Request<'a> {
         self
     }
 
+    /// Sets the transmission priority of the tx stream
+    ///
+    /// Streams with a higher priority are scheduled for transmission before streams
+    /// with a lower priority. Streams with equal priority share the available
+    /// capacity in a round-robin fashion.
+    pub fn set_priority(&mut self, priority: u8) -> &mut Self {
+        self.tx_mut().priority = Some(priority);
+        self
+    }
+
+    /// Sets the amount of time written data has to be acknowledged by the peer
+    ///
+    /// If data written after this call isn't acknowledged within the deadline, the tx stream
+    /// is reset with the provided `error`.
+    pub fn set_send_deadline(
+        &mut self,
+        deadline: Duration,
+        error: application::Error,
+    ) -> &mut Self {
+        self.tx_mut().send_deadline = Some((deadline, error));
+        self
+    }
+
     /// Requests data on the rx stream to be received into the provided slice of chunks
     pub fn receive(&mut self, chunks: &'a mut [bytes::Bytes]) -> &mut Self {
         self.rx_mut().chunks = Some(chunks);
@@ -196,6 +219,13 @@ pub mod tx {
         /// Marks the tx stream as detached, which makes the stream make progress, regardless of
         /// application observations.
         pub detached: bool,
+
+        /// Optionally updates the transmission priority of the stream
+        pub priority: Option<u8>,
+
+        /// Optionally sets the deadline for written data to be acknowledged, along with the
+        /// error used to reset the stream if the deadline expires
+        pub send_deadline: Option<(Duration, application::Error)>,
     }
 
     /// The result of a tx request
@@ -499,6 +529,11 @@ mod tests {
             .finish()
             .flush()
             .reset(application::Error::new(1).unwrap())
+            .set_priority(7)
+            .set_send_deadline(
+                Duration::from_millis(100),
+                application::Error::new(3).unwrap(),
+            )
             .receive(&mut receive_chunks)
             .with_watermark(5, 10)
             .stop_sending(application::Error::new(2).unwrap());
@@ -512,6 +547,8 @@ mod tests {
                     flush: true,
                     reset: Some(reset),
                     detached: false,
+                    priority: Some(7),
+                    send_deadline: Some((deadline, deadline_error)),
                 }),
                 rx: Some(rx::Request {
                     chunks: Some(rx_chunks),
@@ -521,6 +558,8 @@ mod tests {
                     detached: false,
                 })
             } if reset == application::Error::new(1).unwrap()
+              && deadline == Duration::from_millis(100)
+              && deadline_error == application::Error::new(3).unwrap()
               && stop_sending == application::Error::new(2).unwrap()
               && tx_chunks.len() == 1
               && rx_chunks.len() == 2
@@ -21,6 +21,34 @@ pub trait Clock {
     fn get_time(&self) -> Timestamp;
 }
 
+/// A [`Clock`] which can be shared with application tasks
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Shared(alloc::sync::Arc<dyn Clock + Send + Sync>);
+
+#[cfg(feature = "alloc")]
+impl Shared {
+    #[inline]
+    pub fn new<C: 'static + Clock + Send + Sync>(clock: C) -> Self {
+        Self(alloc::sync::Arc::new(clock))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clock for Shared {
+    #[inline]
+    fn get_time(&self) -> Timestamp {
+        self.0.get_time()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for Shared {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Shared").finish()
+    }
+}
+
 pub trait ClockWithTimer: Clock {
     type Timer: Timer;
 
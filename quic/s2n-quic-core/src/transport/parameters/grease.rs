@@ -0,0 +1,90 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{TransportParameterId, TransportParameterLength};
+use crate::{random, varint::VarInt};
+use s2n_codec::{Encoder, EncoderValue};
+
+/// The largest multiplier used when selecting a reserved identifier
+///
+/// This keeps the identifier within a 4 byte variable-length integer.
+const MAX_MULTIPLIER: usize = 1 << 16;
+
+/// The maximum length of the value sent in a reserved transport parameter
+const MAX_VALUE_LEN: usize = 16;
+
+/// A reserved transport parameter with a random identifier and value
+///
+/// Sending these parameters exercises the requirement that peers ignore transport
+/// parameters they don't support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GreaseParameter {
+    id: TransportParameterId,
+    value: [u8; MAX_VALUE_LEN],
+    len: u8,
+}
+
+impl GreaseParameter {
+    /// Creates a reserved transport parameter with a random identifier and value
+    pub fn new<R: random::Generator + ?Sized>(random_generator: &mut R) -> Self {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.1
+        //# Transport parameters with an identifier of the form "31 * N + 27" for
+        //# integer values of N are reserved to exercise the requirement that
+        //# unknown transport parameters be ignored.  These transport parameters
+        //# have no semantics and can carry arbitrary values.
+        let n = random::gen_range_biased(random_generator, 0..=MAX_MULTIPLIER);
+        let id = VarInt::from_u32(31 * n as u32 + 27);
+
+        let len = random::gen_range_biased(random_generator, 0..=MAX_VALUE_LEN);
+        let mut value = [0; MAX_VALUE_LEN];
+        random_generator.public_random_fill(&mut value[..len]);
+
+        Self {
+            id,
+            value,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the identifier of the parameter
+    #[inline]
+    pub fn id(&self) -> VarInt {
+        self.id
+    }
+
+    /// Returns the value of the parameter
+    #[inline]
+    pub fn value(&self) -> &[u8] {
+        &self.value[..self.len as usize]
+    }
+}
+
+impl EncoderValue for GreaseParameter {
+    #[inline]
+    fn encode<E: Encoder>(&self, buffer: &mut E) {
+        buffer.encode(&self.id);
+        buffer.encode_with_len_prefix::<TransportParameterLength, _>(&self.value());
+    }
+}
+
+/// Encodes a set of transport parameters followed by an optional reserved parameter
+#[derive(Debug)]
+pub struct WithGrease<'a, T> {
+    parameters: &'a T,
+    grease: Option<GreaseParameter>,
+}
+
+impl<'a, T> WithGrease<'a, T> {
+    #[inline]
+    pub fn new(parameters: &'a T, grease: Option<GreaseParameter>) -> Self {
+        Self { parameters, grease }
+    }
+}
+
+impl<T: EncoderValue> EncoderValue for WithGrease<'_, T> {
+    #[inline]
+    fn encode<E: Encoder>(&self, buffer: &mut E) {
+        buffer.encode(self.parameters);
+        buffer.encode(&self.grease);
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TransportParameters;
+
+/// Trait which enables an application to inspect and modify the transport parameters that are
+/// sent to the peer
+///
+/// This is the transport parameter analog of the packet
+/// [`Interceptor`](crate::packet::interceptor::Interceptor) and is intended for testing how
+/// peers react to unusual parameter values.
+pub trait Interceptor: 'static + Send {
+    /// Called with the local transport parameters before they are encoded and sent to the peer
+    ///
+    /// Clients are called with `ClientTransportParameters` and servers with
+    /// `ServerTransportParameters`. The modified values are sent as-is and only change what
+    /// is advertised to the peer, not the limits enforced by the local endpoint.
+    #[inline(always)]
+    fn on_local_parameters<
+        OriginalDestinationConnectionId,
+        StatelessResetToken,
+        PreferredAddress,
+        RetrySourceConnectionId,
+    >(
+        &mut self,
+        parameters: &mut TransportParameters<
+            OriginalDestinationConnectionId,
+            StatelessResetToken,
+            PreferredAddress,
+            RetrySourceConnectionId,
+        >,
+    ) {
+        let _ = parameters;
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Disabled(());
+
+impl Interceptor for Disabled {}
+
+impl<A, B> Interceptor for (A, B)
+where
+    A: Interceptor,
+    B: Interceptor,
+{
+    #[inline(always)]
+    fn on_local_parameters<
+        OriginalDestinationConnectionId,
+        StatelessResetToken,
+        PreferredAddress,
+        RetrySourceConnectionId,
+    >(
+        &mut self,
+        parameters: &mut TransportParameters<
+            OriginalDestinationConnectionId,
+            StatelessResetToken,
+            PreferredAddress,
+            RetrySourceConnectionId,
+        >,
+    ) {
+        self.0.on_local_parameters(parameters);
+        self.1.on_local_parameters(parameters);
+    }
+}
@@ -19,6 +19,8 @@ use s2n_codec::{
     DecoderBufferResult, DecoderError, DecoderValue, DecoderValueMut, Encoder, EncoderValue,
 };
 
+pub mod interceptor;
+
 #[cfg(test)]
 mod tests;
 
@@ -295,7 +297,7 @@ macro_rules! transport_parameter {
         impl TransportParameter for $name {
             type CodecValue = $encodable_type;
 
-            const ID: TransportParameterId = TransportParameterId::from_u8($tag);
+            const ID: TransportParameterId = TransportParameterId::from_u32($tag);
 
             fn from_codec_value(value: Self::CodecValue) -> Self {
                 Self(value)
@@ -844,8 +846,45 @@ impl MaxAckDelay {
 impl TransportParameterValidator for MaxAckDelay {
     fn validate(self) -> Result<Self, DecoderError> {
         decoder_invariant!(
-            *self.0 <= 2u64.pow(14),
-            "max_ack_delay cannot be greater than 2^14"
+            *self.0 < 2u64.pow(14),
+            "max_ack_delay must be less than 2^14"
+        );
+        Ok(self)
+    }
+}
+
+// min_ack_delay (0xff04de1b): The minimum amount of time in microseconds that the
+//    endpoint sending this value is willing to delay an acknowledgment. Advertising
+//    this parameter indicates support for receiving ACK_FREQUENCY frames.
+//
+// See <https://datatracker.ietf.org/doc/draft-ietf-quic-ack-frequency/>
+
+transport_parameter!(MinAckDelay(VarInt), 0xff04de1b);
+optional_transport_parameter!(MinAckDelay);
+
+impl MinAckDelay {
+    /// The minimum delay supported by the ack manager's timer
+    pub const RECOMMENDED: Self = Self(VarInt::from_u16(1_000));
+
+    /// Returns the `min_ack_delay` value as a `Duration`
+    pub const fn as_duration(self) -> Duration {
+        Duration::from_micros(self.0.as_u64())
+    }
+
+    /// Returns `true` if the value doesn't exceed the provided `max_ack_delay`
+    ///
+    /// An endpoint treats a `min_ack_delay` that is larger than its `max_ack_delay` as a
+    /// connection error of type TRANSPORT_PARAMETER_ERROR.
+    pub fn is_valid_for(self, max_ack_delay: MaxAckDelay) -> bool {
+        self.as_duration() <= max_ack_delay.as_duration()
+    }
+}
+
+impl TransportParameterValidator for MinAckDelay {
+    fn validate(self) -> Result<Self, DecoderError> {
+        decoder_invariant!(
+            *self.0 < 2u64.pow(24),
+            "min_ack_delay must be less than 2^24"
         );
         Ok(self)
     }
@@ -1189,6 +1228,9 @@ impl<
 mod disabled_parameter;
 pub use disabled_parameter::DisabledParameter;
 
+mod grease;
+pub use grease::{GreaseParameter, WithGrease};
+
 /// Specific TransportParameters sent by the client endpoint
 pub type ClientTransportParameters = TransportParameters<
     DisabledParameter<OriginalDestinationConnectionId>,
@@ -1412,6 +1454,7 @@ impl_transport_parameters!(
         max_datagram_frame_size: MaxDatagramFrameSize,
         ack_delay_exponent: AckDelayExponent,
         max_ack_delay: MaxAckDelay,
+        min_ack_delay: Option<MinAckDelay>,
         migration_support: MigrationSupport,
         active_connection_id_limit: ActiveConnectionIdLimit,
         original_destination_connection_id: OriginalDestinationConnectionId,
@@ -1466,5 +1509,6 @@ impl<
         load!(ack_delay_exponent, ack_delay_exponent);
         load!(max_active_connection_ids, active_connection_id_limit);
         load!(max_datagram_frame_size, max_datagram_frame_size);
+        self.min_ack_delay = limits.min_ack_delay();
     }
 }
@@ -68,6 +68,7 @@ fn server_transport_parameters() -> ServerTransportParameters {
         max_datagram_frame_size: MaxDatagramFrameSize::new(0u16).unwrap(),
         ack_delay_exponent: 2u8.try_into().unwrap(),
         max_ack_delay: integer_value.try_into().unwrap(),
+        min_ack_delay: None,
         migration_support: MigrationSupport::Disabled,
         active_connection_id_limit: integer_value.try_into().unwrap(),
         original_destination_connection_id: Some([1, 2, 3, 4, 5, 6, 7, 8][..].try_into().unwrap()),
@@ -110,6 +111,7 @@ fn client_transport_parameters() -> ClientTransportParameters {
         max_datagram_frame_size: MaxDatagramFrameSize::new(0u16).unwrap(),
         ack_delay_exponent: 2u8.try_into().unwrap(),
         max_ack_delay: integer_value.try_into().unwrap(),
+        min_ack_delay: None,
         migration_support: MigrationSupport::Disabled,
         active_connection_id_limit: integer_value.try_into().unwrap(),
         original_destination_connection_id: Default::default(),
@@ -180,6 +182,36 @@ fn ignore_unknown_parameter() {
     assert_eq!(0, remaining.len());
 }
 
+#[test]
+fn grease_parameter_test() {
+    use s2n_codec::EncoderBuffer;
+
+    let value = client_transport_parameters();
+
+    for seed in 0..=u8::MAX {
+        let mut random = crate::random::testing::Generator(seed);
+        let grease = GreaseParameter::new(&mut random);
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.1
+        //= type=test
+        //# Transport parameters with an identifier of the form "31 * N + 27" for
+        //# integer values of N are reserved to exercise the requirement that
+        //# unknown transport parameters be ignored.
+        assert_eq!(grease.id().as_u64() % 31, 27);
+
+        let mut buffer = vec![0; 32 * 1024];
+        let mut encoder = EncoderBuffer::new(&mut buffer);
+        encoder.encode(&WithGrease::new(&value, Some(grease)));
+
+        let (encoded, _) = encoder.split_off();
+        let decoder = DecoderBuffer::new(encoded);
+        let (decoded_params, remaining) =
+            ClientTransportParameters::decode(decoder).expect("Decoding succeeds");
+        assert_eq!(value, decoded_params);
+        assert_eq!(0, remaining.len());
+    }
+}
+
 #[test]
 fn compute_data_window_test() {
     assert_eq!(
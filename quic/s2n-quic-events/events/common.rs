@@ -19,7 +19,10 @@ struct EndpointMeta {
     timestamp: crate::event::Timestamp,
 }
 
-struct ConnectionInfo {}
+struct ConnectionInfo<'a> {
+    /// The destination connection ID of the first Initial packet sent by the client
+    original_destination_connection_id: ConnectionId<'a>,
+}
 
 // https://tools.ietf.org/id/draft-marx-qlog-event-definitions-quic-h3-02#5.3.3
 struct TransportParameters<'a> {
@@ -366,6 +369,13 @@ enum Frame {
     Datagram {
         len: u16,
     },
+    AckFrequency {
+        sequence_number: u64,
+        ack_eliciting_threshold: u64,
+        request_max_ack_delay: u64,
+        reordering_threshold: u64,
+    },
+    ImmediateAck,
 }
 
 impl IntoEvent<builder::Frame> for &crate::frame::Padding {
@@ -566,6 +576,25 @@ where
     }
 }
 
+impl IntoEvent<builder::Frame> for &crate::frame::ImmediateAck {
+    #[inline]
+    fn into_event(self) -> builder::Frame {
+        builder::Frame::ImmediateAck {}
+    }
+}
+
+impl IntoEvent<builder::Frame> for &crate::frame::AckFrequency {
+    #[inline]
+    fn into_event(self) -> builder::Frame {
+        builder::Frame::AckFrequency {
+            sequence_number: self.sequence_number.as_u64(),
+            ack_eliciting_threshold: self.ack_eliciting_threshold.as_u64(),
+            request_max_ack_delay: self.request_max_ack_delay.as_u64(),
+            reordering_threshold: self.reordering_threshold.as_u64(),
+        }
+    }
+}
+
 enum StreamType {
     Bidirectional,
     Unidirectional,
@@ -711,6 +740,15 @@ enum DatagramDropReason {
     InsufficientConnectionIds,
 }
 
+enum DatagramFrameDropReason {
+    /// The datagram was evicted from a full send queue to make room for a newer datagram.
+    QueueFull,
+    /// The datagram did not fit in the remaining space of the packet.
+    ExceedsPacketCapacity,
+    /// The datagram was larger than the peer's `max_datagram_frame_size`.
+    ExceedsPeerTransportLimits,
+}
+
 enum KeySpace {
     Initial {},
     Handshake {},
@@ -756,6 +794,10 @@ enum PacketDropReason<'a> {
     },
     /// The received Initial packet was not transported in a datagram of at least 1200 bytes
     UndersizedInitialPacket { path: Path<'a> },
+    /// The connection attempt was dropped since the endpoint exceeded its accept rate limit.
+    RateLimited { path: Path<'a> },
+    /// The connection attempt was dropped since the endpoint limits denied the source address.
+    AddressFiltered { path: Path<'a> },
 }
 
 #[deprecated(note = "use on_rx_ack_range_dropped event instead")]
@@ -829,6 +871,16 @@ enum HandshakeStatus {
     HandshakeDoneLost,
 }
 
+/// The phases of the handshake reported by the `HandshakeProgress` event
+enum HandshakePhase {
+    /// The first Initial packet was sent
+    InitialSent,
+    /// The first Handshake packet was received from the peer
+    HandshakeReceived,
+    /// The handshake has been confirmed
+    HandshakeConfirmed,
+}
+
 /// The source that caused a congestion event
 enum CongestionSource {
     /// Explicit Congestion Notification
@@ -878,6 +930,35 @@ enum SlowStartExitCause {
     Other,
 }
 
+/// The reason the congestion window was updated
+enum CongestionWindowUpdatedCause {
+    /// The congestion window grew while in Slow Start
+    SlowStart,
+    /// The congestion window grew at a reduced rate while in HyStart++ Conservative Slow Start
+    ///
+    /// Conservative Slow Start is entered after the round trip time increased during
+    /// Slow Start, and precedes an exit to Congestion Avoidance if the increase persists.
+    ConservativeSlowStart,
+    /// The congestion window was adjusted while in Congestion Avoidance
+    ///
+    /// With the BBR congestion controller, this cause is used for any adjustment
+    /// made after exiting the Startup state.
+    CongestionAvoidance,
+    /// The congestion window was reduced because a packet was determined lost
+    PacketLoss,
+    /// The congestion window was reset to the minimum window due to persistent congestion
+    PersistentCongestion,
+    /// An Explicit Congestion Notification: Congestion Experienced marking was received
+    Ecn,
+    /// The congestion window was scaled to account for an updated MTU
+    MtuUpdate,
+    /// The congestion window was updated due to a reason other than those above
+    ///
+    /// With the BBR congestion controller, this cause is used when the congestion window
+    /// is restored after restarting from idle.
+    Other,
+}
+
 /// The reason the MTU was updated
 enum MtuUpdatedCause {
     /// The MTU was initialized with the default value
@@ -888,6 +969,14 @@ enum MtuUpdatedCause {
     Blackhole,
 }
 
+/// The flow control limit that blocked the local endpoint from sending data
+enum FlowControlScope {
+    /// The connection limit advertised by the peer in MAX_DATA frames
+    Connection,
+    /// The stream limit advertised by the peer in MAX_STREAM_DATA frames
+    Stream { id: u64 },
+}
+
 /// A bandwidth delivery rate estimate with associated metadata
 struct RateSample {
     /// The length of the sampling interval
@@ -104,6 +104,16 @@ struct Congestion<'a> {
     source: CongestionSource,
 }
 
+#[event("recovery:persistent_congestion")]
+/// Persistent congestion was declared and the congestion window was collapsed
+struct PersistentCongestion<'a> {
+    path: Path<'a>,
+    /// The length of the period in which all ack-eliciting packets were lost
+    duration: Duration,
+    /// The congestion window after persistent congestion was declared
+    congestion_window: u32,
+}
+
 #[event("recovery:ack_processed")]
 #[deprecated(note = "use on_rx_ack_range_dropped event instead")]
 /// Events related to ACK processing
@@ -176,8 +186,25 @@ struct ConnectionStarted<'a> {
 #[event("connectivity:connection_closed")]
 //= https://tools.ietf.org/id/draft-marx-qlog-event-definitions-quic-h3-02#5.1.3
 /// Connection closed
+///
+/// This is emitted for both graceful and abrupt closes and summarizes the lifetime of the
+/// connection.
 struct ConnectionClosed {
     error: crate::connection::Error,
+    /// The amount of time since the connection was created
+    duration: Duration,
+    /// The statistics of the connection at the time it was closed
+    statistics: crate::connection::Statistics,
+}
+
+#[event("connectivity:graceful_close")]
+/// A graceful close finished flushing the connection or gave up after its timeout
+///
+/// This is emitted before `ConnectionClosed` so subscribers can tell graceful closes
+/// apart from abrupt ones.
+struct GracefulClose {
+    /// Set if the timeout elapsed before all of the outstanding stream data was flushed
+    timed_out: bool,
 }
 
 #[event("transport:duplicate_packet")]
@@ -223,6 +250,16 @@ struct DatagramDropped {
     reason: DatagramDropReason,
 }
 
+#[event("transport:datagram_frame_dropped")]
+/// Application datagrams were dropped by the connection before they were sent
+struct DatagramFrameDropped {
+    /// The number of datagrams that were dropped
+    count: usize,
+    /// The total number of bytes in the dropped datagrams
+    len: usize,
+    reason: DatagramFrameDropReason,
+}
+
 #[event("connectivity:connection_id_updated")]
 //= https://tools.ietf.org/id/draft-marx-qlog-event-definitions-quic-h3-02#5.1.4
 /// ConnectionId updated
@@ -234,6 +271,30 @@ struct ConnectionIdUpdated<'a> {
     current: ConnectionId<'a>,
 }
 
+#[event("connectivity:connection_id_issued")]
+/// A new connection ID was issued to the peer
+struct ConnectionIdIssued<'a> {
+    sequence_number: u64,
+    connection_id: ConnectionId<'a>,
+}
+
+#[event("connectivity:connection_id_retired")]
+/// The peer retired a connection ID that was issued to it
+struct ConnectionIdRetired<'a> {
+    sequence_number: u64,
+    connection_id: ConnectionId<'a>,
+}
+
+#[event("transport:flow_control_blocked")]
+/// The local endpoint has data to send but is blocked by the peer's flow control limits
+///
+/// This is emitted each time a DATA_BLOCKED or STREAM_DATA_BLOCKED frame is sent to the peer.
+struct FlowControlBlocked {
+    scope: FlowControlScope,
+    /// The flow control limit, in bytes, that blocked the local endpoint
+    window: u64,
+}
+
 #[event("recovery:ecn_state_changed")]
 struct EcnStateChanged<'a> {
     path: Path<'a>,
@@ -245,6 +306,21 @@ struct ConnectionMigrationDenied {
     reason: MigrationDenyReason,
 }
 
+#[event("connectivity:nat_rebinding_detected")]
+/// The peer's address changed without it changing its connection ID, which usually means a
+/// NAT rebinding occurred
+///
+/// This is emitted once validation of the new path finishes. `path` has the same id as
+/// the `MtuUpdated` event with a `NewPath` cause that was emitted when the path was created.
+struct NatRebindingDetected<'a> {
+    /// The path created for the peer's new address
+    path: Path<'a>,
+    /// The address the peer was using before the rebinding
+    previous_remote_addr: SocketAddress<'a>,
+    /// Set if the peer's new address was validated
+    validated: bool,
+}
+
 #[event("connectivity:handshake_status_updated")]
 struct HandshakeStatusUpdated {
     status: HandshakeStatus,
@@ -291,6 +367,21 @@ struct MtuUpdated {
     cause: MtuUpdatedCause,
 }
 
+#[event("connectivity:mtu_probing_complete")]
+/// MTU probing for the path has concluded
+///
+/// The MTU is not expected to change unless a black hole is detected, after which
+/// probing is restarted and this event will be emitted again once it concludes.
+struct MtuProbingComplete {
+    path_id: u64,
+    /// The MTU confirmed for the path
+    mtu: u16,
+    /// The largest MTU that was probed for
+    max_probed_mtu: u16,
+    /// The number of MTU probes that were sent
+    probes_sent: u32,
+}
+
 #[event("recovery:slow_start_exited")]
 /// The slow start congestion controller state has been exited
 struct SlowStartExited {
@@ -299,6 +390,18 @@ struct SlowStartExited {
     congestion_window: u32,
 }
 
+#[event("recovery:congestion_window_updated")]
+/// The congestion window has been updated
+struct CongestionWindowUpdated {
+    path_id: u64,
+    cause: CongestionWindowUpdatedCause,
+    /// The congestion window before the update
+    old_congestion_window: u32,
+    /// The congestion window after the update
+    new_congestion_window: u32,
+    bytes_in_flight: u32,
+}
+
 #[event("recovery:delivery_rate_sampled")]
 /// A new delivery rate sample has been generated
 /// Note: This event is only recorded for congestion controllers that support
@@ -323,3 +426,67 @@ struct BbrStateChanged {
     path_id: u64,
     state: BbrState,
 }
+
+#[event("connectivity:handshake_progress")]
+/// The handshake has reached a new phase
+///
+/// Each phase is reported once and in order.
+struct HandshakeProgress {
+    phase: HandshakePhase,
+    /// The time elapsed since the connection was created
+    elapsed: Duration,
+}
+
+#[event("connectivity:handshake_complete")]
+/// The handshake has been confirmed
+///
+/// This is emitted after the `HandshakeConfirmed` phase is reported by `HandshakeProgress`.
+struct HandshakeComplete {
+    /// The time elapsed between the connection being created and the handshake being confirmed
+    duration: Duration,
+    /// Set if 0-RTT keys were negotiated during the handshake
+    zero_rtt: bool,
+    /// Set if the TLS provider resumed a previous session
+    resumed: bool,
+}
+
+#[event("security:key_updated")]
+/// The 1-RTT keys were updated
+///
+/// This is emitted by both peers once the update is complete, regardless of which side
+/// initiated it.
+struct KeyUpdated {
+    /// The key phase of the updated keys
+    key_phase: u8,
+    /// The number of packets that were sent with the previous keys
+    packets_sent: u64,
+}
+
+#[event("connectivity:path_validation_failed")]
+/// None of the PATH_CHALLENGE frames sent on a path were answered before the validation
+/// timeout expired
+///
+/// If the path was active, the connection falls back to the last validated path.
+struct PathValidationFailed<'a> {
+    /// The path that failed validation
+    path: Path<'a>,
+    /// The number of PATH_CHALLENGE frames sent on the path
+    attempts: u8,
+    /// The path the connection fell back to, if the failed path was active
+    fallback_path: Option<Path<'a>>,
+}
+
+#[event("recovery:ack_frequency_updated")]
+/// The rate at which acknowledgements are sent for received packets has changed
+///
+/// This is emitted when an ACK_FREQUENCY frame from the peer is applied.
+struct AckFrequencyUpdated {
+    /// The sequence number of the ACK_FREQUENCY frame that was applied
+    sequence_number: u64,
+    /// The number of ack-eliciting packets that are received before an acknowledgement is sent
+    ack_eliciting_threshold: u64,
+    /// The maximum amount of time that an acknowledgement is delayed
+    max_ack_delay: Duration,
+    /// The number of packets that can be reordered before an acknowledgement is sent immediately
+    reordering_threshold: u64,
+}
@@ -66,3 +66,17 @@ struct EndpointDatagramDropped {
 struct EndpointConnectionAttemptFailed {
     error: crate::connection::Error,
 }
+
+#[event("transport:retry_sent")]
+#[subject(endpoint)]
+/// A Retry packet was sent to validate the address of a peer attempting a connection
+struct RetrySent<'a> {
+    remote_addr: SocketAddress<'a>,
+}
+
+#[event("transport:packet_dropped")]
+#[subject(endpoint)]
+/// A packet was dropped by the endpoint before it was associated with a connection
+struct EndpointPacketDropped<'a> {
+    reason: PacketDropReason<'a>,
+}
@@ -68,6 +68,22 @@ enum PlatformFeatureConfiguration {
     Ecn { enabled: bool },
     /// Emitted when the maximum transmission unit is configured
     MaxMtu { mtu: u16 },
+    /// Emitted when the socket send buffer is configured
+    SendBufferSize {
+        /// The size of the send buffer granted by the operating system, in bytes
+        ///
+        /// This value may differ from the requested size since the operating system can adjust or
+        /// clamp it.
+        size: usize,
+    },
+    /// Emitted when the socket receive buffer is configured
+    ReceiveBufferSize {
+        /// The size of the receive buffer granted by the operating system, in bytes
+        ///
+        /// This value may differ from the requested size since the operating system can adjust or
+        /// clamp it.
+        size: usize,
+    },
 }
 
 #[event("platform:event_loop_wakeup")]
@@ -18,6 +18,14 @@ struct Output {
     pub connection_publisher_subscriber: TokenStream,
     pub tuple_subscriber: TokenStream,
     pub tracing_subscriber: TokenStream,
+    pub json_subscriber: TokenStream,
+    pub sampled_subscriber: TokenStream,
+    pub box_subscriber: TokenStream,
+    pub vec_subscriber: TokenStream,
+    pub dyn_subscriber: TokenStream,
+    pub dyn_subscriber_impl: TokenStream,
+    pub dyn_box_subscriber: TokenStream,
+    pub sampled_events: usize,
     pub builders: TokenStream,
     pub api: TokenStream,
     pub testing_fields: TokenStream,
@@ -38,6 +46,14 @@ impl ToTokens for Output {
             connection_publisher_subscriber,
             tuple_subscriber,
             tracing_subscriber,
+            json_subscriber,
+            sampled_subscriber,
+            box_subscriber,
+            vec_subscriber,
+            dyn_subscriber,
+            dyn_subscriber_impl,
+            dyn_box_subscriber,
+            sampled_events: _,
             builders,
             api,
             testing_fields,
@@ -106,6 +122,131 @@ impl ToTokens for Output {
                 }
             }
 
+            #[cfg(feature = "event-json")]
+            pub mod json {
+                //! This module contains a subscriber which writes events as newline-delimited JSON
+                use super::api;
+                use core::fmt::{self, Write as _};
+                use std::{io, string::String};
+
+                /// Writes each event as a line of JSON to the provided writer
+                ///
+                /// Each line contains the snake case `type` of the event, the `endpoint` type, the
+                /// `connection_id` for connection-level events and the event `fields`. Integer,
+                /// floating point and boolean fields are written as JSON values and the remaining
+                /// fields as strings holding their `Debug` representation.
+                ///
+                /// The events are written synchronously from the endpoint task so the writer
+                /// must be fast, for example by buffering or forwarding the lines to another
+                /// thread. A slow writer delays the processing of every connection on the
+                /// endpoint. Errors returned by the writer are ignored.
+                #[derive(Debug)]
+                pub struct Subscriber<W> {
+                    writer: W,
+                    line: String,
+                    value: String,
+                }
+
+                impl<W: io::Write> Subscriber<W> {
+                    pub fn new(writer: W) -> Self {
+                        Self {
+                            writer,
+                            line: String::new(),
+                            value: String::new(),
+                        }
+                    }
+
+                    /// Returns the underlying writer
+                    pub fn into_inner(self) -> W {
+                        self.writer
+                    }
+
+                    fn write(
+                        &mut self,
+                        endpoint_type: &api::EndpointType,
+                        connection_id: Option<u64>,
+                        name: &str,
+                        fields: &[(&str, Value)],
+                    ) {
+                        let endpoint_type = match endpoint_type {
+                            api::EndpointType::Client {} => "client",
+                            api::EndpointType::Server {} => "server",
+                        };
+
+                        let line = &mut self.line;
+                        line.clear();
+                        let _ = write!(line, "{{\"type\":\"{name}\",\"endpoint\":\"{endpoint_type}\"");
+                        if let Some(connection_id) = connection_id {
+                            let _ = write!(line, ",\"connection_id\":{connection_id}");
+                        }
+                        line.push_str(",\"fields\":{");
+                        for (idx, (key, value)) in fields.iter().enumerate() {
+                            if idx > 0 {
+                                line.push(',');
+                            }
+                            let _ = write!(line, "\"{key}\":");
+                            match value {
+                                Value::Number(value) => {
+                                    let _ = write!(line, "{value}");
+                                }
+                                // JSON has no representation for NaN or infinity
+                                Value::Float(value) if !value.is_finite() => line.push_str("null"),
+                                Value::Float(value) => {
+                                    let _ = write!(line, "{value}");
+                                }
+                                Value::Bool(value) => {
+                                    let _ = write!(line, "{value}");
+                                }
+                                Value::Debug(value) => {
+                                    self.value.clear();
+                                    let _ = write!(self.value, "{value:?}");
+                                    line.push('"');
+                                    escape(line, &self.value);
+                                    line.push('"');
+                                }
+                            }
+                        }
+                        line.push_str("}}\n");
+
+                        let _ = self.writer.write_all(line.as_bytes());
+                    }
+                }
+
+                /// The value of an event field
+                enum Value<'a> {
+                    Number(&'a dyn fmt::Display),
+                    Float(f64),
+                    Bool(bool),
+                    /// Written as a string holding the `Debug` representation of the value
+                    Debug(&'a dyn fmt::Debug),
+                }
+
+                /// Escapes a string to be included in a JSON string
+                fn escape(output: &mut String, value: &str) {
+                    for c in value.chars() {
+                        match c {
+                            '"' => output.push_str("\\\""),
+                            '\\' => output.push_str("\\\\"),
+                            '\n' => output.push_str("\\n"),
+                            '\r' => output.push_str("\\r"),
+                            '\t' => output.push_str("\\t"),
+                            c if c.is_control() => {
+                                let _ = write!(output, "\\u{:04x}", c as u32);
+                            }
+                            c => output.push(c),
+                        }
+                    }
+                }
+
+                impl<W: 'static + io::Write + Send> super::Subscriber for Subscriber<W> {
+                    type ConnectionContext = ();
+
+                    fn create_connection_context(&mut self, _meta: &api::ConnectionMeta, _info: &api::ConnectionInfo) -> Self::ConnectionContext {}
+
+                    #json_subscriber
+                }
+            }
+
             pub mod builder {
                 use super::*;
 
@@ -395,6 +536,299 @@ impl ToTokens for Output {
                     }
                 }
 
+                /// Subscriber is implemented for boxed subscribers to allow storing them in a
+                /// `Vec`.
+                #[cfg(feature = "alloc")]
+                impl<S> Subscriber for alloc::boxed::Box<S>
+                    where
+                        S: Subscriber,
+                {
+                    type ConnectionContext = S::ConnectionContext;
+
+                    #[inline]
+                    fn create_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> Self::ConnectionContext {
+                        self.as_mut().create_connection_context(meta, info)
+                    }
+
+                    #[inline]
+                    fn supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        self.as_mut().supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #[inline]
+                    fn on_supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        self.as_mut().on_supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #box_subscriber
+
+                    #[inline]
+                    fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+                        self.as_mut().on_event(meta, event);
+                    }
+
+                    #[inline]
+                    fn on_connection_event<E: Event>(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &E) {
+                        self.as_mut().on_connection_event(context, meta, event);
+                    }
+
+                    #[inline]
+                    fn query(context: &Self::ConnectionContext, query: &mut dyn query::Query) -> query::ControlFlow {
+                        S::query(context, query)
+                    }
+
+                    #[inline]
+                    fn query_mut(context: &mut Self::ConnectionContext, query: &mut dyn query::QueryMut) -> query::ControlFlow {
+                        S::query_mut(context, query)
+                    }
+                }
+
+                /// Subscriber is implemented for a `Vec` to compose a list of subscribers which is
+                /// only known at runtime.
+                ///
+                /// Each event is passed to the subscribers in the order of the list. Subscribers
+                /// added to the list after a connection was created will not receive its events.
+                #[cfg(feature = "alloc")]
+                impl<S> Subscriber for alloc::vec::Vec<S>
+                    where
+                        S: Subscriber,
+                {
+                    type ConnectionContext = alloc::vec::Vec<S::ConnectionContext>;
+
+                    #[inline]
+                    fn create_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> Self::ConnectionContext {
+                        self.iter_mut().map(|subscriber| subscriber.create_connection_context(meta, info)).collect()
+                    }
+
+                    #[inline]
+                    fn supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        self.iter_mut()
+                            .zip(conn_context.iter_mut())
+                            .filter_map(|(subscriber, conn_context)| subscriber.supervisor_timeout(conn_context, meta, context))
+                            .min()
+                    }
+
+                    #[inline]
+                    fn on_supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        let mut outcome = supervisor::Outcome::Continue;
+                        for (subscriber, conn_context) in self.iter_mut().zip(conn_context.iter_mut()) {
+                            outcome = match (outcome, subscriber.on_supervisor_timeout(conn_context, meta, context)) {
+                                (supervisor::Outcome::ImmediateClose { reason }, _) | (_, supervisor::Outcome::ImmediateClose { reason }) => supervisor::Outcome::ImmediateClose { reason },
+                                (supervisor::Outcome::Close { error_code }, _) | (_, supervisor::Outcome::Close { error_code }) => supervisor::Outcome::Close { error_code },
+                                _ => supervisor::Outcome::Continue,
+                            };
+                        }
+                        outcome
+                    }
+
+                    #vec_subscriber
+
+                    #[inline]
+                    fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+                        for subscriber in self.iter_mut() {
+                            subscriber.on_event(meta, event);
+                        }
+                    }
+
+                    #[inline]
+                    fn on_connection_event<E: Event>(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &E) {
+                        for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                            subscriber.on_connection_event(context, meta, event);
+                        }
+                    }
+
+                    #[inline]
+                    fn query(context: &Self::ConnectionContext, query: &mut dyn query::Query) -> query::ControlFlow {
+                        let mut flow = query.execute(context);
+                        for context in context.iter() {
+                            flow = flow.and_then(|| S::query(context, query));
+                        }
+                        flow
+                    }
+
+                    #[inline]
+                    fn query_mut(context: &mut Self::ConnectionContext, query: &mut dyn query::QueryMut) -> query::ControlFlow {
+                        let mut flow = query.execute_mut(context);
+                        for context in context.iter_mut() {
+                            flow = flow.and_then(|| S::query_mut(context, query));
+                        }
+                        flow
+                    }
+                }
+
+                /// An object-safe version of [`Subscriber`] which allows subscribers of different
+                /// types to be stored in a list only known at runtime, e.g.
+                /// `Vec<Box<dyn DynSubscriber>>`.
+                ///
+                /// `DynSubscriber` is implemented for every [`Subscriber`] and
+                /// `Box<dyn DynSubscriber>` implements [`Subscriber`] so it can be passed anywhere
+                /// a subscriber is expected.
+                #[cfg(feature = "alloc")]
+                pub trait DynSubscriber: 'static + Send {
+                    /// Creates a type-erased context to be passed to all connection events
+                    fn create_dyn_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> DynConnectionContext;
+
+                    /// The period at which `dyn_on_supervisor_timeout` is called
+                    fn dyn_supervisor_timeout(&mut self, conn_context: &mut DynConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration>;
+
+                    /// Called for each `supervisor_timeout` to determine any action to take on the connection
+                    fn dyn_on_supervisor_timeout(&mut self, conn_context: &mut DynConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome;
+
+                    #dyn_subscriber
+                }
+
+                /// The connection context of a [`DynSubscriber`]
+                ///
+                /// Queries are forwarded to the context of the subscriber which created it.
+                #[cfg(feature = "alloc")]
+                pub struct DynConnectionContext {
+                    context: alloc::boxed::Box<dyn core::any::Any + Send>,
+                    query: fn(&dyn core::any::Any, &mut dyn query::Query) -> query::ControlFlow,
+                    query_mut: fn(&mut dyn core::any::Any, &mut dyn query::QueryMut) -> query::ControlFlow,
+                }
+
+                #[cfg(feature = "alloc")]
+                impl DynConnectionContext {
+                    #[inline]
+                    fn new<S: Subscriber>(context: S::ConnectionContext) -> Self {
+                        Self {
+                            context: alloc::boxed::Box::new(context),
+                            query: |context, query| match context.downcast_ref::<S::ConnectionContext>() {
+                                Some(context) => S::query(context, query),
+                                None => query::ControlFlow::Continue,
+                            },
+                            query_mut: |context, query| match context.downcast_mut::<S::ConnectionContext>() {
+                                Some(context) => S::query_mut(context, query),
+                                None => query::ControlFlow::Continue,
+                            },
+                        }
+                    }
+
+                    #[inline]
+                    fn downcast_mut<C: 'static>(&mut self) -> Option<&mut C> {
+                        self.context.downcast_mut()
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                impl fmt::Debug for DynConnectionContext {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.debug_struct("DynConnectionContext").finish_non_exhaustive()
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                impl<S> DynSubscriber for S
+                    where
+                        S: Subscriber,
+                {
+                    #[inline]
+                    fn create_dyn_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> DynConnectionContext {
+                        DynConnectionContext::new::<S>(self.create_connection_context(meta, info))
+                    }
+
+                    #[inline]
+                    fn dyn_supervisor_timeout(&mut self, conn_context: &mut DynConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        let conn_context = conn_context.downcast_mut::<S::ConnectionContext>()?;
+                        self.supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #[inline]
+                    fn dyn_on_supervisor_timeout(&mut self, conn_context: &mut DynConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        match conn_context.downcast_mut::<S::ConnectionContext>() {
+                            Some(conn_context) => self.on_supervisor_timeout(conn_context, meta, context),
+                            None => supervisor::Outcome::Continue,
+                        }
+                    }
+
+                    #dyn_subscriber_impl
+                }
+
+                /// Subscriber is implemented for boxed [`DynSubscriber`]s to allow storing
+                /// subscribers of different types in a `Vec`.
+                #[cfg(feature = "alloc")]
+                impl Subscriber for alloc::boxed::Box<dyn DynSubscriber> {
+                    type ConnectionContext = DynConnectionContext;
+
+                    #[inline]
+                    fn create_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> Self::ConnectionContext {
+                        self.as_mut().create_dyn_connection_context(meta, info)
+                    }
+
+                    #[inline]
+                    fn supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        self.as_mut().dyn_supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #[inline]
+                    fn on_supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        self.as_mut().dyn_on_supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #dyn_box_subscriber
+
+                    #[inline]
+                    fn query(context: &Self::ConnectionContext, query: &mut dyn query::Query) -> query::ControlFlow {
+                        (context.query)(context.context.as_ref(), query)
+                    }
+
+                    #[inline]
+                    fn query_mut(context: &mut Self::ConnectionContext, query: &mut dyn query::QueryMut) -> query::ControlFlow {
+                        (context.query_mut)(context.context.as_mut(), query)
+                    }
+                }
+
+                /// Subscriber is implemented for [`Sampled`](crate::event::Sampled) to only forward
+                /// a sample of the events to the inner subscriber.
+                #[cfg(feature = "alloc")]
+                impl<S> Subscriber for crate::event::Sampled<S>
+                    where
+                        S: Subscriber,
+                {
+                    type ConnectionContext = S::ConnectionContext;
+
+                    #[inline]
+                    fn create_connection_context(&mut self, meta: &ConnectionMeta, info: &ConnectionInfo) -> Self::ConnectionContext {
+                        self.subscriber.create_connection_context(meta, info)
+                    }
+
+                    #[inline]
+                    fn supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        self.subscriber.supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #[inline]
+                    fn on_supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        self.subscriber.on_supervisor_timeout(conn_context, meta, context)
+                    }
+
+                    #sampled_subscriber
+
+                    #[inline]
+                    fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+                        if self.is_sampled() {
+                            self.subscriber.on_event(meta, event);
+                        }
+                    }
+
+                    #[inline]
+                    fn on_connection_event<E: Event>(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &E) {
+                        if self.is_sampled() {
+                            self.subscriber.on_connection_event(context, meta, event);
+                        }
+                    }
+
+                    #[inline]
+                    fn query(context: &Self::ConnectionContext, query: &mut dyn query::Query) -> query::ControlFlow {
+                        S::query(context, query)
+                    }
+
+                    #[inline]
+                    fn query_mut(context: &mut Self::ConnectionContext, query: &mut dyn query::QueryMut) -> query::ControlFlow {
+                        S::query_mut(context, query)
+                    }
+                }
+
                 pub trait EndpointPublisher {
                     #endpoint_publisher
 
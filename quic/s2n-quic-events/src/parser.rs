@@ -80,6 +80,7 @@ impl Struct {
         let allow_deprecated = &attrs.allow_deprecated;
 
         let destructure_fields: Vec<_> = fields.iter().map(Field::destructure).collect();
+        let json_fields: Vec<_> = fields.iter().map(Field::json).collect();
         let builder_fields = fields.iter().map(Field::builder);
         let builder_field_impls = fields.iter().map(Field::builder_impl);
         let api_fields = fields.iter().map(Field::api);
@@ -142,12 +143,16 @@ impl Struct {
             let snake = ident_str.to_snake_case();
             let function = format!("on_{snake}");
             let counter = Ident::new(&snake, Span::call_site());
+            let dyn_function = Ident::new(&format!("dyn_{function}"), Span::call_site());
             let function = Ident::new(&function, Span::call_site());
 
             let subscriber_doc = format!("Called when the `{ident_str}` event is triggered");
             let publisher_doc =
                 format!("Publishes a `{ident_str}` event to the publisher's subscriber");
 
+            let sampled_index = output.sampled_events;
+            output.sampled_events += 1;
+
             // add a counter for testing structs
             output.testing_fields.extend(quote!(
                 pub #counter: u32,
@@ -195,6 +200,66 @@ impl Struct {
                         }
                     ));
 
+                    output.json_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &api::EndpointMeta, event: &api::#ident) {
+                            let api::#ident { #(#destructure_fields),* } = event;
+                            self.write(&meta.endpoint_type, None, #snake, &[#(#json_fields),*]);
+                        }
+                    ));
+
+                    output.sampled_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &EndpointMeta, event: &#ident) {
+                            if self.sample(#sampled_index, #snake, None) {
+                                self.subscriber.#function(meta, event);
+                            }
+                        }
+                    ));
+
+                    output.box_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &EndpointMeta, event: &#ident) {
+                            self.as_mut().#function(meta, event);
+                        }
+                    ));
+
+                    output.vec_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &EndpointMeta, event: &#ident) {
+                            for subscriber in self.iter_mut() {
+                                subscriber.#function(meta, event);
+                            }
+                        }
+                    ));
+
+                    output.dyn_subscriber.extend(quote!(
+                        #[doc = #subscriber_doc]
+                        #allow_deprecated
+                        fn #dyn_function(&mut self, meta: &EndpointMeta, event: &#ident);
+                    ));
+
+                    output.dyn_subscriber_impl.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #dyn_function(&mut self, meta: &EndpointMeta, event: &#ident) {
+                            self.#function(meta, event);
+                            self.on_event(meta, event);
+                        }
+                    ));
+
+                    output.dyn_box_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &EndpointMeta, event: &#ident) {
+                            self.as_mut().#dyn_function(meta, event);
+                        }
+                    ));
+
                     output.endpoint_publisher.extend(quote!(
                         #[doc = #publisher_doc]
                         fn #function(&mut self, event: builder::#ident);
@@ -259,6 +324,69 @@ impl Struct {
                         }
                     ));
 
+                    output.json_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, _context: &mut Self::ConnectionContext, meta: &api::ConnectionMeta, event: &api::#ident) {
+                            let api::#ident { #(#destructure_fields),* } = event;
+                            self.write(&meta.endpoint_type, Some(meta.id), #snake, &[#(#json_fields),*]);
+                        }
+                    ));
+
+                    output.sampled_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &#ident) {
+                            if self.sample(#sampled_index, #snake, Some(meta.id)) {
+                                self.subscriber.#function(context, meta, event);
+                            }
+                        }
+                    ));
+
+                    output.box_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &#ident) {
+                            self.as_mut().#function(context, meta, event);
+                        }
+                    ));
+
+                    output.vec_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &#ident) {
+                            for (subscriber, context) in self.iter_mut().zip(context.iter_mut()) {
+                                subscriber.#function(context, meta, event);
+                            }
+                        }
+                    ));
+
+                    output.dyn_subscriber.extend(quote!(
+                        #[doc = #subscriber_doc]
+                        #allow_deprecated
+                        fn #dyn_function(&mut self, context: &mut DynConnectionContext, meta: &ConnectionMeta, event: &#ident);
+                    ));
+
+                    output.dyn_subscriber_impl.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #dyn_function(&mut self, context: &mut DynConnectionContext, meta: &ConnectionMeta, event: &#ident) {
+                            if let Some(context) = context.downcast_mut::<S::ConnectionContext>() {
+                                self.#function(context, meta, event);
+                                self.on_connection_event(context, meta, event);
+                            }
+                            self.on_event(meta, event);
+                        }
+                    ));
+
+                    output.dyn_box_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, context: &mut Self::ConnectionContext, meta: &ConnectionMeta, event: &#ident) {
+                            self.as_mut().#dyn_function(context, meta, event);
+                        }
+                    ));
+
                     output.connection_publisher.extend(quote!(
                         #[doc = #publisher_doc]
                         fn #function(&mut self, event: builder::#ident);
@@ -520,6 +648,29 @@ impl Field {
         quote!(#ident)
     }
 
+    /// Returns the name and value of the field for the JSON subscriber
+    fn json(&self) -> TokenStream {
+        let Self { ident, ty, .. } = self;
+        let name = ident.as_ref().expect("event fields are named").to_string();
+
+        let primitive = match ty {
+            syn::Type::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+            _ => None,
+        };
+
+        let value = match primitive.as_deref() {
+            Some(
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+                | "i128" | "isize",
+            ) => quote!(Value::Number(#ident)),
+            Some("f32" | "f64") => quote!(Value::Float(f64::from(*#ident))),
+            Some("bool") => quote!(Value::Bool(*#ident)),
+            _ => quote!(Value::Debug(#ident)),
+        };
+
+        quote!((#name, #value))
+    }
+
     fn builder(&self) -> TokenStream {
         let Self { attrs, ident, .. } = self;
         let attrs = &attrs.extra;
@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bach::time::scheduler;
-use core::task::Poll;
+use core::{task::Poll, time::Duration};
 use s2n_quic_core::{
-    endpoint::Endpoint, inet::SocketAddress, io::event_loop::EventLoop, path::MaxMtu,
+    endpoint::Endpoint, inet::SocketAddress, io::event_loop::EventLoop, path::mtu,
 };
 
 type Error = std::io::Error;
@@ -16,8 +16,9 @@ pub mod network;
 mod socket;
 pub mod time;
 
-pub use model::{Model, TxRecorder};
+pub use model::{Model, PacketType, TxRecorder};
 pub use network::{Network, PathHandle};
+pub use s2n_quic_core::path::ecn::Mode as EcnMode;
 pub use socket::Socket;
 pub use time::now;
 
@@ -26,6 +27,24 @@ pub use bach::task::{self, primary, spawn};
 pub mod rand {
     pub use ::bach::rand::*;
 
+    // The endpoints use a separate generator from the network so seeding them doesn't change
+    // the decisions made by the network model
+    ::bach::scope::define!(endpoint_scope, Scope);
+
+    /// Fills `bytes` with random data for an endpoint, derived from the seed of the current test
+    ///
+    /// Returns `false` if not called from within a test
+    pub fn fill_endpoint_bytes(bytes: &mut [u8]) -> bool {
+        let scope = endpoint_scope::try_borrow_with(|scope| scope.clone());
+
+        if let Some(scope) = scope {
+            scope.enter(|| fill_bytes(bytes));
+            true
+        } else {
+            false
+        }
+    }
+
     #[derive(Clone, Copy, Default)]
     pub struct Havoc;
 
@@ -67,6 +86,7 @@ impl<N: Network> Executor<N> {
             handle: handle.clone(),
             time: scheduler::Scheduler::new(),
             rand: bach::rand::Scope::new(seed),
+            endpoint_rand: bach::rand::Scope::new(!seed),
             buffers: network::Buffers::default(),
             network,
             stalled_iterations: 0,
@@ -112,6 +132,7 @@ struct Env<N> {
     handle: bach::executor::Handle,
     time: scheduler::Scheduler,
     rand: bach::rand::Scope,
+    endpoint_rand: bach::rand::Scope,
     buffers: network::Buffers,
     network: N,
     stalled_iterations: usize,
@@ -119,7 +140,12 @@ struct Env<N> {
 
 impl<N> Env<N> {
     fn enter<F: FnOnce() -> O, O>(&self, f: F) -> O {
-        self.handle.enter(|| self.time.enter(|| self.rand.enter(f)))
+        self.handle.enter(|| {
+            self.time.enter(|| {
+                self.rand
+                    .enter(|| rand::endpoint_scope::with(self.endpoint_rand.clone(), f))
+            })
+        })
     }
 
     fn close<F: FnOnce()>(&mut self, f: F) {
@@ -151,6 +177,7 @@ impl<N: Network> bach::executor::Environment for Env<N> {
             handle,
             time,
             rand,
+            endpoint_rand,
             buffers,
             network,
             ..
@@ -159,9 +186,11 @@ impl<N: Network> bach::executor::Environment for Env<N> {
         handle.enter(|| {
             time.enter(|| {
                 rand.enter(|| {
-                    for task in tasks {
-                        is_ready &= task().is_ready();
-                    }
+                    rand::endpoint_scope::with(endpoint_rand.clone(), || {
+                        for task in tasks {
+                            is_ready &= task().is_ready();
+                        }
+                    });
                     network.execute(buffers);
                 })
             })
@@ -223,7 +252,10 @@ impl Handle {
             handle: self.clone(),
             address: None,
             on_socket: None,
-            max_mtu: MaxMtu::default(),
+            max_mtu: None,
+            min_mtu: None,
+            mtu_config: Default::default(),
+            ecn_mode: None,
             queue_recv_buffer_size: None,
             queue_send_buffer_size: None,
         }
@@ -234,18 +266,66 @@ pub struct Builder {
     handle: Handle,
     address: Option<SocketAddress>,
     on_socket: Option<Box<dyn FnOnce(socket::Socket)>>,
-    max_mtu: MaxMtu,
+    max_mtu: Option<u16>,
+    min_mtu: Option<u16>,
+    mtu_config: mtu::Config,
+    ecn_mode: Option<EcnMode>,
     queue_recv_buffer_size: Option<u32>,
     queue_send_buffer_size: Option<u32>,
 }
 
 impl Builder {
-    pub fn build(self) -> Result<Io> {
+    pub fn build(mut self) -> Result<Io> {
+        fn invalid_input(err: impl core::fmt::Display) -> Error {
+            Error::new(std::io::ErrorKind::InvalidInput, format!("{err}"))
+        }
+
+        if let Some(max_mtu) = self.max_mtu.take() {
+            self.mtu_config.max_mtu = max_mtu.try_into().map_err(invalid_input)?;
+        }
+        if let Some(min_mtu) = self.min_mtu.take() {
+            self.mtu_config.min_mtu = min_mtu.try_into().map_err(invalid_input)?;
+        }
+        self.mtu_config.validate().map_err(invalid_input)?;
         Ok(Io { builder: self })
     }
 
+    /// Sets the largest maximum transmission unit (MTU) that can be sent on a path
+    ///
+    /// The value is validated when the IO provider is built.
     pub fn with_max_mtu(mut self, max_mtu: u16) -> Self {
-        self.max_mtu = max_mtu.try_into().unwrap();
+        self.max_mtu = Some(max_mtu);
+        self
+    }
+
+    /// Sets the smallest maximum transmission unit (MTU) that all paths are assumed to support
+    ///
+    /// The value is validated when the IO provider is built.
+    pub fn with_min_mtu(mut self, min_mtu: u16) -> Self {
+        self.min_mtu = Some(min_mtu);
+        self
+    }
+
+    /// Sets the minimum amount of time between consecutive MTU probes sent on a path
+    pub fn with_mtu_probe_interval(mut self, probe_interval: Duration) -> Self {
+        self.mtu_config.probe_interval = probe_interval;
+        self
+    }
+
+    /// Sets the number of MTU black hole indications tolerated before the MTU of a path is reduced
+    ///
+    /// The value is validated when the IO provider is built.
+    pub fn with_mtu_blackhole_threshold(mut self, count: u8) -> Self {
+        self.mtu_config.black_hole_threshold = count;
+        self
+    }
+
+    /// Sets how Explicit Congestion Notification (ECN) is used
+    ///
+    /// ECN markings are only carried to and from the endpoint when a mode that marks packets
+    /// is set. By default, the network doesn't carry ECN markings for the endpoint.
+    pub fn with_ecn(mut self, mode: EcnMode) -> Self {
+        self.ecn_mode = Some(mode);
         self
     }
 
@@ -290,17 +370,24 @@ impl Io {
             handle: Handle { executor, buffers },
             address,
             on_socket,
-            max_mtu,
+            mtu_config,
+            ecn_mode,
             queue_recv_buffer_size,
             queue_send_buffer_size,
         } = self.builder;
-        endpoint.set_max_mtu(max_mtu);
+        endpoint.set_mtu_config(mtu_config);
+        if let Some(ecn_mode) = ecn_mode {
+            endpoint.set_ecn_mode(ecn_mode);
+        }
+        let ecn_enabled = ecn_mode.map_or(false, EcnMode::is_enabled);
 
         let handle = address.unwrap_or_else(|| buffers.generate_addr());
 
         let (tx, rx, socket) = buffers.register(
             handle,
-            self.builder.max_mtu,
+            E::ENDPOINT_TYPE,
+            mtu_config.max_mtu,
+            ecn_enabled,
             queue_recv_buffer_size,
             queue_send_buffer_size,
         );
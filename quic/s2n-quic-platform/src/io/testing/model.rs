@@ -3,7 +3,12 @@
 
 use super::network::{Buffers, Network, Packet};
 use core::time::Duration;
-use s2n_quic_core::{havoc, path::MaxMtu};
+use s2n_quic_core::{
+    endpoint, havoc,
+    inet::{ExplicitCongestionNotification, SocketAddress},
+    path::MaxMtu,
+    time::Timestamp,
+};
 use std::{
     borrow::Cow,
     sync::{
@@ -33,6 +38,45 @@ impl Network for TxRecorder {
     }
 }
 
+/// The type of the first QUIC packet in a datagram
+///
+/// Packet payloads are encrypted so the model can only inspect the unprotected header bits.
+/// Frames, such as ACKs, can be targeted with a packet interceptor on the endpoint instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketType {
+    Initial,
+    ZeroRtt,
+    Handshake,
+    Retry,
+    VersionNegotiation,
+    OneRtt,
+}
+
+impl PacketType {
+    const COUNT: usize = 6;
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        let tag = *payload.first()?;
+
+        // short header packets have the header form bit unset
+        if tag & 0x80 == 0 {
+            return Some(Self::OneRtt);
+        }
+
+        // version negotiation packets have a version of 0
+        if payload.get(1..5)? == [0; 4] {
+            return Some(Self::VersionNegotiation);
+        }
+
+        Some(match (tag >> 4) & 0x03 {
+            0x0 => Self::Initial,
+            0x1 => Self::ZeroRtt,
+            0x2 => Self::Handshake,
+            _ => Self::Retry,
+        })
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct Model(Arc<State>);
 
@@ -51,6 +95,23 @@ impl Model {
         self
     }
 
+    pub fn packet_jitter(&self) -> Duration {
+        Duration::from_micros(self.0.packet_jitter.load(Ordering::SeqCst))
+    }
+
+    /// The maximum amount of random delay added to each packet
+    ///
+    /// Each packet is delayed by a uniformly distributed amount in `0..value`, in addition to
+    /// the configured delay. Packets are never delivered before the packets transmitted ahead of
+    /// them in the same direction, so this jitter does not reorder packets. See
+    /// [`Self::set_network_jitter`] and [`Self::set_reorder`] for jitter which reorders packets.
+    pub fn set_packet_jitter(&self, value: Duration) -> &Self {
+        self.0
+            .packet_jitter
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        self
+    }
+
     pub fn network_jitter(&self) -> Duration {
         Duration::from_micros(self.0.network_jitter.load(Ordering::SeqCst))
     }
@@ -65,13 +126,45 @@ impl Model {
         self
     }
 
+    /// Returns the one-way delay configured with [`Self::set_delay`]
+    ///
+    /// If the directions were configured separately, the average of the two is returned, which
+    /// is half of the round trip delay.
     pub fn delay(&self) -> Duration {
-        Duration::from_micros(self.0.delay.load(Ordering::SeqCst))
+        (self.delay_tx() + self.delay_rx()) / 2
+    }
+
+    pub fn delay_tx(&self) -> Duration {
+        Duration::from_micros(self.0.delay_tx.load(Ordering::SeqCst))
+    }
+
+    pub fn delay_rx(&self) -> Duration {
+        Duration::from_micros(self.0.delay_rx.load(Ordering::SeqCst))
     }
 
     /// The amount of time a packet is delayed before the receiver is able to read it
+    ///
+    /// This applies to packets in both directions. See [`Self::set_delay_tx`] and
+    /// [`Self::set_delay_rx`] to configure each direction separately.
     pub fn set_delay(&self, value: Duration) -> &Self {
-        self.0.delay.store(value.as_micros() as _, Ordering::SeqCst);
+        self.set_delay_tx(value).set_delay_rx(value)
+    }
+
+    /// The amount of time a packet transmitted by a server is delayed before the client
+    /// is able to read it
+    pub fn set_delay_tx(&self, value: Duration) -> &Self {
+        self.0
+            .delay_tx
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        self
+    }
+
+    /// The amount of time a packet transmitted by a client is delayed before the server
+    /// is able to read it
+    pub fn set_delay_rx(&self, value: Duration) -> &Self {
+        self.0
+            .delay_rx
+            .store(value.as_micros() as _, Ordering::SeqCst);
         self
     }
 
@@ -87,6 +180,32 @@ impl Model {
         self
     }
 
+    pub fn bandwidth(&self) -> u64 {
+        self.0.bandwidth.load(Ordering::SeqCst)
+    }
+
+    /// Sets the number of bytes per second that can be transmitted in each direction
+    ///
+    /// Packets exceeding the rate are queued behind the previously transmitted packets, which
+    /// increases their delay. See [`Self::set_buffer_bytes`] to limit the size of the queue.
+    pub fn set_bandwidth(&self, bytes_per_second: u64) -> &Self {
+        self.0.bandwidth.store(bytes_per_second, Ordering::SeqCst);
+        self
+    }
+
+    pub fn buffer_bytes(&self) -> u64 {
+        self.0.buffer_bytes.load(Ordering::SeqCst)
+    }
+
+    /// Sets the number of bytes that can be queued in each direction when a bandwidth is set
+    ///
+    /// Packets which would exceed the amount of queued bytes are dropped. By default, the queue
+    /// is unbounded.
+    pub fn set_buffer_bytes(&self, value: u64) -> &Self {
+        self.0.buffer_bytes.store(value, Ordering::SeqCst);
+        self
+    }
+
     fn retransmit_rate(&self) -> u64 {
         self.0.retransmit_rate.load(Ordering::SeqCst)
     }
@@ -115,17 +234,127 @@ impl Model {
         self
     }
 
-    fn drop_rate(&self) -> u64 {
-        self.0.drop_rate.load(Ordering::SeqCst)
+    fn ecn_ce_rate(&self) -> u64 {
+        self.0.ecn_ce_rate.load(Ordering::SeqCst)
+    }
+
+    /// The odds an ECN-capable packet will be marked with Congestion Experienced (CE).
+    ///
+    /// The odds are applied to each packet as described in [`Self::set_drop_rate`]. Packets that
+    /// were not sent with an ECT codepoint are never marked.
+    pub fn set_ecn_ce_rate(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.ecn_ce_rate.store(value, Ordering::SeqCst);
+        self
+    }
+
+    fn drop_rate_tx(&self) -> u64 {
+        self.0.drop_rate_tx.load(Ordering::SeqCst)
+    }
+
+    fn drop_rate_rx(&self) -> u64 {
+        self.0.drop_rate_rx.load(Ordering::SeqCst)
     }
 
     /// The odds a packet will be dropped.
     ///
     /// Each packet will make an independent decision with odds of `0.0..1.0`, with `0.0` having no
     /// chance and `1.0` occurring with each packet.
+    ///
+    /// This applies to packets in both directions. See [`Self::set_drop_rate_tx`] and
+    /// [`Self::set_drop_rate_rx`] to configure each direction separately.
     pub fn set_drop_rate(&self, value: f64) -> &Self {
+        self.set_drop_rate_tx(value).set_drop_rate_rx(value)
+    }
+
+    /// The odds a packet transmitted by a server will be dropped.
+    ///
+    /// See [`Self::set_drop_rate`] for how the odds are applied.
+    pub fn set_drop_rate_tx(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.drop_rate_tx.store(value, Ordering::SeqCst);
+        self
+    }
+
+    /// The odds a packet transmitted by a client will be dropped.
+    ///
+    /// See [`Self::set_drop_rate`] for how the odds are applied.
+    pub fn set_drop_rate_rx(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.drop_rate_rx.store(value, Ordering::SeqCst);
+        self
+    }
+
+    /// The odds a datagram starting with the given packet type will be dropped.
+    ///
+    /// The odds are applied to each datagram as described in [`Self::set_drop_rate`], in addition
+    /// to the rate configured there.
+    ///
+    /// This applies to packets in both directions. See [`Self::drop_packet_type_tx`] and
+    /// [`Self::drop_packet_type_rx`] to configure each direction separately.
+    pub fn drop_packet_type(&self, packet_type: PacketType, value: f64) -> &Self {
+        self.drop_packet_type_tx(packet_type, value)
+            .drop_packet_type_rx(packet_type, value)
+    }
+
+    /// The odds a datagram transmitted by a server starting with the given packet type will be
+    /// dropped.
+    pub fn drop_packet_type_tx(&self, packet_type: PacketType, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.drop_packet_type_tx[packet_type as usize].store(value, Ordering::SeqCst);
+        self
+    }
+
+    /// The odds a datagram transmitted by a client starting with the given packet type will be
+    /// dropped.
+    pub fn drop_packet_type_rx(&self, packet_type: PacketType, value: f64) -> &Self {
         let value = rate_to_u64(value);
-        self.0.drop_rate.store(value, Ordering::SeqCst);
+        self.0.drop_packet_type_rx[packet_type as usize].store(value, Ordering::SeqCst);
+        self
+    }
+
+    pub fn nat_port_offset(&self) -> u16 {
+        self.0.nat_port_offset.load(Ordering::SeqCst)
+    }
+
+    /// Rewrites the source port of packets transmitted by clients, as a NAT would
+    ///
+    /// The port of each client packet is increased by `offset` and packets sent to the
+    /// rewritten port are translated back to the client. Changing the offset during a
+    /// connection simulates a NAT rebinding, since the server observes a new source port for
+    /// the client while packets sent to the previous port are dropped.
+    pub fn set_nat_port_offset(&self, offset: u16) -> &Self {
+        self.0.nat_port_offset.store(offset, Ordering::SeqCst);
+        self
+    }
+
+    fn reorder_rate(&self) -> u64 {
+        self.0.reorder_rate.load(Ordering::SeqCst)
+    }
+
+    pub fn max_reorder_displacement(&self) -> u64 {
+        self.0.max_reorder_displacement.load(Ordering::SeqCst)
+    }
+
+    /// The odds a packet will be reordered and the maximum number of packets that can overtake it.
+    ///
+    /// The odds are applied to each packet as described in [`Self::set_drop_rate`]. A reordered
+    /// packet is held back until a random number of packets, between `1` and `max_displacement`,
+    /// have been transmitted to the same destination after it. It is then delivered immediately
+    /// after the last of those packets.
+    ///
+    /// Reordering is bounded by the configured delay: a held packet is never delivered later than
+    /// the time it would have arrived without reordering. If not enough packets are transmitted
+    /// to the destination within the delay, the packet is delivered in its original position.
+    /// Setting a larger delay widens the window in which packets can be overtaken.
+    ///
+    /// All of the decisions are made with the seeded test RNG, so reordering is reproducible.
+    pub fn set_reorder(&self, probability: f64, max_displacement: u64) -> &Self {
+        let rate = rate_to_u64(probability);
+        self.0.reorder_rate.store(rate, Ordering::SeqCst);
+        self.0
+            .max_reorder_displacement
+            .store(max_displacement, Ordering::SeqCst);
         self
     }
 
@@ -139,6 +368,43 @@ impl Model {
         self
     }
 
+    /// The number of UDP payload bytes transmitted by servers
+    ///
+    /// This includes packets that were dropped by the network, but not any retransmissions
+    /// caused by [`Self::set_retransmit_rate`].
+    pub fn bytes_sent(&self) -> u64 {
+        self.0.bytes_sent.load(Ordering::SeqCst)
+    }
+
+    /// The number of UDP payload bytes transmitted by clients and received by servers
+    ///
+    /// This includes packets that were dropped by the network, but not any retransmissions
+    /// caused by [`Self::set_retransmit_rate`].
+    pub fn bytes_received(&self) -> u64 {
+        self.0.bytes_received.load(Ordering::SeqCst)
+    }
+
+    /// Pauses the network
+    ///
+    /// Packets that arrive while the network is paused are held until [`Self::resume`] is
+    /// called, rather than being dropped.
+    pub fn pause(&self) -> &Self {
+        let mut paused = self.0.paused.lock().unwrap();
+        if paused.is_none() {
+            *paused = Some(vec![]);
+        }
+        self
+    }
+
+    /// Resumes the network and delivers all of the packets held while it was paused
+    pub fn resume(&self) -> &Self {
+        let held = self.0.paused.lock().unwrap().take();
+        for (buffers, packet) in held.into_iter().flatten() {
+            deliver(self, &buffers, packet);
+        }
+        self
+    }
+
     /// The number of inflight packets
     fn inflight(&self) -> u64 {
         self.0.current_inflight.load(Ordering::SeqCst)
@@ -188,35 +454,76 @@ fn rate_to_u64(rate: f64) -> u64 {
 }
 
 struct State {
-    delay: AtomicU64,
+    delay_tx: AtomicU64,
+    delay_rx: AtomicU64,
     jitter: AtomicU64,
+    packet_jitter: AtomicU64,
     network_jitter: AtomicU64,
     transmit_rate: AtomicU64,
+    bandwidth: AtomicU64,
+    buffer_bytes: AtomicU64,
+    // the time each direction finishes transmitting the queued packets
+    link_idle_tx: Mutex<Option<Timestamp>>,
+    link_idle_rx: Mutex<Option<Timestamp>>,
+    // the arrival time of the last packet delayed by the packet jitter in each direction
+    jitter_arrival_tx: Mutex<Option<Timestamp>>,
+    jitter_arrival_rx: Mutex<Option<Timestamp>>,
     retransmit_rate: AtomicU64,
     corrupt_rate: AtomicU64,
-    drop_rate: AtomicU64,
+    ecn_ce_rate: AtomicU64,
+    drop_rate_tx: AtomicU64,
+    drop_rate_rx: AtomicU64,
+    drop_packet_type_tx: [AtomicU64; PacketType::COUNT],
+    drop_packet_type_rx: [AtomicU64; PacketType::COUNT],
+    nat_port_offset: AtomicU16,
+    reorder_rate: AtomicU64,
+    max_reorder_displacement: AtomicU64,
+    reorder_queue: Mutex<ReorderQueue>,
+    // the packets held while the network is paused
+    paused: Mutex<Option<Vec<(Buffers, Packet)>>>,
     max_udp_payload: AtomicU16,
     max_inflight: AtomicU64,
     inflight_delay: AtomicU64,
     inflight_delay_threshold: AtomicU64,
     current_inflight: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
-            delay: AtomicU64::new(Duration::from_millis(50).as_micros() as _),
+            delay_tx: AtomicU64::new(Duration::from_millis(50).as_micros() as _),
+            delay_rx: AtomicU64::new(Duration::from_millis(50).as_micros() as _),
             jitter: AtomicU64::new(0),
+            packet_jitter: AtomicU64::new(0),
             network_jitter: AtomicU64::new(0),
             transmit_rate: AtomicU64::new(u64::MAX),
+            bandwidth: AtomicU64::new(u64::MAX),
+            buffer_bytes: AtomicU64::new(u64::MAX),
+            link_idle_tx: Mutex::new(None),
+            link_idle_rx: Mutex::new(None),
+            jitter_arrival_tx: Mutex::new(None),
+            jitter_arrival_rx: Mutex::new(None),
             retransmit_rate: AtomicU64::new(0),
             corrupt_rate: AtomicU64::new(0),
-            drop_rate: AtomicU64::new(0),
+            ecn_ce_rate: AtomicU64::new(0),
+            drop_rate_tx: AtomicU64::new(0),
+            drop_rate_rx: AtomicU64::new(0),
+            drop_packet_type_tx: Default::default(),
+            drop_packet_type_rx: Default::default(),
+            nat_port_offset: AtomicU16::new(0),
+            reorder_rate: AtomicU64::new(0),
+            max_reorder_displacement: AtomicU64::new(0),
+            reorder_queue: Mutex::new(ReorderQueue::default()),
+            paused: Mutex::new(None),
             max_udp_payload: AtomicU16::new(MaxMtu::default().into()),
             max_inflight: AtomicU64::new(u64::MAX),
             inflight_delay: AtomicU64::new(0),
             inflight_delay_threshold: AtomicU64::new(u64::MAX),
             current_inflight: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
         }
     }
 }
@@ -224,18 +531,28 @@ impl Default for State {
 impl Network for Model {
     fn execute(&mut self, buffers: &Buffers) -> usize {
         let jitter = self.jitter();
+        let packet_jitter = self.packet_jitter();
         let network_jitter = self.network_jitter();
         let transmit_rate = self.transmit_rate();
+        let bandwidth = self.bandwidth();
+        let buffer_bytes = self.buffer_bytes();
         let retransmit_rate = self.retransmit_rate();
         let corrupt_rate = self.corrupt_rate();
-        let drop_rate = self.drop_rate();
+        let ecn_ce_rate = self.ecn_ce_rate();
+        let drop_rate_tx = self.drop_rate_tx();
+        let drop_rate_rx = self.drop_rate_rx();
+        let delay_tx = self.delay_tx();
+        let delay_rx = self.delay_rx();
+        let reorder_rate = self.reorder_rate();
+        let max_reorder_displacement = self.max_reorder_displacement();
         let max_udp_payload = self.max_udp_payload() as usize;
+        let nat_port_offset = self.nat_port_offset();
         let inflight_delay = self.inflight_delay();
         let inflight_delay_threshold = self.inflight_delay_threshold();
 
         let now = super::time::now();
-        let mut transmit_time = now + self.delay();
-        let transmit_time = &mut transmit_time;
+        let mut jitter_offset = Duration::ZERO;
+        let jitter_offset = &mut jitter_offset;
 
         #[inline]
         fn gen_rate(rate: u64) -> bool {
@@ -243,7 +560,23 @@ impl Network for Model {
             rate > 0 && super::rand::gen::<u64>() < rate
         }
 
-        let mut transmit = |packet: Cow<Packet>| {
+        let mut transmit = |packet: Cow<Packet>, endpoint_type: endpoint::Type| {
+            // servers transmit in the `tx` direction and clients in the `rx` direction
+            let (delay, drop_rate, link_idle, jitter_arrival) = match endpoint_type {
+                endpoint::Type::Server => (
+                    delay_tx,
+                    drop_rate_tx,
+                    &self.0.link_idle_tx,
+                    &self.0.jitter_arrival_tx,
+                ),
+                endpoint::Type::Client => (
+                    delay_rx,
+                    drop_rate_rx,
+                    &self.0.link_idle_rx,
+                    &self.0.jitter_arrival_rx,
+                ),
+            };
+
             // drop the packet if it's over the current MTU
             if packet.payload.len() > max_udp_payload {
                 return 0;
@@ -259,6 +592,17 @@ impl Network for Model {
                 return 0;
             }
 
+            // drop the packet if enabled for its type
+            if let Some(packet_type) = PacketType::from_payload(&packet.payload) {
+                let drop_packet_type = match endpoint_type {
+                    endpoint::Type::Server => &self.0.drop_packet_type_tx,
+                    endpoint::Type::Client => &self.0.drop_packet_type_rx,
+                };
+                if gen_rate(drop_packet_type[packet_type as usize].load(Ordering::SeqCst)) {
+                    return 0;
+                }
+            }
+
             let mut packet = packet.into_owned();
 
             if !packet.payload.is_empty() && gen_rate(corrupt_rate) {
@@ -276,13 +620,61 @@ impl Network for Model {
                 }
             }
 
+            if packet.ecn.using_ecn() && gen_rate(ecn_ce_rate) {
+                packet.ecn = ExplicitCongestionNotification::Ce;
+            }
+
+            if nat_port_offset != 0 {
+                // translate the client's address to and from the address observed by the server
+                let address = match endpoint_type {
+                    endpoint::Type::Server => &mut packet.path.remote_address.0,
+                    endpoint::Type::Client => &mut packet.path.local_address.0,
+                };
+                let port = match endpoint_type {
+                    endpoint::Type::Server => address.port().wrapping_sub(nat_port_offset),
+                    endpoint::Type::Client => address.port().wrapping_add(nat_port_offset),
+                };
+                address.set_port(port);
+            }
+
             if !jitter.is_zero() {
                 // add a delay for the next packet to be transmitted
-                *transmit_time += gen_jitter(jitter);
+                *jitter_offset += gen_jitter(jitter);
+            }
+
+            // compute the transmit time for this packet
+            let mut transmit_time = now + delay + *jitter_offset;
+
+            if bandwidth != u64::MAX {
+                // queue the packet behind the packets that are still being transmitted
+                let mut link_idle = link_idle.lock().unwrap();
+                let start = link_idle.map_or(now, |idle| idle.max(now));
+
+                // drop the packet if the queue is full
+                let queued = (start - now).as_nanos() * bandwidth as u128
+                    / Duration::from_secs(1).as_nanos();
+                if queued + packet.payload.len() as u128 > buffer_bytes as u128 {
+                    return 0;
+                }
+
+                let serialization = Duration::from_nanos(
+                    packet.payload.len() as u64 * Duration::from_secs(1).as_nanos() as u64
+                        / bandwidth.max(1),
+                );
+                *link_idle = Some(start + serialization);
+                transmit_time += (start + serialization) - now;
             }
 
-            // copy the transmit time for this packet
-            let mut transmit_time = *transmit_time;
+            if !packet_jitter.is_zero() {
+                transmit_time += gen_jitter(packet_jitter);
+
+                // don't deliver the packet before the previous one in the same direction
+                let mut jitter_arrival = jitter_arrival.lock().unwrap();
+                if let Some(prev) = *jitter_arrival {
+                    transmit_time = transmit_time.max(prev);
+                }
+                *jitter_arrival = Some(transmit_time);
+            }
 
             if !network_jitter.is_zero() {
                 transmit_time += gen_jitter(network_jitter);
@@ -299,6 +691,49 @@ impl Network for Model {
             // reverse the addresses so the dst/src are correct for the receiver
             packet.switch();
 
+            let destination = *packet.path.local_address;
+
+            // release any held packets that have now been overtaken by enough packets
+            let released = model
+                .0
+                .reorder_queue
+                .lock()
+                .unwrap()
+                .on_transmit(destination);
+
+            // hold the packet back if it's selected to be reordered
+            let packet =
+                if max_reorder_displacement > 0 && gen_rate(reorder_rate) {
+                    let displacement = super::rand::gen_range(0..max_reorder_displacement) + 1;
+                    let id = model.0.reorder_queue.lock().unwrap().hold(
+                        destination,
+                        displacement,
+                        packet,
+                    );
+
+                    // deliver the packet at its original time if it hasn't been overtaken by then
+                    let buffers = buffers.clone();
+                    let model = model.clone();
+                    super::spawn(async move {
+                        if now != transmit_time {
+                            super::time::delay_until(transmit_time).await;
+                        }
+
+                        if let Some(packet) = model.0.reorder_queue.lock().unwrap().release(id) {
+                            deliver(&model, &buffers, packet);
+                        }
+                    });
+
+                    // nothing left to deliver at the transmit time
+                    if released.is_empty() {
+                        return 1;
+                    }
+
+                    None
+                } else {
+                    Some(packet)
+                };
+
             let buffers = buffers.clone();
 
             // spawn a task that will push the packet onto the receiver queue at the transit time
@@ -309,17 +744,27 @@ impl Network for Model {
                     super::time::delay_until(transmit_time).await;
                 }
 
-                buffers.rx(*packet.path.local_address, |queue| {
-                    model.0.current_inflight.fetch_sub(1, Ordering::SeqCst);
-                    queue.enqueue(packet);
-                });
+                if let Some(packet) = packet {
+                    deliver(&model, &buffers, packet);
+                }
+
+                // the released packets are delivered directly after the packets that overtook them
+                for packet in released {
+                    deliver(&model, &buffers, packet);
+                }
             });
 
             1
         };
 
         let mut transmission_count = 0;
-        buffers.drain_pending_transmissions(|packet| {
+        buffers.drain_pending_transmissions(|packet, endpoint_type| {
+            let bytes = match endpoint_type {
+                endpoint::Type::Server => &self.0.bytes_sent,
+                endpoint::Type::Client => &self.0.bytes_received,
+            };
+            bytes.fetch_add(packet.payload.len() as _, Ordering::SeqCst);
+
             // retransmit the packet until the rate fails or we retransmit 5
             //
             // We limit retransmissions to 5 just so we don't endlessly iterate when the
@@ -327,11 +772,11 @@ impl Network for Model {
             // retransmission coverage without needlessly saturating the network.
             let mut count = 0;
             while count < 5 && gen_rate(retransmit_rate) {
-                transmission_count += transmit(Cow::Borrowed(&packet));
+                transmission_count += transmit(Cow::Borrowed(&packet), endpoint_type);
                 count += 1;
             }
 
-            transmission_count += transmit(Cow::Owned(packet));
+            transmission_count += transmit(Cow::Owned(packet), endpoint_type);
 
             // continue transmitting as long as we are under the rate
             if transmission_count < transmit_rate {
@@ -345,6 +790,79 @@ impl Network for Model {
     }
 }
 
+/// Pushes a packet onto the receiver queue
+fn deliver(model: &Model, buffers: &Buffers, packet: Packet) {
+    // hold the packet until the network is resumed
+    if let Some(held) = model.0.paused.lock().unwrap().as_mut() {
+        held.push((buffers.clone(), packet));
+        return;
+    }
+
+    buffers.rx(*packet.path.local_address, |queue| {
+        model.0.current_inflight.fetch_sub(1, Ordering::SeqCst);
+        queue.enqueue(packet);
+    });
+}
+
+/// Packets that are being held back to be delivered out of order
+#[derive(Default)]
+struct ReorderQueue {
+    next_id: u64,
+    entries: Vec<ReorderEntry>,
+}
+
+struct ReorderEntry {
+    id: u64,
+    destination: SocketAddress,
+    remaining: u64,
+    packet: Packet,
+}
+
+impl ReorderQueue {
+    /// Holds a packet until `displacement` packets have been transmitted to the same destination
+    fn hold(&mut self, destination: SocketAddress, displacement: u64, packet: Packet) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push(ReorderEntry {
+            id,
+            destination,
+            remaining: displacement,
+            packet,
+        });
+        id
+    }
+
+    /// Notifies the queue of a packet transmission and returns any packets that have been overtaken
+    fn on_transmit(&mut self, destination: SocketAddress) -> Vec<Packet> {
+        let mut released = vec![];
+
+        if self.entries.is_empty() {
+            return released;
+        }
+
+        let mut index = 0;
+        while index < self.entries.len() {
+            let entry = &mut self.entries[index];
+            if entry.destination == destination {
+                entry.remaining -= 1;
+                if entry.remaining == 0 {
+                    released.push(self.entries.remove(index).packet);
+                    continue;
+                }
+            }
+            index += 1;
+        }
+
+        released
+    }
+
+    /// Removes the packet with the given id, if it is still being held
+    fn release(&mut self, id: u64) -> Option<Packet> {
+        let index = self.entries.iter().position(|entry| entry.id == id)?;
+        Some(self.entries.remove(index).packet)
+    }
+}
+
 fn gen_jitter(max_jitter: Duration) -> Duration {
     let micros = super::rand::gen_range(0..max_jitter.as_micros() as u64);
     let micros = micros as f64;
@@ -4,6 +4,7 @@
 use crate::{message::Message as _, socket};
 use core::task::{Context, Waker};
 use s2n_quic_core::{
+    endpoint,
     inet::{ExplicitCongestionNotification, SocketAddress},
     io::{rx, tx},
     path::{LocalAddress, MaxMtu, Tuple},
@@ -103,8 +104,8 @@ impl Buffers {
 
             lock.addr_to_host.insert(addr, host);
             let host_to_addr = lock.host_to_addr.get_mut(&host).unwrap();
-            let prev = host_to_addr.pop().unwrap();
-            host_to_addr.push(addr);
+            // the first address is the one the host sends from by default
+            let prev = core::mem::replace(&mut host_to_addr[0], addr);
 
             lock.addr_to_host.remove(&prev);
 
@@ -115,6 +116,34 @@ impl Buffers {
         }
     }
 
+    /// Adds an address to the host, in addition to its current address
+    ///
+    /// Packets sent to the address are delivered to the host. The host sends from the address
+    /// when the local address of the packet's path handle is set to it.
+    pub fn add_address(&self, host: HostId, addr: std::net::SocketAddr) {
+        if let Ok(mut lock) = self.inner.lock() {
+            let addr = addr.into();
+            // can't add an already used address
+            if lock.addr_to_host.contains_key(&addr) {
+                return;
+            }
+
+            lock.addr_to_host.insert(addr, host);
+            lock.host_to_addr.get_mut(&host).unwrap().push(addr);
+
+            lock.tx
+                .get_mut(&host)
+                .unwrap()
+                .additional_addresses
+                .push(addr.into());
+            lock.rx
+                .get_mut(&host)
+                .unwrap()
+                .additional_addresses
+                .push(addr.into());
+        }
+    }
+
     pub fn tx<F: FnOnce(&mut Queue)>(&self, handle: SocketAddress, f: F) {
         if let Ok(mut lock) = self.inner.lock() {
             let lock = &mut *lock;
@@ -189,7 +218,13 @@ impl Buffers {
         }
     }
 
-    pub fn drain_pending_transmissions<F: FnMut(Packet) -> Result<(), ()>>(&self, mut f: F) {
+    /// Drains all of the packets pending transmission
+    ///
+    /// `f` is called with each packet and the type of the endpoint that transmitted it.
+    pub fn drain_pending_transmissions<F: FnMut(Packet, endpoint::Type) -> Result<(), ()>>(
+        &self,
+        mut f: F,
+    ) {
         let mut lock = if let Ok(lock) = self.inner.lock() {
             lock
         } else {
@@ -216,7 +251,7 @@ impl Buffers {
                 // transmit a single packet at a time per queue so they are fairly
                 // transmitted
                 if let Some(packet) = queue.packets.pop_front() {
-                    let result = f(packet);
+                    let result = f(packet, queue.endpoint_type);
                     has_result = true;
 
                     // notify the endpoint that it can send now
@@ -269,7 +304,9 @@ impl Buffers {
     pub fn register(
         &self,
         handle: SocketAddress,
+        endpoint_type: endpoint::Type,
         max_mtu: MaxMtu,
+        ecn_enabled: bool,
         queue_recv_buffer_size: Option<u32>,
         queue_send_buffer_size: Option<u32>,
     ) -> (
@@ -282,7 +319,7 @@ impl Buffers {
         let host = HostId(lock.next_host);
         lock.next_host += 1;
 
-        let queue = Queue::new(handle);
+        let queue = Queue::new(handle, endpoint_type, ecn_enabled);
 
         lock.addr_to_host.insert(handle, host);
         lock.host_to_addr.insert(host, vec![handle]);
@@ -384,11 +421,15 @@ pub struct Queue {
     mtu: u16,
     packets: VecDeque<Packet>,
     local_address: LocalAddress,
+    additional_addresses: Vec<LocalAddress>,
+    endpoint_type: endpoint::Type,
+    /// Set if ECN markings are carried to and from the host
+    ecn_enabled: bool,
     waker: Option<Waker>,
 }
 
 impl Queue {
-    fn new(addr: SocketAddress) -> Self {
+    fn new(addr: SocketAddress, endpoint_type: endpoint::Type, ecn_enabled: bool) -> Self {
         let mtu = MAX_TESTED_MTU;
         let local_address = addr.into();
         Self {
@@ -396,6 +437,9 @@ impl Queue {
             mtu,
             packets: VecDeque::new(),
             local_address,
+            additional_addresses: Vec::new(),
+            endpoint_type,
+            ecn_enabled,
             waker: None,
         }
     }
@@ -433,10 +477,17 @@ impl Queue {
         for msg in msgs.iter().take(remaining_capacity) {
             let mut path = *msg.handle();
 
-            // update the path with the latest address
-            path.local_address = self.local_address;
+            // update the path with the latest address, unless the endpoint selected one of
+            // the host's additional addresses
+            if !self.additional_addresses.contains(&path.local_address) {
+                path.local_address = self.local_address;
+            }
 
-            let ecn = msg.ecn();
+            let ecn = if self.ecn_enabled {
+                msg.ecn()
+            } else {
+                Default::default()
+            };
 
             let msg_payload = msg.payload();
             let payload_len = msg_payload.len().min(self.mtu as _);
@@ -463,7 +514,11 @@ impl Queue {
 
         for (packet, msg) in self.packets.drain(..to_remove).zip(msgs) {
             *msg.handle_mut() = packet.path;
-            *msg.ecn_mut() = packet.ecn;
+            *msg.ecn_mut() = if self.ecn_enabled {
+                packet.ecn
+            } else {
+                Default::default()
+            };
             let payload = msg.payload_mut();
             let to_copy = payload.len().min(packet.payload.len());
             payload[..to_copy].copy_from_slice(&packet.payload[..to_copy]);
@@ -53,6 +53,12 @@ impl Socket {
     pub fn rebind(&self, addr: std::net::SocketAddr) {
         self.0.buffers.rebind(self.0.host, addr);
     }
+
+    /// Adds an address the socket can send from and receive on, in addition to the
+    /// current local address
+    pub fn add_address(&self, addr: std::net::SocketAddr) {
+        self.0.buffers.add_address(self.0.host, addr);
+    }
 }
 
 struct State {
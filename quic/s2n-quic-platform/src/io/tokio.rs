@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{features::gso, message::default as message, socket, syscall};
+use core::time::Duration;
 use s2n_quic_core::{
     endpoint::Endpoint,
     event::{self, EndpointPublisher as _},
     inet::{self, SocketAddress},
     io::event_loop::EventLoop,
-    path::MaxMtu,
+    path::{mtu, MaxMtu, MinMtu},
     task::cooldown::Cooldown,
-    time::Clock as ClockTrait,
+    time::ClockWithTimer,
 };
 use std::{convert::TryInto, io, io::ErrorKind};
 use tokio::runtime::Handle;
@@ -22,11 +23,22 @@ mod tests;
 
 pub type PathHandle = message::Handle;
 pub use builder::Builder;
-pub(crate) use clock::Clock;
+pub use clock::{Clock, Timer};
+pub use s2n_quic_core::path::ecn::Mode as EcnMode;
 
-#[derive(Debug, Default)]
-pub struct Io {
-    builder: Builder,
+#[derive(Debug)]
+pub struct Io<C = Clock> {
+    builder: Builder<C>,
+}
+
+// Only implemented for the default clock so `Io::default()` doesn't require type annotations
+#[allow(clippy::derivable_impls)]
+impl Default for Io {
+    fn default() -> Self {
+        Self {
+            builder: Builder::default(),
+        }
+    }
 }
 
 impl Io {
@@ -39,7 +51,13 @@ impl Io {
         let builder = Builder::default().with_receive_address(address)?;
         Ok(Self { builder })
     }
+}
 
+impl<C> Io<C>
+where
+    C: 'static + ClockWithTimer + Send + Sync,
+    C::Timer: Send,
+{
     pub fn start<E: Endpoint<PathHandle = PathHandle>>(
         self,
         mut endpoint: E,
@@ -54,14 +72,17 @@ impl Io {
             socket_send_buffer_size,
             queue_recv_buffer_size,
             queue_send_buffer_size,
-            mut max_mtu,
+            mut mtu_config,
             max_segments,
             gro_enabled,
             reuse_port,
+            interface,
+            dual_stack,
+            recv_batch_size,
+            ecn_mode,
+            clock,
         } = self.builder;
 
-        let clock = Clock::default();
-
         let mut publisher = event::EndpointPublisherSubscriber::new(
             event::builder::EndpointMeta {
                 endpoint_type: E::ENDPOINT_TYPE,
@@ -71,12 +92,6 @@ impl Io {
             endpoint.subscriber(),
         );
 
-        publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
-            configuration: event::builder::PlatformFeatureConfiguration::Gso {
-                max_segments: max_segments.into(),
-            },
-        });
-
         // try to use the tokio runtime handle if provided, otherwise try to use the implicit tokio
         // runtime in the current scope of the application.
         let handle = if let Some(handle) = handle {
@@ -90,7 +105,7 @@ impl Io {
         let rx_socket = if let Some(rx_socket) = rx_socket {
             rx_socket
         } else if let Some(recv_addr) = recv_addr {
-            syscall::bind_udp(recv_addr, reuse_port)?
+            syscall::bind_udp(recv_addr, reuse_port, interface.as_deref(), dual_stack)?
         } else {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
@@ -103,7 +118,7 @@ impl Io {
         let tx_socket = if let Some(tx_socket) = tx_socket {
             tx_socket
         } else if let Some(send_addr) = send_addr {
-            syscall::bind_udp(send_addr, reuse_port)?
+            syscall::bind_udp(send_addr, reuse_port, interface.as_deref(), dual_stack)?
         } else {
             // No tx_socket or send address was specified, so the tx socket
             // will be a handle to the rx socket.
@@ -118,18 +133,47 @@ impl Io {
             rx_socket.set_recv_buffer_size(size)?;
         }
 
+        // report the sizes the OS actually applied, since they may differ from the requested sizes
+        if let Ok(size) = tx_socket.send_buffer_size() {
+            publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+                configuration: event::builder::PlatformFeatureConfiguration::SendBufferSize {
+                    size,
+                },
+            });
+        }
+
+        if let Ok(size) = rx_socket.recv_buffer_size() {
+            publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+                configuration: event::builder::PlatformFeatureConfiguration::ReceiveBufferSize {
+                    size,
+                },
+            });
+        }
+
         // Configure MTU discovery
         if !syscall::configure_mtu_disc(&tx_socket) {
             // disable MTU probing if we can't prevent fragmentation
-            max_mtu = MaxMtu::MIN;
+            mtu_config.max_mtu = MaxMtu::MIN;
+            mtu_config.min_mtu = MinMtu::MIN;
         }
 
+        let max_mtu = mtu_config.max_mtu;
+
         publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
             configuration: event::builder::PlatformFeatureConfiguration::MaxMtu {
                 mtu: max_mtu.into(),
             },
         });
 
+        // Configure GSO, which is limited to a single segment on platforms without support
+        let gso = crate::features::Gso::from(max_segments);
+
+        publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+            configuration: event::builder::PlatformFeatureConfiguration::Gso {
+                max_segments: gso.max_segments(),
+            },
+        });
+
         // Configure the socket with GRO
         let gro_enabled = gro_enabled.unwrap_or(true) && syscall::configure_gro(&rx_socket);
 
@@ -143,7 +187,7 @@ impl Io {
         syscall::configure_pktinfo(&rx_socket);
 
         // Configure TOS/ECN
-        let tos_enabled = syscall::configure_tos(&rx_socket);
+        let tos_enabled = ecn_mode.is_enabled() && syscall::configure_tos(&rx_socket);
 
         publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
             configuration: event::builder::PlatformFeatureConfiguration::Ecn {
@@ -176,17 +220,25 @@ impl Io {
             // complete
             let rx_cooldown = cooldown("RX");
 
+            // by default, fill as many free slots in the ring as possible with each syscall
+            let batch_size = recv_batch_size.unwrap_or(usize::MAX);
+
             for idx in 0usize..rx_socket_count {
                 let (producer, consumer) = socket::ring::pair(entries, payload_len);
                 consumers.push(consumer);
 
                 // spawn a task that actually reads from the socket into the ring buffer
                 if idx + 1 == rx_socket_count {
-                    handle.spawn(task::rx(rx_socket, producer, rx_cooldown));
+                    handle.spawn(task::rx(rx_socket, producer, rx_cooldown, batch_size));
                     break;
                 } else {
                     let rx_socket = rx_socket.try_clone()?;
-                    handle.spawn(task::rx(rx_socket, producer, rx_cooldown.clone()));
+                    handle.spawn(task::rx(
+                        rx_socket,
+                        producer,
+                        rx_cooldown.clone(),
+                        batch_size,
+                    ));
                 }
             }
 
@@ -197,8 +249,6 @@ impl Io {
         };
 
         let tx = {
-            let gso = crate::features::Gso::from(max_segments);
-
             // compute the payload size for each message from the number of GSO segments we can
             // fill
             let payload_len = {
@@ -246,8 +296,9 @@ impl Io {
             socket::io::tx::Tx::new(producers, gso, max_mtu)
         };
 
-        // Notify the endpoint of the MTU that we chose
-        endpoint.set_max_mtu(max_mtu);
+        // Notify the endpoint of the MTU configuration that we chose
+        endpoint.set_mtu_config(mtu_config);
+        endpoint.set_ecn_mode(ecn_mode);
 
         let task = handle.spawn(
             EventLoop {
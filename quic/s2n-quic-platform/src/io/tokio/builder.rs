@@ -3,8 +3,8 @@
 
 use super::*;
 
-#[derive(Debug, Default)]
-pub struct Builder {
+#[derive(Debug)]
+pub struct Builder<C = Clock> {
     pub(super) handle: Option<Handle>,
     pub(super) rx_socket: Option<socket2::Socket>,
     pub(super) tx_socket: Option<socket2::Socket>,
@@ -14,13 +14,45 @@ pub struct Builder {
     pub(super) socket_send_buffer_size: Option<usize>,
     pub(super) queue_recv_buffer_size: Option<u32>,
     pub(super) queue_send_buffer_size: Option<u32>,
-    pub(super) max_mtu: MaxMtu,
+    pub(super) mtu_config: mtu::Config,
     pub(super) max_segments: gso::MaxSegments,
     pub(super) gro_enabled: Option<bool>,
     pub(super) reuse_port: bool,
+    pub(super) interface: Option<String>,
+    pub(super) dual_stack: Option<bool>,
+    pub(super) recv_batch_size: Option<usize>,
+    pub(super) ecn_mode: EcnMode,
+    pub(super) clock: C,
 }
 
-impl Builder {
+// Only implemented for the default clock so `Builder::default()` doesn't require type annotations
+#[allow(clippy::derivable_impls)]
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            rx_socket: Default::default(),
+            tx_socket: Default::default(),
+            recv_addr: Default::default(),
+            send_addr: Default::default(),
+            socket_recv_buffer_size: Default::default(),
+            socket_send_buffer_size: Default::default(),
+            queue_recv_buffer_size: Default::default(),
+            queue_send_buffer_size: Default::default(),
+            mtu_config: Default::default(),
+            max_segments: Default::default(),
+            gro_enabled: Default::default(),
+            reuse_port: Default::default(),
+            interface: Default::default(),
+            dual_stack: Default::default(),
+            recv_batch_size: Default::default(),
+            ecn_mode: Default::default(),
+            clock: Default::default(),
+        }
+    }
+}
+
+impl<C> Builder<C> {
     #[must_use]
     pub fn with_handle(mut self, handle: Handle) -> Self {
         self.handle = Some(handle);
@@ -74,13 +106,34 @@ impl Builder {
     }
 
     /// Sets the size of the operating system’s send buffer associated with the tx socket
+    /// (SO_SNDBUF)
+    ///
+    /// Links with a high bandwidth-delay product may need a larger buffer to keep the path full.
+    /// The operating system may adjust or clamp the requested size; the size that was actually
+    /// applied is reported with a `PlatformFeatureConfigured` event when the provider starts.
     pub fn with_send_buffer_size(mut self, send_buffer_size: usize) -> io::Result<Self> {
+        if send_buffer_size == 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "send_buffer_size must be greater than zero",
+            ));
+        }
         self.socket_send_buffer_size = Some(send_buffer_size);
         Ok(self)
     }
 
     /// Sets the size of the operating system’s receive buffer associated with the rx socket
+    /// (SO_RCVBUF)
+    ///
+    /// The operating system may adjust or clamp the requested size; the size that was actually
+    /// applied is reported with a `PlatformFeatureConfigured` event when the provider starts.
     pub fn with_recv_buffer_size(mut self, recv_buffer_size: usize) -> io::Result<Self> {
+        if recv_buffer_size == 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "recv_buffer_size must be greater than zero",
+            ));
+        }
         self.socket_recv_buffer_size = Some(recv_buffer_size);
         Ok(self)
     }
@@ -107,7 +160,62 @@ impl Builder {
 
     /// Sets the largest maximum transmission unit (MTU) that can be sent on a path
     pub fn with_max_mtu(mut self, max_mtu: u16) -> io::Result<Self> {
-        self.max_mtu = max_mtu
+        self.mtu_config.max_mtu = max_mtu
+            .try_into()
+            .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
+        Ok(self)
+    }
+
+    /// Sets the smallest maximum transmission unit (MTU) that all paths are assumed to support
+    ///
+    /// See [`mtu::Config::min_mtu`](s2n_quic_core::path::mtu::Config::min_mtu) for the
+    /// accepted values.
+    pub fn with_min_mtu(mut self, min_mtu: u16) -> io::Result<Self> {
+        self.mtu_config.min_mtu = min_mtu
+            .try_into()
+            .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
+        Ok(self)
+    }
+
+    /// Sets the minimum amount of time between consecutive MTU probes sent on a path
+    ///
+    /// See [`mtu::Config::probe_interval`](s2n_quic_core::path::mtu::Config::probe_interval)
+    /// for how the interval interacts with the idle timeout.
+    #[must_use]
+    pub fn with_mtu_probe_interval(mut self, probe_interval: Duration) -> Self {
+        self.mtu_config.probe_interval = probe_interval;
+        self
+    }
+
+    /// Sets the number of MTU black hole indications tolerated before the MTU of a path is reduced
+    ///
+    /// Defaults to 3. See
+    /// [`mtu::Config::black_hole_threshold`](s2n_quic_core::path::mtu::Config::black_hole_threshold)
+    /// for what counts as an indication. Returns an error if `count` is greater than
+    /// [`mtu::MAX_BLACK_HOLE_THRESHOLD`](s2n_quic_core::path::mtu::MAX_BLACK_HOLE_THRESHOLD).
+    pub fn with_mtu_blackhole_threshold(mut self, count: u8) -> io::Result<Self> {
+        if count > mtu::MAX_BLACK_HOLE_THRESHOLD {
+            let err = mtu::ConfigError::BlackHoleThresholdTooLarge {
+                black_hole_threshold: count,
+            };
+            return Err(io::Error::new(ErrorKind::InvalidInput, format!("{err}")));
+        }
+        self.mtu_config.black_hole_threshold = count;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of packets coalesced into a single Generic Segmentation Offload
+    /// (GSO) send
+    ///
+    /// Defaults to 10, which matches the burst limit enforced by the packet pacer. Larger values
+    /// can improve efficiency on low latency, high bandwidth networks where bursts exceed that
+    /// limit. Values above the platform limit are reduced to it, so on platforms without GSO
+    /// support each packet is sent individually. Setting the value to 1 disables GSO. The number
+    /// of segments that was actually applied is reported with a `PlatformFeatureConfigured` event
+    /// when the provider starts.
+    pub fn with_gso_max_segments(mut self, max_segments: usize) -> io::Result<Self> {
+        let max_segments = max_segments.min(gso::MaxSegments::MAX.into());
+        self.max_segments = max_segments
             .try_into()
             .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
         Ok(self)
@@ -117,8 +225,17 @@ impl Builder {
     ///
     /// By default, GSO will be used unless the platform does not support it or an attempt to use
     /// GSO fails. If it is known that GSO is not available, set this option to explicitly disable it.
-    pub fn with_gso_disabled(mut self) -> io::Result<Self> {
-        self.max_segments = 1.try_into().expect("1 is always a valid MaxSegments value");
+    pub fn with_gso_disabled(self) -> io::Result<Self> {
+        self.with_gso_max_segments(1)
+    }
+
+    /// Enables or disables Generic Receive Offload (GRO)
+    ///
+    /// By default, GRO will be used unless the platform does not support it. Enabling GRO on a
+    /// platform without support has no effect. Whether GRO was actually enabled is reported with a
+    /// `PlatformFeatureConfigured` event when the provider starts.
+    pub fn with_gro(mut self, enabled: bool) -> io::Result<Self> {
+        self.gro_enabled = Some(enabled);
         Ok(self)
     }
 
@@ -126,8 +243,36 @@ impl Builder {
     ///
     /// By default, GRO will be used unless the platform does not support it. If it is known that
     /// GRO is not available, set this option to explicitly disable it.
-    pub fn with_gro_disabled(mut self) -> io::Result<Self> {
-        self.gro_enabled = Some(false);
+    pub fn with_gro_disabled(self) -> io::Result<Self> {
+        self.with_gro(false)
+    }
+
+    /// Sets the maximum number of datagrams read from the socket with a single syscall
+    ///
+    /// By default, as many datagrams are read as there are free slots in the receive queue. On
+    /// platforms that support `recvmmsg`, a batch of datagrams is read with a single syscall, which
+    /// reduces the per-packet overhead when receiving from many peers at once. Otherwise, each
+    /// datagram is read with a separate syscall and the value only limits how many are read
+    /// before the endpoint is notified.
+    pub fn with_recv_batch_size(mut self, batch_size: usize) -> io::Result<Self> {
+        if batch_size == 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "recv_batch_size must be greater than zero",
+            ));
+        }
+        self.recv_batch_size = Some(batch_size);
+        Ok(self)
+    }
+
+    /// Sets how Explicit Congestion Notification (ECN) is used
+    ///
+    /// By default, packets are marked as ECN capable on paths that pass ECN validation and the
+    /// congestion controller responds to ECN-CE counts reported by the peer. `EcnMode::Validate`
+    /// keeps marking and validating packets but only reports congestion through events, while
+    /// `EcnMode::Disabled` neither marks packets nor configures the socket to read ECN markings.
+    pub fn with_ecn(mut self, mode: EcnMode) -> io::Result<Self> {
+        self.ecn_mode = mode;
         Ok(self)
     }
 
@@ -143,7 +288,108 @@ impl Builder {
         Ok(self)
     }
 
-    pub fn build(self) -> io::Result<Io> {
+    /// Binds the sockets to the network interface with the given name (SO_BINDTODEVICE)
+    ///
+    /// Packets are only received from and sent over the named interface, regardless of the
+    /// routing table, which allows QUIC traffic to be pinned to a specific NIC on multi-homed
+    /// hosts. The option is applied to sockets created from the receive and send addresses; sockets
+    /// provided with `with_rx_socket` or `with_tx_socket` are used as-is.
+    ///
+    /// The max MTU is not derived from the interface, so it should be configured to fit within the
+    /// MTU of the interface to avoid probing for sizes the interface cannot transmit.
+    ///
+    /// NOTE: this option is only supported on Linux and Android, and may require the
+    /// `CAP_NET_RAW` capability.
+    pub fn with_interface(mut self, name: &str) -> io::Result<Self> {
+        if !cfg!(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux"
+        )) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "binding to an interface is not supported on the current platform",
+            ));
+        }
+        self.interface = Some(name.to_string());
+        Ok(self)
+    }
+
+    /// Configures whether IPv6 sockets also accept IPv4 traffic (IPV6_V6ONLY disabled)
+    ///
+    /// When enabled, a single IPv6 socket serves both IPv4 and IPv6 peers, which avoids running a
+    /// listener per address family. IPv4 peers are reported as IPv4-mapped IPv6 addresses
+    /// (`::ffff:a.b.c.d`) by the socket; the paths compare these equal to the unmapped addresses,
+    /// so a peer migrating between the two forms keeps its connection. An unspecified IPv4 bind
+    /// address (`0.0.0.0`) is replaced with the unspecified IPv6 address on the same port, while
+    /// specific IPv4 addresses are rejected when the socket is bound.
+    ///
+    /// By default, the sockets attempt to accept IPv4 traffic but silently fall back to IPv6-only
+    /// if the platform doesn't allow it. Explicitly configuring this option returns an error
+    /// instead. The option is applied to sockets created from the receive and send addresses;
+    /// sockets provided with `with_rx_socket` or `with_tx_socket` are used as-is.
+    pub fn with_dual_stack(mut self, enabled: bool) -> io::Result<Self> {
+        self.dual_stack = Some(enabled);
+        Ok(self)
+    }
+
+    /// Sets the clock used by the endpoint
+    ///
+    /// All of the time-based machinery of the endpoint, including loss recovery, pacing and the
+    /// idle timeout, reads time from this clock and its timer drives the endpoint's wakeups. The
+    /// timer is polled on the tokio runtime, so it needs to wake the task once the deadline
+    /// passed to [`Timer::update`](s2n_quic_core::time::clock::Timer::update) is reached.
+    ///
+    /// By default, the clock is backed by the tokio runtime's `Instant`, which means pausing the
+    /// tokio clock also pauses the endpoint.
+    #[must_use]
+    pub fn with_clock<T: ClockWithTimer>(self, clock: T) -> Builder<T> {
+        let Self {
+            handle,
+            rx_socket,
+            tx_socket,
+            recv_addr,
+            send_addr,
+            socket_recv_buffer_size,
+            socket_send_buffer_size,
+            queue_recv_buffer_size,
+            queue_send_buffer_size,
+            mtu_config,
+            max_segments,
+            gro_enabled,
+            reuse_port,
+            interface,
+            dual_stack,
+            recv_batch_size,
+            ecn_mode,
+            clock: _,
+        } = self;
+        Builder {
+            handle,
+            rx_socket,
+            tx_socket,
+            recv_addr,
+            send_addr,
+            socket_recv_buffer_size,
+            socket_send_buffer_size,
+            queue_recv_buffer_size,
+            queue_send_buffer_size,
+            mtu_config,
+            max_segments,
+            gro_enabled,
+            reuse_port,
+            interface,
+            dual_stack,
+            recv_batch_size,
+            ecn_mode,
+            clock,
+        }
+    }
+
+    pub fn build(self) -> io::Result<Io<C>> {
+        self.mtu_config
+            .validate()
+            .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
         Ok(Io { builder: self })
     }
 }
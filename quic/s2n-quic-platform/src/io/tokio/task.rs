@@ -30,8 +30,9 @@ macro_rules! libc_msg {
                 socket: S,
                 producer: ring::Producer<Message>,
                 cooldown: Cooldown,
+                batch_size: usize,
             ) -> std::io::Result<()> {
-                unix::rx(socket, producer, cooldown).await
+                unix::rx(socket, producer, cooldown, batch_size).await
             }
 
             pub async fn tx<S: Into<std::net::UdpSocket>>(
@@ -18,12 +18,15 @@ pub async fn rx<S: Into<std::net::UdpSocket>>(
     socket: S,
     producer: ring::Producer<Message>,
     cooldown: Cooldown,
+    batch_size: usize,
 ) -> io::Result<()> {
     let socket = socket.into();
     socket.set_nonblocking(true).unwrap();
 
     let socket = UdpSocket::from_std(socket).unwrap();
-    let result = task::Receiver::new(producer, socket, cooldown).await;
+    let result = task::Receiver::new(producer, socket, cooldown)
+        .with_batch_size(batch_size)
+        .await;
     if let Some(err) = result {
         Err(err)
     } else {
@@ -18,12 +18,15 @@ pub async fn rx<S: Into<std::net::UdpSocket>, M: UnixMessage + Unpin>(
     socket: S,
     producer: ring::Producer<M>,
     cooldown: Cooldown,
+    batch_size: usize,
 ) -> io::Result<()> {
     let socket = socket.into();
     socket.set_nonblocking(true).unwrap();
 
     let socket = AsyncFd::new(socket).unwrap();
-    let result = rx::Receiver::new(producer, socket, cooldown).await;
+    let result = rx::Receiver::new(producer, socket, cooldown)
+        .with_batch_size(batch_size)
+        .await;
     if let Some(err) = result {
         Err(err)
     } else {
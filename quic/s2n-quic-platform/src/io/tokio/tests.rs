@@ -12,15 +12,24 @@ use s2n_quic_core::{
     inet::ExplicitCongestionNotification,
     io::{rx, tx},
     path::Handle as _,
-    time::{Clock, Duration, Timestamp},
+    time::{self, Clock, Duration, Timestamp},
+};
+use std::{
+    collections::BTreeMap,
+    net::ToSocketAddrs,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
-use std::{collections::BTreeMap, net::ToSocketAddrs};
 
 struct TestEndpoint<const IS_SERVER: bool> {
     handle: PathHandle,
+    /// Replies to the sender of the most recent message rather than a fixed handle
+    reply_to_sender: bool,
     messages: BTreeMap<u32, Option<Timestamp>>,
     now: Option<Timestamp>,
-    subscriber: NoopSubscriber,
+    subscriber: TestSubscriber,
 }
 
 impl<const IS_SERVER: bool> TestEndpoint<IS_SERVER> {
@@ -29,6 +38,7 @@ impl<const IS_SERVER: bool> TestEndpoint<IS_SERVER> {
         let messages = (0..messages).map(|id| (id, None)).collect();
         Self {
             handle,
+            reply_to_sender: false,
             messages,
             now: None,
             subscriber: Default::default(),
@@ -36,10 +46,13 @@ impl<const IS_SERVER: bool> TestEndpoint<IS_SERVER> {
     }
 }
 
+/// Records the platform features configured by the IO provider
 #[derive(Debug, Default)]
-struct NoopSubscriber;
+struct TestSubscriber {
+    configurations: Arc<Mutex<Vec<event::api::PlatformFeatureConfiguration>>>,
+}
 
-impl event::Subscriber for NoopSubscriber {
+impl event::Subscriber for TestSubscriber {
     type ConnectionContext = ();
 
     fn create_connection_context(
@@ -48,11 +61,22 @@ impl event::Subscriber for NoopSubscriber {
         _info: &event::api::ConnectionInfo,
     ) -> Self::ConnectionContext {
     }
+
+    fn on_platform_feature_configured(
+        &mut self,
+        _meta: &event::api::EndpointMeta,
+        event: &event::api::PlatformFeatureConfigured,
+    ) {
+        self.configurations
+            .lock()
+            .unwrap()
+            .push(event.configuration.clone());
+    }
 }
 
 impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
     type PathHandle = PathHandle;
-    type Subscriber = NoopSubscriber;
+    type Subscriber = TestSubscriber;
 
     const ENDPOINT_TYPE: endpoint::Type = if IS_SERVER {
         endpoint::Type::Server
@@ -93,7 +117,7 @@ impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
         let now = clock.get_time();
         self.now = Some(now);
 
-        queue.for_each(|_header, payload| {
+        queue.for_each(|header, payload| {
             // we should only be receiving u32 values
             if payload.len() != 4 {
                 return;
@@ -103,6 +127,9 @@ impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
             let id = u32::from_be_bytes(id);
 
             if IS_SERVER {
+                if self.reply_to_sender {
+                    self.handle = header.path;
+                }
                 self.messages.insert(id, None);
             } else {
                 self.messages.remove(&id);
@@ -129,7 +156,7 @@ impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
         self.now.map(|now| now + Duration::from_millis(50))
     }
 
-    fn set_max_mtu(&mut self, _max_mtu: MaxMtu) {
+    fn set_max_mtu(&mut self, _max_mtu: s2n_quic_core::path::MaxMtu) {
         // noop
     }
 
@@ -138,11 +165,13 @@ impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
     }
 }
 
-async fn runtime<A: ToSocketAddrs>(
+async fn runtime<A: ToSocketAddrs, C>(
     receive_addr: A,
     send_addr: Option<A>,
-) -> io::Result<(super::Io, SocketAddress)> {
-    let rx_socket = syscall::bind_udp(receive_addr, false)?;
+    interface: Option<&str>,
+    configure: impl Fn(Builder) -> io::Result<Builder<C>>,
+) -> io::Result<(super::Io<C>, SocketAddress)> {
+    let rx_socket = syscall::bind_udp(receive_addr, false, interface, None)?;
     rx_socket.set_nonblocking(true)?;
     let rx_socket: std::net::UdpSocket = rx_socket.into();
     let rx_addr = rx_socket.local_addr()?;
@@ -150,13 +179,13 @@ async fn runtime<A: ToSocketAddrs>(
     let mut io_builder = Io::builder().with_rx_socket(rx_socket)?;
 
     if let Some(tx_addr) = send_addr {
-        let tx_socket = syscall::bind_udp(tx_addr, false)?;
+        let tx_socket = syscall::bind_udp(tx_addr, false, interface, None)?;
         tx_socket.set_nonblocking(true)?;
         let tx_socket: std::net::UdpSocket = tx_socket.into();
         io_builder = io_builder.with_tx_socket(tx_socket)?
     }
 
-    let io = io_builder.build()?;
+    let io = configure(io_builder)?.build()?;
 
     let rx_addr = if rx_addr.is_ipv6() {
         ("::1", rx_addr.port())
@@ -178,8 +207,33 @@ async fn test<A: ToSocketAddrs>(
     client_rx_addr: A,
     client_tx_addr: Option<A>,
 ) -> io::Result<()> {
-    let (server_io, server_addr) = runtime(server_rx_addr, server_tx_addr).await?;
-    let (client_io, client_addr) = runtime(client_rx_addr, client_tx_addr).await?;
+    test_with(
+        server_rx_addr,
+        server_tx_addr,
+        client_rx_addr,
+        client_tx_addr,
+        None,
+        Ok,
+    )
+    .await
+}
+
+async fn test_with<A: ToSocketAddrs, C>(
+    server_rx_addr: A,
+    server_tx_addr: Option<A>,
+    client_rx_addr: A,
+    client_tx_addr: Option<A>,
+    interface: Option<&str>,
+    configure: impl Fn(Builder) -> io::Result<Builder<C>>,
+) -> io::Result<()>
+where
+    C: 'static + ClockWithTimer + Send + Sync,
+    C::Timer: Send,
+{
+    let (server_io, server_addr) =
+        runtime(server_rx_addr, server_tx_addr, interface, &configure).await?;
+    let (client_io, client_addr) =
+        runtime(client_rx_addr, client_tx_addr, interface, configure).await?;
 
     let server_endpoint = {
         let mut handle = PathHandle::from_remote_address(client_addr.into());
@@ -260,3 +314,272 @@ async fn ipv6_two_socket_test() -> io::Result<()> {
         other => other,
     }
 }
+
+/// A single dual-stack server socket exchanges messages with both IPv4 and IPv6 clients
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn dual_stack_test() -> io::Result<()> {
+    let server_io = Io::builder()
+        .with_receive_address("0.0.0.0:0".parse().unwrap())?
+        .with_dual_stack(true)?
+        .build()?;
+
+    let mut server_endpoint =
+        TestEndpoint::<true>::new(PathHandle::from_remote_address(Default::default()));
+    server_endpoint.reply_to_sender = true;
+    let (server_task, server_addr) = match server_io.start(server_endpoint) {
+        Err(err) if err.kind() == io::ErrorKind::AddrNotAvailable => {
+            eprintln!("The current environment does not support IPv6; skipping");
+            return Ok(());
+        }
+        result => result?,
+    };
+    let port = server_addr.port();
+
+    for (client_addr, server_addr) in [
+        (IPV4_LOCALHOST, ("127.0.0.1", port)),
+        (IPV6_LOCALHOST, ("::1", port)),
+    ] {
+        let (client_io, client_addr) = runtime(client_addr, None, None, Ok).await?;
+        let server_addr: SocketAddress = server_addr.to_socket_addrs()?.next().unwrap().into();
+
+        let client_endpoint = {
+            let mut handle = PathHandle::from_remote_address(server_addr.into());
+            handle.local_address = client_addr.into();
+            TestEndpoint::<false>::new(handle)
+        };
+
+        let (client_task, _) = client_io.start(client_endpoint)?;
+        tokio::time::timeout(core::time::Duration::from_secs(60), client_task).await??;
+    }
+
+    server_task.abort();
+
+    // specific IPv4 addresses can't be bound to a dual-stack socket
+    assert!(syscall::bind_udp(IPV4_LOCALHOST, false, None, Some(true)).is_err());
+
+    Ok(())
+}
+
+/// The burst of messages sent by the client is received regardless of how many are read per syscall
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn recv_batch_size_test() -> io::Result<()> {
+    test_with(
+        IPV4_LOCALHOST,
+        None,
+        IPV4_LOCALHOST,
+        None,
+        None,
+        |builder| builder.with_recv_batch_size(1),
+    )
+    .await?;
+
+    test_with(
+        IPV4_LOCALHOST,
+        None,
+        IPV4_LOCALHOST,
+        None,
+        None,
+        |builder| builder.with_recv_batch_size(8),
+    )
+    .await?;
+
+    assert!(Io::builder().with_recv_batch_size(0).is_err());
+
+    Ok(())
+}
+
+/// The socket buffer sizes applied by the OS are reported when the provider starts
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn socket_buffer_size_test() -> io::Result<()> {
+    use event::api::PlatformFeatureConfiguration as Configuration;
+
+    const SIZE: usize = 64 * 1024;
+
+    let io = Io::builder()
+        .with_receive_address(IPV4_LOCALHOST.parse().unwrap())?
+        .with_send_buffer_size(SIZE)?
+        .with_recv_buffer_size(SIZE)?
+        .build()?;
+
+    let endpoint = TestEndpoint::<true>::new(PathHandle::from_remote_address(Default::default()));
+    let configurations = endpoint.subscriber.configurations.clone();
+    let (task, _addr) = io.start(endpoint)?;
+    task.abort();
+
+    let configurations = configurations.lock().unwrap();
+    let send_buffer_size = configurations
+        .iter()
+        .find_map(|configuration| match configuration {
+            Configuration::SendBufferSize { size, .. } => Some(*size),
+            _ => None,
+        });
+    let recv_buffer_size = configurations
+        .iter()
+        .find_map(|configuration| match configuration {
+            Configuration::ReceiveBufferSize { size, .. } => Some(*size),
+            _ => None,
+        });
+
+    // the OS is allowed to grow the requested sizes (Linux doubles them for bookkeeping overhead)
+    assert!(send_buffer_size.unwrap() >= SIZE, "{send_buffer_size:?}");
+    assert!(recv_buffer_size.unwrap() >= SIZE, "{recv_buffer_size:?}");
+
+    assert!(Io::builder().with_send_buffer_size(0).is_err());
+    assert!(Io::builder().with_recv_buffer_size(0).is_err());
+
+    Ok(())
+}
+
+/// Whether segment offload is enabled is reported once when the provider starts
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn offload_configuration_test() -> io::Result<()> {
+    use event::api::PlatformFeatureConfiguration as Configuration;
+
+    for (gso_max_segments, gro_requested) in [(1, false), (usize::MAX, true)] {
+        let io = Io::builder()
+            .with_receive_address(IPV4_LOCALHOST.parse().unwrap())?
+            .with_gso_max_segments(gso_max_segments)?
+            .with_gro(gro_requested)?
+            .build()?;
+
+        let endpoint =
+            TestEndpoint::<true>::new(PathHandle::from_remote_address(Default::default()));
+        let configurations = endpoint.subscriber.configurations.clone();
+        let (task, _addr) = io.start(endpoint)?;
+        task.abort();
+
+        let configurations = configurations.lock().unwrap();
+        let gso: Vec<_> = configurations
+            .iter()
+            .filter_map(|configuration| match configuration {
+                Configuration::Gso { max_segments, .. } => Some(*max_segments),
+                _ => None,
+            })
+            .collect();
+        let gro: Vec<_> = configurations
+            .iter()
+            .filter_map(|configuration| match configuration {
+                Configuration::Gro { enabled, .. } => Some(*enabled),
+                _ => None,
+            })
+            .collect();
+
+        let max_segments: usize = gso::MaxSegments::MAX.into();
+        assert_eq!(gso, [gso_max_segments.min(max_segments)]);
+        assert_eq!(gro.len(), 1);
+
+        // GRO is only enabled if requested and supported by the platform
+        if !gro_requested {
+            assert_eq!(gro, [false]);
+        }
+    }
+
+    Ok(())
+}
+
+/// Wraps the default clock and counts the number of timer updates
+#[derive(Clone, Debug, Default)]
+struct CountingClock {
+    clock: super::Clock,
+    timer_updates: Arc<AtomicUsize>,
+}
+
+impl time::Clock for CountingClock {
+    fn get_time(&self) -> Timestamp {
+        self.clock.get_time()
+    }
+}
+
+impl ClockWithTimer for CountingClock {
+    type Timer = CountingTimer;
+
+    fn timer(&self) -> Self::Timer {
+        CountingTimer {
+            timer: self.clock.timer(),
+            timer_updates: self.timer_updates.clone(),
+        }
+    }
+}
+
+struct CountingTimer {
+    timer: super::Timer,
+    timer_updates: Arc<AtomicUsize>,
+}
+
+impl time::clock::Timer for CountingTimer {
+    fn poll_ready(&mut self, cx: &mut Context) -> Poll<()> {
+        self.timer.poll_ready(cx)
+    }
+
+    fn update(&mut self, timestamp: Timestamp) {
+        self.timer_updates.fetch_add(1, Ordering::Relaxed);
+        self.timer.update(timestamp)
+    }
+}
+
+/// The endpoint reads time from and arms the timer of the configured clock
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn clock_test() -> io::Result<()> {
+    let clock = CountingClock::default();
+    let timer_updates = clock.timer_updates.clone();
+
+    test_with(
+        IPV4_LOCALHOST,
+        None,
+        IPV4_LOCALHOST,
+        None,
+        None,
+        move |builder| Ok(builder.with_clock(clock.clone())),
+    )
+    .await?;
+
+    assert!(timer_updates.load(Ordering::Relaxed) > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+#[cfg(target_os = "linux")]
+async fn interface_test() -> io::Result<()> {
+    let result = test_with(
+        IPV4_LOCALHOST,
+        Some(IPV4_LOCALHOST),
+        IPV4_LOCALHOST,
+        Some(IPV4_LOCALHOST),
+        Some("lo"),
+        Ok,
+    )
+    .await;
+
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+            eprintln!("The current environment does not allow binding to an interface; skipping");
+            Ok(())
+        }
+        other => other,
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(target_os = "linux")]
+fn interface_builder_test() -> io::Result<()> {
+    let io = Io::builder()
+        .with_receive_address(IPV4_LOCALHOST.parse().unwrap())?
+        .with_interface("lo")?
+        .with_max_mtu(9001)?
+        .build()?;
+    assert_eq!(io.builder.interface.as_deref(), Some("lo"));
+    assert_eq!(u16::from(io.builder.mtu_config.max_mtu), 9001);
+
+    // binding to an interface that doesn't exist fails
+    assert!(syscall::bind_udp(IPV4_LOCALHOST, false, Some("s2n-quic-missing"), None).is_err());
+
+    Ok(())
+}
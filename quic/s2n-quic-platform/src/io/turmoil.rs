@@ -9,13 +9,13 @@ use crate::{
         ring::{self, Consumer, Producer},
     },
 };
-use core::future::Future;
+use core::{future::Future, time::Duration};
 use futures::future::poll_fn;
 use s2n_quic_core::{
     endpoint::Endpoint,
     inet::{self, SocketAddress},
     io::event_loop::{select::Select, EventLoop},
-    path::{self, MaxMtu},
+    path::{self, mtu},
 };
 use std::{convert::TryInto, io, io::ErrorKind};
 use tokio::runtime::Handle;
@@ -26,6 +26,7 @@ mod builder;
 mod tests;
 
 pub use builder::Builder;
+pub use s2n_quic_core::path::ecn::Mode as EcnMode;
 pub type PathHandle = path::Tuple;
 
 #[derive(Default)]
@@ -51,10 +52,13 @@ impl Io {
             handle: _,
             socket,
             addr,
-            max_mtu,
+            mtu_config,
+            ecn_mode,
         } = self.builder;
 
-        endpoint.set_max_mtu(max_mtu);
+        endpoint.set_mtu_config(mtu_config);
+        endpoint.set_ecn_mode(ecn_mode);
+        let max_mtu = mtu_config.max_mtu;
 
         let clock = Clock::default();
 
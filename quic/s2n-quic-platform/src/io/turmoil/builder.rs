@@ -8,7 +8,8 @@ pub struct Builder {
     pub(super) handle: Option<Handle>,
     pub(super) socket: Option<UdpSocket>,
     pub(super) addr: Option<Box<dyn turmoil::ToSocketAddrs + Send + Sync + 'static>>,
-    pub(super) max_mtu: MaxMtu,
+    pub(super) mtu_config: mtu::Config,
+    pub(super) ecn_mode: EcnMode,
 }
 
 impl Builder {
@@ -41,13 +42,64 @@ impl Builder {
 
     /// Sets the largest maximum transmission unit (MTU) that can be sent on a path
     pub fn with_max_mtu(mut self, max_mtu: u16) -> io::Result<Self> {
-        self.max_mtu = max_mtu
+        self.mtu_config.max_mtu = max_mtu
             .try_into()
             .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
         Ok(self)
     }
 
+    /// Sets the smallest maximum transmission unit (MTU) that all paths are assumed to support
+    ///
+    /// See [`mtu::Config::min_mtu`](s2n_quic_core::path::mtu::Config::min_mtu) for the
+    /// accepted values.
+    pub fn with_min_mtu(mut self, min_mtu: u16) -> io::Result<Self> {
+        self.mtu_config.min_mtu = min_mtu
+            .try_into()
+            .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
+        Ok(self)
+    }
+
+    /// Sets the minimum amount of time between consecutive MTU probes sent on a path
+    ///
+    /// See [`mtu::Config::probe_interval`](s2n_quic_core::path::mtu::Config::probe_interval)
+    /// for how the interval interacts with the idle timeout.
+    #[must_use]
+    pub fn with_mtu_probe_interval(mut self, probe_interval: Duration) -> Self {
+        self.mtu_config.probe_interval = probe_interval;
+        self
+    }
+
+    /// Sets the number of MTU black hole indications tolerated before the MTU of a path is reduced
+    ///
+    /// Defaults to 3. See
+    /// [`mtu::Config::black_hole_threshold`](s2n_quic_core::path::mtu::Config::black_hole_threshold)
+    /// for what counts as an indication. Returns an error if `count` is greater than
+    /// [`mtu::MAX_BLACK_HOLE_THRESHOLD`](s2n_quic_core::path::mtu::MAX_BLACK_HOLE_THRESHOLD).
+    pub fn with_mtu_blackhole_threshold(mut self, count: u8) -> io::Result<Self> {
+        if count > mtu::MAX_BLACK_HOLE_THRESHOLD {
+            let err = mtu::ConfigError::BlackHoleThresholdTooLarge {
+                black_hole_threshold: count,
+            };
+            return Err(io::Error::new(ErrorKind::InvalidInput, format!("{err}")));
+        }
+        self.mtu_config.black_hole_threshold = count;
+        Ok(self)
+    }
+
+    /// Sets how Explicit Congestion Notification (ECN) is used
+    ///
+    /// The turmoil network doesn't carry ECN markings, so ECN validation fails on each path
+    /// unless the mode is [`EcnMode::Disabled`], which skips it.
+    #[must_use]
+    pub fn with_ecn(mut self, mode: EcnMode) -> Self {
+        self.ecn_mode = mode;
+        self
+    }
+
     pub fn build(self) -> io::Result<Io> {
+        self.mtu_config
+            .validate()
+            .map_err(|err| io::Error::new(ErrorKind::InvalidInput, format!("{err}")))?;
         Ok(Io { builder: self })
     }
 }
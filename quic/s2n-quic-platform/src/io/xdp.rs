@@ -3,7 +3,10 @@
 
 use crate::io::tokio::Clock;
 use s2n_quic_core::{
-    endpoint::Endpoint, inet::SocketAddress, io::event_loop::EventLoop, path::MaxMtu,
+    endpoint::Endpoint,
+    inet::SocketAddress,
+    io::event_loop::EventLoop,
+    path::{mtu, MaxMtu},
 };
 pub use s2n_quic_core::{
     io::rx,
@@ -110,7 +113,7 @@ impl From<&PathHandle> for crate::message::msg::Handle {
 }
 
 mod builder;
-pub use builder::Builder;
+pub use builder::{Builder, FrameSizeError};
 
 pub struct Provider<Rx, Tx> {
     rx: Rx,
@@ -143,7 +146,10 @@ where
         } = self;
 
         // tell the endpoint what our MTU is
-        endpoint.set_max_mtu(max_mtu);
+        endpoint.set_mtu_config(mtu::Config {
+            max_mtu,
+            ..Default::default()
+        });
 
         // create a tokio clock
         let clock = Clock::new();
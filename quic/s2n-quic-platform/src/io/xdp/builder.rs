@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use core::mem::size_of;
+use core::{fmt, mem::size_of};
 use s2n_quic_core::{
     inet::{ethernet, ipv4, udp},
     path::{MaxMtu, MaxMtuError},
@@ -13,11 +13,39 @@ use tokio::runtime::Handle;
 const MIN_FRAME_OVERHEAD: u16 =
     (size_of::<ethernet::Header>() + size_of::<ipv4::Header>() + size_of::<udp::Header>()) as _;
 
+/// Returned when the UMEM frames are too small to hold packets of the configured max MTU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameSizeError {
+    frame_size: u16,
+    max_mtu: u16,
+}
+
+impl fmt::Display for FrameSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UMEM frame size {} is too small for a max MTU of {}; frames must be at least {} bytes",
+            self.frame_size,
+            self.max_mtu,
+            self.max_mtu as u32 + MIN_FRAME_OVERHEAD as u32,
+        )
+    }
+}
+
+impl std::error::Error for FrameSizeError {}
+
+impl From<FrameSizeError> for std::io::Error {
+    fn from(error: FrameSizeError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, error)
+    }
+}
+
 #[derive(Debug)]
 #[must_use = "Builders do nothing without calling `build`"]
 pub struct Builder<Rx = (), Tx = ()> {
     rx: Rx,
     tx: Tx,
+    frame_size: u16,
     max_mtu: MaxMtu,
     handle: Option<Handle>,
 }
@@ -27,6 +55,7 @@ impl Default for Builder<(), ()> {
         Self {
             rx: (),
             tx: (),
+            frame_size: DEFAULT_FRAME_SIZE as _,
             max_mtu: MaxMtu::try_from(DEFAULT_FRAME_SIZE as u16 - MIN_FRAME_OVERHEAD).unwrap(),
             handle: None,
         }
@@ -41,11 +70,47 @@ impl<Rx, Tx> Builder<Rx, Tx> {
     }
 
     /// Sets the UMEM frame size for the provider
+    ///
+    /// The max MTU is set to the largest packet that fits in a single frame.
     pub fn with_frame_size(mut self, frame_size: u16) -> Result<Self, MaxMtuError> {
         self.max_mtu = frame_size.saturating_sub(MIN_FRAME_OVERHEAD).try_into()?;
+        self.frame_size = frame_size;
+        Ok(self)
+    }
+
+    /// Sets the frame size of the UMEM used by the RX and TX implementations
+    ///
+    /// Unlike [`Self::with_frame_size`], this does not change the max MTU. Each frame must be
+    /// large enough to hold a packet of the configured max MTU, along with its Ethernet, IP and
+    /// UDP headers, otherwise [`Self::build`] returns an error. For example, the 9001 byte max
+    /// MTU used with jumbo frames requires a frame size of at least 9043 bytes.
+    ///
+    /// Note that the UMEM reserves `frame_size * frame_count` bytes of memory up front, where
+    /// the frame count must cover the combined size of the RX, TX, fill and completion rings.
+    /// Doubling the frame size to fit larger packets doubles the memory used by the UMEM.
+    pub fn with_umem_frame_size(mut self, frame_size: u16) -> Self {
+        self.frame_size = frame_size;
+        self
+    }
+
+    /// Sets the largest MTU the provider will send and receive
+    pub fn with_max_mtu(mut self, max_mtu: u16) -> Result<Self, MaxMtuError> {
+        self.max_mtu = max_mtu.try_into()?;
         Ok(self)
     }
 
+    /// Ensures the UMEM frames can hold packets of the max MTU
+    fn validate(&self) -> Result<(), FrameSizeError> {
+        let max_mtu: u16 = self.max_mtu.into();
+        if (self.frame_size as u32) < max_mtu as u32 + MIN_FRAME_OVERHEAD as u32 {
+            return Err(FrameSizeError {
+                frame_size: self.frame_size,
+                max_mtu,
+            });
+        }
+        Ok(())
+    }
+
     /// Sets the RX implementation for the provider
     pub fn with_rx<NewRx>(self, rx: NewRx) -> Builder<NewRx, Tx>
     where
@@ -54,6 +119,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
         let Self {
             tx,
             handle,
+            frame_size,
             max_mtu,
             ..
         } = self;
@@ -61,6 +127,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             rx,
             tx,
             handle,
+            frame_size,
             max_mtu,
         }
     }
@@ -73,6 +140,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
         let Self {
             rx,
             handle,
+            frame_size,
             max_mtu,
             ..
         } = self;
@@ -80,6 +148,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             rx,
             tx,
             handle,
+            frame_size,
             max_mtu,
         }
     }
@@ -90,18 +159,54 @@ where
     Rx: 'static + super::rx::Rx + Send,
     Tx: 'static + super::tx::Tx<PathHandle = Rx::PathHandle> + Send,
 {
-    pub fn build(self) -> super::Provider<Rx, Tx> {
+    /// Builds the provider
+    ///
+    /// Returns an error if the UMEM frames are too small to hold packets of the max MTU.
+    pub fn build(self) -> Result<super::Provider<Rx, Tx>, FrameSizeError> {
+        self.validate()?;
+
         let Self {
             rx,
             tx,
             handle,
             max_mtu,
+            ..
         } = self;
-        super::Provider {
+
+        Ok(super::Provider {
             rx,
             tx,
             handle,
             max_mtu,
-        }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_size_validation() {
+        assert!(Builder::default().validate().is_ok());
+
+        // frames must fit the jumbo frame MTU along with the packet headers
+        let builder = Builder::default().with_max_mtu(9001).unwrap();
+        assert_eq!(
+            builder.with_umem_frame_size(9000).validate(),
+            Err(FrameSizeError {
+                frame_size: 9000,
+                max_mtu: 9001,
+            })
+        );
+        let builder = Builder::default().with_max_mtu(9001).unwrap();
+        assert!(builder
+            .with_umem_frame_size(9001 + MIN_FRAME_OVERHEAD)
+            .validate()
+            .is_ok());
+
+        // the default frame size can't hold jumbo frames
+        let builder = Builder::default().with_max_mtu(9001).unwrap();
+        assert!(builder.validate().is_err());
     }
 }
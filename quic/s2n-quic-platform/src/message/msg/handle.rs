@@ -90,6 +90,11 @@ impl path::Handle for Handle {
         self.local_address
     }
 
+    #[inline]
+    fn set_local_address(&mut self, local_address: LocalAddress) {
+        self.local_address = local_address;
+    }
+
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         let mut eq = true;
@@ -468,3 +468,60 @@ impl<'a, T: Message> Drop for TxQueue<'a, T> {
         self.flush_channel();
     }
 }
+
+#[cfg(all(test, s2n_quic_platform_gso))]
+mod tests {
+    use super::*;
+    use crate::{features::gso::MaxSegments, message::msg, socket::ring};
+    use core::convert::TryInto;
+    use s2n_quic_core::{
+        inet::SocketAddress,
+        io::tx::{Queue as _, Tx as _},
+        path::RemoteAddress,
+    };
+
+    const MAX_MTU: u16 = 1500;
+    const SEGMENT_LEN: usize = 1000;
+
+    /// Queues `count` equally-sized packets to the same peer and returns the payload length of
+    /// each message handed to the socket task
+    fn send(max_segments: usize, count: usize) -> Vec<usize> {
+        let max_segments: MaxSegments = max_segments.try_into().unwrap();
+        let gso = Gso::from(max_segments);
+        let payload_len = MAX_MTU as u32 * usize::from(max_segments) as u32;
+        let (producer, mut consumer) = ring::pair::<msg::Message>(16, payload_len);
+        let mut tx = Tx::new(vec![producer], gso, MAX_MTU.try_into().unwrap());
+
+        let handle =
+            msg::Handle::from_remote_address(RemoteAddress::from(SocketAddress::default()));
+        let payload = [1u8; SEGMENT_LEN];
+
+        tx.queue(|queue| {
+            for _ in 0..count {
+                queue.push((handle, &payload[..])).unwrap();
+            }
+        });
+
+        let len = consumer.acquire(u32::MAX);
+        let payloads = consumer.data()[..len as usize]
+            .iter()
+            .map(|message| message.payload_len())
+            .collect();
+        consumer.release(len);
+        payloads
+    }
+
+    /// Packets for the same path are coalesced into a single message, which is sent with a single
+    /// `sendmsg` call
+    #[test]
+    fn gso_coalescing_test() {
+        assert_eq!(send(10, 10), vec![SEGMENT_LEN * 10]);
+        // messages are split once the segment limit is reached
+        assert_eq!(
+            send(4, 10),
+            vec![SEGMENT_LEN * 4, SEGMENT_LEN * 4, SEGMENT_LEN * 2]
+        );
+        // without GSO each packet is sent individually
+        assert_eq!(send(1, 3), vec![SEGMENT_LEN; 3]);
+    }
+}
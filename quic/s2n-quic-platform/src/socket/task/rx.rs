@@ -31,6 +31,8 @@ pub struct Receiver<T: Message, S: Socket<T>> {
     rx: S,
     ring_cooldown: Cooldown,
     io_cooldown: Cooldown,
+    /// The maximum number of messages to receive in a single call to the socket
+    batch_size: usize,
 }
 
 impl<T, S> Receiver<T, S>
@@ -45,9 +47,20 @@ where
             rx,
             ring_cooldown: cooldown.clone(),
             io_cooldown: cooldown.clone(),
+            batch_size: usize::MAX,
         }
     }
 
+    /// Limits the number of messages received in a single call to the socket
+    ///
+    /// By default, all of the free slots in the ring are filled.
+    #[inline]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        debug_assert_ne!(batch_size, 0, "batch size must be non-zero");
+        self.batch_size = batch_size;
+        self
+    }
+
     #[inline]
     fn poll_ring(&mut self, watermark: u32, cx: &mut Context) -> Poll<Result<(), ()>> {
         loop {
@@ -106,6 +119,8 @@ where
             }
 
             let entries = this.ring.data();
+            let len = entries.len().min(this.batch_size);
+            let entries = &mut entries[..len];
 
             // perform the recv syscall
             match this.rx.recv(cx, entries, &mut events) {
@@ -74,22 +74,61 @@ pub fn udp_socket(addr: std::net::SocketAddr) -> io::Result<Socket> {
     Ok(socket)
 }
 
-/// Creates a UDP socket bound to the provided address
-pub fn bind_udp<A: std::net::ToSocketAddrs>(addr: A, reuse_port: bool) -> io::Result<Socket> {
-    let addr = addr.to_socket_addrs()?.next().ok_or_else(|| {
+/// Creates a UDP socket bound to the provided address and, optionally, network interface
+///
+/// If `dual_stack` is set, IPV6_V6ONLY is explicitly configured on the socket. Enabling dual stack
+/// on an unspecified IPv4 address binds the IPv6 unspecified address on the same port instead.
+pub fn bind_udp<A: std::net::ToSocketAddrs>(
+    addr: A,
+    reuse_port: bool,
+    interface: Option<&str>,
+    dual_stack: Option<bool>,
+) -> io::Result<Socket> {
+    let mut addr = addr.to_socket_addrs()?.next().ok_or_else(|| {
         std::io::Error::new(
             io::ErrorKind::InvalidInput,
             "the provided bind address was empty",
         )
     })?;
+
+    if dual_stack == Some(true) && addr.is_ipv4() {
+        if !addr.ip().is_unspecified() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "dual stack sockets can only be bound to an IPv6 or unspecified address",
+            ));
+        }
+        addr = (std::net::Ipv6Addr::UNSPECIFIED, addr.port()).into();
+    }
+
     let socket = udp_socket(addr)?;
 
+    if let Some(dual_stack) = dual_stack {
+        if addr.is_ipv6() {
+            socket.set_only_v6(!dual_stack)?;
+        }
+    }
+
     #[cfg(unix)]
     socket.set_reuse_port(reuse_port)?;
 
     // mark the variable as "used" regardless of platform support
     let _ = reuse_port;
 
+    if let Some(interface) = interface {
+        #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+        socket.bind_device(Some(interface.as_bytes()))?;
+
+        #[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+        {
+            let _ = interface;
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "binding to an interface is not supported on the current platform",
+            ));
+        }
+    }
+
     socket.bind(&addr.into())?;
 
     Ok(socket)
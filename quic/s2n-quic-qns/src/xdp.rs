@@ -329,7 +329,7 @@ impl Xdp {
             .with_rx(io_rx)
             .with_tx(io_tx)
             .with_frame_size(self.frame_size as _)?
-            .build();
+            .build()?;
 
         self.spawn_udp_rx(udp_socket);
 
@@ -372,7 +372,7 @@ impl Xdp {
             .with_rx(io_rx)
             .with_tx(io_tx)
             .with_frame_size(self.frame_size as _)?
-            .build();
+            .build()?;
 
         if let Ok(udp_socket) = recv_udp_socket {
             let udp_socket = UdpSocket::from_std(udp_socket)?;
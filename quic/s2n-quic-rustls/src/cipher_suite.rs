@@ -1,10 +1,23 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use rustls::{cipher_suite as ciphers, quic, CipherSuite, SupportedCipherSuite};
+use rustls::{
+    cipher_suite as ciphers, kx_group, quic, CipherSuite, SupportedCipherSuite, SupportedKxGroup,
+};
 use s2n_codec::Encoder;
 use s2n_quic_core::crypto::{self, scatter, tls, CryptoError, HeaderProtectionMask, Key};
 
+pub(crate) fn to_tls_cipher_suite(cipher_suite: CipherSuite) -> tls::CipherSuite {
+    match cipher_suite {
+        CipherSuite::TLS13_AES_128_GCM_SHA256 => tls::CipherSuite::TLS_AES_128_GCM_SHA256,
+        CipherSuite::TLS13_AES_256_GCM_SHA384 => tls::CipherSuite::TLS_AES_256_GCM_SHA384,
+        CipherSuite::TLS13_CHACHA20_POLY1305_SHA256 => {
+            tls::CipherSuite::TLS_CHACHA20_POLY1305_SHA256
+        }
+        _ => tls::CipherSuite::Unknown,
+    }
+}
+
 pub struct PacketKey {
     key: quic::PacketKey,
     cipher_suite: tls::CipherSuite,
@@ -89,14 +102,7 @@ impl PacketKeys {
     pub(crate) fn new(keys: quic::Keys, cipher_suite: CipherSuite) -> (Self, HeaderProtectionKeys) {
         let quic::Keys { local, remote } = keys;
 
-        let cipher_suite = match cipher_suite {
-            CipherSuite::TLS13_AES_128_GCM_SHA256 => tls::CipherSuite::TLS_AES_128_GCM_SHA256,
-            CipherSuite::TLS13_AES_256_GCM_SHA384 => tls::CipherSuite::TLS_AES_256_GCM_SHA384,
-            CipherSuite::TLS13_CHACHA20_POLY1305_SHA256 => {
-                tls::CipherSuite::TLS_CHACHA20_POLY1305_SHA256
-            }
-            _ => tls::CipherSuite::Unknown,
-        };
+        let cipher_suite = to_tls_cipher_suite(cipher_suite);
 
         let (sealer_packet, sealer_header) = PacketKey::new(local, cipher_suite);
         let (opener_packet, opener_header) = PacketKey::new(remote, cipher_suite);
@@ -337,6 +343,34 @@ pub static DEFAULT_CIPHERSUITES: &[SupportedCipherSuite] = &[
     ciphers::TLS13_CHACHA20_POLY1305_SHA256,
 ];
 
+pub(crate) fn from_tls_cipher_suite(
+    cipher_suite: tls::CipherSuite,
+) -> Result<SupportedCipherSuite, rustls::Error> {
+    match cipher_suite {
+        tls::CipherSuite::TLS_AES_128_GCM_SHA256 => Ok(ciphers::TLS13_AES_128_GCM_SHA256),
+        tls::CipherSuite::TLS_AES_256_GCM_SHA384 => Ok(ciphers::TLS13_AES_256_GCM_SHA384),
+        tls::CipherSuite::TLS_CHACHA20_POLY1305_SHA256 => {
+            Ok(ciphers::TLS13_CHACHA20_POLY1305_SHA256)
+        }
+        tls::CipherSuite::Unknown => Err(rustls::Error::General(
+            "unsupported cipher suite".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn from_tls_key_exchange_group(
+    group: tls::KeyExchangeGroup,
+) -> Result<&'static SupportedKxGroup, rustls::Error> {
+    match group {
+        tls::KeyExchangeGroup::x25519 => Ok(&kx_group::X25519),
+        tls::KeyExchangeGroup::secp256r1 => Ok(&kx_group::SECP256R1),
+        tls::KeyExchangeGroup::secp384r1 => Ok(&kx_group::SECP384R1),
+        tls::KeyExchangeGroup::secp521r1 | tls::KeyExchangeGroup::Unknown => Err(
+            rustls::Error::General("unsupported key exchange group".to_string()),
+        ),
+    }
+}
+
 #[test]
 fn test_default_cipher_suites() {
     insta::assert_debug_snapshot!("default_cipher_suites", DEFAULT_CIPHERSUITES);
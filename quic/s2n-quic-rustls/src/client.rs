@@ -87,6 +87,8 @@ impl tls::Endpoint for Client {
 pub struct Builder {
     cert_store: rustls::RootCertStore,
     application_protocols: Vec<Vec<u8>>,
+    cipher_suites: Vec<rustls::SupportedCipherSuite>,
+    kx_groups: Vec<&'static rustls::SupportedKxGroup>,
     key_log: Option<Arc<dyn rustls::KeyLog>>,
 }
 
@@ -101,6 +103,8 @@ impl Builder {
         Self {
             cert_store: rustls::RootCertStore::empty(),
             application_protocols: vec![b"h3".to_vec()],
+            cipher_suites: crate::cipher_suite::DEFAULT_CIPHERSUITES.to_vec(),
+            kx_groups: rustls::ALL_KX_GROUPS.to_vec(),
             key_log: None,
         }
     }
@@ -133,6 +137,32 @@ impl Builder {
         Ok(self)
     }
 
+    /// Restricts the cipher suites offered during the handshake, in order of preference
+    pub fn with_cipher_suites(
+        mut self,
+        cipher_suites: &[tls::CipherSuite],
+    ) -> Result<Self, rustls::Error> {
+        self.cipher_suites = cipher_suites
+            .iter()
+            .copied()
+            .map(crate::cipher_suite::from_tls_cipher_suite)
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
+    /// Restricts the key exchange groups offered during the handshake, in order of preference
+    pub fn with_key_exchange_groups(
+        mut self,
+        groups: &[tls::KeyExchangeGroup],
+    ) -> Result<Self, rustls::Error> {
+        self.kx_groups = groups
+            .iter()
+            .copied()
+            .map(crate::cipher_suite::from_tls_key_exchange_group)
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
     pub fn with_key_logging(mut self) -> Result<Self, rustls::Error> {
         self.key_log = Some(Arc::new(rustls::KeyLogFile::new()));
         Ok(self)
@@ -149,8 +179,8 @@ impl Builder {
         }
 
         let mut config = ClientConfig::builder()
-            .with_cipher_suites(crate::cipher_suite::DEFAULT_CIPHERSUITES)
-            .with_safe_default_kx_groups()
+            .with_cipher_suites(&self.cipher_suites)
+            .with_kx_groups(&self.kx_groups)
             .with_protocol_versions(crate::PROTOCOL_VERSIONS)?
             .with_root_certificates(self.cert_store)
             .with_no_client_auth();
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use rustls::Error;
+use rustls::{Error, PeerIncompatible};
 
 pub fn reason(error: rustls::Error) -> &'static str {
     match error {
@@ -19,6 +19,13 @@ pub fn reason(error: rustls::Error) -> &'static str {
         Error::PeerSentOversizedRecord => "peer sent excess record size",
         Error::NoApplicationProtocol => "peer doesn't support any known protocol",
         Error::BadMaxFragmentSize => "bad max fragment size",
+        Error::PeerIncompatible(PeerIncompatible::NoCipherSuitesInCommon) => {
+            "no cipher suites in common"
+        }
+        Error::PeerIncompatible(PeerIncompatible::NoKxGroupsInCommon) => {
+            "no key exchange groups in common"
+        }
+        Error::PeerIncompatible(_) => "peer is incompatible",
         Error::General(_) => "unexpected error",
         // rustls may add a new variant in the future that breaks us so do a wildcard
         #[allow(unreachable_patterns)]
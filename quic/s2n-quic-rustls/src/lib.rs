@@ -48,3 +48,68 @@ fn client_server_test() {
 
     pair.finish();
 }
+
+#[test]
+fn no_common_cipher_suites_test() {
+    use s2n_quic_core::crypto::tls::{self, testing::certificates::*, CipherSuite};
+
+    let mut client = client::Builder::new()
+        .with_certificate(CERT_PEM)
+        .unwrap()
+        .with_cipher_suites(&[CipherSuite::TLS_CHACHA20_POLY1305_SHA256])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut server = server::Builder::new()
+        .with_certificate(CERT_PEM, KEY_PEM)
+        .unwrap()
+        .with_cipher_suites(&[CipherSuite::TLS_AES_128_GCM_SHA256])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+    let error = loop {
+        assert!(pair.is_handshaking(), "handshake should not complete");
+        if let Err(error) = pair.poll(None) {
+            break error;
+        }
+    };
+
+    assert_eq!(error.description(), Some("HANDSHAKE_FAILURE"));
+    assert_eq!(error.reason, "no cipher suites in common");
+}
+
+#[test]
+fn no_common_key_exchange_groups_test() {
+    use s2n_quic_core::crypto::tls::{self, testing::certificates::*, KeyExchangeGroup};
+
+    let mut client = client::Builder::new()
+        .with_certificate(CERT_PEM)
+        .unwrap()
+        .with_key_exchange_groups(&[KeyExchangeGroup::x25519])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut server = server::Builder::new()
+        .with_certificate(CERT_PEM, KEY_PEM)
+        .unwrap()
+        .with_key_exchange_groups(&[KeyExchangeGroup::secp384r1])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+    let error = loop {
+        assert!(pair.is_handshaking(), "handshake should not complete");
+        if let Err(error) = pair.poll(None) {
+            break error;
+        }
+    };
+
+    assert_eq!(error.description(), Some("HANDSHAKE_FAILURE"));
+}
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cipher_suite::{
-    HeaderProtectionKey, HeaderProtectionKeys, OneRttKey, PacketKey, PacketKeys,
+    to_tls_cipher_suite, HeaderProtectionKey, HeaderProtectionKeys, OneRttKey, PacketKey,
+    PacketKeys,
 };
 use bytes::Bytes;
 use core::{fmt, fmt::Debug, task::Poll};
@@ -123,6 +124,45 @@ impl Session {
         }
     }
 
+    fn peer_certificate_chain(&self) -> Option<Vec<Bytes>> {
+        let chain = match &self.connection {
+            Connection::Client(client) => client.peer_certificates(),
+            Connection::Server(server) => server.peer_certificates(),
+        }?;
+
+        Some(
+            chain
+                .iter()
+                .map(|cert| Bytes::copy_from_slice(&cert.0))
+                .collect(),
+        )
+    }
+
+    fn tls_info(&self) -> tls::TlsInfo {
+        let cipher_suite = self
+            .connection
+            .negotiated_cipher_suite()
+            .map_or(tls::CipherSuite::Unknown, |suite| {
+                to_tls_cipher_suite(suite.suite())
+            });
+
+        let protocol_version = match &self.connection {
+            Connection::Client(client) => client.protocol_version(),
+            Connection::Server(server) => server.protocol_version(),
+        };
+        let protocol_version = match protocol_version {
+            Some(rustls::ProtocolVersion::TLSv1_3) => tls::ProtocolVersion::TLS_1_3,
+            _ => tls::ProtocolVersion::Unknown,
+        };
+
+        // rustls doesn't expose the negotiated key exchange group
+        tls::TlsInfo::new(
+            cipher_suite,
+            tls::KeyExchangeGroup::Unknown,
+            protocol_version,
+        )
+    }
+
     fn zero_rtt_keys(&mut self) -> Option<quic::DirectionalKeys> {
         if self.emitted_zero_rtt_keys {
             return None;
@@ -148,6 +188,10 @@ impl Session {
             // the handshake is complete!
             if !self.emitted_handshake_complete {
                 self.rx_phase.transition();
+                context.on_tls_info(self.tls_info());
+                if let Some(chain) = self.peer_certificate_chain() {
+                    context.on_peer_certificate_chain(chain);
+                }
                 context.on_handshake_complete()?;
             }
 
@@ -5,7 +5,7 @@ use bytes::{Bytes, BytesMut};
 use core::{ffi::c_void, marker::PhantomData};
 use s2n_quic_core::{
     application::ServerName,
-    crypto::{tls, CryptoError, CryptoSuite},
+    crypto::{tls, CryptoError, CryptoSuite, Key as _},
     endpoint, transport,
 };
 use s2n_quic_crypto::{
@@ -223,6 +223,8 @@ where
                     _ => {
                         let (key, header_key) =
                             OneRttKey::new(self.endpoint, aead_algo, pair).expect("invalid cipher");
+                        self.context
+                            .on_tls_info(unsafe { get_tls_info(conn, key.cipher_suite()) });
                         // At this point the server is done writing Handshake messages
                         if self.endpoint.is_server() {
                             self.state.tx_phase.transition();
@@ -238,6 +240,14 @@ where
                             let application_protocol =
                                 Bytes::copy_from_slice(get_application_protocol(conn)?);
                             self.context.on_application_protocol(application_protocol)?;
+                            // the server's certificate has been validated by the time the client
+                            // derives the application secrets
+                            if self.endpoint.is_client() {
+                                if let Some(response) = get_ocsp_response(conn) {
+                                    self.context
+                                        .on_ocsp_response(Bytes::copy_from_slice(response));
+                                }
+                            }
                             get_application_params(conn)?
                         };
 
@@ -442,6 +452,35 @@ fn get_algo_type(
     }
 }
 
+unsafe fn get_tls_info(
+    connection: *mut s2n_connection,
+    cipher_suite: tls::CipherSuite,
+) -> tls::TlsInfo {
+    let curve = s2n_connection_get_curve(connection);
+    let curve = if curve.is_null() {
+        None
+    } else {
+        get_cstr_slice(curve)
+    };
+
+    let key_exchange_group = match curve {
+        Some(b"secp256r1") => tls::KeyExchangeGroup::secp256r1,
+        Some(b"secp384r1") => tls::KeyExchangeGroup::secp384r1,
+        Some(b"secp521r1") => tls::KeyExchangeGroup::secp521r1,
+        Some(b"x25519") => tls::KeyExchangeGroup::x25519,
+        _ => tls::KeyExchangeGroup::Unknown,
+    };
+
+    let protocol_version =
+        if s2n_connection_get_actual_protocol_version(connection) == S2N_TLS13 as _ {
+            tls::ProtocolVersion::TLS_1_3
+        } else {
+            tls::ProtocolVersion::Unknown
+        };
+
+    tls::TlsInfo::new(cipher_suite, key_exchange_group, protocol_version)
+}
+
 unsafe fn get_application_params<'a>(
     connection: *mut s2n_connection,
 ) -> Result<tls::ApplicationParameters<'a>, CryptoError> {
@@ -498,6 +537,12 @@ unsafe fn get_transport_parameters<'a>(connection: *mut s2n_connection) -> Optio
     get_slice(ptr, len as _)
 }
 
+unsafe fn get_ocsp_response<'a>(connection: *mut s2n_connection) -> Option<&'a [u8]> {
+    let mut len = 0u32;
+    let ptr = s2n_connection_get_ocsp_response(connection, &mut len);
+    get_slice(ptr, len as _)
+}
+
 unsafe fn get_cstr_slice<'a>(ptr: *const libc::c_char) -> Option<&'a [u8]> {
     let len = libc::strlen(ptr);
     get_slice(ptr as *const _, len)
@@ -15,6 +15,7 @@ use s2n_tls::{
     config::{self, Config},
     enums::ClientAuthType,
     error::Error,
+    security::Policy,
 };
 use std::sync::Arc;
 
@@ -92,6 +93,20 @@ impl Builder {
         Ok(self)
     }
 
+    /// Restricts the cipher suites, key exchange groups and signature schemes offered during the
+    /// handshake to those in the named s2n-tls security policy
+    ///
+    /// s2n-tls doesn't support configuring individual cipher suites so this is the equivalent of
+    /// `with_cipher_suites` and `with_key_exchange_groups` in other providers. The policy must
+    /// support TLS 1.3. See the
+    /// [s2n-tls usage guide](https://github.com/aws/s2n-tls/blob/main/docs/USAGE-GUIDE.md#security-policies)
+    /// for the available policies.
+    pub fn with_security_policy(mut self, version: &str) -> Result<Self, Error> {
+        let policy = Policy::from_version(version)?;
+        self.config.set_security_policy(&policy)?;
+        Ok(self)
+    }
+
     pub fn with_certificate<C: IntoCertificate>(mut self, certificate: C) -> Result<Self, Error> {
         let certificate = certificate.into_certificate()?;
         let certificate = certificate
@@ -102,6 +117,15 @@ impl Builder {
         Ok(self)
     }
 
+    /// Requests the server to staple an OCSP response for its certificate
+    ///
+    /// The stapled response is validated against the trust store and made available to the
+    /// application once the handshake completes.
+    pub fn with_ocsp_stapling(mut self) -> Result<Self, Error> {
+        self.config.enable_ocsp()?;
+        Ok(self)
+    }
+
     /// Clears the default trust store for this client
     ///
     /// By default, the trust store is initialized with common
@@ -54,9 +54,11 @@ mod session;
 
 pub mod certificate;
 pub mod client;
+pub mod resolver;
 pub mod server;
 
 pub use client::Client;
+pub use resolver::{CertificateResolver, CertifiedKey};
 pub use server::Server;
 
 // Re-export the `ClientHelloHandler` and `Connection` to make it easier for users
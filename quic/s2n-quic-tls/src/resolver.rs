@@ -0,0 +1,141 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    certificate::{Certificate, IntoCertificate, IntoPrivateKey, PrivateKey},
+    keylog::KeyLogHandle,
+    server::Builder,
+    ConfigLoader, ConnectionContext,
+};
+use bytes::Bytes;
+use s2n_tls::{config::Config, error::Error};
+use std::sync::Arc;
+
+/// A certificate chain and the private key used to serve it
+pub struct CertifiedKey {
+    certificate: Certificate,
+    private_key: PrivateKey,
+    ocsp_response: Option<Bytes>,
+}
+
+impl CertifiedKey {
+    pub fn new<C: IntoCertificate, PK: IntoPrivateKey>(
+        certificate: C,
+        private_key: PK,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            certificate: certificate.into_certificate()?,
+            private_key: private_key.into_private_key()?,
+            ocsp_response: None,
+        })
+    }
+
+    /// Staples the DER-encoded OCSP response to the certificate
+    ///
+    /// Responses expire so they should be refreshed periodically by returning a new
+    /// `CertifiedKey` from the [`CertificateResolver`].
+    pub fn with_ocsp_response<R: Into<Bytes>>(mut self, response: R) -> Self {
+        self.ocsp_response = Some(response.into());
+        self
+    }
+}
+
+/// Selects the certificate for new connections
+///
+/// This trait can be implemented to rotate certificates without restarting the server. The
+/// resolver is called for each new connection and the server configuration is only rebuilt when
+/// a different [`CertifiedKey`] is returned.
+///
+/// Note that s2n-tls creates the connection before the ClientHello is received so the
+/// `server_name` in the context is not available. Multiple certificates can instead be loaded
+/// into the configuration in `builder`, in which case s2n-tls picks one based on the SNI.
+pub trait CertificateResolver: 'static + Send {
+    fn resolve(&mut self, cx: ConnectionContext) -> Arc<CertifiedKey>;
+}
+
+impl<T: FnMut(ConnectionContext) -> Arc<CertifiedKey> + Send + 'static> CertificateResolver for T {
+    #[inline]
+    fn resolve(&mut self, cx: ConnectionContext) -> Arc<CertifiedKey> {
+        (self)(cx)
+    }
+}
+
+impl CertificateResolver for Box<dyn CertificateResolver> {
+    #[inline]
+    fn resolve(&mut self, cx: ConnectionContext) -> Arc<CertifiedKey> {
+        (**self).resolve(cx)
+    }
+}
+
+/// A [`ConfigLoader`] which builds a `Config` for the certificate selected by a
+/// [`CertificateResolver`]
+pub struct Loader<R, B> {
+    resolver: R,
+    builder: B,
+    current: (Arc<CertifiedKey>, Config),
+    /// Configs built by previous builders may still be in use by existing connections so a single
+    /// key log handle is shared by all of them and kept alive for the lifetime of the loader
+    keylog: Option<KeyLogHandle>,
+}
+
+impl<R, B> Loader<R, B>
+where
+    R: CertificateResolver,
+    B: 'static + Send + FnMut() -> Result<Builder, Error>,
+{
+    /// Creates a loader with the initial certificate returned by `resolver`
+    ///
+    /// Returns an error if the initial certificate can't be loaded.
+    pub fn new(mut resolver: R, mut builder: B) -> Result<Self, Error> {
+        let key = resolver.resolve(ConnectionContext { server_name: None });
+        let mut keylog = None;
+        let config = Self::build(&mut builder, &mut keylog, &key)?;
+
+        Ok(Self {
+            resolver,
+            builder,
+            current: (key, config),
+            keylog,
+        })
+    }
+
+    fn build(
+        builder: &mut B,
+        keylog: &mut Option<KeyLogHandle>,
+        key: &CertifiedKey,
+    ) -> Result<Config, Error> {
+        let mut builder = builder()?;
+        builder.load_certificate(&key.certificate, &key.private_key)?;
+        if let Some(response) = key.ocsp_response.as_ref() {
+            builder = builder.with_ocsp_response(response)?;
+        }
+        let (config, new_keylog) = builder.build_config(keylog.as_ref())?;
+        if keylog.is_none() {
+            *keylog = new_keylog;
+        }
+        Ok(config)
+    }
+}
+
+impl<R, B> ConfigLoader for Loader<R, B>
+where
+    R: CertificateResolver,
+    B: 'static + Send + FnMut() -> Result<Builder, Error>,
+{
+    fn load(&mut self, cx: ConnectionContext) -> Config {
+        let key = self.resolver.resolve(cx);
+        let (current, config) = &mut self.current;
+
+        if !Arc::ptr_eq(current, &key) {
+            // keep serving the previous certificate if the new one can't be loaded
+            if let Ok(new_config) = Self::build(&mut self.builder, &mut self.keylog, &key) {
+                *config = new_config;
+            }
+
+            // remember the key even on failure so the builder isn't called for every connection
+            *current = key;
+        }
+
+        config.clone()
+    }
+}
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    certificate::{Format, IntoCertificate, IntoPrivateKey},
+    certificate::{Certificate, Format, IntoCertificate, IntoPrivateKey, PrivateKey},
     keylog::KeyLogHandle,
     params::Params,
+    resolver::{self, CertificateResolver},
     session::Session,
     ConfigLoader,
 };
@@ -19,6 +20,7 @@ use s2n_tls::{
     config::{self, Config},
     enums::ClientAuthType,
     error::Error,
+    security::Policy,
 };
 use std::sync::Arc;
 
@@ -52,6 +54,26 @@ impl<L: ConfigLoader> Server<L> {
     }
 }
 
+impl<R, B> Server<resolver::Loader<R, B>>
+where
+    R: CertificateResolver,
+    B: 'static + Send + FnMut() -> Result<Builder, Error>,
+{
+    /// Creates a [`Server`] which calls `resolver` to select the certificate for each new
+    /// connection
+    ///
+    /// `builder` configures all of the other server settings and is called each time the
+    /// resolver returns a different certificate. Connections which were established with the
+    /// previous certificate are unaffected.
+    ///
+    /// The resolver is called once to load the initial certificate, which returns an error if it
+    /// can't be loaded. If a later certificate fails to load, the server keeps using the previous
+    /// one.
+    pub fn from_resolver(resolver: R, builder: B) -> Result<Self, Error> {
+        Ok(Self::from_loader(resolver::Loader::new(resolver, builder)?))
+    }
+}
+
 impl Default for Server {
     fn default() -> Self {
         Self::builder()
@@ -120,21 +142,54 @@ impl Builder {
         Ok(self)
     }
 
+    /// Restricts the cipher suites, key exchange groups and signature schemes offered during the
+    /// handshake to those in the named s2n-tls security policy
+    ///
+    /// s2n-tls doesn't support configuring individual cipher suites so this is the equivalent of
+    /// `with_cipher_suites` and `with_key_exchange_groups` in other providers. The policy must
+    /// support TLS 1.3. See the
+    /// [s2n-tls usage guide](https://github.com/aws/s2n-tls/blob/main/docs/USAGE-GUIDE.md#security-policies)
+    /// for the available policies.
+    pub fn with_security_policy(mut self, version: &str) -> Result<Self, Error> {
+        let policy = Policy::from_version(version)?;
+        self.config.set_security_policy(&policy)?;
+        Ok(self)
+    }
+
     pub fn with_certificate<C: IntoCertificate, PK: IntoPrivateKey>(
         mut self,
         certificate: C,
         private_key: PK,
     ) -> Result<Self, Error> {
-        let private_key = private_key.into_private_key()?.0;
-        let certificate = certificate.into_certificate()?.0;
+        let private_key = private_key.into_private_key()?;
+        let certificate = certificate.into_certificate()?;
+        self.load_certificate(&certificate, &private_key)?;
+        Ok(self)
+    }
+
+    pub(crate) fn load_certificate(
+        &mut self,
+        certificate: &Certificate,
+        private_key: &PrivateKey,
+    ) -> Result<(), Error> {
         let certificate = certificate
+            .0
             .as_pem()
             .expect("pem is currently the only certificate format supported");
-        match private_key {
+        match &private_key.0 {
             Format::Pem(bytes) => self.config.load_pem(certificate, bytes.as_ref())?,
             Format::None => self.config.load_public_pem(certificate)?,
             Format::Der(_) => panic!("der private keys not supported"),
         };
+        Ok(())
+    }
+
+    /// Staples the DER-encoded OCSP response to the certificate for clients which request it
+    ///
+    /// This must be called after `with_certificate`. Use a [`CertificateResolver`] to refresh
+    /// the response without restarting the server.
+    pub fn with_ocsp_response<R: AsRef<[u8]>>(mut self, response: R) -> Result<Self, Error> {
+        self.config.set_ocsp_data(response.as_ref())?;
         Ok(self)
     }
 
@@ -197,7 +252,15 @@ impl Builder {
     pub fn with_key_logging(mut self) -> Result<Self, Error> {
         use crate::keylog::KeyLog;
 
-        self.keylog = KeyLog::try_open();
+        self.set_key_log(KeyLog::try_open())?;
+
+        Ok(self)
+    }
+
+    fn set_key_log(&mut self, keylog: Option<KeyLogHandle>) -> Result<(), Error> {
+        use crate::keylog::KeyLog;
+
+        self.keylog = keylog;
 
         unsafe {
             // Safety: the KeyLog is stored on `self` to ensure it outlives `config`
@@ -211,7 +274,21 @@ impl Builder {
             }
         }
 
-        Ok(self)
+        Ok(())
+    }
+
+    /// Builds the `Config` along with the key log handle it refers to
+    ///
+    /// If key logging is enabled, the builder's handle is replaced with `keylog` so configs which
+    /// are rebuilt share a single key log.
+    pub(crate) fn build_config(
+        mut self,
+        keylog: Option<&KeyLogHandle>,
+    ) -> Result<(Config, Option<KeyLogHandle>), Error> {
+        if let Some(keylog) = keylog.filter(|_| self.keylog.is_some()) {
+            self.set_key_log(Some(keylog.clone()))?;
+        }
+        Ok((self.config.build()?, self.keylog))
     }
 
     pub fn build(self) -> Result<Server, Error> {
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::callback::{self, Callback};
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use core::{marker::PhantomData, task::Poll};
 use s2n_quic_core::{
     application::ServerName,
@@ -63,6 +63,23 @@ impl Session {
             server_name,
         })
     }
+
+    /// Returns the DER-encoded certificate chain presented by the peer, if any
+    fn peer_certificate_chain(&self) -> Option<Vec<Bytes>> {
+        // s2n-tls returns an error if the peer did not send a certificate
+        let chain = self.connection.peer_cert_chain().ok()?;
+
+        let chain: Vec<Bytes> = chain
+            .iter()
+            .filter_map(|cert| cert.ok()?.der().ok().map(Bytes::copy_from_slice))
+            .collect();
+
+        if chain.is_empty() {
+            None
+        } else {
+            Some(chain)
+        }
+    }
 }
 
 impl CryptoSuite for Session {
@@ -108,6 +125,12 @@ impl tls::Session for Session {
                 // s2n-tls has indicated that the handshake is complete
                 if !self.handshake_complete {
                     self.state.on_handshake_complete();
+                    if self.connection.resumed() {
+                        context.on_session_resumed();
+                    }
+                    if let Some(chain) = self.peer_certificate_chain() {
+                        context.on_peer_certificate_chain(chain);
+                    }
                     context.on_handshake_complete()?;
                     self.handshake_complete = true;
                 }
@@ -488,3 +488,167 @@ fn config_loader() {
     // make sure the server can actually create a session
     let _ = server.new_server_session(&1);
 }
+
+#[test]
+fn certificate_resolver() {
+    use crate::{CertifiedKey, Server};
+    use s2n_quic_core::crypto::tls::testing::certificates::{
+        MTLS_CA_CERT, MTLS_SERVER_CERT, MTLS_SERVER_KEY,
+    };
+    use std::sync::Mutex;
+
+    let initial = Arc::new(CertifiedKey::new(CERT_PEM, KEY_PEM).unwrap());
+    let current = Arc::new(Mutex::new(initial));
+
+    let resolver = {
+        let current = current.clone();
+        move |_cx: crate::ConnectionContext| current.lock().unwrap().clone()
+    };
+    let mut server = Server::from_resolver(resolver, || Ok(server::Builder::default())).unwrap();
+
+    let mut client = client::Builder::default()
+        .with_certificate(CERT_PEM)
+        .unwrap()
+        .with_certificate(MTLS_CA_CERT)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let peer_certificate = |server: &mut Server<_>, client: &mut client::Client| {
+        let pair = run_result(server, client, None).unwrap();
+        pair.client.context.peer_certificate_chain[0].clone()
+    };
+
+    let original = peer_certificate(&mut server, &mut client);
+    assert_eq!(original, peer_certificate(&mut server, &mut client));
+
+    // rotate the certificate while the server is running
+    *current.lock().unwrap() =
+        Arc::new(CertifiedKey::new(MTLS_SERVER_CERT, MTLS_SERVER_KEY).unwrap());
+
+    let rotated = peer_certificate(&mut server, &mut client);
+    assert_ne!(
+        original, rotated,
+        "new handshakes should use the new certificate"
+    );
+    assert_eq!(rotated, peer_certificate(&mut server, &mut client));
+}
+
+#[test]
+fn certificate_resolver_invalid_certificate() {
+    use crate::{CertifiedKey, Server};
+    use s2n_quic_core::crypto::tls::testing::certificates::MTLS_SERVER_KEY;
+
+    // the private key doesn't match the certificate
+    let key = Arc::new(CertifiedKey::new(CERT_PEM, MTLS_SERVER_KEY).unwrap());
+    let server = Server::from_resolver(
+        move |_cx: crate::ConnectionContext| key.clone(),
+        || Ok(server::Builder::default()),
+    );
+
+    assert!(server.is_err());
+}
+
+#[test]
+fn s2n_client_s2n_server_ocsp_test() {
+    use crate::{CertifiedKey, Server};
+    use s2n_quic_core::crypto::tls::testing::certificates::OCSP_DER;
+
+    let mut server = server::Builder::default()
+        .with_certificate(CERT_PEM, KEY_PEM)
+        .unwrap()
+        .with_ocsp_response(OCSP_DER)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut client = client::Builder::default()
+        .with_certificate(CERT_PEM)
+        .unwrap()
+        .with_ocsp_stapling()
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let pair = run_result(&mut server, &mut client, None).unwrap();
+    assert_eq!(pair.client.context.ocsp_response.as_deref(), Some(OCSP_DER));
+
+    // the response is only stapled when the client requests it
+    let pair = run_result(&mut server, &mut s2n_client(), None).unwrap();
+    assert!(pair.client.context.ocsp_response.is_none());
+
+    // the response can also be provided by a resolver
+    let key = Arc::new(
+        CertifiedKey::new(CERT_PEM, KEY_PEM)
+            .unwrap()
+            .with_ocsp_response(OCSP_DER),
+    );
+    let mut server = Server::from_resolver(
+        move |_cx: crate::ConnectionContext| key.clone(),
+        || Ok(server::Builder::default()),
+    )
+    .unwrap();
+
+    let pair = run_result(&mut server, &mut client, None).unwrap();
+    assert_eq!(pair.client.context.ocsp_response.as_deref(), Some(OCSP_DER));
+}
+
+#[test]
+fn tls_info_test() {
+    use s2n_quic_core::crypto::tls::{CipherSuite, KeyExchangeGroup, ProtocolVersion};
+    use s2n_quic_rustls::rustls;
+
+    // constrain the client to a single cipher suite
+    let cert = openssl::x509::X509::from_pem(CERT_PEM.as_bytes()).unwrap();
+    let mut roots = rustls::RootCertStore::empty();
+    roots
+        .add(&rustls::Certificate(cert.to_der().unwrap()))
+        .unwrap();
+    let mut config = rustls::ClientConfig::builder()
+        .with_cipher_suites(&[rustls::cipher_suite::TLS13_CHACHA20_POLY1305_SHA256])
+        .with_safe_default_kx_groups()
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    config.alpn_protocols = vec![b"h3".to_vec()];
+    let mut client_endpoint = s2n_quic_rustls::Client::new(config);
+    let mut server_endpoint = s2n_server();
+
+    let pair = run_result(&mut server_endpoint, &mut client_endpoint, None).unwrap();
+
+    let server_info = pair.server.context.tls_info.unwrap();
+    let client_info = pair.client.context.tls_info.unwrap();
+    for info in [server_info, client_info] {
+        assert_eq!(info.cipher_suite, CipherSuite::TLS_CHACHA20_POLY1305_SHA256);
+        assert_eq!(info.protocol_version, ProtocolVersion::TLS_1_3);
+    }
+
+    // rustls doesn't expose the negotiated group but s2n-tls does
+    assert_ne!(server_info.key_exchange_group, KeyExchangeGroup::Unknown);
+    assert_eq!(client_info.key_exchange_group, KeyExchangeGroup::Unknown);
+}
+
+#[test]
+fn security_policy_test() {
+    let mut client_endpoint = client::Builder::default()
+        .with_security_policy("default_tls13")
+        .unwrap()
+        .with_certificate(CERT_PEM)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut server_endpoint = server::Builder::default()
+        .with_security_policy("default_tls13")
+        .unwrap()
+        .with_certificate(CERT_PEM, KEY_PEM)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    run(&mut server_endpoint, &mut client_endpoint, None);
+
+    assert!(server::Builder::default()
+        .with_security_policy("not a policy")
+        .is_err());
+}
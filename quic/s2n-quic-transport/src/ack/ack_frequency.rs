@@ -0,0 +1,362 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    contexts::{OnTransmitError, WriteContext},
+    sync::flag,
+    transmission,
+};
+use core::time::Duration;
+use s2n_quic_core::{
+    ack, frame,
+    packet::number::PacketNumber,
+    transport::{
+        self,
+        parameters::{MaxAckDelay, MinAckDelay},
+    },
+    varint::VarInt,
+};
+
+/// The number of ack-eliciting packets the peer is requested to receive before sending an ACK
+const REQUESTED_ACK_ELICITING_THRESHOLD: VarInt = VarInt::from_u8(31);
+
+/// The default number of processed packets before an ACK is sent immediately
+const DEFAULT_PACKET_TOLERANCE: u8 = 10;
+
+/// Requested max_ack_delay values at or above this limit are rejected, mirroring the limit
+/// placed on the `min_ack_delay` transport parameter
+const MAX_REQUEST_MAX_ACK_DELAY: Duration = Duration::from_micros(1 << 24);
+
+pub type Flag = flag::Flag<AckFrequencyWriter>;
+
+/// Tracks the state of the ACK_FREQUENCY extension for a packet space
+///
+/// The local endpoint requests the peer to send fewer acknowledgements once the handshake is
+/// confirmed, and applies requests sent by the peer to its own acknowledgements.
+///
+/// See <https://datatracker.ietf.org/doc/draft-ietf-quic-ack-frequency/>
+#[derive(Clone, Debug)]
+pub struct AckFrequency {
+    /// The `min_ack_delay` advertised to the peer, if the local endpoint supports receiving
+    /// ACK_FREQUENCY frames
+    local_min_ack_delay: Option<MinAckDelay>,
+
+    /// The sequence number of the last ACK_FREQUENCY frame applied
+    largest_sequence_number: Option<VarInt>,
+
+    /// The number of processed packets before an ACK is sent immediately
+    packet_tolerance: u8,
+
+    /// If `true`, out of order packets are acknowledged immediately
+    ack_reordered_packets: bool,
+
+    /// The ACK_FREQUENCY frame sent to the peer, if the peer supports the extension
+    request: Option<Flag>,
+
+    /// If `true`, an IMMEDIATE_ACK frame is sent in the next packet
+    immediate_ack_requested: bool,
+}
+
+impl Default for AckFrequency {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl AckFrequency {
+    /// Creates an `AckFrequency` that neither sends nor accepts ACK_FREQUENCY frames
+    pub const fn disabled() -> Self {
+        Self {
+            local_min_ack_delay: None,
+            largest_sequence_number: None,
+            packet_tolerance: DEFAULT_PACKET_TOLERANCE,
+            ack_reordered_packets: true,
+            request: None,
+            immediate_ack_requested: false,
+        }
+    }
+
+    /// Creates an `AckFrequency` from the `min_ack_delay` values advertised by each endpoint
+    ///
+    /// A request is only sent to the peer if both endpoints advertised support for the extension.
+    pub fn new(
+        local_min_ack_delay: Option<MinAckDelay>,
+        peer_min_ack_delay: Option<MinAckDelay>,
+        peer_max_ack_delay: MaxAckDelay,
+    ) -> Self {
+        let request = local_min_ack_delay.and(peer_min_ack_delay).map(|_| {
+            // The peer's max_ack_delay is already accounted for in the RTT estimate so the
+            // request only changes the number of packets between acknowledgements. The
+            // `min_ack_delay` was validated to not exceed the `max_ack_delay` so the peer
+            // can accept the requested value.
+            let request_max_ack_delay = peer_max_ack_delay.as_duration().as_micros() as u64;
+            let frame = frame::AckFrequency {
+                sequence_number: VarInt::from_u8(0),
+                ack_eliciting_threshold: REQUESTED_ACK_ELICITING_THRESHOLD,
+                request_max_ack_delay: VarInt::new(request_max_ack_delay).unwrap_or(VarInt::MAX),
+                reordering_threshold: VarInt::from_u8(1),
+            };
+            Flag::new(AckFrequencyWriter(Some(frame)))
+        });
+
+        Self {
+            local_min_ack_delay,
+            request,
+            ..Self::disabled()
+        }
+    }
+
+    /// Returns the number of processed packets before an ACK is sent immediately
+    #[inline]
+    pub fn packet_tolerance(&self) -> u8 {
+        self.packet_tolerance
+    }
+
+    /// Returns `true` if out of order packets are acknowledged immediately
+    #[inline]
+    pub fn ack_reordered_packets(&self) -> bool {
+        self.ack_reordered_packets
+    }
+
+    /// Called when the handshake is confirmed to start sending the request to the peer
+    pub fn on_handshake_confirmed(&mut self) {
+        if let Some(request) = self.request.as_mut() {
+            // HANDSHAKE_DONE frames can be received more than once so only send the request once
+            if request.is_idle() {
+                request.send();
+            }
+        }
+    }
+
+    /// Requests the peer to acknowledge the next packet without delay
+    ///
+    /// The request is ignored if the peer doesn't support the extension.
+    pub fn request_immediate_ack(&mut self) {
+        if self.request.is_some() {
+            self.immediate_ack_requested = true;
+        }
+    }
+
+    /// Called when an IMMEDIATE_ACK frame is received from the peer
+    pub fn on_immediate_ack_frame(
+        &mut self,
+        frame: &frame::ImmediateAck,
+    ) -> Result<(), transport::Error> {
+        // Endpoints that didn't advertise the `min_ack_delay` transport parameter don't
+        // expect to receive the frame
+        if self.local_min_ack_delay.is_none() {
+            return Err(transport::Error::PROTOCOL_VIOLATION
+                .with_reason("IMMEDIATE_ACK frames are not supported")
+                .with_frame_type(frame.tag().into()));
+        }
+
+        Ok(())
+    }
+
+    /// Called when an ACK_FREQUENCY frame is received from the peer
+    ///
+    /// Returns the requested `max_ack_delay` if the frame was applied, or `None` if the
+    /// frame was older than one that was already applied.
+    pub fn on_frame(
+        &mut self,
+        frame: &frame::AckFrequency,
+    ) -> Result<Option<Duration>, transport::Error> {
+        let error = |reason| {
+            transport::Error::PROTOCOL_VIOLATION
+                .with_reason(reason)
+                .with_frame_type(frame.tag().into())
+        };
+
+        // Endpoints that didn't advertise the `min_ack_delay` transport parameter don't
+        // expect to receive the frame
+        let min_ack_delay = self
+            .local_min_ack_delay
+            .ok_or_else(|| error("ACK_FREQUENCY frames are not supported"))?;
+
+        let max_ack_delay = Duration::from_micros(frame.request_max_ack_delay.as_u64());
+
+        if max_ack_delay < min_ack_delay.as_duration() {
+            return Err(error("request_max_ack_delay is less than min_ack_delay"));
+        }
+
+        if max_ack_delay >= MAX_REQUEST_MAX_ACK_DELAY {
+            return Err(error("request_max_ack_delay is too large"));
+        }
+
+        // frames can arrive out of order so only apply the most recent request
+        if self
+            .largest_sequence_number
+            .map_or(false, |largest| frame.sequence_number <= largest)
+        {
+            return Ok(None);
+        }
+
+        self.largest_sequence_number = Some(frame.sequence_number);
+
+        // An ACK is sent once the number of processed packets exceeds the threshold
+        self.packet_tolerance = frame
+            .ack_eliciting_threshold
+            .as_u64()
+            .saturating_add(1)
+            .try_into()
+            .unwrap_or(u8::MAX);
+        self.ack_reordered_packets = frame.reordering_threshold != VarInt::from_u8(0);
+
+        Ok(Some(max_ack_delay))
+    }
+
+    /// Called when a set of packets was acknowledged
+    #[inline]
+    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
+        if let Some(request) = self.request.as_mut() {
+            request.on_packet_ack(ack_set);
+        }
+    }
+
+    /// Called when a set of packets was reported lost
+    #[inline]
+    pub fn on_packet_loss<A: ack::Set>(&mut self, ack_set: &A) {
+        if let Some(request) = self.request.as_mut() {
+            request.on_packet_loss(ack_set);
+        }
+    }
+
+    /// Queries the component for any outgoing frames that need to get sent
+    #[inline]
+    pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
+        if let Some(request) = self.request.as_mut() {
+            request.on_transmit(context)?;
+        }
+
+        if self.immediate_ack_requested
+            && context.transmission_constraint().can_transmit()
+            && context.write_frame(&frame::ImmediateAck).is_some()
+        {
+            self.immediate_ack_requested = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl transmission::interest::Provider for AckFrequency {
+    #[inline]
+    fn transmission_interest<Q: transmission::interest::Query>(
+        &self,
+        query: &mut Q,
+    ) -> transmission::interest::Result {
+        if let Some(request) = self.request.as_ref() {
+            request.transmission_interest(query)?;
+        }
+
+        if self.immediate_ack_requested {
+            query.on_new_data()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AckFrequencyWriter(Option<frame::AckFrequency>);
+
+impl flag::Writer for AckFrequencyWriter {
+    fn write_frame<W: WriteContext>(&mut self, context: &mut W) -> Option<PacketNumber> {
+        context.write_frame(self.0.as_ref()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(sequence_number: u8, request_max_ack_delay: u32) -> frame::AckFrequency {
+        frame::AckFrequency {
+            sequence_number: VarInt::from_u8(sequence_number),
+            ack_eliciting_threshold: VarInt::from_u8(20),
+            request_max_ack_delay: VarInt::from_u32(request_max_ack_delay),
+            reordering_threshold: VarInt::from_u8(0),
+        }
+    }
+
+    #[test]
+    fn request_test() {
+        let supported = Some(MinAckDelay::RECOMMENDED);
+
+        for (local, peer, expected) in [
+            (None, None, false),
+            (supported, None, false),
+            (None, supported, false),
+            (supported, supported, true),
+        ] {
+            let ack_frequency = AckFrequency::new(local, peer, MaxAckDelay::RECOMMENDED);
+            assert_eq!(ack_frequency.request.is_some(), expected);
+        }
+    }
+
+    #[test]
+    fn unsupported_test() {
+        let mut ack_frequency = AckFrequency::disabled();
+        assert!(ack_frequency.on_frame(&frame(0, 25_000)).is_err());
+    }
+
+    #[test]
+    fn immediate_ack_test() {
+        let mut ack_frequency = AckFrequency::disabled();
+        assert!(ack_frequency
+            .on_immediate_ack_frame(&frame::ImmediateAck)
+            .is_err());
+
+        // the request is ignored if the peer doesn't support the extension
+        ack_frequency.request_immediate_ack();
+        assert!(!ack_frequency.immediate_ack_requested);
+
+        let supported = Some(MinAckDelay::RECOMMENDED);
+        let mut ack_frequency = AckFrequency::new(supported, supported, MaxAckDelay::RECOMMENDED);
+        assert!(ack_frequency
+            .on_immediate_ack_frame(&frame::ImmediateAck)
+            .is_ok());
+
+        ack_frequency.request_immediate_ack();
+        assert!(ack_frequency.immediate_ack_requested);
+    }
+
+    #[test]
+    fn invalid_delay_test() {
+        let mut ack_frequency = AckFrequency::new(
+            Some(MinAckDelay::RECOMMENDED),
+            None,
+            MaxAckDelay::RECOMMENDED,
+        );
+
+        assert!(ack_frequency.on_frame(&frame(0, 999)).is_err());
+        assert!(ack_frequency.on_frame(&frame(0, 1 << 24)).is_err());
+    }
+
+    #[test]
+    fn sequence_number_test() {
+        let mut ack_frequency = AckFrequency::new(
+            Some(MinAckDelay::RECOMMENDED),
+            None,
+            MaxAckDelay::RECOMMENDED,
+        );
+        assert_eq!(ack_frequency.packet_tolerance(), DEFAULT_PACKET_TOLERANCE);
+        assert!(ack_frequency.ack_reordered_packets());
+
+        assert_eq!(
+            ack_frequency.on_frame(&frame(1, 40_000)).unwrap(),
+            Some(Duration::from_millis(40))
+        );
+        assert_eq!(ack_frequency.packet_tolerance(), 21);
+        assert!(!ack_frequency.ack_reordered_packets());
+
+        // older and duplicate frames are ignored
+        assert_eq!(ack_frequency.on_frame(&frame(0, 30_000)).unwrap(), None);
+        assert_eq!(ack_frequency.on_frame(&frame(1, 30_000)).unwrap(), None);
+
+        assert_eq!(
+            ack_frequency.on_frame(&frame(2, 30_000)).unwrap(),
+            Some(Duration::from_millis(30))
+        );
+    }
+}
@@ -4,10 +4,11 @@
 use crate::{
     ack::{
         ack_eliciting_transmission::{AckElicitingTransmission, AckElicitingTransmissionSet},
+        ack_frequency::AckFrequency,
         ack_ranges::{AckRanges, AckRangesError},
         ack_transmission_state::AckTransmissionState,
     },
-    contexts::WriteContext,
+    contexts::{OnTransmitError, WriteContext},
     processed_packet::ProcessedPacket,
     transmission,
 };
@@ -19,9 +20,10 @@ use s2n_quic_core::{
         builder::{AckAction, AckProcessed},
         IntoEvent as _,
     },
-    frame::{ack::EcnCounts, Ack, Ping},
+    frame::{self, ack::EcnCounts, Ack, Ping},
     packet::number::{PacketNumber, PacketNumberSpace},
     time::{timer, Timer, Timestamp},
+    transport,
     varint::VarInt,
 };
 
@@ -72,6 +74,9 @@ pub struct AckManager {
 
     /// Explicit Congestion Notification counts from processed packets
     ecn_counts: EcnCounts,
+
+    /// State for the ACK_FREQUENCY extension
+    ack_frequency: AckFrequency,
 }
 
 impl AckManager {
@@ -88,9 +93,16 @@ impl AckManager {
             transmissions_since_elicitation: Counter::new(0),
             transmission_state: AckTransmissionState::default(),
             ecn_counts: EcnCounts::default(),
+            ack_frequency: AckFrequency::disabled(),
         }
     }
 
+    /// Enables the ACK_FREQUENCY extension for the packet space
+    pub fn with_ack_frequency(mut self, ack_frequency: AckFrequency) -> Self {
+        self.ack_frequency = ack_frequency;
+        self
+    }
+
     /// Called when an outgoing packet is being assembled
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> bool {
         let constraint = context.transmission_constraint();
@@ -119,6 +131,15 @@ impl AckManager {
             .is_some()
     }
 
+    /// Called when an outgoing packet is being assembled to write any ACK_FREQUENCY frames
+    #[inline]
+    pub fn on_transmit_ack_frequency<W: WriteContext>(
+        &mut self,
+        context: &mut W,
+    ) -> Result<(), OnTransmitError> {
+        self.ack_frequency.on_transmit(context)
+    }
+
     /// Called after an outgoing packet is assembled and `on_transmit` returned `true`
     pub fn on_transmit_complete<W: WriteContext>(&mut self, context: &mut W) {
         debug_assert!(
@@ -180,6 +201,8 @@ impl AckManager {
 
     /// Called when a set of packets was acknowledged
     pub fn on_packet_ack<A: ack::Set>(&mut self, _timestamp: Timestamp, ack_set: &A) {
+        self.ack_frequency.on_packet_ack(ack_set);
+
         if let Some(ack_range) = self.ack_eliciting_transmissions.on_update(ack_set) {
             self.ack_ranges
                 .remove(ack_range)
@@ -192,6 +215,8 @@ impl AckManager {
 
     /// Called when a set of packets was reported lost
     pub fn on_packet_loss<A: ack::Set>(&mut self, ack_set: &A) {
+        self.ack_frequency.on_packet_loss(ack_set);
+
         if self
             .ack_eliciting_transmissions
             .on_update(ack_set)
@@ -301,14 +326,16 @@ impl AckManager {
             //# *  when the received packet has a packet number less than another
             //#    ack-eliciting packet that has been received, or
 
-            should_activate |= !is_largest;
+            // The peer can disable immediate acknowledgement of reordered packets with
+            // the reordering threshold of an ACK_FREQUENCY frame
+            should_activate |= self.ack_frequency.ack_reordered_packets() && !is_largest;
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.1
             //# *  when the packet has a packet number larger than the highest-
             //#    numbered ack-eliciting packet that has been received and there are
             //#    missing packets between that packet and this packet.
 
-            should_activate |= !is_ordered;
+            should_activate |= self.ack_frequency.ack_reordered_packets() && !is_ordered;
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.1
             //# Similarly, packets marked with the ECN Congestion Experienced (CE)
@@ -320,9 +347,8 @@ impl AckManager {
             // https://github.com/quicwg/base-drafts/pull/3623
             // An ACK frame SHOULD be generated for at least every 10th ack-eliciting packet
 
-            // TODO support delayed ack proposal
-            // https://tools.ietf.org/html/draft-iyengar-quic-delayed-ack-00
-            let packet_tolerance = 10;
+            // The peer can change the tolerance with an ACK_FREQUENCY frame
+            let packet_tolerance = self.ack_frequency.packet_tolerance();
 
             should_activate |= self.processed_packets_since_transmission >= packet_tolerance;
 
@@ -331,6 +357,9 @@ impl AckManager {
             //# send a non-probing packet in response.
             should_activate |= processed_packet.path_challenge_on_active_path;
 
+            // The peer requested the packet is acknowledged without delay
+            should_activate |= processed_packet.immediate_ack_requested;
+
             if should_activate {
                 self.transmission_state.activate();
             } else if !self.ack_delay_timer.is_armed() {
@@ -361,6 +390,44 @@ impl AckManager {
         }
     }
 
+    /// Called when the handshake is confirmed
+    pub fn on_handshake_confirmed(&mut self) {
+        self.ack_frequency.on_handshake_confirmed();
+    }
+
+    /// Requests the peer to acknowledge the next packet without delay, if it supports it
+    pub fn request_immediate_ack(&mut self) {
+        self.ack_frequency.request_immediate_ack();
+    }
+
+    /// Called when an IMMEDIATE_ACK frame is received from the peer
+    pub fn on_immediate_ack_frame(
+        &mut self,
+        frame: &frame::ImmediateAck,
+    ) -> Result<(), transport::Error> {
+        self.ack_frequency.on_immediate_ack_frame(frame)
+    }
+
+    /// Called when an ACK_FREQUENCY frame is received from the peer
+    pub fn on_ack_frequency_frame<Pub: event::ConnectionPublisher>(
+        &mut self,
+        frame: &frame::AckFrequency,
+        publisher: &mut Pub,
+    ) -> Result<(), transport::Error> {
+        if let Some(max_ack_delay) = self.ack_frequency.on_frame(frame)? {
+            self.ack_settings.max_ack_delay = max_ack_delay;
+
+            publisher.on_ack_frequency_updated(event::builder::AckFrequencyUpdated {
+                sequence_number: frame.sequence_number.as_u64(),
+                ack_eliciting_threshold: frame.ack_eliciting_threshold.as_u64(),
+                max_ack_delay,
+                reordering_threshold: frame.reordering_threshold.as_u64(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the largest received packet number that has been ACKed at least once
     pub fn largest_received_packet_number_acked(&self) -> PacketNumber {
         self.largest_received_packet_number_acked
@@ -393,7 +460,8 @@ impl transmission::interest::Provider for AckManager {
         &self,
         query: &mut Q,
     ) -> transmission::interest::Result {
-        self.transmission_state.transmission_interest(query)
+        self.transmission_state.transmission_interest(query)?;
+        self.ack_frequency.transmission_interest(query)
     }
 }
 
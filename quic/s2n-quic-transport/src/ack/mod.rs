@@ -1,10 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+pub use ack_frequency::AckFrequency;
 pub use ack_manager::*;
 pub use s2n_quic_core::ack::*;
 
 mod ack_eliciting_transmission;
+mod ack_frequency;
 mod ack_manager;
 pub(crate) mod ack_ranges;
 mod ack_transmission_state;
@@ -12,10 +12,13 @@ use core::{
     fmt,
     sync::atomic::{self, Ordering},
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_core::{
     application,
     application::ServerName,
+    connection::close,
+    crypto::tls::TlsInfo,
     inet::SocketAddress,
     query::{Query, QueryMut},
     stream::StreamType,
@@ -137,6 +140,36 @@ impl Connection {
         self.api.poll_accept(&self.api, stream_type, context)
     }
 
+    /// Accepts an incoming [`Stream`], waiting at most `timeout` for one to arrive
+    ///
+    /// The method will return
+    /// - `Poll::Ready(Ok(Some(stream, stream_type)))` if a [`Stream`] was accepted
+    /// - `Poll::Ready(Ok(None))` if the connection was closed without an error
+    /// - `Poll::Ready(Err(AcceptTimeoutError::TimedOut))` if no [`Stream`] was accepted
+    ///   before the `timeout` elapsed
+    /// - `Poll::Ready(Err(AcceptTimeoutError::ConnectionError(error)))` if no stream could be
+    ///   accepted due to an error
+    /// - `Poll::Pending` if no new [`Stream`] of the given type was accepted by the connection yet.
+    ///   In this case the caller must retry calling [`Self::poll_accept_timeout`].
+    #[inline]
+    pub fn poll_accept_timeout(
+        &mut self,
+        stream_type: Option<StreamType>,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<Option<Stream>, connection::AcceptTimeoutError>> {
+        self.api
+            .poll_accept_timeout(&self.api, stream_type, timeout, context)
+    }
+
+    /// Discards the timeout started by [`Self::poll_accept_timeout`], if any
+    ///
+    /// The next call to [`Self::poll_accept_timeout`] starts a new timeout.
+    #[inline]
+    pub fn reset_accept_timeout(&self) -> Result<(), connection::Error> {
+        self.api.reset_accept_timeout()
+    }
+
     #[inline]
     pub fn poll_open_stream(
         &mut self,
@@ -165,6 +198,33 @@ impl Connection {
         self.api.close_connection(Some(error_code));
     }
 
+    /// Gracefully closes the Connection once all of the outstanding stream data has been
+    /// delivered to the peer
+    ///
+    /// If the data isn't flushed within the provided `timeout`, the Connection is closed
+    /// immediately.
+    #[inline]
+    pub fn poll_close_gracefully(
+        &self,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        self.api.poll_close_gracefully(timeout, context)
+    }
+
+    /// Migrates the Connection to a path from the provided local address
+    ///
+    /// The new path is validated before it is used. If validation fails, the Connection
+    /// remains on the current path.
+    #[inline]
+    pub fn poll_migrate(
+        &self,
+        local_address: SocketAddress,
+        context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>> {
+        self.api.poll_migrate(local_address, context)
+    }
+
     #[inline]
     pub fn server_name(&self) -> Result<Option<ServerName>, connection::Error> {
         self.api.server_name()
@@ -175,6 +235,26 @@ impl Connection {
         self.api.application_protocol()
     }
 
+    #[inline]
+    pub fn peer_certificate_chain(&self) -> Result<Vec<Bytes>, connection::Error> {
+        self.api.peer_certificate_chain()
+    }
+
+    #[inline]
+    pub fn ocsp_response(&self) -> Result<Option<Bytes>, connection::Error> {
+        self.api.ocsp_response()
+    }
+
+    #[inline]
+    pub fn tls_info(&self) -> Result<Option<TlsInfo>, connection::Error> {
+        self.api.tls_info()
+    }
+
+    #[inline]
+    pub fn close_reason(&self) -> Result<Option<close::Reason>, connection::Error> {
+        self.api.close_reason()
+    }
+
     #[inline]
     pub fn id(&self) -> u64 {
         self.api.id()
@@ -189,6 +269,25 @@ impl Connection {
         self.api.keep_alive(enabled)
     }
 
+    pub fn keep_alive_with_period(
+        &self,
+        period: Duration,
+    ) -> Result<(), connection::KeepAliveError> {
+        self.api.keep_alive_with_period(period)
+    }
+
+    pub fn rotate_connection_id(&self) -> Result<(), connection::Error> {
+        self.api.rotate_connection_id()
+    }
+
+    pub fn request_immediate_ack(&self) -> Result<(), connection::Error> {
+        self.api.request_immediate_ack()
+    }
+
+    pub fn initiate_key_update(&self) -> Result<(), connection::Error> {
+        self.api.initiate_key_update()
+    }
+
     #[inline]
     pub fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         self.api.local_address()
@@ -199,6 +298,21 @@ impl Connection {
         self.api.remote_address()
     }
 
+    #[inline]
+    pub fn path_mtu(&self) -> Result<usize, connection::Error> {
+        self.api.path_mtu()
+    }
+
+    #[inline]
+    pub fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error> {
+        self.api.idle_timeout()
+    }
+
+    #[inline]
+    pub fn statistics(&self) -> Result<connection::Statistics, connection::Error> {
+        self.api.statistics()
+    }
+
     #[inline]
     pub fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api.query_event_context(query)
@@ -13,10 +13,13 @@ use bytes::Bytes;
 use core::{
     sync::atomic::AtomicUsize,
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_core::{
     application,
     application::ServerName,
+    connection::close,
+    crypto::tls::TlsInfo,
     inet::SocketAddress,
     query::{Query, QueryMut},
     stream::{ops, StreamId, StreamType},
@@ -44,6 +47,16 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
         context: &Context,
     ) -> Poll<Result<Option<Stream>, connection::Error>>;
 
+    fn poll_accept_timeout(
+        &self,
+        arc_self: &Arc<dyn ConnectionApiProvider>,
+        stream_type: Option<StreamType>,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<Option<Stream>, connection::AcceptTimeoutError>>;
+
+    fn reset_accept_timeout(&self) -> Result<(), connection::Error>;
+
     fn poll_open_stream(
         &self,
         arc_self: &Arc<dyn ConnectionApiProvider>,
@@ -54,20 +67,54 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
 
     fn close_connection(&self, code: Option<application::Error>);
 
+    fn poll_close_gracefully(
+        &self,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>>;
+
+    fn poll_migrate(
+        &self,
+        local_address: SocketAddress,
+        context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>>;
+
     fn server_name(&self) -> Result<Option<ServerName>, connection::Error>;
 
     fn application_protocol(&self) -> Result<Bytes, connection::Error>;
 
+    fn peer_certificate_chain(&self) -> Result<Vec<Bytes>, connection::Error>;
+
+    fn ocsp_response(&self) -> Result<Option<Bytes>, connection::Error>;
+
+    fn tls_info(&self) -> Result<Option<TlsInfo>, connection::Error>;
+
+    fn close_reason(&self) -> Result<Option<close::Reason>, connection::Error>;
+
     fn id(&self) -> u64;
 
     fn ping(&self) -> Result<(), connection::Error>;
 
     fn keep_alive(&self, enabled: bool) -> Result<(), connection::Error>;
 
+    fn keep_alive_with_period(&self, period: Duration) -> Result<(), connection::KeepAliveError>;
+
+    fn rotate_connection_id(&self) -> Result<(), connection::Error>;
+
+    fn request_immediate_ack(&self) -> Result<(), connection::Error>;
+
+    fn initiate_key_update(&self) -> Result<(), connection::Error>;
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error>;
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn path_mtu(&self) -> Result<usize, connection::Error>;
+
+    fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error>;
+
+    fn statistics(&self) -> Result<connection::Statistics, connection::Error>;
+
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error>;
 
     fn query_event_context_mut(&self, query: &mut dyn QueryMut) -> Result<(), connection::Error>;
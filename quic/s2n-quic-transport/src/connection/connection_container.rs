@@ -13,7 +13,7 @@ use crate::{
     endpoint::{
         self,
         connect::{self, ConnectionSender},
-        handle::{AcceptorSender, ConnectorReceiver},
+        handle::{AcceptorSender, ActiveConnectionCount, ConnectorReceiver},
     },
     stream,
 };
@@ -24,8 +24,9 @@ use core::{
     marker::PhantomData,
     ops::Deref,
     pin::Pin,
-    sync::atomic::AtomicUsize,
+    sync::atomic::{AtomicUsize, Ordering},
     task::{Context, Poll},
+    time::Duration,
 };
 use intrusive_collections::{
     intrusive_adapter, KeyAdapter, LinkedList, LinkedListLink, RBTree, RBTreeLink,
@@ -33,6 +34,8 @@ use intrusive_collections::{
 use s2n_quic_core::{
     application,
     application::ServerName,
+    connection::close,
+    crypto::tls::TlsInfo,
     event::supervisor,
     inet::SocketAddress,
     query::{Query, QueryMut},
@@ -166,6 +169,25 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionNode<C, L> {
         }
     }
 
+    /// Wraps an accepted stream ID into a `Stream` handle
+    fn on_accept_response<E>(
+        arc_self: &ConnectionApi,
+        response: Poll<Result<Option<stream::StreamId>, E>>,
+    ) -> Poll<Result<Option<stream::Stream>, E>> {
+        match response {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Err(e).into(),
+            Poll::Ready(Ok(None)) => Ok(None).into(),
+            Poll::Ready(Ok(Some(stream_id))) => {
+                let connection = arc_self.clone();
+                let connection = Connection::new(connection);
+                let stream = stream::Stream::new(connection, stream_id);
+
+                Ok(Some(stream)).into()
+            }
+        }
+    }
+
     #[inline]
     fn ensure_consistency(&self) {
         if !cfg!(debug_assertions) {
@@ -214,7 +236,7 @@ impl<'a, C: connection::Trait, L: connection::Lock<C>> KeyAdapter<'a>
                 // to ensure the timeout value is properly updated.
                 //
                 // Assuming everything is tested properly, this should never be reached
-                Timestamp::from_duration(core::time::Duration::from_secs(0))
+                Timestamp::from_duration(Duration::from_secs(0))
             }
         }
     }
@@ -257,18 +279,27 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
     ) -> Poll<Result<Option<stream::Stream>, connection::Error>> {
         let response = self.api_poll_call(|conn| conn.poll_accept_stream(stream_type, context));
 
-        match response {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(Err(e)) => Err(e).into(),
-            Poll::Ready(Ok(None)) => Ok(None).into(),
-            Poll::Ready(Ok(Some(stream_id))) => {
-                let connection = arc_self.clone();
-                let connection = Connection::new(connection);
-                let stream = stream::Stream::new(connection, stream_id);
+        Self::on_accept_response(arc_self, response)
+    }
 
-                Ok(Some(stream)).into()
-            }
-        }
+    fn poll_accept_timeout(
+        &self,
+        arc_self: &ConnectionApi,
+        stream_type: Option<stream::StreamType>,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<Option<stream::Stream>, connection::AcceptTimeoutError>> {
+        let response = self
+            .api_poll_call(|conn| conn.poll_accept_stream_timeout(stream_type, timeout, context));
+
+        Self::on_accept_response(arc_self, response)
+    }
+
+    fn reset_accept_timeout(&self) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| {
+            conn.reset_accept_stream_timeout();
+            Ok(())
+        })
     }
 
     fn poll_open_stream(
@@ -301,6 +332,22 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         });
     }
 
+    fn poll_close_gracefully(
+        &self,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        self.api_poll_call(|conn| conn.poll_close_gracefully(timeout, context))
+    }
+
+    fn poll_migrate(
+        &self,
+        local_address: SocketAddress,
+        context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>> {
+        self.api_poll_call(|conn| conn.poll_migrate(local_address, context))
+    }
+
     fn server_name(&self) -> Result<Option<ServerName>, connection::Error> {
         self.api_read_call(|conn| Ok(conn.server_name()))
     }
@@ -309,6 +356,22 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| Ok(conn.application_protocol()))
     }
 
+    fn peer_certificate_chain(&self) -> Result<Vec<Bytes>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.peer_certificate_chain()))
+    }
+
+    fn ocsp_response(&self) -> Result<Option<Bytes>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.ocsp_response()))
+    }
+
+    fn tls_info(&self) -> Result<Option<TlsInfo>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.tls_info()))
+    }
+
+    fn close_reason(&self) -> Result<Option<close::Reason>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.close_reason()))
+    }
+
     fn id(&self) -> u64 {
         self.internal_connection_id.into()
     }
@@ -321,6 +384,22 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_write_call(|conn| conn.keep_alive(enabled))
     }
 
+    fn keep_alive_with_period(&self, period: Duration) -> Result<(), connection::KeepAliveError> {
+        self.api_write_call(|conn| conn.keep_alive_with_period(period))
+    }
+
+    fn rotate_connection_id(&self) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.rotate_connection_id())
+    }
+
+    fn request_immediate_ack(&self) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.request_immediate_ack())
+    }
+
+    fn initiate_key_update(&self) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.initiate_key_update())
+    }
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         self.api_read_call(|conn| conn.local_address())
     }
@@ -329,6 +408,18 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| conn.remote_address())
     }
 
+    fn path_mtu(&self) -> Result<usize, connection::Error> {
+        self.api_read_call(|conn| conn.path_mtu())
+    }
+
+    fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error> {
+        self.api_read_call(|conn| conn.idle_timeout())
+    }
+
+    fn statistics(&self) -> Result<connection::Statistics, connection::Error> {
+        self.api_read_call(|conn| conn.statistics())
+    }
+
     #[inline]
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api_read_call(|conn| {
@@ -616,6 +707,8 @@ pub struct ConnectionContainer<C: connection::Trait, L: connection::Lock<C>> {
     ///
     /// This is only used by clients
     connector_receiver: ConnectorReceiver,
+    /// The number of connections past the handshake, shared with the application
+    active_connections: ActiveConnectionCount,
 }
 
 macro_rules! iterate_interruptible {
@@ -669,12 +762,17 @@ macro_rules! iterate_interruptible {
 
 impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
     /// Creates a new `ConnectionContainer`
-    pub(crate) fn new(accept_queue: AcceptorSender, connector_receiver: ConnectorReceiver) -> Self {
+    pub(crate) fn new(
+        accept_queue: AcceptorSender,
+        connector_receiver: ConnectorReceiver,
+        active_connections: ActiveConnectionCount,
+    ) -> Self {
         Self {
             connection_map: RBTree::new(ConnectionTreeAdapter::new()),
             interest_lists: InterestLists::new(),
             accept_queue,
             connector_receiver,
+            active_connections,
         }
     }
 
@@ -685,6 +783,15 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         !self.accept_queue.is_closed()
     }
 
+    /// Stops accepting new connections
+    ///
+    /// Connections that were already queued are left open until the endpoint closes them.
+    pub fn stop_accepting(&mut self) {
+        debug_assert!(<C::Config as endpoint::Config>::ENDPOINT_TYPE.is_server());
+
+        self.accept_queue.close_channel();
+    }
+
     /// Returns `true` if the endpoint can make connection attempts
     fn can_connect(&self) -> bool {
         debug_assert!(<C::Config as endpoint::Config>::ENDPOINT_TYPE.is_client());
@@ -880,6 +987,22 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         Some((result, interests))
     }
 
+    /// Executes the given function on all of the `Connection`s in the `ConnectionContainer`
+    pub fn for_each_connection<F>(&mut self, mut func: F)
+    where
+        F: FnMut(&mut C),
+    {
+        let connection_ids: Vec<_> = self
+            .connection_map
+            .iter()
+            .map(|conn| conn.internal_connection_id)
+            .collect();
+
+        for connection_id in connection_ids {
+            self.with_connection(connection_id, &mut func);
+        }
+    }
+
     /// Removes all Connections in the `done` state from the `ConnectionContainer`.
     fn finalize_done_connections(&mut self) {
         debug_assert_eq!(
@@ -891,6 +1014,11 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
             self.remove_node(&connection);
         }
 
+        // all of the counters are up to date so report the number of active connections
+        let active_connections = self.len() - self.handshake_connections();
+        self.active_connections
+            .store(active_connections, Ordering::Relaxed);
+
         debug_assert_eq!(0, self.count_done_handshaking_connections());
     }
 
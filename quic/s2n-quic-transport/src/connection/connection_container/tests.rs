@@ -17,7 +17,10 @@ use core::{
     time::Duration,
 };
 use s2n_quic_core::{
-    application, event,
+    application,
+    connection::close,
+    crypto::tls::TlsInfo,
+    event,
     event::builder::DatagramDropReason,
     inet::{DatagramInfo, SocketAddress},
     io::tx,
@@ -29,7 +32,7 @@ use s2n_quic_core::{
         version_negotiation::ProtectedVersionNegotiation,
         zero_rtt::ProtectedZeroRtt,
     },
-    path::MaxMtu,
+    path::mtu,
     query,
     time::{Timer, Timestamp},
 };
@@ -99,7 +102,9 @@ impl connection::Trait for TestConnection {
         &mut self,
         _connection_id_format: &mut <Self::Config as endpoint::Config>::ConnectionIdFormat,
         _stateless_reset_token_generator: &mut <Self::Config as endpoint::Config>::StatelessResetTokenGenerator,
+        _random_generator: &mut <Self::Config as endpoint::Config>::RandomGenerator,
         _timestamp: Timestamp,
+        _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
     ) -> Result<(), connection::local_id_registry::LocalIdRegistrationError> {
         Ok(())
     }
@@ -133,6 +138,12 @@ impl connection::Trait for TestConnection {
         _timestamp: Timestamp,
         _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         _datagram: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        _connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
+        _congestion_controller_endpoint: &mut <Self::Config as endpoint::Config>::CongestionControllerEndpoint,
+        _mtu_controller_endpoint: &mut <Self::Config as endpoint::Config>::MtuControllerEndpoint,
+        _pacer_endpoint: &mut <Self::Config as endpoint::Config>::PacerEndpoint,
+        _mtu_config: mtu::Config,
+        _random_generator: &mut <Self::Config as endpoint::Config>::RandomGenerator,
     ) -> Result<(), connection::Error> {
         Ok(())
     }
@@ -146,6 +157,7 @@ impl connection::Trait for TestConnection {
         _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         _packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         _datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        _connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         Ok(())
     }
@@ -160,6 +172,7 @@ impl connection::Trait for TestConnection {
         _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         _packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         _datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        _connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         Ok(())
     }
@@ -174,6 +187,7 @@ impl connection::Trait for TestConnection {
         _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         _packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         _datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        _connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         Ok(())
     }
@@ -233,8 +247,10 @@ impl connection::Trait for TestConnection {
         _path: &<Self::Config as endpoint::Config>::PathHandle,
         _datagram: &DatagramInfo,
         _congestion_controller_endpoint: &mut <Self::Config as endpoint::Config>::CongestionControllerEndpoint,
+        _mtu_controller_endpoint: &mut <Self::Config as endpoint::Config>::MtuControllerEndpoint,
+        _pacer_endpoint: &mut <Self::Config as endpoint::Config>::PacerEndpoint,
         _path_migration: &mut <Self::Config as endpoint::Config>::PathMigrationValidator,
-        _max_mtu: MaxMtu,
+        _mtu_config: mtu::Config,
         _subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
     ) -> Result<path::Id, DatagramDropReason> {
         todo!()
@@ -267,6 +283,19 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn poll_accept_stream_timeout(
+        &mut self,
+        _stream_type: Option<stream::StreamType>,
+        _timeout: Duration,
+        _context: &Context,
+    ) -> Poll<Result<Option<stream::StreamId>, connection::AcceptTimeoutError>> {
+        todo!()
+    }
+
+    fn reset_accept_stream_timeout(&mut self) {
+        todo!()
+    }
+
     fn poll_open_stream(
         &mut self,
         _stream_type: stream::StreamType,
@@ -280,6 +309,22 @@ impl connection::Trait for TestConnection {
         // no-op
     }
 
+    fn poll_close_gracefully(
+        &mut self,
+        _timeout: Duration,
+        _context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        todo!()
+    }
+
+    fn poll_migrate(
+        &mut self,
+        _local_address: SocketAddress,
+        _context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>> {
+        todo!()
+    }
+
     fn server_name(&self) -> Option<ServerName> {
         todo!()
     }
@@ -288,6 +333,22 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn peer_certificate_chain(&self) -> Vec<Bytes> {
+        todo!()
+    }
+
+    fn ocsp_response(&self) -> Option<Bytes> {
+        todo!()
+    }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        todo!()
+    }
+
+    fn close_reason(&self) -> Option<close::Reason> {
+        todo!()
+    }
+
     fn ping(&mut self) -> Result<(), connection::Error> {
         todo!()
     }
@@ -296,6 +357,25 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn keep_alive_with_period(
+        &mut self,
+        _period: Duration,
+    ) -> Result<(), connection::KeepAliveError> {
+        todo!()
+    }
+
+    fn rotate_connection_id(&mut self) -> Result<(), connection::Error> {
+        todo!()
+    }
+
+    fn request_immediate_ack(&mut self) -> Result<(), connection::Error> {
+        todo!()
+    }
+
+    fn initiate_key_update(&mut self) -> Result<(), connection::Error> {
+        todo!()
+    }
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         todo!()
     }
@@ -304,6 +384,18 @@ impl connection::Trait for TestConnection {
         Ok(SocketAddress::default())
     }
 
+    fn path_mtu(&self) -> Result<usize, connection::Error> {
+        todo!()
+    }
+
+    fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error> {
+        todo!()
+    }
+
+    fn statistics(&self) -> Result<connection::Statistics, connection::Error> {
+        todo!()
+    }
+
     fn error(&self) -> Option<connection::Error> {
         None
     }
@@ -412,13 +504,14 @@ fn container_test() {
     check!().with_type::<Vec<Operation>>().for_each(|ops| {
         let mut id_gen = InternalConnectionIdGenerator::new();
         let mut connections = vec![];
-        let (handle, acceptor, connector, _close_handle) = endpoint::handle::Handle::new(100);
+        let (handle, acceptor, connector, _close_handle, active_connections) =
+            endpoint::handle::Handle::new(100);
         let (waker, _wake_count) = futures_test::task::new_count_waker();
         let mut now = unsafe { Timestamp::from_duration(Duration::from_secs(0)) };
 
         let mut handle = Some(handle);
         let mut container: ConnectionContainer<TestConnection, TestLock> =
-            ConnectionContainer::new(acceptor, connector);
+            ConnectionContainer::new(acceptor, connector, active_connections);
 
         for op in ops.iter() {
             match op {
@@ -35,7 +35,7 @@ use core::{
 use s2n_quic_core::{
     application,
     application::ServerName,
-    connection::{error::Error, id::Generator as _, InitialId, PeerId},
+    connection::{close, error::Error, id::Generator as _, InitialId, PeerId},
     crypto::{tls, CryptoSuite},
     datagram::{Receiver, Sender},
     event::{
@@ -54,11 +54,11 @@ use s2n_quic_core::{
         version_negotiation::ProtectedVersionNegotiation,
         zero_rtt::ProtectedZeroRtt,
     },
-    path::{Handle as _, MaxMtu},
+    path::{mtu, Handle as _},
     query,
     recovery::CongestionController,
     stateless_reset::token::Generator as _,
-    time::{timer, Timestamp},
+    time::{clock, timer, Clock as _, Timestamp},
     transport,
 };
 
@@ -84,7 +84,8 @@ enum ConnectionState {
     Handshaking,
     /// The connection is active
     Active,
-    /// The connection was dropped by the application but still has stream data to transmit to the peer.
+    /// The connection was dropped or gracefully closed by the application but still has stream
+    /// data to transmit to the peer.
     ///
     /// Once all of the data is transmitted, the connection will be closed.
     Flushing,
@@ -106,6 +107,10 @@ impl From<connection::Error> for ConnectionState {
                 // If the idle timer expired we directly move into the final state
                 ConnectionState::Finished
             }
+            connection::Error::DeadPeerDetected { .. } => {
+                // The peer is unreachable so the connection is closed silently
+                ConnectionState::Finished
+            }
             connection::Error::NoValidPath { .. } => {
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-9
                 //# When an endpoint has no validated path on which to send packets, it
@@ -127,6 +132,16 @@ impl From<connection::Error> for ConnectionState {
                 //# close.
                 ConnectionState::Closing
             }
+            connection::Error::GracefulCloseTimedOut { .. } => {
+                // The peer is notified that the connection is closing after the graceful close
+                // timed out
+                ConnectionState::Closing
+            }
+            connection::Error::MaxLifetimeExceeded { .. } => {
+                // The peer is notified that the connection is closing after the outstanding
+                // streams were flushed
+                ConnectionState::Closing
+            }
             connection::Error::Closed { .. }
             | connection::Error::Transport { .. }
             | connection::Error::Application { .. } => {
@@ -177,9 +192,85 @@ pub struct ConnectionImpl<Config: endpoint::Config> {
     wakeup_handle: Arc<WakeupHandle<InternalConnectionId>>,
     /// A Waker to the connection.
     waker: Waker,
+    /// The clock used by the IO provider, which timestamps application requests
+    clock: Option<clock::Shared>,
+    /// Set when the application requests a graceful close of the connection
+    graceful_close: Option<GracefulClose>,
+    /// Set when the application is waiting to accept a stream within a timeout
+    accept_timeout: Option<AcceptTimeout>,
+    /// Set when the application requests a migration to a new local address
+    migration: Option<Migration>,
+    /// Set when the application requests the connection IDs issued to the peer to be rotated
+    rotate_connection_ids: bool,
+    /// Set once the connection reaches its max lifetime and should be closed gracefully
+    max_lifetime_reached: bool,
+    /// The reason phrase of the CONNECTION_CLOSE frame received from the peer
+    remote_close_reason: Option<Bytes>,
+    /// The time the connection was created
+    start_time: Timestamp,
     event_context: EventContext<Config>,
 }
 
+/// Tracks the progress of a graceful close requested by the application
+#[derive(Debug)]
+struct GracefulClose {
+    /// The amount of time the connection can spend flushing before it is closed abruptly
+    timeout: Duration,
+    /// Notifies the application once the connection has been closed
+    waker: Option<Waker>,
+}
+
+/// Tracks an application request to accept a stream within a timeout
+#[derive(Debug)]
+struct AcceptTimeout {
+    /// The amount of time to wait for a stream before returning to the application
+    timeout: Duration,
+    /// Set once the timeout has elapsed without a stream being accepted
+    expired: bool,
+    /// Notifies the application once the timeout has elapsed
+    waker: Option<Waker>,
+}
+
+/// Tracks the progress of a migration requested by the application
+#[derive(Debug)]
+struct Migration {
+    /// The local address the application requested to migrate to
+    local_address: SocketAddress,
+    /// The path being validated, once it has been created
+    path_id: Option<path::Id>,
+    /// The outcome of the migration, once it has finished
+    result: Option<Result<(), connection::MigrationError>>,
+    /// Notifies the application once the migration has finished
+    waker: Option<Waker>,
+}
+
+impl Migration {
+    /// Checks if validation of the new path has finished and notifies the application
+    fn poll<Config: endpoint::Config, Pub: event::ConnectionPublisher>(
+        &mut self,
+        path_manager: &mut path::Manager<Config>,
+        publisher: &mut Pub,
+    ) {
+        if self.result.is_some() {
+            return;
+        }
+
+        if let Some(path_id) = self.path_id {
+            if let Poll::Ready(result) = path_manager.poll_migration(path_id, publisher) {
+                self.finish(result);
+            }
+        }
+    }
+
+    fn finish(&mut self, result: Result<(), connection::MigrationError>) {
+        self.result = Some(result);
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
 struct EventContext<Config: endpoint::Config> {
     /// The [`Connection`]s internal identifier
     internal_connection_id: InternalConnectionId,
@@ -269,6 +360,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
         timestamp: Timestamp,
         subscriber: &mut Config::EventSubscriber,
         datagram: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
     ) -> Result<(), connection::Error> {
         let mut publisher = self.event_context.publisher(timestamp, subscriber);
         let space_manager = &mut self.space_manager;
@@ -281,6 +373,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             &self.waker,
             &mut publisher,
             datagram,
+            connection_limits,
         ) {
             Poll::Ready(res) => res?,
             Poll::Pending => return Ok(()),
@@ -328,6 +421,12 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                     .initial_id_expiration_timer
                     .set(timestamp + 3 * self.current_pto())
             }
+
+            // the max lifetime was reached during the handshake so start closing now that it
+            // has completed
+            if self.max_lifetime_reached {
+                self.start_max_lifetime_close(timestamp);
+            }
         }
 
         Ok(())
@@ -371,6 +470,16 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             self.timers.reset_peer_idle_timer_on_send = true;
         }
 
+        if let Some(timeout) = self.limits.dead_peer_timeout() {
+            // The timer is armed `timeout` after the first unacknowledged ack-eliciting packet
+            // was sent so it's cancelled by any acknowledgement since then
+            if let Some(acked) = self.path_manager.active_path().last_ack_eliciting_acked() {
+                if self.timers.dead_peer_timer.is_expired(acked + timeout) {
+                    self.timers.dead_peer_timer.cancel();
+                }
+            }
+        }
+
         let mut publisher = self
             .event_context
             .publisher(packet.datagram.timestamp, subscriber);
@@ -381,6 +490,11 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             })
         }
 
+        // the packet may have validated the path the application is migrating to
+        if let Some(migration) = self.migration.as_mut() {
+            migration.poll(&mut self.path_manager, &mut publisher);
+        }
+
         // check to see if we're flushing and should now close the connection
         if self.poll_flush().is_ready() {
             self.error?;
@@ -402,6 +516,12 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                 self.timers.peer_idle_timer.set(timestamp + duration);
             }
         }
+
+        if let Some(timeout) = self.limits.dead_peer_timeout() {
+            if !self.timers.dead_peer_timer.is_armed() {
+                self.timers.dead_peer_timer.set(timestamp + timeout);
+            }
+        }
     }
 
     fn current_pto(&self) -> Duration {
@@ -526,16 +646,38 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
         Ok(())
     }
 
+    /// Starts gracefully closing the connection after it reached its max lifetime
+    ///
+    /// Connections that are still handshaking are closed once the handshake completes.
+    fn start_max_lifetime_close(&mut self, timestamp: Timestamp) {
+        self.max_lifetime_reached = true;
+
+        if matches!(self.state, ConnectionState::Active) {
+            // stop accepting new data and give the connection some time to flush all
+            // outstanding streams
+            self.state = ConnectionState::Flushing;
+            // bound the flush so a peer that stops reading can't keep the connection open
+            self.timers
+                .max_lifetime_timer
+                .set(timestamp + self.limits.max_connection_lifetime_flush_timeout());
+            let _ = self.poll_flush();
+            self.wakeup_handle.wakeup();
+        }
+    }
+
     /// Polls for the connection to flush all of the outstanding streams
     ///
     /// Once all of the streams are finished, `Poll::Ready` will be returned
     fn poll_flush(&mut self) -> Poll<()> {
         if matches!(self.state, ConnectionState::Flushing) {
+            let is_graceful = self.graceful_close.is_some() || self.max_lifetime_reached;
             let is_finished = if let Some((space, _)) = self.space_manager.application_mut() {
-                space
-                    .stream_manager
-                    .flush(transport::Error::NO_ERROR.into())
-                    .is_ready()
+                let error = transport::Error::NO_ERROR.into();
+                if is_graceful {
+                    space.stream_manager.finish_and_flush(error).is_ready()
+                } else {
+                    space.stream_manager.flush(error).is_ready()
+                }
             } else {
                 debug_assert!(
                     false,
@@ -545,7 +687,16 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             };
 
             if is_finished {
-                self.error = Err(transport::Error::NO_ERROR.into());
+                self.error = Err(match self.limits.max_connection_lifetime() {
+                    // the application didn't close the connection so report the lifetime as
+                    // the reason
+                    Some(max_lifetime)
+                        if self.max_lifetime_reached && self.graceful_close.is_none() =>
+                    {
+                        connection::Error::max_lifetime_exceeded(max_lifetime)
+                    }
+                    _ => transport::Error::NO_ERROR.into(),
+                });
                 return Poll::Ready(());
             }
         }
@@ -576,21 +727,35 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
 
         // The path manager always starts with a single path containing the known peer and local
         // connection ids.
-        let rtt_estimator = RttEstimator::default();
+        let rtt_estimator =
+            RttEstimator::with_initial_rtt(Duration::ZERO, parameters.limits.initial_rtt());
         // Assume clients validate the server's address implicitly.
         let peer_validated = Self::Config::ENDPOINT_TYPE.is_server();
 
-        let initial_path = path::Path::new(
+        let mut initial_path = path::Path::new(
             parameters.path_handle,
             parameters.peer_connection_id,
             parameters.local_connection_id,
             rtt_estimator,
             parameters.congestion_controller,
+            parameters.mtu_controller,
+            parameters.pacer,
             peer_validated,
-            parameters.max_mtu,
+            parameters.mtu_config,
+        );
+        initial_path.ecn_controller.set_mode(parameters.ecn_mode);
+        initial_path.set_amplification_limit(parameters.limits.amplification_limit());
+        initial_path.set_pto_backoff_factor(parameters.limits.pto_backoff_factor());
+        initial_path.set_max_pto(parameters.limits.max_pto());
+        initial_path
+            .set_packet_reordering_threshold(parameters.limits.packet_reordering_threshold());
+        initial_path.set_time_threshold(parameters.limits.time_threshold());
+
+        let mut path_manager = path::Manager::new(initial_path, parameters.peer_id_registry);
+        path_manager.set_path_validation(
+            parameters.limits.path_validation_timeout(),
+            parameters.limits.path_validation_attempts(),
         );
-
-        let path_manager = path::Manager::new(initial_path, parameters.peer_id_registry);
 
         let mut publisher =
             event_context.publisher(parameters.timestamp, parameters.event_subscriber);
@@ -626,6 +791,14 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             space_manager: parameters.space_manager,
             wakeup_handle,
             waker,
+            clock: parameters.clock,
+            graceful_close: None,
+            accept_timeout: None,
+            migration: None,
+            rotate_connection_ids: false,
+            max_lifetime_reached: false,
+            remote_close_reason: None,
+            start_time: parameters.timestamp,
             event_context,
         };
 
@@ -634,7 +807,9 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 parameters.timestamp,
                 parameters.event_subscriber,
                 parameters.datagram_endpoint,
+                parameters.connection_limits,
             ) {
+                let statistics = connection.path_manager.statistics();
                 connection.with_event_publisher(
                     parameters.timestamp,
                     None,
@@ -643,7 +818,11 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                         use s2n_quic_core::event::{
                             builder::ConnectionClosed, ConnectionPublisher,
                         };
-                        publisher.on_connection_closed(ConnectionClosed { error });
+                        publisher.on_connection_closed(ConnectionClosed {
+                            error,
+                            duration: Duration::ZERO,
+                            statistics,
+                        });
                     },
                 );
                 return Err(error);
@@ -672,6 +851,13 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             .max_handshake_duration_timer
             .set(parameters.timestamp + connection.limits.max_handshake_duration());
 
+        if let Some(max_lifetime) = connection.limits.max_connection_lifetime() {
+            connection
+                .timers
+                .max_lifetime_timer
+                .set(parameters.timestamp + max_lifetime);
+        }
+
         Ok(connection)
     }
 
@@ -706,7 +892,35 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
 
         let mut publisher = self.event_context.publisher(timestamp, subscriber);
 
-        publisher.on_connection_closed(event::builder::ConnectionClosed { error });
+        if let Some(graceful_close) = self.graceful_close.as_mut() {
+            let timed_out = matches!(error, connection::Error::GracefulCloseTimedOut { .. });
+            if timed_out
+                || matches!(
+                    error,
+                    connection::Error::Closed {
+                        initiator: endpoint::Location::Local,
+                        ..
+                    }
+                )
+            {
+                publisher.on_graceful_close(event::builder::GracefulClose { timed_out });
+            }
+
+            // let the application know the connection is closed
+            if let Some(waker) = graceful_close.waker.take() {
+                waker.wake();
+            }
+        }
+
+        if let Some(waker) = self.migration.as_mut().and_then(|m| m.waker.take()) {
+            waker.wake();
+        }
+
+        publisher.on_connection_closed(event::builder::ConnectionClosed {
+            error,
+            duration: timestamp.saturating_duration_since(self.start_time),
+            statistics: self.path_manager.statistics(),
+        });
 
         // We don't need any timers anymore
         self.timers.cancel();
@@ -806,25 +1020,33 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         &mut self,
         connection_id_format: &mut Config::ConnectionIdFormat,
         stateless_reset_token_generator: &mut Config::StatelessResetTokenGenerator,
+        random_generator: &mut Config::RandomGenerator,
         timestamp: Timestamp,
+        subscriber: &mut Config::EventSubscriber,
     ) -> Result<(), LocalIdRegistrationError> {
         match self.local_id_registry.connection_id_interest() {
             Interest::New(mut count) => {
                 let remote_address = self.path_manager.active_path().remote_address();
                 let connection_info = ConnectionInfo::new(&remote_address);
+                let mut publisher = self.event_context.publisher(timestamp, subscriber);
 
                 while count > 0 {
-                    let id = connection_id_format.generate(&connection_info);
+                    let id = connection_id_format
+                        .generate_with_random(&connection_info, random_generator);
                     let expiration = connection_id_format
                         .lifetime()
                         .map(|duration| timestamp + duration);
-                    let stateless_reset_token =
-                        stateless_reset_token_generator.generate(id.as_bytes());
-                    self.local_id_registry.register_connection_id(
+                    let stateless_reset_token = stateless_reset_token_generator
+                        .generate_with_random(id.as_bytes(), random_generator);
+                    let sequence_number = self.local_id_registry.register_connection_id(
                         &id,
                         expiration,
                         stateless_reset_token,
                     )?;
+                    publisher.on_connection_id_issued(event::builder::ConnectionIdIssued {
+                        sequence_number: sequence_number as u64,
+                        connection_id: id.into_event(),
+                    });
                     count -= 1;
                 }
                 Ok(())
@@ -908,8 +1130,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 if let Some(edt) = self
                     .path_manager
                     .active_path()
-                    .congestion_controller
-                    .earliest_departure_time()
+                    .earliest_departure_time(timestamp)
                 {
                     if !edt.has_elapsed(timestamp) {
                         // We can't transmit more until a future time, so arm the pacing
@@ -1028,6 +1249,9 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
 
         self.path_manager
             .on_timeout(timestamp, random_generator, &mut publisher)?;
+        if let Some(migration) = self.migration.as_mut() {
+            migration.poll(&mut self.path_manager, &mut publisher);
+        }
         self.local_id_registry.on_timeout(timestamp);
         self.space_manager.on_timeout(
             &mut self.local_id_registry,
@@ -1058,6 +1282,17 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             return Err(connection::Error::idle_timer_expired());
         }
 
+        if self
+            .timers
+            .dead_peer_timer
+            .poll_expiration(timestamp)
+            .is_ready()
+        {
+            if let Some(timeout) = self.limits.dead_peer_timeout() {
+                return Err(connection::Error::dead_peer_detected(timeout));
+            }
+        }
+
         if self
             .timers
             .supervisor_timer
@@ -1067,6 +1302,50 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             self.on_supervisor_timeout(timestamp, subscriber, supervisor_context)?;
         }
 
+        if self
+            .timers
+            .graceful_close_timer
+            .poll_expiration(timestamp)
+            .is_ready()
+        {
+            if let Some(graceful_close) = self.graceful_close.as_ref() {
+                return Err(connection::Error::graceful_close_timed_out(
+                    graceful_close.timeout,
+                ));
+            }
+        }
+
+        if self
+            .timers
+            .max_lifetime_timer
+            .poll_expiration(timestamp)
+            .is_ready()
+        {
+            if self.max_lifetime_reached {
+                // the connection didn't finish flushing in time so the outstanding data is
+                // discarded
+                return Err(connection::Error::graceful_close_timed_out(
+                    self.limits.max_connection_lifetime_flush_timeout(),
+                ));
+            }
+
+            self.start_max_lifetime_close(timestamp);
+        }
+
+        if self
+            .timers
+            .accept_timeout_timer
+            .poll_expiration(timestamp)
+            .is_ready()
+        {
+            if let Some(accept_timeout) = self.accept_timeout.as_mut() {
+                accept_timeout.expired = true;
+                if let Some(waker) = accept_timeout.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+
         // check to see if we're flushing the connection
         if self.poll_flush().is_ready() {
             return self.error;
@@ -1101,16 +1380,75 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         timestamp: Timestamp,
         subscriber: &mut Config::EventSubscriber,
         datagram: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
+        congestion_controller_endpoint: &mut Config::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut Config::MtuControllerEndpoint,
+        pacer_endpoint: &mut Config::PacerEndpoint,
+        mtu_config: mtu::Config,
+        random_generator: &mut Config::RandomGenerator,
     ) -> Result<(), connection::Error> {
         // reset the queued state first so that new wakeup request are not missed
         self.wakeup_handle.wakeup_handled();
 
         // check if crypto progress can be made
-        self.update_crypto_state(timestamp, subscriber, datagram)?;
+        self.update_crypto_state(timestamp, subscriber, datagram, connection_limits)?;
 
         // return an error if the application set one
         self.error?;
 
+        // Application calls aren't aware of the current time and don't have access to the
+        // endpoint providers, so the state they request is applied on the next wakeup.
+
+        // let the streams know about the current time
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.stream_manager.on_wakeup(timestamp);
+        }
+
+        // start the graceful close timer
+        if let Some(graceful_close) = self.graceful_close.as_ref() {
+            if !self.timers.graceful_close_timer.is_armed() {
+                self.timers
+                    .graceful_close_timer
+                    .set(timestamp + graceful_close.timeout);
+            }
+        }
+
+        // start the accept timeout timer
+        if let Some(accept_timeout) = self.accept_timeout.as_ref() {
+            if !accept_timeout.expired && !self.timers.accept_timeout_timer.is_armed() {
+                self.timers
+                    .accept_timeout_timer
+                    .set(timestamp + accept_timeout.timeout);
+            }
+        }
+
+        // rotate the connection IDs requested by the application
+        if core::mem::take(&mut self.rotate_connection_ids) {
+            self.local_id_registry.rotate_connection_ids(timestamp);
+        }
+
+        // create the path requested by the application
+        if let Some(migration) = self.migration.as_mut() {
+            let mut publisher = self.event_context.publisher(timestamp, subscriber);
+
+            if migration.path_id.is_none() && migration.result.is_none() {
+                match self.path_manager.on_migration_requested(
+                    migration.local_address.into(),
+                    congestion_controller_endpoint,
+                    mtu_controller_endpoint,
+                    pacer_endpoint,
+                    mtu_config,
+                    random_generator,
+                    &mut publisher,
+                ) {
+                    Ok(path_id) => migration.path_id = Some(path_id),
+                    Err(error) => migration.finish(Err(error)),
+                }
+            }
+
+            migration.poll(&mut self.path_manager, &mut publisher);
+        }
+
         Ok(())
     }
 
@@ -1120,8 +1458,10 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         path_handle: &Config::PathHandle,
         datagram: &DatagramInfo,
         congestion_controller_endpoint: &mut Config::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut Config::MtuControllerEndpoint,
+        pacer_endpoint: &mut Config::PacerEndpoint,
         path_migration: &mut Config::PathMigrationValidator,
-        max_mtu: MaxMtu,
+        mtu_config: mtu::Config,
         subscriber: &mut Config::EventSubscriber,
     ) -> Result<path::Id, DatagramDropReason> {
         let mut publisher = self.event_context.publisher(datagram.timestamp, subscriber);
@@ -1144,8 +1484,10 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             datagram,
             handshake_confirmed,
             congestion_controller_endpoint,
+            mtu_controller_endpoint,
+            pacer_endpoint,
             path_migration,
-            max_mtu,
+            mtu_config,
             &mut publisher,
         )?;
 
@@ -1189,6 +1531,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         subscriber: &mut Config::EventSubscriber,
         packet_interceptor: &mut Config::PacketInterceptor,
         datagram_endpoint: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-7.2
         //= type=TODO
@@ -1229,6 +1572,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 subscriber,
                 packet_interceptor,
                 datagram_endpoint,
+                connection_limits,
             )?;
         }
 
@@ -1245,6 +1589,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         subscriber: &mut Config::EventSubscriber,
         packet_interceptor: &mut Config::PacketInterceptor,
         datagram_endpoint: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         if let Some((space, handshake_status)) = self.space_manager.initial_mut() {
             let mut publisher = self.event_context.publisher(datagram.timestamp, subscriber);
@@ -1296,12 +1641,18 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 handshake_status,
                 &mut self.local_id_registry,
                 random_generator,
+                &mut self.remote_close_reason,
                 &mut publisher,
                 packet_interceptor,
             )?;
 
             // try to move the crypto state machine forward
-            self.update_crypto_state(datagram.timestamp, subscriber, datagram_endpoint)?;
+            self.update_crypto_state(
+                datagram.timestamp,
+                subscriber,
+                datagram_endpoint,
+                connection_limits,
+            )?;
 
             // notify the connection a packet was processed
             self.on_processed_packet(&processed_packet, subscriber)?;
@@ -1321,6 +1672,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         subscriber: &mut Config::EventSubscriber,
         packet_interceptor: &mut Config::PacketInterceptor,
         datagram_endpoint: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
     ) -> Result<(), ProcessingError> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-5.2.1
         //= type=TODO
@@ -1352,6 +1704,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 ),
             });
 
+            handshake_status.on_handshake_received(datagram.timestamp, &mut publisher);
+
             let processed_packet = space.handle_cleartext_payload(
                 packet.packet_number,
                 packet.payload,
@@ -1361,6 +1715,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 handshake_status,
                 &mut self.local_id_registry,
                 random_generator,
+                &mut self.remote_close_reason,
                 &mut publisher,
                 packet_interceptor,
             )?;
@@ -1384,7 +1739,12 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             self.path_manager[path_id].on_handshake_packet();
 
             // try to move the crypto state machine forward
-            self.update_crypto_state(datagram.timestamp, subscriber, datagram_endpoint)?;
+            self.update_crypto_state(
+                datagram.timestamp,
+                subscriber,
+                datagram_endpoint,
+                connection_limits,
+            )?;
 
             // notify the connection a packet was processed
             self.on_processed_packet(&processed_packet, subscriber)?;
@@ -1483,6 +1843,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 handshake_status,
                 &mut self.local_id_registry,
                 random_generator,
+                &mut self.remote_close_reason,
                 &mut publisher,
                 packet_interceptor,
             )?;
@@ -1772,7 +2133,9 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             .application_mut()
             .ok_or_else(connection::Error::unspecified)?;
 
-        let mut api_context = ConnectionApiCallContext::from_wakeup_handle(&self.wakeup_handle);
+        let now = self.clock.as_ref().map(|clock| clock.get_time());
+        let mut api_context =
+            ConnectionApiCallContext::from_wakeup_handle(&self.wakeup_handle).with_timestamp(now);
 
         space
             .stream_manager
@@ -1799,6 +2162,46 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         space.stream_manager.poll_accept(stream_type, context)
     }
 
+    fn poll_accept_stream_timeout(
+        &mut self,
+        stream_type: Option<stream::StreamType>,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<Option<stream::StreamId>, connection::AcceptTimeoutError>> {
+        if let Poll::Ready(result) = self.poll_accept_stream(stream_type, context) {
+            self.reset_accept_stream_timeout();
+            return result.map_err(Into::into).into();
+        }
+
+        match self.accept_timeout.as_mut() {
+            Some(accept_timeout) if accept_timeout.expired => {
+                // the connection stays open; the application can try accepting again
+                self.accept_timeout = None;
+                return Err(connection::AcceptTimeoutError::TimedOut).into();
+            }
+            Some(accept_timeout) => {
+                accept_timeout.waker = Some(context.waker().clone());
+            }
+            None => {
+                self.accept_timeout = Some(AcceptTimeout {
+                    timeout,
+                    expired: false,
+                    waker: Some(context.waker().clone()),
+                });
+
+                // arm the timer on the next wakeup
+                self.wakeup_handle.wakeup();
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn reset_accept_stream_timeout(&mut self) {
+        self.accept_timeout = None;
+        self.timers.accept_timeout_timer.cancel();
+    }
+
     fn poll_open_stream(
         &mut self,
         stream_type: stream::StreamType,
@@ -1834,6 +2237,93 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.wakeup_handle.wakeup();
     }
 
+    fn poll_close_gracefully(
+        &mut self,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        if let Err(error) = self.error {
+            return match error {
+                // the connection finished flushing all of the outstanding data
+                connection::Error::Closed {
+                    initiator: endpoint::Location::Local,
+                    ..
+                } if self.graceful_close.is_some() => Ok(()),
+                _ => Err(error),
+            }
+            .into();
+        }
+
+        if let Some(graceful_close) = self.graceful_close.as_mut() {
+            graceful_close.waker = Some(context.waker().clone());
+            return Poll::Pending;
+        }
+
+        self.graceful_close = Some(GracefulClose {
+            timeout,
+            waker: Some(context.waker().clone()),
+        });
+
+        // stop accepting new data and give the connection some time to flush all outstanding streams
+        self.state = ConnectionState::Flushing;
+        let _ = self.poll_flush();
+
+        self.wakeup_handle.wakeup();
+
+        Poll::Pending
+    }
+
+    fn poll_migrate(
+        &mut self,
+        local_address: SocketAddress,
+        context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>> {
+        self.error?;
+
+        if let Some(migration) = self.migration.as_mut() {
+            if migration.local_address != local_address {
+                return Err(connection::MigrationError::InProgress).into();
+            }
+
+            if let Some(result) = migration.result {
+                self.migration = None;
+                return result.into();
+            }
+
+            migration.waker = Some(context.waker().clone());
+            return Poll::Pending;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+        //# Clients are responsible for initiating all migrations.
+        if Config::ENDPOINT_TYPE.is_server() {
+            return Err(connection::MigrationError::NotClient).into();
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+        //# An endpoint MUST NOT initiate
+        //# connection migration before the handshake is confirmed, as defined in
+        //# Section 4.1.2 of [QUIC-TLS].
+        if !self.space_manager.is_handshake_confirmed() {
+            return Err(connection::MigrationError::HandshakeNotConfirmed).into();
+        }
+
+        if *self.path_manager.active_path().handle.local_address() == local_address {
+            return Ok(()).into();
+        }
+
+        self.migration = Some(Migration {
+            local_address,
+            path_id: None,
+            result: None,
+            waker: Some(context.waker().clone()),
+        });
+
+        self.wakeup_handle.wakeup();
+
+        Poll::Pending
+    }
+
     fn server_name(&self) -> Option<ServerName> {
         self.space_manager.server_name.clone()
     }
@@ -1842,6 +2332,23 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.space_manager.application_protocol.clone()
     }
 
+    fn peer_certificate_chain(&self) -> Vec<Bytes> {
+        self.space_manager.peer_certificate_chain.clone()
+    }
+
+    fn ocsp_response(&self) -> Option<Bytes> {
+        self.space_manager.ocsp_response.clone()
+    }
+
+    fn tls_info(&self) -> Option<tls::TlsInfo> {
+        self.space_manager.tls_info
+    }
+
+    fn close_reason(&self) -> Option<close::Reason> {
+        let error = self.error.err()?;
+        Some(close::Reason::new(error, self.remote_close_reason.as_ref()))
+    }
+
     fn ping(&mut self) -> Result<(), connection::Error> {
         self.error?;
 
@@ -1878,6 +2385,72 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(())
     }
 
+    fn keep_alive_with_period(
+        &mut self,
+        period: Duration,
+    ) -> Result<(), connection::KeepAliveError> {
+        self.error?;
+
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.keep_alive_with_period(period)?;
+
+            self.wakeup_handle.wakeup();
+        } else {
+            debug_assert!(
+                false,
+                "applications can't interact with the connection until the application space is available"
+            );
+            return Err(connection::Error::unspecified().into());
+        }
+
+        Ok(())
+    }
+
+    fn initiate_key_update(&mut self) -> Result<(), connection::Error> {
+        self.error?;
+
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.initiate_key_update();
+
+            self.wakeup_handle.wakeup();
+        } else {
+            debug_assert!(
+                false,
+                "applications can't interact with the connection until the application space is available"
+            );
+            return Err(connection::Error::unspecified());
+        }
+
+        Ok(())
+    }
+
+    fn rotate_connection_id(&mut self) -> Result<(), connection::Error> {
+        self.error?;
+
+        self.rotate_connection_ids = true;
+        self.wakeup_handle.wakeup();
+
+        Ok(())
+    }
+
+    fn request_immediate_ack(&mut self) -> Result<(), connection::Error> {
+        self.error?;
+
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.request_immediate_ack();
+
+            self.wakeup_handle.wakeup();
+        } else {
+            debug_assert!(
+                false,
+                "applications can't interact with the connection until the application space is available"
+            );
+            return Err(connection::Error::unspecified());
+        }
+
+        Ok(())
+    }
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         Ok(*self.path_manager.active_path().handle.local_address())
     }
@@ -1886,6 +2459,20 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(*self.path_manager.active_path().handle.remote_address())
     }
 
+    fn path_mtu(&self) -> Result<usize, connection::Error> {
+        Ok(self.path_manager.active_path().mtu_controller.mtu())
+    }
+
+    fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error> {
+        // the limits are updated with the minimum of both values once the peer's transport
+        // parameters are received
+        Ok(self.limits.max_idle_timeout())
+    }
+
+    fn statistics(&self) -> Result<connection::Statistics, connection::Error> {
+        Ok(self.path_manager.statistics())
+    }
+
     fn error(&self) -> Option<connection::Error> {
         self.error.err()
     }
@@ -1966,10 +2553,15 @@ impl<Config: endpoint::Config> transmission::interest::Provider for ConnectionIm
         self.space_manager.transmission_interest(query)?;
 
         self.local_id_registry.transmission_interest(query)?;
-        self.path_manager
-            .active_path()
-            .mtu_controller
-            .transmission_interest(query)?;
+
+        // MTU probes are only sent while the connection is active so new probes are suspended
+        // while the connection is flushing
+        if self.state == ConnectionState::Active {
+            self.path_manager
+                .active_path()
+                .mtu_controller
+                .transmission_interest(query)?;
+        }
 
         Ok(())
     }
@@ -28,6 +28,15 @@ pub struct ConnectionTimers {
     pub max_handshake_duration_timer: Timer,
     /// The timer for calling the connection supervisor
     pub supervisor_timer: Timer,
+    /// The timer for closing the connection if a graceful close is still flushing
+    pub graceful_close_timer: Timer,
+    /// The timer for returning from an application request to accept a stream
+    pub accept_timeout_timer: Timer,
+    /// The timer for gracefully closing the connection once it reaches its max lifetime, and
+    /// then for closing it if the outstanding streams aren't flushed in time
+    pub max_lifetime_timer: Timer,
+    /// The timer for closing the connection if the peer stops acknowledging packets
+    pub dead_peer_timer: Timer,
 }
 
 impl ConnectionTimers {
@@ -38,6 +47,10 @@ impl ConnectionTimers {
         self.pacing_timer.cancel();
         self.max_handshake_duration_timer.cancel();
         self.supervisor_timer.cancel();
+        self.graceful_close_timer.cancel();
+        self.accept_timeout_timer.cancel();
+        self.max_lifetime_timer.cancel();
+        self.dead_peer_timer.cancel();
     }
 }
 
@@ -50,6 +63,10 @@ impl timer::Provider for ConnectionTimers {
         self.pacing_timer.timers(query)?;
         self.max_handshake_duration_timer.timers(query)?;
         self.supervisor_timer.timers(query)?;
+        self.graceful_close_timer.timers(query)?;
+        self.accept_timeout_timer.timers(query)?;
+        self.max_lifetime_timer.timers(query)?;
+        self.dead_peer_timer.timers(query)?;
 
         Ok(())
     }
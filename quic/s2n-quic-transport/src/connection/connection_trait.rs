@@ -15,11 +15,16 @@ use crate::{
     stream,
 };
 use bytes::Bytes;
-use core::task::{Context, Poll};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
 use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
     application,
     application::ServerName,
+    connection::close,
+    crypto::tls::TlsInfo,
     event::{self, builder::DatagramDropReason, supervisor, ConnectionPublisher, IntoEvent},
     inet::{DatagramInfo, SocketAddress},
     io::tx,
@@ -32,7 +37,7 @@ use s2n_quic_core::{
         zero_rtt::ProtectedZeroRtt,
         ProtectedPacket,
     },
-    path::{Handle as _, MaxMtu},
+    path::{mtu, Handle as _},
     query,
     time::Timestamp,
 };
@@ -72,13 +77,15 @@ pub trait ConnectionTrait: 'static + Send + Sized {
     /// no longer be signalled.
     fn mark_as_accepted(&mut self);
 
-    /// Generates and registers new connection IDs using the given `ConnectionIdFormat` and
-    /// `StatelessResetTokenGenerator`
+    /// Generates and registers new connection IDs using the given `ConnectionIdFormat`,
+    /// `StatelessResetTokenGenerator` and `RandomGenerator`
     fn on_new_connection_id(
         &mut self,
         connection_id_format: &mut <Self::Config as endpoint::Config>::ConnectionIdFormat,
         stateless_reset_token_generator: &mut <Self::Config as endpoint::Config>::StatelessResetTokenGenerator,
+        random_generator: &mut <Self::Config as endpoint::Config>::RandomGenerator,
         timestamp: Timestamp,
+        subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
     ) -> Result<(), LocalIdRegistrationError>;
 
     /// Queries the connection for outgoing packets
@@ -105,11 +112,18 @@ pub trait ConnectionTrait: 'static + Send + Sized {
     ) -> Result<(), connection::Error>;
 
     /// Handles all external wakeups on the [`Connection`].
+    #[allow(clippy::too_many_arguments)]
     fn on_wakeup(
         &mut self,
         timestamp: Timestamp,
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         datagram: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
+        congestion_controller_endpoint: &mut <Self::Config as endpoint::Config>::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut <Self::Config as endpoint::Config>::MtuControllerEndpoint,
+        pacer_endpoint: &mut <Self::Config as endpoint::Config>::PacerEndpoint,
+        mtu_config: mtu::Config,
+        random_generator: &mut <Self::Config as endpoint::Config>::RandomGenerator,
     ) -> Result<(), connection::Error>;
 
     // Packet handling
@@ -125,6 +139,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError>;
 
     /// Is called when an unprotected initial packet had been received
@@ -138,6 +153,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError>;
 
     /// Is called when a handshake packet had been received
@@ -151,6 +167,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
     ) -> Result<(), ProcessingError>;
 
     /// Is called when a short packet had been received
@@ -201,8 +218,10 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         path_handle: &<Self::Config as endpoint::Config>::PathHandle,
         datagram: &DatagramInfo,
         congestion_controller_endpoint: &mut <Self::Config as endpoint::Config>::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut <Self::Config as endpoint::Config>::MtuControllerEndpoint,
+        pacer_endpoint: &mut <Self::Config as endpoint::Config>::PacerEndpoint,
         migration_validator: &mut <Self::Config as endpoint::Config>::PathMigrationValidator,
-        max_mtu: MaxMtu,
+        mtu_config: mtu::Config,
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
     ) -> Result<path::Id, DatagramDropReason>;
 
@@ -223,6 +242,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
         check_for_stateless_reset: &mut bool,
     ) -> Result<(), connection::Error> {
         macro_rules! emit_drop_reason {
@@ -298,6 +318,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
                 subscriber,
                 packet_interceptor,
                 datagram_endpoint,
+                connection_limits,
             ),
             ProtectedPacket::ZeroRtt(packet) => self.handle_zero_rtt_packet(
                 datagram,
@@ -314,6 +335,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
                 subscriber,
                 packet_interceptor,
                 datagram_endpoint,
+                connection_limits,
             ),
             ProtectedPacket::Retry(packet) => {
                 self.handle_retry_packet(datagram, path_id, packet, subscriber, packet_interceptor)
@@ -372,6 +394,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
         packet_interceptor: &mut <Self::Config as endpoint::Config>::PacketInterceptor,
         datagram_endpoint: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
+        connection_limits: &mut <Self::Config as endpoint::Config>::ConnectionLimits,
         check_for_stateless_reset: &mut bool,
     ) -> Result<(), connection::Error> {
         macro_rules! emit_drop_reason {
@@ -425,6 +448,7 @@ pub trait ConnectionTrait: 'static + Send + Sized {
                 subscriber,
                 packet_interceptor,
                 datagram_endpoint,
+                connection_limits,
                 check_for_stateless_reset,
             );
 
@@ -456,6 +480,16 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         context: &Context,
     ) -> Poll<Result<Option<stream::StreamId>, connection::Error>>;
 
+    fn poll_accept_stream_timeout(
+        &mut self,
+        stream_type: Option<stream::StreamType>,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<Option<stream::StreamId>, connection::AcceptTimeoutError>>;
+
+    /// Discards the timeout started by `poll_accept_stream_timeout`, if any
+    fn reset_accept_stream_timeout(&mut self);
+
     fn poll_open_stream(
         &mut self,
         stream_type: stream::StreamType,
@@ -465,18 +499,55 @@ pub trait ConnectionTrait: 'static + Send + Sized {
 
     fn application_close(&mut self, error: Option<application::Error>);
 
+    fn poll_close_gracefully(
+        &mut self,
+        timeout: Duration,
+        context: &Context,
+    ) -> Poll<Result<(), connection::Error>>;
+
+    fn poll_migrate(
+        &mut self,
+        local_address: SocketAddress,
+        context: &Context,
+    ) -> Poll<Result<(), connection::MigrationError>>;
+
     fn server_name(&self) -> Option<ServerName>;
 
     fn application_protocol(&self) -> Bytes;
 
+    fn peer_certificate_chain(&self) -> Vec<Bytes>;
+
+    fn ocsp_response(&self) -> Option<Bytes>;
+
+    fn tls_info(&self) -> Option<TlsInfo>;
+
+    fn close_reason(&self) -> Option<close::Reason>;
+
     fn ping(&mut self) -> Result<(), connection::Error>;
 
     fn keep_alive(&mut self, enabled: bool) -> Result<(), connection::Error>;
 
+    fn keep_alive_with_period(
+        &mut self,
+        period: Duration,
+    ) -> Result<(), connection::KeepAliveError>;
+
+    fn rotate_connection_id(&mut self) -> Result<(), connection::Error>;
+
+    fn request_immediate_ack(&mut self) -> Result<(), connection::Error>;
+
+    fn initiate_key_update(&mut self) -> Result<(), connection::Error>;
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error>;
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn path_mtu(&self) -> Result<usize, connection::Error>;
+
+    fn idle_timeout(&self) -> Result<Option<Duration>, connection::Error>;
+
+    fn statistics(&self) -> Result<connection::Statistics, connection::Error>;
+
     fn error(&self) -> Option<connection::Error>;
 
     fn query_event_context(&self, query: &mut dyn query::Query);
@@ -11,7 +11,9 @@ use crate::{
 };
 use core::convert::TryInto;
 use s2n_quic_core::{
-    ack, connection, frame,
+    ack,
+    connection::{self, limits},
+    frame,
     memo::Memo,
     packet::number::PacketNumber,
     stateless_reset,
@@ -23,17 +25,6 @@ use std::sync::{Arc, Mutex};
 /// The amount of ConnectionIds we can register without dynamic memory allocation
 const NR_STATIC_REGISTRABLE_IDS: usize = 5;
 
-//= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.1
-//# An endpoint that initiates migration and requires non-zero-length
-//# connection IDs SHOULD ensure that the pool of connection IDs
-//# available to its peer allows the peer to use a new connection ID on
-//# migration, as the peer will be unable to respond if the pool is
-//# exhausted.
-/// Limit on the number of connection IDs issued to the peer to reduce the amount
-/// of per-path state maintained. Increasing this value allows peers to probe
-/// more paths simultaneously at the expense of additional state to maintain.
-const MAX_ACTIVE_CONNECTION_ID_LIMIT: u64 = 3;
-
 /// Buffer to allow time for a peer to process and retire an expiring connection ID
 /// before the connection ID actually expires.
 ///
@@ -78,6 +69,8 @@ pub struct LocalIdRegistry {
     retire_prior_to: u32,
     /// The maximum number of connection IDs to give to the peer
     active_connection_id_limit: u8,
+    /// The locally configured upper bound on `active_connection_id_limit`
+    max_active_connection_id_limit: u8,
     /// Memoized query to track retiring and expired connection IDs
     next_expiration: Memo<Option<Timestamp>, RegisteredIds>,
     /// Memoized query to track if there is any ACK interest
@@ -247,6 +240,9 @@ impl LocalIdRegistry {
             // Initialize to 1 until we know the actual limit
             // from the peer transport parameters
             active_connection_id_limit: 1,
+            // Limits the number of connection IDs issued to the peer to reduce the amount of
+            // per-path state maintained until the configured limit is set
+            max_active_connection_id_limit: limits::ISSUED_CONNECTION_ID_LIMIT_DEFAULT,
             next_expiration: Memo::new(|ids| {
                 ids.iter()
                     .filter_map(|id_info| id_info.next_status_change_time())
@@ -320,7 +316,15 @@ impl LocalIdRegistry {
         //# such as path validation status, as its peer might interact with it
         //# over as many paths as there are issued connection IDs.
         self.active_connection_id_limit =
-            MAX_ACTIVE_CONNECTION_ID_LIMIT.min(active_connection_id_limit) as u8;
+            (self.max_active_connection_id_limit as u64).min(active_connection_id_limit) as u8;
+    }
+
+    /// Sets the locally configured upper bound on the active connection id limit
+    ///
+    /// This must be called before the limit from the peer is set with
+    /// `set_active_connection_id_limit`.
+    pub fn set_max_active_connection_id_limit(&mut self, max_active_connection_id_limit: u8) {
+        self.max_active_connection_id_limit = max_active_connection_id_limit;
     }
 
     /// Registers a connection ID mapping at the mapper with an optional expiration
@@ -333,7 +337,7 @@ impl LocalIdRegistry {
         id: &connection::LocalId,
         expiration: Option<Timestamp>,
         stateless_reset_token: stateless_reset::Token,
-    ) -> Result<(), LocalIdRegistrationError> {
+    ) -> Result<u32, LocalIdRegistrationError> {
         if self.registered_ids.iter().any(|id_info| id_info.id == *id) {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-5.1
             //# As a trivial example, this means the same connection ID
@@ -377,7 +381,7 @@ impl LocalIdRegistry {
 
         self.check_consistency();
 
-        Ok(())
+        Ok(sequence_number)
     }
 
     /// Unregisters connection IDs that have expired
@@ -423,13 +427,15 @@ impl LocalIdRegistry {
     //# accept the previously issued connection IDs until they are retired by
     //# the peer.
     /// Handles the retirement of a sequence_number received from a RETIRE_CONNECTION_ID frame
+    ///
+    /// Returns the retired connection ID, or `None` if it was already retired by the peer.
     pub fn on_retire_connection_id(
         &mut self,
         sequence_number: u32,
         destination_connection_id: &connection::LocalId,
         rtt: Duration,
         timestamp: Timestamp,
-    ) -> Result<(), LocalIdRegistrationError> {
+    ) -> Result<Option<connection::LocalId>, LocalIdRegistrationError> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
         //# Receipt of a RETIRE_CONNECTION_ID frame containing a sequence number
         //# greater than any previously sent to the peer MUST be treated as a
@@ -446,6 +452,8 @@ impl LocalIdRegistry {
             .filter(|id_info| !matches!(id_info.status, PendingRemoval(_)))
             .find(|id_info| id_info.sequence_number == sequence_number);
 
+        let mut retired_id = None;
+
         if let Some(id_info) = id_info {
             if id_info.id == *destination_connection_id {
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-19.16
@@ -464,6 +472,7 @@ impl LocalIdRegistry {
             let removal_time = timestamp + rtt * RTT_MULTIPLIER;
 
             id_info.status = PendingRemoval(removal_time);
+            retired_id = Some(id_info.id);
 
             // clear all of the memoized values
             self.ack_interest.clear();
@@ -474,7 +483,7 @@ impl LocalIdRegistry {
 
         self.check_consistency();
 
-        Ok(())
+        Ok(retired_id)
     }
 
     /// Returns the mappers interest in new connection IDs
@@ -646,6 +655,35 @@ impl LocalIdRegistry {
         self.check_consistency();
     }
 
+    /// Requests the peer to retire all of the connection IDs that were issued to it
+    ///
+    /// New connection IDs are issued in their place as the retired IDs no longer count towards
+    /// the active connection id limit. The retired IDs are removed once the peer confirms their
+    /// retirement, or after the `EXPIRATION_BUFFER` if the peer does not.
+    pub fn rotate_connection_ids(&mut self, timestamp: Timestamp) {
+        for id_info in self
+            .registered_ids
+            .iter_mut()
+            .filter(|id_info| !id_info.is_retired())
+        {
+            id_info.retire(Some(timestamp));
+
+            //= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.2
+            //# Such an endpoint can cause its peer to
+            //# retire connection IDs by sending a NEW_CONNECTION_ID frame with an
+            //# increased Retire Prior To field.
+            self.retire_prior_to = self.retire_prior_to.max(id_info.sequence_number + 1);
+        }
+
+        // clear all of the memoized values
+        self.ack_interest.clear();
+        self.transmission_interest.clear();
+        self.active_id_count.clear();
+        self.next_expiration.clear();
+
+        self.check_consistency();
+    }
+
     /// Validate that the current expiration timer is based on the next status change time
     fn check_consistency(&self) {
         if cfg!(debug_assertions) {
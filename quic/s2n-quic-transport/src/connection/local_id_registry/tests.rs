@@ -3,7 +3,7 @@
 use super::*;
 use s2n_quic_core::{
     connection,
-    connection::id::MIN_LIFETIME,
+    connection::{id::MIN_LIFETIME, limits},
     frame::{Frame, NewConnectionId},
     packet::number::PacketNumberRange,
     random,
@@ -17,7 +17,7 @@ use crate::{
         connection_id_mapper::*,
         local_id_registry::{
             LocalIdInfo, LocalIdRegistrationError, LocalIdRegistry, EXPIRATION_BUFFER,
-            MAX_ACTIVE_CONNECTION_ID_LIMIT, RTT_MULTIPLIER,
+            RTT_MULTIPLIER,
         },
         InternalConnectionIdGenerator,
     },
@@ -392,8 +392,8 @@ fn connection_id_interest() {
 
     reg1.set_active_connection_id_limit(5);
     assert_eq!(
-        MAX_ACTIVE_CONNECTION_ID_LIMIT,
-        reg1.active_connection_id_limit as u64
+        limits::ISSUED_CONNECTION_ID_LIMIT_DEFAULT,
+        reg1.active_connection_id_limit
     );
 
     assert_eq!(
@@ -499,9 +499,18 @@ fn endpoint_may_limit_connection_ids() {
     reg1.set_active_connection_id_limit(100);
 
     assert_eq!(
-        MAX_ACTIVE_CONNECTION_ID_LIMIT,
-        reg1.active_connection_id_limit as u64
+        limits::ISSUED_CONNECTION_ID_LIMIT_DEFAULT,
+        reg1.active_connection_id_limit
     );
+
+    // The locally configured limit can raise the bound
+    reg1.set_max_active_connection_id_limit(8);
+    reg1.set_active_connection_id_limit(100);
+    assert_eq!(8, reg1.active_connection_id_limit);
+
+    // The peer's limit still applies
+    reg1.set_active_connection_id_limit(2);
+    assert_eq!(2, reg1.active_connection_id_limit);
 }
 
 #[test]
@@ -899,3 +908,74 @@ fn retire_handshake_connection_id() {
         }
     }
 }
+
+#[test]
+fn rotate_connection_ids() {
+    let ext_id_1 = id(b"id01");
+    let ext_id_2 = id(b"id02");
+    let ext_id_3 = id(b"id03");
+    let now = time::now();
+
+    let (_mapper, mut reg1) = mapper(ext_id_1, None, TEST_TOKEN_1);
+    reg1.set_active_connection_id_limit(2);
+    assert!(reg1
+        .register_connection_id(&ext_id_2, None, TEST_TOKEN_2)
+        .is_ok());
+    reg1.get_connection_id_info_mut(&ext_id_2).unwrap().status = Active;
+    assert_eq!(
+        connection::id::Interest::None,
+        reg1.connection_id_interest()
+    );
+
+    reg1.rotate_connection_ids(now);
+
+    // All of the issued connection IDs are retired and the peer is asked to retire them
+    for id in [ext_id_1, ext_id_2] {
+        assert_eq!(
+            PendingRetirementConfirmation(Some(now + EXPIRATION_BUFFER)),
+            reg1.get_connection_id_info(&id).unwrap().status
+        );
+    }
+    assert_eq!(2, reg1.retire_prior_to);
+
+    // New connection IDs are requested in their place
+    assert_eq!(
+        connection::id::Interest::New(2),
+        reg1.connection_id_interest()
+    );
+    assert_eq!(
+        Ok(2),
+        reg1.register_connection_id(&ext_id_3, None, TEST_TOKEN_3)
+    );
+
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let mut write_context = MockWriteContext::new(
+        now,
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+    reg1.on_transmit(&mut write_context);
+
+    let expected_frame = Frame::NewConnectionId(NewConnectionId {
+        sequence_number: VarInt::from_u32(2),
+        retire_prior_to: VarInt::from_u32(2),
+        connection_id: ext_id_3.as_bytes(),
+        stateless_reset_token: TEST_TOKEN_3.as_ref().try_into().unwrap(),
+    });
+    assert_eq!(
+        expected_frame,
+        write_context.frame_buffer.pop_front().unwrap().as_frame()
+    );
+
+    // The peer confirms the retirement
+    assert_eq!(
+        Ok(Some(ext_id_2)),
+        reg1.on_retire_connection_id(1, &ext_id_3, Duration::default(), now)
+    );
+    assert_eq!(
+        Ok(None),
+        reg1.on_retire_connection_id(1, &ext_id_3, Duration::default(), now)
+    );
+}
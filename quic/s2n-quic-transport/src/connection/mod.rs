@@ -4,10 +4,18 @@
 //! This module contains the implementation of QUIC `Connections` and their management
 
 use crate::{
-    endpoint, path::MaxMtu, recovery::congestion_controller, space::PacketSpaceManager,
+    endpoint,
+    path::mtu,
+    recovery::{congestion_controller, pacer},
+    space::PacketSpaceManager,
     wakeup_queue::WakeupHandle,
 };
-use s2n_quic_core::{connection, event, event::supervisor, time::Timestamp};
+use s2n_quic_core::{
+    connection, event,
+    event::supervisor,
+    path::ecn,
+    time::{clock, Timestamp},
+};
 
 mod api;
 mod api_provider;
@@ -67,20 +75,30 @@ pub struct Parameters<'a, Cfg: endpoint::Config> {
     pub wakeup_handle: WakeupHandle<InternalConnectionId>,
     /// The initial congestion controller for the connection
     pub congestion_controller: <Cfg::CongestionControllerEndpoint as congestion_controller::Endpoint>::CongestionController,
+    /// The initial MTU controller for the connection
+    pub mtu_controller: <Cfg::MtuControllerEndpoint as mtu::Endpoint>::MtuController,
+    /// The initial pacer for the connection
+    pub pacer: <Cfg::PacerEndpoint as pacer::Endpoint>::Pacer,
     /// The time the connection is being created
     pub timestamp: Timestamp,
     /// The QUIC protocol version which is used for this particular connection
     pub quic_version: u32,
     /// The limits that were advertised to the peer
     pub limits: connection::Limits,
-    /// The largest maximum transmission unit (MTU) that can be sent on a path
-    pub max_mtu: MaxMtu,
+    /// The configuration used for discovering the maximum transmission unit (MTU) of each path
+    pub mtu_config: mtu::Config,
+    /// Controls how Explicit Congestion Notification (ECN) is used on each path
+    pub ecn_mode: ecn::Mode,
+    /// The clock used by the IO provider, which application calls use to timestamp requests
+    pub clock: Option<clock::Shared>,
     /// The context that should be passed to all related connection events
     pub event_context: <Cfg::EventSubscriber as event::Subscriber>::ConnectionContext,
     /// The context passed to the connection supervisor
     pub supervisor_context: &'a supervisor::Context<'a>,
     // The datagram provider for the endpoint
     pub datagram_endpoint: &'a mut Cfg::DatagramEndpoint,
+    /// The connection limits provider for the endpoint
+    pub connection_limits: &'a mut Cfg::ConnectionLimits,
     /// The event subscriber for the endpoint
     pub event_subscriber: &'a mut Cfg::EventSubscriber,
 }
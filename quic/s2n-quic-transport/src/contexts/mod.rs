@@ -100,18 +100,33 @@ pub enum ConnectionOnTransmitError {
 /// The context parameter which is passed from all external API calls
 pub struct ConnectionApiCallContext<'a> {
     wakeup_handle: &'a WakeupHandle<InternalConnectionId>,
+    timestamp: Option<Timestamp>,
 }
 
 impl<'a> ConnectionApiCallContext<'a> {
     /// Creates an [`ConnectionApiCallContext`] from a [`WakeupHandle`]
     pub fn from_wakeup_handle(wakeup_handle: &'a WakeupHandle<InternalConnectionId>) -> Self {
-        Self { wakeup_handle }
+        Self {
+            wakeup_handle,
+            timestamp: None,
+        }
+    }
+
+    /// Sets the time at which the API call was made
+    pub fn with_timestamp(mut self, timestamp: Option<Timestamp>) -> Self {
+        self.timestamp = timestamp;
+        self
     }
 
     /// Returns a reference to the WakeupHandle
     pub fn wakeup_handle(&mut self) -> &WakeupHandle<InternalConnectionId> {
         self.wakeup_handle
     }
+
+    /// Returns the time at which the API call was made, if it is known
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.timestamp
+    }
 }
 
 #[cfg(test)]
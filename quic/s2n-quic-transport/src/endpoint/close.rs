@@ -5,15 +5,50 @@ use super::handle::CloseReceiver;
 use crate::{connection, endpoint::handle::CloseSender};
 use alloc::sync::Arc;
 use core::{
+    pin::Pin,
     sync::atomic::{AtomicBool, Ordering},
     task::{Context, Poll, Waker},
+    time::Duration,
 };
+use futures_core::Stream;
+
+/// Controls how an endpoint handles its open connections when shutting down
+///
+/// In either mode, the endpoint stops accepting new connections as soon as the shutdown is
+/// requested.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ShutdownMode {
+    /// Closes all of the open connections immediately
+    Immediate,
+    /// Waits for the open connections to finish on their own, closing any connections that
+    /// remain open after the shutdown timeout
+    GracefulDrain,
+}
+
+/// A shutdown requested by the application
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Shutdown {
+    pub mode: ShutdownMode,
+    pub timeout: Duration,
+}
+
+/// Sent by the application to express interest in closing the endpoint
+#[derive(Debug)]
+pub(crate) struct Request {
+    /// Woken once the endpoint has closed
+    waker: Waker,
+    /// Set if the endpoint should also stop accepting and close its open connections
+    shutdown: Option<Shutdown>,
+}
 
 /// Held by library. Used to receive close attempts and track close state.
 #[derive(Debug)]
 pub(crate) struct CloseHandle {
     /// Used to determine if the application has interest in closing the endpoint
     first_waker: Option<Waker>,
+    /// The shutdown requested by the application that has not been applied yet
+    shutdown: Option<Shutdown>,
     /// A channel which is used to receive connection close attempts
     close_receiver: CloseReceiver,
     /// Track the endpoint open state
@@ -24,19 +59,23 @@ impl CloseHandle {
     pub fn new(close_receiver: CloseReceiver, endpoint_state: EndpointState) -> Self {
         Self {
             first_waker: None,
+            shutdown: None,
             close_receiver,
             endpoint_state,
         }
     }
 
     /// Returns `Poll::Ready` if there is interest in closing the endpoint.
-    pub fn poll_interest(&mut self) -> Poll<()> {
+    ///
+    /// The endpoint is woken up once a close request is received.
+    pub fn poll_interest(&mut self, context: &mut Context) -> Poll<()> {
         if self.first_waker.is_some() {
             Poll::Ready(())
         } else {
-            match self.close_receiver.try_next() {
-                Ok(Some(waker)) => {
-                    self.first_waker = Some(waker);
+            match Stream::poll_next(Pin::new(&mut self.close_receiver), context) {
+                Poll::Ready(Some(request)) => {
+                    self.first_waker = Some(request.waker);
+                    self.shutdown = request.shutdown;
                     Poll::Ready(())
                 }
                 _ => Poll::Pending,
@@ -44,6 +83,16 @@ impl CloseHandle {
         }
     }
 
+    /// Returns the shutdown requested by the application, if it hasn't already been taken
+    pub fn take_shutdown(&mut self) -> Option<Shutdown> {
+        self.shutdown.take()
+    }
+
+    /// Returns `true` if the endpoint has not been closed yet
+    pub fn is_open(&self) -> bool {
+        self.endpoint_state.is_open()
+    }
+
     /// Marks that the endpoint has finished processing and accepting connections and is
     /// ready to be closed.
     pub fn close(&mut self) {
@@ -52,8 +101,8 @@ impl CloseHandle {
         if let Some(waker) = self.first_waker.take() {
             waker.wake();
         }
-        while let Ok(Some(waker)) = self.close_receiver.try_next() {
-            waker.wake_by_ref();
+        while let Ok(Some(request)) = self.close_receiver.try_next() {
+            request.waker.wake();
         }
     }
 }
@@ -101,6 +150,23 @@ impl Closer {
     pub(crate) fn poll_close(
         &mut self,
         context: &mut Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        self.poll_request(None, context)
+    }
+
+    /// Polls for the endpoint to stop accepting and close all of its connections
+    pub(crate) fn poll_shutdown(
+        &mut self,
+        shutdown: Shutdown,
+        context: &mut Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        self.poll_request(Some(shutdown), context)
+    }
+
+    fn poll_request(
+        &mut self,
+        shutdown: Option<Shutdown>,
+        context: &mut Context,
     ) -> Poll<Result<(), connection::Error>> {
         if !self.endpoint_state.is_open() {
             return Poll::Ready(Ok(()));
@@ -110,7 +176,11 @@ impl Closer {
             match self.close_sender.poll_ready(context) {
                 Poll::Ready(Ok(())) => {
                     // send a waker to the endpoint, which is woken once the endpoint has closed
-                    match self.close_sender.try_send(context.waker().clone()) {
+                    let request = Request {
+                        waker: context.waker().clone(),
+                        shutdown,
+                    };
+                    match self.close_sender.try_send(request) {
                         Ok(_) => {
                             self.request_sent = true;
                         }
@@ -5,8 +5,10 @@
 
 use crate::{connection, stream};
 use s2n_quic_core::{
-    crypto::tls, datagram, endpoint, event, packet, path, random, recovery::congestion_controller,
-    stateless_reset,
+    crypto::tls,
+    datagram, endpoint, event, packet, path, random,
+    recovery::{congestion_controller, pacer},
+    stateless_reset, transport,
 };
 
 /// Configuration parameters for a QUIC endpoint
@@ -14,6 +16,10 @@ pub trait Config: 'static + Send + Sized + core::fmt::Debug {
     /// The type of the TLS endpoint which is utilized
     type TLSEndpoint: tls::Endpoint;
     type CongestionControllerEndpoint: congestion_controller::Endpoint;
+    /// The MTU controller endpoint which selects the sizes to probe for on each path
+    type MtuControllerEndpoint: path::mtu::Endpoint;
+    /// The pacer endpoint which schedules the departure of packets on each path
+    type PacerEndpoint: pacer::Endpoint;
     /// The connections type
     type Connection: connection::Trait<Config = Self>;
     /// The type of lock that synchronizes connection state between threads
@@ -42,6 +48,8 @@ pub trait Config: 'static + Send + Sized + core::fmt::Debug {
     type PathMigrationValidator: path::migration::Validator;
     /// The packet_interceptor implementation for the endpoint
     type PacketInterceptor: packet::interceptor::Interceptor;
+    /// The transport parameters interceptor implementation for the endpoint
+    type TransportParametersInterceptor: transport::parameters::interceptor::Interceptor;
     /// The datagram implementation for the endpoint
     type DatagramEndpoint: datagram::Endpoint;
 
@@ -57,6 +65,12 @@ pub struct Context<'a, Cfg: Config> {
     /// The congestion controller endpoint associated with the endpoint config
     pub congestion_controller: &'a mut Cfg::CongestionControllerEndpoint,
 
+    /// The MTU controller endpoint associated with the endpoint config
+    pub mtu_controller: &'a mut Cfg::MtuControllerEndpoint,
+
+    /// The pacer endpoint associated with the endpoint config
+    pub pacer: &'a mut Cfg::PacerEndpoint,
+
     /// The connection id format associated with the endpoint config
     pub connection_id_format: &'a mut Cfg::ConnectionIdFormat,
 
@@ -86,5 +100,7 @@ pub struct Context<'a, Cfg: Config> {
 
     pub packet_interceptor: &'a mut Cfg::PacketInterceptor,
 
+    pub transport_parameters_interceptor: &'a mut Cfg::TransportParametersInterceptor,
+
     pub datagram: &'a mut Cfg::DatagramEndpoint,
 }
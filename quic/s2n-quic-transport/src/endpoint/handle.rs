@@ -8,9 +8,12 @@ use crate::{
     connection::Connection,
     endpoint::{close, close::CloseHandle, connect},
 };
+use alloc::sync::Arc;
 use core::{
     pin::Pin,
-    task::{Context, Poll, Waker},
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+    time::Duration,
 };
 use futures_channel::mpsc;
 use futures_core::Stream;
@@ -26,9 +29,13 @@ pub(crate) type ConnectorReceiver = mpsc::Receiver<connect::Request>;
 pub(crate) type ConnectorSender = mpsc::Sender<connect::Request>;
 
 /// Held by library. Used to receive close attempts from the application.
-pub(crate) type CloseReceiver = mpsc::Receiver<Waker>;
+pub(crate) type CloseReceiver = mpsc::Receiver<close::Request>;
 /// Held by the application. Used to submit connection close attempts to the library.
-pub(crate) type CloseSender = mpsc::Sender<Waker>;
+pub(crate) type CloseSender = mpsc::Sender<close::Request>;
+
+/// Updated by the library. Used to report the number of connections past the handshake to the
+/// application.
+pub(crate) type ActiveConnectionCount = Arc<AtomicUsize>;
 
 /// The [`Handle`] allows applications to accept and open QUIC connections on an `Endpoint`.
 #[derive(Debug)]
@@ -41,17 +48,27 @@ impl Handle {
     /// Creates a new `Handle` with a limit opening connection limit.
     pub(crate) fn new(
         max_opening_connections: usize,
-    ) -> (Self, AcceptorSender, ConnectorReceiver, CloseHandle) {
+    ) -> (
+        Self,
+        AcceptorSender,
+        ConnectorReceiver,
+        CloseHandle,
+        ActiveConnectionCount,
+    ) {
         let (acceptor_sender, acceptor_receiver) = mpsc::unbounded();
         let (connector_sender, connector_receiver) = mpsc::channel(max_opening_connections);
 
         let (close_sender, close_receiver) = mpsc::channel(max_opening_connections);
 
+        let active_connections = ActiveConnectionCount::default();
+
         let endpoint_state = close::EndpointState::default();
         let closer = close::Closer::new(close_sender, endpoint_state.clone());
         let handle = Self {
             acceptor: Acceptor {
                 acceptor: acceptor_receiver,
+                closer: closer.clone(),
+                active_connections: active_connections.clone(),
             },
             connector: Connector {
                 connector: connector_sender,
@@ -63,6 +80,7 @@ impl Handle {
             acceptor_sender,
             connector_receiver,
             CloseHandle::new(close_receiver, endpoint_state),
+            active_connections,
         )
     }
 }
@@ -70,6 +88,8 @@ impl Handle {
 #[derive(Debug)]
 pub struct Acceptor {
     acceptor: AcceptorReceiver,
+    closer: close::Closer,
+    active_connections: ActiveConnectionCount,
 }
 
 impl Acceptor {
@@ -92,6 +112,26 @@ impl Acceptor {
             Poll::Pending => Poll::Pending,
         }
     }
+
+    /// Returns the number of connections that have completed the handshake and are still being
+    /// tracked by the endpoint
+    pub fn active_connection_count(&self) -> usize {
+        self.active_connections.load(Ordering::Relaxed)
+    }
+
+    /// Polls to stop accepting new connections and close the endpoint
+    ///
+    /// The open connections are handled according to the [`close::ShutdownMode`]. Connections
+    /// that are still open once the `timeout` elapses are closed.
+    pub fn poll_shutdown(
+        &mut self,
+        mode: close::ShutdownMode,
+        timeout: Duration,
+        context: &mut Context,
+    ) -> Poll<Result<(), connection::Error>> {
+        let shutdown = close::Shutdown { mode, timeout };
+        self.closer.poll_shutdown(shutdown, context)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -9,10 +9,14 @@ use crate::{
         Trait as _,
     },
     endpoint,
-    recovery::congestion_controller::{self, Endpoint as _},
-    space::PacketSpaceManager,
+    path::mtu::{self, Endpoint as _},
+    recovery::{
+        congestion_controller::{self, Endpoint as _},
+        pacer::{self, Endpoint as _},
+    },
+    space::{PacketSpaceManager, SERVER_NAME_REJECTED},
 };
-use core::convert::TryInto;
+use core::{convert::TryInto, time::Duration};
 use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
     crypto::{tls, tls::Endpoint as TLSEndpoint, CryptoSuite, InitialKey},
@@ -22,7 +26,12 @@ use s2n_quic_core::{
     packet::initial::ProtectedInitial,
     path::Handle as _,
     stateless_reset::token::Generator as _,
-    transport::{self, parameters::ServerTransportParameters},
+    transport::{
+        self,
+        parameters::{
+            interceptor::Interceptor as _, GreaseParameter, ServerTransportParameters, WithGrease,
+        },
+    },
 };
 
 impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
@@ -86,11 +95,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             // The destination connection ID on the packet was randomly generated by the client
             // so we'll generate a new initial_connection_id.
             let connection_info = ConnectionInfo::new(&remote_address);
-            initial_connection_id = self
-                .config
-                .context()
+            let context = self.config.context();
+            initial_connection_id = context
                 .connection_id_format
-                .generate(&connection_info);
+                .generate_with_random(&connection_info, context.random_generator);
         }
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-17.2
@@ -124,11 +132,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             .lifetime()
             .map(|duration| datagram.timestamp + duration);
 
-        let stateless_reset_token = self
-            .config
-            .context()
+        let context = self.config.context();
+        let stateless_reset_token = context
             .stateless_reset_token_generator
-            .generate(initial_connection_id.as_bytes());
+            .generate_with_random(initial_connection_id.as_bytes(), context.random_generator);
 
         let local_id_registry = self.connection_id_mapper.create_local_id_registry(
             internal_connection_id,
@@ -209,15 +216,32 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             .try_into()
             .expect("Failed to convert max_datagram_frame_size");
 
+        endpoint_context
+            .transport_parameters_interceptor
+            .on_local_parameters(&mut transport_parameters);
+
+        let grease = limits
+            .grease()
+            .then(|| GreaseParameter::new(endpoint_context.random_generator));
+
         let tls_session = endpoint_context
             .tls
-            .new_server_session(&transport_parameters);
+            .new_server_session(&WithGrease::new(&transport_parameters, grease));
 
-        let path_info = congestion_controller::PathInfo::new(&remote_address);
+        let mut path_info = congestion_controller::PathInfo::new(&remote_address);
+        path_info.max_datagram_size = self.mtu_config.min_udp_payload(&remote_address);
         let congestion_controller = endpoint_context
             .congestion_controller
             .new_congestion_controller(path_info);
 
+        let path_info = mtu::PathInfo::new(&remote_address);
+        let mtu_controller = endpoint_context
+            .mtu_controller
+            .new_mtu_controller(path_info);
+
+        let path_info = pacer::PathInfo::new(&remote_address);
+        let pacer = endpoint_context.pacer.new_pacer(path_info);
+
         let quic_version = packet.version;
 
         let meta = event::builder::ConnectionMeta {
@@ -235,7 +259,11 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
 
         let mut event_context = endpoint_context.event_subscriber.create_connection_context(
             &meta.clone().into_event(),
-            &event::builder::ConnectionInfo {}.into_event(),
+            &event::builder::ConnectionInfo {
+                original_destination_connection_id: (&original_destination_connection_id)
+                    .into_event(),
+            }
+            .into_event(),
         );
 
         let mut publisher = event::ConnectionPublisherSubscriber::new(
@@ -254,7 +282,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             &mut publisher,
         );
 
-        let max_mtu = self.max_mtu;
+        let mtu_config = self.mtu_config;
         let connection_parameters = connection::Parameters {
             internal_connection_id,
             local_id_registry,
@@ -268,14 +296,19 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             local_connection_id: initial_connection_id,
             path_handle: header.path,
             congestion_controller,
+            mtu_controller,
+            pacer,
             timestamp: datagram.timestamp,
             quic_version,
             limits,
-            max_mtu,
+            mtu_config,
+            ecn_mode: self.ecn_mode,
+            clock: self.clock.clone(),
             event_context,
             supervisor_context: &supervisor_context,
             event_subscriber: endpoint_context.event_subscriber,
             datagram_endpoint: endpoint_context.datagram,
+            connection_limits: endpoint_context.connection_limits,
         };
 
         let mut connection = <Config as endpoint::Config>::Connection::new(connection_parameters)?;
@@ -287,8 +320,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
                     &header.path,
                     datagram,
                     endpoint_context.congestion_controller,
+                    endpoint_context.mtu_controller,
+                    endpoint_context.pacer,
                     endpoint_context.path_migration,
-                    max_mtu,
+                    mtu_config,
                     endpoint_context.event_subscriber,
                 );
 
@@ -320,6 +355,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
                         endpoint_context.event_subscriber,
                         endpoint_context.packet_interceptor,
                         endpoint_context.datagram,
+                        endpoint_context.connection_limits,
                     )
                     .map_err(|err| {
                         use connection::ProcessingError;
@@ -344,6 +380,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
                     endpoint_context.event_subscriber,
                     endpoint_context.packet_interceptor,
                     endpoint_context.datagram,
+                    endpoint_context.connection_limits,
                     &mut false,
                 )?;
 
@@ -353,17 +390,44 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
         if let Err(error) = handle_first_packet(&mut connection) {
             let endpoint_context = self.config.context();
 
-            connection.with_event_publisher(
+            // Rejections from the connection limiter are sent to the peer in a CONNECTION_CLOSE
+            // frame so it can stop the handshake instead of retransmitting its Initial packets
+            // until it times out. Any other error drops the connection.
+            let is_server_name_rejected = matches!(
+                error,
+                connection::Error::Transport { code, reason, .. }
+                    if code == transport::Error::from(SERVER_NAME_REJECTED).code
+                        && reason == SERVER_NAME_REJECTED.reason
+            );
+
+            if !is_server_name_rejected {
+                let statistics = connection.statistics().unwrap_or_default();
+                connection.with_event_publisher(
+                    datagram.timestamp,
+                    None,
+                    endpoint_context.event_subscriber,
+                    |publisher, _path| {
+                        use s2n_quic_core::event::builder::ConnectionClosed;
+                        // the connection was created for this datagram
+                        publisher.on_connection_closed(ConnectionClosed {
+                            error,
+                            duration: Duration::ZERO,
+                            statistics,
+                        });
+                    },
+                );
+
+                return Err(error);
+            }
+
+            connection.close(
+                error,
+                endpoint_context.connection_close_formatter,
+                &mut self.close_packet_buffer,
                 datagram.timestamp,
-                None,
                 endpoint_context.event_subscriber,
-                |publisher, _path| {
-                    use s2n_quic_core::event::builder::ConnectionClosed;
-                    publisher.on_connection_closed(ConnectionClosed { error });
-                },
+                endpoint_context.packet_interceptor,
             );
-
-            return Err(error);
         }
 
         //= https://www.rfc-editor.org/rfc/rfc9001#section-4.3
@@ -11,8 +11,11 @@ use crate::{
         InternalConnectionId, InternalConnectionIdGenerator, Trait as _,
     },
     endpoint,
-    endpoint::close::CloseHandle,
-    recovery::congestion_controller::{self, Endpoint as _},
+    endpoint::close::{self, CloseHandle},
+    recovery::{
+        congestion_controller::{self, Endpoint as _},
+        pacer::{self, Endpoint as _},
+    },
     space::PacketSpaceManager,
     wakeup_queue::WakeupQueue,
 };
@@ -37,12 +40,17 @@ use s2n_quic_core::{
     io::{rx, tx},
     packet::{initial::ProtectedInitial, interceptor::Interceptor, ProtectedPacket},
     path,
-    path::{Handle as _, MaxMtu},
+    path::{ecn, mtu, mtu::Endpoint as _, Handle as _, MaxMtu},
     random::Generator as _,
     stateless_reset::token::{Generator as _, LEN as StatelessResetTokenLen},
-    time::{Clock, Timestamp},
+    time::{clock, timer::Provider as _, Clock, Duration, Timer, Timestamp},
     token::{self, Format},
-    transport::parameters::ClientTransportParameters,
+    transport::{
+        self,
+        parameters::{
+            interceptor::Interceptor as _, ClientTransportParameters, GreaseParameter, WithGrease,
+        },
+    },
 };
 
 pub mod close;
@@ -77,6 +85,10 @@ pub struct Endpoint<Cfg: Config> {
     wakeup_queue: WakeupQueue<InternalConnectionId>,
     /// Used to receive close attempts and track close state.
     close_handle: CloseHandle,
+    /// Armed while the open connections are draining during a graceful shutdown
+    shutdown_timer: Timer,
+    /// The amount of time the open connections have to drain during a graceful shutdown
+    shutdown_timeout: Duration,
     /// This queue contains wakeups we retrieved from the [`Self::wakeup_queue`] earlier.
     /// This is not a local variable in order to reuse the allocated queue capacity in between
     /// [`Endpoint`] interactions.
@@ -86,7 +98,11 @@ pub struct Endpoint<Cfg: Config> {
     stateless_reset_dispatch: stateless_reset::Dispatch<Cfg::PathHandle>,
     close_packet_buffer: packet_buffer::Buffer,
     /// The largest maximum transmission unit (MTU) that can be sent on a path
-    max_mtu: MaxMtu,
+    mtu_config: mtu::Config,
+    /// Controls how Explicit Congestion Notification (ECN) is used on each path
+    ecn_mode: ecn::Mode,
+    /// The clock used by the IO provider, if it was shared with the endpoint
+    clock: Option<clock::Shared>,
 }
 
 impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
@@ -174,15 +190,24 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
         cx: &mut task::Context<'_>,
         clock: &C,
     ) -> Poll<Result<usize, s2n_quic_core::endpoint::CloseError>> {
-        if self.close_handle.poll_interest().is_ready() // poll for close interest
-            && self.connections.is_empty() // wait for all connections to close gracefully
-            && self.connections.is_open()
-        {
-            // transition to close state
-            self.close_handle.close();
+        let mut wakeup_count = 0;
+
+        // poll for close interest
+        if self.close_handle.poll_interest(cx).is_ready() {
+            if let Some(shutdown) = self.close_handle.take_shutdown() {
+                self.on_shutdown(shutdown, clock.get_time());
+                // make sure the connections are polled after the shutdown is applied
+                wakeup_count += 1;
+            }
+
+            // wait for all connections to close gracefully
+            if self.connections.is_empty() && self.close_handle.is_open() {
+                // transition to close state
+                self.close_handle.close();
 
-            // stop accepting new connections and prepare to close the endpoint
-            self.connections.close();
+                // stop accepting new connections and prepare to close the endpoint
+                self.connections.close();
+            }
         }
 
         // Drop the endpoint if there is no more progress to be made.
@@ -194,8 +219,9 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
             .poll_pending_wakeups(&mut self.dequeued_wakeups, cx);
 
         let mut now: Option<Timestamp> = None;
-        let mut wakeup_count = self.dequeued_wakeups.len();
+        wakeup_count += self.dequeued_wakeups.len();
         let close_packet_buffer = &mut self.close_packet_buffer;
+        let mtu_config = self.mtu_config;
         let endpoint_context = self.config.context();
 
         for internal_id in self.dequeued_wakeups.drain(..) {
@@ -213,6 +239,12 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                     timestamp,
                     endpoint_context.event_subscriber,
                     endpoint_context.datagram,
+                    endpoint_context.connection_limits,
+                    endpoint_context.congestion_controller,
+                    endpoint_context.mtu_controller,
+                    endpoint_context.pacer,
+                    mtu_config,
+                    endpoint_context.random_generator,
                 ) {
                     conn.close(
                         error,
@@ -258,12 +290,29 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
 
     #[inline]
     fn timeout(&self) -> Option<Timestamp> {
-        self.connections.next_expiration()
+        let mut timeout = self.connections.next_expiration();
+        let _ = self.shutdown_timer.timers(&mut timeout);
+        timeout
     }
 
     #[inline]
     fn set_max_mtu(&mut self, max_mtu: MaxMtu) {
-        self.max_mtu = max_mtu
+        self.mtu_config.max_mtu = max_mtu
+    }
+
+    #[inline]
+    fn set_mtu_config(&mut self, mtu_config: mtu::Config) {
+        self.mtu_config = mtu_config
+    }
+
+    #[inline]
+    fn set_ecn_mode(&mut self, ecn_mode: ecn::Mode) {
+        self.ecn_mode = ecn_mode
+    }
+
+    #[inline]
+    fn set_clock(&mut self, clock: clock::Shared) {
+        self.clock = Some(clock)
     }
 
     #[inline]
@@ -296,7 +345,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
     fn new(mut config: Cfg) -> (Self, handle::Handle) {
         // TODO make this limit configurable
         let max_opening_connections = 1000;
-        let (handle, acceptor_sender, connector_receiver, close_handle) =
+        let (handle, acceptor_sender, connector_receiver, close_handle, active_connections) =
             handle::Handle::new(max_opening_connections);
 
         let connection_id_mapper =
@@ -304,17 +353,25 @@ impl<Cfg: Config> Endpoint<Cfg> {
 
         let endpoint = Self {
             config,
-            connections: ConnectionContainer::new(acceptor_sender, connector_receiver),
+            connections: ConnectionContainer::new(
+                acceptor_sender,
+                connector_receiver,
+                active_connections,
+            ),
             connection_id_generator: InternalConnectionIdGenerator::new(),
             connection_id_mapper,
             wakeup_queue: WakeupQueue::new(),
             close_handle,
+            shutdown_timer: Default::default(),
+            shutdown_timeout: Duration::ZERO,
             dequeued_wakeups: VecDeque::new(),
             version_negotiator: version::Negotiator::default(),
             retry_dispatch: retry::Dispatch::default(),
             stateless_reset_dispatch: stateless_reset::Dispatch::default(),
             close_packet_buffer: Default::default(),
-            max_mtu: Default::default(),
+            mtu_config: Default::default(),
+            ecn_mode: Default::default(),
+            clock: None,
         };
 
         (endpoint, handle)
@@ -334,15 +391,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
 
         let remote_address = header.path.remote_address();
 
-        let attempt = s2n_quic_core::endpoint::limits::ConnectionAttempt::new(
-            self.connections.handshake_connections(),
-            self.connections.len(),
-            &remote_address,
-            timestamp.into_event(),
-        );
-
         let context = self.config.context();
-        let outcome = context.endpoint_limits.on_connection_attempt(&attempt);
         let mut publisher = event::EndpointPublisherSubscriber::new(
             event::builder::EndpointMeta {
                 endpoint_type: Cfg::ENDPOINT_TYPE,
@@ -352,6 +401,26 @@ impl<Cfg: Config> Endpoint<Cfg> {
             context.event_subscriber,
         );
 
+        // filter the source address before doing any other work for the attempt
+        let event_address: event::api::SocketAddress = (&remote_address).into_event();
+        if !context.endpoint_limits.accept_connection(&event_address) {
+            let local_address = header.path.local_address();
+            publisher.on_endpoint_packet_dropped(event::builder::EndpointPacketDropped {
+                reason: event::builder::PacketDropReason::AddressFiltered {
+                    path: initial_packet_path(&local_address, &remote_address, packet),
+                },
+            });
+            return None;
+        }
+
+        let attempt = s2n_quic_core::endpoint::limits::ConnectionAttempt::new(
+            self.connections.handshake_connections(),
+            self.connections.len(),
+            &remote_address,
+            timestamp.into_event(),
+        );
+        let outcome = context.endpoint_limits.on_connection_attempt(&attempt);
+
         match outcome {
             Outcome::Allow { .. } => Some(()),
             Outcome::Retry { .. } => {
@@ -366,7 +435,9 @@ impl<Cfg: Config> Endpoint<Cfg> {
 
                 let connection_info = ConnectionInfo::new(&remote_address);
 
-                let local_connection_id = context.connection_id_format.generate(&connection_info);
+                let local_connection_id = context
+                    .connection_id_format
+                    .generate_with_random(&connection_info, context.random_generator);
 
                 self.retry_dispatch.queue::<
                     _,
@@ -403,6 +474,15 @@ impl<Cfg: Config> Endpoint<Cfg> {
                 });
                 None
             }
+            Outcome::RateLimited { .. } => {
+                let local_address = header.path.local_address();
+                publisher.on_endpoint_packet_dropped(event::builder::EndpointPacketDropped {
+                    reason: event::builder::PacketDropReason::RateLimited {
+                        path: initial_packet_path(&local_address, &remote_address, packet),
+                    },
+                });
+                None
+            }
             _ => {
                 publisher.on_endpoint_datagram_dropped(event::builder::EndpointDatagramDropped {
                     len: payload_len as u16,
@@ -545,7 +625,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
             .lookup_internal_connection_id(&datagram.destination_connection_id)
         {
             let mut check_for_stateless_reset = false;
-            let max_mtu = self.max_mtu;
+            let mtu_config = self.mtu_config;
 
             let _ = self.connections.with_connection(internal_id, |conn| {
                 // The path `Id` needs to be passed around instead of the path to get around `&mut self` and
@@ -555,8 +635,10 @@ impl<Cfg: Config> Endpoint<Cfg> {
                         &header.path,
                         datagram,
                         endpoint_context.congestion_controller,
+                        endpoint_context.mtu_controller,
+                        endpoint_context.pacer,
                         endpoint_context.path_migration,
-                        max_mtu,
+                        mtu_config,
                         endpoint_context.event_subscriber,
                     )
                     .map_err(|datagram_drop_reason| {
@@ -583,6 +665,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     endpoint_context.event_subscriber,
                     endpoint_context.packet_interceptor,
                     endpoint_context.datagram,
+                    endpoint_context.connection_limits,
                     &mut check_for_stateless_reset,
                 ) {
                     //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2.1
@@ -608,6 +691,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     endpoint_context.event_subscriber,
                     endpoint_context.packet_interceptor,
                     endpoint_context.datagram,
+                    endpoint_context.connection_limits,
                     &mut check_for_stateless_reset,
                 ) {
                     //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2.1
@@ -796,11 +880,13 @@ impl<Cfg: Config> Endpoint<Cfg> {
         datagram: &DatagramInfo,
         destination_connection_id: &LocalId,
     ) {
-        let token = self
-            .config
-            .context()
+        let context = self.config.context();
+        let token = context
             .stateless_reset_token_generator
-            .generate(destination_connection_id.as_bytes());
+            .generate_with_random(
+                destination_connection_id.as_bytes(),
+                context.random_generator,
+            );
         let max_tag_length = self.config.context().tls.max_tag_length();
         // The datagram payload length is used as the packet length since
         // a stateless reset is only sent if the first packet in a datagram is
@@ -874,6 +960,12 @@ impl<Cfg: Config> Endpoint<Cfg> {
     }
 
     fn on_timeout(&mut self, timestamp: Timestamp) {
+        if self.shutdown_timer.poll_expiration(timestamp).is_ready() {
+            // close the connections that didn't finish draining in time
+            let error = connection::Error::graceful_close_timed_out(self.shutdown_timeout);
+            self.close_connections(error, timestamp);
+        }
+
         let connection_id_mapper = &mut self.connection_id_mapper;
         let close_packet_buffer = &mut self.close_packet_buffer;
         let endpoint_context = self.config.context();
@@ -904,7 +996,9 @@ impl<Cfg: Config> Endpoint<Cfg> {
                 let result = connection.on_new_connection_id(
                     endpoint_context.connection_id_format,
                     endpoint_context.stateless_reset_token_generator,
+                    endpoint_context.random_generator,
                     timestamp,
+                    endpoint_context.event_subscriber,
                 );
                 if result.is_ok() {
                     ConnectionContainerIterationResult::Continue
@@ -916,6 +1010,41 @@ impl<Cfg: Config> Endpoint<Cfg> {
             });
     }
 
+    /// Stops accepting new connections and starts closing the open connections
+    fn on_shutdown(&mut self, shutdown: close::Shutdown, timestamp: Timestamp) {
+        if Cfg::ENDPOINT_TYPE.is_server() {
+            self.connections.stop_accepting();
+        }
+
+        match shutdown.mode {
+            close::ShutdownMode::Immediate => {
+                let error = transport::Error::NO_ERROR.with_reason("endpoint shutdown");
+                self.close_connections(error.into(), timestamp);
+            }
+            close::ShutdownMode::GracefulDrain => {
+                self.shutdown_timeout = shutdown.timeout;
+                self.shutdown_timer.set(timestamp + shutdown.timeout);
+            }
+        }
+    }
+
+    /// Closes all of the open connections with the provided error
+    fn close_connections(&mut self, error: connection::Error, timestamp: Timestamp) {
+        let close_packet_buffer = &mut self.close_packet_buffer;
+        let endpoint_context = self.config.context();
+
+        self.connections.for_each_connection(|conn| {
+            conn.close(
+                error,
+                endpoint_context.connection_close_formatter,
+                close_packet_buffer,
+                timestamp,
+                endpoint_context.event_subscriber,
+                endpoint_context.packet_interceptor,
+            );
+        });
+    }
+
     fn create_client_connection(
         &mut self,
         request: endpoint::connect::Request,
@@ -931,11 +1060,11 @@ impl<Cfg: Config> Endpoint<Cfg> {
         } = request;
 
         let internal_connection_id = self.connection_id_generator.generate_id();
-        let local_connection_id = self
-            .config
-            .context()
-            .connection_id_format
-            .generate(&ConnectionInfo::new(&remote_address));
+        let context = self.config.context();
+        let local_connection_id = context.connection_id_format.generate_with_random(
+            &ConnectionInfo::new(&remote_address),
+            context.random_generator,
+        );
 
         let local_connection_id_expiration_time = self
             .config
@@ -948,11 +1077,10 @@ impl<Cfg: Config> Endpoint<Cfg> {
             // TODO: the client currently generates a random stateless_reset_token but doesnt
             // transmit it. Refactor `create_local_id_registry` to instead accept None for
             // stateless_reset_token.
-            let stateless_reset_token = self
-                .config
-                .context()
+            let context = self.config.context();
+            let stateless_reset_token = context
                 .stateless_reset_token_generator
-                .generate(local_connection_id.as_bytes());
+                .generate_with_random(local_connection_id.as_bytes(), context.random_generator);
             self.connection_id_mapper.create_local_id_registry(
                 internal_connection_id,
                 &local_connection_id,
@@ -989,12 +1117,25 @@ impl<Cfg: Config> Endpoint<Cfg> {
             .create_client_peer_id_registry(internal_connection_id);
 
         let congestion_controller = {
-            let path_info = congestion_controller::PathInfo::new(&remote_address);
+            let mut path_info = congestion_controller::PathInfo::new(&remote_address);
+            path_info.max_datagram_size = self.mtu_config.min_udp_payload(&remote_address);
             endpoint_context
                 .congestion_controller
                 .new_congestion_controller(path_info)
         };
 
+        let mtu_controller = {
+            let path_info = path::mtu::PathInfo::new(&remote_address);
+            endpoint_context
+                .mtu_controller
+                .new_mtu_controller(path_info)
+        };
+
+        let pacer = {
+            let path_info = pacer::PathInfo::new(&remote_address);
+            endpoint_context.pacer.new_pacer(path_info)
+        };
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-15
         //# This version of the specification is identified by the number
         //# 0x00000001.
@@ -1013,7 +1154,11 @@ impl<Cfg: Config> Endpoint<Cfg> {
         );
         let mut event_context = endpoint_context.event_subscriber.create_connection_context(
             &meta.clone().into_event(),
-            &event::builder::ConnectionInfo {}.into_event(),
+            &event::builder::ConnectionInfo {
+                original_destination_connection_id: (&original_destination_connection_id)
+                    .into_event(),
+            }
+            .into_event(),
         );
         let mut publisher = event::ConnectionPublisherSubscriber::new(
             meta,
@@ -1043,6 +1188,14 @@ impl<Cfg: Config> Endpoint<Cfg> {
         .try_into()
         .unwrap();
 
+        endpoint_context
+            .transport_parameters_interceptor
+            .on_local_parameters(&mut transport_parameters);
+
+        let grease = limits
+            .grease()
+            .then(|| GreaseParameter::new(endpoint_context.random_generator));
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-7.2
         //# The Destination Connection ID field from the first Initial packet
         //# sent by a client is used to determine packet protection keys for
@@ -1059,7 +1212,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
             // TODO should SNI be optional? rustls expects a SNI but other tls providers dont seem
             // to require this value.
             .new_client_session(
-                &transport_parameters,
+                &WithGrease::new(&transport_parameters, grease),
                 hostname.expect("application should provide a valid server name"),
             );
         let space_manager = PacketSpaceManager::new(
@@ -1090,14 +1243,19 @@ impl<Cfg: Config> Endpoint<Cfg> {
             local_connection_id,
             path_handle,
             congestion_controller,
+            mtu_controller,
+            pacer,
             timestamp,
             quic_version,
             limits,
-            max_mtu: self.max_mtu,
+            mtu_config: self.mtu_config,
+            ecn_mode: self.ecn_mode,
+            clock: self.clock.clone(),
             event_context,
             supervisor_context: &supervisor_context,
             event_subscriber: endpoint_context.event_subscriber,
             datagram_endpoint: endpoint_context.datagram,
+            connection_limits: endpoint_context.connection_limits,
         };
         let connection = <Cfg as crate::endpoint::Config>::Connection::new(connection_parameters)?;
         self.connections
@@ -1106,6 +1264,26 @@ impl<Cfg: Config> Endpoint<Cfg> {
     }
 }
 
+/// Describes the path of an Initial packet that is dropped before a connection is created for it
+fn initial_packet_path<'a>(
+    local_address: &'a path::LocalAddress,
+    remote_address: &'a path::RemoteAddress,
+    packet: &'a ProtectedInitial,
+) -> event::builder::Path<'a> {
+    event::builder::Path {
+        local_addr: local_address.into_event(),
+        local_cid: event::builder::ConnectionId {
+            bytes: packet.destination_connection_id(),
+        },
+        remote_addr: remote_address.into_event(),
+        remote_cid: event::builder::ConnectionId {
+            bytes: packet.source_connection_id(),
+        },
+        id: 0,
+        is_active: false,
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 pub mod testing {
     use super::*;
@@ -1117,6 +1295,8 @@ pub mod testing {
     impl Config for Server {
         type CongestionControllerEndpoint =
             crate::recovery::congestion_controller::testing::mock::Endpoint;
+        type MtuControllerEndpoint = path::mtu::default::Endpoint;
+        type PacerEndpoint = crate::recovery::pacer::default::Endpoint;
         type TLSEndpoint = s2n_quic_core::crypto::tls::testing::Endpoint;
         type PathHandle = path::RemoteAddress;
         type Connection = connection::Implementation<Self>;
@@ -1132,6 +1312,8 @@ pub mod testing {
         type EventSubscriber = Subscriber;
         type PathMigrationValidator = path::migration::allow_all::Validator;
         type PacketInterceptor = s2n_quic_core::packet::interceptor::Disabled;
+        type TransportParametersInterceptor =
+            s2n_quic_core::transport::parameters::interceptor::Disabled;
         type DatagramEndpoint = s2n_quic_core::datagram::Disabled;
 
         fn context(&mut self) -> super::Context<Self> {
@@ -1147,6 +1329,8 @@ pub mod testing {
     impl Config for Client {
         type CongestionControllerEndpoint =
             crate::recovery::congestion_controller::testing::mock::Endpoint;
+        type MtuControllerEndpoint = path::mtu::default::Endpoint;
+        type PacerEndpoint = crate::recovery::pacer::default::Endpoint;
         type TLSEndpoint = s2n_quic_core::crypto::tls::testing::Endpoint;
         type PathHandle = path::RemoteAddress;
         type Connection = connection::Implementation<Self>;
@@ -1162,6 +1346,8 @@ pub mod testing {
         type EventSubscriber = Subscriber;
         type PathMigrationValidator = path::migration::allow_all::Validator;
         type PacketInterceptor = s2n_quic_core::packet::interceptor::Disabled;
+        type TransportParametersInterceptor =
+            s2n_quic_core::transport::parameters::interceptor::Disabled;
         type DatagramEndpoint = s2n_quic_core::datagram::Disabled;
 
         fn context(&mut self) -> super::Context<Self> {
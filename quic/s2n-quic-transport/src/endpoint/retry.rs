@@ -7,7 +7,7 @@ use core::ops::Range;
 use s2n_quic_core::{
     connection,
     crypto::RetryKey,
-    event,
+    event::{self, IntoEvent},
     inet::ExplicitCongestionNotification,
     io::tx,
     packet,
@@ -72,6 +72,10 @@ impl<Path: path::Handle> Dispatch<Path> {
                         len: len as u16,
                         gso_offset: 0,
                     });
+
+                    publisher.on_retry_sent(event::builder::RetrySent {
+                        remote_addr: transmission.path.remote_address().into_event(),
+                    });
                 }
                 Err(_) => {
                     self.transmissions.push_front(transmission);
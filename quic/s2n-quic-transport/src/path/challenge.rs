@@ -11,9 +11,18 @@ use s2n_quic_core::{
 pub type Data = [u8; frame::path_challenge::DATA_LEN];
 const DISABLED_DATA: Data = [0; frame::path_challenge::DATA_LEN];
 
+//= https://www.rfc-editor.org/rfc/rfc9000#section-8.2.1
+//# An endpoint MAY send multiple PATH_CHALLENGE frames to guard against
+//# packet loss.
+
+// Re-transmitting twice guards against packet loss, while remaining
+// below the amplification limit of 3.
+pub const DEFAULT_ATTEMPTS: u8 = 2;
+
 #[derive(Clone, Debug)]
 pub struct Challenge {
     state: State,
+    attempts: u8,
     abandon_duration: Duration,
     abandon_timer: Timer,
     data: Data,
@@ -57,14 +66,8 @@ impl Challenge {
             //# An endpoint SHOULD NOT probe a new path with packets containing a
             //# PATH_CHALLENGE frame more frequently than it would send an Initial
             //# packet.
-
-            //= https://www.rfc-editor.org/rfc/rfc9000#section-8.2.1
-            //# An endpoint MAY send multiple PATH_CHALLENGE frames to guard against
-            //# packet loss.
-
-            // Re-transmitting twice guards against packet loss, while remaining
-            // below the amplification limit of 3.
-            state: State::RequiresTransmission(2),
+            state: State::RequiresTransmission(DEFAULT_ATTEMPTS),
+            attempts: DEFAULT_ATTEMPTS,
             abandon_duration,
             abandon_timer: Timer::default(),
             data,
@@ -74,12 +77,27 @@ impl Challenge {
     pub fn disabled() -> Self {
         Self {
             state: State::InitialPathDisabled,
+            attempts: 0,
             abandon_duration: Duration::ZERO,
             abandon_timer: Timer::default(),
             data: DISABLED_DATA,
         }
     }
 
+    /// Sets the number of PATH_CHALLENGE frames sent before waiting for the abandon timer
+    pub fn with_attempts(mut self, attempts: u8) -> Self {
+        debug_assert!(attempts >= 1);
+        debug_assert_eq!(self.state, State::RequiresTransmission(self.attempts));
+        self.state = State::RequiresTransmission(attempts);
+        self.attempts = attempts;
+        self
+    }
+
+    /// Returns the number of PATH_CHALLENGE frames sent for this challenge
+    pub fn attempts(&self) -> u8 {
+        self.attempts
+    }
+
     /// When a PATH_CHALLENGE is transmitted this handles any internal state operations.
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
         match self.state {
@@ -104,15 +122,20 @@ impl Challenge {
         }
     }
 
+    /// Returns `true` if the challenge was abandoned because the abandon timer expired
     pub fn on_timeout<Pub: event::ConnectionPublisher>(
         &mut self,
         timestamp: Timestamp,
         publisher: &mut Pub,
         path: event::builder::Path,
-    ) {
+    ) -> bool {
         if self.abandon_timer.poll_expiration(timestamp).is_ready() {
+            let was_pending = self.is_pending();
             self.abandon(publisher, path);
+            return was_pending;
         }
+
+        false
     }
 
     pub fn abandon<Pub: event::ConnectionPublisher>(
@@ -142,6 +165,10 @@ impl Challenge {
         )
     }
 
+    pub fn is_validated(&self) -> bool {
+        matches!(self.state, State::Validated)
+    }
+
     pub fn on_validated(&mut self, data: &[u8]) -> bool {
         if self.is_pending() && ConstantTimeEq::ct_eq(&self.data[..], data).into() {
             self.state = State::Validated;
@@ -288,6 +315,32 @@ mod tests {
         assert_eq!(context.frame_buffer.len(), 0);
     }
 
+    #[test]
+    fn transmit_challenge_configured_attempts() {
+        // Setup:
+        let mut helper = helper_challenge();
+        helper.challenge = helper.challenge.with_attempts(4);
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut context = MockWriteContext::new(
+            helper.now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Client,
+        );
+        assert_eq!(helper.challenge.attempts(), 4);
+        assert_eq!(helper.challenge.state, State::RequiresTransmission(4));
+
+        // Trigger:
+        for _ in 0..5 {
+            helper.challenge.on_transmit(&mut context);
+        }
+
+        // Expectation:
+        assert_eq!(context.frame_buffer.len(), 4);
+        assert_eq!(helper.challenge.state, State::PendingResponse);
+    }
+
     #[test]
     fn successful_on_transmit_arms_the_timer() {
         // Setup:
@@ -351,19 +404,19 @@ mod tests {
         let mut publisher = event::testing::Publisher::snapshot();
         let path = event::builder::Path::test();
 
-        helper.challenge.on_timeout(
+        assert!(!helper.challenge.on_timeout(
             expiration_time - Duration::from_millis(10),
             &mut publisher,
             path,
-        );
+        ));
         assert!(helper.challenge.is_pending());
 
         let path = event::builder::Path::test();
-        helper.challenge.on_timeout(
+        assert!(helper.challenge.on_timeout(
             expiration_time + Duration::from_millis(10),
             &mut publisher,
             path,
-        );
+        ));
         assert!(!helper.challenge.is_pending());
     }
 
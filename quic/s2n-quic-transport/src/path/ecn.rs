@@ -9,6 +9,7 @@ use s2n_quic_core::{
     frame::ack::EcnCounts,
     inet::ExplicitCongestionNotification,
     number::CheckedSub,
+    path::ecn::Mode,
     random,
     time::{timer, Duration, Timer, Timestamp},
     transmission,
@@ -82,9 +83,21 @@ pub struct Controller {
     // The largest acknowledged packet sent with an ECN marking. Used when tracking
     // packets that have been lost for the purpose of detecting a black hole.
     last_acked_ecn_packet_timestamp: Option<Timestamp>,
+    // Controls whether packets are marked and whether congestion is acted upon
+    mode: Mode,
 }
 
 impl Controller {
+    /// Sets how ECN is used on the path
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Returns how ECN is used on the path
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
     /// Restart testing of ECN capability
     pub fn restart<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -126,6 +139,10 @@ impl Controller {
         transmission_mode: transmission::Mode,
         now: Timestamp,
     ) -> ExplicitCongestionNotification {
+        if !self.mode.is_enabled() {
+            return ExplicitCongestionNotification::NotEct;
+        }
+
         if transmission_mode.is_loss_recovery_probing() {
             // Don't mark loss recovery probes as ECN capable in case the ECN
             // marking is causing packet loss
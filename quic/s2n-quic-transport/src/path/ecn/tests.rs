@@ -191,6 +191,25 @@ fn ecn() {
     }
 }
 
+#[test]
+fn ecn_disabled() {
+    let now = time::now();
+
+    for &transmission_mode in &[
+        transmission::Mode::Normal,
+        transmission::Mode::MtuProbing,
+        transmission::Mode::PathValidationOnly,
+    ] {
+        let mut controller = Controller::default();
+        controller.set_mode(Mode::Disabled);
+        assert!(!controller.ecn(transmission_mode, now).using_ecn());
+
+        // packets are not marked even if the path was previously validated
+        controller.state = State::Capable(Timer::default());
+        assert!(!controller.ecn(transmission_mode, now).using_ecn());
+    }
+}
+
 #[test]
 fn ecn_ce_suppression() {
     let now = time::now();
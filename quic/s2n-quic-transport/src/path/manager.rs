@@ -9,6 +9,7 @@ use crate::{
     path::{challenge, Path},
     transmission,
 };
+use core::task::Poll;
 use s2n_quic_core::{
     ack,
     connection::{self, PeerId},
@@ -19,15 +20,17 @@ use s2n_quic_core::{
     packet::number::PacketNumberSpace,
     path::{
         migration::{self, Validator as _},
-        Handle as _, Id, MaxMtu,
+        mtu::{self, Endpoint as _},
+        Handle as _, Id, LocalAddress, MaxMtu,
     },
     random,
     recovery::{
         congestion_controller::{self, Endpoint as _},
+        pacer::{self, Endpoint as _},
         RttEstimator,
     },
     stateless_reset,
-    time::{timer, Timestamp},
+    time::{timer, Duration, Timestamp},
     transport,
 };
 use smallvec::SmallVec;
@@ -68,6 +71,24 @@ pub struct Manager<Config: endpoint::Config> {
     /// The `paths` data structure will need to be enhanced to include garbage collection
     /// of old paths to overcome this limitation.
     pending_packet_authentication: Option<u8>,
+
+    /// The index of a path that failed validation after the application requested a migration
+    ///
+    /// The path is no longer used to send or receive packets and its index is reused by the
+    /// next path that is created. The index can't be removed from `paths` since packets that
+    /// were sent on the path may still be referenced by the recovery managers.
+    failed_migration: Option<u8>,
+
+    /// The amount of time to wait for a PATH_RESPONSE before abandoning path validation
+    ///
+    /// If not set, the timeout is derived from the PTO of the paths.
+    path_validation_timeout: Option<Duration>,
+
+    /// The number of PATH_CHALLENGE frames sent when validating a path
+    path_validation_attempts: u8,
+
+    /// The `max_udp_payload_size` transport parameter advertised by the peer
+    peer_max_udp_payload_size: Option<u16>,
 }
 
 impl<Config: endpoint::Config> Manager<Config> {
@@ -78,12 +99,39 @@ impl<Config: endpoint::Config> Manager<Config> {
             active: 0,
             last_known_active_validated_path: None,
             pending_packet_authentication: None,
+            failed_migration: None,
+            path_validation_timeout: None,
+            path_validation_attempts: challenge::DEFAULT_ATTEMPTS,
+            peer_max_udp_payload_size: None,
         };
         manager.paths[0].activated = true;
         manager.paths[0].is_active = true;
         manager
     }
 
+    /// Configures how long new paths are probed before validation is abandoned
+    #[inline]
+    pub fn set_path_validation(&mut self, timeout: Option<Duration>, attempts: u8) {
+        debug_assert!(attempts >= 1);
+        self.path_validation_timeout = timeout;
+        self.path_validation_attempts = attempts;
+    }
+
+    /// Limits the size of datagrams sent on all paths to the `max_udp_payload_size`
+    /// transport parameter advertised by the peer
+    #[inline]
+    pub fn on_peer_max_udp_payload_size<Pub: event::ConnectionPublisher>(
+        &mut self,
+        max_udp_payload_size: u16,
+        publisher: &mut Pub,
+    ) {
+        self.peer_max_udp_payload_size = Some(max_udp_payload_size);
+
+        for (idx, path) in self.paths.iter_mut().enumerate() {
+            path.on_peer_max_udp_payload_size(max_udp_payload_size, path_id(idx as u8), publisher);
+        }
+    }
+
     /// Update the active path
     fn update_active_path<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -145,6 +193,18 @@ impl<Config: endpoint::Config> Manager<Config> {
         &self.paths[self.active as usize]
     }
 
+    /// Returns a snapshot of the statistics for the connection
+    #[inline]
+    pub fn statistics(&self) -> connection::Statistics {
+        let mut statistics = connection::Statistics::default();
+
+        for (idx, path) in self.paths.iter().enumerate() {
+            path.update_statistics(&mut statistics, idx == self.active as usize);
+        }
+
+        statistics
+    }
+
     /// Return a mutable reference to the active path
     #[inline]
     pub fn active_path_mut(&mut self) -> &mut Path<Config> {
@@ -194,21 +254,38 @@ impl<Config: endpoint::Config> Manager<Config> {
     /// Returns the Path for the provided address if the PathManager knows about it
     #[inline]
     pub fn path(&self, handle: &Config::PathHandle) -> Option<(Id, &Path<Config>)> {
-        self.paths
-            .iter()
-            .enumerate()
-            .find(|(_id, path)| Path::eq_by_handle(path, handle))
-            .map(|(id, path)| (path_id(id as u8), path))
+        let id = self.find_path(handle)?;
+        Some((id, &self[id]))
     }
 
     /// Returns the Path for the provided address if the PathManager knows about it
     #[inline]
     pub fn path_mut(&mut self, handle: &Config::PathHandle) -> Option<(Id, &mut Path<Config>)> {
+        let id = self.find_path(handle)?;
+        Some((id, &mut self[id]))
+    }
+
+    #[inline]
+    fn find_path(&self, handle: &Config::PathHandle) -> Option<Id> {
+        // Clients that migrated to a new local address have multiple paths to the same
+        // remote address so prefer the path that was created for the local address
+        if Config::ENDPOINT_TYPE.is_client() && self.paths.len() > 1 {
+            if let Some(id) = self.position(|path| path.handle.eq(handle)) {
+                return Some(id);
+            }
+        }
+
+        self.position(|path| Path::eq_by_handle(path, handle))
+    }
+
+    /// Returns the id of the first path matching `f`, skipping a path that failed migration
+    #[inline]
+    fn position<F: Fn(&Path<Config>) -> bool>(&self, f: F) -> Option<Id> {
         self.paths
-            .iter_mut()
+            .iter()
             .enumerate()
-            .find(|(_id, path)| Path::eq_by_handle(path, handle))
-            .map(|(id, path)| (path_id(id as u8), path))
+            .find(|(idx, path)| self.failed_migration != Some(*idx as u8) && f(path))
+            .map(|(idx, _)| path_id(idx as u8))
     }
 
     /// Returns an iterator over all paths pending path_challenge or path_response
@@ -232,8 +309,10 @@ impl<Config: endpoint::Config> Manager<Config> {
         datagram: &DatagramInfo,
         handshake_confirmed: bool,
         congestion_controller_endpoint: &mut Config::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut Config::MtuControllerEndpoint,
+        pacer_endpoint: &mut Config::PacerEndpoint,
         migration_validator: &mut Config::PathMigrationValidator,
-        max_mtu: MaxMtu,
+        mtu_config: mtu::Config,
         publisher: &mut Pub,
     ) -> Result<(Id, bool), DatagramDropReason> {
         let valid_initial_received = self.valid_initial_received();
@@ -294,8 +373,10 @@ impl<Config: endpoint::Config> Manager<Config> {
             path_handle,
             datagram,
             congestion_controller_endpoint,
+            mtu_controller_endpoint,
+            pacer_endpoint,
             migration_validator,
-            max_mtu,
+            mtu_config,
             publisher,
         )
     }
@@ -306,8 +387,10 @@ impl<Config: endpoint::Config> Manager<Config> {
         path_handle: &Config::PathHandle,
         datagram: &DatagramInfo,
         congestion_controller_endpoint: &mut Config::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut Config::MtuControllerEndpoint,
+        pacer_endpoint: &mut Config::PacerEndpoint,
         migration_validator: &mut Config::PathMigrationValidator,
-        max_mtu: MaxMtu,
+        mtu_config: mtu::Config,
         publisher: &mut Pub,
     ) -> Result<(Id, bool), DatagramDropReason> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-9
@@ -390,11 +473,18 @@ impl<Config: endpoint::Config> Manager<Config> {
         // we do not need to reset congestion controller and round-trip time estimator
         // again on confirming the peer's ownership of its new address.
         let rtt = RttEstimator::new(self.active_path().rtt_estimator.max_ack_delay());
-        let path_info = congestion_controller::PathInfo::new(&remote_address);
+        let mut path_info = congestion_controller::PathInfo::new(&remote_address);
+        path_info.max_datagram_size = mtu_config.min_udp_payload(&remote_address);
         let cc = congestion_controller_endpoint.new_congestion_controller(path_info);
+        let path_info = mtu::PathInfo::new(&remote_address);
+        let mtu_controller = mtu_controller_endpoint.new_mtu_controller(path_info);
+        let path_info = pacer::PathInfo::new(&remote_address);
+        let pacer = pacer_endpoint.new_pacer(path_info);
 
+        let is_nat_rebinding =
+            self.active_path().local_connection_id == datagram.destination_connection_id;
         let peer_connection_id = {
-            if self.active_path().local_connection_id != datagram.destination_connection_id {
+            if !is_nat_rebinding {
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-9.5
                 //# Similarly, an endpoint MUST NOT reuse a connection ID when sending to
                 //# more than one destination address.
@@ -432,9 +522,27 @@ impl<Config: endpoint::Config> Manager<Config> {
             datagram.destination_connection_id,
             rtt,
             cc,
+            mtu_controller,
+            pacer,
             true,
-            max_mtu,
+            mtu_config,
         );
+        // new paths use ECN in the same way as the rest of the connection
+        path.ecn_controller
+            .set_mode(self.active_path().ecn_controller.mode());
+        path.set_amplification_limit(self.active_path().amplification_limit());
+        path.set_pto_backoff_factor(self.active_path().pto_backoff_factor());
+        path.set_max_pto(self.active_path().max_pto());
+        path.set_packet_reordering_threshold(self.active_path().packet_reordering_threshold());
+        path.set_time_threshold(self.active_path().time_threshold());
+        // new paths are limited by the peer's max_udp_payload_size
+        if let Some(max_udp_payload_size) = self.peer_max_udp_payload_size {
+            path.on_peer_max_udp_payload_size(max_udp_payload_size, new_path_id, publisher);
+        }
+
+        if is_nat_rebinding {
+            path.on_nat_rebinding(active_remote_addr);
+        }
 
         let unblocked = path.on_bytes_received(datagram.payload_len);
 
@@ -461,6 +569,178 @@ impl<Config: endpoint::Config> Manager<Config> {
         Ok((new_path_id, unblocked))
     }
 
+    /// Creates a path from `local_address` to the peer's current address and starts validating
+    /// it on behalf of the application
+    ///
+    /// The path doesn't become active until [`Self::poll_migration`] observes that the peer
+    /// responded to the PATH_CHALLENGE.
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_migration_requested<Pub: event::ConnectionPublisher>(
+        &mut self,
+        local_address: LocalAddress,
+        congestion_controller_endpoint: &mut Config::CongestionControllerEndpoint,
+        mtu_controller_endpoint: &mut Config::MtuControllerEndpoint,
+        pacer_endpoint: &mut Config::PacerEndpoint,
+        mtu_config: mtu::Config,
+        random_generator: &mut dyn random::Generator,
+        publisher: &mut Pub,
+    ) -> Result<Id, connection::MigrationError> {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9
+        //# Clients are responsible for initiating all migrations.
+        debug_assert!(Config::ENDPOINT_TYPE.is_client());
+
+        let mut handle = self.active_path().handle;
+        handle.set_local_address(local_address);
+
+        if handle.local_address() != local_address {
+            return Err(connection::MigrationError::UnsupportedLocalAddress);
+        }
+
+        if let Some(id) = self.position(|path| path.handle.eq(&handle)) {
+            let path = &self[id];
+
+            if id != self.active_path_id()
+                && !path.is_challenge_validated()
+                && !path.is_challenge_pending()
+            {
+                self.set_challenge(id, random_generator);
+            }
+
+            return Ok(id);
+        }
+
+        // Reuse the index of a path that previously failed migration before pushing on to the end
+        //
+        // TODO: Support deletion of old paths: https://github.com/aws/s2n-quic/issues/741
+        let new_path_idx = self
+            .failed_migration
+            .map_or(self.paths.len(), |idx| idx as usize);
+        if new_path_idx >= MAX_ALLOWED_PATHS {
+            return Err(connection::MigrationError::PathLimitExceeded);
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9.5
+        //# An endpoint MUST NOT reuse a connection ID when sending from more
+        //# than one local address -- for example, when initiating connection
+        //# migration as described in Section 9.2 or when probing a new network
+        //# path as described in Section 9.1.
+        let peer_connection_id = self
+            .peer_id_registry
+            .consume_new_id_for_new_path()
+            .ok_or(connection::MigrationError::NoPeerConnectionId)?;
+
+        let new_path_id = path_id(new_path_idx as u8);
+        let remote_address = handle.remote_address();
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9.2
+        //# When migrating, the new path might not support the endpoint's current
+        //# sending rate.  Therefore, the endpoint resets its congestion
+        //# controller and RTT estimate, as described in Section 9.4.
+        let rtt = RttEstimator::new(self.active_path().rtt_estimator.max_ack_delay());
+        let mut path_info = congestion_controller::PathInfo::new(&remote_address);
+        path_info.max_datagram_size = mtu_config.min_udp_payload(&remote_address);
+        let cc = congestion_controller_endpoint.new_congestion_controller(path_info);
+        let path_info = mtu::PathInfo::new(&remote_address);
+        let mtu_controller = mtu_controller_endpoint.new_mtu_controller(path_info);
+        let path_info = pacer::PathInfo::new(&remote_address);
+        let pacer = pacer_endpoint.new_pacer(path_info);
+
+        let mut path = Path::new(
+            handle,
+            peer_connection_id,
+            self.active_path().local_connection_id,
+            rtt,
+            cc,
+            mtu_controller,
+            pacer,
+            true,
+            mtu_config,
+        );
+        // new paths use ECN in the same way as the rest of the connection
+        path.ecn_controller
+            .set_mode(self.active_path().ecn_controller.mode());
+        path.set_amplification_limit(self.active_path().amplification_limit());
+        path.set_pto_backoff_factor(self.active_path().pto_backoff_factor());
+        path.set_max_pto(self.active_path().max_pto());
+        path.set_packet_reordering_threshold(self.active_path().packet_reordering_threshold());
+        path.set_time_threshold(self.active_path().time_threshold());
+        // new paths are limited by the peer's max_udp_payload_size
+        if let Some(max_udp_payload_size) = self.peer_max_udp_payload_size {
+            path.on_peer_max_udp_payload_size(max_udp_payload_size, new_path_id, publisher);
+        }
+
+        let active_path = self.active_path();
+        let active_path_id = self.active_path_id();
+        publisher.on_path_created(event::builder::PathCreated {
+            active: path_event!(active_path, active_path_id),
+            new: path_event!(path, new_path_id),
+        });
+
+        publisher.on_mtu_updated(event::builder::MtuUpdated {
+            path_id: new_path_id.into_event(),
+            mtu: path.mtu_controller.mtu() as u16,
+            cause: MtuUpdatedCause::NewPath,
+        });
+
+        if new_path_idx < self.paths.len() {
+            self.paths[new_path_idx] = path;
+            self.failed_migration = None;
+        } else {
+            self.paths.push(path);
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9.1
+        //# An endpoint MAY probe for peer reachability from a new local address
+        //# using path validation (Section 8.2) prior to migrating the connection
+        //# to the new local address.
+        self.set_challenge(new_path_id, random_generator);
+
+        Ok(new_path_id)
+    }
+
+    /// Checks the progress of a migration started with [`Self::on_migration_requested`]
+    ///
+    /// The path is made active once it has been validated. If validation fails the connection
+    /// remains on the current active path and the failed path is discarded.
+    pub fn poll_migration<Pub: event::ConnectionPublisher>(
+        &mut self,
+        new_path_id: Id,
+        publisher: &mut Pub,
+    ) -> Poll<Result<(), connection::MigrationError>> {
+        if new_path_id == self.active_path_id() {
+            return Poll::Ready(Ok(()));
+        }
+
+        let path = &self[new_path_id];
+
+        if path.is_challenge_pending() {
+            return Poll::Pending;
+        }
+
+        if !path.is_challenge_validated() {
+            self.failed_migration = Some(new_path_id.as_u8());
+            return Poll::Ready(Err(connection::MigrationError::ValidationFailed));
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9.2
+        //# An endpoint can migrate a connection to a new local address by
+        //# sending packets containing non-probing frames from that address.
+        let prev_path_id = self.active_path_id();
+        if self.active_path().is_validated() {
+            self.last_known_active_validated_path = Some(self.active);
+        }
+        self.activate_path(publisher, prev_path_id, new_path_id);
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-9.2
+        //# The new path might not have the same ECN capability.  Therefore, the
+        //# endpoint validates ECN capability as described in Section 13.4.
+        let path = self.active_path_mut();
+        path.ecn_controller
+            .restart(path_event!(path, new_path_id), publisher);
+
+        Poll::Ready(Ok(()))
+    }
+
     fn set_challenge(&mut self, path_id: Id, random_generator: &mut dyn random::Generator) {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.2.1
         //# The endpoint MUST use unpredictable data in every PATH_CHALLENGE
@@ -478,11 +758,15 @@ impl<Config: endpoint::Config> Manager<Config> {
         //# could have a longer round-trip time than the original.  A value of
         //# three times the larger of the current PTO or the PTO for the new path
         //# (using kInitialRtt, as defined in [QUIC-RECOVERY]) is RECOMMENDED.
-        let abandon_duration = self[path_id].pto_period(PacketNumberSpace::ApplicationData);
-        let abandon_duration = 3 * abandon_duration.max(
-            self.active_path()
-                .pto_period(PacketNumberSpace::ApplicationData),
-        );
+        //
+        // The timeout can be overridden with `Limits::with_path_validation_timeout`.
+        let abandon_duration = self.path_validation_timeout.unwrap_or_else(|| {
+            let abandon_duration = self[path_id].pto_period(PacketNumberSpace::ApplicationData);
+            3 * abandon_duration.max(
+                self.active_path()
+                    .pto_period(PacketNumberSpace::ApplicationData),
+            )
+        });
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-9
         //# An endpoint MUST
@@ -492,7 +776,8 @@ impl<Config: endpoint::Config> Manager<Config> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-9.6.3
         //# Servers SHOULD initiate path validation to the client's new address
         //# upon receiving a probe packet from a different address.
-        let challenge = challenge::Challenge::new(abandon_duration, data);
+        let challenge = challenge::Challenge::new(abandon_duration, data)
+            .with_attempts(self.path_validation_attempts);
         self[path_id].set_challenge(challenge);
     }
 
@@ -578,6 +863,7 @@ impl<Config: endpoint::Config> Manager<Config> {
                     path: path_event!(path, id),
                     challenge_data: path.challenge.challenge_data().into_event(),
                 });
+                path.publish_nat_rebinding(id, publisher);
                 // A path was validated so check if it becomes the new
                 // last_known_active_validated_path
                 if path.is_activated() {
@@ -662,6 +948,7 @@ impl<Config: endpoint::Config> Manager<Config> {
         for (idx, path) in self.paths.iter_mut().enumerate() {
             let path_id = idx as u64;
             path.abandon_challenge(publisher, path_id);
+            path.publish_nat_rebinding(path_id, publisher);
         }
     }
 
@@ -720,8 +1007,26 @@ impl<Config: endpoint::Config> Manager<Config> {
         random_generator: &mut dyn random::Generator,
         publisher: &mut Pub,
     ) -> Result<(), connection::Error> {
+        let mut active_path_failed = false;
+
         for (id, path) in self.paths.iter_mut().enumerate() {
-            path.on_timeout(timestamp, path_id(id as u8), random_generator, publisher);
+            let validation_failed =
+                path.on_timeout(timestamp, path_id(id as u8), random_generator, publisher);
+            path.publish_nat_rebinding(id as u64, publisher);
+
+            if validation_failed {
+                if id as u8 == self.active {
+                    // the event is published once the connection falls back to another path
+                    active_path_failed = true;
+                } else {
+                    let id = id as u64;
+                    publisher.on_path_validation_failed(event::builder::PathValidationFailed {
+                        path: path_event!(path, id),
+                        attempts: path.challenge_attempts(),
+                        fallback_path: None,
+                    });
+                }
+            }
         }
 
         if self.active_path().failed_validation() {
@@ -735,8 +1040,28 @@ impl<Config: endpoint::Config> Manager<Config> {
                     let new_path_id = path_id(last_known_active_validated_path);
                     self.activate_path(publisher, prev_path_id, new_path_id);
                     self.last_known_active_validated_path = None;
+
+                    if active_path_failed {
+                        let failed_path = &self[prev_path_id];
+                        let fallback_path = &self[new_path_id];
+                        publisher.on_path_validation_failed(event::builder::PathValidationFailed {
+                            path: path_event!(failed_path, prev_path_id),
+                            attempts: failed_path.challenge_attempts(),
+                            fallback_path: Some(path_event!(fallback_path, new_path_id)),
+                        });
+                    }
                 }
                 None => {
+                    if active_path_failed {
+                        let failed_path = self.active_path();
+                        let failed_path_id = self.active_path_id();
+                        publisher.on_path_validation_failed(event::builder::PathValidationFailed {
+                            path: path_event!(failed_path, failed_path_id),
+                            attempts: failed_path.challenge_attempts(),
+                            fallback_path: None,
+                        });
+                    }
+
                     //= https://www.rfc-editor.org/rfc/rfc9000#section-9
                     //# When an endpoint has no validated path on which to send packets, it
                     //# MAY discard connection state.
@@ -172,8 +172,10 @@ impl Model {
             &datagram,
             true,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration_validator,
-            MaxMtu::default(),
+            Default::default(),
             &mut publisher,
         ) {
             Ok(_) => {
@@ -10,7 +10,6 @@ use crate::{
         testing::{Client, Server},
     },
     path,
-    path::DEFAULT_MAX_MTU,
 };
 use core::time::Duration;
 use s2n_quic_core::{
@@ -58,8 +57,10 @@ fn get_path_by_address_test() {
         first_local_conn_id,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     let second_conn_id = connection::PeerId::try_from_bytes(&[5, 4, 3, 2, 1]).unwrap();
@@ -69,8 +70,10 @@ fn get_path_by_address_test() {
         first_local_conn_id,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     let mut manager = manager_server(first_path.clone());
@@ -100,8 +103,10 @@ fn test_invalid_path_fallback() {
         first_local_conn_id,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     // simulate receiving a handshake packet to force path validation
     first_path.on_handshake_packet();
@@ -116,8 +121,10 @@ fn test_invalid_path_fallback() {
         first_local_conn_id,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     second_path.set_challenge(challenge);
 
@@ -421,6 +428,58 @@ fn dont_validate_path_if_path_challenge_is_abandoned() {
     assert!(!helper.manager[helper.second_path_id].is_validated());
 }
 
+#[test]
+fn discard_path_if_migration_validation_fails() {
+    // Setup:
+    let mut publisher = Publisher::no_snapshot();
+    let mut helper = helper_manager_with_paths(&mut publisher);
+
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let mut context = MockWriteContext::new(
+        helper.now,
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Client,
+    );
+    helper.manager[helper.second_path_id].on_transmit(&mut context);
+    assert_eq!(
+        helper
+            .manager
+            .poll_migration(helper.second_path_id, &mut publisher),
+        Poll::Pending
+    );
+
+    // Trigger:
+    helper
+        .manager
+        .on_timeout(
+            helper.now + helper.challenge_expiration + Duration::from_millis(100),
+            &mut random::testing::Generator(123),
+            &mut publisher,
+        )
+        .unwrap();
+
+    // Expectation:
+    assert_eq!(
+        helper
+            .manager
+            .poll_migration(helper.second_path_id, &mut publisher),
+        Poll::Ready(Err(connection::MigrationError::ValidationFailed))
+    );
+    assert_eq!(helper.manager.active, helper.first_path_id.as_u8());
+    // the failed path is no longer matched and its index is reused by the next path
+    assert_eq!(
+        helper.manager.failed_migration,
+        Some(helper.second_path_id.as_u8())
+    );
+    let second_conn_id = connection::PeerId::try_from_bytes(&[2]).unwrap();
+    assert!(helper
+        .manager
+        .position(|path| path.peer_connection_id == second_conn_id)
+        .is_none());
+}
+
 #[test]
 //= https://www.rfc-editor.org/rfc/rfc9000#section-9.3
 //# If the recipient permits the migration, it MUST send subsequent
@@ -488,8 +547,10 @@ fn silently_return_when_there_is_no_valid_path() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     first_path.set_challenge(challenge);
     let mut manager = manager_server(first_path);
@@ -691,8 +752,10 @@ fn test_adding_new_path() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -718,8 +781,10 @@ fn test_adding_new_path() {
             &datagram,
             true,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -752,8 +817,10 @@ fn do_not_add_new_path_if_handshake_not_confirmed() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -777,8 +844,10 @@ fn do_not_add_new_path_if_handshake_not_confirmed() {
         &datagram,
         handshake_confirmed,
         &mut Default::default(),
+        &mut Default::default(),
+        &mut Default::default(),
         &mut migration::allow_all::Validator,
-        DEFAULT_MAX_MTU,
+        Default::default(),
         &mut publisher,
     );
 
@@ -812,8 +881,10 @@ fn do_not_add_new_path_if_client() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_client(first_path);
     let mut publisher = Publisher::snapshot();
@@ -837,8 +908,10 @@ fn do_not_add_new_path_if_client() {
         &datagram,
         true,
         &mut Default::default(),
+        &mut Default::default(),
+        &mut Default::default(),
         &mut migration::allow_all::Validator,
-        DEFAULT_MAX_MTU,
+        Default::default(),
         &mut publisher,
     );
 
@@ -865,8 +938,10 @@ fn switch_destination_connection_id_after_first_server_response() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_client(zero_path);
     assert_eq!(manager[zero_path_id].peer_connection_id, initial_cid);
@@ -903,8 +978,10 @@ fn limit_number_of_connection_migrations() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
     let mut total_paths = 1;
@@ -926,8 +1003,10 @@ fn limit_number_of_connection_migrations() {
             &new_addr,
             &datagram,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         );
         match res {
@@ -961,8 +1040,10 @@ fn connection_migration_challenge_behavior() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -983,8 +1064,10 @@ fn connection_migration_challenge_behavior() {
             &new_addr,
             &datagram,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1054,8 +1137,10 @@ fn connection_migration_use_max_ack_delay_from_active_path() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(Duration::from_millis(30)),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -1077,8 +1162,10 @@ fn connection_migration_use_max_ack_delay_from_active_path() {
             &new_addr,
             &datagram,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1132,8 +1219,10 @@ fn connection_migration_new_path_abandon_timer() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(Duration::from_millis(30)),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -1154,8 +1243,10 @@ fn connection_migration_new_path_abandon_timer() {
             &new_addr,
             &datagram,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1252,8 +1343,10 @@ fn stop_using_a_retired_connection_id() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -1346,8 +1439,10 @@ fn pending_paths_should_return_paths_pending_validation() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let expected_response_data = [0; 8];
     third_path.on_path_challenge(&expected_response_data);
@@ -1410,8 +1505,10 @@ fn temporary_until_authenticated() {
         connection::LocalId::TEST_ID,
         RttEstimator::default(),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     let mut manager = manager_server(first_path);
 
@@ -1426,8 +1523,10 @@ fn temporary_until_authenticated() {
             &datagram,
             true,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1448,8 +1547,10 @@ fn temporary_until_authenticated() {
             &datagram,
             true,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1485,8 +1586,10 @@ fn temporary_until_authenticated() {
             &datagram,
             true,
             &mut Default::default(),
+            &mut Default::default(),
+            &mut Default::default(),
             &mut migration::allow_all::Validator,
-            DEFAULT_MAX_MTU,
+            Default::default(),
             &mut publisher,
         )
         .unwrap();
@@ -1789,8 +1892,10 @@ pub fn helper_path(peer_id: connection::PeerId) -> ServerPath {
         local_conn_id,
         RttEstimator::new(Duration::from_millis(30)),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     )
 }
 
@@ -8,14 +8,18 @@ use crate::{
     contexts::WriteContext,
     endpoint,
     endpoint::Type,
-    recovery::{congestion_controller, CongestionController, RttEstimator},
+    recovery::{
+        congestion_controller,
+        pacer::{self, Pacer as _},
+        CongestionController, RttEstimator, K_PACKET_THRESHOLD, K_TIME_THRESHOLD,
+    },
     transmission::{self, Mode},
 };
 use s2n_quic_core::{
     counter::{Counter, Saturating},
     event::{self, IntoEvent},
     frame, packet, random,
-    time::{timer, Timestamp},
+    time::{timer, Duration, Timestamp},
 };
 
 mod challenge;
@@ -52,12 +56,24 @@ pub struct Path<Config: endpoint::Config> {
     pub rtt_estimator: RttEstimator,
     /// The congestion controller for the path
     pub congestion_controller: <Config::CongestionControllerEndpoint as congestion_controller::Endpoint>::CongestionController,
+    /// The pacer for the path
+    pub pacer: <Config::PacerEndpoint as pacer::Endpoint>::Pacer,
     /// Probe timeout backoff multiplier
     pub pto_backoff: u32,
+    /// The factor by which the PTO backoff is increased each time the PTO expires
+    pto_backoff_factor: u32,
+    /// The upper bound of the PTO period, if configured
+    max_pto: Option<Duration>,
+    /// The number of packets after which an unacknowledged packet is declared lost
+    packet_reordering_threshold: u64,
+    /// The RTT multiplier after which an unacknowledged packet is declared lost
+    time_threshold: f32,
     /// Tracks whether this path has passed Address or Path validation
     state: State,
+    /// The factor by which sent bytes may exceed received bytes before the path is validated
+    amplification_limit: u8,
     /// Controller for determining the maximum transmission unit of the path
-    pub mtu_controller: mtu::Controller,
+    pub mtu_controller: mtu::Controller<<Config::MtuControllerEndpoint as mtu::Endpoint>::MtuController>,
     /// Controller for determining the ECN capability of the path
     pub ecn_controller: ecn::Controller,
 
@@ -80,6 +96,23 @@ pub struct Path<Config: endpoint::Config> {
 
     /// True if the path is currently active
     is_active: bool,
+
+    /// The number of bytes sent in datagrams on this path
+    bytes_sent: u64,
+
+    /// The number of bytes received in datagrams on this path
+    bytes_received: u64,
+
+    /// The number of packets sent on this path that were declared lost
+    packets_lost: u64,
+
+    /// The peer's previous address, if this path was created because of a NAT rebinding
+    ///
+    /// This is cleared once the outcome of validating the path has been published.
+    nat_rebinding: Option<RemoteAddress>,
+
+    /// The last time an ack-eliciting packet sent on this path was acknowledged
+    last_ack_eliciting_acked: Option<Timestamp>,
 }
 
 impl<Config: endpoint::Config> Clone for Path<Config> {
@@ -90,8 +123,14 @@ impl<Config: endpoint::Config> Clone for Path<Config> {
             local_connection_id: self.local_connection_id,
             rtt_estimator: self.rtt_estimator,
             congestion_controller: self.congestion_controller.clone(),
+            pacer: self.pacer.clone(),
             pto_backoff: self.pto_backoff,
+            pto_backoff_factor: self.pto_backoff_factor,
+            max_pto: self.max_pto,
+            packet_reordering_threshold: self.packet_reordering_threshold,
+            time_threshold: self.time_threshold,
             state: self.state,
+            amplification_limit: self.amplification_limit,
             mtu_controller: self.mtu_controller.clone(),
             ecn_controller: self.ecn_controller.clone(),
             peer_validated: self.peer_validated,
@@ -99,6 +138,11 @@ impl<Config: endpoint::Config> Clone for Path<Config> {
             response_data: self.response_data,
             activated: self.activated,
             is_active: self.is_active,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            packets_lost: self.packets_lost,
+            nat_rebinding: self.nat_rebinding,
+            last_ack_eliciting_acked: self.last_ack_eliciting_acked,
         }
     }
 }
@@ -113,8 +157,10 @@ impl<Config: endpoint::Config> Path<Config> {
         local_connection_id: connection::LocalId,
         rtt_estimator: RttEstimator,
         congestion_controller: <Config::CongestionControllerEndpoint as congestion_controller::Endpoint>::CongestionController,
+        mtu_controller: <Config::MtuControllerEndpoint as mtu::Endpoint>::MtuController,
+        pacer: <Config::PacerEndpoint as pacer::Endpoint>::Pacer,
         peer_validated: bool,
-        max_mtu: MaxMtu,
+        mtu_config: mtu::Config,
     ) -> Path<Config> {
         let state = match Config::ENDPOINT_TYPE {
             Type::Server => {
@@ -137,15 +183,72 @@ impl<Config: endpoint::Config> Path<Config> {
             local_connection_id,
             rtt_estimator,
             congestion_controller,
+            pacer,
             pto_backoff: INITIAL_PTO_BACKOFF,
+            pto_backoff_factor: DEFAULT_PTO_BACKOFF_FACTOR,
+            max_pto: None,
+            packet_reordering_threshold: K_PACKET_THRESHOLD,
+            time_threshold: K_TIME_THRESHOLD,
             state,
-            mtu_controller: mtu::Controller::new(max_mtu, &peer_socket_address),
+            amplification_limit: DEFAULT_AMPLIFICATION_LIMIT,
+            mtu_controller: mtu::Controller::new(mtu_config, &peer_socket_address, mtu_controller),
             ecn_controller: ecn::Controller::default(),
             peer_validated,
             challenge: Challenge::disabled(),
             response_data: None,
             activated: false,
             is_active: false,
+            bytes_sent: 0,
+            bytes_received: 0,
+            packets_lost: 0,
+            nat_rebinding: None,
+            last_ack_eliciting_acked: None,
+        }
+    }
+
+    /// Called when a packet sent on this path has been declared lost
+    #[inline]
+    pub fn on_packet_lost(&mut self) {
+        self.packets_lost += 1;
+    }
+
+    /// Called when an ack-eliciting packet sent on this path has been acknowledged
+    #[inline]
+    pub fn on_ack_eliciting_packet_acked(&mut self, timestamp: Timestamp) {
+        self.last_ack_eliciting_acked = Some(timestamp);
+    }
+
+    /// Returns the last time an ack-eliciting packet sent on this path was acknowledged
+    #[inline]
+    pub fn last_ack_eliciting_acked(&self) -> Option<Timestamp> {
+        self.last_ack_eliciting_acked
+    }
+
+    /// Adds the statistics for this path to the connection `statistics`
+    ///
+    /// The byte and packet counts are accumulated and the largest MTU is the maximum of all
+    /// paths, while the per-path values are only set if `is_active_path` is true.
+    #[inline]
+    pub fn update_statistics(&self, statistics: &mut connection::Statistics, is_active_path: bool) {
+        statistics.bytes_sent += self.bytes_sent;
+        statistics.bytes_received += self.bytes_received;
+        statistics.packets_lost += self.packets_lost;
+        statistics.largest_mtu = statistics
+            .largest_mtu
+            .max(self.mtu_controller.largest_mtu() as u16);
+
+        if is_active_path {
+            statistics.min_rtt = self.rtt_estimator.min_rtt();
+            statistics.smoothed_rtt = self.rtt_estimator.smoothed_rtt();
+            statistics.latest_rtt = self.rtt_estimator.latest_rtt();
+            statistics.rtt_variance = self.rtt_estimator.rttvar();
+            statistics.congestion_window = self.congestion_controller.congestion_window();
+            statistics.bytes_in_flight = self.congestion_controller.bytes_in_flight();
+            statistics.delivery_rate = self
+                .congestion_controller
+                .delivery_rate()
+                .map_or(0, |delivery_rate| delivery_rate.as_bytes_per_second());
+            statistics.mtu = self.mtu_controller.mtu() as u16;
         }
     }
 
@@ -164,6 +267,33 @@ impl<Config: endpoint::Config> Path<Config> {
         self.challenge = challenge;
     }
 
+    /// Marks the path as created for a peer that rebound from `previous_address`
+    #[inline]
+    pub fn on_nat_rebinding(&mut self, previous_address: RemoteAddress) {
+        self.nat_rebinding = Some(previous_address);
+    }
+
+    /// Publishes a `NatRebindingDetected` event once validation of a rebound path has finished
+    #[inline]
+    pub fn publish_nat_rebinding<Pub: event::ConnectionPublisher>(
+        &mut self,
+        path_id: u64,
+        publisher: &mut Pub,
+    ) {
+        let validated = self.is_challenge_validated();
+        if !validated && !self.failed_validation() {
+            return;
+        }
+
+        if let Some(previous_remote_addr) = self.nat_rebinding.take() {
+            publisher.on_nat_rebinding_detected(event::builder::NatRebindingDetected {
+                path: path_event!(self, path_id),
+                previous_remote_addr: previous_remote_addr.into_event(),
+                validated,
+            });
+        }
+    }
+
     #[inline]
     pub fn abandon_challenge<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -186,6 +316,8 @@ impl<Config: endpoint::Config> Path<Config> {
             return;
         }
 
+        self.bytes_sent += bytes as u64;
+
         debug_assert_ne!(
             self.clamp_mtu(bytes, transmission::Mode::Normal),
             0,
@@ -197,6 +329,19 @@ impl<Config: endpoint::Config> Path<Config> {
         }
     }
 
+    /// Sets the factor by which the bytes sent on this path may exceed the bytes received
+    /// before the path is validated
+    #[inline]
+    pub fn set_amplification_limit(&mut self, limit: u8) {
+        debug_assert!(limit >= 1);
+        self.amplification_limit = limit;
+    }
+
+    #[inline]
+    pub fn amplification_limit(&self) -> u8 {
+        self.amplification_limit
+    }
+
     /// Called when bytes have been received on this path
     /// Returns true if receiving these bytes unblocked the
     /// path from being amplification limited
@@ -204,6 +349,8 @@ impl<Config: endpoint::Config> Path<Config> {
     pub fn on_bytes_received(&mut self, bytes: usize) -> bool {
         let was_at_amplification_limit = self.at_amplification_limit();
 
+        self.bytes_received += bytes as u64;
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
         //# For the purposes of
         //# avoiding amplification prior to address validation, servers MUST
@@ -216,12 +363,14 @@ impl<Config: endpoint::Config> Path<Config> {
         //# received.
         //
         if let State::AmplificationLimited { tx_allowance } = &mut self.state {
-            *tx_allowance += bytes.saturating_mul(3) as u32;
+            *tx_allowance += bytes.saturating_mul(self.amplification_limit as usize) as u32;
         }
 
         was_at_amplification_limit && !self.at_amplification_limit()
     }
 
+    /// Returns `true` if validation of the path failed because the challenge was not answered
+    /// in time
     #[inline]
     pub fn on_timeout<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -229,9 +378,10 @@ impl<Config: endpoint::Config> Path<Config> {
         path_id: Id,
         random_generator: &mut dyn random::Generator,
         publisher: &mut Pub,
-    ) {
-        self.challenge
-            .on_timeout(timestamp, publisher, path_event!(self, path_id));
+    ) -> bool {
+        let validation_failed =
+            self.challenge
+                .on_timeout(timestamp, publisher, path_event!(self, path_id));
         self.mtu_controller.on_timeout(timestamp);
         self.ecn_controller.on_timeout(
             timestamp,
@@ -240,6 +390,36 @@ impl<Config: endpoint::Config> Path<Config> {
             self.rtt_estimator.smoothed_rtt(),
             publisher,
         );
+
+        validation_failed
+    }
+
+    /// Limits the size of datagrams sent on this path to the `max_udp_payload_size`
+    /// transport parameter advertised by the peer
+    #[inline]
+    pub fn on_peer_max_udp_payload_size<Pub: event::ConnectionPublisher>(
+        &mut self,
+        max_udp_payload_size: u16,
+        path_id: Id,
+        publisher: &mut Pub,
+    ) {
+        let prev_mtu = self.mtu_controller.mtu();
+        self.mtu_controller
+            .on_peer_max_udp_payload_size(max_udp_payload_size);
+        let mtu = self.mtu_controller.mtu();
+
+        if mtu < prev_mtu {
+            self.congestion_controller.on_mtu_update(
+                mtu as u16,
+                &mut congestion_controller::PathPublisher::new(publisher, path_id),
+            );
+        }
+    }
+
+    /// Returns the number of PATH_CHALLENGE frames sent to validate this path
+    #[inline]
+    pub fn challenge_attempts(&self) -> u8 {
+        self.challenge.attempts()
     }
 
     /// Returns true if this path is able to transmit packets at the given timestamp
@@ -247,11 +427,23 @@ impl<Config: endpoint::Config> Path<Config> {
     pub fn can_transmit(&self, timestamp: Timestamp) -> bool {
         !self.at_amplification_limit()
             && self
-                .congestion_controller
-                .earliest_departure_time()
+                .earliest_departure_time(timestamp)
                 .map_or(true, |edt| edt.has_elapsed(timestamp))
     }
 
+    /// Returns the earliest time that the next packet may be transmitted on this path
+    ///
+    /// If the time is in the past or is `None`, the packet may be transmitted immediately.
+    #[inline]
+    pub fn earliest_departure_time(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        self.pacer.next_departure_time(
+            timestamp,
+            self.congestion_controller.congestion_window(),
+            &self.rtt_estimator,
+            self.congestion_controller.earliest_departure_time(),
+        )
+    }
+
     /// Only PATH_CHALLENGE and PATH_RESPONSE frames should be transmitted here.
     #[inline]
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
@@ -287,6 +479,12 @@ impl<Config: endpoint::Config> Path<Config> {
         self.challenge.is_pending()
     }
 
+    /// Returns true if a PATH_CHALLENGE sent on this path was answered by the peer
+    #[inline]
+    pub fn is_challenge_validated(&self) -> bool {
+        self.challenge.is_validated()
+    }
+
     #[inline]
     pub fn is_response_pending(&self) -> bool {
         self.response_data.is_some()
@@ -412,7 +610,7 @@ impl<Config: endpoint::Config> Path<Config> {
             //
             // The priority during PathValidationOnly is to validate the path, so the
             // minimum MTU is used to avoid packet loss due to MTU limits.
-            Mode::LossRecoveryProbing | Mode::PathValidationOnly => MINIMUM_MTU as usize,
+            Mode::LossRecoveryProbing | Mode::PathValidationOnly => self.mtu_controller.min_mtu(),
             // When MTU Probing, clamp to the size of the MTU we are attempting to validate
             Mode::MtuProbing => self.mtu_controller.probed_sized(),
             // Otherwise use the confirmed MTU
@@ -502,7 +700,13 @@ impl<Config: endpoint::Config> Path<Config> {
         &self,
         space: s2n_quic_core::packet::number::PacketNumberSpace,
     ) -> core::time::Duration {
-        self.rtt_estimator.pto_period(self.pto_backoff, space)
+        let pto_period = self.rtt_estimator.pto_period(self.pto_backoff, space);
+
+        if let Some(max_pto) = self.max_pto {
+            pto_period.min(max_pto)
+        } else {
+            pto_period
+        }
     }
 
     /// Resets the PTO backoff to the initial value
@@ -511,6 +715,75 @@ impl<Config: endpoint::Config> Path<Config> {
         self.pto_backoff = INITIAL_PTO_BACKOFF;
     }
 
+    /// Returns the PTO backoff to use after the next PTO expiration
+    #[inline]
+    pub fn next_pto_backoff(&self) -> u32 {
+        self.pto_backoff.saturating_mul(self.pto_backoff_factor)
+    }
+
+    /// Returns the number of times the PTO has expired since the backoff was last reset
+    #[inline]
+    pub fn pto_count(&self) -> u32 {
+        if self.pto_backoff_factor <= 1 {
+            return 0;
+        }
+
+        let mut backoff = self.pto_backoff;
+        let mut count = 0;
+        while backoff >= self.pto_backoff_factor {
+            backoff /= self.pto_backoff_factor;
+            count += 1;
+        }
+        count
+    }
+
+    /// Sets the factor by which the PTO backoff is increased each time the PTO expires
+    #[inline]
+    pub fn set_pto_backoff_factor(&mut self, factor: u32) {
+        debug_assert!(factor >= 1);
+        self.pto_backoff_factor = factor;
+    }
+
+    #[inline]
+    pub fn pto_backoff_factor(&self) -> u32 {
+        self.pto_backoff_factor
+    }
+
+    /// Sets the upper bound of the PTO period
+    #[inline]
+    pub fn set_max_pto(&mut self, max_pto: Option<Duration>) {
+        self.max_pto = max_pto;
+    }
+
+    #[inline]
+    pub fn max_pto(&self) -> Option<Duration> {
+        self.max_pto
+    }
+
+    /// Sets the number of packets after which an unacknowledged packet is declared lost
+    #[inline]
+    pub fn set_packet_reordering_threshold(&mut self, threshold: u64) {
+        debug_assert!(threshold >= 1);
+        self.packet_reordering_threshold = threshold;
+    }
+
+    #[inline]
+    pub fn packet_reordering_threshold(&self) -> u64 {
+        self.packet_reordering_threshold
+    }
+
+    /// Sets the RTT multiplier after which an unacknowledged packet is declared lost
+    #[inline]
+    pub fn set_time_threshold(&mut self, multiplier: f32) {
+        debug_assert!(multiplier >= 1.0);
+        self.time_threshold = multiplier;
+    }
+
+    #[inline]
+    pub fn time_threshold(&self) -> f32 {
+        self.time_threshold
+    }
+
     /// Marks the path as closing
     pub fn on_closing(&mut self) {
         // Revert the path state to AmplificationLimited so we can control the number
@@ -598,10 +871,7 @@ impl<Config: endpoint::Config> transmission::interest::Provider for Path<Config>
 
 #[cfg(any(test, feature = "testing"))]
 pub mod testing {
-    use crate::{
-        endpoint,
-        path::{Path, DEFAULT_MAX_MTU},
-    };
+    use crate::{endpoint, path::Path};
     use core::time::Duration;
     use s2n_quic_core::{connection, recovery::RttEstimator};
 
@@ -612,8 +882,10 @@ pub mod testing {
             connection::LocalId::TEST_ID,
             RttEstimator::new(Duration::from_millis(30)),
             Default::default(),
+            Default::default(),
+            Default::default(),
             true,
-            DEFAULT_MAX_MTU,
+            Default::default(),
         )
     }
 
@@ -624,8 +896,10 @@ pub mod testing {
             connection::LocalId::TEST_ID,
             RttEstimator::new(Duration::from_millis(30)),
             Default::default(),
+            Default::default(),
+            Default::default(),
             false,
-            DEFAULT_MAX_MTU,
+            Default::default(),
         )
     }
 }
@@ -643,6 +917,7 @@ mod tests {
     use s2n_quic_core::{
         connection, endpoint,
         event::testing::Publisher,
+        packet::number::PacketNumberSpace,
         recovery::{CongestionController, RttEstimator},
         time::{Clock, NoopClock},
         transmission,
@@ -972,6 +1247,56 @@ mod tests {
         assert!(path.is_validated());
     }
 
+    #[test]
+    fn configured_amplification_limit_test() {
+        let mut path = testing::helper_path_server();
+        path.set_amplification_limit(5);
+
+        let unblocked = path.on_bytes_received(1200);
+        assert!(unblocked);
+
+        path.on_bytes_transmitted(1200 * 4);
+        assert!(!path.at_amplification_limit());
+
+        path.on_bytes_transmitted(1200);
+        assert!(path.at_amplification_limit());
+        assert_eq!(
+            path.transmission_constraint(),
+            transmission::Constraint::AmplificationLimited
+        );
+    }
+
+    #[test]
+    fn pto_backoff_test() {
+        let mut path = testing::helper_path_client();
+        let space = PacketNumberSpace::Initial;
+        let base = path.pto_period(space);
+        assert_eq!(path.next_pto_backoff(), 2);
+        assert_eq!(path.pto_count(), 0);
+
+        path.set_pto_backoff_factor(3);
+        path.pto_backoff = path.next_pto_backoff();
+        assert_eq!(path.pto_backoff, 3);
+        assert_eq!(path.pto_count(), 1);
+        path.pto_backoff = path.next_pto_backoff();
+        assert_eq!(path.pto_backoff, 9);
+        assert_eq!(path.pto_count(), 2);
+        assert_eq!(path.pto_period(space), base * 9);
+
+        // the PTO period never exceeds the configured maximum
+        path.set_max_pto(Some(base * 2));
+        assert_eq!(path.pto_period(space), base * 2);
+
+        path.reset_pto_backoff();
+        assert_eq!(path.pto_period(space), base);
+        assert_eq!(path.pto_count(), 0);
+
+        // a factor of 1 disables the backoff
+        path.set_pto_backoff_factor(1);
+        assert_eq!(path.next_pto_backoff(), 1);
+        assert_eq!(path.pto_count(), 0);
+    }
+
     #[test]
     fn amplification_limited_mtu_test() {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
@@ -1115,8 +1440,10 @@ mod tests {
             connection::LocalId::TEST_ID,
             RttEstimator::new(Duration::from_millis(30)),
             Default::default(),
+            Default::default(),
+            Default::default(),
             false,
-            DEFAULT_MAX_MTU,
+            Default::default(),
         );
         let now = NoopClock.get_time();
         let random = &mut random::testing::Generator::default();
@@ -22,6 +22,8 @@ use s2n_quic_core::{
     time::{timer, Timer, Timestamp},
 };
 
+pub use s2n_quic_core::path::mtu::{default, Config, Endpoint, MtuController, PathInfo};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum State {
     //= https://www.rfc-editor.org/rfc/rfc8899#section-5.2
@@ -38,6 +40,17 @@ enum State {
     SearchComplete,
 }
 
+/// Tracks the publication of the `MtuProbingComplete` event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Completion {
+    /// Probing has not yet concluded
+    Incomplete,
+    /// Probing has concluded, but the event has not been published yet
+    Pending,
+    /// The event has been published
+    Published,
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-14.3
 //# Endpoints SHOULD set the initial value of BASE_PLPMTU (Section 5.1 of
 //# [DPLPMTUD]) to be consistent with QUIC's smallest allowed maximum
@@ -67,15 +80,6 @@ const ETHERNET_MTU: u16 = 1500;
 /// the current Path MTU, probing will be considered complete.
 const PROBE_THRESHOLD: u16 = 20;
 
-/// When the black_hole_counter exceeds this threshold, on_black_hole_detected will be
-/// called to reduce the MTU to the BASE_PLPMTU. The black_hole_counter is incremented when
-/// a burst of consecutive packets is lost that starts with a packet that is:
-///      1) not an MTU probe
-///      2) larger than the BASE_PLPMTU
-///      3) sent after the largest MTU-sized acknowledged packet number
-/// This is a possible indication that the path cannot support the MTU that was previously confirmed.
-const BLACK_HOLE_THRESHOLD: u8 = 3;
-
 /// After a black hole has been detected, the mtu::Controller will wait this duration
 /// before probing for a larger MTU again.
 const BLACK_HOLE_COOL_OFF_DURATION: Duration = Duration::from_secs(60);
@@ -88,12 +92,16 @@ const BLACK_HOLE_COOL_OFF_DURATION: Duration = Duration::from_secs(60);
 const PMTU_RAISE_TIMER_DURATION: Duration = Duration::from_secs(600);
 
 #[derive(Clone, Debug)]
-pub struct Controller {
+pub struct Controller<M: MtuController> {
     state: State,
     //= https://www.rfc-editor.org/rfc/rfc8899#section-2
     //# The Packetization Layer PMTU is an estimate of the largest size
     //# of PL datagram that can be sent by a path, controlled by PLPMTUD
     plpmtu: u16,
+    /// The smallest size the plpmtu can reach, derived from the configured minimum MTU
+    base_plpmtu: u16,
+    /// The largest plpmtu confirmed on the path
+    largest_plpmtu: u16,
     /// The maximum size any packet can reach
     max_mtu: MaxMtu,
     /// The maximum size the UDP payload can reach for any probe packet.
@@ -113,6 +121,14 @@ pub struct Controller {
     /// A count of the number of packets with a size > MINIMUM_MTU lost since
     /// the last time a packet with size equal to the current MTU was acknowledged.
     black_hole_counter: Counter<u8, Saturating>,
+    /// When the black_hole_counter exceeds this threshold, on_black_hole_detected will be
+    /// called to reduce the MTU to the base plpmtu. The black_hole_counter is incremented when
+    /// a burst of consecutive packets is lost that starts with a packet that is:
+    ///      1) not an MTU probe
+    ///      2) larger than the base plpmtu
+    ///      3) sent after the largest MTU-sized acknowledged packet number
+    /// This is a possible indication that the path cannot support the MTU that was previously confirmed.
+    black_hole_threshold: u8,
     /// The largest acknowledged packet with size >= the plpmtu. Used when tracking
     /// packets that have been lost for the purpose of detecting a black hole.
     largest_acked_mtu_sized_packet: Option<PacketNumber>,
@@ -121,40 +137,75 @@ pub struct Controller {
     //# sender will continue to use the current PLPMTU, after which it
     //# reenters the Search Phase.
     pmtu_raise_timer: Timer,
+    /// The minimum amount of time between the transmission of consecutive probes
+    probe_interval: Duration,
+    /// Delays the transmission of the next probe until the `probe_interval` has elapsed
+    probe_pacing_timer: Timer,
+    /// The number of probes sent since probing started or a black hole was detected
+    probes_sent: u32,
+    /// The largest size probed for since probing started or a black hole was detected
+    max_probed_size: u16,
+    /// Ensures `MtuProbingComplete` is only published once until a black hole resets probing
+    completion: Completion,
+    /// Selects the sizes to probe for and the size to fall back to when a black hole is detected
+    probe_controller: M,
 }
 
-impl Controller {
-    /// Construct a new mtu::Controller with the given `max_mtu` and `peer_socket_address`
+impl<M: MtuController> Controller<M> {
+    /// Construct a new mtu::Controller with the given `config` and `peer_socket_address`
     ///
-    /// The UDP header length and IP header length will be subtracted from `max_mtu` to
+    /// The UDP header length and IP header length will be subtracted from `config.max_mtu` to
     /// determine the max_udp_payload used for limiting the payload length of probe packets.
     /// max_mtu is the maximum allowed mtu, e.g. for jumbo frames this value is expected to
-    /// be over 9000.
-    pub fn new(max_mtu: MaxMtu, peer_socket_address: &SocketAddress) -> Self {
+    /// be over 9000. The same headers are subtracted from `config.min_mtu` to determine the
+    /// initial plpmtu, which the path will never fall below.
+    pub fn new(config: Config, peer_socket_address: &SocketAddress, probe_controller: M) -> Self {
+        let max_mtu = config.max_mtu;
         let min_ip_header_len = match peer_socket_address {
             SocketAddress::IpV4(_) => IPV4_MIN_HEADER_LEN,
             SocketAddress::IpV6(_) => IPV6_MIN_HEADER_LEN,
         };
         let max_udp_payload =
             (u16::from(max_mtu) - UDP_HEADER_LEN - min_ip_header_len).max(BASE_PLPMTU);
+        let base_plpmtu = config
+            .min_udp_payload(peer_socket_address)
+            .max(BASE_PLPMTU)
+            .min(max_udp_payload);
 
         // The UDP payload size for the most likely MTU is based on standard Ethernet MTU minus
         // the minimum length IP headers (without IPv4 options or IPv6 extensions) and UPD header
         let initial_probed_size =
             (ETHERNET_MTU - UDP_HEADER_LEN - min_ip_header_len).min(max_udp_payload);
 
-        Self {
+        let mut controller = Self {
             state: State::Disabled,
-            plpmtu: BASE_PLPMTU,
+            plpmtu: base_plpmtu,
+            base_plpmtu,
+            largest_plpmtu: base_plpmtu,
             probed_size: initial_probed_size,
             max_mtu,
             max_udp_payload,
             max_probe_size: max_udp_payload,
             probe_count: 0,
             black_hole_counter: Default::default(),
+            black_hole_threshold: config.black_hole_threshold,
             largest_acked_mtu_sized_packet: None,
             pmtu_raise_timer: Timer::default(),
+            probe_interval: config.probe_interval,
+            probe_pacing_timer: Timer::default(),
+            probes_sent: 0,
+            max_probed_size: base_plpmtu,
+            completion: Completion::Incomplete,
+            probe_controller,
+        };
+
+        if initial_probed_size <= base_plpmtu {
+            // The configured minimum MTU already covers a standard Ethernet MTU, so
+            // let the probe controller select the first size to probe for
+            controller.update_probed_size();
         }
+
+        controller
     }
 
     /// Enable path MTU probing
@@ -174,6 +225,9 @@ impl Controller {
         if self.pmtu_raise_timer.poll_expiration(now).is_ready() {
             self.request_new_search(None);
         }
+
+        // Once the probe interval has elapsed, any requested probe may be transmitted
+        let _ = self.probe_pacing_timer.poll_expiration(now);
     }
 
     //= https://www.rfc-editor.org/rfc/rfc8899#section-4.2
@@ -207,6 +261,8 @@ impl Controller {
         if let State::Searching(probe_packet_number, transmit_time) = self.state {
             if packet_number == probe_packet_number {
                 self.plpmtu = self.probed_size;
+                self.largest_plpmtu = self.largest_plpmtu.max(self.plpmtu);
+                self.probe_controller.on_probe_success(self.probed_size);
                 // A new MTU has been confirmed, notify the congestion controller
                 congestion_controller.on_mtu_update(
                     self.plpmtu,
@@ -227,10 +283,13 @@ impl Controller {
                 //# probing is determined by the PMTU_RAISE_TIMER.
 
                 // Subsequent probe packets are sent based on the round trip transmission and
-                // acknowledgement/loss of a packet, so the interval will be at least 1 RTT.
+                // acknowledgement/loss of a packet, so the interval will be at least 1 RTT. If a
+                // probe interval has been configured, the probe_pacing_timer may delay it further.
                 self.request_new_search(Some(transmit_time));
             }
         }
+
+        self.publish_completion(path_id, publisher);
     }
 
     //= https://www.rfc-editor.org/rfc/rfc8899#section-3
@@ -261,6 +320,7 @@ impl Controller {
                 if self.probe_count == MAX_PROBES {
                     // We've sent MAX_PROBES without acknowledgement, so
                     // attempt a smaller probe size
+                    self.probe_controller.on_probe_failure(self.probed_size);
                     self.max_probe_size = self.probed_size;
                     self.update_probed_size();
                     self.request_new_search(None);
@@ -270,22 +330,24 @@ impl Controller {
                 }
             }
             State::Searching(_, _) | State::SearchComplete | State::SearchRequested => {
-                if (BASE_PLPMTU + 1..=self.plpmtu).contains(&lost_bytes)
+                if (self.base_plpmtu + 1..=self.plpmtu).contains(&lost_bytes)
                     && self
                         .largest_acked_mtu_sized_packet
                         .map_or(true, |pn| packet_number > pn)
                     && new_loss_burst
                 {
-                    // A non-probe packet larger than the BASE_PLPMTU that was sent after the last
+                    // A non-probe packet larger than the base plpmtu that was sent after the last
                     // acknowledged MTU-sized packet has been lost
                     self.black_hole_counter += 1;
                 }
 
-                if self.black_hole_counter > BLACK_HOLE_THRESHOLD {
+                if self.black_hole_counter > self.black_hole_threshold {
                     self.on_black_hole_detected(now, congestion_controller, path_id, publisher);
                 }
             }
         }
+
+        self.publish_completion(path_id, publisher);
     }
 
     /// Queries the component for any outgoing frames that need to get sent
@@ -294,7 +356,7 @@ impl Controller {
     /// to the supplied `WriteContext`. This necessitates the caller ensuring the probe packet
     /// written by this method to be in its own connection transmission.
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
-        if self.state != State::SearchRequested || !context.transmission_mode().is_mtu_probing() {
+        if !self.can_transmit_probe() || !context.transmission_mode().is_mtu_probing() {
             //= https://www.rfc-editor.org/rfc/rfc8899#section-5.2
             //# When used with an acknowledged PL (e.g., SCTP), DPLPMTUD SHOULD NOT continue to
             //# generate PLPMTU probes in this state.
@@ -310,7 +372,7 @@ impl Controller {
         if context.remaining_capacity() < probe_payload_size {
             // There isn't enough capacity in the buffer to write the datagram we
             // want to probe, so we've reached the maximum pmtu and the search is complete.
-            self.state = State::SearchComplete;
+            self.on_search_complete();
             return;
         }
 
@@ -334,15 +396,56 @@ impl Controller {
             length: padding_size,
         }) {
             self.probe_count += 1;
+            self.probes_sent += 1;
+            self.max_probed_size = self.max_probed_size.max(self.probed_size);
             self.state = State::Searching(packet_number, context.current_time());
+
+            if self.probe_interval > Duration::ZERO {
+                self.probe_pacing_timer
+                    .set(context.current_time() + self.probe_interval);
+            }
         }
     }
 
+    /// Limits the size of probes and the MTU to the `max_udp_payload_size` transport
+    /// parameter advertised by the peer
+    pub fn on_peer_max_udp_payload_size(&mut self, max_udp_payload_size: u16) {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-18.2
+        //#    This limit does act as an additional constraint on datagram size
+        //#    in the same way as the path MTU, but it is a property of the
+        //#    endpoint and not the path; see Section 14.
+        //
+        // Values below 1200 are rejected when the transport parameters are decoded
+        let max_udp_payload = max_udp_payload_size.max(BASE_PLPMTU);
+
+        if max_udp_payload >= self.max_udp_payload {
+            return;
+        }
+
+        self.max_udp_payload = max_udp_payload;
+        self.max_probe_size = self.max_probe_size.min(max_udp_payload);
+        self.base_plpmtu = self.base_plpmtu.min(max_udp_payload);
+        self.plpmtu = self.plpmtu.min(max_udp_payload);
+        self.largest_plpmtu = self.largest_plpmtu.min(max_udp_payload);
+        self.probed_size = self.probed_size.min(max_udp_payload);
+        self.max_probed_size = self.max_probed_size.min(max_udp_payload);
+    }
+
     /// Gets the currently validated maximum transmission unit, not including IP or UDP header len
     pub fn mtu(&self) -> usize {
         self.plpmtu as usize
     }
 
+    /// Returns the largest MTU confirmed on the path, even if it has since been reduced
+    pub fn largest_mtu(&self) -> usize {
+        self.largest_plpmtu as usize
+    }
+
+    /// Returns the smallest MTU the path can fall back to
+    pub fn min_mtu(&self) -> usize {
+        self.base_plpmtu as usize
+    }
+
     /// Returns the maximum size any packet can reach
     pub fn max_mtu(&self) -> MaxMtu {
         self.max_mtu
@@ -353,12 +456,17 @@ impl Controller {
         self.probed_size as usize
     }
 
-    /// Sets `probed_size` to the next MTU size to probe for based on a binary search
+    /// Returns true if a probe has been requested and the probe interval has elapsed
+    fn can_transmit_probe(&self) -> bool {
+        self.state == State::SearchRequested && !self.probe_pacing_timer.is_armed()
+    }
+
+    /// Sets `probed_size` to the next MTU size to probe for as selected by the `probe_controller`
     fn update_probed_size(&mut self) {
-        //= https://www.rfc-editor.org/rfc/rfc8899#section-5.3.2
-        //# Implementations SHOULD select the set of probe packet sizes to
-        //# maximize the gain in PLPMTU from each search step.
-        self.probed_size = self.plpmtu + ((self.max_probe_size - self.plpmtu) / 2);
+        let probed_size = self
+            .probe_controller
+            .next_probe_size(self.plpmtu, self.max_probe_size);
+        self.probed_size = probed_size.max(self.plpmtu).min(self.max_probe_size);
     }
 
     /// Requests a new search to be initiated
@@ -373,7 +481,7 @@ impl Controller {
         } else {
             // The next probe size is within the threshold of the current MTU
             // so its not worth additional probing.
-            self.state = State::SearchComplete;
+            self.on_search_complete();
 
             if let Some(last_probe_time) = last_probe_time {
                 self.arm_pmtu_raise_timer(last_probe_time + PMTU_RAISE_TIMER_DURATION);
@@ -381,7 +489,7 @@ impl Controller {
         }
     }
 
-    /// Called when an excessive number of packets larger than the BASE_PLPMTU have been lost
+    /// Called when an excessive number of packets larger than the base plpmtu have been lost
     fn on_black_hole_detected<CC: CongestionController, Pub: event::ConnectionPublisher>(
         &mut self,
         now: Timestamp,
@@ -391,14 +499,22 @@ impl Controller {
     ) {
         self.black_hole_counter = Default::default();
         self.largest_acked_mtu_sized_packet = None;
-        // Reset the plpmtu back to the BASE_PLPMTU and notify the congestion controller
-        self.plpmtu = BASE_PLPMTU;
+        // Reset the plpmtu back to the size selected by the probe controller, which is
+        // the base plpmtu by default, and notify the congestion controller
+        let plpmtu = self
+            .probe_controller
+            .on_blackhole_detected(self.plpmtu, self.base_plpmtu);
+        self.plpmtu = plpmtu.max(self.base_plpmtu).min(self.plpmtu);
         congestion_controller.on_mtu_update(
-            BASE_PLPMTU,
+            self.plpmtu,
             &mut congestion_controller::PathPublisher::new(publisher, path_id),
         );
         // Cancel any current probes
         self.state = State::SearchComplete;
+        // Probing will start over once the cool off period has elapsed
+        self.probes_sent = 0;
+        self.max_probed_size = self.plpmtu;
+        self.completion = Completion::Incomplete;
         // Arm the PMTU raise timer to try a larger MTU again after a cooling off period
         self.arm_pmtu_raise_timer(now + BLACK_HOLE_COOL_OFF_DURATION);
 
@@ -409,6 +525,38 @@ impl Controller {
         })
     }
 
+    /// Transitions to the SearchComplete state because no further probing is worthwhile
+    fn on_search_complete(&mut self) {
+        self.state = State::SearchComplete;
+
+        if self.completion == Completion::Incomplete {
+            self.completion = Completion::Pending;
+        }
+    }
+
+    /// Publishes the `MtuProbingComplete` event if probing has concluded since it was last published
+    ///
+    /// Probing may conclude in contexts without access to a publisher, so the event is
+    /// published on the next acknowledgement or loss notification.
+    fn publish_completion<Pub: event::ConnectionPublisher>(
+        &mut self,
+        path_id: path::Id,
+        publisher: &mut Pub,
+    ) {
+        if self.completion != Completion::Pending {
+            return;
+        }
+
+        self.completion = Completion::Published;
+
+        publisher.on_mtu_probing_complete(event::builder::MtuProbingComplete {
+            path_id: path_id.into_event(),
+            mtu: self.plpmtu,
+            max_probed_mtu: self.max_probed_size.max(self.plpmtu),
+            probes_sent: self.probes_sent,
+        });
+    }
+
     /// Arm the PMTU Raise Timer if there is still room to increase the
     /// MTU before hitting the max plpmtu
     fn arm_pmtu_raise_timer(&mut self, timestamp: Timestamp) {
@@ -424,36 +572,39 @@ impl Controller {
     }
 }
 
-impl timer::Provider for Controller {
+impl<M: MtuController> timer::Provider for Controller<M> {
     #[inline]
     fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
         self.pmtu_raise_timer.timers(query)?;
+        self.probe_pacing_timer.timers(query)?;
 
         Ok(())
     }
 }
 
-impl transmission::interest::Provider for Controller {
+impl<M: MtuController> transmission::interest::Provider for Controller<M> {
     #[inline]
     fn transmission_interest<Q: transmission::interest::Query>(
         &self,
         query: &mut Q,
     ) -> transmission::interest::Result {
-        match self.state {
-            State::SearchRequested => query.on_new_data(),
-            _ => Ok(()),
+        if self.can_transmit_probe() {
+            query.on_new_data()?;
         }
+
+        Ok(())
     }
 }
 
 #[cfg(any(test, feature = "testing"))]
 pub mod testing {
-    use crate::path::mtu::{test, Controller};
+    use crate::path::mtu::{default, test, Controller};
 
     /// Creates a new mtu::Controller with the given mtu and probed size
-    pub fn test_controller(mtu: u16, probed_size: u16) -> Controller {
+    pub fn test_controller(mtu: u16, probed_size: u16) -> Controller<default::Controller> {
         let mut controller = test::new_controller(u16::max_value());
         controller.plpmtu = mtu;
+        controller.largest_plpmtu = mtu;
         controller.probed_size = probed_size;
         controller
     }
@@ -462,12 +613,16 @@ pub mod testing {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::contexts::testing::{MockWriteContext, OutgoingFrameBuffer};
+    use crate::{
+        contexts::testing::{MockWriteContext, OutgoingFrameBuffer},
+        transmission::interest::Provider as _,
+    };
     use s2n_quic_core::{
         endpoint,
         event::testing::Publisher,
         frame::Frame,
         packet::number::PacketNumberSpace,
+        path::mtu::DEFAULT_BLACK_HOLE_THRESHOLD,
         recovery::congestion_controller::testing::mock::CongestionController,
         time::{clock::testing::now, timer::Provider as _},
         varint::VarInt,
@@ -475,9 +630,17 @@ mod test {
     use std::{convert::TryInto, net::SocketAddr};
 
     /// Creates a new mtu::Controller with an IPv4 address and the given `max_mtu`
-    pub fn new_controller(max_mtu: u16) -> Controller {
+    pub fn new_controller(max_mtu: u16) -> Controller<default::Controller> {
         let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
-        Controller::new(max_mtu.try_into().unwrap(), &addr.into())
+        Controller::new(mtu_config(max_mtu), &addr.into(), default::Controller)
+    }
+
+    /// Creates a new mtu::Config with the given `max_mtu`
+    fn mtu_config(max_mtu: u16) -> Config {
+        Config {
+            max_mtu: max_mtu.try_into().unwrap(),
+            ..Default::default()
+        }
     }
 
     /// Creates an application space packet number with the given value
@@ -499,7 +662,11 @@ mod test {
     fn min_max_mtu() {
         // Use an IPv6 address to force a smaller `max_udp_payload`
         let addr: SocketAddr = "[::1]:123".parse().unwrap();
-        let controller = Controller::new(MaxMtu::MIN, &addr.into());
+        let controller = Controller::new(
+            mtu_config(MaxMtu::MIN.into()),
+            &addr.into(),
+            default::Controller,
+        );
         assert_eq!(BASE_PLPMTU, controller.plpmtu);
     }
 
@@ -515,7 +682,7 @@ mod test {
     #[test]
     fn new_ipv4() {
         let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
-        let controller = Controller::new(1600.try_into().unwrap(), &addr.into());
+        let controller = Controller::new(mtu_config(1600), &addr.into(), default::Controller);
         assert_eq!(
             1600 - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN,
             controller.max_udp_payload
@@ -539,7 +706,7 @@ mod test {
         let addr: SocketAddr = "[2001:0db8:85a3:0001:0002:8a2e:0370:7334]:9000"
             .parse()
             .unwrap();
-        let controller = Controller::new(2000.try_into().unwrap(), &addr.into());
+        let controller = Controller::new(mtu_config(2000), &addr.into(), default::Controller);
         assert_eq!(
             2000 - UDP_HEADER_LEN - IPV6_MIN_HEADER_LEN,
             controller.max_udp_payload
@@ -558,6 +725,91 @@ mod test {
         );
     }
 
+    #[test]
+    fn new_min_mtu() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let config = Config {
+            min_mtu: 1400.try_into().unwrap(),
+            ..mtu_config(9000)
+        };
+        let controller = Controller::new(config, &addr.into(), default::Controller);
+        assert_eq!(
+            1400 - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN,
+            controller.plpmtu
+        );
+        assert_eq!(controller.plpmtu as usize, controller.min_mtu());
+        assert_eq!(controller.plpmtu, controller.max_probed_size);
+        assert_eq!(
+            ETHERNET_MTU - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN,
+            controller.probed_size
+        );
+    }
+
+    #[test]
+    fn new_min_mtu_larger_than_common_mtu() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let config = Config {
+            min_mtu: 2000.try_into().unwrap(),
+            ..mtu_config(9000)
+        };
+        let controller = Controller::new(config, &addr.into(), default::Controller);
+        let min_udp_payload = 2000 - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN;
+        assert_eq!(min_udp_payload, controller.plpmtu);
+        // The first probe is selected by the probe controller rather than targeting Ethernet
+        assert_eq!(
+            min_udp_payload + (controller.max_udp_payload - min_udp_payload) / 2,
+            controller.probed_size
+        );
+    }
+
+    #[test]
+    fn new_min_mtu_equal_to_max_mtu() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let config = Config {
+            min_mtu: 1500.try_into().unwrap(),
+            ..mtu_config(1500)
+        };
+        let mut controller = Controller::new(config, &addr.into(), default::Controller);
+        assert_eq!(controller.max_udp_payload, controller.plpmtu);
+
+        controller.enable();
+        assert_eq!(State::SearchComplete, controller.state);
+    }
+
+    #[test]
+    fn on_peer_max_udp_payload_size() {
+        let mut controller = new_controller(9000);
+        controller.on_peer_max_udp_payload_size(1300);
+        assert_eq!(1300, controller.max_udp_payload);
+        assert_eq!(1300, controller.max_probe_size);
+        assert_eq!(1300, controller.probed_size);
+        assert_eq!(BASE_PLPMTU as usize, controller.mtu());
+
+        // larger values don't raise the limit
+        controller.on_peer_max_udp_payload_size(1400);
+        assert_eq!(1300, controller.max_udp_payload);
+
+        controller.enable();
+        assert_eq!(State::SearchRequested, controller.state);
+        assert_eq!(1300, controller.probed_size);
+    }
+
+    #[test]
+    fn on_peer_max_udp_payload_size_below_min_mtu() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let config = Config {
+            min_mtu: 1500.try_into().unwrap(),
+            ..mtu_config(9000)
+        };
+        let mut controller = Controller::new(config, &addr.into(), default::Controller);
+        controller.on_peer_max_udp_payload_size(1250);
+        assert_eq!(1250, controller.mtu());
+        assert_eq!(1250, controller.min_mtu());
+
+        controller.enable();
+        assert_eq!(State::SearchComplete, controller.state);
+    }
+
     #[test]
     fn enable_already_enabled() {
         let mut controller = new_controller(1500);
@@ -649,6 +901,32 @@ mod test {
         assert!(!controller.pmtu_raise_timer.is_armed());
     }
 
+    #[test]
+    fn largest_mtu() {
+        let mut controller = new_controller(1500);
+        let pn = pn(1);
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::no_snapshot();
+        assert_eq!(BASE_PLPMTU as usize, controller.largest_mtu());
+
+        controller.state = State::Searching(pn, now);
+        controller.on_packet_ack(
+            pn,
+            controller.probed_size,
+            &mut cc,
+            path::Id::test_id(),
+            &mut publisher,
+        );
+        assert_eq!(1472, controller.mtu());
+        assert_eq!(1472, controller.largest_mtu());
+
+        // the largest MTU is retained after the MTU is reduced
+        controller.on_black_hole_detected(now, &mut cc, path::Id::test_id(), &mut publisher);
+        assert_eq!(BASE_PLPMTU as usize, controller.mtu());
+        assert_eq!(1472, controller.largest_mtu());
+    }
+
     //= https://www.rfc-editor.org/rfc/rfc8899#section-5.3.2
     //= type=test
     //# Implementations SHOULD select the set of probe packet sizes to
@@ -850,7 +1128,7 @@ mod test {
         controller.plpmtu = 1472;
         controller.enable();
 
-        for i in 0..BLACK_HOLE_THRESHOLD + 1 {
+        for i in 0..DEFAULT_BLACK_HOLE_THRESHOLD + 1 {
             let pn = pn(i as usize);
 
             // Losing a packet the size of the BASE_PLPMTU should not increase the black_hole_counter
@@ -898,7 +1176,7 @@ mod test {
                 path::Id::test_id(),
                 &mut publisher,
             );
-            if i < BLACK_HOLE_THRESHOLD {
+            if i < DEFAULT_BLACK_HOLE_THRESHOLD {
                 assert_eq!(controller.black_hole_counter, i + 1);
             }
         }
@@ -914,6 +1192,262 @@ mod test {
         );
     }
 
+    #[test]
+    fn on_packet_loss_black_hole_threshold() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let black_hole_threshold = DEFAULT_BLACK_HOLE_THRESHOLD * 2;
+        let config = Config {
+            black_hole_threshold,
+            ..mtu_config(9000)
+        };
+        let mut controller = Controller::new(config, &addr.into(), default::Controller);
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::snapshot();
+        controller.plpmtu = 1472;
+        controller.enable();
+
+        for i in 0..=black_hole_threshold {
+            // Exceeding the default threshold should not trigger black hole detection
+            assert_eq!(0, cc.on_mtu_update);
+
+            controller.on_packet_loss(
+                pn(i as usize),
+                BASE_PLPMTU + 1,
+                true,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+        }
+
+        assert_eq!(1, cc.on_mtu_update);
+        assert_eq!(BASE_PLPMTU, controller.plpmtu);
+        assert_eq!(controller.black_hole_counter, 0);
+    }
+
+    #[test]
+    fn on_packet_loss_black_hole_min_mtu() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let config = Config {
+            min_mtu: 1400.try_into().unwrap(),
+            ..mtu_config(9000)
+        };
+        let mut controller = Controller::new(config, &addr.into(), default::Controller);
+        let min_udp_payload = controller.plpmtu;
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::snapshot();
+        controller.plpmtu = 1472;
+        controller.enable();
+
+        for i in 0..DEFAULT_BLACK_HOLE_THRESHOLD + 1 {
+            // Losing a packet the size of the configured minimum should not increase the black_hole_counter
+            controller.on_packet_loss(
+                pn(i as usize),
+                min_udp_payload,
+                true,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+            assert_eq!(controller.black_hole_counter, 0);
+        }
+
+        for i in 0..DEFAULT_BLACK_HOLE_THRESHOLD + 1 {
+            controller.on_packet_loss(
+                pn(i as usize),
+                min_udp_payload + 1,
+                true,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+        }
+
+        // The plpmtu falls back to the configured minimum rather than the BASE_PLPMTU
+        assert_eq!(1, cc.on_mtu_update);
+        assert_eq!(min_udp_payload, controller.plpmtu);
+        assert_eq!(State::SearchComplete, controller.state);
+    }
+
+    /// Transmits and acknowledges probes until the search is complete
+    fn probe_until_complete<M: MtuController>(
+        controller: &mut Controller<M>,
+        now: Timestamp,
+        cc: &mut CongestionController,
+        publisher: &mut Publisher,
+    ) {
+        while controller.state == State::SearchRequested {
+            let mut frame_buffer = OutgoingFrameBuffer::new();
+            frame_buffer.set_max_packet_size(Some(controller.probed_size as usize));
+            let mut write_context = MockWriteContext::new(
+                now,
+                &mut frame_buffer,
+                transmission::Constraint::None,
+                transmission::Mode::MtuProbing,
+                endpoint::Type::Server,
+            );
+            let packet_number = write_context.packet_number();
+            controller.on_transmit(&mut write_context);
+            controller.on_packet_ack(
+                packet_number,
+                controller.probed_size,
+                cc,
+                path::Id::test_id(),
+                publisher,
+            );
+        }
+    }
+
+    #[test]
+    fn mtu_probing_complete() {
+        let mut controller = new_controller(9000);
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::snapshot();
+
+        controller.enable();
+        probe_until_complete(&mut controller, now, &mut cc, &mut publisher);
+
+        assert_eq!(State::SearchComplete, controller.state);
+        assert_eq!(Completion::Published, controller.completion);
+        assert_eq!(1, publisher.mtu_probing_complete);
+
+        // Subsequent acknowledgements do not publish the event again
+        controller.on_packet_ack(
+            pn(100),
+            controller.plpmtu,
+            &mut cc,
+            path::Id::test_id(),
+            &mut publisher,
+        );
+        assert_eq!(1, publisher.mtu_probing_complete);
+
+        // A black hole resets the state machine
+        for i in 0..=DEFAULT_BLACK_HOLE_THRESHOLD {
+            controller.on_packet_loss(
+                pn(101 + i as usize),
+                BASE_PLPMTU + 1,
+                true,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+        }
+        assert_eq!(BASE_PLPMTU, controller.plpmtu);
+        assert_eq!(Completion::Incomplete, controller.completion);
+        assert_eq!(0, controller.probes_sent);
+        assert_eq!(1, publisher.mtu_probing_complete);
+
+        // Once the cool off period has elapsed, probing starts over and completes again
+        let now = now + BLACK_HOLE_COOL_OFF_DURATION;
+        controller.on_timeout(now);
+        probe_until_complete(&mut controller, now, &mut cc, &mut publisher);
+
+        assert_eq!(State::SearchComplete, controller.state);
+        assert_eq!(Completion::Published, controller.completion);
+        assert_eq!(2, publisher.mtu_probing_complete);
+    }
+
+    /// Probes for a discrete set of sizes and falls back to the largest size below
+    /// the current MTU when a black hole is detected
+    #[derive(Clone, Debug, Default)]
+    struct DiscreteSizes {
+        acked: Vec<u16>,
+        lost: Vec<u16>,
+    }
+
+    impl DiscreteSizes {
+        const SIZES: [u16; 3] = [1252, 1372, 1472];
+    }
+
+    impl MtuController for DiscreteSizes {
+        fn next_probe_size(&mut self, current: u16, max: u16) -> u16 {
+            Self::SIZES
+                .iter()
+                .copied()
+                .find(|size| *size > current && *size <= max)
+                .unwrap_or(current)
+        }
+
+        fn on_probe_success(&mut self, probed_size: u16) {
+            self.acked.push(probed_size);
+        }
+
+        fn on_probe_failure(&mut self, probed_size: u16) {
+            self.lost.push(probed_size);
+        }
+
+        fn on_blackhole_detected(&mut self, current: u16, base: u16) -> u16 {
+            Self::SIZES
+                .iter()
+                .copied()
+                .rev()
+                .find(|size| *size < current)
+                .unwrap_or(base)
+        }
+    }
+
+    #[test]
+    fn custom_probe_controller() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let mut controller =
+            Controller::new(mtu_config(1500), &addr.into(), DiscreteSizes::default());
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::snapshot();
+
+        // The initial probe is lost MAX_PROBES times, so the controller selects a smaller size
+        controller.state = State::Searching(pn(1), now);
+        controller.probe_count = MAX_PROBES;
+        controller.on_packet_loss(
+            pn(1),
+            controller.probed_size,
+            false,
+            now,
+            &mut cc,
+            path::Id::test_id(),
+            &mut publisher,
+        );
+        assert_eq!(vec![1472], controller.probe_controller.lost);
+        assert_eq!(1252, controller.probed_size);
+        assert_eq!(State::SearchRequested, controller.state);
+
+        // The smaller probe is acknowledged and the next discrete size is probed
+        controller.state = State::Searching(pn(2), now);
+        controller.on_packet_ack(
+            pn(2),
+            controller.probed_size,
+            &mut cc,
+            path::Id::test_id(),
+            &mut publisher,
+        );
+        assert_eq!(vec![1252], controller.probe_controller.acked);
+        assert_eq!(1252, controller.plpmtu);
+        assert_eq!(1372, controller.probed_size);
+
+        // A black hole falls back to the next smallest size instead of the BASE_PLPMTU
+        controller.plpmtu = 1472;
+        for i in 0..=DEFAULT_BLACK_HOLE_THRESHOLD {
+            controller.on_packet_loss(
+                pn(i as usize + 3),
+                controller.plpmtu,
+                true,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+        }
+        assert_eq!(1372, controller.plpmtu);
+        assert_eq!(State::SearchComplete, controller.state);
+    }
+
     #[test]
     fn on_packet_loss_disabled_controller() {
         let mut controller = new_controller(1500);
@@ -921,7 +1455,7 @@ mod test {
         let now = now();
         let mut publisher = Publisher::snapshot();
 
-        for i in 0..BLACK_HOLE_THRESHOLD + 1 {
+        for i in 0..DEFAULT_BLACK_HOLE_THRESHOLD + 1 {
             let pn = pn(i as usize);
             assert_eq!(controller.black_hole_counter, 0);
             controller.on_packet_loss(
@@ -954,7 +1488,7 @@ mod test {
             State::SearchComplete,
         ] {
             controller.state = state;
-            for i in 0..BLACK_HOLE_THRESHOLD + 1 {
+            for i in 0..DEFAULT_BLACK_HOLE_THRESHOLD + 1 {
                 // on_packet_loss may be called with packet numbers from Initial and Handshake space
                 // so it should not fail in this scenario.
                 let pn = PacketNumberSpace::Initial.new_packet_number(VarInt::from_u8(i));
@@ -1093,4 +1627,64 @@ mod test {
         );
         assert_eq!(State::Searching(packet_number, now), controller.state);
     }
+
+    #[test]
+    fn on_transmit_probe_interval() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let probe_interval = Duration::from_millis(500);
+        let config = Config {
+            probe_interval,
+            ..mtu_config(9000)
+        };
+        let mut controller = Controller::new(config, &addr.into(), default::Controller);
+        let mut cc = CongestionController::default();
+        let mut publisher = Publisher::snapshot();
+        let now = now();
+
+        controller.enable();
+        assert!(controller.has_transmission_interest());
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(controller.probed_size as usize));
+        let mut write_context = MockWriteContext::new(
+            now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::MtuProbing,
+            endpoint::Type::Server,
+        );
+        let packet_number = write_context.packet_number();
+        controller.on_transmit(&mut write_context);
+        assert_eq!(State::Searching(packet_number, now), controller.state);
+        assert_eq!(Some(now + probe_interval), controller.next_expiration());
+
+        // The probe is acknowledged before the probe interval has elapsed
+        controller.on_packet_ack(
+            packet_number,
+            controller.probed_size,
+            &mut cc,
+            path::Id::test_id(),
+            &mut publisher,
+        );
+        assert_eq!(State::SearchRequested, controller.state);
+        assert!(!controller.has_transmission_interest());
+
+        // The next probe is not transmitted until the probe interval has elapsed
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(controller.probed_size as usize));
+        let mut write_context = MockWriteContext::new(
+            now + probe_interval / 2,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::MtuProbing,
+            endpoint::Type::Server,
+        );
+        controller.on_transmit(&mut write_context);
+        assert!(write_context.frame_buffer.is_empty());
+        assert_eq!(State::SearchRequested, controller.state);
+
+        controller.on_timeout(now + probe_interval);
+        assert!(controller.has_transmission_interest());
+        assert_eq!(None, controller.next_expiration());
+    }
 }
@@ -17,7 +17,9 @@ use s2n_quic_core::{
     frame::ack::EcnCounts,
     inet::ExplicitCongestionNotification,
     packet::number::{PacketNumber, PacketNumberRange, PacketNumberSpace},
-    recovery::{congestion_controller, CongestionController, RttEstimator, K_GRANULARITY},
+    recovery::{
+        congestion_controller, pacer::Pacer as _, CongestionController, RttEstimator, K_GRANULARITY,
+    },
     time::{timer, timer::Provider, Timer, Timestamp},
     transport,
 };
@@ -67,14 +69,6 @@ pub struct Manager<Config: endpoint::Config> {
     pto_update_pending: bool,
 }
 
-//= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.1
-//# The RECOMMENDED initial value for the packet reordering threshold
-//# (kPacketThreshold) is 3, based on best practices for TCP loss
-//# detection [RFC5681] [RFC6675].  In order to remain similar to TCP,
-//# implementations SHOULD NOT use a packet threshold less than 3; see
-//# [RFC5681].
-const K_PACKET_THRESHOLD: u64 = 3;
-
 /// Initial capacity of the SmallVec used for keeping track of packets
 /// acked in an ack frame
 // TODO: Determine if there is a more appropriate default
@@ -96,7 +90,7 @@ macro_rules! recovery_event {
             latest_rtt: $path.rtt_estimator.latest_rtt(),
             rtt_variance: $path.rtt_estimator.rttvar(),
             max_ack_delay: $path.rtt_estimator.max_ack_delay(),
-            pto_count: ($path.pto_backoff as f32).log2() as u32,
+            pto_count: $path.pto_count(),
             congestion_window: $path.congestion_controller.congestion_window(),
             bytes_in_flight: $path.congestion_controller.bytes_in_flight(),
             congestion_limited: $path.transmission_constraint().is_congestion_limited(),
@@ -192,9 +186,12 @@ impl<Config: endpoint::Config> Manager<Config> {
             //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
             //# When a PTO timer expires, the PTO backoff MUST be increased,
             //# resulting in the PTO period being set to twice its current value.
+            //
+            // The factor can be configured with `Limits::with_pto_backoff_factor`, which
+            // defaults to 2.
             if pto_expired {
                 context.path_mut().pto_backoff =
-                    (context.path().pto_backoff * 2).min(max_pto_backoff);
+                    context.path().next_pto_backoff().min(max_pto_backoff);
                 self.update_pto_timer(context.path(), timestamp, context.is_handshake_confirmed());
             }
         }
@@ -244,6 +241,14 @@ impl<Config: endpoint::Config> Manager<Config> {
             &path.rtt_estimator,
             &mut congestion_controller::PathPublisher::new(publisher, path_id),
         );
+        if congestion_controlled_bytes > 0 {
+            path.pacer.on_packet_sent(
+                time_sent,
+                congestion_controlled_bytes,
+                path.congestion_controller.congestion_window(),
+                &path.rtt_estimator,
+            );
+        }
 
         self.sent_packets.insert(
             packet_number,
@@ -642,6 +647,10 @@ impl<Config: endpoint::Config> Manager<Config> {
             let sent_bytes = acked_packet_info.sent_bytes as usize;
             newly_acked_ecn_counts.increment(acked_packet_info.ecn);
 
+            if acked_packet_info.ack_elicitation.is_ack_eliciting() {
+                path.on_ack_eliciting_packet_acked(timestamp);
+            }
+
             if acked_packet_info.path_id == current_path_id {
                 current_path_acked_bytes += sent_bytes;
             } else if sent_bytes > 0 {
@@ -749,14 +758,15 @@ impl<Config: endpoint::Config> Manager<Config> {
             //# Notification (ECN) [RFC3168] [RFC8311], QUIC treats a Congestion
             //# Experienced (CE) codepoint in the IP header as a signal of
             //# congestion.
-            context
-                .path_mut()
-                .congestion_controller
-                .on_explicit_congestion(
+            let path = context.path_mut();
+            // In `Validate` mode the congestion is only reported, not acted upon
+            if path.ecn_controller.mode().responds_to_congestion() {
+                path.congestion_controller.on_explicit_congestion(
                     ce_count.as_u64(),
                     timestamp,
                     &mut congestion_controller::PathPublisher::new(publisher, path_id),
                 );
+            }
             let path = context.path();
             publisher.on_congestion(event::builder::Congestion {
                 path: path_event!(path, path_id),
@@ -861,7 +871,8 @@ impl<Config: endpoint::Config> Manager<Config> {
             let unacked_path_id = unacked_sent_info.path_id;
             let path = &context.path_by_id(unacked_path_id);
             // Calculate how long we wait until a packet is declared lost
-            let time_threshold = Self::calculate_loss_time_threshold(&path.rtt_estimator);
+            let time_threshold =
+                Self::calculate_loss_time_threshold(&path.rtt_estimator, path.time_threshold());
             // Calculate at what time this particular packet is considered lost based on the
             // current path `time_threshold`
             let packet_lost_time = unacked_sent_info.time_sent + time_threshold;
@@ -872,7 +883,7 @@ impl<Config: endpoint::Config> Manager<Config> {
             let packet_number_threshold_exceeded = largest_acked_packet
                 .checked_distance(unacked_packet_number)
                 .expect("largest_acked_packet >= unacked_packet_number")
-                >= K_PACKET_THRESHOLD;
+                >= path.packet_reordering_threshold();
 
             //= https://www.rfc-editor.org/rfc/rfc9002#section-6.1
             //# A packet is declared lost if it meets all of the following
@@ -944,6 +955,7 @@ impl<Config: endpoint::Config> Manager<Config> {
     ) {
         let current_path_id = context.path_id();
         let mut is_congestion_event = false;
+        let mut is_persistent_congestion = false;
         let mut prev_lost_packet_number = None;
 
         // Remove the lost packets and account for the bytes on the proper congestion controller
@@ -992,6 +1004,8 @@ impl<Config: endpoint::Config> Manager<Config> {
                 is_congestion_event = true;
             }
 
+            path.on_packet_lost();
+
             publisher.on_packet_lost(event::builder::PacketLost {
                 packet_header: event::builder::PacketHeader::new(
                     packet_number,
@@ -1030,11 +1044,21 @@ impl<Config: endpoint::Config> Manager<Config> {
                 //# Endpoints SHOULD set the min_rtt to the newest RTT sample after
                 //# persistent congestion is established.
                 path.rtt_estimator.on_persistent_congestion();
+                is_persistent_congestion = true;
             }
 
             prev_lost_packet_number = Some(packet_number);
         }
 
+        if is_persistent_congestion {
+            let path = context.path();
+            publisher.on_persistent_congestion(event::builder::PersistentCongestion {
+                path: path_event!(path, current_path_id),
+                duration: persistent_congestion_duration,
+                congestion_window: path.congestion_controller.congestion_window(),
+            });
+        }
+
         if is_congestion_event {
             let path = context.path();
             publisher.on_congestion(event::builder::Congestion {
@@ -1044,17 +1068,17 @@ impl<Config: endpoint::Config> Manager<Config> {
         }
     }
 
-    fn calculate_loss_time_threshold(rtt_estimator: &RttEstimator) -> Duration {
+    fn calculate_loss_time_threshold(rtt_estimator: &RttEstimator, multiplier: f32) -> Duration {
         //= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.2
         //# The time threshold is:
         //#
         //# max(kTimeThreshold * max(smoothed_rtt, latest_rtt), kGranularity)
         let mut time_threshold = max(rtt_estimator.smoothed_rtt(), rtt_estimator.latest_rtt());
 
-        //= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.2
-        //# The RECOMMENDED time threshold (kTimeThreshold), expressed as an
-        //# RTT multiplier, is 9/8.
-        time_threshold = (time_threshold * 9) / 8;
+        // The multiplier defaults to kTimeThreshold and can be configured with
+        // `Limits::with_time_threshold`.
+        let nanos = time_threshold.as_nanos() as f64 * multiplier as f64;
+        time_threshold = Duration::from_nanos(nanos as u64);
 
         //= https://www.rfc-editor.org/rfc/rfc9002#section-6.1.2
         //# To avoid declaring
@@ -22,13 +22,13 @@ use s2n_quic_core::{
     frame::ack_elicitation::AckElicitation,
     inet::{DatagramInfo, ExplicitCongestionNotification, SocketAddress},
     packet::number::PacketNumberSpace,
-    path::{migration, RemoteAddress, DEFAULT_MAX_MTU, INITIAL_PTO_BACKOFF},
+    path::{migration, RemoteAddress, INITIAL_PTO_BACKOFF},
     random,
     recovery::{
         congestion_controller::testing::mock::{
             CongestionController as MockCongestionController, Endpoint,
         },
-        DEFAULT_INITIAL_RTT,
+        DEFAULT_INITIAL_RTT, K_PACKET_THRESHOLD, K_TIME_THRESHOLD,
     },
     time::{clock::testing as time, testing::now, Clock, NoopClock},
     transmission::Outcome,
@@ -60,8 +60,10 @@ fn one_second_pto_when_no_previous_rtt_available() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(max_ack_delay),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     manager
@@ -437,8 +439,10 @@ fn on_ack_frame() {
         connection::LocalId::TEST_ID,
         context.path().rtt_estimator,
         MockCongestionController::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     context.path_mut().pto_backoff = 2;
     let ack_receive_time = ack_receive_time + Duration::from_millis(500);
@@ -1527,7 +1531,10 @@ fn detect_and_remove_lost_packets() {
     let expected_time_threshold = Duration::from_secs(9);
     assert_eq!(
         expected_time_threshold,
-        ServerManager::calculate_loss_time_threshold(&context.path().rtt_estimator)
+        ServerManager::calculate_loss_time_threshold(
+            &context.path().rtt_estimator,
+            K_TIME_THRESHOLD
+        )
     );
 
     time_sent += Duration::from_secs(10);
@@ -1736,7 +1743,8 @@ fn detect_lost_packets_persistent_congestion_path_aware() {
     assert_eq!(
         expected_time_threshold,
         ServerManager::calculate_loss_time_threshold(
-            &context.path_by_id(first_path_id).rtt_estimator
+            &context.path_by_id(first_path_id).rtt_estimator,
+            K_TIME_THRESHOLD
         )
     );
 
@@ -1941,6 +1949,66 @@ fn detect_and_remove_lost_packets_nothing_lost() {
     assert_eq!(context.path().congestion_controller.on_packets_lost, 0);
 }
 
+#[test]
+fn detect_and_remove_lost_packets_configured_thresholds() {
+    let space = PacketNumberSpace::ApplicationData;
+    let ecn = ExplicitCongestionNotification::default();
+    let outcome = transmission::Outcome {
+        ack_elicitation: AckElicitation::Eliciting,
+        is_congestion_controlled: true,
+        bytes_sent: 1,
+        bytes_progressed: 0,
+    };
+
+    // Returns the number of packets declared lost when a packet sent 3 packets before the
+    // largest acked packet is checked `elapsed` after it was sent
+    let lost_packets = |packet_threshold: u64, time_threshold: f32, elapsed: Duration| {
+        let mut manager = ServerManager::new(space);
+        let mut path_manager = helper_generate_path_manager(Duration::from_millis(10));
+        let mut context = MockContext::new(&mut path_manager);
+        context
+            .path_mut()
+            .set_packet_reordering_threshold(packet_threshold);
+        context.path_mut().set_time_threshold(time_threshold);
+        manager.largest_acked_packet = Some(space.new_packet_number(VarInt::from_u8(10)));
+        let mut publisher = Publisher::snapshot();
+        let random = &mut random::testing::Generator::default();
+
+        let time_sent = time::now();
+        manager.on_packet_sent(
+            space.new_packet_number(VarInt::from_u8(7)),
+            outcome,
+            time_sent,
+            ecn,
+            transmission::Mode::Normal,
+            None,
+            &mut context,
+            &mut publisher,
+        );
+
+        manager.detect_and_remove_lost_packets(
+            time_sent + elapsed,
+            random,
+            &mut context,
+            &mut publisher,
+        );
+
+        context.lost_packets.len()
+    };
+
+    // the packet meets the default packet threshold
+    assert_eq!(
+        lost_packets(K_PACKET_THRESHOLD, K_TIME_THRESHOLD, Duration::ZERO),
+        1
+    );
+    assert_eq!(lost_packets(5, K_TIME_THRESHOLD, Duration::ZERO), 0);
+
+    // the initial RTT is 333ms so the default time threshold is ~375ms
+    let elapsed = Duration::from_millis(400);
+    assert_eq!(lost_packets(5, K_TIME_THRESHOLD, elapsed), 1);
+    assert_eq!(lost_packets(5, 2.0, elapsed), 0);
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-14.4
 //= type=test
 //# Loss of a QUIC packet that is carried in a PMTU probe is therefore not a
@@ -2625,8 +2693,10 @@ fn update_pto_timer() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(Duration::from_millis(10)),
         MockCongestionController::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
     // simulate receiving a handshake packet to force path validation
     context.path_mut().on_handshake_packet();
@@ -2716,8 +2786,10 @@ fn pto_armed_if_handshake_not_confirmed() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(Duration::from_millis(10)),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     // simulate receiving a handshake packet to force path validation
@@ -2744,8 +2816,10 @@ fn pto_must_be_at_least_k_granularity() {
         connection::LocalId::TEST_ID,
         RttEstimator::new(max_ack_delay),
         Default::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     // Update RTT with the smallest possible sample
@@ -3298,7 +3372,7 @@ fn time_threshold_multiplier_equals_nine_eighths() {
     );
     assert_eq!(
         Duration::from_millis(1125), // 9/8 seconds = 1.125 seconds
-        ServerManager::calculate_loss_time_threshold(&rtt_estimator)
+        ServerManager::calculate_loss_time_threshold(&rtt_estimator, K_TIME_THRESHOLD)
     );
 }
 
@@ -3325,7 +3399,10 @@ fn timer_granularity() {
     //# packets as lost too early, this time threshold MUST be set to at
     //# least the local timer granularity, as indicated by the kGranularity
     //# constant.
-    assert!(ServerManager::calculate_loss_time_threshold(&rtt_estimator) >= K_GRANULARITY);
+    assert!(
+        ServerManager::calculate_loss_time_threshold(&rtt_estimator, K_TIME_THRESHOLD)
+            >= K_GRANULARITY
+    );
 }
 
 #[test]
@@ -3356,8 +3433,10 @@ fn packet_declared_lost_less_than_1_ms_from_loss_threshold() {
     );
     manager.largest_acked_packet = Some(space.new_packet_number(VarInt::from_u8(2)));
 
-    let loss_time_threshold =
-        ServerManager::calculate_loss_time_threshold(&context.path().rtt_estimator);
+    let loss_time_threshold = ServerManager::calculate_loss_time_threshold(
+        &context.path().rtt_estimator,
+        K_TIME_THRESHOLD,
+    );
 
     manager.detect_and_remove_lost_packets(
         sent_time + loss_time_threshold - Duration::from_micros(999),
@@ -3460,8 +3539,10 @@ fn helper_generate_multi_path_manager(
                 &datagram,
                 true,
                 &mut Endpoint::default(),
+                &mut Default::default(),
+                &mut Default::default(),
                 &mut migration::allow_all::Validator,
-                DEFAULT_MAX_MTU,
+                Default::default(),
                 publisher,
             )
             .unwrap();
@@ -3522,8 +3603,10 @@ fn helper_generate_path_manager_with_first_addr(
         connection::LocalId::TEST_ID,
         RttEstimator::new(max_ack_delay),
         MockCongestionController::default(),
+        Default::default(),
+        Default::default(),
         true,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     path::Manager::new(path, registry)
@@ -3543,8 +3626,10 @@ fn helper_generate_client_path_manager(
         connection::LocalId::TEST_ID,
         RttEstimator::new(max_ack_delay),
         MockCongestionController::default(),
+        Default::default(),
+        Default::default(),
         false,
-        DEFAULT_MAX_MTU,
+        Default::default(),
     );
 
     path::Manager::new(path, registry)
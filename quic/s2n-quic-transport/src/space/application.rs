@@ -9,15 +9,15 @@ use crate::{
     processed_packet::ProcessedPacket,
     recovery,
     space::{
-        datagram, keep_alive::KeepAlive, CryptoStream, HandshakeStatus, PacketSpace,
-        TxPacketNumbers,
+        datagram, keep_alive::KeepAlive, spin_bit::Spin, CryptoStream, HandshakeStatus,
+        PacketSpace, TxPacketNumbers,
     },
     stream::Manager as _,
     sync::flag,
     transmission,
     transmission::interest::Provider,
 };
-use core::{convert::TryInto, fmt, marker::PhantomData};
+use core::{convert::TryInto, fmt, marker::PhantomData, time::Duration};
 use once_cell::sync::OnceCell;
 use s2n_codec::EncoderBuffer;
 use s2n_quic_core::{
@@ -25,15 +25,16 @@ use s2n_quic_core::{
     event::{self, ConnectionPublisher as _, IntoEvent},
     frame::{
         ack::AckRanges, crypto::CryptoRef, datagram::DatagramRef, stream::StreamRef, Ack,
-        ConnectionClose, DataBlocked, HandshakeDone, MaxData, MaxStreamData, MaxStreams,
-        NewConnectionId, NewToken, PathChallenge, PathResponse, ResetStream, RetireConnectionId,
-        StopSending, StreamDataBlocked, StreamsBlocked,
+        AckFrequency, ConnectionClose, DataBlocked, HandshakeDone, ImmediateAck, MaxData,
+        MaxStreamData, MaxStreams, NewConnectionId, NewToken, PathChallenge, PathResponse,
+        ResetStream, RetireConnectionId, StopSending, StreamDataBlocked, StreamsBlocked,
     },
     inet::DatagramInfo,
     packet::{
         encoding::{PacketEncoder, PacketEncodingError},
         number::{PacketNumber, PacketNumberRange, PacketNumberSpace, SlidingWindow},
-        short::{CleartextShort, ProtectedShort, Short, SpinBit},
+        short::{CleartextShort, ProtectedShort, Short},
+        KeyPhase,
     },
     path::MaxMtu,
     time::{timer, Timestamp},
@@ -48,8 +49,7 @@ pub struct ApplicationSpace<Config: endpoint::Config> {
     /// All streams that are managed through this connection
     pub stream_manager: Config::StreamManager,
     /// The current state of the Spin bit
-    /// TODO: Spin me
-    pub spin_bit: SpinBit,
+    spin: Spin,
     pub crypto_stream: CryptoStream,
     /// The crypto suite for application data
     /// TODO: What about ZeroRtt?
@@ -62,6 +62,10 @@ pub struct ApplicationSpace<Config: endpoint::Config> {
     //# the handshake (Section 4.1.2).
     key_set: KeySet<<<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::OneRttKey>,
     header_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::OneRttHeaderKey,
+    /// Set when the application requested a key update
+    key_update_requested: bool,
+    /// The number of bytes sent with a key after which a key update is initiated
+    key_update_bytes: Option<u64>,
 
     ping: flag::Ping,
     keep_alive: KeepAlive,
@@ -93,6 +97,8 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         ack_manager: AckManager,
         keep_alive: KeepAlive,
         max_mtu: MaxMtu,
+        key_update_bytes: Option<u64>,
+        spin_bit: bool,
         datagram_manager: datagram::Manager<Config>,
     ) -> Self {
         let key_set = KeySet::new(key, Self::key_limits(max_mtu));
@@ -100,11 +106,13 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         Self {
             tx_packet_numbers: TxPacketNumbers::new(PacketNumberSpace::ApplicationData, now),
             ack_manager,
-            spin_bit: SpinBit::Zero,
+            spin: Spin::new(spin_bit),
             stream_manager,
             crypto_stream: CryptoStream::new(),
             key_set,
             header_key,
+            key_update_requested: false,
+            key_update_bytes,
             ping: flag::Ping::default(),
             keep_alive,
             processed_packet_numbers: SlidingWindow::default(),
@@ -193,7 +201,21 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             packet_interceptor: context.packet_interceptor,
         };
 
-        let spin_bit = self.spin_bit;
+        //= https://www.rfc-editor.org/rfc/rfc9001#section-6.1
+        //# An endpoint MUST NOT initiate a key update prior to having confirmed
+        //# the handshake (Section 4.1.2).
+        if handshake_status.is_confirmed() {
+            let active_key = self.key_set.active_key();
+            let bytes_limit_reached = self
+                .key_update_bytes
+                .map_or(false, |limit| active_key.encrypted_bytes() >= limit);
+
+            if core::mem::take(&mut self.key_update_requested) || bytes_limit_reached {
+                self.key_set.request_update();
+            }
+        }
+
+        let spin_bit = self.spin.on_transmit(&destination_connection_id);
         let header_key = &self.header_key;
         let (_protected_packet, buffer) =
             self.key_set
@@ -217,6 +239,8 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         outcome.bytes_progressed +=
             (self.stream_manager.outgoing_bytes_progressed() - bytes_progressed).as_u64() as usize;
 
+        self.datagram_manager.publish_dropped(context.publisher);
+
         let app_limited = self.is_app_limited(context.path(), outcome.bytes_sent);
 
         let (recovery_manager, mut recovery_context) = self.recovery(
@@ -237,7 +261,11 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         );
 
         // reset the keep alive timer after sending an ack-eliciting packet
-        if outcome.ack_elicitation.is_ack_eliciting() {
+        //
+        // MTU probes are excluded since they are likely to be lost when probing for a larger
+        // MTU, so they can't be relied on to keep the connection alive.
+        if outcome.ack_elicitation.is_ack_eliciting() && !context.transmission_mode.is_mtu_probing()
+        {
             self.keep_alive.reset(timestamp);
         }
 
@@ -297,7 +325,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             packet_interceptor: context.packet_interceptor,
         };
 
-        let spin_bit = self.spin_bit;
+        let spin_bit = self.spin.on_transmit(&destination_connection_id);
         let min_packet_len = context.min_packet_len;
         let header_key = &self.header_key;
         let (_protected_packet, buffer) =
@@ -364,6 +392,9 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         // Retire the local connection ID used during the handshake to reduce linkability
         local_id_registry.retire_handshake_connection_id();
 
+        // Request the peer to send fewer acknowledgements, if it supports it
+        self.ack_manager.on_handshake_confirmed();
+
         //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
         //# A sender SHOULD restart its PTO timer every time an ack-eliciting
         //# packet is sent or acknowledged, or when Initial or Handshake keys are
@@ -433,10 +464,29 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         self.ping.send()
     }
 
+    /// Requests a key update, which is initiated once the handshake has been confirmed
+    pub fn initiate_key_update(&mut self) {
+        self.key_update_requested = true;
+        // make sure a packet is sent with the new keys so the peer responds to the update
+        self.ping.send();
+    }
+
+    /// Requests the peer to acknowledge the next packet without delay
+    pub fn request_immediate_ack(&mut self) {
+        self.ack_manager.request_immediate_ack();
+    }
+
     pub fn keep_alive(&mut self, enabled: bool) {
         self.keep_alive.update(enabled);
     }
 
+    pub fn keep_alive_with_period(
+        &mut self,
+        period: Duration,
+    ) -> Result<(), connection::KeepAliveError> {
+        self.keep_alive.update_period(period)
+    }
+
     /// Returns the Packet Number to be used when encoding outgoing packets
     fn packet_number_encoder(&self) -> PacketNumber {
         self.tx_packet_numbers.largest_sent_packet_number_acked()
@@ -499,6 +549,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             })?;
 
         let packet_number = packet.packet_number;
+        let spin_bit = packet.spin_bit;
         let packet_header =
             event::builder::PacketHeader::new(packet.packet_number, publisher.quic_version());
         let decrypted = self.key_set.decrypt_packet(
@@ -526,6 +577,13 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
                     key_type: event::builder::KeyType::OneRtt { generation },
                     cipher_suite: self.key_set.cipher_suite().into_event(),
                 });
+
+                let key_phase = self.key_set.key_phase();
+                let previous_key = self.key_set.key(KeyPhase::next_phase(key_phase));
+                publisher.on_key_updated(event::builder::KeyUpdated {
+                    key_phase: key_phase as u8,
+                    packets_sent: previous_key.encrypted_packets(),
+                });
             }
             Ok(_) => {}
             Err(_) => {
@@ -552,6 +610,8 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         if decrypted.is_ok() {
             // reset the keep alive timer after receiving a packet
             self.keep_alive.reset(datagram.timestamp);
+            self.spin
+                .on_packet_received(Config::ENDPOINT_TYPE, packet_number, spin_bit);
         }
 
         decrypted.map(|x| x.0)
@@ -888,12 +948,13 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
         )
     }
 
-    fn handle_retire_connection_id_frame(
+    fn handle_retire_connection_id_frame<Pub: event::ConnectionPublisher>(
         &mut self,
         frame: RetireConnectionId,
         datagram: &DatagramInfo,
         path: &mut Path<Config>,
         local_id_registry: &mut connection::LocalIdRegistry,
+        publisher: &mut Pub,
     ) -> Result<(), transport::Error> {
         let sequence_number = frame
             .sequence_number
@@ -914,14 +975,23 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
         //# Receipt of a RETIRE_CONNECTION_ID frame containing a sequence number
         //# greater than any previously sent to the peer MUST be treated as a
         //# connection error of type PROTOCOL_VIOLATION.
-        local_id_registry
+        let retired_id = local_id_registry
             .on_retire_connection_id(
                 sequence_number,
                 &datagram.destination_connection_id,
                 path.rtt_estimator.smoothed_rtt(),
                 datagram.timestamp,
             )
-            .map_err(|err| transport::Error::PROTOCOL_VIOLATION.with_reason(err.message()))
+            .map_err(|err| transport::Error::PROTOCOL_VIOLATION.with_reason(err.message()))?;
+
+        if let Some(retired_id) = retired_id {
+            publisher.on_connection_id_retired(event::builder::ConnectionIdRetired {
+                sequence_number: sequence_number as u64,
+                connection_id: retired_id.into_event(),
+            });
+        }
+
+        Ok(())
     }
 
     fn handle_path_challenge_frame(
@@ -964,7 +1034,7 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
                 .with_frame_type(frame.tag().into()));
         }
 
-        handshake_status.on_handshake_done_received(publisher);
+        handshake_status.on_handshake_done_received(timestamp, publisher);
 
         //= https://www.rfc-editor.org/rfc/rfc9001#section-4.1.2
         //# At the
@@ -975,6 +1045,18 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
         Ok(())
     }
 
+    fn handle_immediate_ack_frame(&mut self, frame: ImmediateAck) -> Result<(), transport::Error> {
+        self.ack_manager.on_immediate_ack_frame(&frame)
+    }
+
+    fn handle_ack_frequency_frame<Pub: event::ConnectionPublisher>(
+        &mut self,
+        frame: AckFrequency,
+        publisher: &mut Pub,
+    ) -> Result<(), transport::Error> {
+        self.ack_manager.on_ack_frequency_frame(&frame, publisher)
+    }
+
     fn on_processed_packet<Pub: event::ConnectionPublisher>(
         &mut self,
         processed_packet: ProcessedPacket,
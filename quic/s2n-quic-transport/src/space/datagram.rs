@@ -12,7 +12,10 @@ use crate::{
 use core::task::Poll;
 use s2n_codec::EncoderValue;
 use s2n_quic_core::{
-    datagram::{Endpoint, ReceiveContext, Receiver, Sender, WriteError},
+    datagram::{
+        DatagramPriority, DropReason, Endpoint, ReceiveContext, Receiver, Sender, WriteError,
+    },
+    event::{self, IntoEvent},
     frame::{self, datagram::DatagramRef},
     query,
     varint::VarInt,
@@ -26,6 +29,11 @@ pub struct Manager<Config: endpoint::Config> {
     pub sender: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Sender,
     pub receiver: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Receiver,
     max_datagram_payload: u64,
+    priority: DatagramPriority,
+    /// Whether datagrams were prioritized in the last packet when using round robin
+    prioritized_last: bool,
+    /// Datagrams dropped by the sender that have yet to be published as events
+    dropped: Vec<(usize, usize, DropReason)>,
 }
 
 impl<Config: endpoint::Config> Manager<Config> {
@@ -33,30 +41,66 @@ impl<Config: endpoint::Config> Manager<Config> {
         sender: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Sender,
         receiver: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Receiver,
         max_datagram_payload: u64,
+        priority: DatagramPriority,
     ) -> Self {
         Self {
             sender,
             receiver,
             max_datagram_payload,
+            priority,
+            prioritized_last: false,
+            dropped: Vec::new(),
+        }
+    }
+
+    /// Returns whether datagrams should be written before stream data in the next packet
+    ///
+    /// This should be called once per packet, since it advances the round robin state.
+    pub fn prioritize_datagrams(&mut self) -> bool {
+        match self.priority {
+            DatagramPriority::Stream => false,
+            DatagramPriority::Datagram => true,
+            DatagramPriority::RoundRobin => {
+                self.prioritized_last = !self.prioritized_last;
+                self.prioritized_last
+            }
+            _ => false,
         }
     }
 
     /// A callback that allows users to write datagrams directly to the packet.
+    ///
+    /// `mtu` is the maximum transmission unit of the path the packet is being sent on.
     pub fn on_transmit<W: WriteContext>(
         &mut self,
         context: &mut W,
         stream_manager: &mut Config::StreamManager,
         datagrams_prioritized: bool,
+        mtu: usize,
     ) {
+        let max_datagram_size = max_datagram_size(mtu, context.header_len(), context.tag_len());
         let mut packet = Packet {
             context,
             has_pending_streams: stream_manager.has_pending_streams(),
             datagrams_prioritized,
             max_datagram_payload: self.max_datagram_payload,
+            max_datagram_size,
+            dropped: &mut self.dropped,
         };
         self.sender.on_transmit(&mut packet);
     }
 
+    /// Publishes an event for the datagrams dropped by the sender since the last call
+    pub fn publish_dropped<Pub: event::ConnectionPublisher>(&mut self, publisher: &mut Pub) {
+        for (count, len, reason) in self.dropped.drain(..) {
+            publisher.on_datagram_frame_dropped(event::builder::DatagramFrameDropped {
+                count,
+                len,
+                reason: reason.into_event(),
+            });
+        }
+    }
+
     // A callback that allows users to access datagrams directly after they are
     // received.
     pub fn on_datagram_frame(
@@ -95,25 +139,37 @@ impl<Config: endpoint::Config> interest::Provider for Manager<Config> {
     }
 }
 
+/// Returns the size of the largest datagram that fits in an otherwise empty packet of `mtu`
+/// bytes with the given header and tag lengths
+pub fn max_datagram_size(mtu: usize, header_len: usize, tag_len: usize) -> usize {
+    datagram_capacity(mtu.saturating_sub(header_len + tag_len))
+}
+
+/// Returns the number of bytes of datagram data that fit in `space` bytes of a packet
+fn datagram_capacity(space: usize) -> usize {
+    // Remove the frame type length and the maximum length value
+    space
+        .saturating_sub(frame::datagram::DATAGRAM_TAG.encoding_size())
+        .saturating_sub(
+            VarInt::new(space as u64)
+                .unwrap_or(VarInt::MAX)
+                .encoding_size(),
+        )
+}
+
 struct Packet<'a, C: WriteContext> {
     context: &'a mut C,
     has_pending_streams: bool,
     datagrams_prioritized: bool,
     max_datagram_payload: u64,
+    max_datagram_size: usize,
+    dropped: &'a mut Vec<(usize, usize, DropReason)>,
 }
 
 impl<'a, C: WriteContext> s2n_quic_core::datagram::Packet for Packet<'a, C> {
     /// Returns the remaining space in the packet
     fn remaining_capacity(&self) -> usize {
-        let space = self.context.remaining_capacity();
-        // Remove the frame type length and the maximum length value
-        space
-            .saturating_sub(frame::datagram::DATAGRAM_TAG.encoding_size())
-            .saturating_sub(
-                VarInt::new(space as u64)
-                    .unwrap_or(VarInt::MAX)
-                    .encoding_size(),
-            )
+        datagram_capacity(self.context.remaining_capacity())
     }
 
     /// Writes a single datagram to a packet
@@ -150,4 +206,14 @@ impl<'a, C: WriteContext> s2n_quic_core::datagram::Packet for Packet<'a, C> {
     fn datagrams_prioritized(&self) -> bool {
         self.datagrams_prioritized
     }
+
+    /// Returns the size of the largest datagram that fits in a packet on the current path
+    fn max_datagram_size(&self) -> usize {
+        self.max_datagram_size
+    }
+
+    /// Records dropped datagrams so an event can be published once the packet is sent
+    fn on_datagram_dropped(&mut self, count: usize, len: usize, reason: DropReason) {
+        self.dropped.push((count, len, reason));
+    }
 }
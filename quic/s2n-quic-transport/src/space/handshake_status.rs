@@ -7,6 +7,7 @@ use s2n_quic_core::{
     event::{self, ConnectionPublisher},
     frame::HandshakeDone,
     packet::number::PacketNumber,
+    time::Timestamp,
 };
 
 pub type Flag = flag::Flag<HandshakeDoneWriter>;
@@ -38,8 +39,17 @@ pub type Flag = flag::Flag<HandshakeDoneWriter>;
 /// the handshake.
 ///
 /// Note: s2n-quic does not implement the optional 1-rtt acked requirement.
+///
+/// The time taken to reach each phase of the handshake is also tracked and reported
+/// with `HandshakeProgress` events.
+#[derive(Debug)]
+pub struct HandshakeStatus {
+    state: State,
+    progress: Progress,
+}
+
 #[derive(Debug, Default)]
-pub enum HandshakeStatus {
+enum State {
     /// Awaiting handshake completion
     #[default]
     InProgress,
@@ -58,32 +68,103 @@ pub enum HandshakeStatus {
     Confirmed,
 }
 
+/// Tracks the phases of the handshake which have been reported
+#[derive(Debug)]
+struct Progress {
+    /// The time the connection was created
+    start: Timestamp,
+    initial_sent: bool,
+    handshake_received: bool,
+    zero_rtt: bool,
+    resumed: bool,
+}
+
 impl HandshakeStatus {
+    /// Creates a new `HandshakeStatus` for a connection created at `start`
+    pub fn new(start: Timestamp) -> Self {
+        Self {
+            state: State::default(),
+            progress: Progress {
+                start,
+                initial_sent: false,
+                handshake_received: false,
+                zero_rtt: false,
+                resumed: false,
+            },
+        }
+    }
+
     /// Returns `true` if the handshake has been completed
     #[inline]
     pub fn is_complete(&self) -> bool {
         // The handshake is complete once its not Pending
-        !matches!(self, HandshakeStatus::InProgress)
+        !matches!(self.state, State::InProgress)
     }
 
     /// Returns `true` if the handshake has been confirmed
     pub fn is_confirmed(&self) -> bool {
-        match self {
-            HandshakeStatus::InProgress | HandshakeStatus::ClientComplete => false,
-            HandshakeStatus::ServerCompleteConfirmed(_) => {
+        match self.state {
+            State::InProgress | State::ClientComplete => false,
+            State::ServerCompleteConfirmed(_) => {
                 //= https://www.rfc-editor.org/rfc/rfc9001#section-4.1.2
                 //# the TLS handshake is considered confirmed at the
                 //# server when the handshake completes
                 true
             }
-            HandshakeStatus::Confirmed => true,
+            State::Confirmed => true,
+        }
+    }
+
+    /// This method is called when an Initial packet has been sent
+    pub fn on_initial_sent<Pub: ConnectionPublisher>(
+        &mut self,
+        timestamp: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        if !self.progress.initial_sent {
+            self.progress.initial_sent = true;
+            self.on_progress(
+                event::builder::HandshakePhase::InitialSent,
+                timestamp,
+                publisher,
+            );
+        }
+    }
+
+    /// This method is called when a Handshake packet has been received from the peer
+    pub fn on_handshake_received<Pub: ConnectionPublisher>(
+        &mut self,
+        timestamp: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        if !self.progress.handshake_received {
+            self.progress.handshake_received = true;
+            self.on_progress(
+                event::builder::HandshakePhase::HandshakeReceived,
+                timestamp,
+                publisher,
+            );
         }
     }
 
+    /// This method is called when 0-RTT keys have been negotiated by the TLS session
+    pub fn on_zero_rtt_keys(&mut self) {
+        self.progress.zero_rtt = true;
+    }
+
+    /// This method is called when the TLS session was resumed
+    pub fn on_session_resumed(&mut self) {
+        self.progress.resumed = true;
+    }
+
     /// This method is called on the client when the HANDSHAKE_DONE
     /// frame has been received
-    pub fn on_handshake_done_received<Pub: ConnectionPublisher>(&mut self, publisher: &mut Pub) {
-        if let HandshakeStatus::ClientComplete = self {
+    pub fn on_handshake_done_received<Pub: ConnectionPublisher>(
+        &mut self,
+        timestamp: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        if let State::ClientComplete = self.state {
             publisher.on_handshake_status_updated(event::builder::HandshakeStatusUpdated {
                 status: event::builder::HandshakeStatus::HandshakeDoneAcked,
             });
@@ -93,7 +174,8 @@ impl HandshakeStatus {
             //= https://www.rfc-editor.org/rfc/rfc9001#section-4.1.2
             //# At the client, the handshake is
             //# considered confirmed when a HANDSHAKE_DONE frame is received.
-            *self = HandshakeStatus::Confirmed;
+            self.state = State::Confirmed;
+            self.on_confirmed(timestamp, publisher);
         }
     }
 
@@ -101,10 +183,11 @@ impl HandshakeStatus {
     pub fn on_handshake_complete<Pub: ConnectionPublisher>(
         &mut self,
         endpoint_type: endpoint::Type,
+        timestamp: Timestamp,
         publisher: &mut Pub,
     ) {
         debug_assert!(
-            matches!(self, Self::InProgress),
+            matches!(self.state, State::InProgress),
             "on_handshake_complete should only be called once."
         );
         publisher.on_handshake_status_updated(event::builder::HandshakeStatusUpdated {
@@ -120,12 +203,40 @@ impl HandshakeStatus {
             //# frame as soon as the handshake is complete.
             let mut flag = Flag::default();
             flag.send();
-            *self = HandshakeStatus::ServerCompleteConfirmed(flag);
+            self.state = State::ServerCompleteConfirmed(flag);
+            self.on_confirmed(timestamp, publisher);
         } else {
-            *self = HandshakeStatus::ClientComplete;
+            self.state = State::ClientComplete;
         }
     }
 
+    /// Reports the confirmation of the handshake along with its total duration
+    fn on_confirmed<Pub: ConnectionPublisher>(&self, timestamp: Timestamp, publisher: &mut Pub) {
+        self.on_progress(
+            event::builder::HandshakePhase::HandshakeConfirmed,
+            timestamp,
+            publisher,
+        );
+        publisher.on_handshake_complete(event::builder::HandshakeComplete {
+            duration: timestamp.saturating_duration_since(self.progress.start),
+            zero_rtt: self.progress.zero_rtt,
+            resumed: self.progress.resumed,
+        });
+    }
+
+    #[inline]
+    fn on_progress<Pub: ConnectionPublisher>(
+        &self,
+        phase: event::builder::HandshakePhase,
+        timestamp: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        publisher.on_handshake_progress(event::builder::HandshakeProgress {
+            phase,
+            elapsed: timestamp.saturating_duration_since(self.progress.start),
+        });
+    }
+
     /// Used for tracking when the HANDSHAKE_DONE frame has been delivered
     /// to the peer.
     pub fn on_packet_ack<A: ack::Set, Pub: event::ConnectionPublisher>(
@@ -133,7 +244,7 @@ impl HandshakeStatus {
         ack_set: &A,
         publisher: &mut Pub,
     ) {
-        if let HandshakeStatus::ServerCompleteConfirmed(flag) = self {
+        if let State::ServerCompleteConfirmed(flag) = &mut self.state {
             // The server is required to re-transmit the frame until it is
             // acknowledged by the peer. Once it is delivered, the state
             // can transition to Confirmed.
@@ -141,7 +252,7 @@ impl HandshakeStatus {
                 publisher.on_handshake_status_updated(event::builder::HandshakeStatusUpdated {
                     status: event::builder::HandshakeStatus::HandshakeDoneAcked,
                 });
-                *self = HandshakeStatus::Confirmed;
+                self.state = State::Confirmed;
             }
         }
     }
@@ -153,7 +264,7 @@ impl HandshakeStatus {
         ack_set: &A,
         publisher: &mut Pub,
     ) {
-        if let HandshakeStatus::ServerCompleteConfirmed(flag) = self {
+        if let State::ServerCompleteConfirmed(flag) = &mut self.state {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.3
             //# The HANDSHAKE_DONE frame MUST be retransmitted until it is
             //# acknowledged.
@@ -167,7 +278,7 @@ impl HandshakeStatus {
 
     /// Queries if any HANDSHAKE_DONE frames need to get sent
     pub fn on_transmit<C: WriteContext>(&mut self, context: &mut C) {
-        if let HandshakeStatus::ServerCompleteConfirmed(flag) = self {
+        if let State::ServerCompleteConfirmed(flag) = &mut self.state {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-19.20
             //# A HANDSHAKE_DONE frame can only be sent by the server.
             let _ = flag.on_transmit(context);
@@ -194,7 +305,7 @@ impl transmission::interest::Provider for HandshakeStatus {
         &self,
         query: &mut Q,
     ) -> transmission::interest::Result {
-        if let HandshakeStatus::ServerCompleteConfirmed(flag) = self {
+        if let State::ServerCompleteConfirmed(flag) = &self.state {
             flag.transmission_interest(query)
         } else {
             Ok(())
@@ -223,7 +334,7 @@ mod tests {
             endpoint::Type::Server,
         );
 
-        let mut status = HandshakeStatus::default();
+        let mut status = HandshakeStatus::new(time::now());
 
         assert!(!status.is_confirmed());
         assert!(!status.is_complete());
@@ -243,7 +354,7 @@ mod tests {
         //= type=test
         //# the TLS handshake is considered confirmed at the
         //# server when the handshake completes.
-        status.on_handshake_complete(endpoint::Type::Server, &mut publisher);
+        status.on_handshake_complete(endpoint::Type::Server, time::now(), &mut publisher);
         assert!(status.is_confirmed());
         assert!(status.is_complete());
 
@@ -288,7 +399,7 @@ mod tests {
             endpoint::Type::Client,
         );
 
-        let mut status = HandshakeStatus::default();
+        let mut status = HandshakeStatus::new(time::now());
 
         assert!(!status.is_complete());
         assert!(!status.is_confirmed());
@@ -305,11 +416,11 @@ mod tests {
         );
 
         // the handshake must be complete prior to being confirmed
-        status.on_handshake_done_received(&mut publisher);
+        status.on_handshake_done_received(time::now(), &mut publisher);
         assert!(!status.is_complete());
         assert!(!status.is_confirmed());
 
-        status.on_handshake_complete(endpoint::Type::Client, &mut publisher);
+        status.on_handshake_complete(endpoint::Type::Client, time::now(), &mut publisher);
         assert!(status.is_complete());
 
         assert!(
@@ -324,11 +435,11 @@ mod tests {
         );
 
         // confirm the client handshake
-        status.on_handshake_done_received(&mut publisher);
+        status.on_handshake_done_received(time::now(), &mut publisher);
         assert!(status.is_confirmed());
 
         // try calling it multiple times
-        status.on_handshake_done_received(&mut publisher);
+        status.on_handshake_done_received(time::now(), &mut publisher);
         assert!(status.is_confirmed());
     }
 }
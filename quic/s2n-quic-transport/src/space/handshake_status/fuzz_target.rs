@@ -1,6 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::State;
 use crate::{
     contexts::testing::*,
     endpoint,
@@ -119,7 +120,7 @@ struct Model {
 impl Model {
     fn new(endpoint_type: endpoint::Type) -> Self {
         Model {
-            subject: HandshakeStatus::InProgress,
+            subject: HandshakeStatus::new(time::now()),
             oracle: Oracle::new(endpoint_type),
         }
     }
@@ -140,8 +141,11 @@ impl Model {
 
     fn on_complete(&mut self) {
         if !self.oracle.complete {
-            self.subject
-                .on_handshake_complete(self.oracle.endpoint_type, &mut Publisher::no_snapshot());
+            self.subject.on_handshake_complete(
+                self.oracle.endpoint_type,
+                time::now(),
+                &mut Publisher::no_snapshot(),
+            );
             self.oracle.on_handshake_complete();
         }
     }
@@ -149,8 +153,8 @@ impl Model {
     fn packet_transmit(&mut self) {
         if self.oracle.can_transmit() {
             assert!(matches!(
-                self.subject,
-                HandshakeStatus::ServerCompleteConfirmed(_)
+                self.subject.state,
+                State::ServerCompleteConfirmed(_)
             ));
 
             let mut frame_buffer = OutgoingFrameBuffer::new();
@@ -189,12 +193,12 @@ impl Model {
             // check that the HANDSHAKE_DONE frame was sent but not yet delivered
             if self.oracle.handshake_done_sent && !self.oracle.handshake_done_delivered {
                 assert!(matches!(
-                    self.subject,
-                    HandshakeStatus::ServerCompleteConfirmed(_)
+                    self.subject.state,
+                    State::ServerCompleteConfirmed(_)
                 ));
 
                 if lost_handshake_done {
-                    if let HandshakeStatus::ServerCompleteConfirmed(flag) = &self.subject {
+                    if let State::ServerCompleteConfirmed(flag) = &self.subject.state {
                         //= https://www.rfc-editor.org/rfc/rfc9000#section-13.3
                         //= type=test
                         //# The HANDSHAKE_DONE frame MUST be retransmitted until it is
@@ -210,7 +214,7 @@ impl Model {
 
     fn on_handshake_done_received(&mut self) {
         self.subject
-            .on_handshake_done_received(&mut Publisher::no_snapshot());
+            .on_handshake_done_received(time::now(), &mut Publisher::no_snapshot());
         self.oracle.on_handshake_done_received();
     }
 
@@ -220,9 +224,9 @@ impl Model {
 
         assert_eq!(
             self.oracle.pending,
-            matches!(self.subject, HandshakeStatus::InProgress)
+            matches!(self.subject.state, State::InProgress)
         );
-        if matches!(self.subject, HandshakeStatus::Confirmed) {
+        if matches!(self.subject.state, State::Confirmed) {
             assert!(self.oracle.complete);
             assert!(self.oracle.confirmed);
         }
@@ -234,14 +238,14 @@ impl Model {
             }
 
             if self.oracle.handshake_done_sent {
-                assert!(!matches!(self.subject, HandshakeStatus::InProgress));
-                if let HandshakeStatus::ServerCompleteConfirmed(flag) = &self.subject {
+                assert!(!matches!(self.subject.state, State::InProgress));
+                if let State::ServerCompleteConfirmed(flag) = &self.subject.state {
                     // HANDSHAKE_DONE should either needs transmission or is in-flight
                     assert!(!flag.is_idle());
                 }
             }
             if self.oracle.handshake_done_delivered {
-                assert!(matches!(self.subject, HandshakeStatus::Confirmed));
+                assert!(matches!(self.subject.state, State::Confirmed));
             }
         }
 
@@ -250,7 +254,7 @@ impl Model {
             // If handshake is complete but awaiting confirmation
             if self.subject.is_complete() && !self.oracle.handshake_done_received {
                 assert!(!self.subject.is_confirmed());
-                assert!(matches!(self.subject, HandshakeStatus::ClientComplete));
+                assert!(matches!(self.subject.state, State::ClientComplete));
             }
 
             if self.oracle.handshake_done_received {
@@ -151,7 +151,7 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
         &mut self,
         context: &mut ConnectionTransmissionContext<Config>,
         transmission_constraint: transmission::Constraint,
-        handshake_status: &HandshakeStatus,
+        handshake_status: &mut HandshakeStatus,
         buffer: EncoderBuffer<'a>,
     ) -> Result<(transmission::Outcome, EncoderBuffer<'a>), PacketEncodingError<'a>> {
         let mut packet_number = self.tx_packet_numbers.next();
@@ -231,6 +231,8 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
                 packet_len: outcome.bytes_sent,
             });
 
+        handshake_status.on_initial_sent(time_sent, context.publisher);
+
         Ok((outcome, buffer))
     }
 
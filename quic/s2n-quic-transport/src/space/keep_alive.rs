@@ -2,18 +2,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::{task::Poll, time::Duration};
-use s2n_quic_core::time::{timer, Timer, Timestamp};
+use s2n_quic_core::{
+    connection,
+    time::{timer, Timer, Timestamp},
+};
 
 #[derive(Debug)]
 pub struct KeepAlive {
     enabled: bool,
     period: Duration,
+    max_idle_timeout: Option<Duration>,
+    last_activity: Option<Timestamp>,
     timer: Timer,
 }
 
 impl KeepAlive {
-    pub fn new(max_idle_timeout: Option<Duration>, max_period: Duration) -> Self {
-        let period = if let Some(max_idle_timeout) = max_idle_timeout {
+    pub fn new(
+        max_idle_timeout: Option<Duration>,
+        max_period: Duration,
+        configured_period: Option<Duration>,
+    ) -> Self {
+        let default_period = if let Some(max_idle_timeout) = max_idle_timeout {
             // send a ping frame at 3/4 max idle timeout to ensure it is delivered in time
             (max_idle_timeout * 3 / 4).min(max_period)
         } else {
@@ -31,9 +40,20 @@ impl KeepAlive {
             max_period
         };
 
+        // A period configured on the limits is validated here, once the idle timeout has been
+        // negotiated with the peer. If it isn't shorter than the negotiated timeout, fall back
+        // to the default period so the connection doesn't idle out.
+        let (enabled, period) = match configured_period {
+            Some(period) if Self::is_valid_period(period, max_idle_timeout) => (true, period),
+            Some(_) => (true, default_period),
+            None => (false, default_period),
+        };
+
         Self {
-            enabled: false,
+            enabled,
             period,
+            max_idle_timeout,
+            last_activity: None,
             timer: Timer::default(),
         }
     }
@@ -43,11 +63,42 @@ impl KeepAlive {
         self.enabled = enabled;
     }
 
+    /// Enables keep-alive with the given period
+    ///
+    /// Returns an error if the period isn't less than the negotiated idle timeout, since the
+    /// connection would time out before a PING frame was sent.
+    #[inline]
+    pub fn update_period(&mut self, period: Duration) -> Result<(), connection::KeepAliveError> {
+        if !Self::is_valid_period(period, self.max_idle_timeout) {
+            let max_idle_timeout = self.max_idle_timeout.unwrap_or_default();
+            return Err(connection::KeepAliveError::InvalidPeriod {
+                period,
+                max_idle_timeout,
+            });
+        }
+
+        self.enabled = true;
+        self.period = period;
+
+        // reschedule the timer from the last activity with the new period
+        if let Some(last_activity) = self.last_activity {
+            self.timer.set(last_activity + period);
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub fn reset(&mut self, now: Timestamp) {
+        self.last_activity = Some(now);
         self.timer.set(now + self.period)
     }
 
+    #[inline]
+    fn is_valid_period(period: Duration, max_idle_timeout: Option<Duration>) -> bool {
+        !period.is_zero() && max_idle_timeout.map_or(true, |timeout| period < timeout)
+    }
+
     #[inline]
     pub fn on_timeout(&mut self, now: Timestamp) -> Poll<()> {
         if !self.enabled {
@@ -23,9 +23,9 @@ use s2n_quic_core::{
     event::{self, IntoEvent},
     frame::{
         ack::AckRanges, crypto::CryptoRef, datagram::DatagramRef, stream::StreamRef, Ack,
-        ConnectionClose, DataBlocked, HandshakeDone, MaxData, MaxStreamData, MaxStreams,
-        NewConnectionId, NewToken, PathChallenge, PathResponse, ResetStream, RetireConnectionId,
-        StopSending, StreamDataBlocked, StreamsBlocked,
+        AckFrequency, ConnectionClose, DataBlocked, HandshakeDone, ImmediateAck, MaxData,
+        MaxStreamData, MaxStreams, NewConnectionId, NewToken, PathChallenge, PathResponse,
+        ResetStream, RetireConnectionId, StopSending, StreamDataBlocked, StreamsBlocked,
     },
     inet::DatagramInfo,
     packet::number::{PacketNumber, PacketNumberSpace},
@@ -41,6 +41,7 @@ mod handshake_status;
 mod initial;
 mod keep_alive;
 mod session_context;
+mod spin_bit;
 mod tx_packet_numbers;
 
 pub(crate) use application::ApplicationSpace;
@@ -48,7 +49,7 @@ pub(crate) use crypto_stream::CryptoStream;
 pub(crate) use handshake::HandshakeSpace;
 pub(crate) use handshake_status::HandshakeStatus;
 pub(crate) use initial::InitialSpace;
-pub(crate) use session_context::SessionContext;
+pub(crate) use session_context::{SessionContext, SERVER_NAME_REJECTED};
 pub(crate) use tx_packet_numbers::TxPacketNumbers;
 
 struct SessionInfo<Config: endpoint::Config> {
@@ -75,6 +76,12 @@ pub struct PacketSpaceManager<Config: endpoint::Config> {
     //# another mechanism is used for agreeing on an application protocol,
     //# endpoints MUST use ALPN for this purpose.
     pub application_protocol: Bytes,
+    /// The DER-encoded certificate chain presented by the peer
+    pub peer_certificate_chain: Vec<Bytes>,
+    /// The OCSP response stapled by the server
+    pub ocsp_response: Option<Bytes>,
+    /// The parameters negotiated by the TLS handshake
+    pub tls_info: Option<tls::TlsInfo>,
 }
 
 impl<Config: endpoint::Config> fmt::Debug for PacketSpaceManager<Config> {
@@ -132,9 +139,12 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             handshake: None,
             application: None,
             zero_rtt_crypto: None,
-            handshake_status: HandshakeStatus::default(),
+            handshake_status: HandshakeStatus::new(now),
             server_name: None,
             application_protocol: Bytes::new(),
+            peer_certificate_chain: Vec::new(),
+            ocsp_response: None,
+            tls_info: None,
         }
     }
 
@@ -246,6 +256,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
         waker: &Waker,
         publisher: &mut Pub,
         datagram: &mut Config::DatagramEndpoint,
+        connection_limits: &mut Config::ConnectionLimits,
     ) -> Poll<Result<(), transport::Error>> {
         if let Some(session_info) = self.session_info.as_mut() {
             let mut context: SessionContext<Config, Pub> = SessionContext {
@@ -262,9 +273,13 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                peer_certificate_chain: &mut self.peer_certificate_chain,
+                ocsp_response: &mut self.ocsp_response,
+                tls_info: &mut self.tls_info,
                 waker,
                 publisher,
                 datagram,
+                connection_limits,
             };
 
             match session_info.session.poll(&mut context)? {
@@ -293,7 +308,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
         let path = path_manager.active_path();
 
         // ensure the backoff doesn't grow too quickly
-        let max_backoff = path.pto_backoff * 2;
+        let max_backoff = path.next_pto_backoff();
 
         if let Some((space, handshake_status)) = self.initial_mut() {
             space.on_timeout(
@@ -641,12 +656,23 @@ pub trait PacketSpace<Config: endpoint::Config> {
             .with_frame_type(frame.tag().into()))
     }
 
-    fn handle_retire_connection_id_frame(
+    fn handle_ack_frequency_frame<Pub: event::ConnectionPublisher>(
+        &mut self,
+        frame: AckFrequency,
+        _publisher: &mut Pub,
+    ) -> Result<(), transport::Error> {
+        Err(transport::Error::PROTOCOL_VIOLATION
+            .with_reason(Self::INVALID_FRAME_ERROR)
+            .with_frame_type(frame.tag().into()))
+    }
+
+    fn handle_retire_connection_id_frame<Pub: event::ConnectionPublisher>(
         &mut self,
         frame: RetireConnectionId,
         _datagram: &DatagramInfo,
         _path: &mut Path<Config>,
         _local_id_registry: &mut connection::LocalIdRegistry,
+        _publisher: &mut Pub,
     ) -> Result<(), transport::Error> {
         Err(transport::Error::PROTOCOL_VIOLATION
             .with_reason(Self::INVALID_FRAME_ERROR)
@@ -716,6 +742,7 @@ pub trait PacketSpace<Config: endpoint::Config> {
     default_frame_handler!(handle_stream_data_blocked_frame, StreamDataBlocked);
     default_frame_handler!(handle_streams_blocked_frame, StreamsBlocked);
     default_frame_handler!(handle_new_token_frame, NewToken);
+    default_frame_handler!(handle_immediate_ack_frame, ImmediateAck);
 
     fn on_processed_packet<Pub: event::ConnectionPublisher>(
         &mut self,
@@ -737,6 +764,7 @@ pub trait PacketSpace<Config: endpoint::Config> {
         handshake_status: &mut HandshakeStatus,
         local_id_registry: &mut connection::LocalIdRegistry,
         random_generator: &mut Config::RandomGenerator,
+        remote_close_reason: &mut Option<Bytes>,
         publisher: &mut Pub,
         packet_interceptor: &mut Config::PacketInterceptor,
     ) -> Result<ProcessedPacket<'a>, connection::Error> {
@@ -840,6 +868,9 @@ pub trait PacketSpace<Config: endpoint::Config> {
                     )
                     .map_err(on_error)?;
 
+                    // keep a copy of the reason phrase so the application can inspect it
+                    *remote_close_reason = frame.reason.map(Bytes::copy_from_slice);
+
                     // skip processing any other frames and return an error
                     return Err(frame.into());
                 }
@@ -905,6 +936,7 @@ pub trait PacketSpace<Config: endpoint::Config> {
                         datagram,
                         &mut path_manager[path_id],
                         local_id_registry,
+                        publisher,
                     )
                     .map_err(on_error)?;
                 }
@@ -926,6 +958,16 @@ pub trait PacketSpace<Config: endpoint::Config> {
                     self.handle_path_response_frame(frame, path_manager, publisher)
                         .map_err(on_error)?;
                 }
+                Frame::AckFrequency(frame) => {
+                    let on_error = on_frame_processed!(frame);
+                    self.handle_ack_frequency_frame(frame, publisher)
+                        .map_err(on_error)?;
+                }
+                Frame::ImmediateAck(frame) => {
+                    let on_error = on_frame_processed!(frame);
+                    self.handle_immediate_ack_frame(frame).map_err(on_error)?;
+                    processed_packet.immediate_ack_requested = true;
+                }
                 Frame::HandshakeDone(frame) => {
                     let on_error = on_frame_processed!(frame);
                     self.handle_handshake_done_frame(
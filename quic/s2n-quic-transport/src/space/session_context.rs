@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    ack::AckManager,
+    ack::{AckFrequency, AckManager},
     connection::{self, limits::Limits},
     endpoint, path,
     space::{
@@ -17,25 +17,32 @@ use s2n_codec::{DecoderBuffer, DecoderValue};
 use s2n_quic_core::{
     ack,
     application::ServerName,
-    connection::{InitialId, PeerId},
+    connection::{
+        limits::{ConnectionDecision, Limiter as _, ServerNameInfo},
+        InitialId, PeerId,
+    },
     crypto,
-    crypto::{tls, CryptoSuite, Key},
+    crypto::{tls, CryptoError, CryptoSuite, Key},
     ct::ConstantTimeEq,
     datagram::{ConnectionInfo, Endpoint},
     event,
     event::IntoEvent,
-    packet::number::PacketNumberSpace,
+    packet::number::{PacketNumberLen, PacketNumberSpace},
     time::Timestamp,
     transport::{
         self,
         parameters::{
             ActiveConnectionIdLimit, ClientTransportParameters, DatagramLimits,
-            InitialFlowControlLimits, InitialSourceConnectionId, MaxAckDelay,
+            InitialFlowControlLimits, InitialSourceConnectionId, MaxAckDelay, MinAckDelay,
             ServerTransportParameters,
         },
     },
 };
 
+/// The error returned when the connection limiter rejects the server name sent by the client
+pub(crate) const SERVER_NAME_REJECTED: CryptoError =
+    CryptoError::UNRECOGNIZED_NAME.with_reason("the server name was rejected");
+
 pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher> {
     pub now: Timestamp,
     pub initial_cid: &'a InitialId,
@@ -52,9 +59,13 @@ pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPu
     pub limits: &'a mut Limits,
     pub server_name: &'a mut Option<ServerName>,
     pub application_protocol: &'a mut Bytes,
+    pub peer_certificate_chain: &'a mut Vec<Bytes>,
+    pub ocsp_response: &'a mut Option<Bytes>,
+    pub tls_info: &'a mut Option<tls::TlsInfo>,
     pub waker: &'a Waker,
     pub publisher: &'a mut Pub,
     pub datagram: &'a mut Config::DatagramEndpoint,
+    pub connection_limits: &'a mut Config::ConnectionLimits,
 }
 
 impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
@@ -70,6 +81,7 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             ActiveConnectionIdLimit,
             DatagramLimits,
             MaxAckDelay,
+            Option<MinAckDelay>,
         ),
         transport::Error,
     > {
@@ -179,15 +191,28 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
         // Load the peer's transport parameters into the connection's limits
         self.limits.load_peer(&peer_parameters);
 
+        let max_udp_payload_size = peer_parameters
+            .max_udp_payload_size
+            .as_u64()
+            .try_into()
+            .unwrap_or(u16::MAX);
+        self.path_manager
+            .on_peer_max_udp_payload_size(max_udp_payload_size, self.publisher);
+
         let initial_flow_control_limits = peer_parameters.flow_control_limits();
         let active_connection_id_limit = peer_parameters.active_connection_id_limit;
         let datagram_limits = peer_parameters.datagram_limits();
+        let min_ack_delay = Self::validate_min_ack_delay(
+            peer_parameters.min_ack_delay,
+            peer_parameters.max_ack_delay,
+        )?;
 
         Ok((
             initial_flow_control_limits,
             active_connection_id_limit,
             datagram_limits,
             peer_parameters.max_ack_delay,
+            min_ack_delay,
         ))
     }
 
@@ -201,6 +226,7 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             ActiveConnectionIdLimit,
             DatagramLimits,
             MaxAckDelay,
+            Option<MinAckDelay>,
         ),
         transport::Error,
     > {
@@ -237,15 +263,28 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
         // Load the peer's transport parameters into the connection's limits
         self.limits.load_peer(&peer_parameters);
 
+        let max_udp_payload_size = peer_parameters
+            .max_udp_payload_size
+            .as_u64()
+            .try_into()
+            .unwrap_or(u16::MAX);
+        self.path_manager
+            .on_peer_max_udp_payload_size(max_udp_payload_size, self.publisher);
+
         let initial_flow_control_limits = peer_parameters.flow_control_limits();
         let active_connection_id_limit = peer_parameters.active_connection_id_limit;
         let datagram_limits = peer_parameters.datagram_limits();
+        let min_ack_delay = Self::validate_min_ack_delay(
+            peer_parameters.min_ack_delay,
+            peer_parameters.max_ack_delay,
+        )?;
 
         Ok((
             initial_flow_control_limits,
             active_connection_id_limit,
             datagram_limits,
             peer_parameters.max_ack_delay,
+            min_ack_delay,
         ))
     }
 
@@ -291,6 +330,24 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         Ok(())
     }
+
+    /// Returns the peer's `min_ack_delay`, which is only advertised by peers that support the
+    /// ACK_FREQUENCY extension
+    fn validate_min_ack_delay(
+        min_ack_delay: Option<MinAckDelay>,
+        max_ack_delay: MaxAckDelay,
+    ) -> Result<Option<MinAckDelay>, transport::Error> {
+        if let Some(min_ack_delay) = min_ack_delay {
+            // A peer can't request to delay acknowledgements for less time than it
+            // advertised it is willing to delay them
+            if !min_ack_delay.is_valid_for(max_ack_delay) {
+                return Err(transport::Error::TRANSPORT_PARAMETER_ERROR
+                    .with_reason("min_ack_delay is larger than max_ack_delay"));
+            }
+        }
+
+        Ok(min_ack_delay)
+    }
 }
 
 impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
@@ -344,6 +401,7 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         // TODO: also store the header_key https://github.com/aws/s2n-quic/issues/319
         *self.zero_rtt_crypto = Some(Box::new(key));
+        self.handshake_status.on_zero_rtt_keys();
 
         self.publisher.on_key_update(event::builder::KeyUpdate {
             key_type: event::builder::KeyType::ZeroRtt,
@@ -373,12 +431,19 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         // Parse transport parameters
         let param_decoder = DecoderBuffer::new(application_parameters.transport_parameters);
-        let (peer_flow_control_limits, active_connection_id_limit, datagram_limits, max_ack_delay) =
-            match Config::ENDPOINT_TYPE {
-                endpoint::Type::Client => self.on_server_params(param_decoder)?,
-                endpoint::Type::Server => self.on_client_params(param_decoder)?,
-            };
+        let (
+            peer_flow_control_limits,
+            active_connection_id_limit,
+            datagram_limits,
+            max_ack_delay,
+            min_ack_delay,
+        ) = match Config::ENDPOINT_TYPE {
+            endpoint::Type::Client => self.on_server_params(param_decoder)?,
+            endpoint::Type::Server => self.on_client_params(param_decoder)?,
+        };
 
+        self.local_id_registry
+            .set_max_active_connection_id_limit(self.limits.issued_connection_id_limit());
         self.local_id_registry
             .set_active_connection_id_limit(active_connection_id_limit.as_u64());
 
@@ -389,23 +454,38 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             peer_flow_control_limits,
         );
 
+        let ack_frequency =
+            AckFrequency::new(self.limits.min_ack_delay(), min_ack_delay, max_ack_delay);
         let ack_manager = AckManager::new(
             PacketNumberSpace::ApplicationData,
             self.limits.ack_settings(),
-        );
+        )
+        .with_ack_frequency(ack_frequency);
 
         let keep_alive = KeepAlive::new(
             self.limits.max_idle_timeout(),
             self.limits.max_keep_alive_period(),
+            self.limits.keep_alive_period(),
         );
 
-        let conn_info =
-            ConnectionInfo::new(datagram_limits.max_datagram_payload, self.waker.clone());
+        // The largest datagram that fits in a short header packet on the active path
+        let active_path = self.path_manager.active_path();
+        let max_datagram_size = datagram::max_datagram_size(
+            active_path.mtu_controller.mtu(),
+            1 + active_path.peer_connection_id.len() + PacketNumberLen::MAX_LEN,
+            key.tag_len(),
+        );
+        let conn_info = ConnectionInfo::new(
+            datagram_limits.max_datagram_payload,
+            max_datagram_size,
+            self.waker.clone(),
+        );
         let (datagram_sender, datagram_receiver) = self.datagram.create_connection(&conn_info);
         let datagram_manager = datagram::Manager::new(
             datagram_sender,
             datagram_receiver,
             datagram_limits.max_datagram_payload,
+            self.datagram.priority(),
         );
 
         self.path_manager
@@ -423,6 +503,8 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             ack_manager,
             keep_alive,
             max_mtu,
+            self.limits.key_update_bytes(),
+            self.limits.spin_bit(),
             datagram_manager,
         )));
         self.publisher.on_key_update(event::builder::KeyUpdate {
@@ -438,6 +520,16 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             .on_server_name_information(event::builder::ServerNameInformation {
                 chosen_server_name: &server_name,
             });
+
+        if Config::ENDPOINT_TYPE.is_server() {
+            let remote_address = self.path_manager.active_path().remote_address();
+            let info = ServerNameInfo::new(&remote_address, &server_name);
+
+            if let ConnectionDecision::Reject = self.connection_limits.on_server_name(&info) {
+                return Err(SERVER_NAME_REJECTED.into());
+            }
+        }
+
         *self.server_name = Some(server_name);
 
         Ok(())
@@ -481,8 +573,11 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             return Err(err);
         }
 
-        self.handshake_status
-            .on_handshake_complete(Config::ENDPOINT_TYPE, self.publisher);
+        self.handshake_status.on_handshake_complete(
+            Config::ENDPOINT_TYPE,
+            self.now,
+            self.publisher,
+        );
 
         if let Some(application) = self.application.as_mut() {
             if Config::ENDPOINT_TYPE.is_server() {
@@ -505,6 +600,22 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
         }
     }
 
+    fn on_session_resumed(&mut self) {
+        self.handshake_status.on_session_resumed();
+    }
+
+    fn on_peer_certificate_chain(&mut self, chain: Vec<Bytes>) {
+        *self.peer_certificate_chain = chain;
+    }
+
+    fn on_ocsp_response(&mut self, response: Bytes) {
+        *self.ocsp_response = Some(response);
+    }
+
+    fn on_tls_info(&mut self, info: tls::TlsInfo) {
+        *self.tls_info = Some(info);
+    }
+
     fn receive_initial(&mut self, max_len: Option<usize>) -> Option<Bytes> {
         let space = self.initial.as_deref_mut()?;
 
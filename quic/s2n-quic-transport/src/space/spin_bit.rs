@@ -0,0 +1,180 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use s2n_quic_core::{
+    connection::PeerId, endpoint, packet::number::PacketNumber, packet::short::SpinBit,
+};
+
+/// Tracks the value of the latency spin bit sent in 1-RTT packets
+///
+/// See <https://www.rfc-editor.org/rfc/rfc9000#section-17.4>
+#[derive(Debug)]
+pub struct Spin {
+    enabled: bool,
+    value: SpinBit,
+    largest_received: Option<PacketNumber>,
+    peer_connection_id: Option<PeerId>,
+}
+
+impl Spin {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            value: SpinBit::Zero,
+            largest_received: None,
+            peer_connection_id: None,
+        }
+    }
+
+    /// Returns the spin bit to include in a packet sent to the provided connection ID
+    #[inline]
+    pub fn on_transmit(&mut self, peer_connection_id: &PeerId) -> SpinBit {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-17.4
+        //# When the spin bit is disabled, endpoints MAY set the spin bit to any
+        //# value and MUST ignore any incoming value.
+        if !self.enabled || Self::is_disabled_for(peer_connection_id) {
+            return SpinBit::Zero;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-17.4
+        //# An endpoint resets the spin value for a network path to zero when
+        //# changing the connection ID being used on that network path.
+        if self.peer_connection_id.as_ref() != Some(peer_connection_id) {
+            self.peer_connection_id = Some(*peer_connection_id);
+            self.value = SpinBit::Zero;
+            self.largest_received = None;
+        }
+
+        self.value
+    }
+
+    /// Updates the spin value after an authenticated 1-RTT packet was received
+    #[inline]
+    pub fn on_packet_received(
+        &mut self,
+        endpoint_type: endpoint::Type,
+        packet_number: PacketNumber,
+        spin_bit: SpinBit,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        if self
+            .largest_received
+            .map_or(false, |largest| packet_number <= largest)
+        {
+            return;
+        }
+
+        self.largest_received = Some(packet_number);
+
+        self.value = match endpoint_type {
+            //= https://www.rfc-editor.org/rfc/rfc9000#section-17.4
+            //# When a server receives a 1-RTT packet that increases the highest
+            //# packet number seen by the server from the client that is using a
+            //# given connection ID, it sets the spin value for that connection ID
+            //# to be equal to the spin bit in the received packet.
+            endpoint::Type::Server => spin_bit,
+            //= https://www.rfc-editor.org/rfc/rfc9000#section-17.4
+            //# When a client receives a 1-RTT packet that increases the highest
+            //# packet number seen by the client from the server that is using a
+            //# given connection ID, it sets the spin value for that connection ID
+            //# to the inverse of the spin bit in the received packet.
+            endpoint::Type::Client => match spin_bit {
+                SpinBit::Zero => SpinBit::One,
+                SpinBit::One => SpinBit::Zero,
+            },
+        };
+    }
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-17.4
+    //# Even when the spin bit is not disabled by the administrator, endpoints
+    //# MUST disable their use of the spin bit for a random selection of at
+    //# least one in every 16 network paths, or for one in every 16
+    //# connection IDs, in order to ensure that QUIC connections that disable
+    //# the spin bit are commonly observed on the network.
+    #[inline]
+    fn is_disabled_for(peer_connection_id: &PeerId) -> bool {
+        // connection IDs are chosen at random so the first byte selects 1 in 16 of them
+        peer_connection_id
+            .as_bytes()
+            .first()
+            .map_or(false, |byte| byte & 0x0f == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{packet::number::PacketNumberSpace, varint::VarInt};
+
+    fn pn(value: u32) -> PacketNumber {
+        PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u32(value))
+    }
+
+    fn peer_id(byte: u8) -> PeerId {
+        PeerId::try_from_bytes(&[byte; 8]).unwrap()
+    }
+
+    #[test]
+    fn disabled_test() {
+        let mut spin = Spin::new(false);
+        let id = peer_id(1);
+
+        for (packet_number, spin_bit) in [(1, SpinBit::One), (2, SpinBit::Zero), (3, SpinBit::One)]
+        {
+            spin.on_packet_received(endpoint::Type::Client, pn(packet_number), spin_bit);
+            assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+        }
+    }
+
+    #[test]
+    fn client_inverts_test() {
+        let mut spin = Spin::new(true);
+        let id = peer_id(1);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+
+        spin.on_packet_received(endpoint::Type::Client, pn(1), SpinBit::Zero);
+        assert_eq!(spin.on_transmit(&id), SpinBit::One);
+
+        spin.on_packet_received(endpoint::Type::Client, pn(2), SpinBit::One);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+
+        // reordered packets don't change the value
+        spin.on_packet_received(endpoint::Type::Client, pn(1), SpinBit::Zero);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+    }
+
+    #[test]
+    fn server_reflects_test() {
+        let mut spin = Spin::new(true);
+        let id = peer_id(1);
+
+        spin.on_packet_received(endpoint::Type::Server, pn(1), SpinBit::One);
+        assert_eq!(spin.on_transmit(&id), SpinBit::One);
+
+        spin.on_packet_received(endpoint::Type::Server, pn(3), SpinBit::Zero);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+
+        spin.on_packet_received(endpoint::Type::Server, pn(2), SpinBit::One);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+    }
+
+    #[test]
+    fn connection_id_change_test() {
+        let mut spin = Spin::new(true);
+        let id = peer_id(1);
+        assert_eq!(spin.on_transmit(&id), SpinBit::Zero);
+
+        spin.on_packet_received(endpoint::Type::Server, pn(1), SpinBit::One);
+        assert_eq!(spin.on_transmit(&id), SpinBit::One);
+
+        // the value is reset when the connection ID changes
+        assert_eq!(spin.on_transmit(&peer_id(2)), SpinBit::Zero);
+
+        // some connection IDs never spin
+        spin.on_packet_received(endpoint::Type::Server, pn(2), SpinBit::One);
+        assert_eq!(spin.on_transmit(&peer_id(0x10)), SpinBit::Zero);
+    }
+}
@@ -205,6 +205,30 @@ macro_rules! tx_stream_apis {
             Ok(())
         }
 
+        /// Enqueues a final chunk of data and marks the stream as finished.
+        ///
+        /// The chunk and the end of the stream are enqueued together, which allows both of them
+        /// to be transmitted in a single `STREAM` frame.
+        ///
+        /// The method will return:
+        /// - `Poll::Ready(Ok(()))` if the data was enqueued and the stream was finished. The
+        ///   provided `Bytes` will be replaced with an empty `Bytes`.
+        /// - `Poll::Ready(Err(stream_error))` if the data could not be sent, because the stream
+        ///   had previously entered an error state.
+        /// - `Poll::Pending` if the send buffer capacity is currently exhausted. In this case, the
+        ///   caller should retry sending after the `Waker` on the provided `Context` is notified.
+        pub fn poll_send_finish(
+            &mut self,
+            chunk: &mut Bytes,
+            cx: &mut Context,
+        ) -> Poll<Result<(), StreamError>> {
+            self.tx_request()?
+                .send(core::slice::from_mut(chunk))
+                .finish()
+                .poll(Some(cx))?
+                .into()
+        }
+
         /// Marks the stream as finished and waits for all outstanding data to be acknowledged
         ///
         /// The method will return:
@@ -224,6 +248,28 @@ macro_rules! tx_stream_apis {
             self.tx_request()?.reset(error_code).poll(None)?;
             Ok(())
         }
+
+        /// Sets the transmission priority of the stream.
+        ///
+        /// Streams with a higher priority are transmitted before streams with a lower priority.
+        pub fn set_priority(&mut self, priority: u8) -> Result<(), StreamError> {
+            self.tx_request()?.set_priority(priority).poll(None)?;
+            Ok(())
+        }
+
+        /// Sets the amount of time data written to the stream has to be acknowledged by the peer.
+        ///
+        /// If the deadline expires, the stream is reset with the provided error code.
+        pub fn set_send_deadline(
+            &mut self,
+            deadline: core::time::Duration,
+            error_code: application::Error,
+        ) -> Result<(), StreamError> {
+            self.tx_request()?
+                .set_send_deadline(deadline, error_code)
+                .poll(None)?;
+            Ok(())
+        }
     };
 }
 
@@ -494,6 +540,20 @@ macro_rules! tx_request_apis {
             self.request.flush();
             self
         }
+
+        pub fn set_priority(&mut self, priority: u8) -> &mut Self {
+            self.request.set_priority(priority);
+            self
+        }
+
+        pub fn set_send_deadline(
+            &mut self,
+            deadline: core::time::Duration,
+            error_code: application::Error,
+        ) -> &mut Self {
+            self.request.set_send_deadline(deadline, error_code);
+            self
+        }
     };
 }
 
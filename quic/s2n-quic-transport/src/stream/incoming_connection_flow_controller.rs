@@ -5,11 +5,12 @@
 
 use crate::{
     contexts::{OnTransmitError, WriteContext},
+    stream::receive_window_tuner::ReceiveWindowTuner,
     sync::{IncrementalValueSync, ValueToFrameWriter},
     transmission,
 };
 use alloc::rc::Rc;
-use core::cell::RefCell;
+use core::{cell::RefCell, time::Duration};
 use s2n_quic_core::{
     ack, frame::max_data::MaxData, packet::number::PacketNumber, stream::StreamId, transport,
     varint::VarInt,
@@ -46,6 +47,13 @@ struct IncomingConnectionFlowControllerImpl {
     /// The amount of flow control credits which had been acquired and where the
     /// data had already been consumed by the application
     pub(super) consumed_window: VarInt,
+    /// The maximum window the connection and stream windows are allowed to grow to, if
+    /// auto-tuning is enabled
+    pub(super) max_auto_tuned_window: Option<u32>,
+    /// Grows the connection window if auto-tuning is enabled
+    pub(super) tuner: Option<ReceiveWindowTuner>,
+    /// The latest smoothed RTT of the connection
+    pub(super) smoothed_rtt: Duration,
 }
 
 impl IncomingConnectionFlowControllerImpl {
@@ -59,6 +67,9 @@ impl IncomingConnectionFlowControllerImpl {
             desired_flow_control_window,
             acquired_window: VarInt::from_u32(0),
             consumed_window: VarInt::from_u32(0),
+            max_auto_tuned_window: None,
+            tuner: None,
+            smoothed_rtt: Duration::ZERO,
         }
     }
 
@@ -107,6 +118,21 @@ impl IncomingConnectionFlowControllerImpl {
 
     #[inline]
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
+        if let Some(tuner) = self.tuner.as_mut() {
+            if let Some(window) = tuner.on_transmit(
+                self.consumed_window,
+                self.desired_flow_control_window,
+                context.current_time(),
+                self.smoothed_rtt,
+            ) {
+                self.desired_flow_control_window = window;
+                self.read_window_sync.update_latest_value(
+                    self.consumed_window
+                        .saturating_add(VarInt::from_u32(self.desired_flow_control_window)),
+                );
+            }
+        }
+
         // Stream ID does not matter here, since it does not get transmitted
         self.read_window_sync
             .on_transmit(StreamId::from_varint(VarInt::from_u32(0)), context)
@@ -143,6 +169,28 @@ impl IncomingConnectionFlowController {
         }
     }
 
+    /// Enables auto-tuning of the connection and stream windows, up to `max_window` bytes
+    pub fn enable_auto_tuning(&mut self, max_window: u32) {
+        let mut inner = self.inner.borrow_mut();
+        inner.max_auto_tuned_window = Some(max_window);
+        inner.tuner = Some(ReceiveWindowTuner::new(max_window));
+    }
+
+    /// Returns the maximum window streams are allowed to grow to, if auto-tuning is enabled
+    pub fn max_auto_tuned_window(&self) -> Option<u32> {
+        self.inner.borrow().max_auto_tuned_window
+    }
+
+    /// Updates the smoothed RTT used to auto-tune the windows
+    pub fn update_smoothed_rtt(&mut self, smoothed_rtt: Duration) {
+        self.inner.borrow_mut().smoothed_rtt = smoothed_rtt;
+    }
+
+    /// Returns the latest smoothed RTT of the connection
+    pub fn smoothed_rtt(&self) -> Duration {
+        self.inner.borrow().smoothed_rtt
+    }
+
     /// Acquires a part of the window from the `IncomingConnectionFlowController` in
     /// in order to be able to use it for receiving data. `desired` is the window
     /// size that is intended to be borrowed.
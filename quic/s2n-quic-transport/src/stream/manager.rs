@@ -190,6 +190,17 @@ pub struct StreamManagerState<S> {
     stream_limits: stream::Limits,
 }
 
+/// Describes how the streams are closed along with the manager
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CloseMode {
+    /// All streams are reset
+    Reset,
+    /// Send streams deliver any data that was already finished; all other streams are reset
+    Flush,
+    /// Send streams are finished and deliver any pending data; receive streams are reset
+    Finish,
+}
+
 impl<S: StreamTrait> StreamManagerState<S> {
     /// Performs the given transaction on the `StreamManagerState`.
     /// If an error occurs, all Streams will be reset with an internal reset.
@@ -199,7 +210,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
     {
         let result = func(self);
         if let Err(err) = result.as_ref() {
-            self.close((*err).into(), false);
+            self.close((*err).into(), CloseMode::Reset);
         }
         result
     }
@@ -248,6 +259,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
             desired_flow_control_window: initial_receive_window.as_u64() as u32,
             initial_send_window,
             max_send_buffer_size: self.stream_limits.max_send_buffer_size.as_u32(),
+            max_reorder_buffer_size: self.stream_limits.max_reorder_buffer_size,
         }));
     }
 
@@ -362,7 +374,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
         Poll::Ready(Ok(first_unopened_id))
     }
 
-    fn close(&mut self, error: connection::Error, flush: bool) {
+    fn close(&mut self, error: connection::Error, mode: CloseMode) {
         if self.close_reason.is_some() {
             return;
         }
@@ -373,10 +385,10 @@ impl<S: StreamTrait> StreamManagerState<S> {
                 // We have to wake inside the lock, since `StreamEvent`s has no capacity
                 // to carry wakers in another iteration
                 let mut events = StreamEvents::new();
-                if flush {
-                    stream.on_flush(error.into(), &mut events);
-                } else {
-                    stream.on_internal_reset(error.into(), &mut events);
+                match mode {
+                    CloseMode::Reset => stream.on_internal_reset(error.into(), &mut events),
+                    CloseMode::Flush => stream.on_flush(error.into(), &mut events),
+                    CloseMode::Finish => stream.on_finish(error.into(), &mut events),
                 }
                 events.wake_all();
             });
@@ -402,8 +414,8 @@ impl<S: StreamTrait> StreamManagerState<S> {
         self.stream_controller.close();
     }
 
-    fn flush(&mut self, error: connection::Error) -> Poll<()> {
-        self.close(error, true);
+    fn flush(&mut self, error: connection::Error, mode: CloseMode) -> Poll<()> {
+        self.close(error, mode);
 
         // if we still have active streams, we're not done flushing
         if self.streams.nr_active_streams() > 0 {
@@ -526,20 +538,28 @@ impl<S: 'static + StreamTrait> AbstractStreamManager<S> {
         F: FnOnce(&mut S) -> R,
     {
         let had_transmission_interest = self.inner.streams.has_transmission_interest();
+        let mut timers_changed = false;
 
         let result = self
             .inner
             .streams
             .with_stream(stream_id, &mut self.inner.stream_controller, |stream| {
-                func(stream)
+                let expiration = stream.next_expiration();
+                let result = func(stream);
+                timers_changed = stream.next_expiration() != expiration;
+                result
             })
             .unwrap_or(unknown_stream_result);
 
         // A wakeup is only triggered if the the transmission list is
         // now empty, but was previously not. The edge triggered behavior
         // minimizes the amount of necessary wakeups.
-        let require_wakeup =
-            !had_transmission_interest && self.inner.streams.has_transmission_interest();
+        //
+        // The connection also needs to update its timer if the call armed or changed one
+        // of the stream's timers.
+        let require_wakeup = (!had_transmission_interest
+            && self.inner.streams.has_transmission_interest())
+            || timers_changed;
 
         // TODO: This currently wakes the connection task while inside the connection Mutex.
         // It will be better if we return the `Waker` instead and perform the wakeup afterwards.
@@ -549,6 +569,19 @@ impl<S: 'static + StreamTrait> AbstractStreamManager<S> {
 
         result
     }
+
+    /// Notifies all of the `Stream`s with a send deadline of the current time
+    fn on_send_deadline(&mut self, now: Timestamp) {
+        self.inner.streams.iterate_send_deadline_list(
+            &mut self.inner.stream_controller,
+            |stream| {
+                let mut events = StreamEvents::new();
+                stream.on_send_deadline(now, &mut events);
+                events.wake_all();
+                StreamContainerIterationResult::Continue
+            },
+        );
+    }
 }
 
 impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
@@ -569,12 +602,17 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
             "Receive window must not exceed 32bit range"
         );
 
+        let mut incoming_connection_flow_controller = IncomingConnectionFlowController::new(
+            initial_local_limits.max_data,
+            initial_local_limits.max_data.as_u64() as u32,
+        );
+        if let Some(max_window) = connection_limits.max_receive_window() {
+            incoming_connection_flow_controller.enable_auto_tuning(max_window);
+        }
+
         Self {
             inner: StreamManagerState {
-                incoming_connection_flow_controller: IncomingConnectionFlowController::new(
-                    initial_local_limits.max_data,
-                    initial_local_limits.max_data.as_u64() as u32,
-                ),
+                incoming_connection_flow_controller,
                 outgoing_connection_flow_controller: OutgoingConnectionFlowController::new(
                     initial_peer_limits.max_data,
                 ),
@@ -731,6 +769,10 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
     }
 
     fn on_rtt_update(&mut self, rtt_estimator: &RttEstimator) {
+        self.inner
+            .incoming_connection_flow_controller
+            .update_smoothed_rtt(rtt_estimator.smoothed_rtt());
+
         let blocked_sync_period = self.blocked_sync_period(rtt_estimator);
 
         {
@@ -779,10 +821,16 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
                 StreamContainerIterationResult::Continue
             },
         );
+        self.on_send_deadline(now);
+    }
+
+    fn on_wakeup(&mut self, now: Timestamp) {
+        // timestamp any data written with a send deadline
+        self.on_send_deadline(now);
     }
 
     fn close(&mut self, error: connection::Error) {
-        self.inner.close(error, false);
+        self.inner.close(error, CloseMode::Reset);
     }
 
     fn close_reason(&self) -> Option<connection::Error> {
@@ -790,7 +838,11 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
     }
 
     fn flush(&mut self, error: connection::Error) -> Poll<()> {
-        self.inner.flush(error)
+        self.inner.flush(error, CloseMode::Flush)
+    }
+
+    fn finish_and_flush(&mut self, error: connection::Error) -> Poll<()> {
+        self.inner.flush(error, CloseMode::Finish)
     }
 
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
@@ -951,11 +1003,23 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
         request: &mut ops::Request,
         context: Option<&Context>,
     ) -> Result<ops::Response, StreamError> {
+        let now = api_call_context.timestamp();
         self.perform_api_call(
             stream_id,
             Err(StreamError::invalid_stream()),
             api_call_context,
-            |stream| stream.poll_request(request, context),
+            |stream| {
+                let result = stream.poll_request(request, context);
+
+                // timestamp any data written with a send deadline at the time of the call
+                if let Some(now) = now {
+                    let mut events = StreamEvents::new();
+                    stream.on_send_deadline(now, &mut events);
+                    events.wake_all();
+                }
+
+                result
+            },
         )
     }
 
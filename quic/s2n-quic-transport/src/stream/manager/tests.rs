@@ -69,6 +69,7 @@ struct MockStream {
     on_packet_loss_count: usize,
     update_blocked_sync_period_count: usize,
     on_timeout_count: usize,
+    on_send_deadline_count: usize,
     on_internal_reset_count: usize,
     on_transmit_try_write_frames: usize,
     on_transmit_count: usize,
@@ -140,6 +141,7 @@ impl StreamTrait for MockStream {
             on_packet_loss_count: 0,
             update_blocked_sync_period_count: 0,
             on_timeout_count: 0,
+            on_send_deadline_count: 0,
             on_internal_reset_count: 0,
             on_data_count: 0,
             on_reset_count: 0,
@@ -273,6 +275,16 @@ impl StreamTrait for MockStream {
         self.on_internal_reset(error, events);
     }
 
+    fn on_finish(&mut self, error: StreamError, events: &mut StreamEvents) {
+        // for testing purposes, it's the same as a reset
+        self.on_internal_reset(error, events);
+    }
+
+    fn on_send_deadline(&mut self, _now: Timestamp, events: &mut StreamEvents) {
+        self.on_send_deadline_count += 1;
+        self.store_wakers(events);
+    }
+
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
         self.on_transmit_count += 1;
         let count = self
@@ -3109,3 +3121,62 @@ fn stream_transmission_fairness_test() {
         }
     }
 }
+
+#[test]
+fn stream_transmission_priority_test() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_2 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_3 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_4 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    for stream_id in [stream_1, stream_2, stream_3, stream_4] {
+        manager.with_asserted_stream(stream_id, |stream| {
+            stream.on_transmit_try_write_frames = 100;
+            stream.on_transmit_limit = Some(1);
+        });
+    }
+    assert_eq!(
+        [stream_1, stream_2, stream_3, stream_4],
+        *manager.streams_waiting_for_transmission()
+    );
+
+    // Higher priority streams move ahead of lower priority streams
+    manager.with_asserted_stream(stream_3, |stream| stream.interests.priority = 2);
+    manager.with_asserted_stream(stream_2, |stream| stream.interests.priority = 1);
+    manager.with_asserted_stream(stream_4, |stream| stream.interests.priority = 2);
+    assert_eq!(
+        [stream_3, stream_4, stream_2, stream_1],
+        *manager.streams_waiting_for_transmission()
+    );
+
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let mut write_context = MockWriteContext::new(
+        time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+
+    // Streams with the same priority take turns, but always go before lower priority streams
+    for expected in [
+        [stream_3, stream_4, stream_2, stream_1],
+        [stream_4, stream_3, stream_2, stream_1],
+        [stream_3, stream_4, stream_2, stream_1],
+    ] {
+        let _ = manager.on_transmit(&mut write_context);
+        write_context.frame_buffer.flush();
+        write_context.frame_buffer.set_error_write_after_n_frames(1);
+
+        assert_eq!(expected, *manager.streams_waiting_for_transmission());
+    }
+
+    // Lowering the priority moves the stream behind the streams with its new priority
+    manager.with_asserted_stream(stream_3, |stream| stream.interests.priority = 0);
+    assert_eq!(
+        [stream_4, stream_2, stream_1, stream_3],
+        *manager.streams_waiting_for_transmission()
+    );
+}
@@ -70,6 +70,9 @@ pub trait Manager:
     /// Called when the connection timer expires
     fn on_timeout(&mut self, now: Timestamp);
 
+    /// Called when the connection is woken up by the application
+    fn on_wakeup(&mut self, now: Timestamp);
+
     /// Closes the manager and resets all streams with the
     /// given error. The current implementation will still
     /// allow to forward frames to the contained Streams as well as to query them
@@ -86,6 +89,12 @@ pub trait Manager:
     /// transmit.
     fn flush(&mut self, error: connection::Error) -> Poll<()>;
 
+    /// Closes the manager, finishes and flushes all send streams and resets all receive streams.
+    ///
+    /// This is used for when the application gracefully closes the connection and expects all
+    /// of the pending data to be delivered to the peer.
+    fn finish_and_flush(&mut self, error: connection::Error) -> Poll<()>;
+
     /// Queries the component for any outgoing frames that need to get sent
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError>;
 
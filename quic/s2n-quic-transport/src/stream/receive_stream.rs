@@ -5,6 +5,7 @@ use crate::{
     contexts::{OnTransmitError, WriteContext},
     stream::{
         incoming_connection_flow_controller::IncomingConnectionFlowController,
+        receive_window_tuner::ReceiveWindowTuner,
         stream_events::StreamEvents,
         stream_interests::{StreamInterestProvider, StreamInterests},
         StreamError,
@@ -24,6 +25,7 @@ use s2n_quic_core::{
     frame::{stream::StreamRef, MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     packet::number::PacketNumber,
     stream::{ops, StreamId},
+    time::Timestamp,
     transport,
     varint::VarInt,
 };
@@ -183,6 +185,8 @@ pub(super) struct ReceiveStreamFlowController {
     pub(super) acquired_connection_window: VarInt,
     /// The amount of credits which had been released in total
     pub(super) released_connection_window: VarInt,
+    /// Grows the stream window if auto-tuning is enabled
+    pub(super) tuner: Option<ReceiveWindowTuner>,
 }
 
 impl ReceiveStreamFlowController {
@@ -191,8 +195,13 @@ impl ReceiveStreamFlowController {
         initial_window: VarInt,
         desired_flow_control_window: u32,
     ) -> Self {
+        let tuner = connection_flow_controller
+            .max_auto_tuned_window()
+            .map(ReceiveWindowTuner::new);
+
         Self {
             connection_flow_controller,
+            tuner,
             read_window_sync: IncrementalValueSync::new(
                 VarInt::from_u32(desired_flow_control_window),
                 initial_window,
@@ -318,6 +327,26 @@ impl ReceiveStreamFlowController {
         usize::try_from(watermark).unwrap_or(core::usize::MAX)
     }
 
+    /// Grows the desired window if auto-tuning is enabled and the peer is consuming the
+    /// window quickly
+    fn tune_window(&mut self, now: Timestamp) {
+        let smoothed_rtt = self.connection_flow_controller.smoothed_rtt();
+        if let Some(tuner) = self.tuner.as_mut() {
+            if let Some(window) = tuner.on_transmit(
+                self.released_connection_window,
+                self.desired_flow_control_window,
+                now,
+                smoothed_rtt,
+            ) {
+                self.desired_flow_control_window = window;
+                self.read_window_sync.update_latest_value(
+                    self.released_connection_window
+                        .saturating_add(VarInt::from_u32(self.desired_flow_control_window)),
+                );
+            }
+        }
+    }
+
     /// Returns the MAX_STREAM_DATA window that is currently synchronized
     /// towards the peer.
     #[cfg(test)]
@@ -349,6 +378,8 @@ pub struct ReceiveStream {
     final_state_observed: bool,
     /// Marks the stream as detached from the application
     detached: bool,
+    /// The maximum distance past the first missing byte at which data is buffered
+    max_reorder_buffer_size: Option<u64>,
 }
 
 impl ReceiveStream {
@@ -357,6 +388,7 @@ impl ReceiveStream {
         connection_flow_controller: IncomingConnectionFlowController,
         initial_window: VarInt,
         desired_flow_control_window: u32,
+        max_reorder_buffer_size: Option<u64>,
     ) -> ReceiveStream {
         // If the stream is created in closed state directly move into the
         // terminal state.
@@ -378,6 +410,7 @@ impl ReceiveStream {
             read_waiter: None,
             final_state_observed: is_closed,
             detached: is_closed,
+            max_reorder_buffer_size,
         };
 
         if is_closed {
@@ -445,6 +478,21 @@ impl ReceiveStream {
                         .acquire_window_up_to(data_end, frame.tag().into())?;
                 }
 
+                // Bound the amount of data that can't be delivered to the application until
+                // the missing data before it is received
+                if let Some(max_reorder_buffer_size) = self.max_reorder_buffer_size {
+                    let contiguous_end =
+                        self.receive_buffer.consumed_len() + self.receive_buffer.len() as u64;
+
+                    if frame.offset.as_u64() > contiguous_end
+                        && data_end.as_u64() - contiguous_end > max_reorder_buffer_size
+                    {
+                        return Err(transport::Error::FLOW_CONTROL_ERROR
+                            .with_reason("stream reorder buffer exceeded")
+                            .with_frame_type(frame.tag().into()));
+                    }
+                }
+
                 // If this is the last frame then inform the receive_buffer so it can check for any
                 // final size errors.
                 let write_result = if frame.is_fin {
@@ -571,6 +619,19 @@ impl ReceiveStream {
         self.wake(events);
     }
 
+    /// Closes the stream when the application gracefully closes the connection
+    ///
+    /// Any data that has not been read by the application is discarded.
+    pub fn on_finish(&mut self, error: StreamError, events: &mut StreamEvents) {
+        self.on_internal_reset(error, events);
+
+        // the stream no longer depends on the application to be finalized
+        self.detached = true;
+        if !matches!(self.state, ReceiveStreamState::Stopping { .. }) {
+            self.final_state_observed = true;
+        }
+    }
+
     /// This is called when a `RESET_STREAM` frame had been received for
     /// this stream
     pub fn on_reset(
@@ -585,7 +646,10 @@ impl ReceiveStream {
         //# error code in any RESET_STREAM frames subsequently received for that
         //# stream.
 
-        let error = StreamError::stream_reset(frame.application_error_code.into());
+        let error = StreamError::stream_reset_with_final_size(
+            frame.application_error_code.into(),
+            frame.final_size.as_u64(),
+        );
         self.init_reset(error, Some(frame.final_size), Some(frame.tag()))?;
 
         // We don't have to send `STOP_SENDING` anymore since the stream was reset by the peer
@@ -711,6 +775,7 @@ impl ReceiveStream {
         //# To avoid blocking a sender, a receiver MAY send a MAX_STREAM_DATA or
         //# MAX_DATA frame multiple times within a round trip or send it early
         //# enough to allow time for loss of the frame and subsequent recovery.
+        self.flow_controller.tune_window(context.current_time());
         self.flow_controller
             .read_window_sync
             .on_transmit(stream_id, context)
@@ -702,6 +702,46 @@ fn exceed_connection_flow_control_window() {
     test_env.assert_pop_error();
 }
 
+#[test]
+fn exceed_reorder_buffer_size() {
+    let mut test_env_config: TestEnvironmentConfig =
+        TestEnvironmentConfig::new(endpoint::Type::Server);
+    test_env_config.stream_id = StreamId::initial(
+        test_env_config.local_endpoint_type.peer_type(),
+        StreamType::Unidirectional,
+    );
+    test_env_config.max_reorder_buffer_size = Some(1000);
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    test_env.feed_data(VarInt::from_u32(0), 100);
+
+    // Data past a gap is accepted as long as it ends within the limit
+    test_env.feed_data(VarInt::from_u32(500), 600);
+
+    // Filling the gap moves the limit forward
+    test_env.feed_data(VarInt::from_u32(100), 400);
+    test_env.feed_data(VarInt::from_u32(1500), 600);
+
+    // Data that ends too far past the first missing byte errors
+    let mut events = StreamEvents::new();
+    assert_is_transport_error(
+        test_env.stream.on_data(
+            &stream_data(
+                test_env.stream.stream_id,
+                VarInt::from_u32(2500),
+                &[1],
+                false,
+            ),
+            &mut events,
+        ),
+        TransportError::FLOW_CONTROL_ERROR,
+    );
+
+    // Data that doesn't leave a gap is not limited
+    test_env.feed_data(VarInt::from_u32(1100), 1000);
+    test_env.feed_data(VarInt::from_u32(2100), 1500);
+}
+
 #[test]
 fn receiving_data_will_lead_to_a_stream_flow_control_window_update() {
     let mut test_env = setup_receive_only_test_env();
@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Grows a receive window based on how quickly the peer consumes it
+
+use core::time::Duration;
+use s2n_quic_core::{time::Timestamp, varint::VarInt};
+
+/// Doubles a receive window if the peer consumes the entire window in less than two round trips
+///
+/// If the window is fully consumed that quickly the peer was most likely blocked on flow control
+/// and could make use of a larger window.
+#[derive(Debug)]
+pub(super) struct ReceiveWindowTuner {
+    /// The maximum window the tuner is allowed to grow to
+    max_window: u32,
+    /// The time and consumed offset at which the current measurement started
+    epoch: Option<(Timestamp, VarInt)>,
+}
+
+impl ReceiveWindowTuner {
+    pub fn new(max_window: u32) -> Self {
+        Self {
+            max_window,
+            epoch: None,
+        }
+    }
+
+    /// Called with the amount of data consumed so far before transmitting a window update
+    ///
+    /// Returns the new desired window if the window should be increased
+    pub fn on_transmit(
+        &mut self,
+        consumed: VarInt,
+        window: u32,
+        now: Timestamp,
+        rtt: Duration,
+    ) -> Option<u32> {
+        let (start_time, start_offset) = *self.epoch.get_or_insert((now, consumed));
+
+        if consumed - start_offset < VarInt::from_u32(window) {
+            return None;
+        }
+
+        // start a new measurement for the next window
+        self.epoch = Some((now, consumed));
+
+        if window >= self.max_window || now.saturating_duration_since(start_time) >= rtt * 2 {
+            return None;
+        }
+
+        Some(window.saturating_mul(2).min(self.max_window))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::clock::testing as time;
+
+    const RTT: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn grows_when_consumed_quickly() {
+        let mut tuner = ReceiveWindowTuner::new(4000);
+        let now = time::now();
+
+        assert_eq!(tuner.on_transmit(VarInt::from_u32(0), 1000, now, RTT), None);
+        // the window hasn't been fully consumed yet
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(500), 1000, now + RTT, RTT),
+            None
+        );
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(1000), 1000, now + RTT, RTT),
+            Some(2000)
+        );
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(3000), 2000, now + RTT * 2, RTT),
+            Some(4000)
+        );
+        // the window is capped at the maximum
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(7000), 4000, now + RTT * 3, RTT),
+            None
+        );
+    }
+
+    #[test]
+    fn does_not_grow_when_consumed_slowly() {
+        let mut tuner = ReceiveWindowTuner::new(4000);
+        let now = time::now();
+
+        assert_eq!(tuner.on_transmit(VarInt::from_u32(0), 1000, now, RTT), None);
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(1000), 1000, now + RTT * 2, RTT),
+            None
+        );
+        // a new measurement was started when the previous window was consumed
+        assert_eq!(
+            tuner.on_transmit(VarInt::from_u32(2000), 1000, now + RTT * 3, RTT),
+            Some(2000)
+        );
+    }
+}
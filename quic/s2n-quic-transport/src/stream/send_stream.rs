@@ -16,6 +16,7 @@ use crate::{
     transmission,
     transmission::interest::Provider as _,
 };
+use alloc::collections::VecDeque;
 use bytes::Bytes;
 use core::{
     convert::TryFrom,
@@ -27,7 +28,7 @@ use s2n_quic_core::{
     frame::{MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     packet::number::PacketNumber,
     stream::{ops, StreamId},
-    time::{timer, Timestamp},
+    time::{timer, Timer, Timestamp},
     transport,
     varint::VarInt,
 };
@@ -132,6 +133,9 @@ enum ResetSource {
     /// The reset had been initiated as an internal reset. Likely caused by a
     /// connection error or termination.
     InternalReset,
+    /// The reset had been initiated because written data wasn't acknowledged
+    /// before the send deadline expired
+    SendDeadline,
 }
 
 impl ResetSource {
@@ -438,6 +442,89 @@ impl ValueToFrameWriter<VarInt> for StreamDataBlockedToFrameWriter {
     }
 }
 
+/// Tracks written data which needs to be acknowledged before the send deadline
+#[derive(Debug)]
+struct SendDeadline {
+    /// The amount of time written data has to be acknowledged by the peer
+    deadline: Duration,
+    /// The error used to reset the stream if the deadline expires
+    error: application::Error,
+    /// The end offsets of writes which are waiting to be acknowledged along with the
+    /// time they were written
+    ///
+    /// Writes are timestamped by the stream manager right after the write call. If the endpoint
+    /// wasn't given a clock, they are timestamped the next time the connection is woken up.
+    writes: VecDeque<(VarInt, Option<Timestamp>)>,
+    /// Expires once the oldest unacknowledged write exceeds the deadline
+    timer: Timer,
+}
+
+impl SendDeadline {
+    fn new(deadline: Duration, error: application::Error) -> Self {
+        Self {
+            deadline,
+            error,
+            writes: VecDeque::new(),
+            timer: Timer::default(),
+        }
+    }
+
+    /// Updates the deadline and error for the stream
+    fn update(&mut self, deadline: Duration, error: application::Error) {
+        self.deadline = deadline;
+        self.error = error;
+        self.arm();
+    }
+
+    /// Records that data up to `end_offset` has been written
+    fn on_write(&mut self, end_offset: VarInt) {
+        match self.writes.back_mut() {
+            // writes that haven't been timestamped yet share the same deadline
+            Some((offset, None)) => *offset = end_offset,
+            _ => self.writes.push_back((end_offset, None)),
+        }
+    }
+
+    /// Timestamps any writes since the last wakeup
+    fn on_wakeup(&mut self, now: Timestamp) {
+        if let Some((_, timestamp @ None)) = self.writes.back_mut() {
+            *timestamp = Some(now);
+            self.arm();
+        }
+    }
+
+    /// Removes all of the writes which have been acknowledged by the peer
+    fn on_acknowledged(&mut self, acknowledged: VarInt) {
+        let len = self.writes.len();
+        while matches!(self.writes.front(), Some((offset, _)) if *offset <= acknowledged) {
+            self.writes.pop_front();
+        }
+
+        if self.writes.len() != len {
+            self.arm();
+        }
+    }
+
+    /// Clears all of the pending writes
+    fn clear(&mut self) {
+        self.writes.clear();
+        self.timer.cancel();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Arms the timer for the oldest timestamped write
+    fn arm(&mut self) {
+        if let Some((_, Some(timestamp))) = self.writes.front() {
+            self.timer.set(*timestamp + self.deadline);
+        } else {
+            self.timer.cancel();
+        }
+    }
+}
+
 /// The sending half of a stream
 #[derive(Debug)]
 pub struct SendStream {
@@ -457,6 +544,10 @@ pub struct SendStream {
     final_state_observed: bool,
     /// Marks the stream as detached from the application
     detached: bool,
+    /// The priority of the stream when scheduling transmissions
+    priority: u8,
+    /// The deadline for written data to be acknowledged, if set by the application
+    send_deadline: Option<SendDeadline>,
 }
 
 impl SendStream {
@@ -485,6 +576,8 @@ impl SendStream {
             write_waiter: None,
             final_state_observed: is_closed,
             detached: is_closed,
+            priority: 0,
+            send_deadline: None,
         };
 
         if is_closed {
@@ -580,6 +673,9 @@ impl SendStream {
     /// This method gets called when a packet delivery got acknowledged
     pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A, events: &mut StreamEvents) {
         self.data_sender.on_packet_ack(ack_set);
+        if let Some(send_deadline) = self.send_deadline.as_mut() {
+            send_deadline.on_acknowledged(self.data_sender.total_acknowledged_len());
+        }
         self.data_sender
             .flow_controller_mut()
             .on_packet_ack(ack_set);
@@ -697,6 +793,46 @@ impl SendStream {
         }
     }
 
+    /// Finishes the stream and flushes any pending data
+    ///
+    /// This is called when the application gracefully closes the connection.
+    pub fn on_finish(&mut self, error: StreamError, events: &mut StreamEvents) {
+        if matches!(self.state, SendStreamState::Sending)
+            && self.data_sender.state() == data_sender::State::Sending
+        {
+            self.data_sender.finish();
+
+            // wake up any blocked writers so they observe the stream is no longer writable
+            self.wake(events);
+        }
+
+        // the stream no longer depends on the application to be finalized
+        self.detached = true;
+
+        self.on_flush(error, events);
+    }
+
+    /// Timestamps any data written since the last call and resets the stream if written
+    /// data hasn't been acknowledged before the send deadline
+    pub fn on_send_deadline(&mut self, now: Timestamp, events: &mut StreamEvents) {
+        let send_deadline = if let Some(send_deadline) = self.send_deadline.as_mut() {
+            send_deadline
+        } else {
+            return;
+        };
+
+        send_deadline.on_wakeup(now);
+
+        if send_deadline.timer.poll_expiration(now).is_ready() {
+            let error = StreamError::stream_reset(send_deadline.error);
+            if self.init_reset(ResetSource::SendDeadline, error) == InitResetResult::ResetInitiated
+            {
+                // wake up the application so the error is observed on the next call
+                self.wake(events);
+            }
+        }
+    }
+
     /// This method is called when a connection window is available
     pub fn on_connection_window_available(&mut self) {
         // Outstanding flow control requests are only fulfilled if the Stream
@@ -755,6 +891,18 @@ impl SendStream {
             self.detach();
         }
 
+        if let Some(priority) = request.priority {
+            self.priority = priority;
+        }
+
+        if let Some((deadline, error)) = request.send_deadline {
+            if let Some(send_deadline) = self.send_deadline.as_mut() {
+                send_deadline.update(deadline, error);
+            } else {
+                self.send_deadline = Some(SendDeadline::new(deadline, error));
+            }
+        }
+
         macro_rules! store_waker {
             ($should_flush:expr) => {
                 // Store the waker, in order to be able to wakeup the caller
@@ -834,6 +982,10 @@ impl SendStream {
 
                 self.data_sender
                     .push(core::mem::replace(chunk, Bytes::new()));
+
+                if let Some(send_deadline) = self.send_deadline.as_mut() {
+                    send_deadline.on_write(self.data_sender.total_enqueued_len());
+                }
             }
         } else if !request.finish && !request.flush && context.is_some() {
             // if `chunks` are `None` or `Some(&[])` and we're not ending or flushing the stream,
@@ -985,6 +1137,11 @@ impl SendStream {
         // to send or resend the remaining data.
         self.data_sender.stop_sending(error);
 
+        // The remaining data no longer needs to be acknowledged
+        if let Some(send_deadline) = self.send_deadline.as_mut() {
+            send_deadline.clear();
+        }
+
         // For an internal reset (which provides no error_code) we do not need
         // to transmit the reset frame
         match (reason.is_internal(), error) {
@@ -1021,6 +1178,9 @@ impl timer::Provider for SendStream {
     #[inline]
     fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
         self.data_sender.flow_controller().timers(query)?;
+        if let Some(send_deadline) = self.send_deadline.as_ref() {
+            send_deadline.timer.timers(query)?;
+        }
         Ok(())
     }
 }
@@ -1028,6 +1188,8 @@ impl timer::Provider for SendStream {
 impl StreamInterestProvider for SendStream {
     #[inline]
     fn stream_interests(&self, interests: &mut StreamInterests) {
+        interests.priority = self.priority;
+
         match self.state {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-3.3
             //# A sender MUST NOT send any of these frames from a terminal state
@@ -1060,6 +1222,11 @@ impl StreamInterestProvider for SendStream {
         // let the stream container know we still have work to do
         interests.retained = true;
 
+        interests.send_deadline = self
+            .send_deadline
+            .as_ref()
+            .map_or(false, |send_deadline| !send_deadline.is_empty());
+
         // Check whether the flow controller reports being blocked on the
         // connection flow control window or the stream flow control window
         match self.data_sender.flow_controller().state() {
@@ -13,7 +13,10 @@ use crate::{
     transmission,
 };
 use alloc::rc::Rc;
-use core::{cell::RefCell, ops::Deref};
+use core::{
+    cell::{Cell, RefCell},
+    ops::Deref,
+};
 use intrusive_collections::{
     intrusive_adapter, KeyAdapter, LinkedList, LinkedListLink, RBTree, RBTreeLink,
 };
@@ -53,6 +56,12 @@ intrusive_adapter!(WaitingForStreamFlowControlCreditsAdapter<S> = Rc<StreamNode<
     waiting_for_stream_flow_control_credits_link: LinkedListLink
 });
 
+// Intrusive list adapter for managing the list of
+// `waiting_for_send_deadline` streams
+intrusive_adapter!(WaitingForSendDeadlineAdapter<S> = Rc<StreamNode<S>>: StreamNode<S> {
+    waiting_for_send_deadline_link: LinkedListLink
+});
+
 // Intrusive red black tree adapter for managing all streams in a tree for
 // lookup by Stream ID
 intrusive_adapter!(StreamTreeAdapter<S> = Rc<StreamNode<S>>: StreamNode<S> {
@@ -79,6 +88,13 @@ struct StreamNode<S> {
     waiting_for_connection_flow_control_credits_link: LinkedListLink,
     /// Allows the Stream to be part of the `waiting_for_stream_flow_control_credits` collection
     waiting_for_stream_flow_control_credits_link: LinkedListLink,
+    /// Allows the Stream to be part of the `waiting_for_send_deadline` collection
+    waiting_for_send_deadline_link: LinkedListLink,
+    /// The transmission priority last reported by the Stream
+    ///
+    /// This is stored outside of `inner` so the transmission lists can be ordered
+    /// while other Streams are borrowed.
+    priority: Cell<u8>,
 }
 
 impl<S> StreamNode<S> {
@@ -93,6 +109,8 @@ impl<S> StreamNode<S> {
             waiting_for_retransmission_link: LinkedListLink::new(),
             waiting_for_connection_flow_control_credits_link: LinkedListLink::new(),
             waiting_for_stream_flow_control_credits_link: LinkedListLink::new(),
+            waiting_for_send_deadline_link: LinkedListLink::new(),
+            priority: Cell::new(0),
         }
     }
 }
@@ -123,6 +141,42 @@ unsafe fn stream_node_rc_from_ref<S>(stream_node: &StreamNode<S>) -> Rc<StreamNo
     temp_node_ptr.deref().clone()
 }
 
+/// Inserts a node behind all nodes in the list with the same or a higher priority
+macro_rules! insert_by_priority {
+    ($list:expr, $node:expr) => {{
+        let priority = $node.priority.get();
+        let mut cursor = $list.back_mut();
+        while cursor.get().map_or(false, |n| n.priority.get() < priority) {
+            cursor.move_prev();
+        }
+        cursor.insert_after($node.clone());
+    }};
+}
+
+/// Restores the priority order of a transmission list after it was spliced
+///
+/// The sort is stable, so Streams with the same priority keep their relative order.
+macro_rules! sort_by_priority {
+    ($list:expr) => {{
+        let mut is_sorted = true;
+        let mut cursor = $list.front();
+        while let (Some(current), Some(next)) = (cursor.get(), cursor.peek_next().get()) {
+            if current.priority.get() < next.priority.get() {
+                is_sorted = false;
+                break;
+            }
+            cursor.move_next();
+        }
+
+        if !is_sorted {
+            let mut unsorted = $list.take();
+            while let Some(node) = unsorted.pop_front() {
+                insert_by_priority!($list, node);
+            }
+        }
+    }};
+}
+
 /// Contains all secondary lists of Streams.
 ///
 /// A Stream can be a member in any of those, in addition to being a member of
@@ -145,6 +199,9 @@ struct InterestLists<S> {
     /// stream flow control window to increase
     waiting_for_stream_flow_control_credits:
         LinkedList<WaitingForStreamFlowControlCreditsAdapter<S>>,
+    /// Streams which have written data that needs to be acknowledged before
+    /// the send deadline expires
+    waiting_for_send_deadline: LinkedList<WaitingForSendDeadlineAdapter<S>>,
 }
 
 impl<S: StreamTrait> InterestLists<S> {
@@ -160,6 +217,7 @@ impl<S: StreamTrait> InterestLists<S> {
             waiting_for_stream_flow_control_credits: LinkedList::new(
                 WaitingForStreamFlowControlCreditsAdapter::new(),
             ),
+            waiting_for_send_deadline: LinkedList::new(WaitingForSendDeadlineAdapter::new()),
         }
     }
 
@@ -206,12 +264,55 @@ impl<S: StreamTrait> InterestLists<S> {
             waiting_for_frame_delivery_link,
             waiting_for_frame_delivery
         );
-        sync_interests!(
+        let priority = interests.priority;
+        let priority_changed = node.priority.replace(priority) != priority;
+
+        // The transmission lists are ordered by priority, so that higher priority
+        // Streams get to transmit first. Streams with the same priority are kept in
+        // the same order as in the other lists.
+        macro_rules! sync_prioritized_interests {
+            ($interest:expr, $link_name:ident, $list_name:ident) => {
+                // Move the Stream to its new position if the priority changed
+                if priority_changed && node.$link_name.is_linked() {
+                    // Safety: We know that the node is only ever part of this list.
+                    let mut cursor = unsafe {
+                        self.$list_name
+                            .cursor_mut_from_ptr(node.deref() as *const StreamNode<S>)
+                    };
+                    cursor.remove();
+                }
+
+                if $interest != node.$link_name.is_linked() {
+                    if $interest {
+                        if matches!(result, StreamContainerIterationResult::Continue) {
+                            insert_by_priority!(self.$list_name, node);
+                        } else {
+                            // Insert in front of all Streams with the same priority
+                            let mut cursor = self.$list_name.front_mut();
+                            while cursor.get().map_or(false, |n| n.priority.get() > priority) {
+                                cursor.move_next();
+                            }
+                            cursor.insert_before(node.clone());
+                        }
+                    } else {
+                        // Safety: We know that the node is only ever part of this list.
+                        let mut cursor = unsafe {
+                            self.$list_name
+                                .cursor_mut_from_ptr(node.deref() as *const StreamNode<S>)
+                        };
+                        cursor.remove();
+                    }
+                }
+                debug_assert_eq!($interest, node.$link_name.is_linked());
+            };
+        }
+
+        sync_prioritized_interests!(
             matches!(interests.transmission, transmission::Interest::NewData),
             waiting_for_transmission_link,
             waiting_for_transmission
         );
-        sync_interests!(
+        sync_prioritized_interests!(
             matches!(interests.transmission, transmission::Interest::LostData),
             waiting_for_retransmission_link,
             waiting_for_retransmission
@@ -226,6 +327,11 @@ impl<S: StreamTrait> InterestLists<S> {
             waiting_for_stream_flow_control_credits_link,
             waiting_for_stream_flow_control_credits
         );
+        sync_interests!(
+            interests.send_deadline,
+            waiting_for_send_deadline_link,
+            waiting_for_send_deadline
+        );
 
         if !interests.retained != node.done_streams_link.is_linked() {
             if !interests.retained {
@@ -469,6 +575,7 @@ impl<S: StreamTrait> StreamContainer<S> {
                 waiting_for_stream_flow_control_credits,
                 waiting_for_stream_flow_control_credits_link
             );
+            remove_stream_from_list!(waiting_for_send_deadline, waiting_for_send_deadline_link);
 
             controller.on_close_stream(stream.inner.borrow().stream_id());
         }
@@ -537,6 +644,27 @@ impl<S: StreamTrait> StreamContainer<S> {
         );
     }
 
+    /// Iterates over all `Stream`s which are waiting for their written data to be
+    /// acknowledged before the send deadline, and executes the given function on each `Stream`
+    ///
+    /// The `stream::Controller` will be notified of streams that have been
+    /// closed to allow for further streams to be opened.
+    pub fn iterate_send_deadline_list<F>(
+        &mut self,
+        controller: &mut stream::Controller,
+        mut func: F,
+    ) where
+        F: FnMut(&mut S) -> StreamContainerIterationResult,
+    {
+        iterate_interruptible!(
+            self,
+            waiting_for_send_deadline,
+            waiting_for_send_deadline_link,
+            controller,
+            func
+        );
+    }
+
     /// Iterates over all `Stream`s which are waiting for transmission,
     /// and executes the given function on each `Stream`
     ///
@@ -553,6 +681,7 @@ impl<S: StreamTrait> StreamContainer<S> {
             controller,
             func
         );
+        sort_by_priority!(self.interest_lists.waiting_for_transmission);
     }
 
     /// Iterates over all `Stream`s which are waiting for retransmission,
@@ -574,6 +703,7 @@ impl<S: StreamTrait> StreamContainer<S> {
             controller,
             func
         );
+        sort_by_priority!(self.interest_lists.waiting_for_retransmission);
     }
 
     /// Iterates over all `Stream`s which are part of this container, and executes
@@ -633,6 +763,9 @@ impl<S: StreamTrait> timer::Provider for StreamContainer<S> {
         {
             stream.inner.borrow().timers(query)?;
         }
+        for stream in self.interest_lists.waiting_for_send_deadline.iter() {
+            stream.inner.borrow().timers(query)?;
+        }
         Ok(())
     }
 }
@@ -42,6 +42,8 @@ pub struct StreamConfig {
     pub initial_send_window: VarInt,
     /// The maximum buffered amount of data on the sending side
     pub max_send_buffer_size: u32,
+    /// The maximum amount of out-of-order data buffered on the receiving side
+    pub max_reorder_buffer_size: Option<u64>,
 }
 
 /// A trait which represents an internally used `Stream`
@@ -116,6 +118,17 @@ pub trait StreamTrait: StreamInterestProvider + timer::Provider + core::fmt::Deb
     /// The stream should finish any pending operations and close
     fn on_flush(&mut self, error: StreamError, events: &mut StreamEvents);
 
+    /// This method is called when the application gracefully closes the connection
+    ///
+    /// The stream should finish the sending side, transmit any pending data and close
+    fn on_finish(&mut self, error: StreamError, events: &mut StreamEvents);
+
+    /// This method is called when the connection is woken up or the send deadline timer expires
+    ///
+    /// The stream should timestamp any newly written data and reset if written data
+    /// wasn't acknowledged before the send deadline.
+    fn on_send_deadline(&mut self, now: Timestamp, events: &mut StreamEvents);
+
     /// Queries the component for any outgoing frames that need to get sent
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError>;
 
@@ -204,6 +217,7 @@ impl StreamTrait for StreamImpl {
                 config.incoming_connection_flow_controller,
                 config.initial_receive_window,
                 config.desired_flow_control_window,
+                config.max_reorder_buffer_size,
             ),
             has_send: !send_is_closed,
             send_stream: SendStream::new(
@@ -314,6 +328,20 @@ impl StreamTrait for StreamImpl {
         self.send_stream.on_flush(error, events);
     }
 
+    #[inline]
+    fn on_finish(&mut self, error: StreamError, events: &mut StreamEvents) {
+        // finishing a receive stream resets it and discards any unread data
+        self.receive_stream.on_finish(error, events);
+
+        // tell the send stream to finish and flush any pending data
+        self.send_stream.on_finish(error, events);
+    }
+
+    #[inline]
+    fn on_send_deadline(&mut self, now: Timestamp, events: &mut StreamEvents) {
+        self.send_stream.on_send_deadline(now, events)
+    }
+
     #[inline]
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
         // Query the receiving side for outgoing data
@@ -20,8 +20,14 @@ pub struct StreamInterests {
     /// Is `true` if the component is interested in packet acknowledge and
     /// loss information
     pub delivery_notifications: bool,
+    /// Is `true` if the `Stream` has written data which needs to be acknowledged
+    /// before the send deadline expires
+    pub send_deadline: bool,
     /// Transmission interest for the component
     pub transmission: Interest,
+    /// The priority of the `Stream` when scheduling transmissions. Streams with a
+    /// higher priority are transmitted before streams with a lower priority.
+    pub priority: u8,
 }
 
 impl StreamInterests {
@@ -31,7 +37,9 @@ impl StreamInterests {
         self.stream_flow_control_credits |= other.stream_flow_control_credits;
         self.retained |= other.retained;
         self.delivery_notifications |= other.delivery_notifications;
+        self.send_deadline |= other.send_deadline;
         let _ = self.transmission.on_interest(other.transmission);
+        self.priority = self.priority.max(other.priority);
     }
 
     #[inline]
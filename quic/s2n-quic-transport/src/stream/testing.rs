@@ -117,6 +117,8 @@ pub fn pn(nr: usize) -> PacketNumber {
 /// - tx => transmission::Interest::NewData
 /// - fin => finalization
 /// - cf => connection_flow_control_credits
+/// - sf => stream_flow_control_credits
+/// - sd => send_deadline
 pub fn stream_interests(interests: &[&str]) -> StreamInterests {
     let mut result = StreamInterests {
         retained: true,
@@ -130,6 +132,7 @@ pub fn stream_interests(interests: &[&str]) -> StreamInterests {
             "fin" => result.retained = false,
             "cf" => result.connection_flow_control_credits = true,
             "sf" => result.stream_flow_control_credits = true,
+            "sd" => result.send_deadline = true,
             other => unreachable!("Unsupported interest {}", other),
         }
     }
@@ -484,6 +487,7 @@ pub struct TestEnvironmentConfig {
     pub initial_connection_receive_window_size: u64,
     pub desired_connection_flow_control_window: u32,
     pub max_send_buffer_size: usize,
+    pub max_reorder_buffer_size: Option<u64>,
     pub transmission_constraint: transmission::Constraint,
     pub local_endpoint_type: endpoint::Type,
     pub max_packet_size: Option<usize>,
@@ -513,6 +517,7 @@ impl TestEnvironmentConfig {
             desired_connection_flow_control_window:
                 TestEnvironment::DEFAULT_INITIAL_CONNECTION_RECEIVE_WINDOW as u32,
             max_send_buffer_size: TestEnvironment::DEFAULT_MAX_SEND_BUFFER_SIZE,
+            max_reorder_buffer_size: None,
             transmission_constraint: transmission::Constraint::None,
             max_packet_size: None,
         }
@@ -545,6 +550,7 @@ pub fn setup_stream_test_env_with_config(config: TestEnvironmentConfig) -> TestE
         desired_flow_control_window: config.desired_flow_control_window,
         initial_send_window: VarInt::new(config.initial_send_window).unwrap(),
         max_send_buffer_size: config.max_send_buffer_size as u32,
+        max_reorder_buffer_size: config.max_reorder_buffer_size,
     });
 
     let (waker, wake_counter) = new_count_waker();
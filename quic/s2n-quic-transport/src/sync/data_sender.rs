@@ -196,6 +196,11 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         self.buffer.total_len()
     }
 
+    /// Returns the amount of bytes that have been acknowledged by the peer without any gaps
+    pub fn total_acknowledged_len(&self) -> VarInt {
+        self.buffer.total_len() - self.buffer.enqueued_len()
+    }
+
     /// Returns true if the data sender doesn't have any data enqueued for sending
     pub fn is_empty(&self) -> bool {
         self.buffer.is_empty()
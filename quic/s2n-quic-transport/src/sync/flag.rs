@@ -14,7 +14,7 @@ use crate::{
 };
 use s2n_quic_core::{ack, packet::number::PacketNumber};
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Flag<W: Writer> {
     delivery: DeliveryState,
     writer: W,
@@ -24,7 +24,7 @@ pub trait Writer: Default {
     fn write_frame<W: WriteContext>(&mut self, context: &mut W) -> Option<PacketNumber>;
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 enum DeliveryState {
     /// The flag has not been requested
     Idle,
@@ -65,6 +65,14 @@ impl Default for DeliveryState {
 }
 
 impl<W: Writer> Flag<W> {
+    /// Creates an idle flag with the provided writer
+    pub fn new(writer: W) -> Self {
+        Self {
+            delivery: DeliveryState::Idle,
+            writer,
+        }
+    }
+
     /// Returns `true` if the flag hasn't been sent
     pub fn is_idle(&self) -> bool {
         matches!(self.delivery, DeliveryState::Idle)
@@ -18,7 +18,7 @@ use s2n_quic_core::packet::number::PacketNumberSpace;
 
 pub enum Payload<'a, Config: endpoint::Config> {
     Normal(Normal<'a, Config>),
-    MtuProbe(MtuProbe<'a>),
+    MtuProbe(MtuProbe<'a, Config>),
     /// For use on non-active paths where only path validation frames are sent.
     PathValidationOnly(PathValidationOnly<'a, Config>),
 }
@@ -56,7 +56,6 @@ impl<'a, Config: endpoint::Config> Payload<'a, Config> {
                     recovery_manager,
                     crypto_stream,
                     datagram_manager,
-                    prioritize_datagrams: false,
                 })
             }
             Mode::MtuProbing => transmission::application::Payload::MtuProbe(MtuProbe {
@@ -113,7 +112,6 @@ pub struct Normal<'a, Config: endpoint::Config> {
     recovery_manager: &'a mut recovery::Manager<Config>,
     crypto_stream: &'a mut CryptoStream,
     datagram_manager: &'a mut datagram::Manager<Config>,
-    prioritize_datagrams: bool,
 }
 
 impl<'a, Config: endpoint::Config> Normal<'a, Config> {
@@ -121,18 +119,22 @@ impl<'a, Config: endpoint::Config> Normal<'a, Config> {
         let can_transmit = context.transmission_constraint().can_transmit()
             || context.transmission_constraint().can_retransmit();
 
+        // The datagram provider's priority decides whether datagrams or stream data are
+        // written first. Whichever goes first gets to use the bytes available in the
+        // congestion window.
+        let prioritize_datagrams = can_transmit && self.datagram_manager.prioritize_datagrams();
+
         //= https://www.rfc-editor.org/rfc/rfc9221#section-5
         //# DATAGRAM frames cannot be fragmented;
         //
-        // We alternate between prioritizing filling the packet with datagrams
-        // and filling the packet with other frames. This is because datagrams
-        // cannot be fragmented across packets and we want to do the most to send
-        // large datagrams.
-        if self.prioritize_datagrams && can_transmit {
+        // Prioritized datagrams are written before any other frames, which gives the
+        // application the best chance to send large datagrams.
+        if prioritize_datagrams {
             self.datagram_manager.on_transmit(
                 context,
                 self.stream_manager,
-                self.prioritize_datagrams,
+                prioritize_datagrams,
+                self.path_manager.active_path().mtu_controller.mtu(),
             );
         }
         let did_send_ack = self.ack_manager.on_transmit(context);
@@ -143,17 +145,17 @@ impl<'a, Config: endpoint::Config> Normal<'a, Config> {
 
             // If we did not prioritize datagrams in this packet, we send them just
             // before we send stream data.
-            if !self.prioritize_datagrams {
+            if !prioritize_datagrams {
                 self.datagram_manager.on_transmit(
                     context,
                     self.stream_manager,
-                    self.prioritize_datagrams,
+                    prioritize_datagrams,
+                    self.path_manager.active_path().mtu_controller.mtu(),
                 );
             }
 
-            // The default sending behavior is to alternate between sending datagrams
-            // and sending stream data. This can be configured by implementing a
-            // custom datagram sender and choosing when to cede packet space for stream data.
+            // The sender decides whether to cede packet space to stream data when datagrams
+            // are not prioritized. The default sender always cedes the space.
             let _ = self.stream_manager.on_transmit(context);
 
             // send PINGs last, since they might not actually be needed if there's an ack-eliciting
@@ -166,9 +168,6 @@ impl<'a, Config: endpoint::Config> Normal<'a, Config> {
             // inform the ack manager the packet is populated
             self.ack_manager.on_transmit_complete(context);
         }
-
-        // Alternate between prioritizing datagrams or not each packet
-        self.prioritize_datagrams = !self.prioritize_datagrams;
     }
 
     // Sends control data frames
@@ -177,6 +176,8 @@ impl<'a, Config: endpoint::Config> Normal<'a, Config> {
         // soon as possible
         self.handshake_status.on_transmit(context);
 
+        let _ = self.ack_manager.on_transmit_ack_frequency(context);
+
         let _ = self.crypto_stream.tx.on_transmit((), context);
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.2
@@ -212,11 +213,12 @@ impl<'a, Config: endpoint::Config> transmission::interest::Provider for Normal<'
     }
 }
 
-pub struct MtuProbe<'a> {
-    mtu_controller: &'a mut mtu::Controller,
+pub struct MtuProbe<'a, Config: endpoint::Config> {
+    mtu_controller:
+        &'a mut mtu::Controller<<Config::MtuControllerEndpoint as mtu::Endpoint>::MtuController>,
 }
 
-impl<'a> MtuProbe<'a> {
+impl<'a, Config: endpoint::Config> MtuProbe<'a, Config> {
     fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
         if context.transmission_constraint().can_transmit() {
             self.mtu_controller.on_transmit(context)
@@ -224,7 +226,7 @@ impl<'a> MtuProbe<'a> {
     }
 }
 
-impl<'a> transmission::interest::Provider for MtuProbe<'a> {
+impl<'a, Config: endpoint::Config> transmission::interest::Provider for MtuProbe<'a, Config> {
     fn transmission_interest<Q: transmission::interest::Query>(
         &self,
         query: &mut Q,
@@ -59,6 +59,37 @@ impl<'a, 'b, 'sub, Config: endpoint::Config> Context<'a, 'b, 'sub, Config> {
             transmission::Constraint::None => {}
         }
     }
+
+    #[inline]
+    fn on_frame_sent(&mut self, frame: event::builder::Frame) {
+        let blocked = match frame {
+            event::builder::Frame::DataBlocked { data_limit } => {
+                Some((event::builder::FlowControlScope::Connection, data_limit))
+            }
+            event::builder::Frame::StreamDataBlocked {
+                stream_id,
+                stream_data_limit,
+            } => Some((
+                event::builder::FlowControlScope::Stream { id: stream_id },
+                stream_data_limit,
+            )),
+            _ => None,
+        };
+
+        self.publisher.on_frame_sent(event::builder::FrameSent {
+            packet_header: event::builder::PacketHeader::new(
+                self.packet_number,
+                self.publisher.quic_version(),
+            ),
+            path_id: self.path_id.into_event(),
+            frame,
+        });
+
+        if let Some((scope, window)) = blocked {
+            self.publisher
+                .on_flow_control_blocked(event::builder::FlowControlBlocked { scope, window });
+        }
+    }
 }
 
 impl<'a, 'b, 'sub, Config: endpoint::Config> WriteContext for Context<'a, 'b, 'sub, Config> {
@@ -126,14 +157,7 @@ impl<'a, 'b, 'sub, Config: endpoint::Config> WriteContext for Context<'a, 'b, 's
         self.outcome.ack_elicitation |= frame.ack_elicitation();
         self.outcome.is_congestion_controlled |= frame.is_congestion_controlled();
 
-        self.publisher.on_frame_sent(event::builder::FrameSent {
-            packet_header: event::builder::PacketHeader::new(
-                self.packet_number,
-                self.publisher.quic_version(),
-            ),
-            path_id: self.path_id.into_event(),
-            frame: frame.into_event(),
-        });
+        self.on_frame_sent(frame.into_event());
         self.packet_number
     }
 
@@ -150,14 +174,7 @@ impl<'a, 'b, 'sub, Config: endpoint::Config> WriteContext for Context<'a, 'b, 's
         self.outcome.ack_elicitation |= frame.ack_elicitation();
         self.outcome.is_congestion_controlled |= frame.is_congestion_controlled();
 
-        self.publisher.on_frame_sent(event::builder::FrameSent {
-            packet_header: event::builder::PacketHeader::new(
-                self.packet_number,
-                self.publisher.quic_version(),
-            ),
-            path_id: self.path_id.into_event(),
-            frame: frame.into_event(),
-        });
+        self.on_frame_sent(frame.into_event());
         Some(self.packet_number)
     }
 
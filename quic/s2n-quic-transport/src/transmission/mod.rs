@@ -27,6 +27,7 @@ use s2n_quic_core::{
     frame::Padding,
     packet::{
         encoding::PacketPayloadEncoder,
+        interceptor::Header,
         number::{PacketNumber, PacketNumberSpace},
         stateless_reset,
     },
@@ -131,6 +132,23 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> PacketPayloadEncoder
             self.outcome.bytes_sent = header_len + tag_len + buffer.len();
         }
     }
+
+    fn on_header(&mut self, header: &mut Header) {
+        use s2n_quic_core::{
+            event::ConnectionPublisher,
+            packet::interceptor::{Interceptor, Packet},
+        };
+
+        // intercept the header before the packet is encrypted and protected
+        self.packet_interceptor.intercept_tx_header(
+            &self.publisher.subject(),
+            &Packet {
+                number: self.packet_number,
+                timestamp: self.timestamp,
+            },
+            header,
+        );
+    }
 }
 
 impl<'a, 'sub, Config: endpoint::Config, P: Payload> transmission::interest::Provider
@@ -129,6 +129,11 @@ impl<Providers: ClientProviders> Builder<Providers> {
     impl_provider_method!(
         /// Sets the event provider for the [`Client`]
         ///
+        /// Multiple subscribers can be composed with a tuple or, when the set of subscribers is
+        /// only known at runtime, a `Vec` of subscribers which are called in order. Subscribers of
+        /// different types can be stored in the same `Vec` by boxing them as
+        /// `Box<dyn DynSubscriber>`.
+        ///
         /// # Examples
         ///
         /// Sets a custom event subscriber for the client
@@ -253,6 +258,40 @@ impl<Providers: ClientProviders> Builder<Providers> {
         ClientProviders
     );
 
+    #[cfg(any(test, feature = "unstable-provider-transport-parameters-interceptor"))]
+    impl_provider_method!(
+        /// Sets the transport parameters interceptor provider for the [`Client`]
+        ///
+        /// The provider can inspect and modify the transport parameters before they are
+        /// sent to the peer.
+        with_transport_parameters_interceptor,
+        transport_parameters_interceptor,
+        ClientProviders
+    );
+
+    #[cfg(any(test, feature = "unstable-provider-mtu"))]
+    impl_provider_method!(
+        /// Sets the MTU provider for the [`Client`]
+        ///
+        /// The provider selects the sizes used when probing each path for a larger
+        /// maximum transmission unit (MTU). By default, a binary search between the
+        /// currently confirmed MTU and the configured max MTU is performed.
+        with_mtu,
+        mtu,
+        ClientProviders
+    );
+
+    #[cfg(any(test, feature = "unstable-provider-pacing"))]
+    impl_provider_method!(
+        /// Sets the pacing provider for the [`Client`]
+        ///
+        /// The provider schedules the departure of packets on each path. By default,
+        /// packets are paced according to the congestion controller.
+        with_pacing,
+        pacing,
+        ClientProviders
+    );
+
     #[cfg(any(test, feature = "unstable-provider-random"))]
     impl_provider_method!(
         /// Sets the random provider for the [`Client`]
@@ -261,7 +300,7 @@ impl<Providers: ClientProviders> Builder<Providers> {
         ClientProviders
     );
 
-    #[cfg(feature = "unstable-provider-datagram")]
+    #[cfg(any(test, feature = "unstable-provider-datagram"))]
     impl_provider_method!(
         /// Sets the datagram provider for the [`Client`]
         with_datagram,
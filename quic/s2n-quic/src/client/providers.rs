@@ -12,7 +12,10 @@ impl_providers_state! {
         congestion_controller: CongestionController,
         connection_close_formatter: ConnectionCloseFormatter,
         connection_id: ConnectionID,
+        mtu: Mtu,
+        pacing: Pacing,
         packet_interceptor: PacketInterceptor,
+        transport_parameters_interceptor: TransportParametersInterceptor,
         stateless_reset_token: StatelessResetToken,
         random: Random,
         event: Event,
@@ -31,7 +34,10 @@ impl<
         CongestionController: congestion_controller::Provider,
         ConnectionCloseFormatter: connection_close_formatter::Provider,
         ConnectionID: connection_id::Provider,
+        Mtu: mtu::Provider,
+        Pacing: pacing::Provider,
         PacketInterceptor: packet_interceptor::Provider,
+        TransportParametersInterceptor: transport_parameters_interceptor::Provider,
         StatelessResetToken: stateless_reset_token::Provider,
         Random: random::Provider,
         Event: event::Provider,
@@ -45,7 +51,10 @@ impl<
         CongestionController,
         ConnectionCloseFormatter,
         ConnectionID,
+        Mtu,
+        Pacing,
         PacketInterceptor,
+        TransportParametersInterceptor,
         StatelessResetToken,
         Random,
         Event,
@@ -61,7 +70,10 @@ impl<
             congestion_controller,
             connection_close_formatter,
             connection_id,
+            mtu,
+            pacing,
             packet_interceptor,
+            transport_parameters_interceptor,
             stateless_reset_token,
             random,
             event,
@@ -77,7 +89,12 @@ impl<
             .start()
             .map_err(StartError::new)?;
         let connection_id = connection_id.start().map_err(StartError::new)?;
+        let mtu = mtu.start().map_err(StartError::new)?;
+        let pacing = pacing.start().map_err(StartError::new)?;
         let packet_interceptor = packet_interceptor.start().map_err(StartError::new)?;
+        let transport_parameters_interceptor = transport_parameters_interceptor
+            .start()
+            .map_err(StartError::new)?;
         let stateless_reset_token = stateless_reset_token.start().map_err(StartError::new)?;
         let random = random.start().map_err(StartError::new)?;
         let endpoint_limits = EndpointLimits;
@@ -105,7 +122,10 @@ impl<
             congestion_controller,
             connection_close_formatter,
             connection_id,
+            mtu,
+            pacing,
             packet_interceptor,
+            transport_parameters_interceptor,
             stateless_reset_token,
             random,
             endpoint_limits,
@@ -193,7 +213,10 @@ struct EndpointConfig<
     CongestionController,
     ConnectionCloseFormatter,
     ConnectionID,
+    Mtu,
+    Pacing,
     PacketInterceptor,
+    TransportParametersInterceptor,
     PathHandle,
     StatelessResetToken,
     Random,
@@ -206,7 +229,10 @@ struct EndpointConfig<
     congestion_controller: CongestionController,
     connection_close_formatter: ConnectionCloseFormatter,
     connection_id: ConnectionID,
+    mtu: Mtu,
+    pacing: Pacing,
     packet_interceptor: PacketInterceptor,
+    transport_parameters_interceptor: TransportParametersInterceptor,
     stateless_reset_token: StatelessResetToken,
     random: Random,
     endpoint_limits: EndpointLimits,
@@ -224,7 +250,10 @@ impl<
         CongestionController: congestion_controller::Endpoint,
         ConnectionCloseFormatter: connection_close_formatter::Formatter,
         ConnectionID: connection::id::Format,
+        Mtu: mtu::Endpoint,
+        Pacing: pacing::Endpoint,
         PacketInterceptor: packet_interceptor::PacketInterceptor,
+        TransportParametersInterceptor: transport_parameters_interceptor::TransportParametersInterceptor,
         PathHandle: path::Handle,
         StatelessResetToken: stateless_reset_token::Generator,
         Random: s2n_quic_core::random::Generator,
@@ -238,7 +267,10 @@ impl<
         CongestionController,
         ConnectionCloseFormatter,
         ConnectionID,
+        Mtu,
+        Pacing,
         PacketInterceptor,
+        TransportParametersInterceptor,
         PathHandle,
         StatelessResetToken,
         Random,
@@ -258,7 +290,10 @@ impl<
         CongestionController: congestion_controller::Endpoint,
         ConnectionCloseFormatter: connection_close_formatter::Formatter,
         ConnectionID: connection::id::Format,
+        Mtu: mtu::Endpoint,
+        Pacing: pacing::Endpoint,
         PacketInterceptor: packet_interceptor::PacketInterceptor,
+        TransportParametersInterceptor: transport_parameters_interceptor::TransportParametersInterceptor,
         PathHandle: path::Handle,
         StatelessResetToken: stateless_reset_token::Generator,
         Random: s2n_quic_core::random::Generator,
@@ -272,7 +307,10 @@ impl<
         CongestionController,
         ConnectionCloseFormatter,
         ConnectionID,
+        Mtu,
+        Pacing,
         PacketInterceptor,
+        TransportParametersInterceptor,
         PathHandle,
         StatelessResetToken,
         Random,
@@ -292,6 +330,8 @@ impl<
     // TODO allow users to specify another lock type
     type ConnectionLock = std::sync::Mutex<Self::Connection>;
     type CongestionControllerEndpoint = CongestionController;
+    type MtuControllerEndpoint = Mtu;
+    type PacerEndpoint = Pacing;
     type EndpointLimits = EndpointLimits;
     type EventSubscriber = Event;
     type TLSEndpoint = Tls;
@@ -300,6 +340,7 @@ impl<
     type StreamManager = stream::DefaultStreamManager;
     type PathMigrationValidator = PathMigration;
     type PacketInterceptor = PacketInterceptor;
+    type TransportParametersInterceptor = TransportParametersInterceptor;
     type DatagramEndpoint = Datagram;
 
     const ENDPOINT_TYPE: endpoint::Type = endpoint::Type::Client;
@@ -307,9 +348,12 @@ impl<
     fn context(&mut self) -> endpoint::Context<Self> {
         endpoint::Context {
             congestion_controller: &mut self.congestion_controller,
+            mtu_controller: &mut self.mtu,
+            pacer: &mut self.pacing,
             connection_close_formatter: &mut self.connection_close_formatter,
             connection_id_format: &mut self.connection_id,
             packet_interceptor: &mut self.packet_interceptor,
+            transport_parameters_interceptor: &mut self.transport_parameters_interceptor,
             stateless_reset_token_generator: &mut self.stateless_reset_token,
             random_generator: &mut self.random,
             tls: &mut self.tls,
@@ -11,7 +11,13 @@ mod handle;
 
 pub use acceptor::*;
 pub use handle::*;
-pub use s2n_quic_core::connection::Error;
+pub use s2n_quic_core::{
+    connection::{
+        close::Reason as CloseReason, AcceptTimeoutError, Error, KeepAliveError, MigrationError,
+        Statistics,
+    },
+    crypto::tls::{CipherSuite, KeyExchangeGroup, ProtocolVersion, TlsInfo},
+};
 
 pub mod error {
     pub use s2n_quic_core::transport::error::Code;
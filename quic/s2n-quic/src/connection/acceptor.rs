@@ -65,6 +65,90 @@ macro_rules! impl_accept_api {
             .into()
         }
 
+        /// Accepts an incoming [`PeerStream`](`crate::stream::PeerStream`), waiting at most
+        /// `timeout` for one to arrive
+        ///
+        /// The connection remains open if the timeout elapses, so accepting can be retried.
+        /// Each call starts a new timeout.
+        ///
+        /// The method will return
+        /// - `Ok(Some(stream)))` if a [`PeerStream`](`crate::stream::PeerStream`) was accepted
+        /// - `Ok(None)` if the connection was closed without an error
+        /// - `Err(AcceptTimeoutError::TimedOut)` if no stream was accepted within `timeout`
+        /// - `Err(AcceptTimeoutError::ConnectionError(error))` if no stream could be accepted due
+        ///   to an error
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # use core::time::Duration;
+        /// # use s2n_quic::connection::AcceptTimeoutError;
+        /// # async fn test() -> Result<(), AcceptTimeoutError> {
+        /// #   let mut acceptor: s2n_quic::connection::StreamAcceptor = todo!();
+        /// #
+        /// loop {
+        ///     match acceptor.accept_stream_timeout(Duration::from_secs(5)).await {
+        ///         Ok(Some(stream)) => {
+        ///             println!("Stream opened from {:?}", stream.connection().remote_addr());
+        ///         }
+        ///         Ok(None) => break,
+        ///         Err(AcceptTimeoutError::TimedOut) => println!("No stream opened yet"),
+        ///         Err(error) => return Err(error),
+        ///     }
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn accept_stream_timeout(
+            &mut self,
+            timeout: core::time::Duration,
+        ) -> Result<Option<crate::stream::PeerStream>, crate::connection::AcceptTimeoutError> {
+            // discard any timeout left behind by a previously dropped call
+            self.0.reset_accept_timeout()?;
+            let _guard = crate::connection::acceptor::AcceptTimeoutGuard(self.0.clone());
+
+            futures::future::poll_fn(|cx| self.poll_accept_stream_timeout(timeout, cx)).await
+        }
+
+        /// Poll for accepting an incoming [`PeerStream`](`crate::stream::PeerStream`), waiting
+        /// at most `timeout` for one to arrive
+        ///
+        /// The method will return
+        /// - `Poll::Ready(Ok(Some(stream)))` if a [`PeerStream`](`crate::stream::PeerStream`) was accepted
+        /// - `Poll::Ready(Ok(None))` if the connection was closed without an error
+        /// - `Poll::Ready(Err(AcceptTimeoutError::TimedOut))` if no stream was accepted within
+        ///   `timeout`
+        /// - `Poll::Ready(Err(AcceptTimeoutError::ConnectionError(error)))` if no stream could be
+        ///   accepted due to an error
+        /// - `Poll::Pending` if no new [`PeerStream`](`crate::stream::PeerStream`) was accepted by the connection yet.
+        ///   In this case the caller must retry calling [`Self::poll_accept_stream_timeout`].
+        ///
+        /// The timeout starts on the first call and is cleared once the method returns
+        /// `Poll::Ready`.
+        #[inline]
+        pub fn poll_accept_stream_timeout(
+            &mut self,
+            timeout: core::time::Duration,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<
+            Result<Option<crate::stream::PeerStream>, crate::connection::AcceptTimeoutError>,
+        > {
+            use s2n_quic_core::stream::StreamType;
+            use $crate::stream::{BidirectionalStream, ReceiveStream};
+
+            Ok(
+                futures::ready!(self.0.poll_accept_timeout(None, timeout, cx))?.map(|stream| {
+                    match stream.id().stream_type() {
+                        StreamType::Unidirectional => ReceiveStream::new(stream.into()).into(),
+                        StreamType::Bidirectional => BidirectionalStream::new(stream).into(),
+                    }
+                }),
+            )
+            .into()
+        }
+
         impl_accept_bidirectional_api!();
         impl_accept_receive_api!();
     };
@@ -179,6 +263,16 @@ macro_rules! impl_accept_receive_api {
     };
 }
 
+/// Discards the accept timeout when an `accept_stream_timeout` future completes or is dropped
+pub(crate) struct AcceptTimeoutGuard(pub(crate) s2n_quic_transport::connection::Connection);
+
+impl Drop for AcceptTimeoutGuard {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = self.0.reset_accept_timeout();
+    }
+}
+
 #[derive(Debug)]
 pub struct StreamAcceptor(pub(crate) s2n_quic_transport::connection::Connection);
 
@@ -138,15 +138,85 @@ macro_rules! impl_handle_api {
         }
 
         /// Returns the local address that this connection is bound to.
+        ///
+        /// The address is read from the active path, so it reflects any migration that occurred
+        /// since the connection was established.
+        ///
+        /// IPv4-mapped IPv6 addresses, as reported by dual-stack sockets, are returned as IPv4
+        /// addresses.
         #[inline]
         pub fn local_addr(&self) -> $crate::connection::Result<std::net::SocketAddr> {
-            self.0.local_address().map(std::net::SocketAddr::from)
+            self.0
+                .local_address()
+                .map(|addr| std::net::SocketAddr::from(addr.unmap()))
         }
 
         /// Returns the remote address that this connection is connected to.
+        ///
+        /// The address is read from the active path, so it reflects any migration that occurred
+        /// since the connection was established.
+        ///
+        /// IPv4-mapped IPv6 addresses, as reported by dual-stack sockets, are returned as IPv4
+        /// addresses.
         #[inline]
         pub fn remote_addr(&self) -> $crate::connection::Result<std::net::SocketAddr> {
-            self.0.remote_address().map(std::net::SocketAddr::from)
+            self.0
+                .remote_address()
+                .map(|addr| std::net::SocketAddr::from(addr.unmap()))
+        }
+
+        /// Returns the largest UDP payload currently usable on the connection's active path.
+        ///
+        /// This is the path MTU minus the IP and UDP headers, which is the same value reported by
+        /// the most recent [`MtuUpdated`](crate::provider::event::events::MtuUpdated) event. It may
+        /// increase as MTU probing completes or decrease if an MTU black hole is detected. QUIC
+        /// packet and frame headers are carried in the same payload, so the amount of stream data
+        /// that fits in a single datagram is smaller than this value.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> Result<(), Box<dyn std::error::Error>> {
+        /// #   let mut connection: s2n_quic::connection::Handle = todo!();
+        /// #   let payload: bytes::Bytes = todo!();
+        /// #
+        /// let mut stream = connection.open_send_stream().await?;
+        ///
+        /// // scale the size of each write with the current path MTU
+        /// let chunk_size = connection.path_mtu()? * 4;
+        /// for chunk in payload.chunks(chunk_size) {
+        ///     stream.send(payload.slice_ref(chunk)).await?;
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn path_mtu(&self) -> $crate::connection::Result<usize> {
+            self.0.path_mtu()
+        }
+
+        /// Returns the idle timeout negotiated with the peer
+        ///
+        /// The effective value is the minimum of the `max_idle_timeout` advertised by each
+        /// endpoint, or `None` if neither endpoint limits the idle period. Until the peer's
+        /// transport parameters are received, the locally configured value is returned.
+        ///
+        /// Note that the idle timer is never shorter than three times the current probe
+        /// timeout (PTO), even if the negotiated value is smaller.
+        #[inline]
+        pub fn idle_timeout(&self) -> $crate::connection::Result<Option<core::time::Duration>> {
+            self.0.idle_timeout()
+        }
+
+        /// Returns a snapshot of the connection's statistics.
+        ///
+        /// This includes the RTT, congestion window and MTU of the active path along with the
+        /// total bytes sent, bytes received and packets lost by the connection. The snapshot is
+        /// cheap to compute, so it can be polled from the application task.
+        #[inline]
+        pub fn stats(&self) -> $crate::connection::Result<$crate::connection::Statistics> {
+            self.0.statistics()
         }
 
         /// Returns the negotiated server name the connection is using.
@@ -156,11 +226,82 @@ macro_rules! impl_handle_api {
         }
 
         /// Returns the negotiated application protocol the connection is using
+        ///
+        /// This is the protocol selected through ALPN from the protocols configured on the TLS
+        /// provider, and can be used to dispatch a connection to the appropriate handler.
+        #[doc(alias = "alpn")]
         #[inline]
         pub fn application_protocol(&self) -> $crate::connection::Result<::bytes::Bytes> {
             self.0.application_protocol()
         }
 
+        /// Returns the DER-encoded certificate chain presented by the peer
+        ///
+        /// The peer's end-entity certificate is first in the chain. The chain is empty if the
+        /// peer did not authenticate with a certificate, which is always the case for clients
+        /// unless the server was configured with `with_client_authentication`.
+        #[inline]
+        pub fn peer_certificate(
+            &self,
+        ) -> $crate::connection::Result<::std::vec::Vec<::bytes::Bytes>> {
+            self.0.peer_certificate_chain()
+        }
+
+        /// Returns the OCSP response stapled by the server during the handshake
+        ///
+        /// This is `None` for servers, or if the server did not staple a response. Clients
+        /// need to request stapling with `with_ocsp_stapling` for the server to send one.
+        #[inline]
+        pub fn ocsp_response(
+            &self,
+        ) -> $crate::connection::Result<::core::option::Option<::bytes::Bytes>> {
+            self.0.ocsp_response()
+        }
+
+        /// Returns the cipher suite, key exchange group and protocol version negotiated by the
+        /// TLS handshake
+        ///
+        /// This is `None` until the handshake has progressed far enough to derive the 1-RTT keys.
+        #[inline]
+        pub fn tls_info(
+            &self,
+        ) -> $crate::connection::Result<::core::option::Option<$crate::connection::TlsInfo>> {
+            self.0.tls_info()
+        }
+
+        /// Returns the reason the connection was closed, or `None` if it is still open
+        ///
+        /// This can be called after an operation on the connection or one of its streams fails
+        /// to determine how the connection was closed. For example, a client may decide whether to
+        /// retry a request based on the application error code received from the server.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let handle: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// use s2n_quic::connection::CloseReason;
+        ///
+        /// match handle.close_reason()? {
+        ///     Some(CloseReason::Application { error, reason, .. }) => {
+        ///         println!("closed by the application with {error:?}: {reason:?}");
+        ///     }
+        ///     Some(CloseReason::IdleTimerExpired) => println!("the connection timed out"),
+        ///     Some(reason) => println!("the connection was closed: {reason:?}"),
+        ///     None => println!("the connection is still open"),
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn close_reason(
+            &self,
+        ) -> $crate::connection::Result<Option<$crate::connection::CloseReason>> {
+            self.0.close_reason()
+        }
+
         /// Returns the internal identifier for the [`Connection`](`crate::Connection`)
         ///
         /// Note: This internal identifier is not the same as the connection ID included in packet
@@ -186,6 +327,56 @@ macro_rules! impl_handle_api {
             self.0.keep_alive(enabled)
         }
 
+        /// Enables keep-alive on the connection, sending a PING frame whenever the connection
+        /// has been idle for the given `period`
+        ///
+        /// Any ack-eliciting packet sent or received resets the period, except for MTU probes,
+        /// which are likely to be lost while probing for larger sizes. Returns
+        /// [`KeepAliveError::InvalidPeriod`](crate::connection::KeepAliveError::InvalidPeriod)
+        /// if the period isn't less than the negotiated idle timeout, since the connection would
+        /// time out before the PING was sent.
+        ///
+        /// Keep-alive can be disabled again with [`Self::keep_alive`].
+        #[inline]
+        pub fn keep_alive_with_period(
+            &mut self,
+            period: core::time::Duration,
+        ) -> $crate::connection::Result<(), $crate::connection::KeepAliveError> {
+            self.0.keep_alive_with_period(period)
+        }
+
+        /// Rotates the connection IDs issued to the peer
+        ///
+        /// New connection IDs are issued to the peer and it is requested to retire all of the
+        /// connection IDs it was previously issued. This can be used to make it harder for an
+        /// observer to link the packets sent before the call to those sent after it.
+        #[inline]
+        pub fn rotate_connection_id(&mut self) -> $crate::connection::Result<()> {
+            self.0.rotate_connection_id()
+        }
+
+        /// Requests the peer to acknowledge the next packet without delay
+        ///
+        /// This sends an IMMEDIATE_ACK frame, which overrides any acknowledgement delay
+        /// negotiated with the ACK_FREQUENCY extension, and can be used to reduce the tail
+        /// latency of request/response exchanges. The request is ignored if the ACK_FREQUENCY
+        /// extension wasn't enabled on both endpoints with
+        /// [`with_ack_frequency`](crate::provider::limits::Limits::with_ack_frequency).
+        #[inline]
+        pub fn request_immediate_ack(&mut self) -> $crate::connection::Result<()> {
+            self.0.request_immediate_ack()
+        }
+
+        /// Initiates an update of the 1-RTT packet protection keys
+        ///
+        /// The update is started once the handshake has been confirmed. A `KeyUpdated` event is
+        /// emitted after the peer responds with packets protected with the new keys. Updates
+        /// can also be initiated automatically with `Limits::with_key_update_bytes`.
+        #[inline]
+        pub fn initiate_key_update(&mut self) -> $crate::connection::Result<()> {
+            self.0.initiate_key_update()
+        }
+
         /// Closes the Connection with the provided error code
         ///
         /// This will immediately terminate all outstanding streams.
@@ -207,6 +398,92 @@ macro_rules! impl_handle_api {
             self.0.close(error_code)
         }
 
+        /// Gracefully closes the Connection once all of the outstanding stream data has been
+        /// delivered to the peer
+        ///
+        /// Any send streams that are still open are finished and no new streams can be opened.
+        /// Once all of the stream data has been acknowledged by the peer, the connection is
+        /// closed without an error. If this does not happen within the provided `timeout`, the
+        /// connection is closed immediately and an error is returned. The peer is sent an
+        /// `APPLICATION_ERROR` transport error in this case so it can tell that some of the data
+        /// was discarded.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   use core::time::Duration;
+        /// #   let mut connection: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// connection.close_gracefully(Duration::from_secs(5)).await?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn close_gracefully(
+            &mut self,
+            timeout: core::time::Duration,
+        ) -> $crate::connection::Result<()> {
+            futures::future::poll_fn(|cx| self.poll_close_gracefully(timeout, cx)).await
+        }
+
+        /// Polls gracefully closing the Connection
+        ///
+        /// The method will return
+        /// - `Poll::Ready(Ok(()))` if all of the outstanding data was flushed and the connection closed
+        /// - `Poll::Ready(Err(error))` if the connection was closed with an error or the `timeout` elapsed
+        /// - `Poll::Pending` if the connection is still flushing
+        #[inline]
+        pub fn poll_close_gracefully(
+            &mut self,
+            timeout: core::time::Duration,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<()>> {
+            self.0.poll_close_gracefully(timeout, cx)
+        }
+
+        /// Migrates the Connection to a new path from the provided local address
+        ///
+        /// The new path is validated with the peer before the Connection switches to it. If
+        /// validation fails, the Connection remains on the current path and an error is
+        /// returned. Only clients can initiate a migration, and only once the handshake has
+        /// been confirmed.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> Result<(), s2n_quic::connection::MigrationError> {
+        /// #   let mut connection: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// connection.migrate("192.168.1.2:4433".parse().unwrap()).await?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn migrate(
+            &mut self,
+            local_addr: std::net::SocketAddr,
+        ) -> $crate::connection::Result<(), $crate::connection::MigrationError> {
+            futures::future::poll_fn(|cx| self.poll_migrate(local_addr, cx)).await
+        }
+
+        /// Polls migrating the Connection to a new path from the provided local address
+        ///
+        /// The method will return
+        /// - `Poll::Ready(Ok(()))` if the new path was validated and is now the active path
+        /// - `Poll::Ready(Err(error))` if the migration failed or the connection was closed
+        /// - `Poll::Pending` if the new path is still being validated
+        #[inline]
+        pub fn poll_migrate(
+            &mut self,
+            local_addr: std::net::SocketAddr,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<(), $crate::connection::MigrationError>> {
+            self.0.poll_migrate(local_addr.into(), cx)
+        }
+
         /// API for querying the connection's
         /// [`Subscriber::ConnectionContext`](crate::provider::event::Subscriber::ConnectionContext).
         ///
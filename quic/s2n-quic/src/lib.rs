@@ -22,6 +22,21 @@
 //! that multiple servers handle address tokens, this provider should not be used. Instead, a custom
 //! implementation of [`provider::address_token::Format`] should be specified.
 //!
+//! ### `provider-event-json`
+//!
+//! Enables [`provider::event::json::Subscriber`], which writes endpoint and connection events as
+//! newline-delimited JSON to an [`std::io::Write`] implementation.
+//!
+//! ### `provider-event-otel`
+//!
+//! Enables [`provider::event::otel::Subscriber`], which exports connection metrics and spans to
+//! [OpenTelemetry](https://opentelemetry.io).
+//!
+//! ### `provider-event-qlog`
+//!
+//! Enables [`provider::event::qlog::Subscriber`], which writes a [qlog](https://datatracker.ietf.org/doc/draft-ietf-quic-qlog-main-schema/)
+//! trace file for each connection.
+//!
 //! ### `provider-event-tracing`
 //!
 //! Enables event integration with [`tracing`](https://docs.rs/tracing). The
@@ -78,13 +93,16 @@ mod tests;
         // add new unstable features to this list
         any(
             feature = "unstable_client_hello",
+            feature = "unstable-provider-clock",
             feature = "unstable-provider-datagram",
             feature = "unstable-provider-io-testing",
             feature = "unstable-provider-io-turmoil",
             feature = "unstable-provider-io-xdp",
+            feature = "unstable-provider-mtu",
             feature = "unstable-provider-packet-interceptor",
+            feature = "unstable-provider-pacing",
             feature = "unstable-provider-random",
-            feature = "unstable-congestion-controller",
+            feature = "unstable-provider-transport-parameters-interceptor",
             feature = "unstable_resumption",
         ),
         // any unstable features requires at least one of the following conditions
@@ -30,6 +30,30 @@ cfg_if!(
     }
 );
 
+cfg_if!(
+    if #[cfg(any(test, feature = "unstable-provider-transport-parameters-interceptor"))] {
+        pub mod transport_parameters_interceptor;
+    } else {
+        pub(crate) mod transport_parameters_interceptor;
+    }
+);
+
+cfg_if!(
+    if #[cfg(any(test, feature = "unstable-provider-mtu"))] {
+        pub mod mtu;
+    } else {
+        pub(crate) mod mtu;
+    }
+);
+
+cfg_if!(
+    if #[cfg(any(test, feature = "unstable-provider-pacing"))] {
+        pub mod pacing;
+    } else {
+        pub(crate) mod pacing;
+    }
+);
+
 cfg_if!(
     if #[cfg(any(test, feature = "unstable-provider-random"))] {
         pub mod random;
@@ -46,6 +70,9 @@ cfg_if!(
     }
 );
 
+#[cfg(any(test, feature = "unstable-provider-clock"))]
+pub mod clock;
+
 /// An error indicating a failure to start an endpoint
 pub struct StartError(Box<dyn 'static + fmt::Display + Send + Sync>);
 
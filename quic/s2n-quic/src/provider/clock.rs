@@ -0,0 +1,24 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Provides the time source for an endpoint
+//!
+//! The clock is owned by the IO provider, which passes it to the endpoint on every iteration of
+//! the event loop. All of the time-based machinery of the endpoint, including loss recovery, pacing
+//! and the idle timeout, reads the current time from it, and the clock's [`Timer`] is armed with
+//! the endpoint's next deadline.
+//!
+//! A custom clock can be configured on the tokio IO provider with
+//! [`Builder::with_clock`](crate::provider::io::tokio::Builder::with_clock):
+//!
+//! ```rust,ignore
+//! let io = s2n_quic::provider::io::tokio::Builder::default()
+//!     .with_receive_address("127.0.0.1:443".parse()?)?
+//!     .with_clock(MyClock::default())
+//!     .build()?;
+//! ```
+
+pub use s2n_quic_core::time::{clock::Timer, Clock, ClockWithTimer, Timestamp};
+
+/// The default clock, which is backed by the tokio runtime
+pub use s2n_quic_platform::io::tokio::Clock as Tokio;
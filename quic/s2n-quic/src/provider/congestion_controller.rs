@@ -1,7 +1,6 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use cfg_if::cfg_if;
 pub use s2n_quic_core::recovery::congestion_controller::Endpoint;
 
 /// Provides congestion controller support for an endpoint
@@ -12,18 +11,20 @@ pub trait Provider {
     fn start(self) -> Result<Self::Endpoint, Self::Error>;
 }
 
-cfg_if! {
-    if #[cfg(feature = "unstable-congestion-controller")] {
-        // Export the types needed to implement the CongestionController trait
-        pub use s2n_quic_core::{
-            random::Generator as RandomGenerator,
-            recovery::{congestion_controller::{CongestionController, PathInfo, Publisher}, RttEstimator},
-            time::Timestamp,
-        };
-    }
-}
-
-pub use s2n_quic_core::recovery::{bbr::Endpoint as Bbr, cubic::Endpoint as Cubic};
+// Export the types needed to implement the CongestionController trait
+pub use s2n_quic_core::{
+    random::Generator as RandomGenerator,
+    recovery::{
+        congestion_controller::{CongestionController, PathInfo, Publisher},
+        RttEstimator,
+    },
+    time::Timestamp,
+};
+
+pub use s2n_quic_core::recovery::{
+    bbr::Endpoint as Bbr,
+    cubic::{Endpoint as Cubic, SlowStartMode},
+};
 pub type Default = Cubic;
 
 impl_provider_utils!();
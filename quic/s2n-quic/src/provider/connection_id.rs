@@ -31,9 +31,12 @@ pub mod default {
         time::Duration,
     };
     use rand::prelude::*;
-    use s2n_quic_core::connection::{
-        self,
-        id::{ConnectionInfo, Generator, Validator},
+    use s2n_quic_core::{
+        connection::{
+            self,
+            id::{ConnectionInfo, Generator, Validator},
+        },
+        random,
     };
 
     #[derive(Debug, Default)]
@@ -124,10 +127,24 @@ pub mod default {
         fn generate(&mut self, _connection_info: &ConnectionInfo) -> connection::LocalId {
             let mut id = [0u8; connection::id::MAX_LEN];
             let id = &mut id[..self.len];
+
             rand::thread_rng().fill_bytes(id);
             (&*id).try_into().expect("length already checked")
         }
 
+        fn generate_with_random(
+            &mut self,
+            _connection_info: &ConnectionInfo,
+            random_generator: &mut dyn random::Generator,
+        ) -> connection::LocalId {
+            let mut id = [0u8; connection::id::MAX_LEN];
+            let id = &mut id[..self.len];
+
+            // connection IDs are sent in the clear
+            random_generator.public_random_fill(id);
+            (&*id).try_into().expect("length already checked")
+        }
+
         fn lifetime(&self) -> Option<Duration> {
             self.lifetime
         }
@@ -146,6 +163,7 @@ pub mod default {
     #[cfg(test)]
     mod tests {
         use super::*;
+        use s2n_quic_core::random::Generator as _;
 
         #[test]
         fn generator_test() {
@@ -204,5 +222,27 @@ pub mod default {
                     .err()
             );
         }
+
+        /// Connection IDs are derived from the endpoint's random generator
+        #[test]
+        fn random_generator_test() {
+            let remote_address = &s2n_quic_core::inet::SocketAddress::default();
+            let connection_info = ConnectionInfo::new(remote_address);
+            let mut format = Format::default();
+
+            let mut random_generator = random::testing::Generator(123);
+            let id = format.generate_with_random(&connection_info, &mut random_generator);
+
+            let mut expected = [0u8; DEFAULT_LEN];
+            random::testing::Generator(123).public_random_fill(&mut expected);
+            assert_eq!(id.as_bytes(), &expected[..]);
+
+            // the same seed produces the same connection ID
+            let mut random_generator = random::testing::Generator(123);
+            assert_eq!(
+                format.generate_with_random(&connection_info, &mut random_generator),
+                id
+            );
+        }
     }
 }
@@ -7,8 +7,8 @@ use s2n_quic_core::datagram::Disabled;
 pub use s2n_quic_core::datagram::{
     default,
     traits::{
-        ConnectionInfo, Endpoint, Packet, PreConnectionInfo, ReceiveContext, Receiver, Sender,
-        WriteError,
+        ConnectionInfo, DatagramPriority, DropReason, Endpoint, Packet, PreConnectionInfo,
+        ReceiveContext, Receiver, Sender, WriteError,
     },
 };
 
@@ -87,9 +87,51 @@ impl BasicRateLimiter {
     }
 }
 
+/// Limits the rate of connection attempts with a token bucket
+///
+/// The bucket starts full with `burst` tokens and is refilled at `rate` tokens per second, up
+/// to `burst`. Each connection attempt takes a token, and is rate limited if none are available.
+#[derive(Debug, Clone, Copy)]
+struct AcceptRateLimiter {
+    rate: u32,
+    burst: u32,
+    tokens: f64,
+    last_refill: Option<Timestamp>,
+}
+
+impl AcceptRateLimiter {
+    fn new(rate: u32, burst: u32) -> Self {
+        Self {
+            rate,
+            burst,
+            tokens: burst as f64,
+            last_refill: None,
+        }
+    }
+
+    /// Returns true if the connection attempt exceeds the rate limit
+    fn should_limit(&mut self, connection_attempt: &ConnectionAttempt) -> bool {
+        let timestamp = connection_attempt.timestamp;
+
+        if let Some(last_refill) = self.last_refill {
+            let elapsed = timestamp.saturating_duration_since(last_refill);
+            self.tokens =
+                (self.tokens + elapsed.as_secs_f64() * self.rate as f64).min(self.burst as f64);
+        }
+        self.last_refill = Some(timestamp);
+
+        if self.tokens < 1.0 {
+            return true;
+        }
+
+        self.tokens -= 1.0;
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BasicRateLimiter, THROTTLED_PORT_LIMIT, THROTTLE_FREQUENCY};
+    use super::{AcceptRateLimiter, BasicRateLimiter, THROTTLED_PORT_LIMIT, THROTTLE_FREQUENCY};
     use core::time::Duration;
     use s2n_quic_core::{
         endpoint::limits::ConnectionAttempt,
@@ -141,6 +183,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn accept_rate_limiter() {
+        let remote_address = SocketAddress::default();
+        let mut mock_clock = MockClock::default();
+        let mut rate_limiter = AcceptRateLimiter::new(10, 5);
+
+        let mut attempt = |clock: &MockClock| {
+            let info = ConnectionAttempt::new(0, 0, &remote_address, clock.get_time().into_event());
+            rate_limiter.should_limit(&info)
+        };
+
+        // the burst is allowed immediately
+        for _ in 0..5 {
+            assert!(!attempt(&mock_clock));
+        }
+        assert!(attempt(&mock_clock));
+
+        // a token is added every 100ms
+        mock_clock.inc_by(Duration::from_millis(100));
+        assert!(!attempt(&mock_clock));
+        assert!(attempt(&mock_clock));
+
+        // the bucket doesn't grow beyond the burst size
+        mock_clock.inc_by(Duration::from_secs(10));
+        for _ in 0..5 {
+            assert!(!attempt(&mock_clock));
+        }
+        assert!(attempt(&mock_clock));
+    }
+
     #[test]
     fn throttle_constants_changed() {
         // If the constants change consider modifying the above test cases to make sure we are
@@ -157,7 +229,27 @@ pub mod default {
     //! Default provider for the endpoint limits.
 
     use super::*;
-    use core::convert::Infallible;
+    use core::{convert::Infallible, fmt};
+
+    /// An error returned when the endpoint limits are configured with invalid values
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum ConfigError {
+        /// The accept rate limit has a `burst` of 0, which would drop every connection attempt
+        ZeroAcceptRateBurst,
+    }
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::ZeroAcceptRateBurst => {
+                    write!(f, "The accept rate limit burst must be greater than 0")
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for ConfigError {}
 
     /// Allows the endpoint limits to be built with specific values
     ///
@@ -177,9 +269,41 @@ pub mod default {
     ///     Ok(())
     /// # }
     /// ```
+    ///
+    /// Require every peer to complete a Retry round trip to validate its address.
+    ///
+    /// ```rust
+    /// use s2n_quic::provider::endpoint_limits;
+    /// # use std::error::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// let limits = endpoint_limits::Default::builder()
+    ///     .with_retry(true)?
+    ///     .build();
+    ///
+    ///     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Accept at most 100 new connections per second, with bursts of up to 20.
+    ///
+    /// ```rust
+    /// use s2n_quic::provider::endpoint_limits;
+    /// # use std::error::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// let limits = endpoint_limits::Default::builder()
+    ///     .with_accept_rate_limit(100, 20)?
+    ///     .build();
+    ///
+    ///     Ok(())
+    /// # }
+    /// ```
     #[derive(Default)]
     pub struct Builder {
         max_inflight_handshake_limit: Option<usize>,
+        retry: bool,
+        accept_rate_limit: Option<(u32, u32)>,
     }
 
     impl Builder {
@@ -189,11 +313,46 @@ pub mod default {
             Ok(self)
         }
 
+        /// Sets whether a Retry packet is sent for every connection attempt without a token
+        ///
+        /// This requires each peer to prove it can receive packets at its address before any
+        /// connection state is allocated, at the cost of an extra round trip. Retry tokens are
+        /// generated and validated by the [`address_token`](crate::provider::address_token)
+        /// provider.
+        pub fn with_retry(mut self, enabled: bool) -> Result<Self, Infallible> {
+            self.retry = enabled;
+            Ok(self)
+        }
+
+        /// Sets the maximum rate of new connection attempts the endpoint accepts
+        ///
+        /// Up to `burst` connection attempts are accepted at once, after which attempts are
+        /// accepted at `per_second` attempts per second. Attempts beyond the limit are dropped
+        /// before any cryptographic work is performed for them, and are retransmitted by the
+        /// peer.
+        ///
+        /// Returns an error if `burst` is 0, since no connection attempt would ever be accepted.
+        pub fn with_accept_rate_limit(
+            mut self,
+            per_second: u32,
+            burst: u32,
+        ) -> Result<Self, ConfigError> {
+            if burst == 0 {
+                return Err(ConfigError::ZeroAcceptRateBurst);
+            }
+            self.accept_rate_limit = Some((per_second, burst));
+            Ok(self)
+        }
+
         /// Build the limits
         pub fn build(self) -> Result<Limits, Infallible> {
             Ok(Limits {
                 max_inflight_handshake_limit: self.max_inflight_handshake_limit,
+                retry: self.retry,
                 rate_limiter: [BasicRateLimiter::default(); THROTTLED_PORTS_LEN],
+                accept_rate_limiter: self
+                    .accept_rate_limit
+                    .map(|(per_second, burst)| AcceptRateLimiter::new(per_second, burst)),
             })
         }
     }
@@ -202,7 +361,11 @@ pub mod default {
     pub struct Limits {
         /// Maximum number of handshakes to allow before Retry packets are queued
         max_inflight_handshake_limit: Option<usize>,
+        /// Queue Retry packets for all connection attempts
+        retry: bool,
         rate_limiter: [BasicRateLimiter; THROTTLED_PORTS_LEN],
+        /// Limits the rate of connection attempts accepted by the endpoint
+        accept_rate_limiter: Option<AcceptRateLimiter>,
     }
 
     impl Limits {
@@ -227,6 +390,16 @@ pub mod default {
                 }
             }
 
+            if let Some(accept_rate_limiter) = self.accept_rate_limiter.as_mut() {
+                if accept_rate_limiter.should_limit(info) {
+                    return Outcome::rate_limited();
+                }
+            }
+
+            if self.retry {
+                return Outcome::retry();
+            }
+
             if let Some(limit) = self.max_inflight_handshake_limit {
                 if info.inflight_handshakes >= limit {
                     return Outcome::retry();
@@ -242,7 +415,9 @@ pub mod default {
         fn default() -> Self {
             Self {
                 max_inflight_handshake_limit: None,
+                retry: false,
                 rate_limiter: [BasicRateLimiter::default(); THROTTLED_PORTS_LEN],
+                accept_rate_limiter: None,
             }
         }
     }
@@ -255,6 +430,22 @@ pub mod default {
             .build()
             .unwrap();
         assert_eq!(elp.max_inflight_handshake_limit, Some(100));
+        assert!(!elp.retry);
+
+        let elp = Limits::builder().with_retry(true).unwrap().build().unwrap();
+        assert!(elp.retry);
+
+        let elp = Limits::builder()
+            .with_accept_rate_limit(100, 20)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(elp.accept_rate_limiter.is_some());
+
+        assert!(matches!(
+            Limits::builder().with_accept_rate_limit(100, 0),
+            Err(ConfigError::ZeroAcceptRateBurst)
+        ));
     }
 
     #[test]
@@ -0,0 +1,17 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Writes events as newline-delimited JSON
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use s2n_quic::{provider::event::json, Server};
+//!
+//! let server = Server::builder()
+//!     .with_event(json::Subscriber::new(std::io::stderr()))?
+//!     .with_io("127.0.0.1:443")?
+//!     .start()?;
+//! ```
+
+pub use s2n_quic_core::event::json::Subscriber;
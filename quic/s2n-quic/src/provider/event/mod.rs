@@ -9,7 +9,7 @@ pub use s2n_quic_core::{
     event::{
         api as events,
         api::{ConnectionInfo, ConnectionMeta},
-        supervisor, Event, Meta, Subscriber, Timestamp,
+        supervisor, DynSubscriber, Event, Meta, Subscriber, Timestamp,
     },
     query,
 };
@@ -29,6 +29,18 @@ pub mod disabled;
 #[cfg(any(feature = "provider-event-tracing", test))]
 pub mod tracing;
 
+/// Provides an implementation to write events as newline-delimited JSON
+#[cfg(any(feature = "provider-event-json", test))]
+pub mod json;
+
+/// Provides an implementation to export connection metrics and spans to OpenTelemetry
+#[cfg(any(feature = "provider-event-otel", test))]
+pub mod otel;
+
+/// Provides an implementation to write a qlog trace for each connection
+#[cfg(any(feature = "provider-event-qlog", test))]
+pub mod qlog;
+
 /// Provides an implementation to emit perf metrics to the console
 #[cfg(feature = "provider-event-console-perf")]
 pub mod console_perf;
@@ -0,0 +1,404 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{connection::Error, provider::event};
+use opentelemetry::{
+    global::{self, BoxedTracer},
+    metrics::{Counter, Histogram, Meter},
+    trace::{Span as _, Status, Tracer},
+    KeyValue,
+};
+
+/// The name of the instrumentation scope used by [`Subscriber::default`]
+const SCOPE: &str = "s2n-quic";
+
+/// An event subscriber that exports connection metrics and spans to
+/// [OpenTelemetry](https://opentelemetry.io)
+///
+/// The following metrics are recorded, each with a `quic.endpoint` attribute set to either
+/// `client` or `server`:
+///
+/// * `quic.connection.handshake.duration` - the time taken to confirm the handshake, in seconds
+/// * `quic.connection.rtt` - the latest round trip time sample, in seconds
+/// * `quic.connection.congestion_window` - the congestion window, in bytes
+/// * `quic.connection.mtu` - the MTU of a path each time it is updated, in bytes
+/// * `quic.stream.bytes_sent` - the number of stream bytes sent to peers
+/// * `quic.stream.bytes_received` - the number of stream bytes received from peers
+///
+/// Each connection is recorded as a `quic.connection` span, which ends when the connection is
+/// closed. The span status is set to an error unless the connection was closed gracefully or
+/// due to an idle timeout.
+///
+/// # Examples
+///
+/// Exports the metrics and spans with the globally configured meter and tracer providers.
+///
+/// ```rust,ignore
+/// use s2n_quic::{provider::event::otel, Server};
+///
+/// let server = Server::builder()
+///     .with_event(otel::Subscriber::default())?
+///     .with_io("127.0.0.1:443")?
+///     .start()?;
+/// ```
+#[derive(Debug)]
+pub struct Subscriber<T = BoxedTracer> {
+    tracer: T,
+    metrics: Metrics,
+}
+
+impl Default for Subscriber {
+    fn default() -> Self {
+        Self::new(&global::meter(SCOPE), global::tracer(SCOPE))
+    }
+}
+
+impl<T: Tracer> Subscriber<T> {
+    /// Creates a subscriber that records metrics with `meter` and connection spans with `tracer`
+    pub fn new(meter: &Meter, tracer: T) -> Self {
+        Self {
+            tracer,
+            metrics: Metrics::new(meter),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Metrics {
+    handshake_duration: Histogram<f64>,
+    rtt: Histogram<f64>,
+    congestion_window: Histogram<u64>,
+    mtu: Histogram<u64>,
+    bytes_sent: Counter<u64>,
+    bytes_received: Counter<u64>,
+}
+
+impl Metrics {
+    fn new(meter: &Meter) -> Self {
+        Self {
+            handshake_duration: meter
+                .f64_histogram("quic.connection.handshake.duration")
+                .with_description("The time taken to confirm the handshake, in seconds")
+                .init(),
+            rtt: meter
+                .f64_histogram("quic.connection.rtt")
+                .with_description("The latest round trip time sample, in seconds")
+                .init(),
+            congestion_window: meter
+                .u64_histogram("quic.connection.congestion_window")
+                .with_description("The congestion window, in bytes")
+                .init(),
+            mtu: meter
+                .u64_histogram("quic.connection.mtu")
+                .with_description("The MTU of a path each time it is updated, in bytes")
+                .init(),
+            bytes_sent: meter
+                .u64_counter("quic.stream.bytes_sent")
+                .with_description("The number of stream bytes sent to peers")
+                .init(),
+            bytes_received: meter
+                .u64_counter("quic.stream.bytes_received")
+                .with_description("The number of stream bytes received from peers")
+                .init(),
+        }
+    }
+}
+
+/// Returns the attributes attached to each metric recorded for a connection
+fn attributes(meta: &event::ConnectionMeta) -> [KeyValue; 1] {
+    let endpoint = match meta.endpoint_type {
+        event::events::EndpointType::Client { .. } => "client",
+        event::events::EndpointType::Server { .. } => "server",
+    };
+    [KeyValue::new("quic.endpoint", endpoint)]
+}
+
+impl<T> event::Subscriber for Subscriber<T>
+where
+    T: 'static + Tracer + Send,
+    T::Span: 'static + Send,
+{
+    type ConnectionContext = T::Span;
+
+    #[inline]
+    fn create_connection_context(
+        &mut self,
+        meta: &event::ConnectionMeta,
+        _info: &event::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+        let mut span = self.tracer.start("quic.connection");
+        let [endpoint] = attributes(meta);
+        span.set_attribute(endpoint);
+        span.set_attribute(KeyValue::new("quic.connection.id", meta.id as i64));
+        span
+    }
+
+    #[inline]
+    fn on_handshake_complete(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        event: &event::events::HandshakeComplete,
+    ) {
+        self.metrics
+            .handshake_duration
+            .record(event.duration.as_secs_f64(), &attributes(meta));
+        context.add_event(
+            "handshake_complete",
+            vec![
+                KeyValue::new("quic.handshake.zero_rtt", event.zero_rtt),
+                KeyValue::new("quic.handshake.resumed", event.resumed),
+            ],
+        );
+    }
+
+    #[inline]
+    fn on_recovery_metrics(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        event: &event::events::RecoveryMetrics,
+    ) {
+        let attributes = attributes(meta);
+        self.metrics
+            .rtt
+            .record(event.latest_rtt.as_secs_f64(), &attributes);
+        self.metrics
+            .congestion_window
+            .record(event.congestion_window as u64, &attributes);
+    }
+
+    #[inline]
+    fn on_mtu_updated(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        event: &event::events::MtuUpdated,
+    ) {
+        self.metrics.mtu.record(event.mtu as u64, &attributes(meta));
+    }
+
+    #[inline]
+    fn on_tx_stream_progress(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        event: &event::events::TxStreamProgress,
+    ) {
+        self.metrics
+            .bytes_sent
+            .add(event.bytes as u64, &attributes(meta));
+    }
+
+    #[inline]
+    fn on_rx_stream_progress(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        event: &event::events::RxStreamProgress,
+    ) {
+        self.metrics
+            .bytes_received
+            .add(event.bytes as u64, &attributes(meta));
+    }
+
+    #[inline]
+    fn on_connection_closed(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        event: &event::events::ConnectionClosed,
+    ) {
+        let statistics = &event.statistics;
+        context.set_attribute(KeyValue::new(
+            "quic.connection.bytes_sent",
+            statistics.bytes_sent as i64,
+        ));
+        context.set_attribute(KeyValue::new(
+            "quic.connection.bytes_received",
+            statistics.bytes_received as i64,
+        ));
+        context.set_attribute(KeyValue::new(
+            "quic.connection.packets_lost",
+            statistics.packets_lost as i64,
+        ));
+
+        match event.error {
+            Error::Closed { .. } | Error::IdleTimerExpired { .. } => {}
+            error => context.set_status(Status::error(error.to_string())),
+        }
+
+        context.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        provider::io::testing::{test, Model, Random},
+        tests::setup::{start_client, start_server, SERVER_CERTS},
+        Client, Server,
+    };
+    use futures::future::BoxFuture;
+    use opentelemetry::{metrics::MeterProvider as _, trace::TracerProvider as _, Context};
+    use opentelemetry_sdk::{
+        export::trace::{ExportResult, SpanData, SpanExporter},
+        metrics::{
+            data::{ResourceMetrics, Sum, Temporality},
+            reader::{AggregationSelector, MetricProducer, MetricReader, TemporalitySelector},
+            Aggregation, InstrumentKind, ManualReader, MeterProvider, Pipeline,
+        },
+        trace::TracerProvider,
+        Resource,
+    };
+    use s2n_quic_core::{crypto::tls::testing::certificates, stream::testing::Data};
+    use std::sync::{Arc, Mutex, Weak};
+
+    /// Keeps the spans exported by the tracer provider in memory
+    #[derive(Clone, Debug, Default)]
+    struct Spans(Arc<Mutex<Vec<SpanData>>>);
+
+    impl SpanExporter for Spans {
+        fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+            self.0.lock().unwrap().extend(batch);
+            Box::pin(core::future::ready(Ok(())))
+        }
+    }
+
+    /// Shares a [`ManualReader`] with the meter provider so the metrics can be collected in memory
+    #[derive(Clone, Debug)]
+    struct Reader(Arc<ManualReader>);
+
+    impl TemporalitySelector for Reader {
+        fn temporality(&self, kind: InstrumentKind) -> Temporality {
+            self.0.temporality(kind)
+        }
+    }
+
+    impl AggregationSelector for Reader {
+        fn aggregation(&self, kind: InstrumentKind) -> Aggregation {
+            self.0.aggregation(kind)
+        }
+    }
+
+    impl MetricReader for Reader {
+        fn register_pipeline(&self, pipeline: Weak<Pipeline>) {
+            self.0.register_pipeline(pipeline)
+        }
+
+        fn register_producer(&self, producer: Box<dyn MetricProducer>) {
+            self.0.register_producer(producer)
+        }
+
+        fn collect(&self, rm: &mut ResourceMetrics) -> opentelemetry::metrics::Result<()> {
+            self.0.collect(rm)
+        }
+
+        fn force_flush(&self, cx: &Context) -> opentelemetry::metrics::Result<()> {
+            self.0.force_flush(cx)
+        }
+
+        fn shutdown(&self) -> opentelemetry::metrics::Result<()> {
+            self.0.shutdown()
+        }
+    }
+
+    #[test]
+    fn otel_subscriber_test() {
+        let reader = Reader(Arc::new(ManualReader::builder().build()));
+        let meter_provider = MeterProvider::builder().with_reader(reader.clone()).build();
+        let spans = Spans::default();
+        let tracer_provider = TracerProvider::builder()
+            .with_simple_exporter(spans.clone())
+            .build();
+        let subscriber =
+            || Subscriber::new(&meter_provider.meter(SCOPE), tracer_provider.tracer(SCOPE));
+
+        test(Model::default(), |handle| {
+            let server = Server::builder()
+                .with_io(handle.builder().build()?)?
+                .with_random(Random)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(subscriber())?
+                .start()?;
+            let client = Client::builder()
+                .with_io(handle.builder().build()?)?
+                .with_random(Random)?
+                .with_tls(certificates::CERT_PEM)?
+                .with_event(subscriber())?
+                .start()?;
+            let addr = start_server(server)?;
+            start_client(client, addr, Data::new(10_000))?;
+            Ok(addr)
+        })
+        .unwrap();
+
+        // each endpoint records its connection as a span
+        tracer_provider.force_flush();
+        let mut endpoints: Vec<_> = spans
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|span| span.name == "quic.connection")
+            .filter_map(|span| {
+                span.attributes
+                    .iter()
+                    .find(|(key, _)| key.as_str() == "quic.endpoint")
+                    .map(|(_, value)| value.as_str().into_owned())
+            })
+            .collect();
+        endpoints.sort();
+        assert_eq!(endpoints, ["client", "server"]);
+
+        let mut metrics = ResourceMetrics {
+            resource: Resource::empty(),
+            scope_metrics: vec![],
+        };
+        reader.collect(&mut metrics).unwrap();
+        let metrics: Vec<_> = metrics
+            .scope_metrics
+            .iter()
+            .flat_map(|scope| &scope.metrics)
+            .collect();
+
+        for name in [
+            "quic.connection.handshake.duration",
+            "quic.connection.rtt",
+            "quic.connection.congestion_window",
+            "quic.connection.mtu",
+            "quic.stream.bytes_sent",
+            "quic.stream.bytes_received",
+        ] {
+            assert!(
+                metrics.iter().any(|metric| metric.name == name),
+                "missing metric {name}"
+            );
+        }
+
+        // both endpoints count the echoed stream data, attributed to their endpoint type
+        for name in ["quic.stream.bytes_sent", "quic.stream.bytes_received"] {
+            let metric = metrics.iter().find(|metric| metric.name == name).unwrap();
+            let sum = metric.data.as_any().downcast_ref::<Sum<u64>>().unwrap();
+            let mut endpoints: Vec<_> = sum
+                .data_points
+                .iter()
+                .map(|point| {
+                    assert!(point.value >= 10_000, "{name}: {point:?}");
+                    point
+                        .attributes
+                        .iter()
+                        .map(|(key, value)| format!("{}={value}", key.as_str()))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            endpoints.sort();
+            assert_eq!(
+                endpoints,
+                [["quic.endpoint=client"], ["quic.endpoint=server"]],
+                "{name}"
+            );
+        }
+    }
+}
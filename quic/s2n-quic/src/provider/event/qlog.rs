@@ -0,0 +1,370 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Writes a [qlog](https://datatracker.ietf.org/doc/draft-ietf-quic-qlog-main-schema/) trace
+//! for each connection
+//!
+//! The traces use the `JSON-SEQ` serialization of qlog 0.3 and can be loaded into tools like
+//! [qvis](https://qvis.quictools.info/).
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use s2n_quic::{provider::event::qlog, Server};
+//!
+//! let server = Server::builder()
+//!     .with_event(qlog::Subscriber::new("/tmp/qlog"))?
+//!     .with_io("127.0.0.1:443")?
+//!     .start()?;
+//! ```
+
+use crate::provider::event::{self, events, ConnectionInfo, ConnectionMeta};
+use core::{fmt::Write as _, time::Duration};
+use std::{
+    fs::OpenOptions,
+    io::{self, Write as _},
+    path::PathBuf,
+    sync::{mpsc, Arc},
+    thread,
+};
+
+/// The qlog version written in the header of each trace
+const QLOG_VERSION: &str = "0.3";
+
+/// The number of bytes buffered for a connection before they are passed to the writer thread
+const FLUSH_THRESHOLD: usize = 64 * 1024;
+
+/// Writes a qlog trace file for each connection into a directory
+///
+/// Each trace is named `<original destination connection id>-<endpoint>.sqlog`, with the
+/// connection id encoded in hex. Existing files are appended to rather than truncated. The
+/// following events are recorded:
+///
+/// * `transport:packet_sent`, `transport:packet_received` and `transport:packet_dropped`
+/// * `transport:datagram_dropped`
+/// * `recovery:packet_lost` and `recovery:metrics_updated`, which includes the congestion
+///   window and RTT estimates
+/// * `connectivity:mtu_updated` and `connectivity:connection_closed`
+///
+/// Records are buffered in memory for each connection and written by a dedicated thread, so the
+/// endpoint task never blocks on file IO and trace files are only kept open while a chunk of
+/// records is being written. When the subscriber and all of its connections are dropped, the
+/// remaining records are written before the thread exits. Errors creating or writing to the trace
+/// files are ignored.
+#[derive(Clone, Debug)]
+pub struct Subscriber {
+    dir: PathBuf,
+    writer: Writer,
+}
+
+impl Subscriber {
+    /// Creates a subscriber which writes the traces into the provided directory
+    ///
+    /// The directory must exist before the first connection is created.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            writer: Writer::spawn(),
+        }
+    }
+}
+
+/// A chunk of trace records to append to a file
+#[derive(Debug)]
+struct Chunk {
+    path: Arc<PathBuf>,
+    records: String,
+}
+
+impl Chunk {
+    fn write(&self) -> io::Result<()> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&*self.path)?
+            .write_all(self.records.as_bytes())
+    }
+}
+
+/// Passes chunks of records to the writer thread
+#[derive(Clone, Debug)]
+struct Writer {
+    // the sender is declared first so it is dropped before the thread is joined
+    sender: mpsc::Sender<Chunk>,
+    thread: Arc<WriterThread>,
+}
+
+impl Writer {
+    fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel::<Chunk>();
+        let handle = thread::Builder::new()
+            .name("s2n-quic-qlog".into())
+            .spawn(move || {
+                for chunk in receiver {
+                    let _ = chunk.write();
+                }
+            })
+            .expect("failed to spawn the qlog writer thread");
+
+        Self {
+            sender,
+            thread: Arc::new(WriterThread(Some(handle))),
+        }
+    }
+}
+
+/// Waits for the writer thread to write the remaining chunks once the last [`Writer`] is dropped
+#[derive(Debug)]
+struct WriterThread(Option<thread::JoinHandle<()>>);
+
+impl Drop for WriterThread {
+    fn drop(&mut self) {
+        if let Some(handle) = self.0.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The per-connection trace state
+#[derive(Debug)]
+pub struct ConnectionContext {
+    path: Arc<PathBuf>,
+    writer: Writer,
+    start: Duration,
+    records: String,
+}
+
+impl ConnectionContext {
+    /// Writes a single event record to the trace
+    ///
+    /// The `data` closure writes the members of the event's `data` object.
+    fn write(&mut self, meta: &ConnectionMeta, name: &str, data: impl FnOnce(&mut String)) {
+        let time = meta
+            .timestamp
+            .duration_since_start()
+            .saturating_sub(self.start);
+        let time = time.as_secs_f64() * 1000.0;
+
+        let records = &mut self.records;
+        // JSON-SEQ records start with the ASCII record separator
+        records.push('\x1e');
+        let _ = write!(records, "{{\"time\":{time},\"name\":\"{name}\",\"data\":{{");
+        data(records);
+        records.push_str("}}\n");
+
+        if records.len() >= FLUSH_THRESHOLD {
+            self.flush();
+        }
+    }
+
+    /// Passes the buffered records to the writer thread
+    fn flush(&mut self) {
+        if self.records.is_empty() {
+            return;
+        }
+
+        let chunk = Chunk {
+            path: self.path.clone(),
+            records: core::mem::take(&mut self.records),
+        };
+        let _ = self.writer.sender.send(chunk);
+    }
+}
+
+impl Drop for ConnectionContext {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl event::Subscriber for Subscriber {
+    type ConnectionContext = ConnectionContext;
+
+    fn create_connection_context(
+        &mut self,
+        meta: &ConnectionMeta,
+        info: &ConnectionInfo,
+    ) -> Self::ConnectionContext {
+        let vantage_point = match meta.endpoint_type {
+            events::EndpointType::Client { .. } => "client",
+            events::EndpointType::Server { .. } => "server",
+        };
+
+        let mut group_id = String::new();
+        for byte in info.original_destination_connection_id.bytes {
+            let _ = write!(group_id, "{byte:02x}");
+        }
+
+        let path = self.dir.join(format!("{group_id}-{vantage_point}.sqlog"));
+        let records = format!(
+            "\x1e{{\"qlog_version\":\"{QLOG_VERSION}\",\"qlog_format\":\"JSON-SEQ\",\
+            \"title\":\"s2n-quic\",\"trace\":{{\"vantage_point\":{{\"name\":\"s2n-quic\",\
+            \"type\":\"{vantage_point}\"}},\"common_fields\":{{\"group_id\":\"{group_id}\",\
+            \"time_format\":\"relative\"}}}}}}\n",
+        );
+
+        ConnectionContext {
+            path: Arc::new(path),
+            writer: self.writer.clone(),
+            start: meta.timestamp.duration_since_start(),
+            records,
+        }
+    }
+
+    #[inline]
+    fn on_packet_sent(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketSent,
+    ) {
+        context.write(meta, "transport:packet_sent", |data| {
+            write_header(data, &event.packet_header);
+            let _ = write!(data, ",\"raw\":{{\"length\":{}}}", event.packet_len);
+        });
+    }
+
+    #[inline]
+    fn on_packet_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketReceived,
+    ) {
+        context.write(meta, "transport:packet_received", |data| {
+            write_header(data, &event.packet_header);
+        });
+    }
+
+    #[inline]
+    fn on_packet_dropped(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketDropped,
+    ) {
+        use events::PacketDropReason as Reason;
+
+        let trigger = match &event.reason {
+            Reason::ConnectionError { .. } => "internal_error",
+            Reason::HandshakeNotComplete { .. } => "key_unavailable",
+            Reason::VersionMismatch { .. } => "unsupported",
+            Reason::UnprotectFailed { .. } | Reason::DecryptionFailed { .. } => {
+                "decryption_failure"
+            }
+            Reason::RetryDiscarded { .. } => "rejected",
+            Reason::ConnectionIdMismatch { .. }
+            | Reason::DecodingFailed { .. }
+            | Reason::NonEmptyRetryToken { .. }
+            | Reason::UndersizedInitialPacket { .. } => "invalid",
+            _ => "general",
+        };
+
+        context.write(meta, "transport:packet_dropped", |data| {
+            if let Reason::DecryptionFailed { packet_header, .. } = &event.reason {
+                write_header(data, packet_header);
+                data.push(',');
+            }
+            let _ = write!(data, "\"trigger\":\"{trigger}\"");
+        });
+    }
+
+    #[inline]
+    fn on_datagram_dropped(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::DatagramDropped,
+    ) {
+        context.write(meta, "transport:datagram_dropped", |data| {
+            let _ = write!(data, "\"raw\":{{\"length\":{}}}", event.len);
+        });
+    }
+
+    #[inline]
+    fn on_packet_lost(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketLost,
+    ) {
+        context.write(meta, "recovery:packet_lost", |data| {
+            write_header(data, &event.packet_header);
+        });
+    }
+
+    #[inline]
+    fn on_recovery_metrics(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::RecoveryMetrics,
+    ) {
+        context.write(meta, "recovery:metrics_updated", |data| {
+            let _ = write!(
+                data,
+                "\"min_rtt\":{},\"smoothed_rtt\":{},\"latest_rtt\":{},\"rtt_variance\":{},\
+                \"pto_count\":{},\"congestion_window\":{},\"bytes_in_flight\":{}",
+                millis(event.min_rtt),
+                millis(event.smoothed_rtt),
+                millis(event.latest_rtt),
+                millis(event.rtt_variance),
+                event.pto_count,
+                event.congestion_window,
+                event.bytes_in_flight,
+            );
+        });
+    }
+
+    #[inline]
+    fn on_mtu_updated(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::MtuUpdated,
+    ) {
+        context.write(meta, "connectivity:mtu_updated", |data| {
+            let _ = write!(data, "\"new\":{}", event.mtu);
+        });
+    }
+
+    #[inline]
+    fn on_connection_closed(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::ConnectionClosed,
+    ) {
+        context.write(meta, "connectivity:connection_closed", |_data| {});
+
+        // the trace is complete so pass the remaining records to the writer thread
+        context.flush();
+    }
+}
+
+/// Writes the qlog `header` member for a packet
+fn write_header(data: &mut String, header: &events::PacketHeader) {
+    use events::PacketHeader as Header;
+
+    let (packet_type, packet_number) = match header {
+        Header::Initial { number, .. } => ("initial", Some(number)),
+        Header::Handshake { number, .. } => ("handshake", Some(number)),
+        Header::ZeroRtt { number, .. } => ("0RTT", Some(number)),
+        Header::OneRtt { number, .. } => ("1RTT", Some(number)),
+        Header::Retry { .. } => ("retry", None),
+        Header::VersionNegotiation { .. } => ("version_negotiation", None),
+        Header::StatelessReset { .. } => ("stateless_reset", None),
+        _ => ("unknown", None),
+    };
+
+    let _ = write!(data, "\"header\":{{\"packet_type\":\"{packet_type}\"");
+    if let Some(packet_number) = packet_number {
+        let _ = write!(data, ",\"packet_number\":{packet_number}");
+    }
+    data.push('}');
+}
+
+/// qlog durations are expressed in milliseconds
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
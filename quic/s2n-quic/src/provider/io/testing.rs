@@ -24,11 +24,62 @@ impl super::Provider for Io {
     }
 }
 
+/// A random provider which derives its values from the seed of the current test
+///
+/// Endpoints configured with this provider generate the same connection IDs, stateless reset
+/// tokens, and other random values each time a test is run with the same seed. The endpoints
+/// draw from a separate generator than the network model, so configuring it doesn't change the
+/// decisions made by the network.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Random;
+
+impl crate::provider::random::Provider for Random {
+    type Generator = Self;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Generator, Self::Error> {
+        Ok(self)
+    }
+}
+
+impl s2n_quic_core::random::Generator for Random {
+    fn public_random_fill(&mut self, dest: &mut [u8]) {
+        Self::fill(dest)
+    }
+
+    fn private_random_fill(&mut self, dest: &mut [u8]) {
+        Self::fill(dest)
+    }
+}
+
+impl Random {
+    fn fill(dest: &mut [u8]) {
+        assert!(
+            testing::rand::fill_endpoint_bytes(dest),
+            "the testing random provider can only be used within a test"
+        );
+    }
+}
+
+/// The seed used by [`test`] when `S2N_QUIC_TEST_SEED` is not set
+pub const DEFAULT_SEED: u64 = 123456789;
+
 /// Runs a single test with the given network
 ///
+/// The seed is read from the `S2N_QUIC_TEST_SEED` environment variable, if set, so a failure
+/// can be replayed with the seed it printed. Otherwise, [`DEFAULT_SEED`] is used.
+///
 /// Returns the total runtime of the test
 pub fn test<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(network: N, f: F) -> Result<Duration> {
-    test_seed(network, 123456789, f)
+    let seed = std::env::var("S2N_QUIC_TEST_SEED")
+        .ok()
+        .map(|seed| {
+            seed.parse()
+                .unwrap_or_else(|err| panic!("invalid S2N_QUIC_TEST_SEED {seed:?}: {err}"))
+        })
+        .unwrap_or(DEFAULT_SEED);
+
+    test_with_seed(seed, network, f)
 }
 
 /// Runs a single test with the given network and seed value
@@ -39,6 +90,36 @@ pub fn test_seed<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(
     seed: u64,
     f: F,
 ) -> Result<Duration> {
+    test_with_seed(seed, network, f)
+}
+
+/// Runs a single test with the given seed value and network
+///
+/// The seed controls the decisions made by the network model, as well as the connection IDs
+/// and random values generated by endpoints configured with the [`Random`] provider. If the
+/// test fails, the seed is printed so it can be replayed.
+///
+/// Returns the total runtime of the test
+pub fn test_with_seed<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(
+    seed: u64,
+    network: N,
+    f: F,
+) -> Result<Duration> {
+    struct SeedReporter {
+        seed: u64,
+        is_ok: bool,
+    }
+
+    impl Drop for SeedReporter {
+        fn drop(&mut self) {
+            if !self.is_ok {
+                eprintln!("test failed with S2N_QUIC_TEST_SEED={}", self.seed);
+            }
+        }
+    }
+
+    let mut reporter = SeedReporter { seed, is_ok: false };
+
     let mut executor = Executor::new(network, seed);
     let handle = executor.handle().clone();
 
@@ -51,5 +132,7 @@ pub fn test_seed<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(
 
     let now = unsafe { now.as_duration() };
 
+    reporter.is_ok = true;
+
     Ok(now)
 }
@@ -4,13 +4,17 @@
 //! Provides an implementation of the [`io::Provider`](crate::provider::io::Provider)
 //! using the [`Tokio runtime`](https://docs.rs/tokio/latest/tokio/runtime/index.html)
 
-use s2n_quic_core::{endpoint::Endpoint, inet::SocketAddress};
+use s2n_quic_core::{endpoint::Endpoint, inet::SocketAddress, time::ClockWithTimer};
 use s2n_quic_platform::io::tokio;
 use std::io;
 
-pub use self::tokio::{Builder, Io as Provider};
+pub use self::tokio::{Builder, EcnMode, Io as Provider};
 
-impl super::Provider for Provider {
+impl<C> super::Provider for Provider<C>
+where
+    C: 'static + ClockWithTimer + Send + Sync,
+    C::Timer: Send,
+{
     type PathHandle = tokio::PathHandle;
     type Error = io::Error;
 
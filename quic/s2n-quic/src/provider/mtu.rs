@@ -0,0 +1,25 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use s2n_quic_core::path::mtu::{default, Endpoint, MtuController, PathInfo};
+
+/// Provides MTU probing support for an endpoint
+pub trait Provider {
+    type Endpoint: Endpoint;
+    type Error: 'static + core::fmt::Display + Send + Sync;
+
+    fn start(self) -> Result<Self::Endpoint, Self::Error>;
+}
+
+pub type Default = default::Endpoint;
+
+impl_provider_utils!();
+
+impl<T: Endpoint> Provider for T {
+    type Endpoint = T;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Endpoint, Self::Error> {
+        Ok(self)
+    }
+}
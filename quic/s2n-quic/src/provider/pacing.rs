@@ -0,0 +1,30 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use s2n_quic_core::recovery::pacer::{default, disabled, Endpoint, Pacer, PathInfo};
+
+/// Provides packet pacing support for an endpoint
+pub trait Provider {
+    type Endpoint: Endpoint;
+    type Error: 'static + core::fmt::Display + Send + Sync;
+
+    fn start(self) -> Result<Self::Endpoint, Self::Error>;
+}
+
+/// Paces packets according to the congestion controller
+pub type Default = default::Endpoint;
+
+/// Transmits packets as soon as the congestion window allows, without pacing
+#[cfg_attr(not(feature = "unstable-provider-pacing"), allow(dead_code))]
+pub type NoPacing = disabled::Endpoint;
+
+impl_provider_utils!();
+
+impl<T: Endpoint> Provider for T {
+    type Endpoint = T;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Endpoint, Self::Error> {
+        Ok(self)
+    }
+}
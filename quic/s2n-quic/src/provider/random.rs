@@ -1,6 +1,16 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+//! Provides random number generation support for an endpoint
+//!
+//! All of the randomness generated by the endpoint is drawn from the configured provider,
+//! including connection IDs and stateless reset tokens produced by the default providers,
+//! address token nonces and keys, and path challenges. Custom connection ID formats and stateless
+//! reset token generators can do the same by implementing `generate_with_random`.
+//!
+//! Randomness used by the TLS handshake, such as the ClientHello random and key shares, remains
+//! the responsibility of the TLS provider.
+
 pub use s2n_quic_core::random::Generator;
 
 /// Provides random number generation support for an endpoint
@@ -33,12 +33,17 @@ pub trait Provider: 'static {
 
 pub use random::Provider as Default;
 
+#[cfg(feature = "s2n-quic-crypto")]
+pub use keyed::Keyed;
+
 impl_provider_utils!();
 
 mod random {
     use core::convert::Infallible;
     use rand::prelude::*;
-    use s2n_quic_core::{frame::new_connection_id::STATELESS_RESET_TOKEN_LEN, stateless_reset};
+    use s2n_quic_core::{
+        frame::new_connection_id::STATELESS_RESET_TOKEN_LEN, random, stateless_reset,
+    };
 
     /// Randomly generated stateless reset token.
     ///
@@ -86,6 +91,16 @@ mod random {
             rand::thread_rng().fill_bytes(&mut token);
             token.into()
         }
+
+        fn generate_with_random(
+            &mut self,
+            _local_connection_id: &[u8],
+            random_generator: &mut dyn random::Generator,
+        ) -> stateless_reset::Token {
+            let mut token = [0u8; STATELESS_RESET_TOKEN_LEN];
+            random_generator.private_random_fill(&mut token);
+            token.into()
+        }
     }
 
     #[cfg(test)]
@@ -105,3 +120,80 @@ mod random {
         }
     }
 }
+
+#[cfg(feature = "s2n-quic-crypto")]
+mod keyed {
+    use core::convert::Infallible;
+    use s2n_quic_core::{frame::new_connection_id::STATELESS_RESET_TOKEN_LEN, stateless_reset};
+    use s2n_quic_crypto::hmac;
+
+    /// Stateless reset tokens derived from a secret key
+    ///
+    /// The token for a connection ID is the HMAC-SHA256 of the connection ID, truncated to
+    /// the length of a stateless reset token. Endpoints configured with the same secret generate
+    /// the same token for a given connection ID, so a stateless reset sent by any endpoint in a
+    /// fleet is accepted by the peer of a connection established with another endpoint.
+    ///
+    /// The secret must be kept private and should be difficult to guess, since anyone with
+    /// knowledge of it can terminate the connections of the endpoints using it.
+    pub struct Keyed {
+        key: hmac::Key,
+    }
+
+    impl Keyed {
+        /// Creates a generator that derives tokens from the given secret
+        pub fn new(secret: &[u8]) -> Self {
+            Self {
+                key: hmac::Key::new(hmac::HMAC_SHA256, secret),
+            }
+        }
+    }
+
+    impl core::fmt::Debug for Keyed {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // don't include the key in the output
+            f.debug_struct("Keyed").finish_non_exhaustive()
+        }
+    }
+
+    impl super::Provider for Keyed {
+        type Generator = Self;
+        type Error = Infallible;
+
+        fn start(self) -> Result<Self::Generator, Self::Error> {
+            Ok(self)
+        }
+    }
+
+    impl stateless_reset::token::Generator for Keyed {
+        fn generate(&mut self, local_connection_id: &[u8]) -> stateless_reset::Token {
+            let tag = hmac::sign(&self.key, local_connection_id);
+            let mut token = [0u8; STATELESS_RESET_TOKEN_LEN];
+            token.copy_from_slice(&tag.as_ref()[..STATELESS_RESET_TOKEN_LEN]);
+            token.into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use s2n_quic_core::{connection, stateless_reset::token::Generator as _};
+
+        #[test]
+        fn keyed_stateless_reset_token_test() {
+            let id_1 = connection::LocalId::try_from_bytes(b"id01").unwrap();
+            let id_2 = connection::LocalId::try_from_bytes(b"id02").unwrap();
+
+            let mut generator = Keyed::new(b"secret");
+            let token = generator.generate(id_1.as_bytes());
+
+            // the token is stable for a connection ID
+            assert_eq!(token, generator.generate(id_1.as_bytes()));
+            assert_ne!(token, generator.generate(id_2.as_bytes()));
+
+            // endpoints sharing the secret generate the same token
+            assert_eq!(token, Keyed::new(b"secret").generate(id_1.as_bytes()));
+            assert_ne!(token, Keyed::new(b"other").generate(id_1.as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use s2n_quic_core::transport::parameters::{
+    interceptor::{Disabled, Interceptor as TransportParametersInterceptor},
+    ClientTransportParameters, ServerTransportParameters, TransportParameters,
+};
+
+/// Provides transport parameters interceptor support for an endpoint
+pub trait Provider: 'static {
+    type TransportParametersInterceptor: 'static + TransportParametersInterceptor;
+    type Error: core::fmt::Display + Send + Sync;
+
+    fn start(self) -> Result<Self::TransportParametersInterceptor, Self::Error>;
+}
+
+pub type Default = Disabled;
+
+impl_provider_utils!();
+
+impl<T: 'static + Send + TransportParametersInterceptor> Provider for T {
+    type TransportParametersInterceptor = T;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::TransportParametersInterceptor, Self::Error> {
+        Ok(self)
+    }
+}
@@ -1,10 +1,14 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{connection::Connection, provider::*};
+use crate::{
+    connection::{self, Connection},
+    provider::*,
+};
 use core::{
     fmt,
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_transport::endpoint::handle::Acceptor;
 
@@ -14,6 +18,7 @@ mod providers;
 pub use builder::*;
 pub use providers::*;
 pub use s2n_quic_core::application::ServerName as Name;
+pub use s2n_quic_transport::endpoint::close::ShutdownMode;
 
 /// A QUIC server endpoint, capable of accepting connections
 pub struct Server {
@@ -101,6 +106,77 @@ impl Server {
         }
     }
 
+    /// Returns the number of connections that have completed the handshake and are still open
+    ///
+    /// Connections are counted until the endpoint stops tracking them, which includes the time
+    /// spent in the closing and draining states. The value is read from an atomic counter, so
+    /// it's cheap to call frequently (for example, when reporting load for autoscaling).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::{error::Error, path::Path};
+    /// # use s2n_quic::Server;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let server = Server::builder()
+    ///     .with_tls((Path::new("./certs/cert.pem"), Path::new("./certs/key.pem")))?
+    ///     .with_io("127.0.0.1:443")?
+    ///     .start()?;
+    ///
+    /// println!("active connections: {}", server.active_connection_count());
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn active_connection_count(&self) -> usize {
+        self.acceptor.active_connection_count()
+    }
+
+    /// Stops accepting new connections and waits for the open connections to close
+    ///
+    /// With [`ShutdownMode::Immediate`], all of the open connections are closed right away.
+    /// With [`ShutdownMode::GracefulDrain`], the open connections can continue to transfer
+    /// data until the application or the peer closes them. Any connections still open after
+    /// `timeout` are closed with [`connection::Error::GracefulCloseTimedOut`], which notifies the
+    /// peer with an `APPLICATION_ERROR` code rather than `NO_ERROR`.
+    ///
+    /// The server can't accept connections while it's shutting down. Connections that were
+    /// established but not yet accepted are closed along with the other open connections, so
+    /// they should be accepted before calling `shutdown` if the application wants to handle them.
+    ///
+    /// QUIC doesn't provide a transport-level signal for asking clients to move to another
+    /// server. Applications that want clients to migrate elsewhere should notify them with their
+    /// own protocol (for example, an HTTP/3 GOAWAY frame) before or while draining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::{error::Error, path::Path, time::Duration};
+    /// # use s2n_quic::{server::ShutdownMode, Server};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut server = Server::builder()
+    ///     .with_tls((Path::new("./certs/cert.pem"), Path::new("./certs/key.pem")))?
+    ///     .with_io("127.0.0.1:443")?
+    ///     .start()?;
+    ///
+    /// server
+    ///     .shutdown(ShutdownMode::GracefulDrain, Duration::from_secs(30))
+    ///     .await?;
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(
+        &mut self,
+        mode: ShutdownMode,
+        timeout: Duration,
+    ) -> Result<(), connection::Error> {
+        futures::future::poll_fn(|cx| self.acceptor.poll_shutdown(mode, timeout, cx)).await
+    }
+
     /// Returns the local address that this listener is bound to.
     ///
     /// This can be useful, for example, when binding to port `0` to figure out which
@@ -146,6 +146,28 @@ impl<Providers: ServerProviders> Builder<Providers> {
         /// #    Ok(())
         /// # }
         /// ```
+        ///
+        /// Requires every peer to complete a Retry round trip before any connection state is
+        /// allocated. The Retry tokens are generated and validated by the
+        /// [`address_token`](crate::provider::address_token) provider.
+        ///
+        /// ```rust,no_run
+        /// # use std::{error::Error, time::Duration};
+        /// use s2n_quic::{Server, provider::endpoint_limits};
+        ///
+        /// # #[tokio::main]
+        /// # async fn main() -> Result<(), Box<dyn Error>> {
+        /// let limits = endpoint_limits::Default::builder()
+        ///     .with_retry(true)?
+        ///     .build()?;
+        ///
+        /// let server = Server::builder()
+        ///     .with_endpoint_limits(limits)?
+        ///     .start()?;
+        /// #
+        /// #    Ok(())
+        /// # }
+        /// ```
         with_endpoint_limits,
         endpoint_limits,
         ServerProviders
@@ -154,6 +176,11 @@ impl<Providers: ServerProviders> Builder<Providers> {
     impl_provider_method!(
         /// Sets the event provider for the [`Server`]
         ///
+        /// Multiple subscribers can be composed with a tuple or, when the set of subscribers is
+        /// only known at runtime, a `Vec` of subscribers which are called in order. Subscribers of
+        /// different types can be stored in the same `Vec` by boxing them as
+        /// `Box<dyn DynSubscriber>`.
+        ///
         /// # Examples
         ///
         /// Sets a custom event subscriber for the server
@@ -179,6 +206,10 @@ impl<Providers: ServerProviders> Builder<Providers> {
     impl_provider_method!(
         /// Sets the token provider for the [`Server`]
         ///
+        /// The provider generates and validates the tokens sent in Retry packets and NEW_TOKEN
+        /// frames. Since the tokens are handed back by the peer, the provider can keep them
+        /// stateless by authenticating their contents rather than storing them.
+        ///
         /// # Examples
         ///
         /// Sets a custom token provider for the server
@@ -303,6 +334,40 @@ impl<Providers: ServerProviders> Builder<Providers> {
         ServerProviders
     );
 
+    #[cfg(any(test, feature = "unstable-provider-transport-parameters-interceptor"))]
+    impl_provider_method!(
+        /// Sets the transport parameters interceptor provider for the [`Server`]
+        ///
+        /// The provider can inspect and modify the transport parameters before they are
+        /// sent to the peer.
+        with_transport_parameters_interceptor,
+        transport_parameters_interceptor,
+        ServerProviders
+    );
+
+    #[cfg(any(test, feature = "unstable-provider-mtu"))]
+    impl_provider_method!(
+        /// Sets the MTU provider for the [`Server`]
+        ///
+        /// The provider selects the sizes used when probing each path for a larger
+        /// maximum transmission unit (MTU). By default, a binary search between the
+        /// currently confirmed MTU and the configured max MTU is performed.
+        with_mtu,
+        mtu,
+        ServerProviders
+    );
+
+    #[cfg(any(test, feature = "unstable-provider-pacing"))]
+    impl_provider_method!(
+        /// Sets the pacing provider for the [`Server`]
+        ///
+        /// The provider schedules the departure of packets on each path. By default,
+        /// packets are paced according to the congestion controller.
+        with_pacing,
+        pacing,
+        ServerProviders
+    );
+
     #[cfg(any(test, feature = "unstable-provider-random"))]
     impl_provider_method!(
         /// Sets the random provider for the [`Server`]
@@ -311,7 +376,7 @@ impl<Providers: ServerProviders> Builder<Providers> {
         ServerProviders
     );
 
-    #[cfg(feature = "unstable-provider-datagram")]
+    #[cfg(any(test, feature = "unstable-provider-datagram"))]
     impl_provider_method!(
         /// Sets the datagram provider for the [`Server`]
         with_datagram,
@@ -285,6 +285,68 @@ macro_rules! impl_send_stream_api {
             $dispatch_body
         }
 
+        /// Enqueues a final chunk of data and marks the stream as finished.
+        ///
+        /// This is equivalent to calling [`send`](Self::send) followed by [`finish`](Self::finish),
+        /// except the data and the end of the stream can be transmitted together in a single frame.
+        /// The returned future resolves once the data is enqueued, not when it is acknowledged by
+        /// the peer.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok(())` if the data was enqueued for sending and the stream was finished.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// let data = bytes::Bytes::from_static(&[1, 2, 3, 4]);
+        /// stream.send_finish(data).await?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn send_finish(&mut self, mut data: bytes::Bytes) -> $crate::stream::Result<()> {
+            ::futures::future::poll_fn(|cx| self.poll_send_finish(&mut data, cx)).await
+        }
+
+        /// Enqueues a final chunk of data and marks the stream as finished.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Poll::Pending` if the stream's send buffer capacity is currently exhausted. In this case,
+        ///   the caller should retry sending after the [`Waker`](core::task::Waker) on the provided
+        ///   [`Context`](core::task::Context) is notified.
+        /// - `Poll::Ready(Ok(()))` if the data was enqueued for sending and the stream was finished.
+        ///   The provided `chunk` will be replaced with an empty [`Bytes`](bytes::Bytes).
+        /// - `Poll::Ready(Err(e))` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        #[inline]
+        pub fn poll_send_finish(
+            &mut self,
+            chunk: &mut bytes::Bytes,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::stream::Result<()>> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable()).into()
+                };
+                ($variant: expr) => {
+                    $variant.poll_send_finish(chunk, cx)
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
         /// Marks the stream as finished and waits for all outstanding data to be acknowledged.
         ///
         /// This method is equivalent to calling [`finish`](Self::finish) and [`flush`](Self::flush).
@@ -376,6 +438,92 @@ macro_rules! impl_send_stream_api {
             let $stream = self;
             $dispatch_body
         }
+
+        /// Sets the transmission priority of the stream.
+        ///
+        /// When multiple streams have data ready to send, streams with a higher priority are
+        /// transmitted first and get to use the available congestion window before streams with
+        /// a lower priority. Streams with the same priority share the available capacity in a
+        /// round-robin fashion. Streams start with a priority of `0`.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        /// - `Ok(())` if the priority was updated.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// // send data on this stream before streams with the default priority
+        /// stream.set_priority(1)?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn set_priority(&mut self, priority: u8) -> $crate::stream::Result<()> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable())
+                };
+                ($variant: expr) => {
+                    $variant.set_priority(priority)
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
+        /// Sets the amount of time data written to the stream has to be acknowledged by the peer.
+        ///
+        /// The deadline is measured from the time the data is written and applies to all data
+        /// written after this call. If written data isn't acknowledged before the deadline
+        /// expires, for example because it's blocked behind a stalled congestion window, the
+        /// stream is reset with the provided `error_code` and the next call on the stream
+        /// returns an error.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        /// - `Ok(())` if the deadline was updated.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   use core::time::Duration;
+        /// #   let mut stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// const DEADLINE_EXPIRED: u32 = 1;
+        /// stream.set_send_deadline(Duration::from_millis(250), DEADLINE_EXPIRED.into())?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn set_send_deadline(
+            &mut self,
+            deadline: core::time::Duration,
+            error_code: $crate::application::Error,
+        ) -> $crate::stream::Result<()> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable())
+                };
+                ($variant: expr) => {
+                    $variant.set_send_deadline(deadline, error_code)
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
     };
 }
 
@@ -24,16 +24,60 @@ use std::{
 #[macro_use]
 mod recorder;
 
-mod setup;
+pub(crate) mod setup;
 use setup::*;
 
+mod accept_rate_limit;
+mod accept_timeout;
+mod ack_frequency;
+mod active_connections;
+mod address_filter;
+mod alpn;
+mod amplification_limit;
 mod blackhole;
+mod close_reason;
+mod congestion_controller;
+mod connection_id;
 mod connection_migration;
+mod datagram;
+mod dead_peer;
+mod ecn;
+mod event_json;
+mod event_qlog;
+mod flow_control;
+mod graceful_close;
+mod grease;
+mod handshake_progress;
+mod idle_timeout;
+mod immediate_ack;
+mod initial_rtt;
 mod interceptor;
+mod keep_alive;
+mod key_update;
+mod max_ack_delay;
+mod max_lifetime;
+mod max_pto;
 mod mtu;
 mod no_tls;
+mod pacing;
 mod pto;
+mod reorder;
+mod retry;
 mod self_test;
+mod send_deadline;
+mod send_finish;
+mod send_vectored;
+mod server_name;
+mod server_shutdown;
+mod spin_bit;
+mod stateless_reset;
+mod statistics;
+mod stop_sending;
+mod stream_limits;
+mod stream_priority;
+mod stream_reset;
+mod subscriber_list;
+mod transport_parameters;
 
 // TODO: https://github.com/aws/s2n-quic/issues/1726
 //
@@ -44,6 +88,8 @@ mod self_test;
 mod client_handshake_confirm;
 #[cfg(not(target_os = "windows"))]
 mod mtls;
+#[cfg(not(target_os = "windows"))]
+mod ocsp;
 
 mod issue_1361;
 mod issue_1427;
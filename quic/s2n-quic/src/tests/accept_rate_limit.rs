@@ -0,0 +1,62 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::endpoint_limits;
+
+const CONNECTIONS: usize = 20;
+const BURST: u32 = 5;
+
+/// Connection attempts beyond the accept rate limit are dropped and eventually accepted once
+/// the limiter has capacity again
+#[test]
+fn accept_rate_limit_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+    let subscriber = recorder::EndpointPacketDropped::new();
+    let drop_events = subscriber.events();
+    let connected = Arc::new(Mutex::new(0));
+
+    test(model, |handle| {
+        let limits = endpoint_limits::Default::builder()
+            .with_accept_rate_limit(10, BURST)?
+            .build()?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .with_endpoint_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+        let client = build_client(handle)?;
+
+        // flood the server with connection attempts
+        for _ in 0..CONNECTIONS {
+            let client = client.clone();
+            let connected = connected.clone();
+            primary::spawn(async move {
+                let connect = Connect::new(addr).with_server_name("localhost");
+                let connection = client.connect(connect).await.unwrap();
+                *connected.lock().unwrap() += 1;
+
+                // hold the connection open until every attempt has finished
+                delay(Duration::from_secs(5)).await;
+                connection.close(0u32.into());
+            });
+        }
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let drop_events = drop_events.lock().unwrap();
+    let rate_limited = drop_events
+        .iter()
+        .filter(|reason| **reason == recorder::PacketDropReason::RateLimited)
+        .count();
+    assert!(
+        rate_limited >= CONNECTIONS - BURST as usize,
+        "{rate_limited} attempts were rate limited"
+    );
+    assert_eq!(*connected.lock().unwrap(), CONNECTIONS);
+}
@@ -0,0 +1,129 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::now;
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Accepting a stream with a timeout reports a timeout if no stream arrives in time and leaves
+/// the connection open
+#[test]
+fn accept_stream_timeout_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    let timed_out = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let timed_out = timed_out.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+
+            let start = now();
+            let result = connection.accept_stream_timeout(TIMEOUT).await;
+            assert!(
+                matches!(result, Err(crate::connection::AcceptTimeoutError::TimedOut)),
+                "{result:?}"
+            );
+            *timed_out.lock().unwrap() = Some(now() - start);
+
+            // the connection is still usable after the timeout
+            let stream = connection
+                .accept_stream_timeout(Duration::from_secs(10))
+                .await
+                .unwrap()
+                .unwrap();
+            let mut stream = match stream {
+                crate::stream::PeerStream::Bidirectional(stream) => stream,
+                stream => panic!("unexpected stream: {stream:?}"),
+            };
+            let chunk = stream.receive().await.unwrap().unwrap();
+            stream.send(chunk).await.unwrap();
+            stream.finish().unwrap();
+
+            // keep the connection open for the client
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // open a stream after the server's accept timeout elapses
+            delay(TIMEOUT * 2).await;
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            assert_eq!(
+                stream.receive().await.unwrap(),
+                Some(Bytes::from_static(b"hello"))
+            );
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let timed_out = timed_out.lock().unwrap().take().unwrap();
+    assert!(timed_out >= TIMEOUT, "{timed_out:?}");
+    assert!(timed_out < TIMEOUT * 2, "{timed_out:?}");
+}
+
+/// Dropping an `accept_stream_timeout` future doesn't shorten the timeout of the next call
+#[test]
+fn accept_stream_timeout_dropped_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    let timed_out = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let addr = server.local_addr()?;
+
+        let timed_out = timed_out.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+
+            // abandon the first call half way through its timeout
+            {
+                let accept = connection.accept_stream_timeout(TIMEOUT);
+                let abandon = delay(TIMEOUT / 2);
+                futures::pin_mut!(accept, abandon);
+                let result = futures::future::select(accept, abandon).await;
+                assert!(matches!(result, futures::future::Either::Right(_)));
+            }
+
+            let start = now();
+            let result = connection.accept_stream_timeout(TIMEOUT).await;
+            assert!(
+                matches!(result, Err(crate::connection::AcceptTimeoutError::TimedOut)),
+                "{result:?}"
+            );
+            *timed_out.lock().unwrap() = Some(now() - start);
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            // keep the connection open until the server's timeouts elapse
+            delay(TIMEOUT * 3).await;
+            drop(connection);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let timed_out = timed_out.lock().unwrap().take().unwrap();
+    assert!(timed_out >= TIMEOUT, "{timed_out:?}");
+}
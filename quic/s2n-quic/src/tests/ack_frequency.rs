@@ -0,0 +1,65 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// Transfers data between a client and server with the ACK_FREQUENCY extension configured on
+/// both endpoints and returns the number of ACK frames sent, along with the applied updates
+fn run(enabled: bool) -> (usize, Vec<events::AckFrequencyUpdated>) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let ack_frames = recorder::AckFrameSent::new();
+    let ack_frame_events = ack_frames.events();
+    let updates = recorder::AckFrequencyUpdated::new();
+    let update_events = updates.events();
+    let limits = provider::limits::Limits::default()
+        .with_ack_frequency(enabled)
+        .unwrap();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), (ack_frames.clone(), updates.clone())))?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event((events(), (ack_frames, updates)))?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let ack_frames = ack_frame_events.lock().unwrap().len();
+    let updates = update_events.lock().unwrap().clone();
+    (ack_frames, updates)
+}
+
+#[test]
+fn ack_frequency_test() {
+    let (disabled_acks, disabled_updates) = run(false);
+    let (enabled_acks, enabled_updates) = run(true);
+
+    // the default ACK rate is used when the extension is disabled
+    assert!(disabled_updates.is_empty(), "{disabled_updates:?}");
+
+    // each endpoint applies the request from its peer
+    assert_eq!(enabled_updates.len(), 2, "{enabled_updates:?}");
+    for update in &enabled_updates {
+        assert_eq!(update.sequence_number, 0);
+        assert!(update.ack_eliciting_threshold > 9);
+    }
+
+    assert!(
+        enabled_acks < disabled_acks,
+        "enabled: {enabled_acks}, disabled: {disabled_acks}"
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+#[test]
+fn active_connection_count_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let counts = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let client = build_client(handle)?;
+        let addr = server.local_addr()?;
+
+        let server_counts = counts.clone();
+        primary::spawn(async move {
+            server_counts
+                .lock()
+                .unwrap()
+                .push(server.active_connection_count());
+
+            let mut connections = vec![];
+            for _ in 0..3 {
+                connections.push(server.accept().await.unwrap());
+                server_counts
+                    .lock()
+                    .unwrap()
+                    .push(server.active_connection_count());
+            }
+
+            // close the connections and give the endpoint time to stop tracking them
+            drop(connections);
+            delay(Duration::from_secs(2)).await;
+
+            server_counts
+                .lock()
+                .unwrap()
+                .push(server.active_connection_count());
+        });
+
+        primary::spawn(async move {
+            let mut connections = vec![];
+            for _ in 0..3 {
+                let connect = Connect::new(addr).with_server_name("localhost");
+                connections.push(client.connect(connect).await.unwrap());
+            }
+
+            // wait for the server to close the connections
+            for connection in &mut connections {
+                while let Ok(Some(_)) = connection.accept().await {}
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let counts = counts.lock().unwrap().clone();
+    assert_eq!(counts, [0, 1, 2, 3, 0]);
+}
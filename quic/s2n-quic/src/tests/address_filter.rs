@@ -0,0 +1,68 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::endpoint_limits::{ConnectionAttempt, Limiter, Outcome};
+use std::net::IpAddr;
+
+/// Denies connection attempts from a single IP address
+struct AddressFilter {
+    denied: Arc<Mutex<Option<IpAddr>>>,
+}
+
+impl Limiter for AddressFilter {
+    fn on_connection_attempt(&mut self, _info: &ConnectionAttempt) -> Outcome {
+        Outcome::allow()
+    }
+
+    fn accept_connection(&mut self, remote_address: &events::SocketAddress) -> bool {
+        let remote_address = SocketAddr::from(remote_address.clone());
+        *self.denied.lock().unwrap() != Some(remote_address.ip())
+    }
+}
+
+/// Connection attempts from a filtered address are dropped and never complete a handshake
+#[test]
+fn address_filter_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+    let subscriber = recorder::EndpointPacketDropped::new();
+    let drop_events = subscriber.events();
+    let denied = Arc::new(Mutex::new(None));
+    let results = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .with_endpoint_limits(AddressFilter {
+                denied: denied.clone(),
+            })?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let allowed = build_client(handle)?;
+        let filtered = build_client(handle)?;
+        *denied.lock().unwrap() = Some(filtered.local_addr()?.ip());
+
+        for (name, client) in [("allowed", allowed), ("filtered", filtered)] {
+            let results = results.clone();
+            primary::spawn(async move {
+                let connect = Connect::new(addr).with_server_name("localhost");
+                let result = client.connect(connect).await;
+                results.lock().unwrap().push((name, result.is_ok()));
+            });
+        }
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let mut results = results.lock().unwrap().clone();
+    results.sort();
+    assert_eq!(results, [("allowed", true), ("filtered", false)]);
+
+    let drop_events = drop_events.lock().unwrap();
+    assert!(drop_events.contains(&recorder::PacketDropReason::AddressFiltered));
+}
@@ -0,0 +1,84 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::tls;
+
+// the server and client each offer two protocols, with only one in common
+const SERVER_PROTOCOLS: [&str; 2] = ["hq-interop", "netbench"];
+const CLIENT_PROTOCOLS: [&str; 2] = ["h3", "netbench"];
+
+#[test]
+fn alpn_test() {
+    let model = Model::default();
+    let protocols = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let server_tls = tls::default::Server::builder()
+            .with_certificate(certificates::CERT_PEM, certificates::KEY_PEM)?
+            .with_application_protocols(SERVER_PROTOCOLS.iter())?
+            .build()?;
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let server_protocols = protocols.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let protocol = connection.application_protocol().unwrap();
+
+            // dispatch to a handler based on the negotiated protocol
+            match &protocol[..] {
+                b"netbench" => {
+                    let mut stream = connection
+                        .accept_bidirectional_stream()
+                        .await
+                        .unwrap()
+                        .unwrap();
+                    while let Some(chunk) = stream.receive().await.unwrap() {
+                        stream.send(chunk).await.unwrap();
+                    }
+                }
+                other => panic!("unexpected application protocol {other:?}"),
+            }
+
+            server_protocols.lock().unwrap().push(protocol);
+        });
+
+        let client_tls = tls::default::Client::builder()
+            .with_certificate(certificates::CERT_PEM)?
+            .with_application_protocols(CLIENT_PROTOCOLS.iter())?
+            .build()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(client_tls)?
+            .start()?;
+
+        let client_protocols = protocols.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            client_protocols
+                .lock()
+                .unwrap()
+                .push(connection.application_protocol().unwrap());
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            while let Some(_chunk) = stream.receive().await.unwrap() {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // both the client and the server observe the same negotiated protocol
+    let protocols = protocols.lock().unwrap();
+    assert_eq!(protocols.len(), 2);
+    for protocol in protocols.iter() {
+        assert_eq!(&protocol[..], b"netbench");
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{
+    event::api::Subject,
+    packet::interceptor::{Datagram, Interceptor},
+};
+
+/// Drops all of the datagrams received after the first one so the client's address is never
+/// validated
+#[derive(Default)]
+struct DropAfterFirstDatagram {
+    received: bool,
+}
+
+impl Interceptor for DropAfterFirstDatagram {
+    #[inline]
+    fn intercept_rx_datagram<'a>(
+        &mut self,
+        _subject: &Subject,
+        _datagram: &Datagram,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        if core::mem::replace(&mut self.received, true) {
+            return DecoderBufferMut::new(&mut []);
+        }
+        payload
+    }
+}
+
+/// Returns the number of bytes received and sent by a server with the given amplification
+/// limit before the client gives up on the handshake
+fn unvalidated_bytes(amplification_limit: u8) -> (u64, u64) {
+    let subscriber = (
+        recorder::DatagramReceived::new(),
+        recorder::DatagramSent::new(),
+    );
+    let received = subscriber.0.events();
+    let sent = subscriber.1.events();
+
+    test(Model::default(), |handle| {
+        let limits = Limits::default()
+            .with_amplification_limit(amplification_limit)
+            .unwrap();
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .with_packet_interceptor(DropAfterFirstDatagram::default())?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            // the server never receives the client's Handshake packets so this fails
+            assert!(client.connect(connect).await.is_err());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let received = received.lock().unwrap().iter().map(|len| *len as u64).sum();
+    let sent = sent.lock().unwrap().iter().map(|len| *len as u64).sum();
+    (received, sent)
+}
+
+/// The server doesn't send more than 3 times the received bytes by default
+#[test]
+fn default_amplification_limit_test() {
+    let (received, sent) = unvalidated_bytes(3);
+
+    assert!(received > 0);
+    assert!(sent > 0);
+    assert!(
+        sent <= received * 3,
+        "sent {sent} bytes after receiving {received}"
+    );
+}
+
+/// Raising the limit allows the server to send more than 3 times the received bytes before
+/// the client's address is validated
+#[test]
+fn raised_amplification_limit_test() {
+    let (received, sent) = unvalidated_bytes(10);
+
+    assert!(received > 0);
+    assert!(
+        sent > received * 3,
+        "sent {sent} bytes after receiving {received}"
+    );
+    assert!(
+        sent <= received * 10,
+        "sent {sent} bytes after receiving {received}"
+    );
+}
@@ -0,0 +1,90 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::connection::CloseReason;
+use s2n_quic_core::endpoint;
+
+const CLOSE_ERROR: u32 = 42;
+
+type Reason = Arc<Mutex<Option<CloseReason>>>;
+
+/// Starts a server which closes the connection with an application error after receiving a
+/// request
+fn start_closing_server(handle: &io::Handle, reason: Reason) -> io::Result<SocketAddr> {
+    let mut server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .start()?;
+    let addr = server.local_addr()?;
+
+    spawn(async move {
+        let mut connection = server.accept().await.unwrap();
+        let mut stream = connection
+            .accept_bidirectional_stream()
+            .await
+            .unwrap()
+            .unwrap();
+
+        // wait for the request
+        let _ = stream.receive().await.unwrap();
+
+        // the connection is still open
+        assert_eq!(connection.close_reason().unwrap(), None);
+
+        connection.close(CLOSE_ERROR.into());
+        *reason.lock().unwrap() = connection.close_reason().unwrap();
+    });
+
+    Ok(addr)
+}
+
+/// The client can read back the application error code the server closed the connection with
+#[test]
+fn close_reason_application_test() {
+    let model = Model::default();
+    let server_reason = Reason::default();
+    let client_reason = Reason::default();
+
+    test(model, |handle| {
+        let addr = start_closing_server(handle, server_reason.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+
+        let client_reason = client_reason.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"request")).await.unwrap();
+
+            // the server closes the connection instead of responding
+            assert!(stream.receive().await.is_err());
+
+            *client_reason.lock().unwrap() = connection.close_reason().unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert_application_close(client_reason, endpoint::Location::Remote);
+    assert_application_close(server_reason, endpoint::Location::Local);
+}
+
+fn assert_application_close(reason: Reason, expected_initiator: endpoint::Location) {
+    let reason = reason.lock().unwrap().take();
+    assert!(
+        matches!(
+            &reason,
+            Some(CloseReason::Application { error, reason, initiator, .. })
+                if *error == CLOSE_ERROR.into()
+                    && reason.is_empty()
+                    && *initiator == expected_initiator
+        ),
+        "{:?}",
+        reason
+    );
+}
@@ -0,0 +1,485 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+// Construct a simulation where a client sends some data over a lossy network,
+// which the server echos back. The CongestionWindowUpdated events that the
+// server experiences are recorded and returned at the end of the simulation.
+fn congestion_window_updates(drop_rate: f64) -> Vec<events::CongestionWindowUpdated> {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_drop_rate(drop_rate);
+
+    let subscriber = recorder::CongestionWindowUpdated::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events_handle = events.lock().unwrap();
+    events_handle.clone()
+}
+
+#[test]
+fn congestion_window_updated_test() {
+    let events = congestion_window_updates(0.0);
+
+    assert!(!events.is_empty());
+
+    // without loss, the congestion window only grows in slow start
+    for event in &events {
+        assert!(
+            matches!(
+                event.cause,
+                events::CongestionWindowUpdatedCause::SlowStart { .. }
+                    | events::CongestionWindowUpdatedCause::MtuUpdate { .. }
+            ),
+            "{:?}",
+            event
+        );
+        assert!(event.new_congestion_window > event.old_congestion_window);
+    }
+
+    // each update should start from the congestion window of the previous update
+    for window in events.windows(2) {
+        assert_eq!(
+            window[0].new_congestion_window,
+            window[1].old_congestion_window
+        );
+    }
+}
+
+#[test]
+fn congestion_window_updated_packet_loss_test() {
+    let events = congestion_window_updates(0.05);
+
+    let decreases: Vec<_> = events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.cause,
+                events::CongestionWindowUpdatedCause::PacketLoss { .. }
+            )
+        })
+        .collect();
+    assert!(!decreases.is_empty());
+    for event in decreases {
+        assert!(event.new_congestion_window < event.old_congestion_window);
+    }
+
+    for window in events.windows(2) {
+        assert_eq!(
+            window[0].new_congestion_window,
+            window[1].old_congestion_window
+        );
+    }
+}
+
+mod custom {
+    use crate::provider::congestion_controller::{
+        self as cc, CongestionController, PathInfo, Publisher, RandomGenerator, RttEstimator,
+        Timestamp,
+    };
+    use s2n_quic_core::recovery::CubicCongestionController;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// The number of times each method was invoked across all of the controllers for an endpoint
+    #[derive(Debug, Default)]
+    pub struct Calls {
+        pub sent: AtomicUsize,
+        pub acked: AtomicUsize,
+        pub lost: AtomicUsize,
+    }
+
+    #[derive(Clone, Debug, Default)]
+    pub struct Endpoint {
+        pub calls: Arc<Calls>,
+    }
+
+    impl cc::Endpoint for Endpoint {
+        type CongestionController = Counting;
+
+        fn new_congestion_controller(&mut self, path_info: PathInfo) -> Self::CongestionController {
+            Counting {
+                inner: CubicCongestionController::new(path_info.max_datagram_size),
+                calls: self.calls.clone(),
+            }
+        }
+    }
+
+    /// A third party controller which delegates to CUBIC while counting the calls it receives
+    #[derive(Clone, Debug)]
+    pub struct Counting {
+        inner: CubicCongestionController,
+        calls: Arc<Calls>,
+    }
+
+    impl CongestionController for Counting {
+        type PacketInfo = <CubicCongestionController as CongestionController>::PacketInfo;
+
+        fn congestion_window(&self) -> u32 {
+            self.inner.congestion_window()
+        }
+
+        fn bytes_in_flight(&self) -> u32 {
+            self.inner.bytes_in_flight()
+        }
+
+        fn is_congestion_limited(&self) -> bool {
+            self.inner.is_congestion_limited()
+        }
+
+        fn requires_fast_retransmission(&self) -> bool {
+            self.inner.requires_fast_retransmission()
+        }
+
+        fn on_packet_sent<Pub: Publisher>(
+            &mut self,
+            time_sent: Timestamp,
+            sent_bytes: usize,
+            app_limited: Option<bool>,
+            rtt_estimator: &RttEstimator,
+            publisher: &mut Pub,
+        ) -> Self::PacketInfo {
+            self.calls.sent.fetch_add(1, Ordering::Relaxed);
+            self.inner
+                .on_packet_sent(time_sent, sent_bytes, app_limited, rtt_estimator, publisher)
+        }
+
+        fn on_rtt_update<Pub: Publisher>(
+            &mut self,
+            time_sent: Timestamp,
+            now: Timestamp,
+            rtt_estimator: &RttEstimator,
+            publisher: &mut Pub,
+        ) {
+            self.inner
+                .on_rtt_update(time_sent, now, rtt_estimator, publisher)
+        }
+
+        fn on_ack<Pub: Publisher>(
+            &mut self,
+            newest_acked_time_sent: Timestamp,
+            bytes_acknowledged: usize,
+            newest_acked_packet_info: Self::PacketInfo,
+            rtt_estimator: &RttEstimator,
+            random_generator: &mut dyn RandomGenerator,
+            ack_receive_time: Timestamp,
+            publisher: &mut Pub,
+        ) {
+            self.calls.acked.fetch_add(1, Ordering::Relaxed);
+            self.inner.on_ack(
+                newest_acked_time_sent,
+                bytes_acknowledged,
+                newest_acked_packet_info,
+                rtt_estimator,
+                random_generator,
+                ack_receive_time,
+                publisher,
+            )
+        }
+
+        fn on_packet_lost<Pub: Publisher>(
+            &mut self,
+            lost_bytes: u32,
+            packet_info: Self::PacketInfo,
+            persistent_congestion: bool,
+            new_loss_burst: bool,
+            random_generator: &mut dyn RandomGenerator,
+            timestamp: Timestamp,
+            publisher: &mut Pub,
+        ) {
+            self.calls.lost.fetch_add(1, Ordering::Relaxed);
+            self.inner.on_packet_lost(
+                lost_bytes,
+                packet_info,
+                persistent_congestion,
+                new_loss_burst,
+                random_generator,
+                timestamp,
+                publisher,
+            )
+        }
+
+        fn on_explicit_congestion<Pub: Publisher>(
+            &mut self,
+            ce_count: u64,
+            event_time: Timestamp,
+            publisher: &mut Pub,
+        ) {
+            self.inner
+                .on_explicit_congestion(ce_count, event_time, publisher)
+        }
+
+        fn on_mtu_update<Pub: Publisher>(&mut self, max_data_size: u16, publisher: &mut Pub) {
+            self.inner.on_mtu_update(max_data_size, publisher)
+        }
+
+        fn on_packet_discarded<Pub: Publisher>(&mut self, bytes_sent: usize, publisher: &mut Pub) {
+            self.inner.on_packet_discarded(bytes_sent, publisher)
+        }
+
+        fn earliest_departure_time(&self) -> Option<Timestamp> {
+            self.inner.earliest_departure_time()
+        }
+    }
+}
+
+/// A third party congestion controller is driven by the endpoint over a lossy network
+#[test]
+fn custom_congestion_controller_test() {
+    use std::sync::atomic::Ordering;
+
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_drop_rate(0.05);
+
+    let congestion_controller = custom::Endpoint::default();
+    let calls = congestion_controller.calls.clone();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_congestion_controller(congestion_controller)?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(calls.sent.load(Ordering::Relaxed) > 0);
+    assert!(calls.acked.load(Ordering::Relaxed) > 0);
+    assert!(calls.lost.load(Ordering::Relaxed) > 0);
+}
+
+/// Persistent congestion is declared when all packets are lost for longer than the persistent
+/// congestion threshold, and the congestion window collapses to the minimum
+#[test]
+fn persistent_congestion_test() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(100);
+    model.set_delay(rtt / 2);
+
+    let subscriber = recorder::PersistentCongestion::new();
+    let events = subscriber.events();
+
+    test(model.clone(), |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .start()?;
+        let addr = server.local_addr()?;
+        spawn(async move {
+            // only receive data so the client is the only sender
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+            while let Ok(Some(_)) = stream.receive().await {}
+
+            // keep the connection open for the sender
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(subscriber)?
+            .start()?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+            let chunk = Bytes::from(vec![42; 10_000]);
+            for _ in 0..1_000 {
+                stream.send(chunk.clone()).await.unwrap();
+            }
+            stream.close().await.unwrap();
+        });
+
+        spawn(async move {
+            delay(rtt * 10).await;
+
+            // drop all of the client's packets for long enough to establish persistent
+            // congestion. The server's packets are still delivered so its acknowledgements
+            // don't produce inflated RTT samples when the outage ends.
+            model.set_drop_rate_rx(1.0);
+            delay(rtt * 20).await;
+            model.set_drop_rate_rx(0.0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    assert!(!events.is_empty());
+    for &(duration, congestion_window) in events.iter() {
+        // the minimum window is 2 packets
+        assert!(congestion_window <= 2 * 1472, "{congestion_window}");
+        assert!(duration >= rtt * 3, "{duration:?}");
+    }
+}
+
+/// Echoes a large payload over a high-delay network using congestion controllers created by
+/// `congestion_controller` and returns how long the transfer took
+fn echo_duration(
+    congestion_controller: fn() -> provider::congestion_controller::Cubic,
+) -> Duration {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(250));
+    let duration = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .with_congestion_controller(congestion_controller())?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_congestion_controller(congestion_controller())?
+            .start()?;
+        let duration = duration.clone();
+        primary::spawn(async move {
+            let start = io::now();
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            primary::spawn(async move {
+                let mut data = Data::new(10_000_000);
+                while let Some(chunk) = data.send_one(usize::MAX) {
+                    send.send(chunk).await.unwrap();
+                }
+            });
+
+            let mut data = Data::new(10_000_000);
+            while let Some(chunk) = recv.receive().await.unwrap() {
+                data.receive(&[chunk]);
+            }
+            assert!(data.is_finished());
+
+            *duration.lock().unwrap() = Some(io::now() - start);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let duration = duration.lock().unwrap().take().unwrap();
+    duration
+}
+
+/// A larger initial congestion window ramps up a transfer on a high-delay path in fewer round trips
+#[test]
+fn initial_congestion_window_test() {
+    let baseline = echo_duration(Default::default);
+    let larger = echo_duration(|| {
+        provider::congestion_controller::Cubic::builder()
+            .with_initial_congestion_window(1_000_000)
+            .unwrap()
+            .build()
+            .unwrap()
+    });
+
+    assert!(
+        larger < baseline,
+        "baseline: {baseline:?}, larger initial window: {larger:?}"
+    );
+}
+
+/// Echoes data over a network with a bottleneck queue using the given slow start mode and
+/// returns the peak bytes in flight of the server along with its congestion window updates
+fn slow_start_overshoot(
+    mode: provider::congestion_controller::SlowStartMode,
+) -> (u32, Vec<events::CongestionWindowUpdated>) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    // packets queue at the bottleneck once the path is full, increasing the RTT
+    model.set_inflight_delay_threshold(20);
+    model.set_inflight_delay(Duration::from_millis(1));
+
+    let bytes_in_flight = recorder::BytesInFlight::new();
+    let bytes_in_flight_events = bytes_in_flight.events();
+    let congestion_window = recorder::CongestionWindowUpdated::new();
+    let congestion_window_events = congestion_window.events();
+
+    test(model, |handle| {
+        let congestion_controller = provider::congestion_controller::Cubic::builder()
+            .with_slow_start_mode(mode)
+            .build()?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((bytes_in_flight, congestion_window))?
+            .with_congestion_controller(congestion_controller)?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let peak = bytes_in_flight_events
+        .lock()
+        .unwrap()
+        .iter()
+        .copied()
+        .max()
+        .unwrap();
+    let congestion_window_events = congestion_window_events.lock().unwrap().clone();
+    (peak, congestion_window_events)
+}
+
+/// HyStart++ slows the growth of the congestion window once queueing delay builds, which
+/// reduces how far the bytes in flight overshoot the capacity of the path
+#[test]
+fn slow_start_mode_test() {
+    use provider::congestion_controller::SlowStartMode;
+
+    let is_conservative = |events: &[events::CongestionWindowUpdated]| {
+        events.iter().any(|event| {
+            matches!(
+                event.cause,
+                events::CongestionWindowUpdatedCause::ConservativeSlowStart { .. }
+            )
+        })
+    };
+
+    let (hybrid, events) = slow_start_overshoot(SlowStartMode::Hybrid);
+    assert!(!is_conservative(&events));
+
+    let (hystart_plus_plus, events) = slow_start_overshoot(SlowStartMode::HyStartPlusPlus);
+    assert!(is_conservative(&events));
+
+    assert!(
+        hystart_plus_plus < hybrid,
+        "hybrid: {hybrid}, hystart++: {hystart_plus_plus}"
+    );
+}
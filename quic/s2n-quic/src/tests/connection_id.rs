@@ -0,0 +1,88 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+const ISSUED_CONNECTION_ID_LIMIT: u64 = 2;
+
+/// The peer retires all of the connection IDs it was issued after a forced rotation
+#[test]
+fn rotate_connection_id_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+
+    let subscriber = (
+        recorder::ConnectionIdIssued::new(),
+        recorder::ConnectionIdRetired::new(),
+    );
+    let issued = subscriber.0.events();
+    let retired = subscriber.1.events();
+    let issued_before_rotation = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .start()?;
+        let limits = Limits::default()
+            .with_issued_connection_id_limit(ISSUED_CONNECTION_ID_LIMIT)
+            .unwrap();
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let issued = issued.clone();
+        let issued_before_rotation = issued_before_rotation.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // wait for the peer to be issued its connection IDs
+            delay(Duration::from_millis(100)).await;
+            *issued_before_rotation.lock().unwrap() = issued.lock().unwrap().clone();
+
+            connection.rotate_connection_id().unwrap();
+
+            // wait for the peer to retire the connection IDs
+            delay(Duration::from_millis(100)).await;
+
+            // the connection is still usable with the new connection IDs
+            stream.send(Bytes::from_static(b"B")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let issued_before_rotation = issued_before_rotation.lock().unwrap().clone();
+    let issued = issued.lock().unwrap().clone();
+    let retired = retired.lock().unwrap().clone();
+
+    // the configured limit bounds the number of connection IDs issued at a time
+    assert_eq!(
+        issued_before_rotation.len() as u64,
+        ISSUED_CONNECTION_ID_LIMIT
+    );
+    assert_eq!(
+        (issued.len() - issued_before_rotation.len()) as u64,
+        ISSUED_CONNECTION_ID_LIMIT,
+        "{issued:?}"
+    );
+
+    // the handshake connection ID and every connection ID issued before the rotation
+    // were retired by the peer
+    assert!(retired.contains(&0), "{retired:?}");
+    for sequence_number in issued_before_rotation {
+        assert!(retired.contains(&sequence_number), "{retired:?}");
+    }
+}
@@ -2,6 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::*;
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{
+    event::api::Subject,
+    packet::interceptor::{Datagram, Interceptor},
+};
 
 fn run_test<F>(mut on_rebind: F)
 where
@@ -129,3 +134,336 @@ fn port_rebind_test() {
 fn ip_and_port_rebind_test() {
     run_test(|addr| rebind_ip(rebind_port(addr)));
 }
+
+/// Drops all of the datagrams received from the blackholed address
+#[derive(Clone, Default)]
+struct Blackhole(Arc<Mutex<Option<SocketAddr>>>);
+
+impl Interceptor for Blackhole {
+    #[inline]
+    fn intercept_rx_datagram<'a>(
+        &mut self,
+        _subject: &Subject,
+        datagram: &Datagram,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        let remote_address: SocketAddr = (&datagram.remote_address).into();
+        if *self.0.lock().unwrap() == Some(remote_address) {
+            return DecoderBufferMut::new(&mut []);
+        }
+        payload
+    }
+}
+
+/// Migrates the client to an additional address after sending a request and returns the
+/// result of the migration, along with the client's local address once it finished
+fn client_migration<S: 'static + events::Subscriber>(
+    blackhole: bool,
+    limits: provider::limits::Limits,
+    subscriber: S,
+) -> (
+    Result<(), crate::connection::MigrationError>,
+    SocketAddr,
+    SocketAddr,
+) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+
+    let new_addr = Arc::new(Mutex::new(None));
+    let interceptor = Blackhole::default();
+    let result = Arc::new(Mutex::new(None));
+
+    let on_socket = {
+        let new_addr = new_addr.clone();
+        let interceptor = interceptor.clone();
+        move |socket: io::Socket| {
+            let addr = rebind_ip(socket.local_addr().unwrap());
+            socket.add_address(addr);
+            *new_addr.lock().unwrap() = Some(addr);
+            if blackhole {
+                *interceptor.0.lock().unwrap() = Some(addr);
+            }
+        }
+    };
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .with_packet_interceptor(interceptor)?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().on_socket(on_socket).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+
+        let addr = start_server(server)?;
+        let new_addr = new_addr.lock().unwrap().unwrap();
+        let result = result.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            // wait for a response so the handshake is confirmed
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let migrated = connection.migrate(new_addr).await;
+            let local_addr = connection.local_addr().unwrap();
+
+            // send enough data on the resulting path for the MTU to be probed
+            let (mut recv, mut send) = stream.split();
+            let mut data = Data::new(1_000_000);
+            while let Some(chunk) = data.send_one(usize::MAX) {
+                send.send(chunk).await.unwrap();
+            }
+            send.finish().unwrap();
+
+            *result.lock().unwrap() = Some((migrated, local_addr, new_addr));
+
+            // drain the data echoed back by the server
+            while recv.receive().await.unwrap().is_some() {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let result = result.lock().unwrap().take();
+    result.unwrap()
+}
+
+/// The client switches to a new local address once the path is validated and probes the
+/// MTU of the new path
+#[test]
+fn client_migration_test() {
+    let subscriber = (recorder::PathValidated::new(), recorder::MtuUpdated::new());
+    let validated = subscriber.0.events();
+    let mtu_updates = subscriber.1.events();
+
+    let (result, local_addr, new_addr) =
+        client_migration(false, provider::limits::Limits::default(), subscriber);
+
+    assert!(result.is_ok(), "{result:?}");
+    assert_eq!(local_addr, new_addr);
+    assert_eq!(*validated.lock().unwrap(), [1]);
+
+    let mtu_updates = mtu_updates.lock().unwrap();
+    let new_path: Vec<_> = mtu_updates.iter().filter(|e| e.path_id == 1).collect();
+    assert!(matches!(
+        new_path[0].cause,
+        events::MtuUpdatedCause::NewPath { .. }
+    ));
+    assert!(new_path
+        .iter()
+        .any(|e| matches!(e.cause, events::MtuUpdatedCause::ProbeAcknowledged { .. })));
+}
+
+/// The client stays on its current path if the new path can't be validated
+#[test]
+fn client_migration_failure_test() {
+    let subscriber = (recorder::PathValidated::new(), recorder::MtuUpdated::new());
+    let validated = subscriber.0.events();
+
+    let (result, local_addr, new_addr) =
+        client_migration(true, provider::limits::Limits::default(), subscriber);
+
+    assert!(
+        matches!(
+            result,
+            Err(crate::connection::MigrationError::ValidationFailed)
+        ),
+        "{result:?}"
+    );
+    assert_ne!(local_addr, new_addr);
+    assert!(validated.lock().unwrap().is_empty());
+}
+
+/// The configured number of challenges are sent on the new path and the failure is reported
+/// once the configured timeout expires
+#[test]
+fn client_migration_validation_failed_test() {
+    let run = |limits| {
+        let subscriber = recorder::PathValidationFailed::new();
+        let failures = subscriber.events();
+        let (result, local_addr, new_addr) = client_migration(true, limits, subscriber);
+
+        assert!(
+            matches!(
+                result,
+                Err(crate::connection::MigrationError::ValidationFailed)
+            ),
+            "{result:?}"
+        );
+        assert_ne!(local_addr, new_addr);
+
+        let failures = failures.lock().unwrap();
+        assert_eq!(failures.len(), 1, "{failures:?}");
+        failures[0]
+    };
+
+    let (attempts, has_fallback, default_time) = run(provider::limits::Limits::default());
+    assert_eq!(attempts, 2);
+    // the previous path is still active so the connection doesn't need to fall back
+    assert!(!has_fallback);
+
+    let limits = provider::limits::Limits::default()
+        .with_path_validation_timeout(Duration::from_secs(10))
+        .unwrap()
+        .with_path_validation_attempts(3)
+        .unwrap();
+    let (attempts, has_fallback, configured_time) = run(limits);
+    assert_eq!(attempts, 3);
+    assert!(!has_fallback);
+
+    // both runs start the migration at the same time so the configured timeout delays the
+    // failure past the default timeout
+    assert!(configured_time > default_time);
+}
+
+/// The server reports the outcome of validating the client's address after a NAT changes the
+/// client's source port
+#[test]
+fn nat_rebinding_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+
+    let subscriber = (
+        recorder::NatRebindingDetected::new(),
+        recorder::MtuUpdated::new(),
+    );
+    let rebindings = subscriber.0.events();
+    let mtu_updates = subscriber.1.events();
+    let client_addr = Arc::new(Mutex::new(None));
+
+    test(model.clone(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = build_client(handle)?;
+        *client_addr.lock().unwrap() = Some(client.local_addr()?);
+
+        let addr = start_server(server)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // let the server observe the connection ID the client switched to after the
+            // handshake was confirmed before changing the address
+            delay(Duration::from_millis(100)).await;
+
+            // the NAT assigns the client a new port
+            model.set_nat_port_offset(1);
+
+            stream.send(Bytes::from_static(b"B")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // wait for the server to finish validating the new address
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let client_addr = client_addr.lock().unwrap().unwrap();
+    let rebindings = rebindings.lock().unwrap();
+    assert_eq!(rebindings.len(), 1, "{rebindings:?}");
+
+    let (path_id, previous_addr, new_addr, validated) = rebindings[0];
+    assert_eq!(previous_addr, client_addr);
+    assert_eq!(new_addr.ip(), client_addr.ip());
+    assert_eq!(new_addr.port(), client_addr.port() + 1);
+    assert!(validated);
+
+    // the event can be correlated with the creation of the path
+    assert!(mtu_updates.lock().unwrap().iter().any(|event| {
+        event.path_id == path_id && matches!(event.cause, events::MtuUpdatedCause::NewPath { .. })
+    }));
+}
+
+/// The server's connection handle reports the client's current address after the client's
+/// address changes
+#[test]
+fn server_remote_addr_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+
+    let addrs = Arc::new(Mutex::new(None));
+    let client_addr = Arc::new(Mutex::new(None));
+
+    test(model.clone(), |handle| {
+        let mut server = build_server(handle)?;
+        let server_addr = server.local_addr()?;
+        let client = build_client(handle)?;
+        *client_addr.lock().unwrap() = Some(client.local_addr()?);
+
+        let addrs = addrs.clone();
+        primary::spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let accepted_addr = connection.remote_addr().unwrap();
+            assert_eq!(connection.local_addr().unwrap(), server_addr);
+
+            let mut stream = connection
+                .accept_bidirectional_stream()
+                .await
+                .unwrap()
+                .unwrap();
+
+            // echo the request sent before and after the address change
+            for _ in 0..2 {
+                let chunk = stream.receive().await.unwrap().unwrap();
+                stream.send(chunk).await.unwrap();
+            }
+
+            // wait for the server to finish validating the new address
+            delay(Duration::from_secs(1)).await;
+
+            let migrated_addr = connection.remote_addr().unwrap();
+            assert_eq!(connection.local_addr().unwrap(), server_addr);
+            *addrs.lock().unwrap() = Some((accepted_addr, migrated_addr));
+        });
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // let the server observe the connection ID the client switched to after the
+            // handshake was confirmed before changing the address
+            delay(Duration::from_millis(100)).await;
+
+            // the NAT assigns the client a new port
+            model.set_nat_port_offset(1);
+
+            stream.send(Bytes::from_static(b"B")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // keep the connection open until the server reads the new address
+            delay(Duration::from_secs(2)).await;
+        });
+
+        Ok(server_addr)
+    })
+    .unwrap();
+
+    let client_addr = client_addr.lock().unwrap().unwrap();
+    let (accepted_addr, migrated_addr) = addrs.lock().unwrap().unwrap();
+    assert_eq!(accepted_addr, client_addr);
+    assert_eq!(migrated_addr.ip(), client_addr.ip());
+    assert_eq!(migrated_addr.port(), client_addr.port() + 1);
+}
@@ -0,0 +1,296 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::datagram::{
+    default::{DatagramError, Endpoint, Receiver, Sender},
+    DatagramPriority,
+};
+use futures::future::poll_fn;
+
+fn datagram_endpoint(send_capacity: usize) -> Endpoint {
+    datagram_endpoint_with_priority(send_capacity, DatagramPriority::default())
+}
+
+fn datagram_endpoint_with_priority(send_capacity: usize, priority: DatagramPriority) -> Endpoint {
+    Endpoint::builder()
+        .with_send_capacity(send_capacity)
+        .unwrap()
+        .with_recv_capacity(1000)
+        .unwrap()
+        .with_priority(priority)
+        .build()
+        .unwrap()
+}
+
+/// Starts a server that records the length of each datagram it receives and discards
+/// any stream data
+fn start_datagram_server(
+    handle: &io::Handle,
+    received: Arc<Mutex<Vec<usize>>>,
+) -> io::Result<SocketAddr> {
+    let mut server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .with_datagram(datagram_endpoint(1))?
+        .start()?;
+    let addr = server.local_addr()?;
+
+    spawn(async move {
+        while let Some(mut connection) = server.accept().await {
+            let received = received.clone();
+            let handle = connection.handle();
+            spawn(async move {
+                while let Ok(datagram) = poll_fn(|cx| {
+                    handle
+                        .datagram_mut(|receiver: &mut Receiver| receiver.poll_recv_datagram(cx))
+                        .unwrap()
+                })
+                .await
+                {
+                    received.lock().unwrap().push(datagram.len());
+                }
+            });
+            spawn(async move {
+                while let Ok(Some(mut stream)) = connection.accept_bidirectional_stream().await {
+                    spawn(async move { while let Ok(Some(_)) = stream.receive().await {} });
+                }
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+// Datagrams larger than the current path MTU allows are rejected with the maximum size
+#[test]
+fn datagram_path_mtu_test() {
+    let model = Model::default();
+    let received = Arc::new(Mutex::new(vec![]));
+    let max_datagram_size = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let addr = start_datagram_server(handle, received.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_datagram(datagram_endpoint(10))?
+            .start()?;
+
+        let max_datagram_size = max_datagram_size.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            // the limit is known as soon as the handshake completes
+            let size = connection
+                .datagram_mut(|sender: &mut Sender| sender.max_datagram_size())
+                .unwrap();
+            assert!(size < connection.path_mtu().unwrap());
+
+            let result = connection
+                .datagram_mut(|sender: &mut Sender| {
+                    sender.send_datagram(Bytes::from(vec![42; size + 1]))
+                })
+                .unwrap();
+            assert!(matches!(
+                result,
+                Err(DatagramError::ExceedsPathMtu { max_datagram_size, .. }) if max_datagram_size == size
+            ));
+
+            // a datagram of the maximum size is sent in a single packet
+            connection
+                .datagram_mut(|sender: &mut Sender| {
+                    sender.send_datagram(Bytes::from(vec![42; size]))
+                })
+                .unwrap()
+                .unwrap();
+
+            *max_datagram_size.lock().unwrap() = Some(size);
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let size = max_datagram_size.lock().unwrap().unwrap();
+    assert_eq!(*received.lock().unwrap(), [size]);
+}
+
+// Datagrams evicted from a full send queue are reported with an event
+#[test]
+fn datagram_dropped_event_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    let received = Arc::new(Mutex::new(vec![]));
+    let subscriber = recorder::DatagramFrameDropped::new();
+    let events = subscriber.events();
+    const COUNT: usize = 100;
+    const LEN: usize = 1000;
+
+    test(model, |handle| {
+        let addr = start_datagram_server(handle, received.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_datagram(datagram_endpoint(10))?
+            .with_event(subscriber)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            // enqueue datagrams faster than the connection can send them
+            for _ in 0..COUNT {
+                connection
+                    .datagram_mut(|sender: &mut Sender| {
+                        sender.send_datagram_forced(Bytes::from(vec![42; LEN]))
+                    })
+                    .unwrap()
+                    .unwrap();
+            }
+
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    let received = received.lock().unwrap();
+
+    assert!(!events.is_empty());
+    for event in events.iter() {
+        assert_eq!(event.len, event.count * LEN);
+        assert!(matches!(
+            event.reason,
+            events::DatagramFrameDropReason::QueueFull { .. }
+        ));
+    }
+
+    // every datagram was either dropped or received by the server
+    let dropped: usize = events.iter().map(|event| event.count).sum();
+    assert_eq!(dropped + received.len(), COUNT);
+}
+
+// Awaiting `poll_send_datagram` applies backpressure instead of dropping datagrams
+#[test]
+fn datagram_backpressure_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    let received = Arc::new(Mutex::new(vec![]));
+    let subscriber = recorder::DatagramFrameDropped::new();
+    let events = subscriber.events();
+    const COUNT: usize = 100;
+    const LEN: usize = 1000;
+
+    test(model, |handle| {
+        let addr = start_datagram_server(handle, received.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_datagram(datagram_endpoint(1))?
+            .with_event(subscriber)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            for _ in 0..COUNT {
+                let mut datagram = Bytes::from(vec![42; LEN]);
+                poll_fn(|cx| {
+                    connection
+                        .datagram_mut(|sender: &mut Sender| {
+                            sender.poll_send_datagram(&mut datagram, cx)
+                        })
+                        .unwrap()
+                })
+                .await
+                .unwrap();
+            }
+
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(events.lock().unwrap().is_empty());
+    assert_eq!(*received.lock().unwrap(), [LEN; COUNT]);
+}
+
+/// Sends stream data and datagrams at the same time over a congested network and
+/// returns the number of datagrams that were dropped
+fn dropped_datagrams_with_priority(priority: DatagramPriority) -> usize {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_drop_rate(0.01);
+    let received = Arc::new(Mutex::new(vec![]));
+    let subscriber = recorder::DatagramFrameDropped::new();
+    let events = subscriber.events();
+    const COUNT: usize = 200;
+    const LEN: usize = 1000;
+
+    test(model, |handle| {
+        let addr = start_datagram_server(handle, received.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_datagram(datagram_endpoint_with_priority(10, priority))?
+            .with_event(subscriber)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // keep the congestion window full of stream data
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            primary::spawn(async move {
+                let chunk = Bytes::from(vec![42; 10_000]);
+                for _ in 0..200 {
+                    if stream.send(chunk.clone()).await.is_err() {
+                        return;
+                    }
+                }
+                let _ = stream.finish();
+            });
+
+            for _ in 0..COUNT {
+                connection
+                    .datagram_mut(|sender: &mut Sender| {
+                        sender.send_datagram_forced(Bytes::from(vec![42; LEN]))
+                    })
+                    .unwrap()
+                    .unwrap();
+                delay(Duration::from_millis(5)).await;
+            }
+
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let dropped: usize = events.lock().unwrap().iter().map(|event| event.count).sum();
+    let received = received.lock().unwrap().len();
+    assert!(dropped + received <= COUNT);
+    dropped
+}
+
+// Datagrams are dropped first when stream data is prioritized under congestion
+#[test]
+fn datagram_priority_test() {
+    let stream = dropped_datagrams_with_priority(DatagramPriority::Stream);
+    let round_robin = dropped_datagrams_with_priority(DatagramPriority::RoundRobin);
+    let datagram = dropped_datagrams_with_priority(DatagramPriority::Datagram);
+
+    assert!(stream > round_robin, "{} > {}", stream, round_robin);
+    assert!(round_robin > datagram, "{} > {}", round_robin, datagram);
+}
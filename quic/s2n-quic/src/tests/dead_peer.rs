@@ -0,0 +1,80 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::now;
+
+/// Drops all packets after the handshake and returns how long it took the client to notice,
+/// along with the error it observed
+fn blackhole_after_handshake(
+    limits: provider::limits::Limits,
+) -> (Duration, crate::connection::Error) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let result = Arc::new(Mutex::new(None));
+
+    test(model.clone(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_limits(limits)?
+            .start()?;
+
+        let result = result.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // the peer becomes unreachable
+            model.set_drop_rate(1.0);
+            let start = now();
+
+            let mut stream = connection.open_send_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            let error = match stream.flush().await {
+                Err(crate::stream::Error::ConnectionError { error, .. }) => error,
+                other => panic!("unexpected result: {other:?}"),
+            };
+
+            *result.lock().unwrap() = Some((now().saturating_duration_since(start), error));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let result = result.lock().unwrap().take();
+    result.unwrap()
+}
+
+#[test]
+fn dead_peer_test() {
+    let timeout = Duration::from_secs(2);
+    let limits = provider::limits::Limits::default()
+        .with_dead_peer_timeout(timeout)
+        .unwrap();
+    let (elapsed, error) = blackhole_after_handshake(limits);
+
+    assert!(
+        matches!(error, crate::connection::Error::DeadPeerDetected { timeout: t, .. } if t == timeout),
+        "{error:?}"
+    );
+    assert!(elapsed < timeout + Duration::from_secs(1), "{elapsed:?}");
+
+    // without dead peer detection the client waits for the idle timeout
+    let (idle_elapsed, error) = blackhole_after_handshake(Default::default());
+    assert!(
+        matches!(error, crate::connection::Error::IdleTimerExpired { .. }),
+        "{error:?}"
+    );
+    assert!(elapsed * 5 < idle_elapsed, "{elapsed:?} {idle_elapsed:?}");
+}
@@ -0,0 +1,110 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::EcnMode;
+
+struct Events {
+    states: Vec<events::EcnState>,
+    congestion: Vec<events::CongestionSource>,
+    windows: Vec<events::CongestionWindowUpdated>,
+}
+
+impl Events {
+    fn is_capable(&self) -> bool {
+        self.states
+            .iter()
+            .any(|state| matches!(state, events::EcnState::Capable { .. }))
+    }
+
+    fn ecn_congestion_count(&self) -> usize {
+        self.congestion
+            .iter()
+            .filter(|source| matches!(source, events::CongestionSource::Ecn { .. }))
+            .count()
+    }
+
+    fn ecn_window_reductions(&self) -> usize {
+        self.windows
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event.cause,
+                    events::CongestionWindowUpdatedCause::Ecn { .. }
+                )
+            })
+            .count()
+    }
+}
+
+// Construct a simulation where a client sends some data over a network that marks packets
+// with ECN-CE, which the server echos back. The ECN related events that the server
+// experiences are recorded and returned at the end of the simulation.
+fn ecn_events(mode: EcnMode, ce_rate: f64) -> Events {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_ecn_ce_rate(ce_rate);
+
+    let states = recorder::EcnStateChanged::new();
+    let congestion = recorder::Congestion::new();
+    let windows = recorder::CongestionWindowUpdated::new();
+    let events = (states.events(), congestion.events(), windows.events());
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_ecn(mode).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((states, (congestion, windows)))?
+            .start()?;
+        // the client needs to read the CE marks to report them back to the server
+        let client = Client::builder()
+            .with_io(handle.builder().with_ecn(EcnMode::Enabled).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let (states, congestion, windows) = events;
+    let states = states.lock().unwrap().clone();
+    let congestion = congestion.lock().unwrap().clone();
+    let windows = windows.lock().unwrap().clone();
+    Events {
+        states,
+        congestion,
+        windows,
+    }
+}
+
+/// The congestion controller reduces the congestion window when the peer reports CE marks
+#[test]
+fn ecn_enabled_test() {
+    let events = ecn_events(EcnMode::Enabled, 0.05);
+
+    assert!(events.is_capable(), "{:?}", events.states);
+    assert!(events.ecn_congestion_count() > 0);
+    assert!(events.ecn_window_reductions() > 0);
+}
+
+/// CE marks are reported but don't affect the congestion window in validate mode
+#[test]
+fn ecn_validate_test() {
+    let events = ecn_events(EcnMode::Validate, 0.05);
+
+    assert!(events.is_capable(), "{:?}", events.states);
+    assert!(events.ecn_congestion_count() > 0);
+    assert_eq!(events.ecn_window_reductions(), 0);
+}
+
+/// Packets aren't marked as ECN capable, so the network never marks them with CE
+#[test]
+fn ecn_disabled_test() {
+    let events = ecn_events(EcnMode::Disabled, 0.05);
+
+    assert!(!events.is_capable(), "{:?}", events.states);
+    assert_eq!(events.ecn_congestion_count(), 0);
+    assert_eq!(events.ecn_window_reductions(), 0);
+}
@@ -0,0 +1,67 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// Collects the output of a JSON event subscriber
+#[derive(Clone, Default)]
+struct JsonOutput(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for JsonOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The JSON event subscriber writes a line for each event with the connection ID and fields
+#[test]
+fn json_event_test() {
+    let model = Model::default();
+    let output = JsonOutput::default();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(crate::provider::event::json::Subscriber::new(
+                output.clone(),
+            ))?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    let mtu_updated = output
+        .lines()
+        .find(|line| line.starts_with(r#"{"type":"mtu_updated""#))
+        .expect("missing mtu_updated event");
+
+    assert_eq!(
+        mtu_updated,
+        r#"{"type":"mtu_updated","endpoint":"server","connection_id":0,"fields":{"path_id":0,"mtu":1200,"cause":"NewPath"}}"#
+    );
+
+    // booleans are written as JSON values
+    let handshake_complete = output
+        .lines()
+        .find(|line| line.starts_with(r#"{"type":"handshake_complete","endpoint":"server""#))
+        .expect("missing handshake_complete event");
+    assert!(
+        handshake_complete.ends_with(r#""zero_rtt":false,"resumed":false}}"#),
+        "{handshake_complete}"
+    );
+
+    // every event is written on its own line
+    assert!(output
+        .lines()
+        .all(|line| line.starts_with(r#"{"type":""#) && line.ends_with("}}")));
+}
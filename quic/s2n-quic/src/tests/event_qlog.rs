@@ -0,0 +1,72 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::event::qlog;
+
+/// The qlog event subscriber writes a trace for each connection, named by the original
+/// destination connection id
+#[test]
+fn qlog_event_test() {
+    let model = Model::default();
+    let dir = std::env::temp_dir().join(format!("s2n-quic-qlog-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(qlog::Subscriber::new(&dir))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_random(io::Random)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(qlog::Subscriber::new(&dir))?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let mut names: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+
+    let server_trace = std::fs::read_to_string(dir.join(&names[1])).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // the client and server traces share the original destination connection id
+    let (group_id, _) = names[0].split_once('-').unwrap();
+    assert_eq!(
+        names,
+        [
+            format!("{group_id}-client.sqlog"),
+            format!("{group_id}-server.sqlog")
+        ]
+    );
+
+    let mut records = server_trace.split('\x1e').skip(1);
+    let header = records.next().expect("missing header");
+    assert!(header.starts_with(r#"{"qlog_version":"0.3","qlog_format":"JSON-SEQ""#));
+    assert!(header.contains(&format!(r#""group_id":"{group_id}""#)));
+
+    let records: Vec<_> = records.collect();
+    assert!(records
+        .iter()
+        .any(|record| record.contains(r#""name":"connectivity:mtu_updated","data":{"new":1200}"#)));
+    assert!(records
+        .iter()
+        .any(|record| record.contains(r#""name":"transport:packet_sent""#)));
+    assert!(records
+        .iter()
+        .any(|record| record.contains(r#""name":"recovery:metrics_updated""#)));
+
+    // every record is a JSON object terminated by a newline
+    assert!(records
+        .iter()
+        .all(|record| record.starts_with(r#"{"time":"#) && record.ends_with("}}\n")));
+}
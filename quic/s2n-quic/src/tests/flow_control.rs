@@ -0,0 +1,145 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::{io::testing::now, limits::Limits};
+
+const DELAY: Duration = Duration::from_millis(50);
+const LEN: usize = 10_000_000;
+const WINDOW: u64 = 64_000;
+
+/// Echoes a large payload over a high-delay path and returns the time it took to complete
+fn transfer(limits: Limits) -> Duration {
+    let model = Model::default();
+    model.set_delay(DELAY);
+    let duration = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .with_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_limits(limits)?
+            .start()?;
+
+        let duration = duration.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let start = now();
+
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            primary::spawn(async move {
+                let mut data = Data::new(LEN as _);
+                while let Some(chunk) = data.send_one(usize::MAX) {
+                    send.send(chunk).await.unwrap();
+                }
+            });
+
+            let mut data = Data::new(LEN as _);
+            while !data.is_finished() {
+                let chunk = recv.receive().await.unwrap().unwrap();
+                data.receive(&[chunk]);
+            }
+            *duration.lock().unwrap() = Some(now() - start);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let duration = duration.lock().unwrap().take().unwrap();
+    duration
+}
+
+/// Auto-tuning grows small receive windows so a high bandwidth-delay product path can be used
+#[test]
+fn receive_window_auto_tuning_test() {
+    let limits = Limits::default()
+        .with_stream_receive_window(WINDOW)
+        .unwrap()
+        .with_connection_receive_window(WINDOW)
+        .unwrap();
+
+    let static_duration = transfer(limits);
+    let tuned_duration = transfer(
+        limits
+            .with_receive_window_auto_tuning(WINDOW * 256)
+            .unwrap(),
+    );
+
+    assert!(
+        tuned_duration * 2 < static_duration,
+        "expected auto-tuning to improve throughput; static: {static_duration:?}, auto-tuned: {tuned_duration:?}"
+    );
+}
+
+/// Sends a payload to a server with the given limits and returns the flow control blocked
+/// events observed by the client
+fn blocked_events(limits: Limits) -> Vec<(events::FlowControlScope, u64)> {
+    let model = Model::default();
+    let subscriber = recorder::FlowControlBlocked::new();
+    let blocked = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .with_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let blocked = blocked.lock().unwrap().clone();
+    blocked
+}
+
+/// A sender blocked by the peer's stream window emits a stream-level event
+#[test]
+fn stream_flow_control_blocked_test() {
+    let limits = Limits::default()
+        .with_stream_receive_window(10_000)
+        .unwrap();
+
+    let blocked = blocked_events(limits);
+
+    assert!(!blocked.is_empty());
+    assert!(blocked.iter().all(|(scope, window)| matches!(
+        scope,
+        events::FlowControlScope::Stream { .. }
+    ) && *window >= 10_000));
+}
+
+/// A sender blocked by the peer's connection window emits a connection-level event
+#[test]
+fn connection_flow_control_blocked_test() {
+    let limits = Limits::default()
+        .with_connection_receive_window(10_000)
+        .unwrap();
+
+    let blocked = blocked_events(limits);
+
+    assert!(!blocked.is_empty());
+    assert!(blocked.iter().all(|(scope, window)| matches!(
+        scope,
+        events::FlowControlScope::Connection { .. }
+    ) && *window >= 10_000));
+}
@@ -0,0 +1,166 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use s2n_quic_core::transport;
+
+const RESPONSE_CHUNKS: usize = 100;
+const CHUNK_LEN: usize = 10_000;
+
+/// Starts a server which responds to a single request with a large response, without
+/// finishing the stream, and then gracefully closes the connection
+fn start_response_server(
+    handle: &io::Handle,
+    subscriber: recorder::GracefulClose,
+    timeout: Duration,
+    result: Arc<Mutex<Option<crate::connection::Result<()>>>>,
+) -> io::Result<SocketAddr> {
+    let mut server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .with_event(subscriber)?
+        .start()?;
+    let addr = server.local_addr()?;
+
+    spawn(async move {
+        let mut connection = server.accept().await.unwrap();
+        let mut stream = connection
+            .accept_bidirectional_stream()
+            .await
+            .unwrap()
+            .unwrap();
+
+        // wait for the request
+        let _ = stream.receive().await.unwrap();
+
+        let chunk = Bytes::from(vec![42; CHUNK_LEN]);
+        for _ in 0..RESPONSE_CHUNKS {
+            stream.send(chunk.clone()).await.unwrap();
+        }
+
+        *result.lock().unwrap() = Some(connection.close_gracefully(timeout).await);
+    });
+
+    Ok(addr)
+}
+
+/// The outcome of a request to a server that gracefully closes the connection
+struct Outcome {
+    /// The number of response bytes received by the client
+    received: usize,
+    /// The result of the server's graceful close
+    result: crate::connection::Result<()>,
+    /// The `timed_out` field of each `GracefulClose` event
+    events: Vec<bool>,
+    /// The error the client observed when the connection was closed
+    client_error: Option<crate::connection::Error>,
+}
+
+/// Sends a request to the server and records how the connection was closed
+fn request(model: Model, timeout: Duration) -> Outcome {
+    let subscriber = recorder::GracefulClose::new();
+    let events = subscriber.events();
+    let result = Arc::new(Mutex::new(None));
+    let received = Arc::new(Mutex::new(0));
+    let client_error = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let addr = start_response_server(handle, subscriber, timeout, result.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+
+        let received = received.clone();
+        let client_error = client_error.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"request")).await.unwrap();
+
+            while let Ok(Some(chunk)) = stream.receive().await {
+                *received.lock().unwrap() += chunk.len();
+            }
+
+            // wait for the server to close the connection
+            let error = loop {
+                match connection.accept().await {
+                    Ok(Some(_)) => continue,
+                    Ok(None) => break None,
+                    Err(error) => break Some(error),
+                }
+            };
+            *client_error.lock().unwrap() = error;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let received = *received.lock().unwrap();
+    let result = result.lock().unwrap().take().unwrap();
+    let events = events.lock().unwrap().clone();
+    let client_error = client_error.lock().unwrap().take();
+    Outcome {
+        received,
+        result,
+        events,
+        client_error,
+    }
+}
+
+/// A graceful close delivers all of the outstanding stream data before closing the connection
+#[test]
+fn graceful_close_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let outcome = request(model, Duration::from_secs(10));
+
+    assert_eq!(outcome.received, RESPONSE_CHUNKS * CHUNK_LEN);
+    assert!(outcome.result.is_ok(), "{:?}", outcome.result);
+    assert_eq!(outcome.events, [false]);
+    assert!(
+        !matches!(
+            outcome.client_error,
+            Some(crate::connection::Error::Transport { code, .. })
+                if code == transport::Error::APPLICATION_ERROR.code
+        ),
+        "{:?}",
+        outcome.client_error
+    );
+}
+
+/// The connection is closed immediately if the outstanding data isn't flushed in time
+#[test]
+fn graceful_close_timeout_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    // limit the amount of data that can be delivered before the timeout
+    model.set_max_inflight(10);
+
+    let outcome = request(model, Duration::from_millis(10));
+
+    assert!(outcome.received < RESPONSE_CHUNKS * CHUNK_LEN);
+    assert!(
+        matches!(
+            outcome.result,
+            Err(crate::connection::Error::GracefulCloseTimedOut { .. })
+        ),
+        "{:?}",
+        outcome.result
+    );
+    assert_eq!(outcome.events, [true]);
+
+    // the peer can tell the close apart from one that delivered all of the data
+    assert!(
+        matches!(
+            outcome.client_error,
+            Some(crate::connection::Error::Transport { code, .. })
+                if code == transport::Error::APPLICATION_ERROR.code
+        ),
+        "{:?}",
+        outcome.client_error
+    );
+}
@@ -0,0 +1,43 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+/// Transfers data between a client and server with GREASE enabled on the provided endpoints
+fn run(client_grease: bool, server_grease: bool) {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let limits = Limits::default().with_grease(server_grease).unwrap();
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event(events())?
+            .start()?;
+
+        let limits = Limits::default().with_grease(client_grease).unwrap();
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event(events())?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(100_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn client_grease_test() {
+    run(true, false);
+}
+
+#[test]
+fn server_grease_test() {
+    run(false, true);
+}
@@ -0,0 +1,54 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// The client reports each handshake phase in order, followed by the total handshake duration
+#[test]
+fn handshake_progress_test() {
+    let model = Model::default();
+    let delay = Duration::from_millis(50);
+    model.set_delay(delay);
+
+    let subscriber = (
+        recorder::HandshakeProgress::new(),
+        recorder::HandshakeComplete::new(),
+    );
+    let progress = subscriber.0.events();
+    let complete = subscriber.1.events();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(subscriber)?
+            .start()?;
+        start_client(client, addr, Data::new(1_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let progress = progress.lock().unwrap();
+    let phases: Vec<_> = progress
+        .iter()
+        .map(|event| format!("{:?}", event.phase))
+        .collect();
+    assert_eq!(
+        phases,
+        ["InitialSent", "HandshakeReceived", "HandshakeConfirmed"]
+    );
+
+    // the elapsed time only moves forward between phases
+    assert!(progress
+        .windows(2)
+        .all(|phases| phases[0].elapsed <= phases[1].elapsed));
+    // the server's Handshake packets take at least a round trip to arrive
+    assert!(progress[1].elapsed >= delay * 2);
+
+    let complete = complete.lock().unwrap();
+    assert_eq!(complete.len(), 1);
+    assert_eq!(complete[0].duration, progress[2].elapsed);
+    assert!(!complete[0].zero_rtt);
+    assert!(!complete[0].resumed);
+}
@@ -0,0 +1,85 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+fn limits(max_idle_timeout: Duration) -> Limits {
+    Limits::default()
+        .with_max_idle_timeout(max_idle_timeout)
+        .unwrap()
+}
+
+/// Returns the idle timeout reported by the client and server connections, respectively
+fn negotiate(
+    client_idle_timeout: Duration,
+    server_idle_timeout: Duration,
+) -> (Option<Duration>, Option<Duration>) {
+    let client_result = Arc::new(Mutex::new(None));
+    let server_result = Arc::new(Mutex::new(None));
+
+    test(Model::default(), |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits(server_idle_timeout))?
+            .with_event(events())?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits(client_idle_timeout))?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let server_result = server_result.clone();
+        spawn(async move {
+            let connection = server.accept().await.unwrap();
+            *server_result.lock().unwrap() = Some(connection.idle_timeout().unwrap());
+        });
+
+        let client_result = client_result.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            *client_result.lock().unwrap() = Some(connection.idle_timeout().unwrap());
+
+            // wait for the server to close the connection
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let client = client_result.lock().unwrap().take().unwrap();
+    let server = server_result.lock().unwrap().take().unwrap();
+    (client, server)
+}
+
+#[test]
+fn idle_timeout_min_test() {
+    let short = Duration::from_secs(10);
+    let long = Duration::from_secs(20);
+
+    // both endpoints use the smaller of the two values
+    assert_eq!(negotiate(short, long), (Some(short), Some(short)));
+    assert_eq!(negotiate(long, short), (Some(short), Some(short)));
+}
+
+#[test]
+fn idle_timeout_zero_test() {
+    let timeout = Duration::from_secs(10);
+
+    // a value of zero defers to the peer
+    assert_eq!(
+        negotiate(Duration::ZERO, timeout),
+        (Some(timeout), Some(timeout))
+    );
+    assert_eq!(
+        negotiate(timeout, Duration::ZERO),
+        (Some(timeout), Some(timeout))
+    );
+    assert_eq!(negotiate(Duration::ZERO, Duration::ZERO), (None, None));
+}
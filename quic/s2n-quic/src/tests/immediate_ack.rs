@@ -0,0 +1,90 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::now;
+
+const DELAY: Duration = Duration::from_millis(10);
+const RTT: Duration = Duration::from_millis(20);
+
+/// The server delays acknowledgements for much longer than the RTT
+const MAX_ACK_DELAY: Duration = Duration::from_millis(200);
+
+/// Sends a single ack-eliciting packet from the client once the connection is idle and returns
+/// how long it took for the server to acknowledge it
+fn ack_latency(request_immediate_ack: bool) -> Duration {
+    let model = Model::default();
+    model.set_delay(DELAY);
+
+    let ack_frames = recorder::AckFrameReceived::new();
+    let ack_frame_events = ack_frames.events();
+    let requested_at = Arc::new(Mutex::new(None));
+
+    let client_limits = provider::limits::Limits::default()
+        .with_ack_frequency(true)
+        .unwrap();
+    let server_limits = client_limits.with_max_ack_delay(MAX_ACK_DELAY).unwrap();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(server_limits)?
+            .with_event(events())?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(client_limits)?
+            .with_event((events(), ack_frames))?
+            .start()?;
+
+        let addr = start_server(server)?;
+
+        let requested_at = requested_at.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // wait for the handshake and the ACK_FREQUENCY frames to be acknowledged
+            delay(Duration::from_secs(1)).await;
+
+            *requested_at.lock().unwrap() = Some(now());
+            if request_immediate_ack {
+                connection.request_immediate_ack().unwrap();
+            } else {
+                connection.ping().unwrap();
+            }
+
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let requested_at = requested_at.lock().unwrap().unwrap();
+    let acked_at = ack_frame_events
+        .lock()
+        .unwrap()
+        .iter()
+        .copied()
+        .find(|received_at| *received_at > requested_at)
+        .expect("the packet should be acknowledged");
+
+    acked_at.saturating_duration_since(requested_at)
+}
+
+#[test]
+fn immediate_ack_test() {
+    let latency = ack_latency(true);
+    assert!(latency <= RTT, "{latency:?}");
+}
+
+#[test]
+fn delayed_ack_test() {
+    // without the request, the server waits for its max_ack_delay before acknowledging
+    let latency = ack_latency(false);
+    assert!(latency >= MAX_ACK_DELAY, "{latency:?}");
+}
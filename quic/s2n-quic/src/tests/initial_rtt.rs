@@ -0,0 +1,72 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+use s2n_quic_core::event::api::PacketHeader;
+
+/// The one-way delay of the network, which results in a 1.2s RTT
+const NETWORK_DELAY: Duration = Duration::from_millis(600);
+
+/// Returns the number of Initial and Handshake packets sent by both endpoints while
+/// completing a handshake with the given limits
+fn handshake_packets(limits: Limits) -> usize {
+    let model = Model::default();
+    model.set_delay(NETWORK_DELAY);
+
+    let subscriber = recorder::PacketSent::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber.clone()))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            connection.close(0u32.into());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.packet_header,
+                PacketHeader::Initial { .. } | PacketHeader::Handshake { .. }
+            )
+        })
+        .count()
+}
+
+/// The default initial RTT is well below the network RTT, so the PTO expires before the
+/// peer has a chance to respond and the handshake packets are needlessly retransmitted
+#[test]
+fn initial_rtt_test() {
+    let default = handshake_packets(Limits::default());
+
+    let tuned = Limits::default()
+        .with_initial_rtt(NETWORK_DELAY * 2)
+        .unwrap();
+    let tuned = handshake_packets(tuned);
+
+    assert!(
+        tuned < default,
+        "tuned initial RTT sent {tuned} packets; default sent {default} packets"
+    );
+}
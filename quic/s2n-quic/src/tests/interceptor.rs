@@ -2,6 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::*;
+use crate::connection;
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{
+    endpoint,
+    event::api::Subject,
+    packet::interceptor::{Header, Interceptor, Packet},
+    transport,
+};
 
 fn intercept_loss(loss: Loss<Random>) {
     let model = Model::default();
@@ -39,3 +47,122 @@ fn interceptor_failure_test() {
             .build(),
     )
 }
+
+// ensure a received payload can be modified after it is decrypted, but before its frames
+// are processed
+#[test]
+fn interceptor_rx_payload_test() {
+    let model = Model::default();
+    let subscriber = recorder::ConnectionClosed::new();
+    let closed_events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .with_packet_interceptor(CorruptFirstApplicationPacket)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .start()?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            if let Ok(mut connection) = client.connect(connect).await {
+                // wait for the server to close the connection
+                while let Ok(Some(_)) = connection.accept().await {}
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let closed_events = closed_events.lock().unwrap();
+    assert_eq!(1, closed_events.len());
+    assert!(matches!(
+        closed_events[0],
+        connection::Error::Transport {
+            code,
+            initiator: endpoint::Location::Local,
+            ..
+        } if code == transport::Error::PROTOCOL_VIOLATION.code
+    ));
+}
+
+/// Replaces the first frame of the first 1-RTT packet received with an unknown frame type
+struct CorruptFirstApplicationPacket;
+
+impl Interceptor for CorruptFirstApplicationPacket {
+    #[inline]
+    fn intercept_rx_payload<'a>(
+        &mut self,
+        _subject: &Subject,
+        packet: &Packet,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        if !(packet.number.space().is_application_data() && packet.number.as_u64() == 0) {
+            return payload;
+        }
+
+        let payload = payload.into_less_safe_slice();
+        // 0x1f is not assigned to any frame type
+        payload[0] = 0x1f;
+        DecoderBufferMut::new(payload)
+    }
+}
+
+// ensure the header of a transmitted packet can be modified before it is encrypted
+#[test]
+fn interceptor_tx_header_test() {
+    let model = Model::default();
+    let subscriber = recorder::EndpointDatagramDropped::new();
+    let drop_events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_packet_interceptor(RewriteDestinationConnectionId)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(100_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the rewritten packet is dropped by the server and the data it carried is retransmitted
+    let drop_events = drop_events.lock().unwrap();
+    let unknown_connection_id_drops = drop_events
+        .iter()
+        .filter(|reason| {
+            matches!(
+                reason,
+                events::DatagramDropReason::UnknownDestinationConnectionId { .. }
+            )
+        })
+        .count();
+    assert_eq!(1, unknown_connection_id_drops);
+}
+
+/// Rewrites the destination connection ID of the third 1-RTT packet transmitted
+struct RewriteDestinationConnectionId;
+
+impl Interceptor for RewriteDestinationConnectionId {
+    #[inline]
+    fn intercept_tx_header(&mut self, _subject: &Subject, packet: &Packet, header: &mut Header) {
+        if packet.number.space().is_application_data() && packet.number.as_u64() == 2 {
+            header.destination_connection_id.fill(0);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn limits() -> Limits {
+    Limits::default()
+        .with_max_idle_timeout(IDLE_TIMEOUT)
+        .unwrap()
+}
+
+/// Sets up a client connection that stays idle for several idle timeouts, calling
+/// `configure` once the handshake completes
+fn idle_client<C>(client_limits: Limits, configure: C) -> Vec<Duration>
+where
+    C: 'static + Send + FnOnce(&mut crate::Connection),
+{
+    let subscriber = recorder::KeepAliveTimerExpired::new();
+    let events = subscriber.events();
+
+    test(Model::default(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits())?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(client_limits)?
+            .with_event(subscriber)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            configure(&mut connection);
+
+            // stay idle for longer than the idle timeout
+            delay(IDLE_TIMEOUT * 3).await;
+
+            // the connection is still usable
+            let mut stream = connection.open_send_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    events.clone()
+}
+
+/// Keep-alive PINGs are sent at the requested period
+#[test]
+fn keep_alive_with_period_test() {
+    let period = Duration::from_secs(2);
+    let events = idle_client(limits(), move |connection| {
+        connection.keep_alive_with_period(period).unwrap();
+    });
+
+    assert!(events.len() >= (IDLE_TIMEOUT * 3).as_secs() as usize / 2 - 1);
+    assert!(events.iter().all(|timeout| *timeout == period));
+}
+
+/// The keep-alive period configured on the limits is used by default
+#[test]
+fn keep_alive_limits_default_test() {
+    let period = Duration::from_secs(5);
+    let client_limits = limits().with_keep_alive_period(period).unwrap();
+    let events = idle_client(client_limits, |_connection| {});
+
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|timeout| *timeout == period));
+}
+
+/// A period that isn't less than the idle timeout is rejected
+#[test]
+fn keep_alive_with_period_exceeds_idle_timeout_test() {
+    let events = idle_client(limits(), |connection| {
+        let error = connection
+            .keep_alive_with_period(IDLE_TIMEOUT * 2)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            crate::connection::KeepAliveError::InvalidPeriod { .. }
+        ));
+
+        // fall back to the default period so the rest of the test can complete
+        connection.keep_alive(true).unwrap();
+    });
+
+    // the default period is 3/4 of the idle timeout
+    assert!(!events.is_empty());
+    assert!(events
+        .iter()
+        .all(|timeout| *timeout == IDLE_TIMEOUT * 3 / 4));
+}
@@ -0,0 +1,107 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+/// Runs a client that sends `data` to the echo server, returning the `KeyUpdated` events for
+/// the client and server
+fn key_updates<C>(
+    client_limits: Limits,
+    data: Data,
+    configure: C,
+) -> (Vec<events::KeyUpdated>, Vec<events::KeyUpdated>)
+where
+    C: 'static + Send + FnOnce(&mut crate::Connection),
+{
+    let client_subscriber = recorder::KeyUpdated::new();
+    let client_events = client_subscriber.events();
+    let server_subscriber = recorder::KeyUpdated::new();
+    let server_events = server_subscriber.events();
+
+    test(Model::default(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(server_subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(client_limits)?
+            .with_event(client_subscriber)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            configure(&mut connection);
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            let mut send_data = data;
+            let mut recv_data = data;
+
+            while let Some(chunk) = send_data.send_one(usize::MAX) {
+                stream.send(chunk).await.unwrap();
+            }
+            stream.finish().unwrap();
+
+            // the echo server doesn't finish its side of the stream so wait for all of the data
+            while !recv_data.is_finished() {
+                let chunk = stream.receive().await.unwrap().unwrap();
+                recv_data.receive(&[chunk]);
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let client_events = client_events.lock().unwrap().clone();
+    let server_events = server_events.lock().unwrap().clone();
+    (client_events, server_events)
+}
+
+/// Both peers rotate to the next key phase after the client initiates a key update
+#[test]
+fn initiate_key_update_test() {
+    let (client, server) = key_updates(Limits::default(), Data::new(10_000), |connection| {
+        connection.initiate_key_update().unwrap();
+    });
+
+    assert_eq!(client.len(), 1);
+    assert_eq!(server.len(), 1);
+
+    for event in client.iter().chain(server.iter()) {
+        assert_eq!(event.key_phase, 1);
+        assert!(event.packets_sent > 0);
+    }
+}
+
+/// Keys are updated after the configured number of bytes have been sent
+#[test]
+fn key_update_bytes_test() {
+    let limits = Limits::default().with_key_update_bytes(20_000).unwrap();
+    let (client, server) = key_updates(limits, Data::new(200_000), |_connection| {});
+
+    assert!(client.len() > 1, "{client:?}");
+    assert!(server.len() > 1, "{server:?}");
+
+    for events in [&client, &server] {
+        for (idx, event) in events.iter().enumerate() {
+            // the phase alternates with each update
+            assert_eq!(event.key_phase as usize, (idx + 1) % 2);
+        }
+    }
+}
+
+/// Keys aren't updated unless requested
+#[test]
+fn no_key_update_test() {
+    let (client, server) = key_updates(Limits::default(), Data::new(10_000), |_connection| {});
+
+    assert!(client.is_empty());
+    assert!(server.is_empty());
+}
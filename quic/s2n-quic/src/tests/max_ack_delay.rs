@@ -0,0 +1,65 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+const MESSAGES: usize = 50;
+const MESSAGE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Sends a small message from the client every `MESSAGE_INTERVAL` and returns the number of
+/// ACK frames sent by the server, which is configured with the provided `max_ack_delay`
+fn run(max_ack_delay: Duration) -> usize {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+
+    let ack_frames = recorder::AckFrameSent::new();
+    let ack_frame_events = ack_frames.events();
+    let limits = provider::limits::Limits::default()
+        .with_max_ack_delay(max_ack_delay)
+        .unwrap();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), ack_frames))?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            for _ in 0..MESSAGES {
+                stream.send(Bytes::from_static(&[1; 100])).await.unwrap();
+                delay(MESSAGE_INTERVAL).await;
+            }
+
+            stream.finish().unwrap();
+            delay(Duration::from_secs(1)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let ack_frames = ack_frame_events.lock().unwrap().len();
+    ack_frames
+}
+
+#[test]
+fn max_ack_delay_test() {
+    // the server acknowledges each message before the next one arrives
+    let immediate = run(Duration::from_millis(5));
+    assert!(immediate >= MESSAGES, "{immediate}");
+
+    // the server waits up to 50ms, so several messages are acknowledged in each ACK
+    let batched = run(Duration::from_millis(50));
+    assert!(
+        batched * 3 < immediate,
+        "batched: {batched}, immediate: {immediate}"
+    );
+}
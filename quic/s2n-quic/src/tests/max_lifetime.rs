@@ -0,0 +1,170 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::now;
+
+/// Connects to a server with the provided max connection lifetime and returns how long the
+/// client's connection was open, along with the errors reported by the server
+fn run(max_lifetime: Duration, request: bool) -> (Duration, Vec<crate::connection::Error>) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let subscriber = recorder::ConnectionClosed::new();
+    let closed = subscriber.events();
+    let lifetime = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let limits = provider::limits::Limits::default()
+            .with_max_connection_lifetime(max_lifetime)
+            .unwrap();
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+
+        let lifetime = lifetime.clone();
+        primary::spawn(async move {
+            let start = now();
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            if request {
+                let mut stream = connection.open_bidirectional_stream().await.unwrap();
+                stream.send(Bytes::from_static(b"A")).await.unwrap();
+                let chunk = stream.receive().await.unwrap().unwrap();
+                assert_eq!(&chunk[..], b"A");
+            }
+
+            // wait for the server to close the connection
+            while let Ok(Some(_)) = connection.accept().await {}
+
+            *lifetime.lock().unwrap() = Some(now().saturating_duration_since(start));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let lifetime = lifetime.lock().unwrap().take().unwrap();
+    let closed = closed.lock().unwrap().clone();
+    (lifetime, closed)
+}
+
+#[test]
+fn max_lifetime_test() {
+    let max_lifetime = Duration::from_secs(5);
+    let (lifetime, closed) = run(max_lifetime, true);
+
+    // the connection is closed well before the default idle timeout
+    assert!(lifetime >= max_lifetime, "{lifetime:?}");
+    assert!(
+        lifetime < max_lifetime + Duration::from_secs(1),
+        "{lifetime:?}"
+    );
+
+    assert!(
+        matches!(
+            closed[..],
+            [crate::connection::Error::MaxLifetimeExceeded { max_lifetime: m, .. }] if m == max_lifetime
+        ),
+        "{closed:?}"
+    );
+}
+
+/// A connection that reaches its lifetime during the handshake is closed once the handshake
+/// completes, rather than failing the handshake
+#[test]
+fn max_lifetime_handshake_test() {
+    let max_lifetime = Duration::from_millis(10);
+    let (_lifetime, closed) = run(max_lifetime, false);
+
+    assert!(
+        matches!(
+            closed[..],
+            [crate::connection::Error::MaxLifetimeExceeded { .. }]
+        ),
+        "{closed:?}"
+    );
+}
+
+/// A peer that stops reading can't keep the connection open past the flush timeout
+#[test]
+fn max_lifetime_flush_timeout_test() {
+    let max_lifetime = Duration::from_secs(1);
+    let flush_timeout = Duration::from_secs(2);
+
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let subscriber = recorder::ConnectionClosed::new();
+    let closed = subscriber.events();
+    let lifetime = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let limits = provider::limits::Limits::default()
+            .with_max_connection_lifetime(max_lifetime)
+            .unwrap()
+            .with_max_connection_lifetime_flush_timeout(flush_timeout)
+            .unwrap();
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = server.local_addr()?;
+
+        primary::spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            // keep sending until the connection is closed
+            let mut data = Data::new(u64::MAX);
+            while let Some(chunk) = data.send_one(usize::MAX) {
+                if stream.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let lifetime = lifetime.clone();
+        primary::spawn(async move {
+            let start = now();
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // accept the stream but never read from it
+            let _stream = connection.accept_receive_stream().await.unwrap();
+
+            // wait for the server to close the connection
+            while let Ok(Some(_)) = connection.accept().await {}
+
+            *lifetime.lock().unwrap() = Some(now().saturating_duration_since(start));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let lifetime = lifetime.lock().unwrap().take().unwrap();
+    assert!(lifetime >= max_lifetime + flush_timeout, "{lifetime:?}");
+    assert!(
+        lifetime < max_lifetime + flush_timeout + Duration::from_secs(1),
+        "{lifetime:?}"
+    );
+
+    let closed = closed.lock().unwrap().clone();
+    assert!(
+        matches!(
+            closed[..],
+            [crate::connection::Error::GracefulCloseTimedOut { timeout, .. }] if timeout == flush_timeout
+        ),
+        "{closed:?}"
+    );
+}
@@ -0,0 +1,81 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+use s2n_quic_core::time::Timestamp;
+
+const NETWORK_DELAY: Duration = Duration::from_millis(50);
+const BLACKHOLE_START: Duration = Duration::from_millis(400);
+const BLACKHOLE_DURATION: Duration = Duration::from_secs(5);
+const MAX_PTO: Duration = Duration::from_millis(500);
+
+/// Returns the longest period the client went without sending a packet while all of the
+/// packets were being dropped
+fn max_blackhole_send_gap(limits: Limits) -> Duration {
+    let model = Model::default();
+    model.set_delay(NETWORK_DELAY);
+
+    let subscriber = recorder::PacketSentTime::new();
+    let sent = subscriber.events();
+    let window = Arc::new(Mutex::new(None));
+
+    test(model.clone(), |handle| {
+        let addr = server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        start_client(client, addr, Data::new(1_000_000))?;
+
+        let window = window.clone();
+        spawn(async move {
+            // drop all of the packets in the middle of the transfer
+            delay(BLACKHOLE_START).await;
+            let start = crate::provider::io::testing::now();
+            model.set_drop_rate(1.0);
+
+            delay(BLACKHOLE_DURATION).await;
+            let end = crate::provider::io::testing::now();
+            model.set_drop_rate(0.0);
+
+            *window.lock().unwrap() = Some((start, end));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let (start, end): (Timestamp, Timestamp) = window.lock().unwrap().unwrap();
+    let sent = sent.lock().unwrap();
+    let sent: Vec<_> = sent
+        .iter()
+        .filter(|time| (start..end).contains(time))
+        .collect();
+
+    assert!(sent.len() > 1, "the client should send probes");
+
+    sent.windows(2)
+        .map(|pair| pair[1].saturating_duration_since(*pair[0]))
+        .max()
+        .unwrap()
+}
+
+/// Without a maximum, the PTO period doubles each time it expires
+#[test]
+fn default_pto_backoff_test() {
+    let gap = max_blackhole_send_gap(Limits::default());
+
+    assert!(gap > MAX_PTO, "longest gap was {gap:?}");
+}
+
+/// The PTO period never exceeds the configured maximum while the path is silent
+#[test]
+fn max_pto_test() {
+    let limits = Limits::default().with_max_pto(MAX_PTO).unwrap();
+    let gap = max_blackhole_send_gap(limits);
+
+    assert!(gap <= MAX_PTO, "longest gap was {gap:?}");
+}
@@ -149,3 +149,101 @@ fn mtls_auth_failure() {
     // confirm server connection was attempted but failed
     assert!(server_connection_closed.load(Ordering::SeqCst));
 }
+
+#[test]
+fn mtls_peer_certificate() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let server_peer_chain = Arc::new(Mutex::new(None));
+    let server_peer_chain_clone = server_peer_chain.clone();
+    let client_peer_chain = Arc::new(Mutex::new(None));
+    let client_peer_chain_clone = client_peer_chain.clone();
+
+    test(model, |handle| {
+        let server_tls = build_server_mtls_provider(certificates::MTLS_CA_CERT)?;
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+
+        let addr = server.local_addr()?;
+        spawn(async move {
+            let conn = server.accept().await.unwrap();
+            *server_peer_chain_clone.lock().unwrap() = Some(conn.peer_certificate().unwrap());
+        });
+
+        let client_tls = build_client_mtls_provider(certificates::MTLS_CA_CERT)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let conn = client.connect(connect).await.unwrap();
+            *client_peer_chain_clone.lock().unwrap() = Some(conn.peer_certificate().unwrap());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the server should observe the client's certificate and vice versa
+    let server_peer_chain = server_peer_chain.lock().unwrap().take().unwrap();
+    assert!(!server_peer_chain.is_empty());
+    let client_peer_chain = client_peer_chain.lock().unwrap().take().unwrap();
+    assert!(!client_peer_chain.is_empty());
+    assert_ne!(server_peer_chain[0], client_peer_chain[0]);
+}
+
+#[test]
+fn mtls_client_without_certificate() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let server_connection_closed = Arc::new(AtomicBool::new(false));
+    let server_connection_closed_clone = server_connection_closed.clone();
+
+    test(model, |handle| {
+        let server_tls = build_server_mtls_provider(certificates::MTLS_CA_CERT)?;
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+
+        let addr = server.local_addr()?;
+        spawn(async move {
+            if server.accept().await.is_some() {
+                panic!("connection should not be accepted without a client certificate");
+            }
+            server_connection_closed_clone.store(true, Ordering::SeqCst);
+        });
+
+        // the client trusts the server but doesn't have an identity to present
+        let client_tls = provider::tls::default::Client::builder()
+            .with_certificate(certificates::MTLS_CA_CERT)?
+            .build()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let result = async {
+                let mut conn = client.connect(connect).await?;
+                conn.accept_bidirectional_stream().await?;
+                Ok::<_, crate::connection::Error>(())
+            }
+            .await;
+
+            assert!(result.is_err(), "handshake should fail");
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(server_connection_closed.load(Ordering::SeqCst));
+}
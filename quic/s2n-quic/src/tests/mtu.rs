@@ -2,10 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::*;
+use crate::provider::{
+    limits::Limits,
+    mtu::{self, MtuController},
+};
 use s2n_codec::encoder::scatter;
 use s2n_quic_core::{
-    event::api::Subject,
+    event::api::{PacketHeader, Subject},
     packet::interceptor::{Interceptor, Packet},
+    recovery::{K_PACKET_THRESHOLD, K_TIME_THRESHOLD},
 };
 
 // Construct a simulation where a client sends some data, which the server echos
@@ -43,7 +48,15 @@ fn mtu_updates(max_mtu: u16) -> Vec<events::MtuUpdated> {
 // then jumbo frames should be negotiated.
 #[test]
 fn mtu_probe_jumbo_frame_test() {
-    let events = mtu_updates(9_001);
+    let max_mtu = 9_001;
+    let model = Model::default();
+    model.set_max_udp_payload(max_mtu);
+
+    let packet_sent = recorder::PacketSent::new();
+    let packets = packet_sent.events();
+    let events = EchoServer::new(Data::new(10_000_000))
+        .with_max_mtu(max_mtu)
+        .run(model, (recorder::MtuUpdated::new(), packet_sent));
 
     // handshake is padded to 1200, so we should immediate have an mtu of 1200
     // since the handshake successfully completes
@@ -63,6 +76,128 @@ fn mtu_probe_jumbo_frame_test() {
     // find with a payload of 10_000_000 bytes.
     let last_probe = events.last().unwrap();
     assert_eq!(last_probe.mtu, 8943);
+
+    // each acknowledged probe was padded to the full size being probed
+    let packets = packets.lock().unwrap();
+    for probe in events.iter().filter(|event| {
+        matches!(
+            event.cause,
+            events::MtuUpdatedCause::ProbeAcknowledged { .. }
+        )
+    }) {
+        assert!(
+            packets.iter().any(|packet| {
+                matches!(packet.packet_header, PacketHeader::OneRtt { .. })
+                    && packet.packet_len == probe.mtu as usize
+            }),
+            "missing probe packet for {}",
+            probe.mtu
+        );
+    }
+}
+
+// the client's first Initial packet is padded to the minimum QUIC datagram size
+#[test]
+fn initial_packet_padding_test() {
+    let model = Model::default();
+
+    let subscriber = recorder::PacketSent::new();
+    let packets = subscriber.events();
+
+    test(model, |handle| {
+        let server = build_server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let packets = packets.lock().unwrap();
+    let first = packets.first().unwrap();
+    assert!(
+        matches!(first.packet_header, PacketHeader::Initial { number: 0, .. }),
+        "{:?}",
+        first.packet_header
+    );
+    assert_eq!(first.packet_len, 1200);
+}
+
+// the MTU queried from the connection matches the last MtuUpdated event, which is the
+// largest UDP payload that can be sent on the path
+#[test]
+fn path_mtu_test() {
+    let model = Model::default();
+    let max_mtu = 9_001;
+    model.set_max_udp_payload(max_mtu);
+
+    let subscriber = recorder::MtuUpdated::new();
+    let events = subscriber.events();
+    let path_mtus = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(subscriber)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let events = events.clone();
+        let path_mtus = path_mtus.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut stream) = stream.split();
+
+            // drain the data echoed back by the server
+            primary::spawn(
+                async move { while let Some(_chunk) = recv.receive().await.unwrap() {} },
+            );
+
+            let mut data = Data::new(10_000_000);
+            loop {
+                let path_mtu = connection.path_mtu().unwrap();
+                assert_eq!(
+                    path_mtu,
+                    events.lock().unwrap().last().unwrap().mtu as usize
+                );
+                path_mtus.lock().unwrap().push(path_mtu);
+
+                // size each chunk to the current path MTU
+                let chunk = match data.send_one(path_mtu) {
+                    Some(chunk) => chunk,
+                    None => break,
+                };
+                stream.send(chunk).await.unwrap();
+            }
+
+            stream.finish().unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let path_mtus = path_mtus.lock().unwrap();
+    // the handshake is padded to 1200 bytes
+    assert_eq!(*path_mtus.first().unwrap(), 1200);
+    // the MTU increases as the client probes for jumbo frames
+    assert_eq!(*path_mtus.last().unwrap(), 8943);
+    // the MTU never decreases since the network doesn't change
+    assert!(path_mtus.windows(2).all(|mtus| mtus[0] <= mtus[1]));
+    // the IP and UDP headers are excluded from the reported MTU
+    // MAX_MTU - UDP_HEADER_LEN - IPV4_HEADER_LEN
+    assert!(path_mtus.iter().all(|mtu| *mtu <= max_mtu as usize - 28));
 }
 
 // if we specify jumbo frames on the endpoint and the network does not support
@@ -75,6 +210,155 @@ fn mtu_probe_jumbo_frame_unsupported_test() {
     assert_eq!(last_mtu.mtu, 1472);
 }
 
+// if the endpoint supports jumbo frames but the network does not, probing
+// concludes at the largest size the network supports and this is signalled
+// exactly once
+#[test]
+fn mtu_probing_complete_test() {
+    let model = Model::default();
+    model.set_max_udp_payload(1_500);
+    let max_mtu = 9_001;
+
+    let subscriber = recorder::MtuProbingComplete::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    let complete = &events[0];
+    // ETHERNET_MTU - UDP_HEADER_LEN - IPV4_HEADER_LEN
+    assert_eq!(complete.mtu, 1472);
+    // larger probes were attempted, but never acknowledged
+    assert!(complete.max_probed_mtu > 1472);
+    assert!(complete.probes_sent > 1);
+}
+
+/// Probes for a discrete set of UDP payload sizes instead of binary searching
+#[derive(Clone, Copy, Debug, Default)]
+struct DiscreteSizes;
+
+impl DiscreteSizes {
+    const SIZES: [u16; 3] = [1472, 4000, 8000];
+}
+
+impl mtu::Endpoint for DiscreteSizes {
+    type MtuController = Self;
+
+    fn new_mtu_controller(&mut self, _path_info: mtu::PathInfo) -> Self::MtuController {
+        *self
+    }
+}
+
+impl MtuController for DiscreteSizes {
+    fn next_probe_size(&mut self, current: u16, max: u16) -> u16 {
+        Self::SIZES
+            .iter()
+            .copied()
+            .find(|size| *size > current && *size <= max)
+            .unwrap_or(current)
+    }
+}
+
+// if a custom MTU provider is specified, only the sizes it selects should be probed
+#[test]
+fn mtu_probe_custom_controller_test() {
+    let model = Model::default();
+    let max_mtu = 9_001;
+    model.set_max_udp_payload(max_mtu);
+
+    let subscriber = recorder::MtuUpdated::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_mtu(DiscreteSizes)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    let probed: Vec<_> = events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.cause,
+                events::MtuUpdatedCause::ProbeAcknowledged { .. }
+            )
+        })
+        .map(|event| event.mtu)
+        .collect();
+    assert_eq!(probed, DiscreteSizes::SIZES);
+}
+
+// if we specify a probe interval, consecutive MTU probes are spaced out by
+// at least that interval
+#[test]
+fn mtu_probe_interval_test() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(10);
+    let probe_interval = Duration::from_millis(100);
+    let max_mtu = 9001;
+    let subscriber = recorder::MtuProbeAcknowledged::new();
+    let events = subscriber.events();
+
+    model.set_delay(rtt / 2);
+    model.set_max_udp_payload(max_mtu);
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(
+                handle
+                    .builder()
+                    .with_max_mtu(max_mtu)
+                    .with_mtu_probe_interval(probe_interval)
+                    .build()?,
+            )?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        // we need a large payload to allow for multiple rounds of MTU probing
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let acknowledged = events.lock().unwrap();
+    assert!(acknowledged.len() > 1);
+    for pair in acknowledged.windows(2) {
+        assert!(pair[1] - pair[0] >= probe_interval);
+    }
+}
+
 // if we lose every packet during a round trip and then allow packets through,
 // this is not determined to be an MTU black hole
 #[test]
@@ -88,15 +372,25 @@ fn mtu_loss_no_blackhole() {
     model.set_delay(rtt / 2);
     model.set_max_udp_payload(max_mtu);
 
+    // pin the loss detection thresholds so the packets lost during the round trip are
+    // detected the same way regardless of the default configuration
+    let limits = Limits::default()
+        .with_packet_reordering_threshold(K_PACKET_THRESHOLD)
+        .unwrap()
+        .with_time_threshold(K_TIME_THRESHOLD)
+        .unwrap();
+
     test(model.clone(), |handle| {
         let server = Server::builder()
             .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
             .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
             .with_event(subscriber)?
             .start()?;
         let client = Client::builder()
             .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
             .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
             .start()?;
         let addr = start_server(server)?;
         // we need a large payload to allow for multiple rounds of MTU probing
@@ -123,6 +417,54 @@ fn mtu_loss_no_blackhole() {
     assert_eq!(8943, events.lock().unwrap().last().unwrap().mtu);
 }
 
+// if we lose every packet transmitted by the server during a round trip while the
+// client's packets still get through, this is not determined to be an MTU black hole
+#[test]
+fn mtu_loss_no_blackhole_tx() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(100);
+    let max_mtu = 9001;
+    let subscriber = recorder::MtuUpdated::new();
+    let events = subscriber.events();
+
+    model.set_delay(rtt / 2);
+    model.set_max_udp_payload(max_mtu);
+
+    test(model.clone(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        // we need a large payload to allow for multiple rounds of MTU probing
+        start_client(client, addr, Data::new(10_000_000))?;
+
+        spawn(async move {
+            // let all packets go through for 10 RTTs - this will reach the end of MTU probing
+            model.set_drop_rate_tx(0.0);
+            delay(rtt * 10).await;
+
+            // drop all of the server's packets for a single round trip
+            model.set_drop_rate_tx(1.0);
+            delay(rtt * 1).await;
+
+            // now let the rest of the packets through
+            model.set_drop_rate_tx(0.0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // MTU remained jumbo despite the packet loss
+    assert_eq!(8943, events.lock().unwrap().last().unwrap().mtu);
+}
+
 // if the MTU is decreased after an MTU probe previously raised the MTU for the path,
 // we detect an MTU black hole and decrease the MTU to the minimum
 #[test]
@@ -132,6 +474,8 @@ fn mtu_blackhole() {
     let max_mtu = 9001;
     let subscriber = recorder::MtuUpdated::new();
     let events = subscriber.events();
+    let persistent_congestion = recorder::PersistentCongestion::new();
+    let persistent_congestion_events = persistent_congestion.events();
 
     model.set_delay(rtt / 2);
     model.set_max_udp_payload(max_mtu);
@@ -140,7 +484,7 @@ fn mtu_blackhole() {
         let server = Server::builder()
             .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
             .with_tls(SERVER_CERTS)?
-            .with_event(subscriber)?
+            .with_event((subscriber, persistent_congestion))?
             .start()?;
         let client = Client::builder()
             .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
@@ -165,6 +509,209 @@ fn mtu_blackhole() {
 
     // MTU dropped to the minimum
     assert_eq!(1200, events.lock().unwrap().last().unwrap().mtu);
+    // the black hole is not mistaken for congestion collapse
+    assert!(persistent_congestion_events.lock().unwrap().is_empty());
+}
+
+/// Lowers the path MTU once probing has completed and returns how long the server took to
+/// detect the black hole with the given threshold
+fn mtu_blackhole_detection_delay(black_hole_threshold: Option<u8>) -> Duration {
+    let model = Model::default();
+    let rtt = Duration::from_millis(100);
+    let max_mtu = 9001;
+    let subscriber = recorder::MtuBlackholeDetected::new();
+    let events = subscriber.events();
+    let lowered_at = Arc::new(Mutex::new(None));
+
+    model.set_delay(rtt / 2);
+    model.set_max_udp_payload(max_mtu);
+
+    test(model.clone(), |handle| {
+        let mut io = handle.builder().with_max_mtu(max_mtu);
+        if let Some(black_hole_threshold) = black_hole_threshold {
+            io = io.with_mtu_blackhole_threshold(black_hole_threshold);
+        }
+        let server = Server::builder()
+            .with_io(io.build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().with_max_mtu(max_mtu).build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        // we need a large payload to allow for multiple rounds of MTU probing
+        start_client(client, addr, Data::new(10_000_000))?;
+
+        let lowered_at = lowered_at.clone();
+        spawn(async move {
+            // let all packets go through for 10 RTTs - this will reach the end of MTU probing
+            model.set_drop_rate(0.0);
+            delay(rtt * 10).await;
+
+            // decrease the MTU to trigger a blackhole
+            model.set_max_udp_payload(1200);
+            *lowered_at.lock().unwrap() = Some(crate::provider::io::testing::now());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let lowered_at = lowered_at.lock().unwrap().unwrap();
+    let detected_at = *events
+        .lock()
+        .unwrap()
+        .first()
+        .expect("the black hole should be detected");
+    detected_at.saturating_duration_since(lowered_at)
+}
+
+// a larger black hole threshold delays, but does not prevent, decreasing the MTU
+// to the minimum when the loss persists
+#[test]
+fn mtu_blackhole_threshold_test() {
+    let default_delay = mtu_blackhole_detection_delay(None);
+    let delay = mtu_blackhole_detection_delay(Some(10));
+
+    assert!(
+        delay > default_delay,
+        "threshold 10: {delay:?}, default: {default_delay:?}"
+    );
+}
+
+// a threshold that can never be exceeded would silently disable black hole detection
+#[test]
+fn mtu_blackhole_threshold_too_large_test() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        assert!(handle
+            .builder()
+            .with_mtu_blackhole_threshold(u8::MAX)
+            .build()
+            .is_err());
+        assert!(handle
+            .builder()
+            .with_mtu_blackhole_threshold(u8::MAX - 1)
+            .build()
+            .is_ok());
+        Ok(())
+    })
+    .unwrap();
+}
+
+// if both endpoints are configured with a minimum MTU, the path starts at that
+// MTU and probing continues upwards from there
+#[test]
+fn mtu_min_mtu_test() {
+    let model = Model::default();
+    model.set_max_udp_payload(9_001);
+
+    let subscriber = recorder::MtuUpdated::new();
+    let events = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(
+                handle
+                    .builder()
+                    .with_max_mtu(9_001)
+                    .with_min_mtu(1_500)
+                    .build()?,
+            )?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(
+                handle
+                    .builder()
+                    .with_max_mtu(9_001)
+                    .with_min_mtu(1_500)
+                    .build()?,
+            )?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(10_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let events = events.lock().unwrap();
+
+    // ETHERNET_MTU - UDP_HEADER_LEN - IPV4_HEADER_LEN
+    let handshake_mtu = events[0].clone();
+    assert_eq!(handshake_mtu.mtu, 1472);
+    assert!(matches!(
+        handshake_mtu.cause,
+        events::MtuUpdatedCause::NewPath { .. }
+    ));
+
+    assert!(events.iter().all(|event| event.mtu >= 1472));
+    assert!(events.last().unwrap().mtu > 1472);
+}
+
+// a server configured with a minimum MTU still accepts initial packets from a client
+// that only pads them to the 1200 bytes required by QUIC
+#[test]
+fn mtu_min_mtu_minimum_initial_test() {
+    let model = Model::default();
+    let subscriber = recorder::PacketDropped::new();
+    let drop_events = subscriber.events();
+    let connected = Arc::new(Mutex::new(false));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().with_min_mtu(1_500).build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let connected = connected.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            *connected.lock().unwrap() = client.connect(connect).await.is_ok();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(*connected.lock().unwrap());
+    assert!(!drop_events
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|reason| *reason == recorder::PacketDropReason::UndersizedInitialPacket));
+}
+
+// the minimum MTU must be valid and must not exceed the maximum MTU
+#[test]
+fn mtu_min_mtu_greater_than_max_mtu_test() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        assert!(handle
+            .builder()
+            .with_max_mtu(1_500)
+            .with_min_mtu(1_501)
+            .build()
+            .is_err());
+        // values below the QUIC minimum are rejected rather than panicking
+        assert!(handle.builder().with_min_mtu(1_000).build().is_err());
+        assert!(handle.builder().with_max_mtu(1_000).build().is_err());
+        Ok(())
+    })
+    .unwrap();
 }
 
 // ensure the server enforces the minimum MTU for all initial packets
@@ -0,0 +1,45 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::tls;
+
+#[test]
+fn ocsp_response_test() {
+    let model = Model::default();
+    let ocsp_response = Arc::new(Mutex::new(None));
+    let ocsp_response_clone = ocsp_response.clone();
+
+    test(model, |handle| {
+        let server_tls = tls::default::Server::builder()
+            .with_certificate(certificates::CERT_PEM, certificates::KEY_PEM)?
+            .with_ocsp_response(certificates::OCSP_DER)?
+            .build()?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client_tls = tls::default::Client::builder()
+            .with_certificate(certificates::CERT_PEM)?
+            .with_ocsp_stapling()?
+            .build()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(client_tls)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let conn = client.connect(connect).await.unwrap();
+            *ocsp_response_clone.lock().unwrap() = conn.ocsp_response().unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let ocsp_response = ocsp_response.lock().unwrap().take().unwrap();
+    assert_eq!(&ocsp_response[..], certificates::OCSP_DER);
+}
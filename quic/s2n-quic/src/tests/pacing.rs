@@ -0,0 +1,181 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::pacing::{self, PathInfo};
+use s2n_quic_core::{
+    recovery::{RttEstimator, K_GRANULARITY},
+    time::Timestamp,
+};
+
+/// Records the time each congestion controlled packet was sent and defers
+/// all pacing decisions to the wrapped pacer
+#[derive(Clone, Debug, Default)]
+struct Recorder<P> {
+    inner: P,
+    sent: Arc<Mutex<Vec<Timestamp>>>,
+}
+
+impl<E: pacing::Endpoint> pacing::Endpoint for Recorder<E> {
+    type Pacer = Recorder<E::Pacer>;
+
+    fn new_pacer(&mut self, path_info: PathInfo) -> Self::Pacer {
+        Recorder {
+            inner: self.inner.new_pacer(path_info),
+            sent: self.sent.clone(),
+        }
+    }
+}
+
+impl<P: pacing::Pacer> pacing::Pacer for Recorder<P> {
+    fn on_packet_sent(
+        &mut self,
+        time_sent: Timestamp,
+        bytes_sent: usize,
+        congestion_window: u32,
+        rtt_estimator: &RttEstimator,
+    ) {
+        self.sent.lock().unwrap().push(time_sent);
+        self.inner
+            .on_packet_sent(time_sent, bytes_sent, congestion_window, rtt_estimator);
+    }
+
+    fn next_departure_time(
+        &self,
+        now: Timestamp,
+        congestion_window: u32,
+        rtt_estimator: &RttEstimator,
+        congestion_controller_departure_time: Option<Timestamp>,
+    ) -> Option<Timestamp> {
+        self.inner.next_departure_time(
+            now,
+            congestion_window,
+            rtt_estimator,
+            congestion_controller_departure_time,
+        )
+    }
+}
+
+/// Spaces each congestion controlled packet by a fixed interval
+#[derive(Clone, Copy, Debug, Default)]
+struct FixedInterval {
+    next_departure_time: Option<Timestamp>,
+}
+
+const FIXED_INTERVAL: Duration = Duration::from_millis(5);
+
+impl pacing::Endpoint for FixedInterval {
+    type Pacer = Self;
+
+    fn new_pacer(&mut self, _path_info: PathInfo) -> Self::Pacer {
+        Self::default()
+    }
+}
+
+impl pacing::Pacer for FixedInterval {
+    fn on_packet_sent(
+        &mut self,
+        time_sent: Timestamp,
+        _bytes_sent: usize,
+        _congestion_window: u32,
+        _rtt_estimator: &RttEstimator,
+    ) {
+        self.next_departure_time = Some(time_sent + FIXED_INTERVAL);
+    }
+
+    fn next_departure_time(
+        &self,
+        _now: Timestamp,
+        _congestion_window: u32,
+        _rtt_estimator: &RttEstimator,
+        _congestion_controller_departure_time: Option<Timestamp>,
+    ) -> Option<Timestamp> {
+        self.next_departure_time
+    }
+}
+
+// Construct a simulation where a client sends some data, which the server echos
+// back. The time each congestion controlled packet was sent by the server is
+// recorded and returned at the end of the simulation.
+fn server_sent_times<E: pacing::Endpoint>(pacer: E) -> Vec<Timestamp> {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let pacer = Recorder {
+        inner: pacer,
+        sent: Default::default(),
+    };
+    let sent = pacer.sent.clone();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_pacing(pacer)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let sent = sent.lock().unwrap();
+    sent.clone()
+}
+
+// Returns the largest number of packets sent at the same time
+fn max_burst(sent: &[Timestamp]) -> usize {
+    let mut max = 0;
+    let mut burst = 0;
+    let mut prev = None;
+    for time_sent in sent {
+        if prev == Some(time_sent) {
+            burst += 1;
+        } else {
+            burst = 1;
+        }
+        max = max.max(burst);
+        prev = Some(time_sent);
+    }
+    max
+}
+
+// Disabling pacing should allow larger bursts of packets than the default pacer,
+// which spreads bursts evenly over time
+#[test]
+fn pacing_disabled_test() {
+    let default_burst = max_burst(&server_sent_times(pacing::Default::default()));
+    let no_pacing_burst = max_burst(&server_sent_times(pacing::NoPacing::default()));
+
+    assert!(
+        no_pacing_burst > default_burst,
+        "no_pacing_burst: {}, default_burst: {}",
+        no_pacing_burst,
+        default_burst
+    );
+}
+
+// A custom pacer should be consulted before each packet is transmitted
+#[test]
+fn pacing_fixed_interval_test() {
+    let sent = server_sent_times(FixedInterval::default());
+
+    assert!(!sent.is_empty());
+
+    for window in sent.windows(2) {
+        // packets coalesced into the same datagram are sent at the same time
+        if window[0] == window[1] {
+            continue;
+        }
+        // departure times within the timer granularity are considered elapsed
+        assert!(
+            window[1] - window[0] >= FIXED_INTERVAL - K_GRANULARITY,
+            "{:?}",
+            window
+        );
+    }
+}
@@ -2,6 +2,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::*;
+use s2n_quic_core::time::Timestamp;
+
+/// A subscriber which records the events it receives
+pub trait Recorder: 'static + events::Subscriber + Send + Clone {
+    type Output: Clone;
+
+    fn events(&self) -> Arc<Mutex<Vec<Self::Output>>>;
+}
+
+/// Records the events of `A`, while also notifying the `B` subscriber
+impl<A, B> Recorder for (A, B)
+where
+    A: Recorder,
+    B: 'static + events::Subscriber + Send + Clone,
+{
+    type Output = A::Output;
+
+    fn events(&self) -> Arc<Mutex<Vec<Self::Output>>> {
+        self.0.events()
+    }
+}
 
 macro_rules! event_recorder {
     ($sub:ident, $event:ident, $method:ident) => {
@@ -31,6 +52,14 @@ macro_rules! event_recorder {
             }
         }
 
+        impl Recorder for $sub {
+            type Output = $storage;
+
+            fn events(&self) -> Arc<Mutex<Vec<$storage>>> {
+                self.events.clone()
+            }
+        }
+
         impl events::Subscriber for $sub {
             type ConnectionContext = $sub;
 
@@ -57,7 +86,80 @@ macro_rules! event_recorder {
 }
 
 event_recorder!(PacketSent, PacketSent, on_packet_sent);
+event_recorder!(PacketReceived, PacketReceived, on_packet_received);
+event_recorder!(ConnectionSummary, ConnectionClosed, on_connection_closed);
 event_recorder!(MtuUpdated, MtuUpdated, on_mtu_updated);
+event_recorder!(
+    CongestionWindowUpdated,
+    CongestionWindowUpdated,
+    on_congestion_window_updated
+);
+event_recorder!(
+    EcnStateChanged,
+    EcnStateChanged,
+    on_ecn_state_changed,
+    events::EcnState,
+    |event: &events::EcnStateChanged, storage: &mut Vec<events::EcnState>| {
+        storage.push(event.state.clone());
+    }
+);
+event_recorder!(
+    Congestion,
+    Congestion,
+    on_congestion,
+    events::CongestionSource,
+    |event: &events::Congestion, storage: &mut Vec<events::CongestionSource>| {
+        storage.push(event.source.clone());
+    }
+);
+event_recorder!(
+    PersistentCongestion,
+    PersistentCongestion,
+    on_persistent_congestion,
+    (Duration, u32),
+    |event: &events::PersistentCongestion, storage: &mut Vec<(Duration, u32)>| {
+        storage.push((event.duration, event.congestion_window));
+    }
+);
+event_recorder!(
+    MtuProbingComplete,
+    MtuProbingComplete,
+    on_mtu_probing_complete
+);
+event_recorder!(
+    PacketSentTime,
+    PacketSent,
+    on_packet_sent,
+    Timestamp,
+    |_event: &events::PacketSent, storage: &mut Vec<Timestamp>| {
+        storage.push(crate::provider::io::testing::now());
+    }
+);
+event_recorder!(
+    MtuProbeAcknowledged,
+    MtuUpdated,
+    on_mtu_updated,
+    Timestamp,
+    |event: &events::MtuUpdated, storage: &mut Vec<Timestamp>| {
+        if matches!(
+            event.cause,
+            events::MtuUpdatedCause::ProbeAcknowledged { .. }
+        ) {
+            storage.push(crate::provider::io::testing::now());
+        }
+    }
+);
+event_recorder!(
+    MtuBlackholeDetected,
+    MtuUpdated,
+    on_mtu_updated,
+    Timestamp,
+    |event: &events::MtuUpdated, storage: &mut Vec<Timestamp>| {
+        if matches!(event.cause, events::MtuUpdatedCause::Blackhole { .. }) {
+            storage.push(crate::provider::io::testing::now());
+        }
+    }
+);
 event_recorder!(
     PathUpdated,
     RecoveryMetrics,
@@ -79,11 +181,99 @@ event_recorder!(
         storage.push(event.pto_count);
     }
 );
+event_recorder!(
+    MinRtt,
+    RecoveryMetrics,
+    on_recovery_metrics,
+    Duration,
+    |event: &events::RecoveryMetrics, storage: &mut Vec<Duration>| {
+        storage.push(event.min_rtt);
+    }
+);
+event_recorder!(
+    RttVariance,
+    RecoveryMetrics,
+    on_recovery_metrics,
+    Duration,
+    |event: &events::RecoveryMetrics, storage: &mut Vec<Duration>| {
+        storage.push(event.rtt_variance);
+    }
+);
+event_recorder!(
+    BytesInFlight,
+    RecoveryMetrics,
+    on_recovery_metrics,
+    u32,
+    |event: &events::RecoveryMetrics, storage: &mut Vec<u32>| {
+        storage.push(event.bytes_in_flight);
+    }
+);
+event_recorder!(
+    AckRangeSent,
+    AckRangeSent,
+    on_ack_range_sent,
+    (u64, core::ops::RangeInclusive<u64>),
+    |event: &events::AckRangeSent, storage: &mut Vec<(u64, core::ops::RangeInclusive<u64>)>| {
+        if let events::PacketHeader::OneRtt { number, .. } = event.packet_header {
+            storage.push((number, event.ack_range.clone()));
+        }
+    }
+);
+event_recorder!(
+    PacketLost,
+    PacketLost,
+    on_packet_lost,
+    u64,
+    |event: &events::PacketLost, storage: &mut Vec<u64>| {
+        if let events::PacketHeader::OneRtt { number, .. } = event.packet_header {
+            storage.push(number);
+        }
+    }
+);
+event_recorder!(
+    ConnectionIdUpdated,
+    ConnectionIdUpdated,
+    on_connection_id_updated,
+    Vec<u8>,
+    |event: &events::ConnectionIdUpdated, storage: &mut Vec<Vec<u8>>| {
+        storage.push(event.current.bytes.to_vec());
+    }
+);
+event_recorder!(
+    KeepAliveTimerExpired,
+    KeepAliveTimerExpired,
+    on_keep_alive_timer_expired,
+    Duration,
+    |event: &events::KeepAliveTimerExpired, storage: &mut Vec<Duration>| {
+        storage.push(event.timeout);
+    }
+);
 event_recorder!(
     HandshakeStatus,
     HandshakeStatusUpdated,
     on_handshake_status_updated
 );
+event_recorder!(HandshakeProgress, HandshakeProgress, on_handshake_progress);
+event_recorder!(HandshakeComplete, HandshakeComplete, on_handshake_complete);
+event_recorder!(KeyUpdated, KeyUpdated, on_key_updated);
+event_recorder!(
+    DatagramSent,
+    DatagramSent,
+    on_datagram_sent,
+    u16,
+    |event: &events::DatagramSent, storage: &mut Vec<u16>| {
+        storage.push(event.len);
+    }
+);
+event_recorder!(
+    DatagramReceived,
+    DatagramReceived,
+    on_datagram_received,
+    u16,
+    |event: &events::DatagramReceived, storage: &mut Vec<u16>| {
+        storage.push(event.len);
+    }
+);
 event_recorder!(
     PacketDropped,
     PacketDropped,
@@ -95,6 +285,29 @@ event_recorder!(
         }
     }
 );
+event_recorder!(
+    ConnectionClosed,
+    ConnectionClosed,
+    on_connection_closed,
+    crate::connection::Error,
+    |event: &events::ConnectionClosed, storage: &mut Vec<crate::connection::Error>| {
+        storage.push(event.error);
+    }
+);
+event_recorder!(
+    GracefulClose,
+    GracefulClose,
+    on_graceful_close,
+    bool,
+    |event: &events::GracefulClose, storage: &mut Vec<bool>| {
+        storage.push(event.timed_out);
+    }
+);
+event_recorder!(
+    DatagramFrameDropped,
+    DatagramFrameDropped,
+    on_datagram_frame_dropped
+);
 event_recorder!(
     ActivePathUpdated,
     ActivePathUpdated,
@@ -105,6 +318,146 @@ event_recorder!(
         storage.push(addr);
     }
 );
+event_recorder!(
+    PathValidationFailed,
+    PathValidationFailed,
+    on_path_validation_failed,
+    (u8, bool, Timestamp),
+    |event: &events::PathValidationFailed, storage: &mut Vec<(u8, bool, Timestamp)>| {
+        let now = crate::provider::io::testing::now();
+        storage.push((event.attempts, event.fallback_path.is_some(), now));
+    }
+);
+event_recorder!(
+    PathValidated,
+    PathChallengeUpdated,
+    on_path_challenge_updated,
+    u64,
+    |event: &events::PathChallengeUpdated, storage: &mut Vec<u64>| {
+        if matches!(
+            event.path_challenge_status,
+            events::PathChallengeStatus::Validated { .. }
+        ) {
+            storage.push(event.path.id);
+        }
+    }
+);
+event_recorder!(
+    NatRebindingDetected,
+    NatRebindingDetected,
+    on_nat_rebinding_detected,
+    (u64, SocketAddr, SocketAddr, bool),
+    |event: &events::NatRebindingDetected,
+     storage: &mut Vec<(u64, SocketAddr, SocketAddr, bool)>| {
+        storage.push((
+            event.path.id,
+            (&event.previous_remote_addr).into(),
+            (&event.path.remote_addr).into(),
+            event.validated,
+        ));
+    }
+);
+
+#[derive(Clone, Default)]
+pub struct EndpointPacketDropped {
+    pub events: Arc<Mutex<Vec<PacketDropReason>>>,
+}
+
+impl EndpointPacketDropped {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Arc<Mutex<Vec<PacketDropReason>>> {
+        self.events.clone()
+    }
+}
+
+impl events::Subscriber for EndpointPacketDropped {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_endpoint_packet_dropped(
+        &mut self,
+        _meta: &events::EndpointMeta,
+        event: &events::EndpointPacketDropped,
+    ) {
+        if let Ok(reason) = (&event.reason).try_into() {
+            self.events.lock().unwrap().push(reason);
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct EndpointDatagramDropped {
+    pub events: Arc<Mutex<Vec<events::DatagramDropReason>>>,
+}
+
+impl EndpointDatagramDropped {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Arc<Mutex<Vec<events::DatagramDropReason>>> {
+        self.events.clone()
+    }
+}
+
+impl events::Subscriber for EndpointDatagramDropped {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_endpoint_datagram_dropped(
+        &mut self,
+        _meta: &events::EndpointMeta,
+        event: &events::EndpointDatagramDropped,
+    ) {
+        self.events.lock().unwrap().push(event.reason.clone());
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RetrySent {
+    pub events: Arc<Mutex<Vec<SocketAddr>>>,
+}
+
+impl RetrySent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Arc<Mutex<Vec<SocketAddr>>> {
+        self.events.clone()
+    }
+}
+
+impl events::Subscriber for RetrySent {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_retry_sent(&mut self, _meta: &events::EndpointMeta, event: &events::RetrySent) {
+        let addr = (&event.remote_addr).into();
+        self.events.lock().unwrap().push(addr);
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PacketDropReason {
@@ -118,6 +471,8 @@ pub enum PacketDropReason {
     NonEmptyRetryToken,
     RetryDiscarded,
     UndersizedInitialPacket,
+    RateLimited,
+    AddressFiltered,
 }
 
 impl<'a> TryFrom<&events::PacketDropReason<'a>> for PacketDropReason {
@@ -137,7 +492,79 @@ impl<'a> TryFrom<&events::PacketDropReason<'a>> for PacketDropReason {
             NonEmptyRetryToken { .. } => Self::NonEmptyRetryToken,
             RetryDiscarded { .. } => Self::RetryDiscarded,
             UndersizedInitialPacket { .. } => Self::UndersizedInitialPacket,
+            RateLimited { .. } => Self::RateLimited,
+            AddressFiltered { .. } => Self::AddressFiltered,
             _ => return Err(()),
         })
     }
 }
+event_recorder!(
+    ConnectionIdIssued,
+    ConnectionIdIssued,
+    on_connection_id_issued,
+    u64,
+    |event: &events::ConnectionIdIssued, storage: &mut Vec<u64>| {
+        storage.push(event.sequence_number);
+    }
+);
+event_recorder!(
+    ConnectionIdRetired,
+    ConnectionIdRetired,
+    on_connection_id_retired,
+    u64,
+    |event: &events::ConnectionIdRetired, storage: &mut Vec<u64>| {
+        storage.push(event.sequence_number);
+    }
+);
+event_recorder!(
+    FlowControlBlocked,
+    FlowControlBlocked,
+    on_flow_control_blocked,
+    (events::FlowControlScope, u64),
+    |event: &events::FlowControlBlocked, storage: &mut Vec<(events::FlowControlScope, u64)>| {
+        storage.push((event.scope.clone(), event.window));
+    }
+);
+event_recorder!(
+    StreamFrameSent,
+    FrameSent,
+    on_frame_sent,
+    (u64, u16, bool),
+    |event: &events::FrameSent, storage: &mut Vec<(u64, u16, bool)>| {
+        if let events::Frame::Stream {
+            id, len, is_fin, ..
+        } = event.frame
+        {
+            storage.push((id, len, is_fin));
+        }
+    }
+);
+event_recorder!(
+    AckFrameSent,
+    FrameSent,
+    on_frame_sent,
+    u64,
+    |event: &events::FrameSent, storage: &mut Vec<u64>| {
+        if let (events::PacketHeader::OneRtt { number, .. }, events::Frame::Ack { .. }) =
+            (&event.packet_header, &event.frame)
+        {
+            storage.push(*number);
+        }
+    }
+);
+event_recorder!(
+    AckFrequencyUpdated,
+    AckFrequencyUpdated,
+    on_ack_frequency_updated
+);
+event_recorder!(
+    AckFrameReceived,
+    FrameReceived,
+    on_frame_received,
+    Timestamp,
+    |event: &events::FrameReceived, storage: &mut Vec<Timestamp>| {
+        if let events::Frame::Ack { .. } = event.frame {
+            storage.push(crate::provider::io::testing::now());
+        }
+    }
+);
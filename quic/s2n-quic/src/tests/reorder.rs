@@ -0,0 +1,122 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::limits::Limits;
+
+fn reorder_test<S>(model: Model, seed: u64, subscriber: S)
+where
+    S: 'static + provider::event::Subscriber,
+{
+    reorder_test_with_limits(model, seed, Limits::default(), subscriber)
+}
+
+fn reorder_test_with_limits<S>(model: Model, seed: u64, limits: Limits, subscriber: S)
+where
+    S: 'static + provider::event::Subscriber,
+{
+    io::test_with_seed(seed, model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event(events())?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+/// Reordered packets create gaps in the ACK ranges sent by the receiver
+#[test]
+fn reorder_ack_ranges_test() {
+    let model = Model::default();
+    let subscriber = recorder::AckRangeSent::new();
+    let events = subscriber.events();
+
+    model.set_delay(Duration::from_millis(50));
+    model.set_reorder(0.1, 2);
+
+    reorder_test(model, 123456789, subscriber);
+
+    let events = events.lock().unwrap();
+    assert!(!events.is_empty());
+
+    // an ACK frame with a gap is sent as multiple ranges in the same packet
+    let has_gap = events.windows(2).any(|ranges| ranges[0].0 == ranges[1].0);
+    assert!(has_gap, "expected at least one ACK frame with a gap");
+}
+
+/// Packets displaced past the packet threshold are declared lost even though
+/// the network never dropped them
+#[test]
+fn reorder_spurious_loss_test() {
+    let model = Model::default();
+    let subscriber = recorder::PacketLost::new();
+    let events = subscriber.events();
+
+    model.set_delay(Duration::from_millis(50));
+    model.set_reorder(0.1, 10);
+
+    reorder_test(model, 123456789, subscriber);
+
+    assert!(!events.lock().unwrap().is_empty());
+}
+
+/// Raising the packet reordering threshold avoids declaring reordered packets lost
+#[test]
+fn reorder_packet_threshold_test() {
+    let run = |limits| {
+        let model = Model::default();
+        let subscriber = recorder::PacketLost::new();
+        let events = subscriber.events();
+
+        model.set_delay(Duration::from_millis(50));
+        model.set_reorder(0.1, 10);
+
+        reorder_test_with_limits(model, 123456789, limits, subscriber);
+
+        let events = events.lock().unwrap();
+        events.len()
+    };
+
+    let default = run(Limits::default());
+    let limits = Limits::default()
+        .with_packet_reordering_threshold(20)
+        .unwrap();
+    let raised = run(limits);
+
+    assert!(
+        raised < default,
+        "raised threshold lost {raised} packets; default lost {default} packets"
+    );
+}
+
+/// Reordering decisions come from the seeded RNG so runs can be reproduced
+#[test]
+fn reorder_seed_test() {
+    let run = |seed| {
+        let model = Model::default();
+        let subscriber = recorder::PacketLost::new();
+        let events = subscriber.events();
+
+        model.set_delay(Duration::from_millis(50));
+        model.set_reorder(0.1, 10);
+
+        reorder_test(model, seed, subscriber);
+
+        let events = events.lock().unwrap();
+        events.clone()
+    };
+
+    assert_eq!(run(42), run(42));
+    assert_ne!(run(42), run(43));
+}
@@ -0,0 +1,69 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::{endpoint_limits, io::testing::now};
+
+const DELAY: Duration = Duration::from_millis(50);
+
+/// Connects a client to a server and returns the time it took to establish the connection
+/// along with the addresses the server sent Retry packets to
+fn connect(retry: bool) -> (Duration, Vec<SocketAddr>) {
+    let model = Model::default();
+    model.set_delay(DELAY);
+    let subscriber = recorder::RetrySent::new();
+    let retries = subscriber.events();
+    let handshake_duration = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let limits = endpoint_limits::Default::builder()
+            .with_retry(retry)?
+            .build()?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .with_endpoint_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client = build_client(handle)?;
+        let handshake_duration = handshake_duration.clone();
+        primary::spawn(async move {
+            let start = now();
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            *handshake_duration.lock().unwrap() = Some(now() - start);
+
+            // make sure the connection is usable after the retry
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            assert_eq!(
+                stream.receive().await.unwrap(),
+                Some(Bytes::from_static(b"hello"))
+            );
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let handshake_duration = handshake_duration.lock().unwrap().take().unwrap();
+    let retries = retries.lock().unwrap().clone();
+    (handshake_duration, retries)
+}
+
+/// Enabling retry adds a round trip to the handshake and the connection still completes
+#[test]
+fn retry_test() {
+    let (baseline, retries) = connect(false);
+    assert!(retries.is_empty());
+
+    let (duration, retries) = connect(true);
+    assert_eq!(retries.len(), 1);
+    assert!(
+        duration >= baseline + DELAY * 2,
+        "expected an extra round trip; baseline: {baseline:?}, with retry: {duration:?}"
+    );
+}
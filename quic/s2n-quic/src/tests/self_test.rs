@@ -11,6 +11,71 @@ fn client_server_test() {
     test(Model::default(), client_server).unwrap();
 }
 
+/// Showing that the delay can be configured separately for each direction
+#[test]
+fn asymmetric_delay_test() {
+    let model = Model::default();
+    let delay_tx = Duration::from_millis(10);
+    let delay_rx = Duration::from_millis(90);
+    let subscriber = recorder::MinRtt::new();
+    let events = subscriber.events();
+
+    model.set_delay_tx(delay_tx).set_delay_rx(delay_rx);
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscriber)?
+            .start()?;
+        let addr = start_server(server)?;
+        client(handle, addr)?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the round trip is the sum of the delays in each direction
+    let min_rtt = *events.lock().unwrap().last().unwrap();
+    let expected = delay_tx + delay_rx;
+    assert!(
+        min_rtt >= expected && min_rtt < expected + Duration::from_millis(5),
+        "{min_rtt:?}"
+    );
+}
+
+/// Showing that the seed controls the randomness in endpoints using the testing random provider
+#[test]
+fn seed_test() {
+    let run = |seed| {
+        let subscriber = recorder::ConnectionIdUpdated::new();
+        let events = subscriber.events();
+
+        io::test_with_seed(seed, Model::default(), |handle| {
+            let server = Server::builder()
+                .with_io(handle.builder().build()?)?
+                .with_random(io::Random)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(subscriber)?
+                .start()?;
+            let addr = start_server(server)?;
+            client(handle, addr)?;
+            Ok(addr)
+        })
+        .unwrap();
+
+        let ids = events.lock().unwrap();
+        ids.clone()
+    };
+
+    let ids = run(123);
+    assert!(!ids.is_empty());
+
+    // the same seed generates the same connection IDs
+    assert_eq!(ids, run(123));
+    // while a different seed generates different ones
+    assert_ne!(ids, run(456));
+}
+
 /// Showing that the TxRecorder is working
 #[test]
 fn packet_sent_event_test() {
@@ -62,3 +127,180 @@ fn packet_sent_event_test() {
     }
     assert!(events.is_empty());
 }
+
+/// Showing that the model counts the UDP payload bytes transmitted in each direction
+#[test]
+fn bytes_on_the_wire_test() {
+    let recorder = io::TxRecorder::default();
+    let network_packets = recorder.get_packets();
+    let model = Model::default();
+    let mut server_socket = None;
+
+    test((recorder, model.clone()), |handle| {
+        let addr = server(handle)?;
+        server_socket = Some(addr);
+        let client = build_client(handle)?;
+        start_client(client, addr, Data::new(1_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let server_socket = server_socket.unwrap();
+    let (mut server_bytes, mut client_bytes) = (0, 0);
+    for packet in network_packets.lock().unwrap().iter() {
+        let local_socket: SocketAddr = packet.path.local_address.0.into();
+        if local_socket == server_socket {
+            server_bytes += packet.payload.len() as u64;
+        } else {
+            client_bytes += packet.payload.len() as u64;
+        }
+    }
+
+    assert_eq!(model.bytes_sent(), server_bytes);
+    assert_eq!(model.bytes_received(), client_bytes);
+
+    // both endpoints send at least a padded Initial packet and the 1_000 bytes of stream data
+    // without too much overhead
+    for bytes in [model.bytes_sent(), model.bytes_received()] {
+        assert!((2_200..10_000).contains(&bytes), "{bytes}");
+    }
+}
+
+/// Showing that jitter increases the RTT variance without reordering packets
+#[test]
+fn jitter_test() {
+    let run = |jitter| {
+        let model = Model::default();
+        model.set_packet_jitter(jitter);
+
+        let packet_received = recorder::PacketReceived::new();
+        let packets = packet_received.events();
+        let rtt_variance = EchoServer::new(Data::new(1_000_000))
+            .run(model, (recorder::RttVariance::new(), packet_received));
+
+        let packet_numbers: Vec<_> = packets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|packet| match packet.packet_header {
+                events::PacketHeader::OneRtt { number, .. } => Some(number),
+                _ => None,
+            })
+            .collect();
+        assert!(
+            packet_numbers.windows(2).all(|pair| pair[0] < pair[1]),
+            "{packet_numbers:?}"
+        );
+
+        *rtt_variance.last().unwrap()
+    };
+
+    let without_jitter = run(Duration::ZERO);
+    let with_jitter = run(Duration::from_millis(20));
+    assert!(
+        with_jitter > without_jitter,
+        "{with_jitter:?} > {without_jitter:?}"
+    );
+}
+
+/// Showing that a transfer over a bandwidth limited link completes near the link rate
+#[test]
+fn bandwidth_test() {
+    let model = Model::default();
+    let bandwidth = 1_000_000;
+    let len = 10_000_000;
+    let delay = Duration::from_millis(50);
+    model.set_delay(delay);
+    model.set_bandwidth(bandwidth);
+    // buffer a single bandwidth-delay product
+    model.set_buffer_bytes(bandwidth * (delay * 2).as_millis() as u64 / 1000);
+
+    let elapsed = test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+        start_client(client, addr, Data::new(len))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the data can't be transferred any faster than the link rate
+    let min = Duration::from_secs(len / bandwidth);
+    assert!(elapsed > min, "{elapsed:?}");
+    // while allowing for the handshake, slow start, and packet overhead
+    assert!(elapsed < min * 3 / 2, "{elapsed:?}");
+}
+
+/// Showing that pausing the network holds packets until it's resumed
+#[test]
+fn pause_test() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(100);
+    model.set_delay(rtt / 2);
+
+    let subscriber = recorder::ConnectionClosed::new();
+    let closed = subscriber.events();
+
+    test(model.clone(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        let addr = start_server(server)?;
+        let client = build_client(handle)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+
+        spawn(async move {
+            // wait for the handshake to complete before pausing the transfer
+            delay(rtt * 3).await;
+            model.pause();
+            delay(rtt * 3).await;
+            model.resume();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the held packets are delivered and the transfer completes without closing the connection
+    assert!(closed.lock().unwrap().is_empty());
+}
+
+/// Showing that packets can be dropped by type
+///
+/// After the handshake, the server's 1-RTT packets carry the ACKs for the client's data. Dropping
+/// them starves the client of ACKs, which causes it to retransmit with probe timeouts.
+#[test]
+fn drop_packet_type_test() {
+    let model = Model::default();
+    let rtt = Duration::from_millis(100);
+    model.set_delay(rtt / 2);
+
+    let subscriber = recorder::Pto::new();
+    let pto_counts = subscriber.events();
+
+    test(model.clone(), |handle| {
+        let addr = server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), subscriber))?
+            .start()?;
+        start_client(client, addr, Data::new(100_000))?;
+
+        spawn(async move {
+            // wait for the handshake to complete before starving the client of ACKs
+            delay(rtt * 3).await;
+            model.drop_packet_type_tx(io::PacketType::OneRtt, 1.0);
+            delay(rtt * 5).await;
+            model.drop_packet_type_tx(io::PacketType::OneRtt, 0.0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the client probes for the missing ACKs and still completes the transfer
+    let max_pto_count = pto_counts.lock().unwrap().iter().copied().max().unwrap();
+    assert!(max_pto_count > 0);
+}
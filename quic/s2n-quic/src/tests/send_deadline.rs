@@ -0,0 +1,118 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+const CHUNKS: usize = 100;
+const CHUNK_LEN: usize = 10_000;
+const DEADLINE_ERROR: u32 = 123;
+
+type StreamResult = Option<crate::stream::Result<()>>;
+
+/// Starts a server which reads a single receive stream until it is finished or reset
+fn start_receive_server(
+    handle: &io::Handle,
+    result: Arc<Mutex<StreamResult>>,
+) -> io::Result<SocketAddr> {
+    let mut server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .start()?;
+    let addr = server.local_addr()?;
+
+    spawn(async move {
+        let mut connection = server.accept().await.unwrap();
+        let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+
+        *result.lock().unwrap() = Some(loop {
+            match stream.receive().await {
+                Ok(Some(_)) => continue,
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        });
+
+        // keep the connection open for the sender
+        while let Ok(Some(_)) = connection.accept().await {}
+    });
+
+    Ok(addr)
+}
+
+/// Sends a large payload on a stream with the given send deadline and returns the result
+/// observed by the sender and the receiver
+fn send_with_deadline(model: Model, deadline: Duration) -> (StreamResult, StreamResult) {
+    let sender = Arc::new(Mutex::new(None));
+    let receiver = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let addr = start_receive_server(handle, receiver.clone())?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+
+        let sender = sender.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+            stream
+                .set_send_deadline(deadline, DEADLINE_ERROR.into())
+                .unwrap();
+
+            let chunk = Bytes::from(vec![42; CHUNK_LEN]);
+            let result = async {
+                for _ in 0..CHUNKS {
+                    stream.send(chunk.clone()).await?;
+                }
+                stream.close().await
+            }
+            .await;
+            *sender.lock().unwrap() = Some(result);
+
+            // give the receiver time to observe the result
+            delay(Duration::from_secs(5)).await;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let sender = sender.lock().unwrap().take();
+    let receiver = receiver.lock().unwrap().take();
+    (sender, receiver)
+}
+
+fn is_deadline_reset(result: &StreamResult) -> bool {
+    matches!(
+        result,
+        Some(Err(crate::stream::Error::StreamReset { error, .. }))
+            if *error == crate::application::Error::from(DEADLINE_ERROR)
+    )
+}
+
+/// A stream is reset with the configured error if its data isn't acknowledged in time
+#[test]
+fn send_deadline_expired_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_drop_rate(0.2);
+
+    let (sender, receiver) = send_with_deadline(model, Duration::from_millis(100));
+
+    assert!(is_deadline_reset(&sender), "{:?}", sender);
+    assert!(is_deadline_reset(&receiver), "{:?}", receiver);
+}
+
+/// A stream finishes normally if all of its data is acknowledged before the deadline
+#[test]
+fn send_deadline_met_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let (sender, receiver) = send_with_deadline(model, Duration::from_secs(10));
+
+    assert!(matches!(sender, Some(Ok(()))), "{:?}", sender);
+    assert!(matches!(receiver, Some(Ok(()))), "{:?}", receiver);
+}
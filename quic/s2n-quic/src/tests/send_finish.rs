@@ -0,0 +1,74 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// The final chunk of data and the end of the stream are transmitted in the same frame
+#[test]
+fn send_finish_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let subscriber = recorder::StreamFrameSent::new();
+    let frames = subscriber.events();
+    let received = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let received = received.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+
+            let mut data = vec![];
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                data.extend_from_slice(&chunk);
+            }
+            *received.lock().unwrap() = Some(data);
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), subscriber))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            stream
+                .send_finish(Bytes::from_static(b"response"))
+                .await
+                .unwrap();
+
+            // the stream is already finished so closing only waits for the acknowledgement
+            stream.close().await.unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let received = received.lock().unwrap().take().unwrap();
+    assert_eq!(&received[..], b"response");
+
+    let frames = frames.lock().unwrap();
+    assert!(
+        frames
+            .iter()
+            .any(|(_id, len, is_fin)| *is_fin && *len as usize == b"response".len()),
+        "{frames:?}"
+    );
+    assert!(
+        frames.iter().all(|(_id, len, is_fin)| !*is_fin || *len > 0),
+        "the FIN should never be sent on its own: {frames:?}"
+    );
+}
@@ -0,0 +1,61 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// Separate chunks sent in a single vectored call arrive as one contiguous stream of data
+#[test]
+fn send_vectored_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let header = Bytes::from_static(b"content-length: 100000\r\n\r\n");
+    let body = Bytes::from(vec![42; 100_000]);
+    let trailer = Bytes::from_static(b"done");
+    let expected = [&header[..], &body[..], &trailer[..]].concat();
+
+    let received = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let received = received.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+
+            let mut data = vec![];
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                data.extend_from_slice(&chunk);
+            }
+            *received.lock().unwrap() = Some(data);
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            let mut chunks = [header, body, trailer];
+            stream.send_vectored(&mut chunks).await.unwrap();
+
+            // all of the chunks are consumed by the stream
+            assert!(chunks.iter().all(|chunk| chunk.is_empty()));
+
+            stream.close().await.unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let received = received.lock().unwrap().take().unwrap();
+    assert_eq!(received.len(), expected.len());
+    assert!(received == expected, "received data did not match");
+}
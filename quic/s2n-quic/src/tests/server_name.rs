@@ -0,0 +1,98 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{
+    connection,
+    provider::limits::{ConnectionDecision, ConnectionInfo, Limiter, Limits, ServerNameInfo},
+};
+use s2n_quic_core::{crypto::CryptoError, endpoint, transport};
+
+/// Only accepts connections for a single server name
+struct ServerNameLimits {
+    server_name: &'static str,
+}
+
+impl Limiter for ServerNameLimits {
+    fn on_connection(&mut self, _info: &ConnectionInfo) -> Limits {
+        Limits::default()
+    }
+
+    fn on_server_name(&mut self, info: &ServerNameInfo) -> ConnectionDecision {
+        if info.server_name == self.server_name {
+            ConnectionDecision::Accept
+        } else {
+            ConnectionDecision::Reject
+        }
+    }
+}
+
+fn server_name_test(
+    server_name: &'static str,
+) -> (Vec<connection::Error>, Vec<events::HandshakeStatusUpdated>) {
+    let model = Model::default();
+    let closed_subscriber = recorder::ConnectionClosed::new();
+    let closed_events = closed_subscriber.events();
+    let handshake_subscriber = recorder::HandshakeStatus::new();
+    let handshake_events = handshake_subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(ServerNameLimits { server_name })?
+            .with_event((closed_subscriber, handshake_subscriber))?
+            .start()?;
+        let client = build_client(handle)?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let result = client.connect(connect).await;
+            assert_eq!(result.is_ok(), server_name == "localhost");
+
+            // exchange some data with the server if the connection was accepted
+            if let Ok(mut connection) = result {
+                let mut stream = connection.open_bidirectional_stream().await.unwrap();
+                stream.send(Bytes::from_static(b"hello")).await.unwrap();
+                stream.finish().unwrap();
+                while let Some(_chunk) = stream.receive().await.unwrap() {}
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let closed_events = closed_events.lock().unwrap().clone();
+    let handshake_events = handshake_events.lock().unwrap().clone();
+    (closed_events, handshake_events)
+}
+
+#[test]
+fn server_name_accept_test() {
+    let (_closed_events, handshake_events) = server_name_test("localhost");
+
+    assert!(handshake_events
+        .iter()
+        .any(|event| matches!(event.status, events::HandshakeStatus::Complete { .. })));
+}
+
+#[test]
+fn server_name_reject_test() {
+    let (closed_events, handshake_events) = server_name_test("example.com");
+
+    // the server aborts the handshake with an `unrecognized_name` alert
+    assert_eq!(1, closed_events.len());
+    assert!(matches!(
+        closed_events[0],
+        connection::Error::Transport {
+            code,
+            initiator: endpoint::Location::Local,
+            ..
+        } if code == transport::Error::from(CryptoError::UNRECOGNIZED_NAME).code
+    ));
+    assert!(!handshake_events
+        .iter()
+        .any(|event| matches!(event.status, events::HandshakeStatus::Complete { .. })));
+}
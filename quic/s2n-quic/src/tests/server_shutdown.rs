@@ -0,0 +1,157 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{provider::io::testing::now, server::ShutdownMode};
+use s2n_quic_core::transport;
+
+#[derive(Debug, Default)]
+struct Outcome {
+    /// Set if the client received all of the echoed data
+    transfer_finished: bool,
+    /// How long the server took to shut down
+    shutdown_duration: Option<Duration>,
+    /// Set if the server stopped returning connections after shutting down
+    accept_closed: bool,
+    /// The error the client's connection was closed with, if any
+    client_error: Option<crate::connection::Error>,
+}
+
+/// Returns `true` if the peer closed the connection with an error code that can be told apart
+/// from a clean close
+fn is_timed_out_close(error: &Option<crate::connection::Error>) -> bool {
+    matches!(
+        error,
+        Some(crate::connection::Error::Transport { code, .. })
+            if *code == transport::Error::APPLICATION_ERROR.code
+    )
+}
+
+/// Shuts down the server while a client is in the middle of an echo transfer
+fn run(mode: ShutdownMode, timeout: Duration) -> Outcome {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let outcome = Arc::new(Mutex::new(Outcome::default()));
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let client = build_client(handle)?;
+        let addr = server.local_addr()?;
+
+        let server_outcome = outcome.clone();
+        primary::spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection
+                .accept_bidirectional_stream()
+                .await
+                .unwrap()
+                .unwrap();
+
+            // wait for the transfer to start before shutting down
+            let chunk = stream.receive().await.unwrap().unwrap();
+            stream.send(chunk).await.unwrap();
+
+            spawn(async move {
+                let _connection = connection;
+                while let Ok(Some(chunk)) = stream.receive().await {
+                    let _ = stream.send(chunk).await;
+                }
+            });
+
+            let start = now();
+            server.shutdown(mode, timeout).await.unwrap();
+            let shutdown_duration = now().saturating_duration_since(start);
+            let accept_closed = server.accept().await.is_none();
+
+            let mut outcome = server_outcome.lock().unwrap();
+            outcome.shutdown_duration = Some(shutdown_duration);
+            outcome.accept_closed = accept_closed;
+        });
+
+        let client_outcome = outcome.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            let mut send_data = Data::new(1_000_000);
+            let mut recv_data = send_data;
+
+            spawn(async move {
+                while let Some(chunk) = send_data.send_one(usize::MAX) {
+                    if send.send(chunk).await.is_err() {
+                        return;
+                    }
+                }
+                let _ = send.finish();
+            });
+
+            let client_error = loop {
+                match recv.receive().await {
+                    Ok(Some(chunk)) => recv_data.receive(&[chunk]),
+                    Ok(None) => break None,
+                    Err(crate::stream::Error::ConnectionError { error, .. }) => break Some(error),
+                    Err(_) => break None,
+                }
+            };
+
+            let mut outcome = client_outcome.lock().unwrap();
+            outcome.transfer_finished = recv_data.is_finished();
+            outcome.client_error = client_error;
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let mut outcome = outcome.lock().unwrap();
+    core::mem::take(&mut *outcome)
+}
+
+#[test]
+fn server_shutdown_graceful_drain_test() {
+    let timeout = Duration::from_secs(30);
+    let outcome = run(ShutdownMode::GracefulDrain, timeout);
+
+    // the in-flight transfer completes before the connection is closed
+    assert!(outcome.transfer_finished, "{outcome:?}");
+    assert!(outcome.accept_closed, "{outcome:?}");
+    assert!(!is_timed_out_close(&outcome.client_error), "{outcome:?}");
+
+    let shutdown_duration = outcome.shutdown_duration.unwrap();
+    assert!(shutdown_duration < timeout, "{outcome:?}");
+}
+
+#[test]
+fn server_shutdown_graceful_drain_timeout_test() {
+    let timeout = Duration::from_millis(100);
+    let outcome = run(ShutdownMode::GracefulDrain, timeout);
+
+    // the transfer doesn't fit in the timeout so the connection is closed
+    assert!(!outcome.transfer_finished, "{outcome:?}");
+    assert!(outcome.accept_closed, "{outcome:?}");
+    // the client can tell the close apart from one that drained all of the data
+    assert!(is_timed_out_close(&outcome.client_error), "{outcome:?}");
+
+    let shutdown_duration = outcome.shutdown_duration.unwrap();
+    assert!(shutdown_duration >= timeout, "{outcome:?}");
+    assert!(
+        shutdown_duration < timeout + Duration::from_secs(2),
+        "{outcome:?}"
+    );
+}
+
+#[test]
+fn server_shutdown_immediate_test() {
+    let timeout = Duration::from_secs(30);
+    let outcome = run(ShutdownMode::Immediate, timeout);
+
+    // the in-flight transfer is interrupted
+    assert!(!outcome.transfer_finished, "{outcome:?}");
+    assert!(outcome.accept_closed, "{outcome:?}");
+
+    let shutdown_duration = outcome.shutdown_duration.unwrap();
+    assert!(shutdown_duration < Duration::from_secs(2), "{outcome:?}");
+}
@@ -1,11 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::recorder::Recorder;
 use crate::{
     client::Connect,
     provider::{
         event,
-        io::testing::{primary, spawn, Handle, Result},
+        io::testing::{primary, spawn, test, Handle, Model, Result},
     },
     Client, Server,
 };
@@ -81,6 +82,7 @@ pub fn server(handle: &Handle) -> Result<SocketAddr> {
 pub fn build_server(handle: &Handle) -> Result<Server> {
     Ok(Server::builder()
         .with_io(handle.builder().build().unwrap())?
+        .with_random(io::Random)?
         .with_tls(SERVER_CERTS)?
         .with_event(events())?
         .start()?)
@@ -122,9 +124,69 @@ pub fn start_client(client: Client, server_addr: SocketAddr, data: Data) -> Resu
     Ok(())
 }
 
+/// A scenario in which a client sends [`Data`] to a server, which echoes it back
+///
+/// The server accepts streams with [`start_server`] and the client verifies the echoed data
+/// with [`start_client`].
+pub struct EchoServer {
+    data: Data,
+    max_mtu: Option<u16>,
+}
+
+impl EchoServer {
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            max_mtu: None,
+        }
+    }
+
+    /// Sets the max MTU of both the client and server IO providers
+    pub fn with_max_mtu(mut self, max_mtu: u16) -> Self {
+        self.max_mtu = Some(max_mtu);
+        self
+    }
+
+    /// Runs the scenario on `model` and returns the events recorded on the server
+    pub fn run<R: Recorder>(self, model: Model, recorder: R) -> Vec<R::Output> {
+        let recorded = recorder.events();
+
+        test(model, |handle| {
+            let io = || {
+                let mut io = handle.builder();
+                if let Some(max_mtu) = self.max_mtu {
+                    io = io.with_max_mtu(max_mtu);
+                }
+                io.build()
+            };
+
+            let server = Server::builder()
+                .with_io(io()?)?
+                .with_random(io::Random)?
+                .with_tls(SERVER_CERTS)?
+                .with_event((events(), recorder))?
+                .start()?;
+            let client = Client::builder()
+                .with_io(io()?)?
+                .with_random(io::Random)?
+                .with_tls(certificates::CERT_PEM)?
+                .with_event(events())?
+                .start()?;
+            let addr = start_server(server)?;
+            start_client(client, addr, self.data)?;
+            Ok(addr)
+        })
+        .unwrap();
+
+        let recorded = recorded.lock().unwrap();
+        recorded.clone()
+    }
+}
+
 pub fn build_client(handle: &Handle) -> Result<Client> {
     Ok(Client::builder()
         .with_io(handle.builder().build().unwrap())?
+        .with_random(io::Random)?
         .with_tls(certificates::CERT_PEM)?
         .with_event(events())?
         .start()?)
@@ -0,0 +1,93 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{
+    event::api::Subject,
+    packet::interceptor::{Datagram, Interceptor},
+};
+
+/// Records the spin bit of each received datagram that starts with a short header packet,
+/// similar to what an on-path observer would see
+#[derive(Clone, Default)]
+struct SpinObserver(Arc<Mutex<Vec<bool>>>);
+
+impl Interceptor for SpinObserver {
+    #[inline]
+    fn intercept_rx_datagram<'a>(
+        &mut self,
+        _subject: &Subject,
+        _datagram: &Datagram,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        if let Ok(tag) = payload.peek_byte(0) {
+            // short header packets have the header form bit unset
+            if tag & 0x80 == 0 {
+                self.0.lock().unwrap().push(tag & 0x20 != 0);
+            }
+        }
+        payload
+    }
+}
+
+/// Returns the spin bits observed by the client and server, respectively
+fn observe(spin_bit: bool) -> (Vec<bool>, Vec<bool>) {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let client_observer = SpinObserver::default();
+    let server_observer = SpinObserver::default();
+    let limits = provider::limits::Limits::default()
+        .with_spin_bit(spin_bit)
+        .unwrap();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event(events())?
+            .with_packet_interceptor(server_observer.clone())?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(limits)?
+            .with_event(events())?
+            .with_packet_interceptor(client_observer.clone())?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let client = core::mem::take(&mut *client_observer.0.lock().unwrap());
+    let server = core::mem::take(&mut *server_observer.0.lock().unwrap());
+    (client, server)
+}
+
+fn toggles(spin_bits: &[bool]) -> bool {
+    spin_bits.windows(2).any(|pair| pair[0] != pair[1])
+}
+
+#[test]
+fn spin_bit_disabled_test() {
+    let (client, server) = observe(false);
+
+    assert!(!client.is_empty());
+    assert!(!server.is_empty());
+    // the spin bit stays constant when disabled
+    assert!(client.iter().chain(server.iter()).all(|spin| !spin));
+}
+
+#[test]
+fn spin_bit_enabled_test() {
+    let (client, server) = observe(true);
+
+    // each endpoint may not spin on some connection IDs so check both directions
+    assert!(toggles(&client) || toggles(&server));
+}
@@ -0,0 +1,94 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::stateless_reset_token::Keyed;
+
+type Slot<T> = Arc<Mutex<Option<T>>>;
+
+/// Starts a server with the given stateless reset token secret and stores its socket in `slot`
+fn start_keyed_server(
+    handle: &io::Handle,
+    secret: &[u8],
+    slot: Slot<io::Socket>,
+) -> io::Result<SocketAddr> {
+    let io = handle
+        .builder()
+        .on_socket(move |socket| *slot.lock().unwrap() = Some(socket))
+        .build()?;
+    let server = Server::builder()
+        .with_io(io)?
+        .with_tls(SERVER_CERTS)?
+        .with_stateless_reset_token(Keyed::new(secret))?
+        .start()?;
+    start_server(server)
+}
+
+/// Connects a client to one server and then routes the client's packets to another server,
+/// which has no state for the connection, returning the error the client observed
+fn reset_by_other_server(secret_a: &'static [u8], secret_b: &'static [u8]) -> crate::stream::Error {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(10));
+    let error = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let socket_a = Arc::new(Mutex::new(None));
+        let socket_b = Arc::new(Mutex::new(None));
+        let addr = start_keyed_server(handle, secret_a, socket_a.clone())?;
+        start_keyed_server(handle, secret_b, socket_b.clone())?;
+        let client = build_client(handle)?;
+
+        let error = error.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream.send(Bytes::from_static(b"A")).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // move the second server to the address of the first so it receives the client's
+            // packets for a connection it doesn't know about
+            let socket_a: io::Socket = socket_a.lock().unwrap().take().unwrap();
+            let socket_b: io::Socket = socket_b.lock().unwrap().take().unwrap();
+            socket_a.rebind("9.9.9.9:9999".parse().unwrap());
+            socket_b.rebind(addr);
+
+            stream.send(Bytes::from_static(b"B")).await.unwrap();
+            *error.lock().unwrap() = Some(stream.receive().await.unwrap_err());
+
+            // keep the sockets open until the client has observed the error
+            drop((socket_a, socket_b));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let error = error.lock().unwrap().take();
+    error.unwrap()
+}
+
+fn is_stateless_reset(error: &crate::stream::Error) -> bool {
+    matches!(
+        error,
+        crate::stream::Error::ConnectionError {
+            error: crate::connection::Error::StatelessReset { .. },
+            ..
+        }
+    )
+}
+
+/// A stateless reset sent by a server sharing the secret of the connection's server is accepted
+#[test]
+fn shared_secret_stateless_reset_test() {
+    let error = reset_by_other_server(b"shared secret", b"shared secret");
+    assert!(is_stateless_reset(&error), "{error:?}");
+}
+
+/// A stateless reset sent by a server with a different secret isn't accepted
+#[test]
+fn different_secret_stateless_reset_test() {
+    let error = reset_by_other_server(b"secret a", b"secret b");
+    assert!(!is_stateless_reset(&error), "{error:?}");
+}
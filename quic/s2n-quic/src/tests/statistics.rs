@@ -0,0 +1,206 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::connection::Statistics;
+
+#[test]
+fn statistics_test() {
+    let model = Model::default();
+    let delay = Duration::from_millis(50);
+    model.set_delay(delay).set_drop_rate(0.05);
+
+    let statistics = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let statistics = statistics.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            let initial: Statistics = connection.stats().unwrap();
+
+            let len = 100_000;
+            stream
+                .send(Data::new(len as _).send_one(len).unwrap())
+                .await
+                .unwrap();
+            stream.finish().unwrap();
+
+            // wait for the server to echo the data back
+            let mut received = 0;
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                received += chunk.len();
+            }
+            assert_eq!(received, len);
+
+            let stats = connection.stats().unwrap();
+            assert!(stats.bytes_sent > initial.bytes_sent + len as u64);
+            assert!(stats.bytes_received > initial.bytes_received + len as u64);
+            *statistics.lock().unwrap() = Some(stats);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let stats = statistics.lock().unwrap().unwrap();
+
+    // the round trip is the delay in each direction
+    let expected_rtt = delay * 2;
+    let tolerance = Duration::from_millis(5);
+    for rtt in [stats.min_rtt, stats.latest_rtt] {
+        assert!(
+            rtt > expected_rtt - tolerance && rtt < expected_rtt + tolerance,
+            "{stats:?}"
+        );
+    }
+    assert!(stats.smoothed_rtt > expected_rtt - tolerance, "{stats:?}");
+
+    // packets are dropped by the model
+    assert!(stats.packets_lost > 0, "{stats:?}");
+
+    assert!(stats.congestion_window > 0, "{stats:?}");
+    // the MTU is probed up to the default max MTU
+    assert_eq!(stats.mtu, 1472, "{stats:?}");
+}
+
+/// The delivery rate estimate of the BBR congestion controller converges on the bandwidth of the
+/// path
+#[test]
+fn delivery_rate_test() {
+    let model = Model::default();
+    let bandwidth = 1_000_000;
+    model.set_delay(Duration::from_millis(25));
+    model.set_bandwidth(bandwidth);
+
+    let delivery_rates = Arc::new(Mutex::new(vec![]));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .start()?;
+        let addr = server.local_addr()?;
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+            while let Ok(Some(_)) = stream.receive().await {}
+
+            // keep the connection open for the sender
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_congestion_controller(provider::congestion_controller::Bbr::default())?
+            .start()?;
+        let delivery_rates = delivery_rates.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            let chunk = Bytes::from(vec![42; 10_000]);
+            for _ in 0..500 {
+                stream.send(chunk.clone()).await.unwrap();
+                let stats = connection.stats().unwrap();
+                delivery_rates.lock().unwrap().push(stats.delivery_rate);
+            }
+            stream.close().await.unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let delivery_rates = delivery_rates.lock().unwrap();
+
+    // skip the estimates taken while the congestion window was still growing
+    for &delivery_rate in &delivery_rates[delivery_rates.len() / 2..] {
+        let error = delivery_rate.abs_diff(bandwidth);
+        assert!(error < bandwidth / 10, "{delivery_rate}");
+    }
+}
+
+/// The `ConnectionClosed` event summarizes the lifetime of the connection
+#[test]
+fn connection_closed_summary_test() {
+    let model = Model::default();
+    let delay = Duration::from_millis(50);
+    model.set_delay(delay);
+
+    let subscriber = recorder::ConnectionSummary::new();
+    let summaries = subscriber.events();
+    let len = 100_000;
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), subscriber))?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream
+                .send(Data::new(len as _).send_one(len).unwrap())
+                .await
+                .unwrap();
+            stream.finish().unwrap();
+
+            // wait for the server to echo the data back
+            while stream.receive().await.unwrap().is_some() {}
+
+            connection
+                .close_gracefully(Duration::from_secs(1))
+                .await
+                .unwrap();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let summaries = summaries.lock().unwrap();
+    assert_eq!(summaries.len(), 1);
+    let summary = &summaries[0];
+
+    assert!(
+        matches!(
+            summary.error,
+            crate::connection::Error::Closed {
+                initiator: s2n_quic_core::endpoint::Location::Local,
+                ..
+            }
+        ),
+        "{summary:?}"
+    );
+
+    // the connection was open for at least the handshake and the echo round trips
+    assert!(summary.duration >= delay * 4, "{summary:?}");
+
+    let stats = summary.statistics;
+    assert!(stats.bytes_sent > len as u64, "{stats:?}");
+    assert!(stats.bytes_received > len as u64, "{stats:?}");
+    // the MTU is probed up to the default max MTU
+    assert_eq!(stats.largest_mtu, 1472, "{stats:?}");
+    let expected_rtt = delay * 2;
+    assert!(stats.min_rtt >= expected_rtt, "{stats:?}");
+}
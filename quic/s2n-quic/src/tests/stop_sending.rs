@@ -0,0 +1,86 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+const STOP_SENDING_ERROR: u32 = 77;
+const TOTAL_LEN: usize = 10_000_000;
+const CHUNK_LEN: usize = 10_000;
+
+/// A receiver stops a large transfer early and the sender observes the reset
+#[test]
+fn stop_sending_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let sender = Arc::new(Mutex::new(None));
+    let receiver = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let receiver = receiver.clone();
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection.accept_receive_stream().await.unwrap().unwrap();
+
+            // read part of the transfer before cancelling it
+            let mut received = 0;
+            while received < TOTAL_LEN / 100 {
+                let chunk = stream.receive().await.unwrap().unwrap();
+                received += chunk.len();
+            }
+
+            stream
+                .stop_sending(crate::application::Error::from(STOP_SENDING_ERROR))
+                .unwrap();
+            *receiver.lock().unwrap() = Some(received);
+
+            // keep the connection open for the client
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        let client = build_client(handle)?;
+        let sender = sender.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_send_stream().await.unwrap();
+
+            let mut sent = 0;
+            let result = async {
+                while sent < TOTAL_LEN {
+                    stream.send(Bytes::from(vec![42; CHUNK_LEN])).await?;
+                    sent += CHUNK_LEN;
+                }
+                stream.finish()?;
+                stream.flush().await
+            }
+            .await;
+
+            *sender.lock().unwrap() = Some((sent, result));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let received = receiver.lock().unwrap().take().unwrap();
+    let (sent, result) = sender.lock().unwrap().take().unwrap();
+
+    assert!(received >= TOTAL_LEN / 100);
+    assert!(sent < TOTAL_LEN, "the transfer should be cancelled early");
+    assert!(
+        matches!(
+            result,
+            Err(crate::stream::Error::StreamReset { error, .. })
+                if error == crate::application::Error::from(STOP_SENDING_ERROR)
+        ),
+        "{result:?}"
+    );
+}
@@ -0,0 +1,141 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{connection, provider::limits::Limits};
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{
+    endpoint,
+    event::api::Subject,
+    frame::{Frame, FrameMut},
+    packet::interceptor::{Interceptor, Packet},
+    stream::{StreamId, StreamType},
+    transport,
+};
+
+const MAX_STREAMS: u64 = 2;
+
+/// Opens a single stream of the given type on a server that only allows `MAX_STREAMS` and
+/// rewrites the stream ID, as received by the server, to exceed the limit
+fn open_stream(stream_type: StreamType) -> Vec<connection::Error> {
+    let model = Model::default();
+    let subscriber = recorder::ConnectionClosed::new();
+    let closed_events = subscriber.events();
+
+    test(model, |handle| {
+        let limits = Limits::default()
+            .with_max_open_remote_bidirectional_streams(MAX_STREAMS)?
+            .with_max_open_remote_unidirectional_streams(MAX_STREAMS)?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_limits(limits)?
+            .with_event((events(), subscriber))?
+            .with_packet_interceptor(ExceedStreamLimit {
+                stream_type,
+                done: false,
+            })?
+            .start()?;
+        let addr = start_server(server)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let data = Bytes::from_static(b"hello");
+            let _ = match stream_type {
+                StreamType::Bidirectional => {
+                    let mut stream = connection.open_bidirectional_stream().await.unwrap();
+                    stream.send(data).await
+                }
+                StreamType::Unidirectional => {
+                    let mut stream = connection.open_send_stream().await.unwrap();
+                    stream.send(data).await
+                }
+            };
+
+            // wait for the server to close the connection
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let closed_events = closed_events.lock().unwrap().clone();
+    closed_events
+}
+
+fn assert_stream_limit_error(closed_events: &[connection::Error]) {
+    assert_eq!(1, closed_events.len(), "{closed_events:?}");
+    assert!(
+        matches!(
+            closed_events[0],
+            connection::Error::Transport {
+                code,
+                initiator: endpoint::Location::Local,
+                ..
+            } if code == transport::Error::STREAM_LIMIT_ERROR.code
+        ),
+        "{closed_events:?}"
+    );
+}
+
+/// A peer opening more bidirectional streams than advertised closes the connection
+#[test]
+fn bidirectional_stream_limit_test() {
+    assert_stream_limit_error(&open_stream(StreamType::Bidirectional));
+}
+
+/// A peer opening more unidirectional streams than advertised closes the connection
+#[test]
+fn unidirectional_stream_limit_test() {
+    assert_stream_limit_error(&open_stream(StreamType::Unidirectional));
+}
+
+/// Rewrites the ID of the first client-initiated stream of the given type to the first ID
+/// past the server's stream limit
+struct ExceedStreamLimit {
+    stream_type: StreamType,
+    done: bool,
+}
+
+impl Interceptor for ExceedStreamLimit {
+    #[inline]
+    fn intercept_rx_payload<'a>(
+        &mut self,
+        _subject: &Subject,
+        packet: &Packet,
+        payload: DecoderBufferMut<'a>,
+    ) -> DecoderBufferMut<'a> {
+        if self.done || !packet.number.space().is_application_data() {
+            return payload;
+        }
+
+        let initial = StreamId::initial(endpoint::Type::Client, self.stream_type).as_varint();
+        let exceeded = StreamId::nth(endpoint::Type::Client, self.stream_type, MAX_STREAMS)
+            .unwrap()
+            .as_varint();
+
+        let payload = payload.into_less_safe_slice();
+
+        // decode a copy of the payload to find the offset of the stream frame
+        let mut frames = payload.to_vec();
+        let mut buffer = DecoderBufferMut::new(&mut frames);
+        let mut offset = 0;
+        while !buffer.is_empty() {
+            let len = buffer.len();
+            let (frame, remaining) = buffer.decode::<FrameMut>().unwrap();
+            if matches!(frame, Frame::Stream(ref stream) if stream.stream_id == initial) {
+                // the frame type and both stream IDs are encoded in a single byte
+                payload[offset + 1] = exceeded.as_u64() as u8;
+                self.done = true;
+                break;
+            }
+            offset += len - remaining.len();
+            buffer = remaining;
+        }
+
+        DecoderBufferMut::new(payload)
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::now;
+
+/// Starts a server that discards all of the data it receives
+fn start_discard_server(handle: &io::Handle) -> io::Result<SocketAddr> {
+    let mut server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .start()?;
+    let addr = server.local_addr()?;
+
+    spawn(async move {
+        while let Some(mut connection) = server.accept().await {
+            spawn(async move {
+                while let Ok(Some(mut stream)) = connection.accept_receive_stream().await {
+                    spawn(async move { while let Ok(Some(_)) = stream.receive().await {} });
+                }
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+// A stream with a higher priority completes before a stream with a lower priority when
+// both start sending at the same time
+#[test]
+fn stream_priority_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_max_inflight(20);
+    let elapsed = Arc::new(Mutex::new(vec![]));
+    const CHUNKS: usize = 50;
+
+    test(model, |handle| {
+        let addr = start_discard_server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .start()?;
+
+        let elapsed = elapsed.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let start = now();
+
+            for priority in [0, 1] {
+                let mut stream = connection.open_send_stream().await.unwrap();
+                stream.set_priority(priority).unwrap();
+
+                let elapsed = elapsed.clone();
+                primary::spawn(async move {
+                    let chunk = Bytes::from(vec![42; 10_000]);
+                    for _ in 0..CHUNKS {
+                        stream.send(chunk.clone()).await.unwrap();
+                    }
+                    stream.close().await.unwrap();
+                    elapsed.lock().unwrap().push((priority, now() - start));
+                });
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let elapsed = elapsed.lock().unwrap();
+    let (first_priority, urgent) = elapsed[0];
+    let (_, bulk) = elapsed[1];
+
+    assert_eq!(
+        first_priority, 1,
+        "the high priority stream should finish first"
+    );
+    // the high priority stream uses most of the bandwidth until it completes
+    assert!(urgent * 3 < bulk * 2, "{:?} < {:?}", urgent, bulk);
+}
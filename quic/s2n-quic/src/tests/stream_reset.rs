@@ -0,0 +1,84 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+const RESET_ERROR: u32 = 1234;
+
+/// A stream reset by the peer reports the peer's error code and final size when read
+#[test]
+fn stream_reset_test() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    let result = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        spawn(async move {
+            let mut connection = server.accept().await.unwrap();
+            let mut stream = connection
+                .accept_bidirectional_stream()
+                .await
+                .unwrap()
+                .unwrap();
+
+            // respond with some data before resetting the stream
+            stream.send(Bytes::from(vec![42; 1000])).await.unwrap();
+            stream.flush().await.unwrap();
+            stream
+                .reset(crate::application::Error::from(RESET_ERROR))
+                .unwrap();
+
+            // keep the connection open for the client
+            while let Ok(Some(_)) = connection.accept().await {}
+        });
+
+        let client = build_client(handle)?;
+        let result = result.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"request")).await.unwrap();
+
+            let mut received = 0;
+            let error = loop {
+                match stream.receive().await {
+                    Ok(Some(chunk)) => received += chunk.len() as u64,
+                    Ok(None) => panic!("the stream should be reset"),
+                    Err(err) => break err,
+                }
+            };
+
+            *result.lock().unwrap() = Some((received, error));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let (received, error) = result.lock().unwrap().take().unwrap();
+
+    match error {
+        crate::stream::Error::StreamReset {
+            error, final_size, ..
+        } => {
+            assert_eq!(error, crate::application::Error::from(RESET_ERROR));
+            let final_size = final_size.expect("the peer should provide the final size");
+            assert!(final_size >= received, "{final_size} < {received}");
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+
+    assert_eq!(
+        s2n_quic_core::application::error::TryInto::application_error(&error),
+        Some(crate::application::Error::from(RESET_ERROR))
+    );
+}
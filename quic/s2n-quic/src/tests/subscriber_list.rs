@@ -0,0 +1,71 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::event::DynSubscriber;
+
+/// Each subscriber in a runtime-sized list receives the connection events
+#[test]
+fn subscriber_list_test() {
+    let model = Model::default();
+    let subscribers: Vec<_> = (0..3)
+        .map(|_| Box::new(recorder::MtuUpdated::new()))
+        .collect();
+    let events: Vec<_> = subscribers
+        .iter()
+        .map(|subscriber| subscriber.events())
+        .collect();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscribers)?
+            .start()?;
+        let addr = start_server(server)?;
+        client(handle, addr)?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let expected = events[0].lock().unwrap().clone();
+    assert!(!expected.is_empty());
+    for events in &events[1..] {
+        let events = events.lock().unwrap();
+        assert_eq!(format!("{:?}", *events), format!("{expected:?}"));
+    }
+}
+
+/// Subscribers of different types can be stored in the same list
+#[test]
+fn dyn_subscriber_list_test() {
+    let model = Model::default();
+    let mtu_updated = recorder::MtuUpdated::new();
+    let mtu_events = mtu_updated.events();
+    let handshake_status = recorder::HandshakeStatus::new();
+    let handshake_events = handshake_status.events();
+    let connection_closed = recorder::ConnectionSummary::new();
+    let closed_events = connection_closed.events();
+
+    let subscribers: Vec<Box<dyn DynSubscriber>> = vec![
+        Box::new(mtu_updated),
+        Box::new(handshake_status),
+        Box::new((events(), connection_closed)),
+    ];
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(subscribers)?
+            .start()?;
+        let addr = start_server(server)?;
+        client(handle, addr)?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(!mtu_events.lock().unwrap().is_empty());
+    assert!(!handshake_events.lock().unwrap().is_empty());
+    assert!(!closed_events.lock().unwrap().is_empty());
+}
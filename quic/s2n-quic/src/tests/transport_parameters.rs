@@ -0,0 +1,52 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::transport_parameters_interceptor::{
+    TransportParameters, TransportParametersInterceptor,
+};
+
+/// Advertises the provided `max_udp_payload_size` to the peer
+struct MaxUdpPayloadSize(u16);
+
+impl TransportParametersInterceptor for MaxUdpPayloadSize {
+    fn on_local_parameters<A, B, C, D>(
+        &mut self,
+        parameters: &mut TransportParameters<A, B, C, D>,
+    ) {
+        parameters.max_udp_payload_size = self.0.try_into().unwrap();
+    }
+}
+
+#[test]
+fn max_udp_payload_size_test() {
+    let max_udp_payload_size = 1300;
+
+    let model = Model::default();
+    let subscriber = recorder::MtuUpdated::new();
+    let mtu_updates = subscriber.events();
+
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(events())?
+            .with_transport_parameters_interceptor(MaxUdpPayloadSize(max_udp_payload_size))?
+            .start()?;
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(subscriber)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1_000_000))?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let mtu_updates = mtu_updates.lock().unwrap();
+    let max_mtu = mtu_updates.iter().map(|event| event.mtu).max().unwrap();
+
+    // the client probes up to, but never beyond, the limit advertised by the server
+    assert_eq!(max_mtu, max_udp_payload_size, "{mtu_updates:?}");
+}